@@ -5,7 +5,7 @@ use lemmy_api_common::{
   comment::{CommentResponse, CreateCommentLike},
   context::LemmyContext,
   send_activity::{ActivityChannel, SendActivityData},
-  utils::{check_community_user_action, check_downvotes_enabled},
+  utils::{check_community_content_visible, check_community_user_action, check_downvotes_enabled},
 };
 use lemmy_db_schema::{
   newtypes::LocalUserId,
@@ -42,6 +42,12 @@ pub async fn like_comment(
     &mut context.pool(),
   )
   .await?;
+  check_community_content_visible(
+    Some(&local_user_view.person),
+    &orig_comment.community,
+    &mut context.pool(),
+  )
+  .await?;
 
   // Add parent poster or commenter to recipients
   let comment_reply = CommentReply::read_by_comment(&mut context.pool(), comment_id).await;
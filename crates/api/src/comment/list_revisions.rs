@@ -0,0 +1,62 @@
+use actix_web::web::{Data, Json, Query};
+use lemmy_api_common::{
+  comment::{CommentRevisionDiff, ListCommentRevisions, ListCommentRevisionsResponse},
+  context::LemmyContext,
+  utils::{check_community_content_visible, check_private_instance, is_mod_or_admin_opt},
+};
+use lemmy_db_schema::source::{
+  comment::{Comment, CommentRevision},
+  local_site::LocalSite,
+};
+use lemmy_db_views::structs::{CommentView, LocalUserView};
+use lemmy_utils::{error::LemmyError, utils::diff::line_diff};
+
+#[tracing::instrument(skip(context))]
+pub async fn list_comment_revisions(
+  data: Query<ListCommentRevisions>,
+  context: Data<LemmyContext>,
+  local_user_view: Option<LocalUserView>,
+) -> Result<Json<ListCommentRevisionsResponse>, LemmyError> {
+  let local_site = LocalSite::read(&mut context.pool()).await?;
+  check_private_instance(&local_user_view, &local_site)?;
+
+  let comment_id = data.comment_id;
+  let person_id = local_user_view.as_ref().map(|u| u.person.id);
+  let comment_view = CommentView::read(&mut context.pool(), comment_id, person_id).await?;
+  check_community_content_visible(
+    local_user_view.as_ref().map(|u| &u.person),
+    &comment_view.community,
+    &mut context.pool(),
+  )
+  .await?;
+
+  // Earlier versions of deleted or removed comments are only visible to mods
+  if comment_view.comment.deleted || comment_view.comment.removed {
+    is_mod_or_admin_opt(
+      &mut context.pool(),
+      local_user_view.as_ref(),
+      Some(comment_view.community.id),
+    )
+    .await?;
+  }
+
+  let revisions = CommentRevision::list_for_comment(&mut context.pool(), comment_id).await?;
+
+  // Each revision is compared with the one after it, the last one with the current comment
+  let next_versions = revisions
+    .iter()
+    .skip(1)
+    .map(|r| r.content.clone())
+    .chain(std::iter::once(comment_view.comment.content));
+  let revisions = revisions
+    .iter()
+    .cloned()
+    .zip(next_versions)
+    .map(|(revision, next_content)| CommentRevisionDiff {
+      content_diff: line_diff(&revision.content, &next_content),
+      revision,
+    })
+    .collect();
+
+  Ok(Json(ListCommentRevisionsResponse { revisions }))
+}
@@ -1,3 +1,5 @@
 pub mod distinguish;
 pub mod like;
+pub mod list_revisions;
+pub mod react;
 pub mod save;
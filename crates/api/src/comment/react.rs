@@ -0,0 +1,80 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  build_response::build_comment_response,
+  comment::CommentResponse,
+  context::LemmyContext,
+  reaction::ReactToComment,
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::{check_community_content_visible, check_community_user_action, check_emoji_reaction},
+};
+use lemmy_db_schema::source::reaction::{Reaction, ReactionForm};
+use lemmy_db_views::structs::{CommentView, LocalUserView};
+use lemmy_utils::error::{LemmyError, LemmyErrorExt, LemmyErrorType};
+use std::ops::Deref;
+
+#[tracing::instrument(skip(context))]
+pub async fn react_to_comment(
+  data: Json<ReactToComment>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> Result<Json<CommentResponse>, LemmyError> {
+  let comment_id = data.comment_id;
+  let orig_comment = CommentView::read(&mut context.pool(), comment_id, None).await?;
+
+  check_community_user_action(
+    &local_user_view.person,
+    orig_comment.community.id,
+    &mut context.pool(),
+  )
+  .await?;
+  check_community_content_visible(
+    Some(&local_user_view.person),
+    &orig_comment.community,
+    &mut context.pool(),
+  )
+  .await?;
+  let custom_emoji =
+    check_emoji_reaction(&data.emoji, orig_comment.community.id, &mut context.pool()).await?;
+
+  let person_id = local_user_view.person.id;
+  let post_id = orig_comment.post.id;
+  if data.add {
+    let form = ReactionForm {
+      person_id,
+      post_id,
+      comment_id: Some(comment_id),
+      emoji: data.emoji.clone(),
+      custom_emoji_id: custom_emoji.as_ref().map(|e| e.id),
+    };
+    Reaction::react(&mut context.pool(), &form)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntLikeComment)?;
+  } else {
+    Reaction::remove(
+      &mut context.pool(),
+      person_id,
+      post_id,
+      Some(comment_id),
+      &data.emoji,
+    )
+    .await?;
+  }
+
+  ActivityChannel::submit_activity(
+    SendActivityData::ReactPostOrComment(
+      orig_comment.comment.ap_id,
+      local_user_view.person.clone(),
+      orig_comment.community,
+      data.emoji.clone(),
+      custom_emoji,
+      data.add,
+    ),
+    &context,
+  )
+  .await?;
+
+  Ok(Json(
+    build_comment_response(context.deref(), comment_id, Some(local_user_view), vec![]).await?,
+  ))
+}
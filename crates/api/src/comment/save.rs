@@ -2,10 +2,14 @@ use actix_web::web::{Data, Json};
 use lemmy_api_common::{
   comment::{CommentResponse, SaveComment},
   context::LemmyContext,
+  utils::check_community_content_visible_by_id,
 };
 use lemmy_db_schema::{
-  source::comment::{CommentSaved, CommentSavedForm},
-  traits::Saveable,
+  source::{
+    comment::{Comment, CommentSaved, CommentSavedForm},
+    post::Post,
+  },
+  traits::{Crud, Saveable},
 };
 use lemmy_db_views::structs::{CommentView, LocalUserView};
 use lemmy_utils::error::{LemmyError, LemmyErrorExt, LemmyErrorType};
@@ -16,6 +20,15 @@ pub async fn save_comment(
   context: Data<LemmyContext>,
   local_user_view: LocalUserView,
 ) -> Result<Json<CommentResponse>, LemmyError> {
+  let comment = Comment::read(&mut context.pool(), data.comment_id).await?;
+  let post = Post::read(&mut context.pool(), comment.post_id).await?;
+  check_community_content_visible_by_id(
+    Some(&local_user_view.person),
+    post.community_id,
+    &mut context.pool(),
+  )
+  .await?;
+
   let comment_saved_form = CommentSavedForm {
     comment_id: data.comment_id,
     person_id: local_user_view.person.id,
@@ -5,7 +5,14 @@ use lemmy_api_common::{
   comment::{CommentReportResponse, CreateCommentReport},
   context::LemmyContext,
   send_activity::{ActivityChannel, SendActivityData},
-  utils::{check_community_user_action, send_new_report_email_to_admins},
+  utils::{
+    check_community_content_visible,
+    check_community_user_action,
+    generate_report_ap_id,
+    send_new_report_email_to_admins,
+    send_new_report_notifications,
+    send_webhook_event,
+  },
 };
 use lemmy_db_schema::{
   source::{
@@ -13,9 +20,11 @@ use lemmy_db_schema::{
     local_site::LocalSite,
   },
   traits::Reportable,
+  WebhookEventType,
 };
 use lemmy_db_views::structs::{CommentReportView, CommentView, LocalUserView};
 use lemmy_utils::error::{LemmyError, LemmyErrorExt, LemmyErrorType};
+use std::future::ready;
 
 /// Creates a comment report and notifies the moderators of the community
 #[tracing::instrument(skip(context))]
@@ -39,12 +48,20 @@ pub async fn create_comment_report(
     &mut context.pool(),
   )
   .await?;
+  check_community_content_visible(
+    Some(&local_user_view.person),
+    &comment_view.community,
+    &mut context.pool(),
+  )
+  .await?;
 
+  let ap_id = generate_report_ap_id(&context.settings().get_protocol_and_hostname())?;
   let report_form = CommentReportForm {
     creator_id: person_id,
     comment_id,
     original_comment_text: comment_view.comment.content,
     reason,
+    ap_id: Some(ap_id.clone()),
   };
 
   let report = CommentReport::report(&mut context.pool(), &report_form)
@@ -64,10 +81,26 @@ pub async fn create_comment_report(
     )
     .await?;
   }
+  send_new_report_notifications(
+    Some(comment_view.community.id),
+    &comment_report_view.creator.name,
+    &comment_report_view.comment_creator.name,
+    &comment_report_view.comment_report.reason,
+    &context,
+  )
+  .await?;
+  send_webhook_event(
+    WebhookEventType::ReportCreated,
+    Some(comment_view.community.id),
+    ready(Ok::<_, LemmyError>(&comment_report_view)),
+    &mut context.pool(),
+  )
+  .await;
 
   ActivityChannel::submit_activity(
     SendActivityData::CreateReport(
       comment_view.comment.ap_id.inner().clone(),
+      ap_id.into(),
       local_user_view.person,
       comment_view.community,
       data.reason.clone(),
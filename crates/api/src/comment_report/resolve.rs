@@ -1,14 +1,21 @@
-use actix_web::web::{Data, Json};
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
 use lemmy_api_common::{
   comment::{CommentReportResponse, ResolveCommentReport},
   context::LemmyContext,
-  utils::check_community_mod_action,
+  live_hub::LiveMessage,
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::{check_community_mod_action, log_report_resolution},
 };
 use lemmy_db_schema::{source::comment_report::CommentReport, traits::Reportable};
 use lemmy_db_views::structs::{CommentReportView, LocalUserView};
-use lemmy_utils::error::{LemmyError, LemmyErrorExt, LemmyErrorType};
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorExt, LemmyErrorType},
+  utils::validation::is_valid_body_field,
+};
 
-/// Resolves or unresolves a comment report and notifies the moderators of the community
+/// Resolves or unresolves a comment report, logs it in the modlog and notifies the moderators of
+/// the community
 #[tracing::instrument(skip(context))]
 pub async fn resolve_comment_report(
   data: Json<ResolveCommentReport>,
@@ -27,21 +34,51 @@ pub async fn resolve_comment_report(
     &mut context.pool(),
   )
   .await?;
+  is_valid_body_field(&data.reason, false)?;
 
   if data.resolved {
-    CommentReport::resolve(&mut context.pool(), report_id, person_id)
-      .await
-      .with_lemmy_type(LemmyErrorType::CouldntResolveReport)?;
+    CommentReport::resolve(
+      &mut context.pool(),
+      report_id,
+      person_id,
+      data.reason.clone(),
+    )
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntResolveReport)?;
   } else {
     CommentReport::unresolve(&mut context.pool(), report_id, person_id)
       .await
       .with_lemmy_type(LemmyErrorType::CouldntResolveReport)?;
   }
+  log_report_resolution(
+    person_id,
+    &report.post,
+    Some(&report.comment),
+    1,
+    data.resolved,
+    data.reason.clone(),
+    &mut context.pool(),
+  )
+  .await?;
+
+  context.live().send(LiveMessage::Report {
+    community_id: Some(report.community.id),
+  });
 
   let report_id = data.report_id;
   let comment_report_view =
     CommentReportView::read(&mut context.pool(), report_id, person_id).await?;
 
+  ActivityChannel::submit_activity(
+    SendActivityData::ResolveCommentReport(
+      comment_report_view.comment_report.clone(),
+      local_user_view.person,
+      data.resolved,
+    ),
+    &context,
+  )
+  .await?;
+
   Ok(Json(CommentReportResponse {
     comment_report_view,
   }))
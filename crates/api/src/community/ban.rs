@@ -4,11 +4,18 @@ use lemmy_api_common::{
   community::{BanFromCommunity, BanFromCommunityResponse},
   context::LemmyContext,
   send_activity::{ActivityChannel, SendActivityData},
-  utils::{check_community_mod_action, check_expire_time, remove_user_data_in_community},
+  utils::{
+    check_community_mod_action,
+    check_expire_time,
+    remove_user_data_in_community,
+    send_webhook_event,
+  },
+  webhook::PersonBannedEvent,
 };
 use lemmy_db_schema::{
   source::{
     community::{
+      Community,
       CommunityFollower,
       CommunityFollowerForm,
       CommunityPersonBan,
@@ -17,6 +24,7 @@ use lemmy_db_schema::{
     moderator::{ModBanFromCommunity, ModBanFromCommunityForm},
   },
   traits::{Bannable, Crud, Followable},
+  WebhookEventType,
 };
 use lemmy_db_views::structs::LocalUserView;
 use lemmy_db_views_actor::structs::PersonView;
@@ -91,6 +99,23 @@ pub async fn ban_from_community(
 
   let person_view = PersonView::read(&mut context.pool(), data.person_id).await?;
 
+  send_webhook_event(
+    WebhookEventType::PersonBanned,
+    Some(data.community_id),
+    async {
+      Ok::<_, LemmyError>(PersonBannedEvent {
+        person: person_view.person.clone(),
+        moderator: local_user_view.person.clone(),
+        community: Some(Community::read(&mut context.pool(), data.community_id).await?),
+        banned: data.ban,
+        reason: data.reason.clone(),
+        expires: data.expires,
+      })
+    },
+    &mut context.pool(),
+  )
+  .await;
+
   ActivityChannel::submit_activity(
     SendActivityData::BanFromCommunity(
       local_user_view.person,
@@ -12,6 +12,7 @@ use lemmy_db_schema::{
     community::{Community, CommunityFollower, CommunityFollowerForm},
   },
   traits::{Crud, Followable},
+  CommunityVisibility,
 };
 use lemmy_db_views::structs::LocalUserView;
 use lemmy_db_views_actor::structs::CommunityView;
@@ -35,6 +36,14 @@ pub async fn follow_community(
       check_community_user_action(&local_user_view.person, community.id, &mut context.pool())
         .await?;
 
+      // Follow requests of private communities need to be approved by a moderator
+      community_follower_form.pending = community.visibility == CommunityVisibility::Private
+        && !CommunityView::is_mod_or_admin(
+          &mut context.pool(),
+          local_user_view.person.id,
+          community.id,
+        )
+        .await?;
       CommunityFollower::follow(&mut context.pool(), &community_follower_form)
         .await
         .with_lemmy_type(LemmyErrorType::CommunityFollowerAlreadyExists)?;
@@ -3,4 +3,8 @@ pub mod ban;
 pub mod block;
 pub mod follow;
 pub mod hide;
+pub mod pending_follows;
+pub mod post_tag;
 pub mod transfer;
+pub mod warn;
+pub mod wiki;
@@ -0,0 +1,69 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  community::ApproveCommunityPendingFollow,
+  context::LemmyContext,
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::check_community_mod_action,
+  SuccessResponse,
+};
+use lemmy_db_schema::{
+  source::{
+    community::{Community, CommunityFollower, CommunityFollowerForm},
+    person::Person,
+  },
+  traits::{Crud, Followable},
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyError, LemmyErrorExt, LemmyErrorType};
+
+#[tracing::instrument(skip(context))]
+pub async fn approve_community_pending_follow(
+  data: Json<ApproveCommunityPendingFollow>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> Result<Json<SuccessResponse>, LemmyError> {
+  check_community_mod_action(
+    &local_user_view.person,
+    data.community_id,
+    false,
+    &mut context.pool(),
+  )
+  .await?;
+
+  let community = Community::read(&mut context.pool(), data.community_id).await?;
+  let follower = Person::read(&mut context.pool(), data.follower_id).await?;
+
+  if data.approve {
+    CommunityFollower::approve(&mut context.pool(), community.id, follower.id)
+      .await
+      .with_lemmy_type(LemmyErrorType::NoPendingFollowRequest)?;
+
+    // Remote followers are only told once they are approved
+    if !follower.local {
+      ActivityChannel::submit_activity(
+        SendActivityData::AcceptFollower(community, follower),
+        &context,
+      )
+      .await?;
+    }
+  } else {
+    // Only deny requests, approved followers have to be banned instead
+    if CommunityFollower::is_approved_follower(&mut context.pool(), community.id, follower.id)
+      .await?
+    {
+      Err(LemmyErrorType::NoPendingFollowRequest)?
+    }
+    let form = CommunityFollowerForm {
+      community_id: community.id,
+      person_id: follower.id,
+      pending: true,
+    };
+    let deleted = CommunityFollower::unfollow(&mut context.pool(), &form).await?;
+    if deleted == 0 {
+      Err(LemmyErrorType::NoPendingFollowRequest)?
+    }
+  }
+
+  Ok(Json(SuccessResponse::default()))
+}
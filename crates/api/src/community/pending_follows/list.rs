@@ -0,0 +1,37 @@
+use activitypub_federation::config::Data;
+use actix_web::web::{Json, Query};
+use lemmy_api_common::{
+  community::{ListCommunityPendingFollows, ListCommunityPendingFollowsResponse},
+  context::LemmyContext,
+  utils::check_community_mod_action,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_db_views_actor::structs::CommunityFollowerView;
+use lemmy_utils::error::LemmyError;
+
+#[tracing::instrument(skip(context))]
+pub async fn list_community_pending_follows(
+  data: Query<ListCommunityPendingFollows>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> Result<Json<ListCommunityPendingFollowsResponse>, LemmyError> {
+  check_community_mod_action(
+    &local_user_view.person,
+    data.community_id,
+    false,
+    &mut context.pool(),
+  )
+  .await?;
+
+  let pending_follows = CommunityFollowerView::list_pending(
+    &mut context.pool(),
+    data.community_id,
+    data.page,
+    data.limit,
+  )
+  .await?;
+
+  Ok(Json(ListCommunityPendingFollowsResponse {
+    pending_follows,
+  }))
+}
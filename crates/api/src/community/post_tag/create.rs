@@ -0,0 +1,78 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  community::{CommunityPostTagResponse, CreateCommunityPostTag},
+  context::LemmyContext,
+  utils::{check_community_mod_action, generate_post_tag_url, local_site_to_slur_regex},
+};
+use lemmy_db_schema::{
+  source::{
+    community::Community,
+    community_post_tag::{
+      CommunityPostTag,
+      CommunityPostTagInsertForm,
+      CommunityPostTagUpdateForm,
+    },
+    local_site::LocalSite,
+    moderator::{ModCommunityPostTag, ModCommunityPostTagForm},
+  },
+  traits::Crud,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorExt, LemmyErrorType},
+  utils::{slurs::check_slurs, validation::is_valid_post_tag_name},
+};
+
+#[tracing::instrument(skip(context))]
+pub async fn create_community_post_tag(
+  data: Json<CreateCommunityPostTag>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> Result<Json<CommunityPostTagResponse>, LemmyError> {
+  let local_site = LocalSite::read(&mut context.pool()).await?;
+  let display_name = data.display_name.trim().to_string();
+  is_valid_post_tag_name(&display_name)?;
+  check_slurs(&display_name, &local_site_to_slur_regex(&local_site))?;
+
+  let community = Community::read(&mut context.pool(), data.community_id).await?;
+  check_community_mod_action(
+    &local_user_view.person,
+    community.id,
+    false,
+    &mut context.pool(),
+  )
+  .await?;
+  // Tags of remote communities are managed on their home instance, and received via federation
+  if !community.local {
+    Err(LemmyErrorType::ObjectNotLocal)?
+  }
+
+  let tag_form = CommunityPostTagInsertForm::builder()
+    .community_id(community.id)
+    .display_name(display_name)
+    .build();
+  let inserted_tag = CommunityPostTag::create(&mut context.pool(), &tag_form)
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntCreatePostTag)?;
+
+  let ap_id = generate_post_tag_url(&community.actor_id, inserted_tag.id)?;
+  let update_form = CommunityPostTagUpdateForm {
+    ap_id: Some(ap_id),
+    ..Default::default()
+  };
+  let community_post_tag =
+    CommunityPostTag::update(&mut context.pool(), inserted_tag.id, &update_form)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreatePostTag)?;
+
+  let form = ModCommunityPostTagForm {
+    mod_person_id: local_user_view.person.id,
+    community_post_tag_id: community_post_tag.id,
+    display_name: community_post_tag.display_name.clone(),
+    deleted: Some(false),
+  };
+  ModCommunityPostTag::create(&mut context.pool(), &form).await?;
+
+  Ok(Json(CommunityPostTagResponse { community_post_tag }))
+}
@@ -0,0 +1,59 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  community::{CommunityPostTagResponse, DeleteCommunityPostTag},
+  context::LemmyContext,
+  utils::check_community_mod_action,
+};
+use lemmy_db_schema::{
+  source::{
+    community::Community,
+    community_post_tag::{CommunityPostTag, CommunityPostTagUpdateForm},
+    moderator::{ModCommunityPostTag, ModCommunityPostTagForm},
+  },
+  traits::Crud,
+  utils::naive_now,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyError, LemmyErrorExt, LemmyErrorType};
+
+#[tracing::instrument(skip(context))]
+pub async fn delete_community_post_tag(
+  data: Json<DeleteCommunityPostTag>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> Result<Json<CommunityPostTagResponse>, LemmyError> {
+  let orig_tag = CommunityPostTag::read(&mut context.pool(), data.tag_id).await?;
+  check_community_mod_action(
+    &local_user_view.person,
+    orig_tag.community_id,
+    false,
+    &mut context.pool(),
+  )
+  .await?;
+  // Tags of remote communities are managed on their home instance, and received via federation
+  let community = Community::read(&mut context.pool(), orig_tag.community_id).await?;
+  if !community.local {
+    Err(LemmyErrorType::ObjectNotLocal)?
+  }
+
+  // Deleted tags are kept so that they can be restored, but are hidden from posts
+  let form = CommunityPostTagUpdateForm {
+    deleted: Some(data.deleted),
+    updated: Some(Some(naive_now())),
+    ..Default::default()
+  };
+  let community_post_tag = CommunityPostTag::update(&mut context.pool(), data.tag_id, &form)
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntUpdatePostTag)?;
+
+  let form = ModCommunityPostTagForm {
+    mod_person_id: local_user_view.person.id,
+    community_post_tag_id: community_post_tag.id,
+    display_name: community_post_tag.display_name.clone(),
+    deleted: Some(community_post_tag.deleted),
+  };
+  ModCommunityPostTag::create(&mut context.pool(), &form).await?;
+
+  Ok(Json(CommunityPostTagResponse { community_post_tag }))
+}
@@ -0,0 +1,353 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use chrono::{Duration, Utc};
+use lemmy_api_common::{
+  community::{BanFromCommunity, WarnPerson, WarnPersonResponse},
+  context::LemmyContext,
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::{check_community_mod_action, send_warning_notifications, send_webhook_event},
+  webhook::PersonBannedEvent,
+};
+use lemmy_db_schema::{
+  source::{
+    comment::Comment,
+    community::{
+      Community,
+      CommunityFollower,
+      CommunityFollowerForm,
+      CommunityPersonBan,
+      CommunityPersonBanForm,
+    },
+    moderator::{ModBanFromCommunity, ModBanFromCommunityForm, ModWarning, ModWarningForm},
+    person::Person,
+    post::Post,
+  },
+  traits::{Bannable, Crud, Followable},
+  WebhookEventType,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_db_views_actor::structs::CommunityPersonBanView;
+use lemmy_db_views_moderator::structs::ModWarningView;
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorExt, LemmyErrorType},
+  utils::validation::is_valid_body_field,
+};
+
+#[tracing::instrument(skip(context))]
+pub async fn warn_person(
+  data: Json<WarnPerson>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> Result<Json<WarnPersonResponse>, LemmyError> {
+  let (post, comment) = match (data.post_id, data.comment_id) {
+    (Some(post_id), None) => (Post::read(&mut context.pool(), post_id).await?, None),
+    (None, Some(comment_id)) => {
+      let comment = Comment::read(&mut context.pool(), comment_id).await?;
+      let post = Post::read(&mut context.pool(), comment.post_id).await?;
+      (post, Some(comment))
+    }
+    _ => Err(LemmyErrorType::InvalidWarningTarget)?,
+  };
+  let warned_person_id = comment.as_ref().map_or(post.creator_id, |c| c.creator_id);
+
+  check_community_mod_action(
+    &local_user_view.person,
+    post.community_id,
+    false,
+    &mut context.pool(),
+  )
+  .await?;
+  is_valid_body_field(&Some(data.reason.clone()), false)?;
+
+  let form = ModWarningForm {
+    mod_person_id: local_user_view.person.id,
+    other_person_id: warned_person_id,
+    community_id: post.community_id,
+    post_id: post.id,
+    comment_id: comment.as_ref().map(|c| c.id),
+    reason: data.reason.clone(),
+  };
+  let mod_warning = ModWarning::create(&mut context.pool(), &form)
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntCreateWarning)?;
+
+  let community = Community::read(&mut context.pool(), post.community_id).await?;
+  let warned_person = Person::read(&mut context.pool(), warned_person_id).await?;
+  if warned_person.local {
+    send_warning_notifications(&mod_warning, &community, &context).await?;
+  } else if data.federate.unwrap_or(true) {
+    let object_id = comment.map_or(post.ap_id, |c| c.ap_id);
+    ActivityChannel::submit_activity(
+      SendActivityData::WarnPerson(
+        object_id.into(),
+        local_user_view.person.clone(),
+        warned_person.clone(),
+        community.clone(),
+        data.reason.clone(),
+      ),
+      &context,
+    )
+    .await?;
+  }
+
+  let banned = ban_after_warnings(&community, &warned_person, &local_user_view, &context).await?;
+
+  let mod_warning_view = ModWarningView::read(&mut context.pool(), mod_warning.id, false).await?;
+  Ok(Json(WarnPersonResponse {
+    mod_warning_view,
+    banned,
+  }))
+}
+
+/// Bans the person from the community for `warning_ban_days`, if the community has a warning
+/// threshold and the person reached it within `warning_ban_window_days`. Returns whether the
+/// person was banned.
+async fn ban_after_warnings(
+  community: &Community,
+  person: &Person,
+  local_user_view: &LocalUserView,
+  context: &Data<LemmyContext>,
+) -> Result<bool, LemmyError> {
+  let Some(threshold) = community.warning_ban_threshold else {
+    return Ok(false);
+  };
+  let since = Utc::now() - Duration::days(community.warning_ban_window_days.into());
+  let warnings =
+    ModWarning::count_since(&mut context.pool(), person.id, community.id, since).await?;
+  if warnings < i64::from(threshold)
+    || CommunityPersonBanView::get(&mut context.pool(), person.id, community.id).await?
+  {
+    return Ok(false);
+  }
+
+  let expires = Utc::now() + Duration::days(community.warning_ban_days.into());
+  let reason = format!(
+    "{warnings} warnings within {} days",
+    community.warning_ban_window_days
+  );
+  let community_user_ban_form = CommunityPersonBanForm {
+    community_id: community.id,
+    person_id: person.id,
+    expires: Some(Some(expires)),
+  };
+  CommunityPersonBan::ban(&mut context.pool(), &community_user_ban_form)
+    .await
+    .with_lemmy_type(LemmyErrorType::CommunityUserAlreadyBanned)?;
+
+  // Also unsubscribe them from the community, if they are subscribed
+  let community_follower_form = CommunityFollowerForm {
+    community_id: community.id,
+    person_id: person.id,
+    pending: false,
+  };
+  CommunityFollower::unfollow(&mut context.pool(), &community_follower_form)
+    .await
+    .ok();
+
+  let form = ModBanFromCommunityForm {
+    mod_person_id: local_user_view.person.id,
+    other_person_id: person.id,
+    community_id: community.id,
+    reason: Some(reason.clone()),
+    banned: Some(true),
+    expires: Some(expires),
+  };
+  ModBanFromCommunity::create(&mut context.pool(), &form).await?;
+
+  send_webhook_event(
+    WebhookEventType::PersonBanned,
+    Some(community.id),
+    async {
+      Ok::<_, LemmyError>(PersonBannedEvent {
+        person: person.clone(),
+        moderator: local_user_view.person.clone(),
+        community: Some(community.clone()),
+        banned: true,
+        reason: Some(reason.clone()),
+        expires: Some(expires.timestamp()),
+      })
+    },
+    &mut context.pool(),
+  )
+  .await;
+
+  ActivityChannel::submit_activity(
+    SendActivityData::BanFromCommunity(
+      local_user_view.person.clone(),
+      community.id,
+      person.clone(),
+      BanFromCommunity {
+        community_id: community.id,
+        person_id: person.id,
+        ban: true,
+        remove_data: Some(false),
+        reason: Some(reason),
+        expires: Some(expires.timestamp()),
+      },
+    ),
+    context,
+  )
+  .await?;
+  Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+  #![allow(clippy::unwrap_used)]
+  #![allow(clippy::indexing_slicing)]
+
+  use crate::community::warn::ban_after_warnings;
+  use activitypub_federation::config::FederationConfig;
+  use chrono::{Duration, Utc};
+  use lemmy_api_common::context::LemmyContext;
+  use lemmy_db_schema::{
+    source::{
+      community::{Community, CommunityInsertForm, CommunityPersonBan},
+      instance::Instance,
+      local_user::{LocalUser, LocalUserInsertForm},
+      moderator::{ModWarning, ModWarningForm},
+      person::{Person, PersonInsertForm},
+      post::{Post, PostInsertForm},
+      secret::Secret,
+    },
+    traits::Crud,
+    utils::build_db_pool_for_tests,
+  };
+  use lemmy_db_views::structs::LocalUserView;
+  use lemmy_utils::rate_limit::{RateLimitCell, RateLimitConfig};
+  use reqwest::Client;
+  use reqwest_middleware::ClientBuilder;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_ban_after_warnings() {
+    let pool_ = build_db_pool_for_tests().await;
+    let pool = &mut (&pool_).into();
+    let secret = Secret::init(pool).await.unwrap();
+    let context = LemmyContext::create(
+      pool_.clone(),
+      ClientBuilder::new(Client::default()).build(),
+      secret,
+      RateLimitCell::new(RateLimitConfig::builder().build())
+        .await
+        .clone(),
+    );
+    let context = FederationConfig::builder()
+      .domain("example.com")
+      .app_data(context)
+      .build()
+      .await
+      .unwrap()
+      .to_request_data();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld".to_string())
+      .await
+      .unwrap();
+
+    let new_mod = PersonInsertForm::builder()
+      .name("warning_mod".into())
+      .public_key("pubkey".to_string())
+      .instance_id(inserted_instance.id)
+      .build();
+    let inserted_mod = Person::create(pool, &new_mod).await.unwrap();
+    let local_user_form = LocalUserInsertForm::builder()
+      .person_id(inserted_mod.id)
+      .password_encrypted("123456".to_string())
+      .build();
+    let inserted_local_user = LocalUser::create(pool, &local_user_form).await.unwrap();
+    let mod_view = LocalUserView::read(pool, inserted_local_user.id)
+      .await
+      .unwrap();
+
+    let new_person = PersonInsertForm::builder()
+      .name("warned_person".into())
+      .public_key("pubkey".to_string())
+      .instance_id(inserted_instance.id)
+      .build();
+    let inserted_person = Person::create(pool, &new_person).await.unwrap();
+
+    let new_community = CommunityInsertForm::builder()
+      .name("warning_community".to_string())
+      .title("nada".to_owned())
+      .public_key("pubkey".to_string())
+      .instance_id(inserted_instance.id)
+      .warning_ban_threshold(Some(2))
+      .warning_ban_window_days(Some(7))
+      .warning_ban_days(Some(3))
+      .build();
+    let inserted_community = Community::create(pool, &new_community).await.unwrap();
+
+    let new_post = PostInsertForm::builder()
+      .name("A warned post".into())
+      .creator_id(inserted_person.id)
+      .community_id(inserted_community.id)
+      .build();
+    let inserted_post = Post::create(pool, &new_post).await.unwrap();
+
+    let warning_form = ModWarningForm {
+      mod_person_id: inserted_mod.id,
+      other_person_id: inserted_person.id,
+      community_id: inserted_community.id,
+      post_id: inserted_post.id,
+      comment_id: None,
+      reason: "Stay on topic".to_string(),
+    };
+
+    // A single warning stays below the threshold
+    ModWarning::create(pool, &warning_form).await.unwrap();
+    let banned = ban_after_warnings(&inserted_community, &inserted_person, &mod_view, &context)
+      .await
+      .unwrap();
+    assert!(!banned);
+    let ban = CommunityPersonBan::read(pool, inserted_person.id, inserted_community.id)
+      .await
+      .unwrap();
+    assert!(ban.is_none());
+
+    // The second warning within the window reaches it
+    ModWarning::create(pool, &warning_form).await.unwrap();
+    let before = Utc::now();
+    let banned = ban_after_warnings(&inserted_community, &inserted_person, &mod_view, &context)
+      .await
+      .unwrap();
+    let after = Utc::now();
+    assert!(banned);
+    let ban = CommunityPersonBan::read(pool, inserted_person.id, inserted_community.id)
+      .await
+      .unwrap()
+      .unwrap();
+    let expires = ban.expires.unwrap();
+    assert!(expires >= before + Duration::days(3));
+    assert!(expires <= after + Duration::days(3));
+
+    // Persons who are already banned aren't banned again
+    let banned = ban_after_warnings(&inserted_community, &inserted_person, &mod_view, &context)
+      .await
+      .unwrap();
+    assert!(!banned);
+
+    // Communities without a threshold never ban
+    let new_community_2 = CommunityInsertForm::builder()
+      .name("warning_community_2".to_string())
+      .title("nada".to_owned())
+      .public_key("pubkey".to_string())
+      .instance_id(inserted_instance.id)
+      .build();
+    let inserted_community_2 = Community::create(pool, &new_community_2).await.unwrap();
+    let banned = ban_after_warnings(&inserted_community_2, &inserted_person, &mod_view, &context)
+      .await
+      .unwrap();
+    assert!(!banned);
+
+    Person::delete(pool, inserted_mod.id).await.unwrap();
+    Person::delete(pool, inserted_person.id).await.unwrap();
+    Community::delete(pool, inserted_community.id)
+      .await
+      .unwrap();
+    Community::delete(pool, inserted_community_2.id)
+      .await
+      .unwrap();
+    Instance::delete(pool, inserted_instance.id).await.unwrap();
+  }
+}
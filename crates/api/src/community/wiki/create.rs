@@ -0,0 +1,78 @@
+use super::{create_wiki_page_revision, wiki_page_response};
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  community_wiki::{CommunityWikiPageResponse, CreateCommunityWikiPage},
+  context::LemmyContext,
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::{check_community_mod_action, generate_wiki_page_url, local_site_to_slur_regex},
+};
+use lemmy_db_schema::{
+  source::{
+    community::Community,
+    community_wiki::{CommunityWikiPage, CommunityWikiPageInsertForm},
+    local_site::LocalSite,
+  },
+  traits::Crud,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorExt, LemmyErrorType},
+  utils::{
+    slurs::check_slurs,
+    validation::{is_valid_body_field, is_valid_post_title, is_valid_wiki_page_slug},
+  },
+};
+
+#[tracing::instrument(skip(context))]
+pub async fn create_community_wiki_page(
+  data: Json<CreateCommunityWikiPage>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> Result<Json<CommunityWikiPageResponse>, LemmyError> {
+  let local_site = LocalSite::read(&mut context.pool()).await?;
+  let slur_regex = local_site_to_slur_regex(&local_site);
+  is_valid_wiki_page_slug(&data.slug)?;
+  is_valid_post_title(&data.title)?;
+  is_valid_body_field(&Some(data.body.clone()), true)?;
+  check_slurs(&data.title, &slur_regex)?;
+  check_slurs(&data.body, &slur_regex)?;
+
+  let community = Community::read(&mut context.pool(), data.community_id).await?;
+  check_community_mod_action(
+    &local_user_view.person,
+    community.id,
+    false,
+    &mut context.pool(),
+  )
+  .await?;
+  // Wikis of remote communities are edited on their home instance, and received via federation
+  if !community.local {
+    Err(LemmyErrorType::ObjectNotLocal)?
+  }
+
+  let form = CommunityWikiPageInsertForm::builder()
+    .community_id(community.id)
+    .slug(data.slug.clone())
+    .title(data.title.clone())
+    .body(data.body.clone())
+    .ap_id(Some(generate_wiki_page_url(
+      &community.actor_id,
+      &data.slug,
+    )?))
+    .members_can_edit(data.members_can_edit)
+    .build();
+  let wiki_page = CommunityWikiPage::create(&mut context.pool(), &form)
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntCreateWikiPage)?;
+  create_wiki_page_revision(&wiki_page, &local_user_view.person, &mut context.pool()).await?;
+
+  // The wiki is part of the community object, so other instances fetch it again after an update
+  ActivityChannel::submit_activity(
+    SendActivityData::UpdateCommunity(local_user_view.person.clone(), community),
+    &context,
+  )
+  .await?;
+
+  Ok(wiki_page_response(wiki_page))
+}
@@ -0,0 +1,57 @@
+use super::wiki_page_response;
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  community_wiki::{CommunityWikiPageResponse, DeleteCommunityWikiPage},
+  context::LemmyContext,
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::check_community_mod_action,
+};
+use lemmy_db_schema::{
+  source::{
+    community::Community,
+    community_wiki::{CommunityWikiPage, CommunityWikiPageUpdateForm},
+  },
+  traits::Crud,
+  utils::naive_now,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyError, LemmyErrorExt, LemmyErrorType};
+
+#[tracing::instrument(skip(context))]
+pub async fn delete_community_wiki_page(
+  data: Json<DeleteCommunityWikiPage>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> Result<Json<CommunityWikiPageResponse>, LemmyError> {
+  let orig_wiki_page = CommunityWikiPage::read(&mut context.pool(), data.wiki_page_id).await?;
+  let community = Community::read(&mut context.pool(), orig_wiki_page.community_id).await?;
+  check_community_mod_action(
+    &local_user_view.person,
+    community.id,
+    false,
+    &mut context.pool(),
+  )
+  .await?;
+  if !community.local {
+    Err(LemmyErrorType::ObjectNotLocal)?
+  }
+
+  // Deleted pages are kept with their revisions, so that they can be restored
+  let form = CommunityWikiPageUpdateForm {
+    deleted: Some(data.deleted),
+    updated: Some(Some(naive_now())),
+    ..Default::default()
+  };
+  let wiki_page = CommunityWikiPage::update(&mut context.pool(), orig_wiki_page.id, &form)
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntUpdateWikiPage)?;
+
+  ActivityChannel::submit_activity(
+    SendActivityData::UpdateCommunity(local_user_view.person.clone(), community),
+    &context,
+  )
+  .await?;
+
+  Ok(wiki_page_response(wiki_page))
+}
@@ -0,0 +1,34 @@
+use actix_web::web::{Data, Json, Query};
+use lemmy_api_common::{
+  community_wiki::{ListCommunityWikiPages, ListCommunityWikiPagesResponse},
+  context::LemmyContext,
+  utils::{check_community_content_visible, check_private_instance},
+};
+use lemmy_db_schema::{
+  source::{community::Community, community_wiki::CommunityWikiPage, local_site::LocalSite},
+  traits::Crud,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::LemmyError;
+
+#[tracing::instrument(skip(context))]
+pub async fn list_community_wiki_pages(
+  data: Query<ListCommunityWikiPages>,
+  context: Data<LemmyContext>,
+  local_user_view: Option<LocalUserView>,
+) -> Result<Json<ListCommunityWikiPagesResponse>, LemmyError> {
+  let local_site = LocalSite::read(&mut context.pool()).await?;
+  check_private_instance(&local_user_view, &local_site)?;
+
+  let community = Community::read(&mut context.pool(), data.community_id).await?;
+  check_community_content_visible(
+    local_user_view.as_ref().map(|l| &l.person),
+    &community,
+    &mut context.pool(),
+  )
+  .await?;
+
+  let wiki_pages = CommunityWikiPage::list_for_community(&mut context.pool(), community.id).await?;
+
+  Ok(Json(ListCommunityWikiPagesResponse { wiki_pages }))
+}
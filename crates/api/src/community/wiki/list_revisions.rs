@@ -0,0 +1,65 @@
+use super::check_wiki_page_readable;
+use actix_web::web::{Data, Json, Query};
+use lemmy_api_common::{
+  community_wiki::{
+    CommunityWikiPageRevisionDiff,
+    ListCommunityWikiPageRevisions,
+    ListCommunityWikiPageRevisionsResponse,
+  },
+  context::LemmyContext,
+  utils::check_private_instance,
+};
+use lemmy_db_schema::{
+  source::{
+    community::Community,
+    community_wiki::{CommunityWikiPage, CommunityWikiPageRevision},
+    local_site::LocalSite,
+  },
+  traits::Crud,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::{error::LemmyError, utils::diff::line_diff};
+
+#[tracing::instrument(skip(context))]
+pub async fn list_community_wiki_page_revisions(
+  data: Query<ListCommunityWikiPageRevisions>,
+  context: Data<LemmyContext>,
+  local_user_view: Option<LocalUserView>,
+) -> Result<Json<ListCommunityWikiPageRevisionsResponse>, LemmyError> {
+  let local_site = LocalSite::read(&mut context.pool()).await?;
+  check_private_instance(&local_user_view, &local_site)?;
+
+  let wiki_page = CommunityWikiPage::read(&mut context.pool(), data.wiki_page_id).await?;
+  let community = Community::read(&mut context.pool(), wiki_page.community_id).await?;
+  check_wiki_page_readable(
+    &wiki_page,
+    &community,
+    local_user_view.as_ref(),
+    &mut context.pool(),
+  )
+  .await?;
+
+  let revisions =
+    CommunityWikiPageRevision::list_for_page(&mut context.pool(), wiki_page.id).await?;
+
+  // Each revision is compared with the one before it, the first one with an empty page
+  let previous_versions = std::iter::once((String::new(), String::new())).chain(
+    revisions
+      .iter()
+      .map(|(r, _)| (r.title.clone(), r.body.clone())),
+  );
+  let revisions = revisions
+    .into_iter()
+    .zip(previous_versions)
+    .map(
+      |((revision, editor), (previous_title, previous_body))| CommunityWikiPageRevisionDiff {
+        title_diff: line_diff(&previous_title, &revision.title),
+        body_diff: line_diff(&previous_body, &revision.body),
+        revision,
+        editor,
+      },
+    )
+    .collect();
+
+  Ok(Json(ListCommunityWikiPageRevisionsResponse { revisions }))
+}
@@ -0,0 +1,92 @@
+use actix_web::web::Json;
+use lemmy_api_common::{
+  community_wiki::CommunityWikiPageResponse,
+  utils::{check_community_content_visible, check_community_user_action, is_mod_or_admin_opt},
+};
+use lemmy_db_schema::{
+  source::{
+    community::{Community, CommunityFollower},
+    community_wiki::{CommunityWikiPage, CommunityWikiPageRevision, CommunityWikiPageRevisionForm},
+    person::Person,
+  },
+  utils::DbPool,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_db_views_actor::structs::CommunityView;
+use lemmy_utils::{
+  error::{LemmyErrorExt, LemmyErrorType, LemmyResult},
+  utils::markdown::markdown_to_html,
+};
+
+pub mod create;
+pub mod delete;
+pub mod list;
+pub mod list_revisions;
+pub mod read;
+pub mod revert;
+pub mod update;
+
+/// Checks that the user can read the wiki page. Deleted pages are only visible to mods.
+async fn check_wiki_page_readable(
+  wiki_page: &CommunityWikiPage,
+  community: &Community,
+  local_user_view: Option<&LocalUserView>,
+  pool: &mut DbPool<'_>,
+) -> LemmyResult<()> {
+  check_community_content_visible(local_user_view.map(|l| &l.person), community, pool).await?;
+  if wiki_page.deleted {
+    is_mod_or_admin_opt(pool, local_user_view, Some(community.id))
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntFindObject)?;
+  }
+  Ok(())
+}
+
+/// Checks that the person can edit the wiki page. Mods can edit all pages, approved followers
+/// only those which allow edits by members.
+async fn check_wiki_page_edit_allowed(
+  person: &Person,
+  wiki_page: &CommunityWikiPage,
+  community: &Community,
+  pool: &mut DbPool<'_>,
+) -> LemmyResult<()> {
+  // Wikis of remote communities are edited on their home instance, and received via federation
+  if !community.local {
+    Err(LemmyErrorType::ObjectNotLocal)?
+  }
+  if wiki_page.deleted {
+    Err(LemmyErrorType::Deleted)?
+  }
+  check_community_user_action(person, community.id, pool).await?;
+
+  let is_mod_or_admin = CommunityView::is_mod_or_admin(pool, person.id, community.id).await?;
+  let is_editing_member = wiki_page.members_can_edit
+    && CommunityFollower::is_approved_follower(pool, community.id, person.id).await?;
+  if !is_mod_or_admin && !is_editing_member {
+    Err(LemmyErrorType::NoWikiPageEditAllowed)?
+  }
+  Ok(())
+}
+
+/// Stores the current content of the wiki page as a new revision.
+async fn create_wiki_page_revision(
+  wiki_page: &CommunityWikiPage,
+  editor: &Person,
+  pool: &mut DbPool<'_>,
+) -> LemmyResult<()> {
+  let form = CommunityWikiPageRevisionForm {
+    wiki_page_id: wiki_page.id,
+    editor_id: editor.id,
+    title: wiki_page.title.clone(),
+    body: wiki_page.body.clone(),
+  };
+  CommunityWikiPageRevision::create(pool, &form).await?;
+  Ok(())
+}
+
+fn wiki_page_response(wiki_page: CommunityWikiPage) -> Json<CommunityWikiPageResponse> {
+  Json(CommunityWikiPageResponse {
+    body_html: markdown_to_html(&wiki_page.body),
+    wiki_page,
+  })
+}
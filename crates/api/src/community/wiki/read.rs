@@ -0,0 +1,37 @@
+use super::{check_wiki_page_readable, wiki_page_response};
+use actix_web::web::{Data, Json, Query};
+use lemmy_api_common::{
+  community_wiki::{CommunityWikiPageResponse, GetCommunityWikiPage},
+  context::LemmyContext,
+  utils::check_private_instance,
+};
+use lemmy_db_schema::{
+  source::{community::Community, community_wiki::CommunityWikiPage, local_site::LocalSite},
+  traits::Crud,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyError, LemmyErrorExt, LemmyErrorType};
+
+#[tracing::instrument(skip(context))]
+pub async fn get_community_wiki_page(
+  data: Query<GetCommunityWikiPage>,
+  context: Data<LemmyContext>,
+  local_user_view: Option<LocalUserView>,
+) -> Result<Json<CommunityWikiPageResponse>, LemmyError> {
+  let local_site = LocalSite::read(&mut context.pool()).await?;
+  check_private_instance(&local_user_view, &local_site)?;
+
+  let community = Community::read(&mut context.pool(), data.community_id).await?;
+  let wiki_page = CommunityWikiPage::read_from_slug(&mut context.pool(), community.id, &data.slug)
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntFindObject)?;
+  check_wiki_page_readable(
+    &wiki_page,
+    &community,
+    local_user_view.as_ref(),
+    &mut context.pool(),
+  )
+  .await?;
+
+  Ok(wiki_page_response(wiki_page))
+}
@@ -0,0 +1,62 @@
+use super::{check_wiki_page_edit_allowed, create_wiki_page_revision, wiki_page_response};
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  community_wiki::{CommunityWikiPageResponse, RevertCommunityWikiPage},
+  context::LemmyContext,
+  send_activity::{ActivityChannel, SendActivityData},
+};
+use lemmy_db_schema::{
+  source::{
+    community::Community,
+    community_wiki::{CommunityWikiPage, CommunityWikiPageRevision, CommunityWikiPageUpdateForm},
+  },
+  traits::Crud,
+  utils::naive_now,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyError, LemmyErrorExt, LemmyErrorType};
+
+#[tracing::instrument(skip(context))]
+pub async fn revert_community_wiki_page(
+  data: Json<RevertCommunityWikiPage>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> Result<Json<CommunityWikiPageResponse>, LemmyError> {
+  let revision = CommunityWikiPageRevision::read(&mut context.pool(), data.revision_id)
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntFindObject)?;
+  if revision.wiki_page_id != data.wiki_page_id {
+    Err(LemmyErrorType::CouldntFindObject)?
+  }
+
+  let orig_wiki_page = CommunityWikiPage::read(&mut context.pool(), data.wiki_page_id).await?;
+  let community = Community::read(&mut context.pool(), orig_wiki_page.community_id).await?;
+  check_wiki_page_edit_allowed(
+    &local_user_view.person,
+    &orig_wiki_page,
+    &community,
+    &mut context.pool(),
+  )
+  .await?;
+
+  let form = CommunityWikiPageUpdateForm {
+    title: Some(revision.title),
+    body: Some(revision.body),
+    updated: Some(Some(naive_now())),
+    ..Default::default()
+  };
+  let wiki_page = CommunityWikiPage::update(&mut context.pool(), orig_wiki_page.id, &form)
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntUpdateWikiPage)?;
+  // Reverting keeps the history, the restored version is added as the newest revision
+  create_wiki_page_revision(&wiki_page, &local_user_view.person, &mut context.pool()).await?;
+
+  ActivityChannel::submit_activity(
+    SendActivityData::UpdateCommunity(local_user_view.person.clone(), community),
+    &context,
+  )
+  .await?;
+
+  Ok(wiki_page_response(wiki_page))
+}
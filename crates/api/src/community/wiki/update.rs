@@ -0,0 +1,83 @@
+use super::{check_wiki_page_edit_allowed, create_wiki_page_revision, wiki_page_response};
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  community_wiki::{CommunityWikiPageResponse, EditCommunityWikiPage},
+  context::LemmyContext,
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::{check_community_mod_action, local_site_to_slur_regex},
+};
+use lemmy_db_schema::{
+  source::{
+    community::Community,
+    community_wiki::{CommunityWikiPage, CommunityWikiPageUpdateForm},
+    local_site::LocalSite,
+  },
+  traits::Crud,
+  utils::naive_now,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorExt, LemmyErrorType},
+  utils::{
+    slurs::check_slurs_opt,
+    validation::{is_valid_body_field, is_valid_post_title},
+  },
+};
+
+#[tracing::instrument(skip(context))]
+pub async fn update_community_wiki_page(
+  data: Json<EditCommunityWikiPage>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> Result<Json<CommunityWikiPageResponse>, LemmyError> {
+  let local_site = LocalSite::read(&mut context.pool()).await?;
+  let slur_regex = local_site_to_slur_regex(&local_site);
+  if let Some(title) = &data.title {
+    is_valid_post_title(title)?;
+  }
+  is_valid_body_field(&data.body, true)?;
+  check_slurs_opt(&data.title, &slur_regex)?;
+  check_slurs_opt(&data.body, &slur_regex)?;
+
+  let orig_wiki_page = CommunityWikiPage::read(&mut context.pool(), data.wiki_page_id).await?;
+  let community = Community::read(&mut context.pool(), orig_wiki_page.community_id).await?;
+  check_wiki_page_edit_allowed(
+    &local_user_view.person,
+    &orig_wiki_page,
+    &community,
+    &mut context.pool(),
+  )
+  .await?;
+  if data.members_can_edit.is_some() {
+    check_community_mod_action(
+      &local_user_view.person,
+      community.id,
+      false,
+      &mut context.pool(),
+    )
+    .await?;
+  }
+
+  let form = CommunityWikiPageUpdateForm {
+    title: data.title.clone(),
+    body: data.body.clone(),
+    members_can_edit: data.members_can_edit,
+    updated: Some(Some(naive_now())),
+    ..Default::default()
+  };
+  let wiki_page = CommunityWikiPage::update(&mut context.pool(), orig_wiki_page.id, &form)
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntUpdateWikiPage)?;
+  if wiki_page.title != orig_wiki_page.title || wiki_page.body != orig_wiki_page.body {
+    create_wiki_page_revision(&wiki_page, &local_user_view.person, &mut context.pool()).await?;
+  }
+
+  ActivityChannel::submit_activity(
+    SendActivityData::UpdateCommunity(local_user_view.person.clone(), community),
+    &context,
+  )
+  .await?;
+
+  Ok(wiki_page_response(wiki_page))
+}
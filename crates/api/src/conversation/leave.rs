@@ -0,0 +1,26 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_common::{
+  context::LemmyContext,
+  conversation::LeaveConversation,
+  utils::check_conversation_participant,
+  SuccessResponse,
+};
+use lemmy_db_schema::source::conversation::ConversationParticipant;
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyError, LemmyErrorExt, LemmyErrorType};
+
+#[tracing::instrument(skip(context))]
+pub async fn leave_conversation(
+  data: Json<LeaveConversation>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> Result<Json<SuccessResponse>, LemmyError> {
+  let person_id = local_user_view.person.id;
+  check_conversation_participant(data.conversation_id, person_id, &mut context.pool()).await?;
+
+  ConversationParticipant::leave(&mut context.pool(), data.conversation_id, person_id)
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntUpdatePrivateMessage)?;
+
+  Ok(Json(SuccessResponse::default()))
+}
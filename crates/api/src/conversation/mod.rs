@@ -0,0 +1,3 @@
+pub mod leave;
+pub mod mark_read;
+pub mod mute;
@@ -0,0 +1,32 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_common::{
+  context::LemmyContext,
+  conversation::{ConversationResponse, MuteConversation},
+  utils::check_conversation_participant,
+};
+use lemmy_db_schema::source::conversation::ConversationParticipant;
+use lemmy_db_views::structs::{ConversationView, LocalUserView};
+use lemmy_utils::error::{LemmyError, LemmyErrorExt, LemmyErrorType};
+
+#[tracing::instrument(skip(context))]
+pub async fn mute_conversation(
+  data: Json<MuteConversation>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> Result<Json<ConversationResponse>, LemmyError> {
+  let person_id = local_user_view.person.id;
+  check_conversation_participant(data.conversation_id, person_id, &mut context.pool()).await?;
+
+  ConversationParticipant::set_muted(
+    &mut context.pool(),
+    data.conversation_id,
+    person_id,
+    data.muted,
+  )
+  .await
+  .with_lemmy_type(LemmyErrorType::CouldntUpdatePrivateMessage)?;
+
+  let conversation_view =
+    ConversationView::read(&mut context.pool(), data.conversation_id, person_id).await?;
+  Ok(Json(ConversationResponse { conversation_view }))
+}
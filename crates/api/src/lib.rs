@@ -19,13 +19,18 @@ use totp_rs::{Secret, TOTP};
 pub mod comment;
 pub mod comment_report;
 pub mod community;
+pub mod conversation;
 pub mod local_user;
+pub mod person_note;
 pub mod post;
 pub mod post_report;
 pub mod private_message;
 pub mod private_message_report;
+pub mod push_subscription;
+pub mod report;
 pub mod site;
 pub mod sitemap;
+pub mod webhook;
 
 /// Converts the captcha to a base64 encoded wav audio file
 pub(crate) fn captcha_as_wav_base64(captcha: &Captcha) -> Result<String, LemmyError> {
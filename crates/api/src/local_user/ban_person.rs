@@ -4,7 +4,8 @@ use lemmy_api_common::{
   context::LemmyContext,
   person::{BanPerson, BanPersonResponse},
   send_activity::{ActivityChannel, SendActivityData},
-  utils::{check_expire_time, is_admin, remove_user_data},
+  utils::{check_expire_time, is_admin, remove_user_data, send_webhook_event},
+  webhook::PersonBannedEvent,
 };
 use lemmy_db_schema::{
   source::{
@@ -13,6 +14,7 @@ use lemmy_db_schema::{
     person::{Person, PersonUpdateForm},
   },
   traits::Crud,
+  WebhookEventType,
 };
 use lemmy_db_views::structs::LocalUserView;
 use lemmy_db_views_actor::structs::PersonView;
@@ -20,6 +22,7 @@ use lemmy_utils::{
   error::{LemmyError, LemmyErrorExt, LemmyErrorType},
   utils::validation::is_valid_body_field,
 };
+use std::future::ready;
 
 #[tracing::instrument(skip(context))]
 pub async fn ban_from_site(
@@ -71,6 +74,21 @@ pub async fn ban_from_site(
 
   let person_view = PersonView::read(&mut context.pool(), data.person_id).await?;
 
+  send_webhook_event(
+    WebhookEventType::PersonBanned,
+    None,
+    ready(Ok::<_, LemmyError>(PersonBannedEvent {
+      person: person_view.person.clone(),
+      moderator: local_user_view.person.clone(),
+      community: None,
+      banned: data.ban,
+      reason: data.reason.clone(),
+      expires: data.expires,
+    })),
+    &mut context.pool(),
+  )
+  .await;
+
   ActivityChannel::submit_activity(
     SendActivityData::BanFromSite(
       local_user_view.person,
@@ -0,0 +1,70 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  context::LemmyContext,
+  person::{FollowPerson, FollowPersonResponse},
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::check_person_block,
+};
+use lemmy_db_schema::{
+  source::person::{Person, PersonFollower, PersonFollowerForm},
+  traits::{Crud, Followable},
+  SubscribedType,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_db_views_actor::structs::PersonView;
+use lemmy_utils::error::{LemmyError, LemmyErrorExt, LemmyErrorType};
+
+#[tracing::instrument(skip(context))]
+pub async fn follow_person(
+  data: Json<FollowPerson>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> Result<Json<FollowPersonResponse>, LemmyError> {
+  let target_id = data.person_id;
+  let person_id = local_user_view.person.id;
+
+  if target_id == person_id {
+    Err(LemmyErrorType::CantFollowYourself)?
+  }
+
+  let target = Person::read(&mut context.pool(), target_id).await?;
+  if data.follow {
+    check_person_block(person_id, target_id, &mut context.pool()).await?;
+  }
+  // Mark follows of remote persons as pending, the actual federation activity is sent via
+  // `SendActivity` handler
+  let person_follower_form = PersonFollowerForm {
+    person_id: target_id,
+    follower_id: person_id,
+    pending: !target.local,
+  };
+
+  let subscribed = if data.follow {
+    PersonFollower::follow(&mut context.pool(), &person_follower_form)
+      .await
+      .with_lemmy_type(LemmyErrorType::PersonFollowerAlreadyExists)?;
+    if target.local {
+      SubscribedType::Subscribed
+    } else {
+      SubscribedType::Pending
+    }
+  } else {
+    PersonFollower::unfollow(&mut context.pool(), &person_follower_form)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUnfollowPerson)?;
+    SubscribedType::NotSubscribed
+  };
+
+  ActivityChannel::submit_activity(
+    SendActivityData::FollowPerson(target, local_user_view.person.clone(), data.follow),
+    &context,
+  )
+  .await?;
+
+  let person_view = PersonView::read(&mut context.pool(), target_id).await?;
+  Ok(Json(FollowPersonResponse {
+    person_view,
+    subscribed,
+  }))
+}
@@ -0,0 +1,49 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_common::{
+  context::LemmyContext,
+  person::{CreateKeywordFilter, KeywordFilterResponse},
+  utils::check_expire_time,
+};
+use lemmy_db_schema::source::local_user_keyword_filter::{
+  LocalUserKeywordFilter,
+  LocalUserKeywordFilterForm,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorExt, LemmyErrorType},
+  utils::validation::{is_valid_keyword_filter, is_valid_keyword_filter_regex},
+};
+
+#[tracing::instrument(skip(context))]
+pub async fn create_keyword_filter(
+  data: Json<CreateKeywordFilter>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> Result<Json<KeywordFilterResponse>, LemmyError> {
+  let keyword = data.keyword.trim().to_string();
+  is_valid_keyword_filter(&keyword)?;
+
+  let is_regex = data.is_regex.unwrap_or(false);
+  if is_regex {
+    is_valid_keyword_filter_regex(&keyword)?;
+    LocalUserKeywordFilter::check_regex(&mut context.pool(), &keyword)
+      .await
+      .with_lemmy_type(LemmyErrorType::InvalidRegex)?;
+  }
+
+  let form = LocalUserKeywordFilterForm {
+    local_user_id: local_user_view.local_user.id,
+    keyword,
+    is_regex,
+    filter_title: data.filter_title.unwrap_or(true),
+    filter_body: data.filter_body.unwrap_or(true),
+    filter_url: data.filter_url.unwrap_or(false),
+    expires: check_expire_time(data.expires)?,
+  };
+  let keyword_filter = LocalUserKeywordFilter::create_within_limit(&mut context.pool(), &form)
+    .await
+    .with_lemmy_type(LemmyErrorType::KeywordFilterAlreadyExists)?
+    .ok_or(LemmyErrorType::TooManyKeywordFilters)?;
+
+  Ok(Json(KeywordFilterResponse { keyword_filter }))
+}
@@ -0,0 +1,22 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_common::{context::LemmyContext, person::DeleteKeywordFilter, SuccessResponse};
+use lemmy_db_schema::source::local_user_keyword_filter::LocalUserKeywordFilter;
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyError, LemmyErrorType};
+
+#[tracing::instrument(skip(context))]
+pub async fn delete_keyword_filter(
+  data: Json<DeleteKeywordFilter>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> Result<Json<SuccessResponse>, LemmyError> {
+  // Only deletes the filter if it belongs to this user
+  let deleted =
+    LocalUserKeywordFilter::delete(&mut context.pool(), data.id, local_user_view.local_user.id)
+      .await?;
+  if deleted == 0 {
+    Err(LemmyErrorType::CouldntFindObject)?
+  }
+
+  Ok(Json(SuccessResponse::default()))
+}
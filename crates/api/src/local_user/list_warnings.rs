@@ -0,0 +1,44 @@
+use actix_web::web::{Data, Json, Query};
+use lemmy_api_common::{
+  context::LemmyContext,
+  person::{ListWarnings, ListWarningsResponse},
+};
+use lemmy_db_schema::{source::local_site::LocalSite, ModlogActionType};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_db_views_moderator::{
+  modlog_combined_view::ModlogCombinedQuery,
+  structs::{ModlogCursor, ModlogEntry},
+};
+use lemmy_utils::error::LemmyError;
+
+#[tracing::instrument(skip(context))]
+pub async fn list_warnings(
+  data: Query<ListWarnings>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> Result<Json<ListWarningsResponse>, LemmyError> {
+  let local_site = LocalSite::read(&mut context.pool()).await?;
+  let entries = ModlogCombinedQuery {
+    type_: Some(ModlogActionType::ModWarning),
+    other_person_id: Some(local_user_view.person.id),
+    hide_modlog_names: local_site.hide_modlog_mod_names,
+    page_after: data.page_cursor.clone(),
+    limit: data.limit,
+    ..Default::default()
+  }
+  .list(&mut context.pool())
+  .await?;
+
+  let next_page = entries.last().map(ModlogCursor::after_entry);
+  let warnings = entries
+    .into_iter()
+    .filter_map(|e| match e.entry {
+      ModlogEntry::ModWarning(v) => Some(v),
+      _ => None,
+    })
+    .collect();
+  Ok(Json(ListWarningsResponse {
+    warnings,
+    next_page,
+  }))
+}
@@ -3,16 +3,21 @@ pub mod ban_person;
 pub mod block;
 pub mod change_password;
 pub mod change_password_after_reset;
+pub mod follow;
 pub mod generate_totp_secret;
 pub mod get_captcha;
+pub mod keyword_filter;
 pub mod list_banned;
 pub mod list_logins;
+pub mod list_warnings;
 pub mod login;
 pub mod logout;
+pub mod moderation_history;
 pub mod notifications;
 pub mod report_count;
 pub mod reset_password;
 pub mod save_settings;
+pub mod unsubscribe_email_digest;
 pub mod update_totp;
 pub mod validate_auth;
 pub mod verify_email;
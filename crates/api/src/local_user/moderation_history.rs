@@ -0,0 +1,120 @@
+use actix_web::web::{Data, Json, Query};
+use chrono::{DateTime, Utc};
+use lemmy_api_common::{
+  context::LemmyContext,
+  person::{GetModerationHistory, GetModerationHistoryResponse, ModerationHistoryItem},
+  utils::check_community_mod_action_opt,
+};
+use lemmy_db_schema::{utils::limit_and_offset, ModlogActionType};
+use lemmy_db_views::{
+  comment_report_view::CommentReportQuery,
+  post_report_view::PostReportQuery,
+  structs::LocalUserView,
+};
+use lemmy_db_views_moderator::{
+  modlog_combined_view::ModlogCombinedQuery,
+  structs::{ModlogEntry, PersonNoteView},
+};
+use lemmy_utils::error::LemmyError;
+
+/// Collects the notes about a person, the mod actions against them and the resolved reports
+/// about their content, so that mods can see at a glance how often someone was in trouble.
+#[tracing::instrument(skip(context))]
+pub async fn get_moderation_history(
+  data: Query<GetModerationHistory>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> Result<Json<GetModerationHistoryResponse>, LemmyError> {
+  let person_id = data.person_id;
+  let community_id = data.community_id;
+  check_community_mod_action_opt(&local_user_view, community_id, &mut context.pool()).await?;
+  let (limit, _) = limit_and_offset(None, data.limit)?;
+
+  let notes = PersonNoteView::list(&mut context.pool(), person_id, community_id).await?;
+
+  let mut history = vec![];
+  for type_ in [
+    ModlogActionType::ModRemovePost,
+    ModlogActionType::ModRemoveComment,
+    ModlogActionType::ModBanFromCommunity,
+    ModlogActionType::ModBan,
+    ModlogActionType::ModWarning,
+  ] {
+    // Site bans aren't tied to a community, but are relevant for the mods of every community
+    let community_id = if type_ == ModlogActionType::ModBan {
+      None
+    } else {
+      community_id
+    };
+    let entries = ModlogCombinedQuery {
+      type_: Some(type_),
+      community_id,
+      other_person_id: Some(person_id),
+      limit: Some(limit),
+      ..Default::default()
+    }
+    .list(&mut context.pool())
+    .await?;
+    history.extend(entries.into_iter().filter_map(|e| match e.entry {
+      ModlogEntry::ModRemovePost(v) => Some(ModerationHistoryItem::ModRemovePost(v)),
+      ModlogEntry::ModRemoveComment(v) => Some(ModerationHistoryItem::ModRemoveComment(v)),
+      ModlogEntry::ModBanFromCommunity(v) => Some(ModerationHistoryItem::ModBanFromCommunity(v)),
+      ModlogEntry::ModBan(v) => Some(ModerationHistoryItem::ModBan(v)),
+      ModlogEntry::ModWarning(v) => Some(ModerationHistoryItem::ModWarning(v)),
+      _ => None,
+    }));
+  }
+
+  let post_reports = PostReportQuery {
+    community_id,
+    reported_person_id: Some(person_id),
+    resolved_only: true,
+    limit: Some(limit),
+    ..Default::default()
+  }
+  .list(&mut context.pool(), &local_user_view)
+  .await?;
+  history.extend(
+    post_reports
+      .into_iter()
+      .map(ModerationHistoryItem::PostReport),
+  );
+
+  let comment_reports = CommentReportQuery {
+    community_id,
+    reported_person_id: Some(person_id),
+    resolved_only: true,
+    limit: Some(limit),
+    ..Default::default()
+  }
+  .list(&mut context.pool(), &local_user_view)
+  .await?;
+  history.extend(
+    comment_reports
+      .into_iter()
+      .map(ModerationHistoryItem::CommentReport),
+  );
+
+  history.sort_by(|a, b| history_item_time(b).cmp(&history_item_time(a)));
+  history.truncate(usize::try_from(limit)?);
+
+  Ok(Json(GetModerationHistoryResponse { notes, history }))
+}
+
+/// When the action was taken, or the report resolved.
+fn history_item_time(item: &ModerationHistoryItem) -> DateTime<Utc> {
+  match item {
+    ModerationHistoryItem::ModRemovePost(v) => v.mod_remove_post.when_,
+    ModerationHistoryItem::ModRemoveComment(v) => v.mod_remove_comment.when_,
+    ModerationHistoryItem::ModBanFromCommunity(v) => v.mod_ban_from_community.when_,
+    ModerationHistoryItem::ModBan(v) => v.mod_ban.when_,
+    ModerationHistoryItem::ModWarning(v) => v.mod_warning.when_,
+    ModerationHistoryItem::PostReport(v) => {
+      v.post_report.updated.unwrap_or(v.post_report.published)
+    }
+    ModerationHistoryItem::CommentReport(v) => v
+      .comment_report
+      .updated
+      .unwrap_or(v.comment_report.published),
+  }
+}
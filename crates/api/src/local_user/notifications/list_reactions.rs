@@ -0,0 +1,26 @@
+use actix_web::web::{Data, Json, Query};
+use lemmy_api_common::{
+  context::LemmyContext,
+  reaction::{GetReactions, ListReactionsResponse},
+};
+use lemmy_db_views::{reaction_view::ReactionQuery, structs::LocalUserView};
+use lemmy_utils::error::LemmyError;
+
+#[tracing::instrument(skip(context))]
+pub async fn list_received_reactions(
+  data: Query<GetReactions>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> Result<Json<ListReactionsResponse>, LemmyError> {
+  let reactions = ReactionQuery {
+    recipient_id: Some(local_user_view.person.id),
+    unread_only: data.unread_only.unwrap_or_default(),
+    page: data.page,
+    limit: data.limit,
+    ..Default::default()
+  }
+  .list(&mut context.pool())
+  .await?;
+
+  Ok(Json(ListReactionsResponse { reactions }))
+}
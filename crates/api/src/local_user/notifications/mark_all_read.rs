@@ -2,8 +2,10 @@ use actix_web::web::{Data, Json};
 use lemmy_api_common::{context::LemmyContext, person::GetRepliesResponse};
 use lemmy_db_schema::source::{
   comment_reply::CommentReply,
+  conversation::ConversationParticipant,
   person_mention::PersonMention,
   private_message::PrivateMessage,
+  reaction::Reaction,
 };
 use lemmy_db_views::structs::LocalUserView;
 use lemmy_utils::error::{LemmyError, LemmyErrorExt, LemmyErrorType};
@@ -29,6 +31,14 @@ pub async fn mark_all_notifications_read(
   PrivateMessage::mark_all_as_read(&mut context.pool(), person_id)
     .await
     .with_lemmy_type(LemmyErrorType::CouldntUpdatePrivateMessage)?;
+  ConversationParticipant::mark_all_as_read(&mut context.pool(), person_id)
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntUpdatePrivateMessage)?;
+
+  // Mark all reactions to the user's posts and comments as read
+  Reaction::mark_all_as_read(&mut context.pool(), person_id)
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntUpdateComment)?;
 
   Ok(Json(GetRepliesResponse { replies: vec![] }))
 }
@@ -0,0 +1,44 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_common::{
+  context::LemmyContext,
+  reaction::{MarkReactionAsRead, ReactionResponse},
+};
+use lemmy_db_schema::{
+  source::reaction::{Reaction, ReactionUpdateForm},
+  traits::Crud,
+};
+use lemmy_db_views::structs::{LocalUserView, ReactionView};
+use lemmy_utils::error::{LemmyError, LemmyErrorExt, LemmyErrorType};
+
+#[tracing::instrument(skip(context))]
+pub async fn mark_reaction_as_read(
+  data: Json<MarkReactionAsRead>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> Result<Json<ReactionResponse>, LemmyError> {
+  let reaction_view = ReactionView::read(&mut context.pool(), data.reaction_id).await?;
+
+  // Only the author of the post or comment which was reacted to gets notified
+  let recipient_id = reaction_view
+    .comment
+    .as_ref()
+    .map(|c| c.creator_id)
+    .unwrap_or(reaction_view.post.creator_id);
+  if local_user_view.person.id != recipient_id {
+    Err(LemmyErrorType::CouldntUpdateComment)?
+  }
+
+  Reaction::update(
+    &mut context.pool(),
+    reaction_view.reaction.id,
+    &ReactionUpdateForm {
+      read: Some(data.read),
+    },
+  )
+  .await
+  .with_lemmy_type(LemmyErrorType::CouldntUpdateComment)?;
+
+  let reaction_view = ReactionView::read(&mut context.pool(), data.reaction_id).await?;
+
+  Ok(Json(ReactionResponse { reaction_view }))
+}
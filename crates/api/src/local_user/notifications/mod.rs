@@ -1,6 +1,8 @@
 pub mod list_mentions;
+pub mod list_reactions;
 pub mod list_replies;
 pub mod mark_all_read;
 pub mod mark_mention_read;
+pub mod mark_reaction_read;
 pub mod mark_reply_read;
 pub mod unread_count;
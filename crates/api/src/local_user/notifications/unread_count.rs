@@ -1,6 +1,6 @@
 use actix_web::web::{Data, Json};
 use lemmy_api_common::{context::LemmyContext, person::GetUnreadCountResponse};
-use lemmy_db_views::structs::{LocalUserView, PrivateMessageView};
+use lemmy_db_views::structs::{LocalUserView, PrivateMessageView, ReactionView};
 use lemmy_db_views_actor::structs::{CommentReplyView, PersonMentionView};
 use lemmy_utils::error::LemmyError;
 
@@ -18,9 +18,12 @@ pub async fn unread_count(
   let private_messages =
     PrivateMessageView::get_unread_messages(&mut context.pool(), person_id).await?;
 
+  let reactions = ReactionView::get_unread_count(&mut context.pool(), person_id).await?;
+
   Ok(Json(GetUnreadCountResponse {
     replies,
     mentions,
     private_messages,
+    reactions,
   }))
 }
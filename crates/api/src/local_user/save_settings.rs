@@ -17,7 +17,12 @@ use lemmy_db_schema::{
 use lemmy_db_views::structs::{LocalUserView, SiteView};
 use lemmy_utils::{
   error::{LemmyError, LemmyErrorExt, LemmyErrorType},
-  utils::validation::{is_valid_bio_field, is_valid_display_name, is_valid_matrix_id},
+  utils::validation::{
+    is_valid_bio_field,
+    is_valid_display_name,
+    is_valid_encryption_public_key,
+    is_valid_matrix_id,
+  },
 };
 
 #[tracing::instrument(skip(context))]
@@ -33,6 +38,7 @@ pub async fn save_user_settings(
   let bio = diesel_option_overwrite(data.bio.clone());
   let display_name = diesel_option_overwrite(data.display_name.clone());
   let matrix_user_id = diesel_option_overwrite(data.matrix_user_id.clone());
+  let encryption_public_key = diesel_option_overwrite(data.encryption_public_key.clone());
   let email_deref = data.email.as_deref().map(str::to_lowercase);
   let email = diesel_option_overwrite(email_deref.clone());
 
@@ -75,6 +81,10 @@ pub async fn save_user_settings(
     is_valid_matrix_id(matrix_user_id)?;
   }
 
+  if let Some(Some(encryption_public_key)) = &encryption_public_key {
+    is_valid_encryption_public_key(encryption_public_key)?;
+  }
+
   let local_user_id = local_user_view.local_user.id;
   let person_id = local_user_view.person.id;
   let default_listing_type = data.default_listing_type;
@@ -84,6 +94,7 @@ pub async fn save_user_settings(
     display_name,
     bio,
     matrix_user_id,
+    encryption_public_key,
     bot_account: data.bot_account,
     avatar,
     banner,
@@ -117,6 +128,7 @@ pub async fn save_user_settings(
     post_listing_mode: data.post_listing_mode,
     enable_keyboard_navigation: data.enable_keyboard_navigation,
     enable_animated_images: data.enable_animated_images,
+    email_digest: data.email_digest,
     ..Default::default()
   };
 
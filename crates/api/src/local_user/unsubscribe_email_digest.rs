@@ -0,0 +1,29 @@
+use actix_web::web::{Data, Json, Query};
+use lemmy_api_common::{
+  claims::UnsubscribeClaims,
+  context::LemmyContext,
+  person::UnsubscribeEmailDigest,
+  SuccessResponse,
+};
+use lemmy_db_schema::{
+  source::local_user::{LocalUser, LocalUserUpdateForm},
+  traits::Crud,
+  EmailDigestFrequency,
+};
+use lemmy_utils::error::LemmyResult;
+
+/// This is a GET request, so that the link in the digest works with a single click.
+pub async fn unsubscribe_email_digest(
+  data: Query<UnsubscribeEmailDigest>,
+  context: Data<LemmyContext>,
+) -> LemmyResult<Json<SuccessResponse>> {
+  let local_user_id = UnsubscribeClaims::validate(&data.token, &context)?;
+
+  let form = LocalUserUpdateForm {
+    email_digest: Some(EmailDigestFrequency::Never),
+    ..Default::default()
+  };
+  LocalUser::update(&mut context.pool(), local_user_id, &form).await?;
+
+  Ok(Json(SuccessResponse::default()))
+}
@@ -0,0 +1,40 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_common::{
+  context::LemmyContext,
+  person::{CreatePersonNote, PersonNoteResponse},
+  utils::check_community_mod_action_opt,
+};
+use lemmy_db_schema::{
+  source::person_note::{PersonNote, PersonNoteInsertForm},
+  traits::Crud,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_db_views_moderator::structs::PersonNoteView;
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorExt, LemmyErrorType},
+  utils::validation::is_valid_body_field,
+};
+
+#[tracing::instrument(skip(context))]
+pub async fn create_person_note(
+  data: Json<CreatePersonNote>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> Result<Json<PersonNoteResponse>, LemmyError> {
+  check_community_mod_action_opt(&local_user_view, data.community_id, &mut context.pool()).await?;
+  let content = data.content.trim().to_string();
+  is_valid_body_field(&Some(content.clone()), false)?;
+
+  let form = PersonNoteInsertForm {
+    person_id: data.person_id,
+    creator_id: local_user_view.person.id,
+    community_id: data.community_id,
+    content,
+  };
+  let person_note = PersonNote::create(&mut context.pool(), &form)
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntCreatePersonNote)?;
+
+  let person_note_view = PersonNoteView::read(&mut context.pool(), person_note.id).await?;
+  Ok(Json(PersonNoteResponse { person_note_view }))
+}
@@ -0,0 +1,29 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_common::{
+  context::LemmyContext,
+  person::DeletePersonNote,
+  utils::check_community_mod_action_opt,
+  SuccessResponse,
+};
+use lemmy_db_schema::{source::person_note::PersonNote, traits::Crud};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::LemmyError;
+
+#[tracing::instrument(skip(context))]
+pub async fn delete_person_note(
+  data: Json<DeletePersonNote>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> Result<Json<SuccessResponse>, LemmyError> {
+  let orig_note = PersonNote::read(&mut context.pool(), data.person_note_id).await?;
+  check_community_mod_action_opt(
+    &local_user_view,
+    orig_note.community_id,
+    &mut context.pool(),
+  )
+  .await?;
+
+  PersonNote::delete(&mut context.pool(), orig_note.id).await?;
+
+  Ok(Json(SuccessResponse::default()))
+}
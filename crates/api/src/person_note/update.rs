@@ -0,0 +1,46 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_common::{
+  context::LemmyContext,
+  person::{EditPersonNote, PersonNoteResponse},
+  utils::check_community_mod_action_opt,
+};
+use lemmy_db_schema::{
+  source::person_note::{PersonNote, PersonNoteUpdateForm},
+  traits::Crud,
+  utils::naive_now,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_db_views_moderator::structs::PersonNoteView;
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorExt, LemmyErrorType},
+  utils::validation::is_valid_body_field,
+};
+
+/// Notes are shared by the moderation team, so any mod who can read a note can also edit it.
+#[tracing::instrument(skip(context))]
+pub async fn update_person_note(
+  data: Json<EditPersonNote>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> Result<Json<PersonNoteResponse>, LemmyError> {
+  let orig_note = PersonNote::read(&mut context.pool(), data.person_note_id).await?;
+  check_community_mod_action_opt(
+    &local_user_view,
+    orig_note.community_id,
+    &mut context.pool(),
+  )
+  .await?;
+  let content = data.content.trim().to_string();
+  is_valid_body_field(&Some(content.clone()), false)?;
+
+  let form = PersonNoteUpdateForm {
+    content: Some(content),
+    updated: Some(Some(naive_now())),
+  };
+  PersonNote::update(&mut context.pool(), orig_note.id, &form)
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntUpdatePersonNote)?;
+
+  let person_note_view = PersonNoteView::read(&mut context.pool(), orig_note.id).await?;
+  Ok(Json(PersonNoteResponse { person_note_view }))
+}
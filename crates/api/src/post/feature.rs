@@ -5,7 +5,7 @@ use lemmy_api_common::{
   context::LemmyContext,
   post::{FeaturePost, PostResponse},
   send_activity::{ActivityChannel, SendActivityData},
-  utils::{check_community_mod_action, is_admin},
+  utils::{check_community_mod_action, check_post_published, is_admin},
 };
 use lemmy_db_schema::{
   source::{
@@ -26,6 +26,7 @@ pub async fn feature_post(
 ) -> Result<Json<PostResponse>, LemmyError> {
   let post_id = data.post_id;
   let orig_post = Post::read(&mut context.pool(), post_id).await?;
+  check_post_published(&orig_post, local_user_view.person.id)?;
 
   check_community_mod_action(
     &local_user_view.person,
@@ -64,11 +65,14 @@ pub async fn feature_post(
 
   ModFeaturePost::create(&mut context.pool(), &form).await?;
 
-  ActivityChannel::submit_activity(
-    SendActivityData::FeaturePost(post, local_user_view.person.clone(), data.featured),
-    &context,
-  )
-  .await?;
+  // Scheduled posts which are featured get announced as featured once they are published
+  if post.scheduled_publish_time.is_none() {
+    ActivityChannel::submit_activity(
+      SendActivityData::FeaturePost(post, local_user_view.person.clone(), data.featured),
+      &context,
+    )
+    .await?;
+  }
 
   build_post_response(
     &context,
@@ -5,7 +5,13 @@ use lemmy_api_common::{
   context::LemmyContext,
   post::{CreatePostLike, PostResponse},
   send_activity::{ActivityChannel, SendActivityData},
-  utils::{check_community_user_action, check_downvotes_enabled, mark_post_as_read},
+  utils::{
+    check_community_content_visible,
+    check_community_user_action,
+    check_downvotes_enabled,
+    check_post_published,
+    mark_post_as_read,
+  },
 };
 use lemmy_db_schema::{
   source::{
@@ -33,6 +39,7 @@ pub async fn like_post(
   // Check for a community ban
   let post_id = data.post_id;
   let post = Post::read(&mut context.pool(), post_id).await?;
+  check_post_published(&post, local_user_view.person.id)?;
 
   check_community_user_action(
     &local_user_view.person,
@@ -40,6 +47,13 @@ pub async fn like_post(
     &mut context.pool(),
   )
   .await?;
+  let community = Community::read(&mut context.pool(), post.community_id).await?;
+  check_community_content_visible(
+    Some(&local_user_view.person),
+    &community,
+    &mut context.pool(),
+  )
+  .await?;
 
   let like_form = PostLikeForm {
     post_id: data.post_id,
@@ -63,16 +77,20 @@ pub async fn like_post(
   // Mark the post as read
   mark_post_as_read(person_id, post_id, &mut context.pool()).await?;
 
-  ActivityChannel::submit_activity(
-    SendActivityData::LikePostOrComment(
-      post.ap_id,
-      local_user_view.person.clone(),
-      Community::read(&mut context.pool(), post.community_id).await?,
-      data.score,
-    ),
-    &context,
-  )
-  .await?;
+  // Other instances only learn about scheduled posts once they get published, so votes of the
+  // creator before that stay local
+  if post.scheduled_publish_time.is_none() {
+    ActivityChannel::submit_activity(
+      SendActivityData::LikePostOrComment(
+        post.ap_id,
+        local_user_view.person.clone(),
+        community,
+        data.score,
+      ),
+      &context,
+    )
+    .await?;
+  }
 
   build_post_response(
     context.deref(),
@@ -0,0 +1,52 @@
+use actix_web::web::{Data, Json, Query};
+use lemmy_api_common::{
+  context::LemmyContext,
+  reaction::{ListReactions, ListReactionsResponse},
+  utils::{check_community_content_visible, check_private_instance},
+};
+use lemmy_db_schema::{
+  source::{comment::Comment, community::Community, local_site::LocalSite, post::Post},
+  traits::Crud,
+};
+use lemmy_db_views::{reaction_view::ReactionQuery, structs::LocalUserView};
+use lemmy_utils::error::{LemmyError, LemmyErrorType};
+
+#[tracing::instrument(skip(context))]
+pub async fn list_reactions(
+  data: Query<ListReactions>,
+  context: Data<LemmyContext>,
+  local_user_view: Option<LocalUserView>,
+) -> Result<Json<ListReactionsResponse>, LemmyError> {
+  let local_site = LocalSite::read(&mut context.pool()).await?;
+  check_private_instance(&local_user_view, &local_site)?;
+
+  let post_id = match (data.post_id, data.comment_id) {
+    (_, Some(comment_id)) => {
+      Comment::read(&mut context.pool(), comment_id)
+        .await?
+        .post_id
+    }
+    (Some(post_id), None) => post_id,
+    (None, None) => Err(LemmyErrorType::NoIdGiven)?,
+  };
+  let post = Post::read(&mut context.pool(), post_id).await?;
+  let community = Community::read(&mut context.pool(), post.community_id).await?;
+  check_community_content_visible(
+    local_user_view.as_ref().map(|u| &u.person),
+    &community,
+    &mut context.pool(),
+  )
+  .await?;
+
+  let reactions = ReactionQuery {
+    post_id: Some(post_id),
+    comment_id: data.comment_id,
+    page: data.page,
+    limit: data.limit,
+    ..Default::default()
+  }
+  .list(&mut context.pool())
+  .await?;
+
+  Ok(Json(ListReactionsResponse { reactions }))
+}
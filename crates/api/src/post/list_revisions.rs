@@ -0,0 +1,72 @@
+use actix_web::web::{Data, Json, Query};
+use lemmy_api_common::{
+  context::LemmyContext,
+  post::{ListPostRevisions, ListPostRevisionsResponse, PostRevisionDiff},
+  utils::{check_community_content_visible, check_private_instance, is_mod_or_admin_opt},
+};
+use lemmy_db_schema::source::{
+  local_site::LocalSite,
+  post::{Post, PostRevision},
+};
+use lemmy_db_views::structs::{LocalUserView, PostView};
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorExt, LemmyErrorType},
+  utils::diff::line_diff,
+};
+
+#[tracing::instrument(skip(context))]
+pub async fn list_post_revisions(
+  data: Query<ListPostRevisions>,
+  context: Data<LemmyContext>,
+  local_user_view: Option<LocalUserView>,
+) -> Result<Json<ListPostRevisionsResponse>, LemmyError> {
+  let local_site = LocalSite::read(&mut context.pool()).await?;
+  check_private_instance(&local_user_view, &local_site)?;
+
+  let post_id = data.post_id;
+  let person_id = local_user_view.as_ref().map(|u| u.person.id);
+  let community_id = Post::read(&mut context.pool(), post_id).await?.community_id;
+  let is_mod_or_admin = is_mod_or_admin_opt(
+    &mut context.pool(),
+    local_user_view.as_ref(),
+    Some(community_id),
+  )
+  .await
+  .is_ok();
+
+  // Reading the post view makes sure that deleted or removed posts are only visible to mods
+  let post_view = PostView::read(&mut context.pool(), post_id, person_id, is_mod_or_admin)
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntFindPost)?;
+  check_community_content_visible(
+    local_user_view.as_ref().map(|u| &u.person),
+    &post_view.community,
+    &mut context.pool(),
+  )
+  .await?;
+  let post = post_view.post;
+
+  let revisions = PostRevision::list_for_post(&mut context.pool(), post_id).await?;
+
+  // Each revision is compared with the one after it, the last one with the current post
+  let next_versions = revisions
+    .iter()
+    .skip(1)
+    .map(|r| (r.name.clone(), r.body.clone()))
+    .chain(std::iter::once((post.name, post.body)));
+  let revisions = revisions
+    .iter()
+    .cloned()
+    .zip(next_versions)
+    .map(|(revision, (next_name, next_body))| PostRevisionDiff {
+      name_diff: line_diff(&revision.name, &next_name),
+      body_diff: line_diff(
+        revision.body.as_deref().unwrap_or_default(),
+        next_body.as_deref().unwrap_or_default(),
+      ),
+      revision,
+    })
+    .collect();
+
+  Ok(Json(ListPostRevisionsResponse { revisions }))
+}
@@ -5,7 +5,7 @@ use lemmy_api_common::{
   context::LemmyContext,
   post::{LockPost, PostResponse},
   send_activity::{ActivityChannel, SendActivityData},
-  utils::check_community_mod_action,
+  utils::{check_community_mod_action, check_post_published},
 };
 use lemmy_db_schema::{
   source::{
@@ -25,6 +25,7 @@ pub async fn lock_post(
 ) -> Result<Json<PostResponse>, LemmyError> {
   let post_id = data.post_id;
   let orig_post = Post::read(&mut context.pool(), post_id).await?;
+  check_post_published(&orig_post, local_user_view.person.id)?;
 
   check_community_mod_action(
     &local_user_view.person,
@@ -55,11 +56,14 @@ pub async fn lock_post(
   };
   ModLockPost::create(&mut context.pool(), &form).await?;
 
-  ActivityChannel::submit_activity(
-    SendActivityData::LockPost(post, local_user_view.person.clone(), data.locked),
-    &context,
-  )
-  .await?;
+  // The lock of a scheduled post is federated as part of the post once it gets published
+  if post.scheduled_publish_time.is_none() {
+    ActivityChannel::submit_activity(
+      SendActivityData::LockPost(post, local_user_view.person.clone(), data.locked),
+      &context,
+    )
+    .await?;
+  }
 
   build_post_response(
     &context,
@@ -1,6 +1,11 @@
 use actix_web::web::{Data, Json};
-use lemmy_api_common::{context::LemmyContext, post::MarkPostAsRead, SuccessResponse};
-use lemmy_db_schema::source::post::PostRead;
+use lemmy_api_common::{
+  context::LemmyContext,
+  post::MarkPostAsRead,
+  utils::{check_community_content_visible, check_post_published},
+  SuccessResponse,
+};
+use lemmy_db_schema::source::post::{Post, PostRead};
 use lemmy_db_views::structs::LocalUserView;
 use lemmy_utils::error::{LemmyError, LemmyErrorExt, LemmyErrorType, MAX_API_PARAM_ELEMENTS};
 use std::collections::HashSet;
@@ -19,6 +24,18 @@ pub async fn mark_post_as_read(
     Err(LemmyErrorType::TooManyItems)?;
   }
 
+  for post in Post::read_many(&mut context.pool(), &post_ids).await? {
+    check_post_published(&post, person_id)?;
+  }
+  for community in Post::list_communities(&mut context.pool(), &post_ids).await? {
+    check_community_content_visible(
+      Some(&local_user_view.person),
+      &community,
+      &mut context.pool(),
+    )
+    .await?;
+  }
+
   // Mark the post as read / unread
   if data.read {
     PostRead::mark_as_read(&mut context.pool(), post_ids, person_id)
@@ -1,6 +1,10 @@
 pub mod feature;
 pub mod get_link_metadata;
 pub mod like;
+pub mod list_reactions;
+pub mod list_revisions;
 pub mod lock;
 pub mod mark_read;
+pub mod react;
 pub mod save;
+pub mod vote_poll;
@@ -0,0 +1,94 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  build_response::build_post_response,
+  context::LemmyContext,
+  post::PostResponse,
+  reaction::ReactToPost,
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::{
+    check_community_content_visible,
+    check_community_user_action,
+    check_emoji_reaction,
+    check_post_published,
+  },
+};
+use lemmy_db_schema::{
+  source::{
+    community::Community,
+    post::Post,
+    reaction::{Reaction, ReactionForm},
+  },
+  traits::Crud,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyError, LemmyErrorExt, LemmyErrorType};
+use std::ops::Deref;
+
+#[tracing::instrument(skip(context))]
+pub async fn react_to_post(
+  data: Json<ReactToPost>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> Result<Json<PostResponse>, LemmyError> {
+  let post_id = data.post_id;
+  let post = Post::read(&mut context.pool(), post_id).await?;
+  check_post_published(&post, local_user_view.person.id)?;
+
+  check_community_user_action(
+    &local_user_view.person,
+    post.community_id,
+    &mut context.pool(),
+  )
+  .await?;
+  let community = Community::read(&mut context.pool(), post.community_id).await?;
+  check_community_content_visible(
+    Some(&local_user_view.person),
+    &community,
+    &mut context.pool(),
+  )
+  .await?;
+  let custom_emoji =
+    check_emoji_reaction(&data.emoji, post.community_id, &mut context.pool()).await?;
+
+  let person_id = local_user_view.person.id;
+  if data.add {
+    let form = ReactionForm {
+      person_id,
+      post_id,
+      comment_id: None,
+      emoji: data.emoji.clone(),
+      custom_emoji_id: custom_emoji.as_ref().map(|e| e.id),
+    };
+    Reaction::react(&mut context.pool(), &form)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntLikePost)?;
+  } else {
+    Reaction::remove(&mut context.pool(), person_id, post_id, None, &data.emoji).await?;
+  }
+
+  // Other instances only learn about scheduled posts once they get published, so reactions of
+  // the creator before that stay local
+  if post.scheduled_publish_time.is_none() {
+    ActivityChannel::submit_activity(
+      SendActivityData::ReactPostOrComment(
+        post.ap_id,
+        local_user_view.person.clone(),
+        community,
+        data.emoji.clone(),
+        custom_emoji,
+        data.add,
+      ),
+      &context,
+    )
+    .await?;
+  }
+
+  build_post_response(
+    context.deref(),
+    post.community_id,
+    &local_user_view.person,
+    post_id,
+  )
+  .await
+}
@@ -2,11 +2,11 @@ use actix_web::web::{Data, Json};
 use lemmy_api_common::{
   context::LemmyContext,
   post::{PostResponse, SavePost},
-  utils::mark_post_as_read,
+  utils::{check_community_content_visible_by_id, check_post_published, mark_post_as_read},
 };
 use lemmy_db_schema::{
-  source::post::{PostSaved, PostSavedForm},
-  traits::Saveable,
+  source::post::{Post, PostSaved, PostSavedForm},
+  traits::{Crud, Saveable},
 };
 use lemmy_db_views::structs::{LocalUserView, PostView};
 use lemmy_utils::error::{LemmyError, LemmyErrorExt, LemmyErrorType};
@@ -17,6 +17,15 @@ pub async fn save_post(
   context: Data<LemmyContext>,
   local_user_view: LocalUserView,
 ) -> Result<Json<PostResponse>, LemmyError> {
+  let post = Post::read(&mut context.pool(), data.post_id).await?;
+  check_post_published(&post, local_user_view.person.id)?;
+  check_community_content_visible_by_id(
+    Some(&local_user_view.person),
+    post.community_id,
+    &mut context.pool(),
+  )
+  .await?;
+
   let post_saved_form = PostSavedForm {
     post_id: data.post_id,
     person_id: local_user_view.person.id,
@@ -0,0 +1,115 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use chrono::Utc;
+use lemmy_api_common::{
+  build_response::build_post_response,
+  context::LemmyContext,
+  post::{PostResponse, VotePoll},
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::{
+    check_community_content_visible_by_id,
+    check_community_user_action,
+    check_post_deleted_or_removed,
+    check_post_published,
+    mark_post_as_read,
+  },
+};
+use lemmy_db_schema::{
+  source::{
+    poll::{Poll, PollOption, PollVote},
+    post::Post,
+  },
+  traits::Crud,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyError, LemmyErrorExt, LemmyErrorType};
+use std::ops::Deref;
+
+#[tracing::instrument(skip(context))]
+pub async fn vote_poll(
+  data: Json<VotePoll>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> Result<Json<PostResponse>, LemmyError> {
+  let post_id = data.post_id;
+  let post = Post::read(&mut context.pool(), post_id).await?;
+  check_post_published(&post, local_user_view.person.id)?;
+
+  check_community_user_action(
+    &local_user_view.person,
+    post.community_id,
+    &mut context.pool(),
+  )
+  .await?;
+  check_community_content_visible_by_id(
+    Some(&local_user_view.person),
+    post.community_id,
+    &mut context.pool(),
+  )
+  .await?;
+
+  check_post_deleted_or_removed(&post)?;
+  if post.locked {
+    Err(LemmyErrorType::Locked)?
+  }
+
+  let poll = Poll::read_for_post(&mut context.pool(), post_id)
+    .await?
+    .ok_or(LemmyErrorType::InvalidPoll)?;
+  if poll.end_time.is_some_and(|end_time| end_time < Utc::now()) {
+    Err(LemmyErrorType::PollClosed)?
+  }
+
+  let mut option_ids = data.option_ids.clone();
+  option_ids.sort_by_key(|id| id.0);
+  option_ids.dedup();
+  if !poll.multiple_choice && option_ids.len() > 1 {
+    Err(LemmyErrorType::InvalidPollOption)?
+  }
+  let options = PollOption::list_for_post(&mut context.pool(), post_id).await?;
+  let chosen_options = option_ids
+    .iter()
+    .map(|id| {
+      options
+        .iter()
+        .find(|o| &o.id == id)
+        .ok_or(LemmyErrorType::InvalidPollOption)
+    })
+    .collect::<Result<Vec<_>, _>>()?;
+
+  // Votes in remote polls are sent to the poll creator. Other platforms have no way to retract a
+  // vote, so votes in remote polls can't be changed.
+  let person_id = local_user_view.person.id;
+  if !post.local && PollVote::has_voted(&mut context.pool(), post_id, person_id).await? {
+    Err(LemmyErrorType::CantChangeRemotePollVote)?
+  }
+
+  PollVote::vote(&mut context.pool(), post_id, person_id, option_ids)
+    .await
+    .with_lemmy_type(LemmyErrorType::InvalidPollOption)?;
+
+  // Mark the post as read
+  mark_post_as_read(person_id, post_id, &mut context.pool()).await?;
+
+  if !post.local {
+    if !chosen_options.is_empty() {
+      let option_names = chosen_options.iter().map(|o| o.name.clone()).collect();
+      ActivityChannel::submit_activity(
+        SendActivityData::VotePoll(post.clone(), local_user_view.person.clone(), option_names),
+        &context,
+      )
+      .await?;
+    }
+  } else if post.scheduled_publish_time.is_none() {
+    // Other instances get the new vote counts with an update of the poll
+    ActivityChannel::submit_activity(SendActivityData::UpdatePost(post.clone()), &context).await?;
+  }
+
+  build_post_response(
+    context.deref(),
+    post.community_id,
+    &local_user_view.person,
+    post_id,
+  )
+  .await
+}
@@ -5,7 +5,15 @@ use lemmy_api_common::{
   context::LemmyContext,
   post::{CreatePostReport, PostReportResponse},
   send_activity::{ActivityChannel, SendActivityData},
-  utils::{check_community_user_action, send_new_report_email_to_admins},
+  utils::{
+    check_community_content_visible,
+    check_community_user_action,
+    check_post_published,
+    generate_report_ap_id,
+    send_new_report_email_to_admins,
+    send_new_report_notifications,
+    send_webhook_event,
+  },
 };
 use lemmy_db_schema::{
   source::{
@@ -13,9 +21,11 @@ use lemmy_db_schema::{
     post_report::{PostReport, PostReportForm},
   },
   traits::Reportable,
+  WebhookEventType,
 };
 use lemmy_db_views::structs::{LocalUserView, PostReportView, PostView};
 use lemmy_utils::error::{LemmyError, LemmyErrorExt, LemmyErrorType};
+use std::future::ready;
 
 /// Creates a post report and notifies the moderators of the community
 #[tracing::instrument(skip(context))]
@@ -32,6 +42,7 @@ pub async fn create_post_report(
   let person_id = local_user_view.person.id;
   let post_id = data.post_id;
   let post_view = PostView::read(&mut context.pool(), post_id, None, false).await?;
+  check_post_published(&post_view.post, person_id)?;
 
   check_community_user_action(
     &local_user_view.person,
@@ -39,7 +50,14 @@ pub async fn create_post_report(
     &mut context.pool(),
   )
   .await?;
+  check_community_content_visible(
+    Some(&local_user_view.person),
+    &post_view.community,
+    &mut context.pool(),
+  )
+  .await?;
 
+  let ap_id = generate_report_ap_id(&context.settings().get_protocol_and_hostname())?;
   let report_form = PostReportForm {
     creator_id: person_id,
     post_id,
@@ -47,6 +65,7 @@ pub async fn create_post_report(
     original_post_url: post_view.post.url,
     original_post_body: post_view.post.body,
     reason,
+    ap_id: Some(ap_id.clone()),
   };
 
   let report = PostReport::report(&mut context.pool(), &report_form)
@@ -65,10 +84,26 @@ pub async fn create_post_report(
     )
     .await?;
   }
+  send_new_report_notifications(
+    Some(post_view.community.id),
+    &post_report_view.creator.name,
+    &post_report_view.post_creator.name,
+    &post_report_view.post_report.reason,
+    &context,
+  )
+  .await?;
+  send_webhook_event(
+    WebhookEventType::ReportCreated,
+    Some(post_view.community.id),
+    ready(Ok::<_, LemmyError>(&post_report_view)),
+    &mut context.pool(),
+  )
+  .await;
 
   ActivityChannel::submit_activity(
     SendActivityData::CreateReport(
       post_view.post.ap_id.inner().clone(),
+      ap_id.into(),
       local_user_view.person,
       post_view.community,
       data.reason.clone(),
@@ -27,6 +27,7 @@ pub async fn list_post_reports(
     unresolved_only,
     page,
     limit,
+    ..Default::default()
   }
   .list(&mut context.pool(), &local_user_view)
   .await?;
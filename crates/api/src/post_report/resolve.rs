@@ -1,14 +1,21 @@
-use actix_web::web::{Data, Json};
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
 use lemmy_api_common::{
   context::LemmyContext,
+  live_hub::LiveMessage,
   post::{PostReportResponse, ResolvePostReport},
-  utils::check_community_mod_action,
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::{check_community_mod_action, log_report_resolution},
 };
 use lemmy_db_schema::{source::post_report::PostReport, traits::Reportable};
 use lemmy_db_views::structs::{LocalUserView, PostReportView};
-use lemmy_utils::error::{LemmyError, LemmyErrorExt, LemmyErrorType};
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorExt, LemmyErrorType},
+  utils::validation::is_valid_body_field,
+};
 
-/// Resolves or unresolves a post report and notifies the moderators of the community
+/// Resolves or unresolves a post report, logs it in the modlog and notifies the moderators of
+/// the community
 #[tracing::instrument(skip(context))]
 pub async fn resolve_post_report(
   data: Json<ResolvePostReport>,
@@ -27,18 +34,48 @@ pub async fn resolve_post_report(
     &mut context.pool(),
   )
   .await?;
+  is_valid_body_field(&data.reason, false)?;
 
   if data.resolved {
-    PostReport::resolve(&mut context.pool(), report_id, person_id)
-      .await
-      .with_lemmy_type(LemmyErrorType::CouldntResolveReport)?;
+    PostReport::resolve(
+      &mut context.pool(),
+      report_id,
+      person_id,
+      data.reason.clone(),
+    )
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntResolveReport)?;
   } else {
     PostReport::unresolve(&mut context.pool(), report_id, person_id)
       .await
       .with_lemmy_type(LemmyErrorType::CouldntResolveReport)?;
   }
+  log_report_resolution(
+    person_id,
+    &report.post,
+    None,
+    1,
+    data.resolved,
+    data.reason.clone(),
+    &mut context.pool(),
+  )
+  .await?;
+
+  context.live().send(LiveMessage::Report {
+    community_id: Some(report.community.id),
+  });
 
   let post_report_view = PostReportView::read(&mut context.pool(), report_id, person_id).await?;
 
+  ActivityChannel::submit_activity(
+    SendActivityData::ResolvePostReport(
+      post_report_view.post_report.clone(),
+      local_user_view.person,
+      data.resolved,
+    ),
+    &context,
+  )
+  .await?;
+
   Ok(Json(PostReportResponse { post_report_view }))
 }
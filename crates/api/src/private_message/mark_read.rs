@@ -19,7 +19,7 @@ pub async fn mark_pm_as_read(
   // Checking permissions
   let private_message_id = data.private_message_id;
   let orig_private_message = PrivateMessage::read(&mut context.pool(), private_message_id).await?;
-  if local_user_view.person.id != orig_private_message.recipient_id {
+  if Some(local_user_view.person.id) != orig_private_message.recipient_id {
     Err(LemmyErrorType::CouldntUpdatePrivateMessage)?
   }
 
@@ -3,7 +3,7 @@ use actix_web::web::{Data, Json};
 use lemmy_api_common::{
   context::LemmyContext,
   private_message::{CreatePrivateMessageReport, PrivateMessageReportResponse},
-  utils::send_new_report_email_to_admins,
+  utils::{send_new_report_email_to_admins, send_new_report_notifications, send_webhook_event},
 };
 use lemmy_db_schema::{
   source::{
@@ -12,9 +12,14 @@ use lemmy_db_schema::{
     private_message_report::{PrivateMessageReport, PrivateMessageReportForm},
   },
   traits::{Crud, Reportable},
+  WebhookEventType,
 };
 use lemmy_db_views::structs::{LocalUserView, PrivateMessageReportView};
-use lemmy_utils::error::{LemmyError, LemmyErrorExt, LemmyErrorType};
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorExt, LemmyErrorType},
+  utils::validation::is_valid_body_field,
+};
+use std::future::ready;
 
 #[tracing::instrument(skip(context))]
 pub async fn create_pm_report(
@@ -31,10 +36,25 @@ pub async fn create_pm_report(
   let private_message_id = data.private_message_id;
   let private_message = PrivateMessage::read(&mut context.pool(), private_message_id).await?;
 
+  // The server can't read encrypted messages, so the recipient has to provide the plaintext
+  let original_pm_text = if private_message.encrypted {
+    if Some(person_id) != private_message.recipient_id {
+      Err(LemmyErrorType::NotAConversationParticipant)?
+    }
+    let decrypted_text = data
+      .decrypted_text
+      .clone()
+      .ok_or(LemmyErrorType::DecryptedTextRequired)?;
+    is_valid_body_field(&Some(decrypted_text.clone()), false)?;
+    decrypted_text
+  } else {
+    private_message.content
+  };
+
   let report_form = PrivateMessageReportForm {
     creator_id: person_id,
     private_message_id,
-    original_pm_text: private_message.content,
+    original_pm_text,
     reason,
   };
 
@@ -55,6 +75,21 @@ pub async fn create_pm_report(
     )
     .await?;
   }
+  send_new_report_notifications(
+    None,
+    &private_message_report_view.creator.name,
+    &private_message_report_view.private_message_creator.name,
+    &private_message_report_view.private_message_report.reason,
+    &context,
+  )
+  .await?;
+  send_webhook_event(
+    WebhookEventType::ReportCreated,
+    None,
+    ready(Ok::<_, LemmyError>(&private_message_report_view)),
+    &mut context.pool(),
+  )
+  .await;
 
   // TODO: consider federating this
 
@@ -1,12 +1,16 @@
 use actix_web::web::{Data, Json};
 use lemmy_api_common::{
   context::LemmyContext,
+  live_hub::LiveMessage,
   private_message::{PrivateMessageReportResponse, ResolvePrivateMessageReport},
   utils::is_admin,
 };
 use lemmy_db_schema::{source::private_message_report::PrivateMessageReport, traits::Reportable};
 use lemmy_db_views::structs::{LocalUserView, PrivateMessageReportView};
-use lemmy_utils::error::{LemmyError, LemmyErrorExt, LemmyErrorType};
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorExt, LemmyErrorType},
+  utils::validation::is_valid_body_field,
+};
 
 #[tracing::instrument(skip(context))]
 pub async fn resolve_pm_report(
@@ -15,19 +19,29 @@ pub async fn resolve_pm_report(
   local_user_view: LocalUserView,
 ) -> Result<Json<PrivateMessageReportResponse>, LemmyError> {
   is_admin(&local_user_view)?;
+  is_valid_body_field(&data.reason, false)?;
 
   let report_id = data.report_id;
   let person_id = local_user_view.person.id;
   if data.resolved {
-    PrivateMessageReport::resolve(&mut context.pool(), report_id, person_id)
-      .await
-      .with_lemmy_type(LemmyErrorType::CouldntResolveReport)?;
+    PrivateMessageReport::resolve(
+      &mut context.pool(),
+      report_id,
+      person_id,
+      data.reason.clone(),
+    )
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntResolveReport)?;
   } else {
     PrivateMessageReport::unresolve(&mut context.pool(), report_id, person_id)
       .await
       .with_lemmy_type(LemmyErrorType::CouldntResolveReport)?;
   }
 
+  context
+    .live()
+    .send(LiveMessage::Report { community_id: None });
+
   let private_message_report_view =
     PrivateMessageReportView::read(&mut context.pool(), report_id).await?;
 
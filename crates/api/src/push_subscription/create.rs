@@ -0,0 +1,46 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  context::LemmyContext,
+  web_push::{CreatePushSubscription, PushSubscriptionResponse},
+};
+use lemmy_db_schema::{
+  source::push_subscription::{PushSubscription, PushSubscriptionInsertForm},
+  traits::Crud,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorExt, LemmyErrorType},
+  utils::validation::check_url_host_public,
+  web_push::check_push_subscription_keys,
+};
+
+#[tracing::instrument(skip(context))]
+pub async fn create_push_subscription(
+  data: Json<CreatePushSubscription>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> Result<Json<PushSubscriptionResponse>, LemmyError> {
+  // Push services are always reached over https, and never in the network of the server
+  if data.endpoint.scheme() != "https" {
+    Err(LemmyErrorType::InvalidPushSubscription)?
+  }
+  check_url_host_public(&data.endpoint)?;
+  check_push_subscription_keys(&data.p256dh, &data.auth)?;
+
+  let form = PushSubscriptionInsertForm {
+    local_user_id: local_user_view.local_user.id,
+    endpoint: data.endpoint.to_string(),
+    p256dh: data.p256dh.clone(),
+    auth: data.auth.clone(),
+    notify_replies: data.notify_replies,
+    notify_mentions: data.notify_mentions,
+    notify_private_messages: data.notify_private_messages,
+    notify_reports: data.notify_reports,
+  };
+  let push_subscription = PushSubscription::create(&mut context.pool(), &form)
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntCreatePushSubscription)?;
+
+  Ok(Json(PushSubscriptionResponse { push_subscription }))
+}
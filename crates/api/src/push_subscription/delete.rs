@@ -0,0 +1,26 @@
+use super::read_own_push_subscription;
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{context::LemmyContext, web_push::DeletePushSubscription, SuccessResponse};
+use lemmy_db_schema::{source::push_subscription::PushSubscription, traits::Crud};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::LemmyError;
+
+#[tracing::instrument(skip(context))]
+pub async fn delete_push_subscription(
+  data: Json<DeletePushSubscription>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> Result<Json<SuccessResponse>, LemmyError> {
+  let push_subscription = read_own_push_subscription(
+    data.push_subscription_id,
+    &local_user_view,
+    &mut context.pool(),
+  )
+  .await?;
+
+  // Queued notifications are removed as well
+  PushSubscription::delete(&mut context.pool(), push_subscription.id).await?;
+
+  Ok(Json(SuccessResponse::default()))
+}
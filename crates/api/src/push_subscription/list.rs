@@ -0,0 +1,18 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{context::LemmyContext, web_push::ListPushSubscriptionsResponse};
+use lemmy_db_schema::source::push_subscription::PushSubscription;
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::LemmyError;
+
+#[tracing::instrument(skip(context))]
+pub async fn list_push_subscriptions(
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> Result<Json<ListPushSubscriptionsResponse>, LemmyError> {
+  let push_subscriptions =
+    PushSubscription::list_for_local_user(&mut context.pool(), local_user_view.local_user.id)
+      .await?;
+
+  Ok(Json(ListPushSubscriptionsResponse { push_subscriptions }))
+}
@@ -0,0 +1,27 @@
+use lemmy_db_schema::{
+  newtypes::PushSubscriptionId,
+  source::push_subscription::PushSubscription,
+  traits::Crud,
+  utils::DbPool,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+pub mod create;
+pub mod delete;
+pub mod list;
+pub mod update;
+pub mod vapid_public_key;
+
+/// Reads a push subscription, which can only be managed by the user who created it.
+async fn read_own_push_subscription(
+  push_subscription_id: PushSubscriptionId,
+  local_user_view: &LocalUserView,
+  pool: &mut DbPool<'_>,
+) -> LemmyResult<PushSubscription> {
+  let push_subscription = PushSubscription::read(pool, push_subscription_id).await?;
+  if push_subscription.local_user_id != local_user_view.local_user.id {
+    Err(LemmyErrorType::NoPushSubscriptionEditAllowed)?
+  }
+  Ok(push_subscription)
+}
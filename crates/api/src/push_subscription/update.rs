@@ -0,0 +1,42 @@
+use super::read_own_push_subscription;
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  context::LemmyContext,
+  web_push::{EditPushSubscription, PushSubscriptionResponse},
+};
+use lemmy_db_schema::{
+  source::push_subscription::{PushSubscription, PushSubscriptionUpdateForm},
+  traits::Crud,
+  utils::naive_now,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyError, LemmyErrorExt, LemmyErrorType};
+
+#[tracing::instrument(skip(context))]
+pub async fn update_push_subscription(
+  data: Json<EditPushSubscription>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> Result<Json<PushSubscriptionResponse>, LemmyError> {
+  let push_subscription = read_own_push_subscription(
+    data.push_subscription_id,
+    &local_user_view,
+    &mut context.pool(),
+  )
+  .await?;
+
+  let form = PushSubscriptionUpdateForm {
+    notify_replies: data.notify_replies,
+    notify_mentions: data.notify_mentions,
+    notify_private_messages: data.notify_private_messages,
+    notify_reports: data.notify_reports,
+    updated: Some(Some(naive_now())),
+  };
+  let push_subscription =
+    PushSubscription::update(&mut context.pool(), push_subscription.id, &form)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdatePushSubscription)?;
+
+  Ok(Json(PushSubscriptionResponse { push_subscription }))
+}
@@ -0,0 +1,18 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{context::LemmyContext, web_push::GetVapidPublicKeyResponse};
+use lemmy_utils::error::{LemmyError, LemmyErrorType};
+
+/// The key is public, so it is also available without login.
+#[tracing::instrument(skip(context))]
+pub async fn get_vapid_public_key(
+  context: Data<LemmyContext>,
+) -> Result<Json<GetVapidPublicKeyResponse>, LemmyError> {
+  let vapid_public_key = context
+    .secret()
+    .vapid_public_key
+    .clone()
+    .ok_or(LemmyErrorType::WebPushNotConfigured)?;
+
+  Ok(Json(GetVapidPublicKeyResponse { vapid_public_key }))
+}
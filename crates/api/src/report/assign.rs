@@ -0,0 +1,62 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_common::{
+  context::LemmyContext,
+  report::{AssignReport, ReportCombinedResponse},
+  utils::check_community_mod_action_opt,
+};
+use lemmy_db_schema::{
+  source::report_combined::{ReportCombined, ReportCombinedUpdateForm},
+  utils::naive_now,
+};
+use lemmy_db_views::structs::{LocalUserView, ReportCombinedView};
+use lemmy_db_views_actor::structs::CommunityView;
+use lemmy_utils::error::{LemmyError, LemmyErrorType};
+
+/// Claims a report queue entry or assigns it to another mod, so that mods don't work on the same
+/// reports twice.
+#[tracing::instrument(skip(context))]
+pub async fn assign_report(
+  data: Json<AssignReport>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> Result<Json<ReportCombinedResponse>, LemmyError> {
+  let report_combined = ReportCombined::read(&mut context.pool(), data.report_combined_id).await?;
+  check_community_mod_action_opt(
+    &local_user_view,
+    report_combined.community_id,
+    &mut context.pool(),
+  )
+  .await?;
+
+  // The assignee has to be able to resolve the reports
+  if let Some(assignee_id) = data.assignee_id {
+    let can_resolve = match report_combined.community_id {
+      Some(community_id) => {
+        CommunityView::is_mod_or_admin(&mut context.pool(), assignee_id, community_id).await?
+      }
+      None => LocalUserView::read_person(&mut context.pool(), assignee_id)
+        .await
+        .map(|u| u.local_user.admin)
+        .unwrap_or(false),
+    };
+    if !can_resolve {
+      Err(LemmyErrorType::InvalidReportAssignee)?
+    }
+  }
+
+  let form = ReportCombinedUpdateForm {
+    assignee_id: Some(data.assignee_id),
+    assigned: Some(data.assignee_id.map(|_| naive_now())),
+  };
+  ReportCombined::update(&mut context.pool(), report_combined.id, &form).await?;
+
+  let report_combined_view = ReportCombinedView::read(
+    &mut context.pool(),
+    report_combined.id,
+    local_user_view.person.id,
+  )
+  .await?;
+  Ok(Json(ReportCombinedResponse {
+    report_combined_view,
+  }))
+}
@@ -0,0 +1,47 @@
+use actix_web::web::{Data, Json};
+use lemmy_api_common::{
+  context::LemmyContext,
+  report::{CreateReportNote, ReportNoteResponse},
+  utils::check_community_mod_action_opt,
+};
+use lemmy_db_schema::{
+  source::{
+    report_combined::ReportCombined,
+    report_note::{ReportNote, ReportNoteInsertForm},
+  },
+  traits::Crud,
+};
+use lemmy_db_views::structs::{LocalUserView, ReportNoteView};
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorExt, LemmyErrorType},
+  utils::validation::is_valid_body_field,
+};
+
+#[tracing::instrument(skip(context))]
+pub async fn create_report_note(
+  data: Json<CreateReportNote>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> Result<Json<ReportNoteResponse>, LemmyError> {
+  let report_combined = ReportCombined::read(&mut context.pool(), data.report_combined_id).await?;
+  check_community_mod_action_opt(
+    &local_user_view,
+    report_combined.community_id,
+    &mut context.pool(),
+  )
+  .await?;
+  let content = data.content.trim().to_string();
+  is_valid_body_field(&Some(content.clone()), false)?;
+
+  let form = ReportNoteInsertForm {
+    report_combined_id: report_combined.id,
+    creator_id: local_user_view.person.id,
+    content,
+  };
+  let report_note = ReportNote::create(&mut context.pool(), &form)
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntCreateReportNote)?;
+
+  let report_note_view = ReportNoteView::read(&mut context.pool(), report_note.id).await?;
+  Ok(Json(ReportNoteResponse { report_note_view }))
+}
@@ -0,0 +1,44 @@
+use actix_web::web::{Data, Json, Query};
+use lemmy_api_common::{
+  context::LemmyContext,
+  report::{ListReports, ListReportsResponse},
+  utils::check_community_mod_action,
+};
+use lemmy_db_views::{
+  report_combined_view::ReportCombinedQuery,
+  structs::{LocalUserView, PaginationCursor},
+};
+use lemmy_utils::error::LemmyError;
+
+/// Lists the report queue of a community if an id is supplied, or of all communities which the
+/// user moderates
+#[tracing::instrument(skip(context))]
+pub async fn list_reports(
+  data: Query<ListReports>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> Result<Json<ListReportsResponse>, LemmyError> {
+  if let Some(community_id) = data.community_id {
+    check_community_mod_action(
+      &local_user_view.person,
+      community_id,
+      false,
+      &mut context.pool(),
+    )
+    .await?;
+  }
+
+  let reports = ReportCombinedQuery {
+    community_id: data.community_id,
+    unresolved_only: data.unresolved_only.unwrap_or_default(),
+    assignee_id: data.assignee_id,
+    unassigned_only: data.unassigned_only.unwrap_or_default(),
+    page_after: data.page_cursor.clone(),
+    limit: data.limit,
+  }
+  .list(&mut context.pool(), &local_user_view)
+  .await?;
+
+  let next_page = reports.last().map(PaginationCursor::after_report);
+  Ok(Json(ListReportsResponse { reports, next_page }))
+}
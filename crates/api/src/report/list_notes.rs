@@ -0,0 +1,27 @@
+use actix_web::web::{Data, Json, Query};
+use lemmy_api_common::{
+  context::LemmyContext,
+  report::{ListReportNotes, ListReportNotesResponse},
+  utils::check_community_mod_action_opt,
+};
+use lemmy_db_schema::source::report_combined::ReportCombined;
+use lemmy_db_views::structs::{LocalUserView, ReportNoteView};
+use lemmy_utils::error::LemmyError;
+
+#[tracing::instrument(skip(context))]
+pub async fn list_report_notes(
+  data: Query<ListReportNotes>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> Result<Json<ListReportNotesResponse>, LemmyError> {
+  let report_combined = ReportCombined::read(&mut context.pool(), data.report_combined_id).await?;
+  check_community_mod_action_opt(
+    &local_user_view,
+    report_combined.community_id,
+    &mut context.pool(),
+  )
+  .await?;
+
+  let report_notes = ReportNoteView::list(&mut context.pool(), report_combined.id).await?;
+  Ok(Json(ListReportNotesResponse { report_notes }))
+}
@@ -0,0 +1,5 @@
+pub mod assign;
+pub mod create_note;
+pub mod list;
+pub mod list_notes;
+pub mod resolve;
@@ -0,0 +1,141 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  context::LemmyContext,
+  live_hub::LiveMessage,
+  report::{ReportCombinedResponse, ResolveReports},
+  send_activity::{ActivityChannel, SendActivityData},
+  utils::{check_community_mod_action_opt, log_report_resolution},
+};
+use lemmy_db_schema::{
+  source::{
+    comment::Comment,
+    comment_report::CommentReport,
+    post::Post,
+    post_report::PostReport,
+    private_message_report::PrivateMessageReport,
+    report_combined::ReportCombined,
+  },
+  traits::Crud,
+};
+use lemmy_db_views::structs::{LocalUserView, ReportCombinedView};
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorExt, LemmyErrorType},
+  utils::validation::is_valid_body_field,
+};
+
+/// Resolves or reopens all reports of a report queue entry at once, and logs it in the modlog
+/// together with the number of reports.
+#[tracing::instrument(skip(context))]
+pub async fn resolve_reports(
+  data: Json<ResolveReports>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> Result<Json<ReportCombinedResponse>, LemmyError> {
+  let report_combined = ReportCombined::read(&mut context.pool(), data.report_combined_id).await?;
+  check_community_mod_action_opt(
+    &local_user_view,
+    report_combined.community_id,
+    &mut context.pool(),
+  )
+  .await?;
+  is_valid_body_field(&data.reason, false)?;
+
+  let person_id = local_user_view.person.id;
+  let reason = data.reason.clone();
+  if let Some(post_id) = report_combined.post_id {
+    let reports = if data.resolved {
+      PostReport::resolve_all_for_object(&mut context.pool(), post_id, person_id, reason.clone())
+        .await
+    } else {
+      PostReport::unresolve_all_for_object(&mut context.pool(), post_id, person_id).await
+    }
+    .with_lemmy_type(LemmyErrorType::CouldntResolveReport)?;
+    if !reports.is_empty() {
+      let post = Post::read(&mut context.pool(), post_id).await?;
+      log_report_resolution(
+        person_id,
+        &post,
+        None,
+        reports.len(),
+        data.resolved,
+        reason,
+        &mut context.pool(),
+      )
+      .await?;
+    }
+    for report in reports {
+      ActivityChannel::submit_activity(
+        SendActivityData::ResolvePostReport(report, local_user_view.person.clone(), data.resolved),
+        &context,
+      )
+      .await?;
+    }
+  } else if let Some(comment_id) = report_combined.comment_id {
+    let reports = if data.resolved {
+      CommentReport::resolve_all_for_object(
+        &mut context.pool(),
+        comment_id,
+        person_id,
+        reason.clone(),
+      )
+      .await
+    } else {
+      CommentReport::unresolve_all_for_object(&mut context.pool(), comment_id, person_id).await
+    }
+    .with_lemmy_type(LemmyErrorType::CouldntResolveReport)?;
+    if !reports.is_empty() {
+      let comment = Comment::read(&mut context.pool(), comment_id).await?;
+      let post = Post::read(&mut context.pool(), comment.post_id).await?;
+      log_report_resolution(
+        person_id,
+        &post,
+        Some(&comment),
+        reports.len(),
+        data.resolved,
+        reason,
+        &mut context.pool(),
+      )
+      .await?;
+    }
+    for report in reports {
+      ActivityChannel::submit_activity(
+        SendActivityData::ResolveCommentReport(
+          report,
+          local_user_view.person.clone(),
+          data.resolved,
+        ),
+        &context,
+      )
+      .await?;
+    }
+  } else if let Some(private_message_id) = report_combined.private_message_id {
+    if data.resolved {
+      PrivateMessageReport::resolve_all_for_object(
+        &mut context.pool(),
+        private_message_id,
+        person_id,
+        reason,
+      )
+      .await
+    } else {
+      PrivateMessageReport::unresolve_all_for_object(
+        &mut context.pool(),
+        private_message_id,
+        person_id,
+      )
+      .await
+    }
+    .with_lemmy_type(LemmyErrorType::CouldntResolveReport)?;
+  }
+
+  context.live().send(LiveMessage::Report {
+    community_id: report_combined.community_id,
+  });
+
+  let report_combined_view =
+    ReportCombinedView::read(&mut context.pool(), report_combined.id, person_id).await?;
+  Ok(Json(ReportCombinedResponse {
+    report_combined_view,
+  }))
+}
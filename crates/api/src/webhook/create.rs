@@ -0,0 +1,42 @@
+use super::{check_webhook_permission, clean_webhook_events};
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  context::LemmyContext,
+  webhook::{CreateWebhook, WebhookResponse},
+};
+use lemmy_db_schema::{
+  source::webhook::{Webhook, WebhookInsertForm},
+  traits::Crud,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorExt, LemmyErrorType},
+  utils::validation::{check_url_host_public, check_url_scheme, is_valid_webhook_secret},
+};
+
+#[tracing::instrument(skip(context))]
+pub async fn create_webhook(
+  data: Json<CreateWebhook>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> Result<Json<WebhookResponse>, LemmyError> {
+  check_webhook_permission(&local_user_view, data.community_id, &mut context.pool()).await?;
+  check_url_scheme(&Some(data.url.clone()))?;
+  check_url_host_public(&data.url)?;
+  is_valid_webhook_secret(&data.secret)?;
+  let events = clean_webhook_events(&data.events, data.community_id)?;
+
+  let form = WebhookInsertForm::builder()
+    .creator_id(local_user_view.person.id)
+    .community_id(data.community_id)
+    .url(data.url.clone().into())
+    .secret(data.secret.clone())
+    .events(events)
+    .build();
+  let webhook = Webhook::create(&mut context.pool(), &form)
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntCreateWebhook)?;
+
+  Ok(Json(WebhookResponse { webhook }))
+}
@@ -0,0 +1,22 @@
+use super::check_webhook_permission;
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{context::LemmyContext, webhook::DeleteWebhook, SuccessResponse};
+use lemmy_db_schema::{source::webhook::Webhook, traits::Crud};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::LemmyError;
+
+#[tracing::instrument(skip(context))]
+pub async fn delete_webhook(
+  data: Json<DeleteWebhook>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> Result<Json<SuccessResponse>, LemmyError> {
+  let webhook = Webhook::read(&mut context.pool(), data.webhook_id).await?;
+  check_webhook_permission(&local_user_view, webhook.community_id, &mut context.pool()).await?;
+
+  // Its queue state and delivery log are removed as well
+  Webhook::delete(&mut context.pool(), webhook.id).await?;
+
+  Ok(Json(SuccessResponse::default()))
+}
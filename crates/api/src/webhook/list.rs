@@ -0,0 +1,23 @@
+use super::check_webhook_permission;
+use activitypub_federation::config::Data;
+use actix_web::web::{Json, Query};
+use lemmy_api_common::{
+  context::LemmyContext,
+  webhook::{ListWebhooks, ListWebhooksResponse},
+};
+use lemmy_db_schema::source::webhook::Webhook;
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::LemmyError;
+
+#[tracing::instrument(skip(context))]
+pub async fn list_webhooks(
+  data: Query<ListWebhooks>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> Result<Json<ListWebhooksResponse>, LemmyError> {
+  check_webhook_permission(&local_user_view, data.community_id, &mut context.pool()).await?;
+
+  let webhooks = Webhook::list(&mut context.pool(), data.community_id).await?;
+
+  Ok(Json(ListWebhooksResponse { webhooks }))
+}
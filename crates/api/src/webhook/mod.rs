@@ -0,0 +1,55 @@
+use lemmy_api_common::utils::{check_community_mod_action, is_admin};
+use lemmy_db_schema::{
+  newtypes::CommunityId,
+  source::community::Community,
+  traits::Crud,
+  utils::DbPool,
+  WebhookEventType,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
+pub mod create;
+pub mod delete;
+pub mod list;
+pub mod list_deliveries;
+pub mod update;
+
+/// Site webhooks are managed by admins, those of a local community by its moderators.
+async fn check_webhook_permission(
+  local_user_view: &LocalUserView,
+  community_id: Option<CommunityId>,
+  pool: &mut DbPool<'_>,
+) -> LemmyResult<()> {
+  if let Some(community_id) = community_id {
+    let community = Community::read(pool, community_id).await?;
+    // Events of remote communities are only known to their home instance
+    if !community.local {
+      Err(LemmyErrorType::ObjectNotLocal)?
+    }
+    check_community_mod_action(&local_user_view.person, community.id, false, pool).await
+  } else {
+    is_admin(local_user_view)
+  }
+}
+
+/// Removes duplicate events, and checks that at least one is selected. Events like new
+/// registration applications are only available for site webhooks.
+fn clean_webhook_events(
+  events: &[WebhookEventType],
+  community_id: Option<CommunityId>,
+) -> LemmyResult<Vec<WebhookEventType>> {
+  let mut cleaned: Vec<WebhookEventType> = vec![];
+  for event in events {
+    if community_id.is_some() && !event.for_communities() {
+      Err(LemmyErrorType::InvalidWebhookEvent)?
+    }
+    if !cleaned.contains(event) {
+      cleaned.push(*event);
+    }
+  }
+  if cleaned.is_empty() {
+    Err(LemmyErrorType::NoWebhookEvents)?
+  }
+  Ok(cleaned)
+}
@@ -0,0 +1,52 @@
+use super::{check_webhook_permission, clean_webhook_events};
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  context::LemmyContext,
+  webhook::{EditWebhook, WebhookResponse},
+};
+use lemmy_db_schema::{
+  source::webhook::{Webhook, WebhookUpdateForm},
+  traits::Crud,
+  utils::naive_now,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorExt, LemmyErrorType},
+  utils::validation::{check_url_host_public, check_url_scheme, is_valid_webhook_secret},
+};
+
+#[tracing::instrument(skip(context))]
+pub async fn update_webhook(
+  data: Json<EditWebhook>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> Result<Json<WebhookResponse>, LemmyError> {
+  let webhook = Webhook::read(&mut context.pool(), data.webhook_id).await?;
+  check_webhook_permission(&local_user_view, webhook.community_id, &mut context.pool()).await?;
+  check_url_scheme(&data.url)?;
+  if let Some(url) = &data.url {
+    check_url_host_public(url)?;
+  }
+  if let Some(secret) = &data.secret {
+    is_valid_webhook_secret(secret)?;
+  }
+  let events = data
+    .events
+    .as_deref()
+    .map(|events| clean_webhook_events(events, webhook.community_id))
+    .transpose()?;
+
+  let form = WebhookUpdateForm {
+    url: data.url.clone().map(Into::into),
+    secret: data.secret.clone(),
+    events,
+    enabled: data.enabled,
+    updated: Some(Some(naive_now())),
+  };
+  let webhook = Webhook::update(&mut context.pool(), webhook.id, &form)
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntUpdateWebhook)?;
+
+  Ok(Json(WebhookResponse { webhook }))
+}
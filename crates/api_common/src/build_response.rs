@@ -2,16 +2,26 @@ use crate::{
   comment::CommentResponse,
   community::CommunityResponse,
   context::LemmyContext,
+  live_hub::LiveMessage,
+  multi_community::MultiCommunityResponse,
   post::PostResponse,
-  utils::{check_person_block, get_interface_language, is_mod_or_admin, send_email_to_user},
+  utils::{
+    check_person_block,
+    get_interface_language,
+    is_mod_or_admin,
+    send_email_to_user,
+    send_push_message,
+  },
+  web_push::{PushMessage, PushMessageType},
 };
 use actix_web::web::Json;
 use lemmy_db_schema::{
-  newtypes::{CommentId, CommunityId, LocalUserId, PostId},
+  newtypes::{CommentId, CommunityId, LocalUserId, MultiCommunityId, PostId},
   source::{
     actor_language::CommunityLanguage,
     comment::Comment,
     comment_reply::{CommentReply, CommentReplyInsertForm},
+    multi_community::MultiCommunity,
     person::Person,
     person_mention::{PersonMention, PersonMentionInsertForm},
     post::Post,
@@ -19,7 +29,7 @@ use lemmy_db_schema::{
   traits::Crud,
 };
 use lemmy_db_views::structs::{CommentView, LocalUserView, PostView};
-use lemmy_db_views_actor::structs::CommunityView;
+use lemmy_db_views_actor::structs::{CommunityView, MultiCommunityView};
 use lemmy_utils::{
   error::LemmyError,
   utils::{markdown::markdown_to_html, mention::MentionData},
@@ -63,6 +73,21 @@ pub async fn build_community_response(
   }))
 }
 
+pub async fn build_multi_community_response(
+  context: &LemmyContext,
+  multi_community_id: MultiCommunityId,
+) -> Result<Json<MultiCommunityResponse>, LemmyError> {
+  let multi_community_view =
+    MultiCommunityView::read(&mut context.pool(), multi_community_id).await?;
+  let communities =
+    MultiCommunity::list_communities(&mut context.pool(), multi_community_id).await?;
+
+  Ok(Json(MultiCommunityResponse {
+    multi_community_view,
+    communities,
+  }))
+}
+
 pub async fn build_post_response(
   context: &LemmyContext,
   community_id: CommunityId,
@@ -94,6 +119,11 @@ pub async fn send_local_notifs(
 ) -> Result<Vec<LocalUserId>, LemmyError> {
   let mut recipient_ids = Vec::new();
   let inbox_link = format!("{}/inbox", context.settings().get_protocol_and_hostname());
+  let comment_link = format!(
+    "{}/comment/{}",
+    context.settings().get_protocol_and_hostname(),
+    comment.id
+  );
 
   // Send the local mentions
   for mention in mentions
@@ -116,10 +146,18 @@ pub async fn send_local_notifs(
 
       // Allow this to fail softly, since comment edits might re-update or replace it
       // Let the uniqueness handle this fail
-      PersonMention::create(&mut context.pool(), &user_mention_form)
+      let mention = PersonMention::create(&mut context.pool(), &user_mention_form)
         .await
         .ok();
 
+      // Only new comments are pushed to connected clients, the same as for emails
+      if let Some(mention) = mention.filter(|_| do_send_email) {
+        context.live().send(LiveMessage::PersonMention {
+          recipient_id: mention.recipient_id,
+          person_mention_id: mention.id,
+        });
+      }
+
       // Send an email to those local users that have notifications on
       if do_send_email {
         let lang = get_interface_language(&mention_user_view);
@@ -130,7 +168,14 @@ pub async fn send_local_notifs(
           &lang.notification_mentioned_by_body(&content, &inbox_link, &person.name),
           context.settings(),
         )
-        .await
+        .await;
+        let message = PushMessage {
+          type_: PushMessageType::PersonMention,
+          title: lang.notification_mentioned_by_subject(&person.name),
+          body: comment.content.clone(),
+          url: comment_link.clone(),
+        };
+        send_push_message(mention_user_view.person.id, message, &mut context.pool()).await;
       }
     }
   }
@@ -161,9 +206,15 @@ pub async fn send_local_notifs(
 
         // Allow this to fail softly, since comment edits might re-update or replace it
         // Let the uniqueness handle this fail
-        CommentReply::create(&mut context.pool(), &comment_reply_form)
+        let reply = CommentReply::create(&mut context.pool(), &comment_reply_form)
           .await
           .ok();
+        if let Some(reply) = reply.filter(|_| do_send_email) {
+          context.live().send(LiveMessage::CommentReply {
+            recipient_id: reply.recipient_id,
+            comment_reply_id: reply.id,
+          });
+        }
 
         if do_send_email {
           let lang = get_interface_language(&parent_user_view);
@@ -174,7 +225,14 @@ pub async fn send_local_notifs(
             &lang.notification_comment_reply_body(&content, &inbox_link, &person.name),
             context.settings(),
           )
-          .await
+          .await;
+          let message = PushMessage {
+            type_: PushMessageType::CommentReply,
+            title: lang.notification_comment_reply_subject(&person.name),
+            body: comment.content.clone(),
+            url: comment_link.clone(),
+          };
+          send_push_message(parent_user_view.person.id, message, &mut context.pool()).await;
         }
       }
     }
@@ -199,9 +257,15 @@ pub async fn send_local_notifs(
 
         // Allow this to fail softly, since comment edits might re-update or replace it
         // Let the uniqueness handle this fail
-        CommentReply::create(&mut context.pool(), &comment_reply_form)
+        let reply = CommentReply::create(&mut context.pool(), &comment_reply_form)
           .await
           .ok();
+        if let Some(reply) = reply.filter(|_| do_send_email) {
+          context.live().send(LiveMessage::CommentReply {
+            recipient_id: reply.recipient_id,
+            comment_reply_id: reply.id,
+          });
+        }
 
         if do_send_email {
           let lang = get_interface_language(&parent_user_view);
@@ -212,7 +276,14 @@ pub async fn send_local_notifs(
             &lang.notification_post_reply_body(&content, &inbox_link, &person.name),
             context.settings(),
           )
-          .await
+          .await;
+          let message = PushMessage {
+            type_: PushMessageType::CommentReply,
+            title: lang.notification_post_reply_subject(&person.name),
+            body: comment.content.clone(),
+            url: comment_link.clone(),
+          };
+          send_push_message(parent_user_view.person.id, message, &mut context.pool()).await;
         }
       }
     }
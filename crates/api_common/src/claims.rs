@@ -71,12 +71,48 @@ impl Claims {
   }
 }
 
+/// The audience of unsubscribe tokens, which keeps them apart from login tokens.
+const EMAIL_DIGEST_AUDIENCE: &str = "email_digest";
+
+/// Token in the unsubscribe link of email digests, which turns them off without logging in.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UnsubscribeClaims {
+  /// local_user_id
+  pub sub: String,
+  pub aud: String,
+}
+
+impl UnsubscribeClaims {
+  pub fn validate(token: &str, context: &LemmyContext) -> LemmyResult<LocalUserId> {
+    let mut validation = Validation::default();
+    validation.validate_exp = false;
+    validation.required_spec_claims = ["sub", "aud"].map(ToString::to_string).into();
+    validation.set_audience(&[EMAIL_DIGEST_AUDIENCE]);
+    let key = DecodingKey::from_secret(context.secret().jwt_secret.as_ref());
+    let claims = decode::<UnsubscribeClaims>(token, &key, &validation)
+      .with_lemmy_type(LemmyErrorType::InvalidUnsubscribeToken)?;
+    Ok(LocalUserId(claims.claims.sub.parse()?))
+  }
+
+  pub fn generate(user_id: LocalUserId, context: &LemmyContext) -> LemmyResult<String> {
+    let claims = UnsubscribeClaims {
+      sub: user_id.0.to_string(),
+      aud: EMAIL_DIGEST_AUDIENCE.to_string(),
+    };
+    let key = EncodingKey::from_secret(context.secret().jwt_secret.as_ref());
+    Ok(encode(&Header::default(), &claims, &key)?)
+  }
+}
+
 #[cfg(test)]
 mod tests {
   #![allow(clippy::unwrap_used)]
   #![allow(clippy::indexing_slicing)]
 
-  use crate::{claims::Claims, context::LemmyContext};
+  use crate::{
+    claims::{Claims, UnsubscribeClaims},
+    context::LemmyContext,
+  };
   use actix_web::test::TestRequest;
   use lemmy_db_schema::{
     source::{
@@ -135,6 +171,17 @@ mod tests {
     let valid = Claims::validate(&jwt, &context).await;
     assert!(valid.is_ok());
 
+    // Unsubscribe tokens can't be used to log in, and login tokens can't unsubscribe
+    let unsubscribe_token = UnsubscribeClaims::generate(inserted_local_user.id, &context).unwrap();
+    assert_eq!(
+      inserted_local_user.id,
+      UnsubscribeClaims::validate(&unsubscribe_token, &context).unwrap()
+    );
+    assert!(Claims::validate(&unsubscribe_token, &context)
+      .await
+      .is_err());
+    assert!(UnsubscribeClaims::validate(&jwt, &context).is_err());
+
     let num_deleted = Person::delete(pool, inserted_person.id).await.unwrap();
     assert_eq!(1, num_deleted);
   }
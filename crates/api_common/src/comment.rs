@@ -1,5 +1,6 @@
 use lemmy_db_schema::{
   newtypes::{CommentId, CommentReportId, CommunityId, LanguageId, LocalUserId, PostId},
+  source::comment::CommentRevision,
   CommentSortType,
   ListingType,
 };
@@ -129,6 +130,31 @@ pub struct GetCommentsResponse {
   pub comments: Vec<CommentView>,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// List the earlier versions of a comment.
+pub struct ListCommentRevisions {
+  pub comment_id: CommentId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The comment revisions response, oldest first.
+pub struct ListCommentRevisionsResponse {
+  pub revisions: Vec<CommentRevisionDiff>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// An earlier version of a comment, with a line diff to the version which replaced it.
+pub struct CommentRevisionDiff {
+  pub revision: CommentRevision,
+  pub content_diff: String,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
 #[cfg_attr(feature = "full", derive(TS))]
 #[cfg_attr(feature = "full", ts(export))]
@@ -146,6 +172,7 @@ pub struct CommentReportResponse {
   pub comment_report_view: CommentReportView,
 }
 
+#[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
 #[cfg_attr(feature = "full", derive(TS))]
 #[cfg_attr(feature = "full", ts(export))]
@@ -153,6 +180,8 @@ pub struct CommentReportResponse {
 pub struct ResolveCommentReport {
   pub report_id: CommentReportId,
   pub resolved: bool,
+  /// Why the report was resolved. For posts and comments it is shown in the modlog.
+  pub reason: Option<String>,
 }
 
 #[skip_serializing_none]
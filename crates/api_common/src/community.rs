@@ -1,10 +1,18 @@
 use lemmy_db_schema::{
-  newtypes::{CommunityId, LanguageId, PersonId},
-  source::site::Site,
+  newtypes::{CommentId, CommunityId, CommunityPostTagId, LanguageId, PersonId, PostId},
+  source::{community_post_tag::CommunityPostTag, site::Site},
+  CommunityVisibility,
   ListingType,
   SortType,
 };
-use lemmy_db_views_actor::structs::{CommunityModeratorView, CommunityView, PersonView};
+use lemmy_db_views::structs::CustomEmojiView;
+use lemmy_db_views_actor::structs::{
+  CommunityFollowerView,
+  CommunityModeratorView,
+  CommunityView,
+  PersonView,
+};
+use lemmy_db_views_moderator::structs::ModWarningView;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 #[cfg(feature = "full")]
@@ -31,6 +39,10 @@ pub struct GetCommunityResponse {
   pub site: Option<Site>,
   pub moderators: Vec<CommunityModeratorView>,
   pub discussion_languages: Vec<LanguageId>,
+  /// The tags which can be used for posts in this community.
+  pub post_tags: Vec<CommunityPostTag>,
+  /// The emojis which the moderators added for this community, in addition to those of the site.
+  pub custom_emojis: Vec<CustomEmojiView>,
 }
 
 #[skip_serializing_none]
@@ -54,6 +66,8 @@ pub struct CreateCommunity {
   /// Whether to restrict posting only to moderators.
   pub posting_restricted_to_mods: Option<bool>,
   pub discussion_languages: Option<Vec<LanguageId>>,
+  /// Who can see the content of the community.
+  pub visibility: Option<CommunityVisibility>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -109,6 +123,29 @@ pub struct BanFromCommunityResponse {
   pub banned: bool,
 }
 
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Warn the creator of a post or comment. Either a post or a comment has to be given.
+pub struct WarnPerson {
+  pub post_id: Option<PostId>,
+  pub comment_id: Option<CommentId>,
+  pub reason: String,
+  /// Whether to send the warning to the instance of a remote user. Defaults to true.
+  pub federate: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The response for a warning.
+pub struct WarnPersonResponse {
+  pub mod_warning_view: ModWarningView,
+  /// Whether the person was banned from the community, because they got too many warnings.
+  pub banned: bool,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
 #[cfg_attr(feature = "full", derive(TS))]
 #[cfg_attr(feature = "full", ts(export))]
@@ -147,6 +184,14 @@ pub struct EditCommunity {
   /// Whether to restrict posting only to moderators.
   pub posting_restricted_to_mods: Option<bool>,
   pub discussion_languages: Option<Vec<LanguageId>>,
+  /// Who can see the content of the community.
+  pub visibility: Option<CommunityVisibility>,
+  /// Ban users automatically after this many warnings. 0 disables it.
+  pub warning_ban_threshold: Option<i32>,
+  /// The number of days in which the warnings are counted.
+  pub warning_ban_window_days: Option<i32>,
+  /// How long the automatic ban lasts, in days.
+  pub warning_ban_days: Option<i32>,
 }
 
 #[skip_serializing_none]
@@ -191,6 +236,36 @@ pub struct FollowCommunity {
   pub follow: bool,
 }
 
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// List the follow requests of a private community which wait for approval (only doable by
+/// moderators).
+pub struct ListCommunityPendingFollows {
+  pub community_id: CommunityId,
+  pub page: Option<i64>,
+  pub limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The pending follow requests of a community.
+pub struct ListCommunityPendingFollowsResponse {
+  pub pending_follows: Vec<CommunityFollowerView>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Approve or deny the follow request of a private community (only doable by moderators).
+pub struct ApproveCommunityPendingFollow {
+  pub community_id: CommunityId,
+  pub follower_id: PersonId,
+  pub approve: bool,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
 #[cfg_attr(feature = "full", derive(TS))]
 #[cfg_attr(feature = "full", ts(export))]
@@ -218,3 +293,38 @@ pub struct TransferCommunity {
   pub community_id: CommunityId,
   pub person_id: PersonId,
 }
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Create a tag for posts in a community (only doable by moderators).
+pub struct CreateCommunityPostTag {
+  pub community_id: CommunityId,
+  pub display_name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Rename a community post tag (only doable by moderators).
+pub struct EditCommunityPostTag {
+  pub tag_id: CommunityPostTagId,
+  pub display_name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Delete a community post tag (only doable by moderators).
+pub struct DeleteCommunityPostTag {
+  pub tag_id: CommunityPostTagId,
+  pub deleted: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The community post tag response.
+pub struct CommunityPostTagResponse {
+  pub community_post_tag: CommunityPostTag,
+}
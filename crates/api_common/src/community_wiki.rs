@@ -0,0 +1,117 @@
+use lemmy_db_schema::{
+  newtypes::{CommunityId, CommunityWikiPageId, CommunityWikiPageRevisionId},
+  source::{
+    community_wiki::{CommunityWikiPage, CommunityWikiPageRevision},
+    person::Person,
+  },
+};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Create a page in the wiki of a community (only doable by moderators).
+pub struct CreateCommunityWikiPage {
+  pub community_id: CommunityId,
+  /// Identifies the page in its community. Only lowercase letters, digits, `-` and `_`.
+  pub slug: String,
+  pub title: String,
+  pub body: String,
+  /// Allow approved followers of the community to edit the page, not only moderators.
+  pub members_can_edit: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Edit a wiki page. Only moderators can change who may edit the page.
+pub struct EditCommunityWikiPage {
+  pub wiki_page_id: CommunityWikiPageId,
+  pub title: Option<String>,
+  pub body: Option<String>,
+  pub members_can_edit: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Delete or restore a wiki page (only doable by moderators).
+pub struct DeleteCommunityWikiPage {
+  pub wiki_page_id: CommunityWikiPageId,
+  pub deleted: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Get a wiki page by its slug.
+pub struct GetCommunityWikiPage {
+  pub community_id: CommunityId,
+  pub slug: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A wiki page, with its body rendered to html.
+pub struct CommunityWikiPageResponse {
+  pub wiki_page: CommunityWikiPage,
+  pub body_html: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// List the pages in the wiki of a community.
+pub struct ListCommunityWikiPages {
+  pub community_id: CommunityId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The wiki pages of a community, ordered by title.
+pub struct ListCommunityWikiPagesResponse {
+  pub wiki_pages: Vec<CommunityWikiPage>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// List all versions of a wiki page.
+pub struct ListCommunityWikiPageRevisions {
+  pub wiki_page_id: CommunityWikiPageId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The wiki page revisions response, oldest first.
+pub struct ListCommunityWikiPageRevisionsResponse {
+  pub revisions: Vec<CommunityWikiPageRevisionDiff>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A version of a wiki page, with line diffs to the version before it.
+pub struct CommunityWikiPageRevisionDiff {
+  pub revision: CommunityWikiPageRevision,
+  pub editor: Person,
+  pub title_diff: String,
+  pub body_diff: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Restore an earlier version of a wiki page. This is stored as a new revision.
+pub struct RevertCommunityWikiPage {
+  pub wiki_page_id: CommunityWikiPageId,
+  pub revision_id: CommunityWikiPageRevisionId,
+}
@@ -1,3 +1,4 @@
+use crate::live_hub::LiveHub;
 use lemmy_db_schema::{
   source::secret::Secret,
   utils::{ActualDbPool, DbPool},
@@ -15,6 +16,7 @@ pub struct LemmyContext {
   client: Arc<ClientWithMiddleware>,
   secret: Arc<Secret>,
   rate_limit_cell: RateLimitCell,
+  live: LiveHub,
 }
 
 impl LemmyContext {
@@ -29,6 +31,7 @@ impl LemmyContext {
       client: Arc::new(client),
       secret: Arc::new(secret),
       rate_limit_cell,
+      live: LiveHub::new(),
     }
   }
   pub fn pool(&self) -> DbPool<'_> {
@@ -49,4 +52,7 @@ impl LemmyContext {
   pub fn settings_updated_channel(&self) -> &RateLimitCell {
     &self.rate_limit_cell
   }
+  pub fn live(&self) -> &LiveHub {
+    &self.live
+  }
 }
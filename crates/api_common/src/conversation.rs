@@ -0,0 +1,98 @@
+use lemmy_db_schema::newtypes::{ConversationId, PersonId};
+use lemmy_db_views::structs::{ConversationView, PrivateMessageView};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Start a new conversation with one or more people, by sending a first message to all of them.
+pub struct CreateConversation {
+  /// The other participants, you are added automatically.
+  pub participant_ids: Vec<PersonId>,
+  pub content: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Send a message to all participants of a conversation.
+pub struct CreateConversationMessage {
+  pub conversation_id: ConversationId,
+  pub content: String,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// List your conversations, the most recently active ones first.
+pub struct ListConversations {
+  pub unread_only: Option<bool>,
+  pub page: Option<i64>,
+  pub limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The conversations response.
+pub struct ListConversationsResponse {
+  pub conversations: Vec<ConversationView>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Get a conversation and its messages.
+pub struct GetConversation {
+  pub conversation_id: ConversationId,
+  pub page: Option<i64>,
+  pub limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A conversation with its messages, newest first.
+pub struct GetConversationResponse {
+  pub conversation_view: ConversationView,
+  pub private_messages: Vec<PrivateMessageView>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Mark all messages of a conversation as read.
+pub struct MarkConversationAsRead {
+  pub conversation_id: ConversationId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Mute a conversation. Messages of muted conversations don't count as unread and don't send
+/// emails.
+pub struct MuteConversation {
+  pub conversation_id: ConversationId,
+  pub muted: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Leave a conversation. You won't receive any of its messages anymore.
+pub struct LeaveConversation {
+  pub conversation_id: ConversationId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A single conversation response.
+pub struct ConversationResponse {
+  pub conversation_view: ConversationView,
+}
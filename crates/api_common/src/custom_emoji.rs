@@ -1,10 +1,12 @@
-use lemmy_db_schema::newtypes::CustomEmojiId;
+use lemmy_db_schema::newtypes::{CommunityId, CustomEmojiId};
 use lemmy_db_views::structs::CustomEmojiView;
 use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
 #[cfg(feature = "full")]
 use ts_rs::TS;
 use url::Url;
 
+#[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[cfg_attr(feature = "full", derive(TS))]
 #[cfg_attr(feature = "full", ts(export))]
@@ -16,6 +18,9 @@ pub struct CreateCustomEmoji {
   pub image_url: Url,
   pub alt_text: String,
   pub keywords: Vec<String>,
+  /// Adds the emoji to the emoji set of a community (only doable by its moderators), instead of
+  /// the site.
+  pub community_id: Option<CommunityId>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -0,0 +1,106 @@
+use crate::post::CreatePoll;
+use lemmy_db_schema::{
+  newtypes::{CommentId, CommunityId, CommunityPostTagId, DraftId, LanguageId, PostId},
+  source::draft::Draft,
+};
+use lemmy_db_views::structs::{CommentView, PostView};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+use url::Url;
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Save an unpublished post or comment. Give a `post_id` for a comment draft, otherwise it is a
+/// post draft. The content is only validated once the draft gets published.
+pub struct CreateDraft {
+  pub community_id: Option<CommunityId>,
+  pub name: Option<String>,
+  #[cfg_attr(feature = "full", ts(type = "string"))]
+  pub url: Option<Url>,
+  /// The post body, or the comment content.
+  pub body: Option<String>,
+  pub nsfw: Option<bool>,
+  pub language_id: Option<LanguageId>,
+  pub post_id: Option<PostId>,
+  pub parent_id: Option<CommentId>,
+  pub tags: Option<Vec<CommunityPostTagId>>,
+  /// The time (unix timestamp) to schedule the post for once the draft is published.
+  pub scheduled_publish_time: Option<i64>,
+  pub poll: Option<CreatePoll>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Edit a draft. The post and parent comment of a comment draft can't be changed.
+pub struct EditDraft {
+  pub draft_id: DraftId,
+  pub community_id: Option<CommunityId>,
+  pub name: Option<String>,
+  #[cfg_attr(feature = "full", ts(type = "string"))]
+  pub url: Option<Url>,
+  pub body: Option<String>,
+  pub nsfw: Option<bool>,
+  pub language_id: Option<LanguageId>,
+  /// Replaces the tags of a post draft.
+  pub tags: Option<Vec<CommunityPostTagId>>,
+  pub scheduled_publish_time: Option<i64>,
+  /// Replaces the poll of a post draft.
+  pub poll: Option<CreatePoll>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Delete one of your drafts.
+pub struct DeleteDraft {
+  pub draft_id: DraftId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Publish a draft as a post or comment, which removes the draft.
+pub struct PublishDraft {
+  pub draft_id: DraftId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+pub struct DraftResponse {
+  pub draft: Draft,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// List your drafts.
+pub struct ListDrafts {
+  pub page: Option<i64>,
+  pub limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The drafts response, newest first.
+pub struct ListDraftsResponse {
+  pub drafts: Vec<Draft>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+#[serde(tag = "type_")]
+/// The post or comment which was created from a draft.
+pub enum PublishDraftResponse {
+  Post(PostView),
+  Comment(CommentView),
+}
@@ -4,12 +4,21 @@ pub mod build_response;
 pub mod claims;
 pub mod comment;
 pub mod community;
+pub mod community_wiki;
 #[cfg(feature = "full")]
 pub mod context;
+pub mod conversation;
 pub mod custom_emoji;
+pub mod draft;
+pub mod live;
+#[cfg(feature = "full")]
+pub mod live_hub;
+pub mod multi_community;
 pub mod person;
 pub mod post;
 pub mod private_message;
+pub mod reaction;
+pub mod report;
 #[cfg(feature = "full")]
 pub mod request;
 #[cfg(feature = "full")]
@@ -18,6 +27,8 @@ pub mod sensitive;
 pub mod site;
 #[cfg(feature = "full")]
 pub mod utils;
+pub mod web_push;
+pub mod webhook;
 
 pub extern crate lemmy_db_schema;
 pub extern crate lemmy_db_views;
@@ -0,0 +1,36 @@
+use crate::person::GetReportCountResponse;
+use lemmy_db_views::structs::{PostView, PrivateMessageView};
+use lemmy_db_views_actor::structs::{CommentReplyView, PersonMentionView};
+use lemmy_db_views_moderator::structs::ModWarningView;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Opens a stream of server-sent events, which pushes new notifications as they arrive. Each
+/// event contains a `LiveEvent` as json.
+pub struct GetLiveEvents {
+  /// Also push the new posts of your subscribed communities.
+  pub posts: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+#[serde(tag = "type_")]
+/// An event which is pushed over the live stream.
+pub enum LiveEvent {
+  CommentReply(CommentReplyView),
+  PersonMention(PersonMentionView),
+  PrivateMessage(PrivateMessageView),
+  /// The report counts changed, only sent to moderators and admins.
+  ReportCount(GetReportCountResponse),
+  /// A new post in a subscribed community, only sent if requested.
+  Post(PostView),
+  /// A moderator warned you.
+  Warning(ModWarningView),
+}
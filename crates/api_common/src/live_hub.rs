@@ -0,0 +1,69 @@
+use lemmy_db_schema::newtypes::{
+  CommentReplyId,
+  CommunityId,
+  PersonId,
+  PersonMentionId,
+  PostId,
+  PrivateMessageId,
+};
+use tokio::sync::broadcast::{self, Receiver, Sender};
+
+/// How many messages are buffered for each connection. Connections which fall further behind
+/// skip the oldest messages.
+const CHANNEL_CAPACITY: usize = 1024;
+
+/// Something happened which connected clients might need to know about. The messages only
+/// contain ids, each connection reads the data itself so that it is rendered for its user.
+#[derive(Clone, Debug)]
+pub enum LiveMessage {
+  CommentReply {
+    recipient_id: PersonId,
+    comment_reply_id: CommentReplyId,
+  },
+  PersonMention {
+    recipient_id: PersonId,
+    person_mention_id: PersonMentionId,
+  },
+  PrivateMessage {
+    recipient_ids: Vec<PersonId>,
+    private_message_id: PrivateMessageId,
+  },
+  /// A report was created or resolved. Private message reports have no community.
+  Report { community_id: Option<CommunityId> },
+  Post {
+    community_id: CommunityId,
+    post_id: PostId,
+  },
+  Warning {
+    recipient_id: PersonId,
+    mod_warning_id: i32,
+  },
+}
+
+/// Distributes live messages to the clients which are connected to this server process.
+#[derive(Clone)]
+pub struct LiveHub {
+  sender: Sender<LiveMessage>,
+}
+
+impl LiveHub {
+  pub fn new() -> Self {
+    let (sender, _) = broadcast::channel(CHANNEL_CAPACITY);
+    LiveHub { sender }
+  }
+
+  pub fn send(&self, message: LiveMessage) {
+    // This only fails if no client is connected
+    self.sender.send(message).ok();
+  }
+
+  pub fn subscribe(&self) -> Receiver<LiveMessage> {
+    self.sender.subscribe()
+  }
+}
+
+impl Default for LiveHub {
+  fn default() -> Self {
+    Self::new()
+  }
+}
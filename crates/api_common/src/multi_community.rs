@@ -0,0 +1,82 @@
+use lemmy_db_schema::{
+  newtypes::{CommunityId, MultiCommunityId, PersonId},
+  source::community::Community,
+};
+use lemmy_db_views_actor::structs::MultiCommunityView;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Create a multi-community, which combines the posts of several communities into one feed.
+pub struct CreateMultiCommunity {
+  /// The unique name, which is used for the url.
+  pub name: String,
+  /// A longer title, that can contain other characters.
+  pub title: String,
+  pub description: Option<String>,
+  pub community_ids: Vec<CommunityId>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Edit a multi-community. If given, `community_ids` replaces the existing communities.
+pub struct EditMultiCommunity {
+  pub multi_community_id: MultiCommunityId,
+  pub title: Option<String>,
+  pub description: Option<String>,
+  pub community_ids: Option<Vec<CommunityId>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Delete your own multi-community.
+pub struct DeleteMultiCommunity {
+  pub multi_community_id: MultiCommunityId,
+  pub deleted: bool,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Get a multi-community. Must provide either an id, or the name of a local multi-community.
+pub struct GetMultiCommunity {
+  pub id: Option<MultiCommunityId>,
+  pub name: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A multi-community response.
+pub struct MultiCommunityResponse {
+  pub multi_community_view: MultiCommunityView,
+  pub communities: Vec<Community>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// List multi-communities, optionally only those of a single creator.
+pub struct ListMultiCommunities {
+  pub creator_id: Option<PersonId>,
+  pub page: Option<i64>,
+  pub limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The response for listing multi-communities.
+pub struct ListMultiCommunitiesResponse {
+  pub multi_communities: Vec<MultiCommunityView>,
+}
@@ -1,18 +1,38 @@
 use crate::sensitive::Sensitive;
 use lemmy_db_schema::{
-  newtypes::{CommentReplyId, CommunityId, LanguageId, PersonId, PersonMentionId},
+  newtypes::{
+    CommentReplyId,
+    CommunityId,
+    LanguageId,
+    LocalUserKeywordFilterId,
+    PersonId,
+    PersonMentionId,
+    PersonNoteId,
+  },
+  source::local_user_keyword_filter::LocalUserKeywordFilter,
   CommentSortType,
+  EmailDigestFrequency,
   ListingType,
   PostListingMode,
   SortType,
+  SubscribedType,
 };
-use lemmy_db_views::structs::{CommentView, PostView};
+use lemmy_db_views::structs::{CommentReportView, CommentView, PostReportView, PostView};
 use lemmy_db_views_actor::structs::{
   CommentReplyView,
   CommunityModeratorView,
   PersonMentionView,
   PersonView,
 };
+use lemmy_db_views_moderator::structs::{
+  ModBanFromCommunityView,
+  ModBanView,
+  ModRemoveCommentView,
+  ModRemovePostView,
+  ModWarningView,
+  ModlogCursor,
+  PersonNoteView,
+};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 #[cfg(feature = "full")]
@@ -105,6 +125,9 @@ pub struct SaveUserSettings {
   pub bio: Option<String>,
   /// Your matrix user id. Ex: @my_user:matrix.org
   pub matrix_user_id: Option<String>,
+  /// A public key which others use to send you end-to-end encrypted private messages. Send an
+  /// empty string to remove it.
+  pub encryption_public_key: Option<String>,
   /// Whether to show or hide avatars.
   pub show_avatars: Option<bool>,
   /// Sends notifications to your email.
@@ -129,6 +152,18 @@ pub struct SaveUserSettings {
   pub enable_keyboard_navigation: Option<bool>,
   /// Whether user avatars or inline images in the UI that are gifs should be allowed to play or should be paused
   pub enable_animated_images: Option<bool>,
+  /// How often to receive an email with the top posts of your subscribed communities and your
+  /// unread replies.
+  pub email_digest: Option<EmailDigestFrequency>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Turns off email digests, with the token from the unsubscribe link of a digest. Doesn't require
+/// login.
+pub struct UnsubscribeEmailDigest {
+  pub token: String,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
@@ -251,6 +286,60 @@ pub struct BlockPersonResponse {
   pub blocked: bool,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Follow a person.
+pub struct FollowPerson {
+  pub person_id: PersonId,
+  pub follow: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The response for following a person.
+pub struct FollowPersonResponse {
+  pub person_view: PersonView,
+  /// Follows of remote persons stay pending until they are accepted.
+  pub subscribed: SubscribedType,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Mute posts and comments containing a keyword, or matching a regex.
+pub struct CreateKeywordFilter {
+  pub keyword: String,
+  /// Treat the keyword as a case-insensitive POSIX regular expression. Defaults to false.
+  pub is_regex: Option<bool>,
+  /// Apply to post titles. Defaults to true.
+  pub filter_title: Option<bool>,
+  /// Apply to post bodies and comments. Defaults to true.
+  pub filter_body: Option<bool>,
+  /// Apply to post urls. Defaults to false.
+  pub filter_url: Option<bool>,
+  /// A time when the filter should expire, as a unix timestamp.
+  pub expires: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The response for creating a keyword filter.
+pub struct KeywordFilterResponse {
+  pub keyword_filter: LocalUserKeywordFilter,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Delete one of your keyword filters.
+pub struct DeleteKeywordFilter {
+  pub id: LocalUserKeywordFilterId,
+}
+
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
 #[cfg_attr(feature = "full", derive(TS))]
@@ -382,6 +471,8 @@ pub struct GetUnreadCountResponse {
   pub replies: i64,
   pub mentions: i64,
   pub private_messages: i64,
+  /// Reactions by others to your posts and comments.
+  pub reactions: i64,
 }
 
 #[derive(Serialize, Deserialize, Clone, Default, Debug)]
@@ -413,3 +504,96 @@ pub struct UpdateTotp {
 pub struct UpdateTotpResponse {
   pub enabled: bool,
 }
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Add a private note about a person. Notes for a community can be added and read by its mods,
+/// notes without a community only by admins.
+pub struct CreatePersonNote {
+  pub person_id: PersonId,
+  pub community_id: Option<CommunityId>,
+  pub content: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Edit a note about a person.
+pub struct EditPersonNote {
+  pub person_note_id: PersonNoteId,
+  pub content: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Delete a note about a person.
+pub struct DeletePersonNote {
+  pub person_note_id: PersonNoteId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A response for a note about a person.
+pub struct PersonNoteResponse {
+  pub person_note_view: PersonNoteView,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Get the notes and moderation history of a person. Mods have to give one of their communities,
+/// admins can leave it out to get the history across all communities.
+pub struct GetModerationHistory {
+  pub person_id: PersonId,
+  pub community_id: Option<CommunityId>,
+  /// The maximum number of history items.
+  pub limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+#[serde(tag = "type_")]
+/// A moderation action against a person, or a resolved report about their content.
+pub enum ModerationHistoryItem {
+  ModRemovePost(ModRemovePostView),
+  ModRemoveComment(ModRemoveCommentView),
+  ModBanFromCommunity(ModBanFromCommunityView),
+  ModBan(ModBanView),
+  ModWarning(ModWarningView),
+  PostReport(PostReportView),
+  CommentReport(CommentReportView),
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The moderation history of a person, newest items first.
+pub struct GetModerationHistoryResponse {
+  pub notes: Vec<PersonNoteView>,
+  pub history: Vec<ModerationHistoryItem>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// List the warnings which you got from moderators.
+pub struct ListWarnings {
+  pub limit: Option<i64>,
+  pub page_cursor: Option<ModlogCursor>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Your warnings, newest first.
+pub struct ListWarningsResponse {
+  pub warnings: Vec<ModWarningView>,
+  pub next_page: Option<ModlogCursor>,
+}
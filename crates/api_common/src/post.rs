@@ -1,5 +1,16 @@
 use lemmy_db_schema::{
-  newtypes::{CommentId, CommunityId, DbUrl, LanguageId, PostId, PostReportId},
+  newtypes::{
+    CommentId,
+    CommunityId,
+    CommunityPostTagId,
+    DbUrl,
+    LanguageId,
+    MultiCommunityId,
+    PollOptionId,
+    PostId,
+    PostReportId,
+  },
+  source::{community_post_tag::CommunityPostTag, post::PostRevision},
   ListingType,
   PostFeatureType,
   SortType,
@@ -28,6 +39,26 @@ pub struct CreatePost {
   pub honeypot: Option<String>,
   pub nsfw: Option<bool>,
   pub language_id: Option<LanguageId>,
+  /// Tags of the community which apply to this post.
+  pub tags: Option<Vec<CommunityPostTagId>>,
+  /// If given, the post stays hidden until this time (unix timestamp), and is then published.
+  pub scheduled_publish_time: Option<i64>,
+  /// Attach a poll to the post.
+  pub poll: Option<CreatePoll>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A poll which is created together with a post.
+pub struct CreatePoll {
+  pub options: Vec<String>,
+  pub multiple_choice: Option<bool>,
+  /// No more votes are accepted after this time (unix timestamp).
+  pub end_time: Option<i64>,
+  /// Hide the vote counts until the poll is closed. Requires an end time.
+  pub hide_results: Option<bool>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -57,6 +88,7 @@ pub struct GetPostResponse {
   pub moderators: Vec<CommunityModeratorView>,
   /// A list of cross-posts, or other times / communities this link has been posted to.
   pub cross_posts: Vec<PostView>,
+  pub tags: Vec<CommunityPostTag>,
 }
 
 #[skip_serializing_none]
@@ -72,6 +104,10 @@ pub struct GetPosts {
   pub limit: Option<i64>,
   pub community_id: Option<CommunityId>,
   pub community_name: Option<String>,
+  /// Only show posts from the communities of this multi-community
+  pub multi_community_id: Option<MultiCommunityId>,
+  /// Only show posts which have this community post tag
+  pub tag_id: Option<CommunityPostTagId>,
   pub saved_only: Option<bool>,
   pub liked_only: Option<bool>,
   pub disliked_only: Option<bool>,
@@ -89,6 +125,59 @@ pub struct GetPostsResponse {
   pub next_page: Option<PaginationCursor>,
 }
 
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// List your posts which are scheduled to be published later.
+pub struct ListScheduledPosts {
+  pub page: Option<i64>,
+  pub limit: Option<i64>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The scheduled posts response.
+pub struct ListScheduledPostsResponse {
+  pub posts: Vec<PostView>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// List the earlier versions of a post.
+pub struct ListPostRevisions {
+  pub post_id: PostId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The post revisions response, oldest first.
+pub struct ListPostRevisionsResponse {
+  pub revisions: Vec<PostRevisionDiff>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// An earlier version of a post, with line diffs to the version which replaced it.
+pub struct PostRevisionDiff {
+  pub revision: PostRevision,
+  pub name_diff: String,
+  pub body_diff: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Vote in the poll of a post. An empty list of options removes your vote.
+pub struct VotePoll {
+  pub post_id: PostId,
+  pub option_ids: Vec<PollOptionId>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
 #[cfg_attr(feature = "full", derive(TS))]
 #[cfg_attr(feature = "full", ts(export))]
@@ -113,6 +202,12 @@ pub struct EditPost {
   pub body: Option<String>,
   pub nsfw: Option<bool>,
   pub language_id: Option<LanguageId>,
+  /// Replaces the tags of the post.
+  pub tags: Option<Vec<CommunityPostTagId>>,
+  /// Changes the publish time (unix timestamp) of a post which is still scheduled.
+  pub scheduled_publish_time: Option<i64>,
+  /// Publishes a post which is still scheduled right away.
+  pub publish_now: Option<bool>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
@@ -191,6 +286,7 @@ pub struct PostReportResponse {
   pub post_report_view: PostReportView,
 }
 
+#[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
 #[cfg_attr(feature = "full", derive(TS))]
 #[cfg_attr(feature = "full", ts(export))]
@@ -198,6 +294,8 @@ pub struct PostReportResponse {
 pub struct ResolvePostReport {
   pub report_id: PostReportId,
   pub resolved: bool,
+  /// Why the report was resolved. For posts and comments it is shown in the modlog.
+  pub reason: Option<String>,
 }
 
 #[skip_serializing_none]
@@ -5,6 +5,7 @@ use serde_with::skip_serializing_none;
 #[cfg(feature = "full")]
 use ts_rs::TS;
 
+#[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
 #[cfg_attr(feature = "full", derive(TS))]
 #[cfg_attr(feature = "full", ts(export))]
@@ -12,15 +13,24 @@ use ts_rs::TS;
 pub struct CreatePrivateMessage {
   pub content: String,
   pub recipient_id: PersonId,
+  /// Whether the content is ciphertext, encrypted with the `encryption_public_key` of the
+  /// recipient. Only possible for local recipients.
+  pub encrypted: Option<bool>,
+  /// Opaque data which the recipient needs for decryption.
+  pub encryption_metadata: Option<String>,
 }
 
+#[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
 #[cfg_attr(feature = "full", derive(TS))]
 #[cfg_attr(feature = "full", ts(export))]
 /// Edit a private message.
 pub struct EditPrivateMessage {
   pub private_message_id: PrivateMessageId,
+  /// For encrypted messages, this has to be ciphertext again.
   pub content: String,
+  /// Replaces the metadata of an encrypted message.
+  pub encryption_metadata: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
@@ -69,6 +79,7 @@ pub struct PrivateMessageResponse {
   pub private_message_view: PrivateMessageView,
 }
 
+#[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
 #[cfg_attr(feature = "full", derive(TS))]
 #[cfg_attr(feature = "full", ts(export))]
@@ -76,6 +87,8 @@ pub struct PrivateMessageResponse {
 pub struct CreatePrivateMessageReport {
   pub private_message_id: PrivateMessageId,
   pub reason: String,
+  /// The decrypted text of an encrypted message, so that admins can review it.
+  pub decrypted_text: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -86,6 +99,7 @@ pub struct PrivateMessageReportResponse {
   pub private_message_report_view: PrivateMessageReportView,
 }
 
+#[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
 #[cfg_attr(feature = "full", derive(TS))]
 #[cfg_attr(feature = "full", ts(export))]
@@ -93,6 +107,8 @@ pub struct PrivateMessageReportResponse {
 pub struct ResolvePrivateMessageReport {
   pub report_id: PrivateMessageReportId,
   pub resolved: bool,
+  /// Why the report was resolved.
+  pub reason: Option<String>,
 }
 
 #[skip_serializing_none]
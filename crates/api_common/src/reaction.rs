@@ -0,0 +1,75 @@
+use lemmy_db_schema::newtypes::{CommentId, PostId, ReactionId};
+use lemmy_db_views::structs::ReactionView;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Add or remove an emoji reaction on a post. The emoji is either a unicode emoji, or the
+/// shortcode of a custom emoji surrounded by colons, like `:lemmy:`.
+pub struct ReactToPost {
+  pub post_id: PostId,
+  pub emoji: String,
+  pub add: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Add or remove an emoji reaction on a comment.
+pub struct ReactToComment {
+  pub comment_id: CommentId,
+  pub emoji: String,
+  pub add: bool,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// List who reacted to a post or comment. Give either a `post_id` or a `comment_id`.
+pub struct ListReactions {
+  pub post_id: Option<PostId>,
+  pub comment_id: Option<CommentId>,
+  pub page: Option<i64>,
+  pub limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A list of reactions, newest first.
+pub struct ListReactionsResponse {
+  pub reactions: Vec<ReactionView>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Get the reactions of others to your posts and comments.
+pub struct GetReactions {
+  pub page: Option<i64>,
+  pub limit: Option<i64>,
+  pub unread_only: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Mark a reaction to your post or comment as read.
+pub struct MarkReactionAsRead {
+  pub reaction_id: ReactionId,
+  pub read: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The response for a reaction notification action.
+pub struct ReactionResponse {
+  pub reaction_view: ReactionView,
+}
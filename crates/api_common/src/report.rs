@@ -0,0 +1,100 @@
+use lemmy_db_schema::newtypes::{CommunityId, PersonId, ReportCombinedId};
+use lemmy_db_views::structs::{PaginationCursor, ReportCombinedView, ReportNoteView};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// List the report queue, where all reports about the same post, comment or private message are
+/// grouped into one entry.
+pub struct ListReports {
+  /// if no community is given, it returns reports for all communities moderated by the auth user
+  pub community_id: Option<CommunityId>,
+  /// Only shows the entries with unresolved reports
+  pub unresolved_only: Option<bool>,
+  /// Only shows the entries claimed by this mod
+  pub assignee_id: Option<PersonId>,
+  /// Only shows the entries which nobody claimed yet
+  pub unassigned_only: Option<bool>,
+  pub page_cursor: Option<PaginationCursor>,
+  pub limit: Option<i64>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The report queue response.
+pub struct ListReportsResponse {
+  pub reports: Vec<ReportCombinedView>,
+  /// the pagination cursor to use to fetch the next page
+  pub next_page: Option<PaginationCursor>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A report queue entry response.
+pub struct ReportCombinedResponse {
+  pub report_combined_view: ReportCombinedView,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Claim a report queue entry, or assign it to another mod. Without an assignee, the entry is
+/// released.
+pub struct AssignReport {
+  pub report_combined_id: ReportCombinedId,
+  pub assignee_id: Option<PersonId>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Resolve or reopen all reports of a report queue entry at once.
+pub struct ResolveReports {
+  pub report_combined_id: ReportCombinedId,
+  pub resolved: bool,
+  /// Shown in the modlog for posts and comments.
+  pub reason: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Add an internal note to a report queue entry, which only the mods can see.
+pub struct CreateReportNote {
+  pub report_combined_id: ReportCombinedId,
+  pub content: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A response for a note about a report.
+pub struct ReportNoteResponse {
+  pub report_note_view: ReportNoteView,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// List the notes of a report queue entry.
+pub struct ListReportNotes {
+  pub report_combined_id: ReportCombinedId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The notes of a report queue entry, oldest first.
+pub struct ListReportNotesResponse {
+  pub report_notes: Vec<ReportNoteView>,
+}
@@ -10,9 +10,12 @@ use lemmy_db_schema::{
   newtypes::{CommunityId, DbUrl, PersonId},
   source::{
     comment::Comment,
+    comment_report::CommentReport,
     community::Community,
+    custom_emoji::CustomEmoji,
     person::Person,
     post::Post,
+    post_report::PostReport,
     private_message::PrivateMessage,
   },
 };
@@ -48,7 +51,10 @@ pub enum SendActivityData {
   DeleteComment(Comment, Person, Community),
   RemoveComment(Comment, Person, Community, Option<String>),
   LikePostOrComment(DbUrl, Person, Community, i16),
+  ReactPostOrComment(DbUrl, Person, Community, String, Option<CustomEmoji>, bool),
   FollowCommunity(Community, Person, bool),
+  AcceptFollower(Community, Person),
+  FollowPerson(Person, Person, bool),
   UpdateCommunity(Person, Community),
   DeleteCommunity(Person, Community, bool),
   RemoveCommunity(Person, Community, Option<String>, bool),
@@ -59,7 +65,11 @@ pub enum SendActivityData {
   UpdatePrivateMessage(PrivateMessageView),
   DeletePrivateMessage(Person, PrivateMessage, bool),
   DeleteUser(Person, bool),
-  CreateReport(Url, Person, Community, String),
+  CreateReport(Url, Url, Person, Community, String),
+  ResolvePostReport(PostReport, Person, bool),
+  ResolveCommentReport(CommentReport, Person, bool),
+  VotePoll(Post, Person, Vec<String>),
+  WarnPerson(Url, Person, Person, Community, String),
 }
 
 // TODO: instead of static, move this into LemmyContext. make sure that stopping the process with
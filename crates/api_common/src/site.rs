@@ -1,6 +1,11 @@
 use lemmy_db_schema::{
   newtypes::{CommentId, CommunityId, InstanceId, LanguageId, PersonId, PostId},
-  source::{instance::Instance, language::Language, tagline::Tagline},
+  source::{
+    instance::Instance,
+    language::Language,
+    local_user_keyword_filter::LocalUserKeywordFilter,
+    tagline::Tagline,
+  },
   ListingType,
   ModlogActionType,
   RegistrationMode,
@@ -11,6 +16,7 @@ use lemmy_db_views::structs::{
   CommentView,
   CustomEmojiView,
   LocalUserView,
+  PaginationCursor,
   PostView,
   RegistrationApplicationView,
   SiteView,
@@ -21,26 +27,11 @@ use lemmy_db_views_actor::structs::{
   CommunityModeratorView,
   CommunityView,
   InstanceBlockView,
+  MultiCommunityView,
   PersonBlockView,
   PersonView,
 };
-use lemmy_db_views_moderator::structs::{
-  AdminPurgeCommentView,
-  AdminPurgeCommunityView,
-  AdminPurgePersonView,
-  AdminPurgePostView,
-  ModAddCommunityView,
-  ModAddView,
-  ModBanFromCommunityView,
-  ModBanView,
-  ModFeaturePostView,
-  ModHideCommunityView,
-  ModLockPostView,
-  ModRemoveCommentView,
-  ModRemoveCommunityView,
-  ModRemovePostView,
-  ModTransferCommunityView,
-};
+use lemmy_db_views_moderator::structs::{ModlogCursor, ModlogEntry};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 #[cfg(feature = "full")]
@@ -52,6 +43,8 @@ use ts_rs::TS;
 #[cfg_attr(feature = "full", ts(export))]
 /// Searches the site, given a query string, and some optional filters.
 pub struct Search {
+  /// The search query. Supports `"exact phrases"`, excluding words with `-word`, and filtering
+  /// with `community:name` or `author:name`.
   pub q: String,
   pub community_id: Option<CommunityId>,
   pub community_name: Option<String>,
@@ -66,8 +59,8 @@ pub struct Search {
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[cfg_attr(feature = "full", derive(TS))]
 #[cfg_attr(feature = "full", ts(export))]
-/// The search response, containing lists of the return type possibilities
-// TODO this should be redone as a list of tagged enums
+/// The search response, containing lists of the return type possibilities. Use `SearchCombined`
+/// to get a single list of tagged results instead.
 pub struct SearchResponse {
   pub type_: SearchType,
   pub comments: Vec<CommentView>,
@@ -76,6 +69,47 @@ pub struct SearchResponse {
   pub users: Vec<PersonView>,
 }
 
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Searches posts, comments, communities and users at once, returning a single list ordered by
+/// relevance.
+pub struct SearchCombined {
+  /// The search query, with the same syntax as for `Search`.
+  pub q: String,
+  pub community_id: Option<CommunityId>,
+  pub community_name: Option<String>,
+  pub creator_id: Option<PersonId>,
+  /// Only return content from this instance.
+  pub local_only: Option<bool>,
+  pub page_cursor: Option<PaginationCursor>,
+  pub limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+#[serde(tag = "type_")]
+/// A single item of a combined search.
+pub enum SearchResult {
+  Post(PostView),
+  Comment(CommentView),
+  Community(CommunityView),
+  Person(PersonView),
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The combined search response.
+pub struct SearchCombinedResponse {
+  pub results: Vec<SearchResult>,
+  /// the pagination cursor to use to fetch the next page
+  pub next_page: Option<PaginationCursor>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
 #[cfg_attr(feature = "full", derive(TS))]
 #[cfg_attr(feature = "full", ts(export))]
@@ -96,6 +130,7 @@ pub struct ResolveObjectResponse {
   pub post: Option<PostView>,
   pub community: Option<CommunityView>,
   pub person: Option<PersonView>,
+  pub multi_community: Option<MultiCommunityView>,
 }
 
 #[skip_serializing_none]
@@ -106,33 +141,27 @@ pub struct ResolveObjectResponse {
 pub struct GetModlog {
   pub mod_person_id: Option<PersonId>,
   pub community_id: Option<CommunityId>,
-  pub page: Option<i64>,
   pub limit: Option<i64>,
   pub type_: Option<ModlogActionType>,
+  /// The person who was affected by the action, like the banned person or the creator of a
+  /// removed post.
   pub other_person_id: Option<PersonId>,
+  /// Only return entries which were created at or after this unix time.
+  pub since: Option<i64>,
+  /// Only return entries which were created before this unix time.
+  pub until: Option<i64>,
+  pub page_cursor: Option<ModlogCursor>,
 }
 
+#[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[cfg_attr(feature = "full", derive(TS))]
 #[cfg_attr(feature = "full", ts(export))]
-/// The modlog fetch response.
-// TODO this should be redone as a list of tagged enums
+/// The modlog fetch response, newest entries first.
 pub struct GetModlogResponse {
-  pub removed_posts: Vec<ModRemovePostView>,
-  pub locked_posts: Vec<ModLockPostView>,
-  pub featured_posts: Vec<ModFeaturePostView>,
-  pub removed_comments: Vec<ModRemoveCommentView>,
-  pub removed_communities: Vec<ModRemoveCommunityView>,
-  pub banned_from_community: Vec<ModBanFromCommunityView>,
-  pub banned: Vec<ModBanView>,
-  pub added_to_community: Vec<ModAddCommunityView>,
-  pub transferred_to_community: Vec<ModTransferCommunityView>,
-  pub added: Vec<ModAddView>,
-  pub admin_purged_persons: Vec<AdminPurgePersonView>,
-  pub admin_purged_communities: Vec<AdminPurgeCommunityView>,
-  pub admin_purged_posts: Vec<AdminPurgePostView>,
-  pub admin_purged_comments: Vec<AdminPurgeCommentView>,
-  pub hidden_communities: Vec<ModHideCommunityView>,
+  pub modlog: Vec<ModlogEntry>,
+  /// Pass this as `page_cursor` to get the next page.
+  pub next_page: Option<ModlogCursor>,
 }
 
 #[skip_serializing_none]
@@ -308,6 +337,7 @@ pub struct MyUserInfo {
   pub community_blocks: Vec<CommunityBlockView>,
   pub instance_blocks: Vec<InstanceBlockView>,
   pub person_blocks: Vec<PersonBlockView>,
+  pub keyword_filters: Vec<LocalUserKeywordFilter>,
   pub discussion_languages: Vec<LanguageId>,
 }
 
@@ -1,51 +1,86 @@
 use crate::{
+  claims::UnsubscribeClaims,
   context::LemmyContext,
+  live_hub::LiveMessage,
+  post::CreatePoll,
   request::purge_image_from_pictrs,
+  send_activity::{ActivityChannel, SendActivityData},
   sensitive::Sensitive,
   site::FederatedInstances,
+  web_push::{PushMessage, PushMessageType},
 };
+use activitypub_federation::config::Data;
 use actix_web::cookie::{Cookie, SameSite};
 use anyhow::Context;
 use chrono::{DateTime, Days, Local, TimeZone, Utc};
 use lemmy_db_schema::{
-  newtypes::{CommunityId, DbUrl, PersonId, PostId},
+  aggregates::structs::PostAggregates,
+  newtypes::{CommunityId, CommunityPostTagId, ConversationId, DbUrl, PersonId, PostId},
   source::{
     comment::{Comment, CommentUpdateForm},
-    community::{Community, CommunityModerator, CommunityUpdateForm},
+    community::{Community, CommunityFollower, CommunityModerator, CommunityUpdateForm},
+    community_post_tag::CommunityPostTag,
+    conversation::{
+      Conversation,
+      ConversationInsertForm,
+      ConversationParticipant,
+      ConversationUpdateForm,
+    },
+    custom_emoji::CustomEmoji,
     email_verification::{EmailVerification, EmailVerificationForm},
     instance::Instance,
     local_site::LocalSite,
     local_site_rate_limit::LocalSiteRateLimit,
+    moderator::{ModResolveReport, ModResolveReportForm, ModWarning},
     password_reset_request::PasswordResetRequest,
     person::{Person, PersonUpdateForm},
     person_block::PersonBlock,
     post::{Post, PostRead},
+    private_message::PrivateMessage,
+    push_subscription::{PushNotification, PushNotificationForm, PushSubscription},
+    webhook::{Webhook, WebhookEvent, WebhookEventForm},
   },
   traits::Crud,
   utils::DbPool,
+  CommentSortType,
+  CommunityVisibility,
+  EmailDigestFrequency,
+  ListingType,
+  SortType,
+  WebhookEventType,
+};
+use lemmy_db_views::{
+  comment_view::CommentQuery,
+  post_view::PostQuery,
+  structs::{LocalUserView, PostView},
 };
-use lemmy_db_views::{comment_view::CommentQuery, structs::LocalUserView};
-use lemmy_db_views_actor::structs::{
-  CommunityModeratorView,
-  CommunityPersonBanView,
-  CommunityView,
+use lemmy_db_views_actor::{
+  comment_reply_view::CommentReplyQuery,
+  structs::{CommunityModeratorView, CommunityPersonBanView, CommunityView, PersonView},
 };
 use lemmy_utils::{
   email::{send_email, translations::Lang},
-  error::{LemmyError, LemmyErrorExt, LemmyErrorType, LemmyResult},
+  error::{LemmyError, LemmyErrorExt, LemmyErrorType, LemmyResult, MAX_API_PARAM_ELEMENTS},
   location_info,
   rate_limit::RateLimitConfig,
   settings::structs::Settings,
-  utils::slurs::build_slur_regex,
+  utils::{
+    slurs::build_slur_regex,
+    validation::{is_valid_emoji_reaction, is_valid_poll_options},
+  },
 };
 use regex::Regex;
 use rosetta_i18n::{Language, LanguageId};
-use std::collections::HashSet;
+use serde::Serialize;
+use std::{collections::HashSet, future::Future};
 use tracing::warn;
 use url::{ParseError, Url};
 
 pub static AUTH_COOKIE_NAME: &str = "auth";
 
+/// Longer bodies of push messages are cut off.
+const PUSH_MESSAGE_BODY_LENGTH: usize = 200;
+
 #[tracing::instrument(skip_all)]
 pub async fn is_mod_or_admin(
   pool: &mut DbPool<'_>,
@@ -180,6 +215,40 @@ async fn check_community_ban(
   Ok(())
 }
 
+/// Checks that the person can see the content of the community. Content of private communities
+/// is only visible to approved followers, moderators and admins, content of local-only
+/// communities only to logged in users.
+pub async fn check_community_content_visible(
+  person: Option<&Person>,
+  community: &Community,
+  pool: &mut DbPool<'_>,
+) -> LemmyResult<()> {
+  let visible = match (community.visibility, person) {
+    (CommunityVisibility::Public, _) => true,
+    (CommunityVisibility::LocalOnly, person) => person.is_some(),
+    (CommunityVisibility::Private, Some(person)) => {
+      CommunityFollower::is_approved_follower(pool, community.id, person.id).await?
+        || CommunityView::is_mod_or_admin(pool, person.id, community.id).await?
+    }
+    (CommunityVisibility::Private, None) => false,
+  };
+  if !visible {
+    Err(LemmyErrorType::CommunityContentNotVisible)?
+  }
+  Ok(())
+}
+
+/// Same as [`check_community_content_visible`], for content of which only the community id is
+/// at hand.
+pub async fn check_community_content_visible_by_id(
+  person: Option<&Person>,
+  community_id: CommunityId,
+  pool: &mut DbPool<'_>,
+) -> LemmyResult<()> {
+  let community = Community::read(pool, community_id).await?;
+  check_community_content_visible(person, &community, pool).await
+}
+
 /// Check that the given user can perform a mod action in the community.
 ///
 /// In particular it checks that he is an admin or mod, wasn't banned and the community isn't
@@ -221,6 +290,37 @@ pub fn check_post_deleted_or_removed(post: &Post) -> Result<(), LemmyError> {
   }
 }
 
+/// Scheduled posts only exist for their creator until they get published, so everyone else gets
+/// the same error as for a missing post.
+pub fn check_post_published(post: &Post, person_id: PersonId) -> Result<(), LemmyError> {
+  if post.scheduled_publish_time.is_some() && post.creator_id != person_id {
+    Err(LemmyErrorType::CouldntFindPost)?
+  } else {
+    Ok(())
+  }
+}
+
+/// Makes sure that all the given tags were defined by the community, and weren't deleted.
+pub async fn check_post_tags(
+  tag_ids: &[CommunityPostTagId],
+  community_id: CommunityId,
+  pool: &mut DbPool<'_>,
+) -> LemmyResult<()> {
+  if tag_ids.len() > MAX_API_PARAM_ELEMENTS {
+    Err(LemmyErrorType::TooManyItems)?;
+  }
+  let community_tags: HashSet<_> = CommunityPostTag::list_for_community(pool, community_id)
+    .await?
+    .into_iter()
+    .map(|t| t.id)
+    .collect();
+  if tag_ids.iter().all(|t| community_tags.contains(t)) {
+    Ok(())
+  } else {
+    Err(LemmyErrorType::InvalidPostTag)?
+  }
+}
+
 #[tracing::instrument(skip_all)]
 pub async fn check_person_block(
   my_id: PersonId,
@@ -246,6 +346,277 @@ pub fn check_downvotes_enabled(score: i16, local_site: &LocalSite) -> Result<(),
   }
 }
 
+/// Checks that an emoji can be used as a reaction in the community. Returns the custom emoji if the
+/// reaction is a shortcode, which has to be in one of the emoji sets available in the community.
+pub async fn check_emoji_reaction(
+  emoji: &str,
+  community_id: CommunityId,
+  pool: &mut DbPool<'_>,
+) -> LemmyResult<Option<CustomEmoji>> {
+  is_valid_emoji_reaction(emoji)?;
+  let Some(shortcode) = emoji.strip_prefix(':').and_then(|e| e.strip_suffix(':')) else {
+    return Ok(None);
+  };
+  let custom_emoji = CustomEmoji::list_for_community(pool, community_id)
+    .await?
+    .into_iter()
+    .find(|e| e.shortcode == shortcode)
+    .ok_or(LemmyErrorType::InvalidEmojiReaction)?;
+  Ok(Some(custom_emoji))
+}
+
+/// Queues an event for delivery to the webhooks which are subscribed to it. The data is only
+/// loaded if there are any. Errors are only logged, so that they don't affect the action which
+/// caused the event.
+pub async fn send_webhook_event<T, E>(
+  event_type: WebhookEventType,
+  community_id: Option<CommunityId>,
+  data: impl Future<Output = Result<T, E>>,
+  pool: &mut DbPool<'_>,
+) where
+  T: Serialize,
+  LemmyError: From<E>,
+{
+  let res: LemmyResult<()> = async {
+    if Webhook::is_subscribed(pool, event_type, community_id).await? {
+      let form = WebhookEventForm {
+        event_type,
+        community_id,
+        data: serde_json::to_value(data.await?)?,
+      };
+      WebhookEvent::create(pool, &form).await?;
+    }
+    Ok(())
+  }
+  .await;
+  if let Err(e) = res {
+    warn!("Failed to queue webhook event {event_type}: {e}");
+  }
+}
+
+/// Sends a scheduled post which just got published to the live feed, webhooks and other
+/// instances, like a newly created post.
+pub async fn announce_published_post(post: Post, context: &Data<LemmyContext>) -> LemmyResult<()> {
+  PostAggregates::update_ranks(&mut context.pool(), post.id)
+    .await
+    .map_err(|e| warn!("Failed to update ranks of scheduled post: {e}"))
+    .ok();
+  context.live().send(LiveMessage::Post {
+    community_id: post.community_id,
+    post_id: post.id,
+  });
+  send_webhook_event(
+    WebhookEventType::PostCreated,
+    Some(post.community_id),
+    PostView::read(&mut context.pool(), post.id, None, false),
+    &mut context.pool(),
+  )
+  .await;
+  ActivityChannel::submit_activity(SendActivityData::CreatePost(post.clone()), context).await?;
+  // Only a creator who is also a moderator can feature a post before it is published
+  if post.featured_community {
+    let creator = Person::read(&mut context.pool(), post.creator_id).await?;
+    ActivityChannel::submit_activity(SendActivityData::FeaturePost(post, creator, true), context)
+      .await?;
+  }
+  Ok(())
+}
+
+/// Queues a push message for the subscriptions of a local person which have its type enabled.
+/// Failures are only logged, so that they don't affect the action which caused the message.
+pub async fn send_push_message(person_id: PersonId, message: PushMessage, pool: &mut DbPool<'_>) {
+  let res: LemmyResult<()> = async {
+    let subscriptions = PushSubscription::list_for_person(pool, person_id)
+      .await?
+      .into_iter()
+      .filter(|s| match message.type_ {
+        PushMessageType::CommentReply => s.notify_replies,
+        PushMessageType::PersonMention => s.notify_mentions,
+        PushMessageType::PrivateMessage => s.notify_private_messages,
+        PushMessageType::Report => s.notify_reports,
+        // Warnings can't be turned off, as they might be followed by a ban
+        PushMessageType::Warning => true,
+      });
+    // The encrypted message has to fit into a single record of the push service
+    let message = PushMessage {
+      body: message
+        .body
+        .chars()
+        .take(PUSH_MESSAGE_BODY_LENGTH)
+        .collect(),
+      ..message
+    };
+    let payload = serde_json::to_string(&message)?;
+    let forms: Vec<_> = subscriptions
+      .map(|s| PushNotificationForm {
+        subscription_id: s.id,
+        payload: payload.clone(),
+      })
+      .collect();
+    if !forms.is_empty() {
+      PushNotification::create(pool, &forms).await?;
+    }
+    Ok(())
+  }
+  .await;
+  if let Err(e) = res {
+    warn!("Failed to queue push message for {person_id}: {e}");
+  }
+}
+
+/// Notifies the local participants of a conversation about a new message, except its creator.
+/// Participants who muted the conversation only get it over their live connections.
+pub async fn send_private_message_notifications(
+  private_message: &PrivateMessage,
+  creator: &Person,
+  context: &LemmyContext,
+) -> Result<(), LemmyError> {
+  let conversation_id = private_message.conversation_id;
+  let recipient_ids: Vec<PersonId> =
+    Conversation::list_participants(&mut context.pool(), conversation_id)
+      .await?
+      .into_iter()
+      .filter(|p| p.local && p.id != creator.id)
+      .map(|p| p.id)
+      .collect();
+  context.live().send(LiveMessage::PrivateMessage {
+    recipient_ids: recipient_ids.clone(),
+    private_message_id: private_message.id,
+  });
+
+  let url = format!("{}/inbox", context.settings().get_protocol_and_hostname());
+  // The server can't read encrypted messages
+  let body = if private_message.encrypted {
+    String::new()
+  } else {
+    private_message.content.clone()
+  };
+  for recipient_id in recipient_ids {
+    let muted = ConversationParticipant::read(&mut context.pool(), conversation_id, recipient_id)
+      .await?
+      .map(|p| p.muted)
+      .unwrap_or(false);
+    if muted {
+      continue;
+    }
+    let local_recipient = LocalUserView::read_person(&mut context.pool(), recipient_id).await?;
+    let lang = get_interface_language(&local_recipient);
+    let message = PushMessage {
+      type_: PushMessageType::PrivateMessage,
+      title: lang.notification_private_message_subject(&creator.name),
+      body: body.clone(),
+      url: url.clone(),
+    };
+    send_push_message(recipient_id, message, &mut context.pool()).await;
+  }
+  Ok(())
+}
+
+/// Notifies the local moderators of the community and the admins about a new report. Private
+/// message reports have no community, so they only go to admins.
+pub async fn send_new_report_notifications(
+  community_id: Option<CommunityId>,
+  reporter_username: &str,
+  reported_username: &str,
+  reason: &str,
+  context: &LemmyContext,
+) -> Result<(), LemmyError> {
+  context.live().send(LiveMessage::Report { community_id });
+
+  let mut recipient_ids: HashSet<PersonId> = PersonView::admins(&mut context.pool())
+    .await?
+    .into_iter()
+    .map(|a| a.person.id)
+    .collect();
+  if let Some(community_id) = community_id {
+    let moderators =
+      CommunityModeratorView::for_community(&mut context.pool(), community_id).await?;
+    recipient_ids.extend(
+      moderators
+        .into_iter()
+        .filter(|m| m.moderator.local)
+        .map(|m| m.moderator.id),
+    );
+  }
+
+  let settings = context.settings();
+  let url = format!("{}/reports", settings.get_protocol_and_hostname());
+  for recipient_id in recipient_ids {
+    let local_recipient = LocalUserView::read_person(&mut context.pool(), recipient_id).await?;
+    let lang = get_interface_language(&local_recipient);
+    let message = PushMessage {
+      type_: PushMessageType::Report,
+      title: lang.new_report_subject(&settings.hostname, reported_username, reporter_username),
+      body: reason.to_string(),
+      url: url.clone(),
+    };
+    send_push_message(recipient_id, message, &mut context.pool()).await;
+  }
+  Ok(())
+}
+
+/// Notifies a local person about a warning which they got from a moderator. Warnings aren't part
+/// of the translations yet.
+pub async fn send_warning_notifications(
+  mod_warning: &ModWarning,
+  community: &Community,
+  context: &LemmyContext,
+) -> Result<(), LemmyError> {
+  let recipient_id = mod_warning.other_person_id;
+  context.live().send(LiveMessage::Warning {
+    recipient_id,
+    mod_warning_id: mod_warning.id,
+  });
+
+  let protocol_and_hostname = context.settings().get_protocol_and_hostname();
+  let url = match mod_warning.comment_id {
+    Some(comment_id) => format!("{protocol_and_hostname}/comment/{comment_id}"),
+    None => format!("{protocol_and_hostname}/post/{}", mod_warning.post_id),
+  };
+  let title = format!("You were warned by the moderators of {}", community.title);
+  let message = PushMessage {
+    type_: PushMessageType::Warning,
+    title: title.clone(),
+    body: mod_warning.reason.clone(),
+    url: url.clone(),
+  };
+  send_push_message(recipient_id, message, &mut context.pool()).await;
+
+  let local_recipient = LocalUserView::read_person(&mut context.pool(), recipient_id).await?;
+  let body = format!(
+    "<h1>{}</h1><p>{}</p><a href=\"{url}\">{url}</a>",
+    escape_html(&title),
+    escape_html(&mod_warning.reason),
+  );
+  send_email_to_user(&local_recipient, &title, &body, context.settings()).await;
+  Ok(())
+}
+
+/// Adds resolving or reopening the reports about a post or comment to the modlog. Private message
+/// reports aren't logged, as the messages aren't public.
+pub async fn log_report_resolution(
+  mod_person_id: PersonId,
+  post: &Post,
+  comment: Option<&Comment>,
+  report_count: usize,
+  resolved: bool,
+  reason: Option<String>,
+  pool: &mut DbPool<'_>,
+) -> Result<(), LemmyError> {
+  let form = ModResolveReportForm {
+    mod_person_id,
+    other_person_id: comment.map_or(post.creator_id, |c| c.creator_id),
+    community_id: post.community_id,
+    post_id: post.id,
+    comment_id: comment.map(|c| c.id),
+    report_count: i32::try_from(report_count)?,
+    resolved: Some(resolved),
+    reason,
+  };
+  ModResolveReport::create(pool, &form).await?;
+  Ok(())
+}
+
 #[tracing::instrument(skip_all)]
 pub fn check_private_instance(
   local_user_view: &Option<LocalUserView>,
@@ -486,6 +857,106 @@ pub async fn send_new_report_email_to_admins(
   Ok(())
 }
 
+/// How many posts and replies are listed in an email digest, at most.
+const EMAIL_DIGEST_ITEMS: i64 = 10;
+
+/// Sends the email digest of a user, with the top posts of their subscribed communities and their
+/// unread replies. Nothing is sent if there is neither.
+pub async fn send_email_digest(
+  local_user_view: &LocalUserView,
+  context: &LemmyContext,
+) -> Result<(), LemmyError> {
+  let Some(email) = &local_user_view.local_user.email else {
+    return Ok(());
+  };
+  let person = &local_user_view.person;
+  let sort = match local_user_view.local_user.email_digest {
+    EmailDigestFrequency::Weekly => SortType::TopWeek,
+    _ => SortType::TopDay,
+  };
+  let posts = PostQuery {
+    listing_type: Some(ListingType::Subscribed),
+    sort: Some(sort),
+    local_user: Some(local_user_view),
+    limit: Some(EMAIL_DIGEST_ITEMS),
+    ..Default::default()
+  }
+  .list(&mut context.pool())
+  .await?;
+  let replies = CommentReplyQuery {
+    my_person_id: Some(person.id),
+    recipient_id: Some(person.id),
+    sort: Some(CommentSortType::New),
+    unread_only: true,
+    show_bot_accounts: local_user_view.local_user.show_bot_accounts,
+    page: None,
+    limit: Some(EMAIL_DIGEST_ITEMS),
+  }
+  .list(&mut context.pool())
+  .await?;
+  if posts.is_empty() && replies.is_empty() {
+    return Ok(());
+  }
+
+  let settings = context.settings();
+  let protocol_and_hostname = settings.get_protocol_and_hostname();
+  let lang = get_interface_language(local_user_view);
+  let mut body = String::new();
+  if !posts.is_empty() {
+    body.push_str(&format!("<h1>{}</h1><ul>", lang.email_digest_top_posts()));
+    for post_view in &posts {
+      body.push_str(&format!(
+        "<li><a href=\"{protocol_and_hostname}/post/{}\">{}</a> - {}</li>",
+        post_view.post.id,
+        escape_html(&post_view.post.name),
+        // The parameters of translations are in alphabetical order
+        escape_html(&lang.email_digest_post_details(
+          &post_view.counts.comments.to_string(),
+          &post_view.community.title,
+          &post_view.counts.score.to_string(),
+        )),
+      ));
+    }
+    body.push_str("</ul>");
+  }
+  if !replies.is_empty() {
+    body.push_str(&format!(
+      "<h1>{}</h1><ul>",
+      lang.email_digest_unread_replies()
+    ));
+    for reply_view in &replies {
+      let content: String = reply_view.comment.content.chars().take(200).collect();
+      body.push_str(&format!(
+        "<li><a href=\"{protocol_and_hostname}/comment/{}\">{}</a><div>{}</div></li>",
+        reply_view.comment.id,
+        escape_html(&lang.notification_comment_reply_subject(&reply_view.creator.name)),
+        escape_html(&content),
+      ));
+    }
+    body.push_str(&format!(
+      "</ul><a href=\"{protocol_and_hostname}/inbox\">{}</a>",
+      lang.email_digest_inbox()
+    ));
+  }
+  let token = UnsubscribeClaims::generate(local_user_view.local_user.id, context)?;
+  body.push_str(&format!(
+    "<p><a href=\"{protocol_and_hostname}/api/v3/user/email_digest/unsubscribe?token={token}\">{}</a></p>",
+    lang.email_digest_unsubscribe()
+  ));
+
+  let subject = lang.email_digest_subject(&settings.hostname);
+  send_email(&subject, email, &person.name, &body, settings).await
+}
+
+/// User content is inserted into the html of emails as text.
+fn escape_html(text: &str) -> String {
+  text
+    .replace('&', "&amp;")
+    .replace('<', "&lt;")
+    .replace('>', "&gt;")
+    .replace('"', "&quot;")
+}
+
 pub fn check_private_instance_and_federation_enabled(
   local_site: &LocalSite,
 ) -> Result<(), LemmyError> {
@@ -694,6 +1165,9 @@ pub enum EndpointType {
   Post,
   Comment,
   PrivateMessage,
+  MultiCommunity,
+  Conversation,
+  CustomEmoji,
 }
 
 /// Generates an apub endpoint for a given domain, IE xyz.tld
@@ -708,11 +1182,72 @@ pub fn generate_local_apub_endpoint(
     EndpointType::Post => "post",
     EndpointType::Comment => "comment",
     EndpointType::PrivateMessage => "private_message",
+    EndpointType::MultiCommunity => "m",
+    EndpointType::Conversation => "conversation",
+    EndpointType::CustomEmoji => "emoji",
   };
 
   Ok(Url::parse(&format!("{domain}/{point}/{name}"))?.into())
 }
 
+/// Creates a local conversation between the given persons.
+pub async fn create_local_conversation(
+  person_ids: &[PersonId],
+  context: &LemmyContext,
+) -> Result<Conversation, LemmyError> {
+  let conversation = Conversation::create(
+    &mut context.pool(),
+    &ConversationInsertForm::builder().build(),
+  )
+  .await
+  .with_lemmy_type(LemmyErrorType::CouldntCreateConversation)?;
+  let ap_id = generate_local_apub_endpoint(
+    EndpointType::Conversation,
+    &conversation.id.to_string(),
+    &context.settings().get_protocol_and_hostname(),
+  )?;
+  let conversation = Conversation::update(
+    &mut context.pool(),
+    conversation.id,
+    &ConversationUpdateForm {
+      ap_id: Some(ap_id),
+      ..Default::default()
+    },
+  )
+  .await
+  .with_lemmy_type(LemmyErrorType::CouldntCreateConversation)?;
+  ConversationParticipant::add(&mut context.pool(), conversation.id, person_ids)
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntCreateConversation)?;
+  Ok(conversation)
+}
+
+/// Returns the conversation which contains only the direct messages between two persons,
+/// creating it if necessary.
+pub async fn get_or_create_direct_conversation(
+  person_id: PersonId,
+  other_person_id: PersonId,
+  context: &LemmyContext,
+) -> Result<Conversation, LemmyError> {
+  let person_ids = [person_id, other_person_id];
+  match Conversation::read_for_participants(&mut context.pool(), &person_ids).await? {
+    Some(conversation) => Ok(conversation),
+    None => create_local_conversation(&person_ids, context).await,
+  }
+}
+
+/// Makes sure that the person takes part in the conversation.
+pub async fn check_conversation_participant(
+  conversation_id: ConversationId,
+  person_id: PersonId,
+  pool: &mut DbPool<'_>,
+) -> Result<ConversationParticipant, LemmyError> {
+  let participant = ConversationParticipant::read(pool, conversation_id, person_id)
+    .await?
+    .ok_or(LemmyErrorType::NotAConversationParticipant)?;
+  Ok(participant)
+}
+
 pub fn generate_followers_url(actor_id: &DbUrl) -> Result<DbUrl, ParseError> {
   Ok(Url::parse(&format!("{actor_id}/followers"))?.into())
 }
@@ -754,6 +1289,31 @@ pub fn generate_moderators_url(community_id: &DbUrl) -> Result<DbUrl, LemmyError
   Ok(Url::parse(&format!("{community_id}/moderators"))?.into())
 }
 
+pub fn generate_post_tag_url(
+  community_id: &DbUrl,
+  tag_id: CommunityPostTagId,
+) -> Result<DbUrl, LemmyError> {
+  Ok(Url::parse(&format!("{community_id}/tag/{}", tag_id.0))?.into())
+}
+
+pub fn generate_wiki_url(community_id: &DbUrl) -> Result<DbUrl, LemmyError> {
+  Ok(Url::parse(&format!("{community_id}/wiki"))?.into())
+}
+
+pub fn generate_wiki_page_url(community_id: &DbUrl, slug: &str) -> Result<DbUrl, LemmyError> {
+  Ok(Url::parse(&format!("{community_id}/wiki/{slug}"))?.into())
+}
+
+/// The id of the Flag activity which federates a new report. It is stored with the report, so
+/// that resolving it can refer to the same activity.
+pub fn generate_report_ap_id(protocol_and_hostname: &str) -> Result<DbUrl, ParseError> {
+  let id = format!(
+    "{protocol_and_hostname}/activities/flag/{}",
+    uuid::Uuid::new_v4()
+  );
+  Ok(Url::parse(&id)?.into())
+}
+
 pub fn create_login_cookie(jwt: Sensitive<String>) -> Cookie<'static> {
   let mut cookie = Cookie::new(AUTH_COOKIE_NAME, jwt.into_inner());
   cookie.set_secure(true);
@@ -777,6 +1337,50 @@ pub fn check_expire_time(expires_unix_opt: Option<i64>) -> LemmyResult<Option<Da
   }
 }
 
+/// Converts the publish time of a scheduled post, which must be in the future and at most a year
+/// ahead.
+pub fn check_scheduled_publish_time(
+  scheduled_unix_opt: Option<i64>,
+) -> LemmyResult<Option<DateTime<Utc>>> {
+  const MAX_SCHEDULE_TERM: Days = Days::new(365);
+
+  if let Some(scheduled_unix) = scheduled_unix_opt {
+    let scheduled = Utc
+      .timestamp_opt(scheduled_unix, 0)
+      .single()
+      .ok_or(LemmyErrorType::InvalidUnixTime)?;
+    if scheduled < Utc::now() || scheduled > Utc::now() + MAX_SCHEDULE_TERM {
+      Err(LemmyErrorType::InvalidScheduledPublishTime)?
+    }
+    Ok(Some(scheduled))
+  } else {
+    Ok(None)
+  }
+}
+
+/// Validates a new poll, and returns its converted end time.
+pub fn check_poll(poll: &CreatePoll) -> LemmyResult<Option<DateTime<Utc>>> {
+  is_valid_poll_options(&poll.options)?;
+  let end_time = match poll.end_time {
+    Some(end_unix) => {
+      let end_time = Utc
+        .timestamp_opt(end_unix, 0)
+        .single()
+        .ok_or(LemmyErrorType::InvalidUnixTime)?;
+      if end_time < Utc::now() {
+        Err(LemmyErrorType::InvalidPoll)?
+      }
+      Some(end_time)
+    }
+    None => None,
+  };
+  // Results which are hidden until the poll closes need a closing time
+  if poll.hide_results.unwrap_or(false) && end_time.is_none() {
+    Err(LemmyErrorType::InvalidPoll)?
+  }
+  Ok(end_time)
+}
+
 fn limit_expire_time(expires: DateTime<Utc>) -> LemmyResult<Option<DateTime<Utc>>> {
   const MAX_BAN_TERM: Days = Days::new(10 * 365);
 
@@ -794,7 +1398,12 @@ mod tests {
   #![allow(clippy::unwrap_used)]
   #![allow(clippy::indexing_slicing)]
 
-  use crate::utils::{honeypot_check, limit_expire_time, password_length_check};
+  use crate::utils::{
+    check_scheduled_publish_time,
+    honeypot_check,
+    limit_expire_time,
+    password_length_check,
+  };
   use chrono::{Days, Utc};
 
   #[test]
@@ -834,4 +1443,22 @@ mod tests {
       None
     );
   }
+  #[test]
+  fn test_scheduled_publish_time() {
+    assert_eq!(check_scheduled_publish_time(None).unwrap(), None);
+
+    let next_week = (Utc::now() + Days::new(7)).timestamp();
+    assert_eq!(
+      check_scheduled_publish_time(Some(next_week))
+        .unwrap()
+        .map(|t| t.timestamp()),
+      Some(next_week)
+    );
+
+    // Times in the past and too far in the future are rejected
+    let yesterday = (Utc::now() - Days::new(1)).timestamp();
+    assert!(check_scheduled_publish_time(Some(yesterday)).is_err());
+    let two_years = (Utc::now() + Days::new(2 * 365)).timestamp();
+    assert!(check_scheduled_publish_time(Some(two_years)).is_err());
+  }
 }
@@ -0,0 +1,92 @@
+use lemmy_db_schema::{newtypes::PushSubscriptionId, source::push_subscription::PushSubscription};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+use url::Url;
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Register a Web Push subscription, with the values of the `PushSubscription` which the browser
+/// returned. All notification types are enabled by default.
+pub struct CreatePushSubscription {
+  #[cfg_attr(feature = "full", ts(type = "string"))]
+  pub endpoint: Url,
+  pub p256dh: String,
+  pub auth: String,
+  pub notify_replies: Option<bool>,
+  pub notify_mentions: Option<bool>,
+  pub notify_private_messages: Option<bool>,
+  pub notify_reports: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Change which notifications a push subscription receives.
+pub struct EditPushSubscription {
+  pub push_subscription_id: PushSubscriptionId,
+  pub notify_replies: Option<bool>,
+  pub notify_mentions: Option<bool>,
+  pub notify_private_messages: Option<bool>,
+  pub notify_reports: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Delete a push subscription, for example when the user logs out.
+pub struct DeletePushSubscription {
+  pub push_subscription_id: PushSubscriptionId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A response for a push subscription.
+pub struct PushSubscriptionResponse {
+  pub push_subscription: PushSubscription,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The push subscriptions of your user.
+pub struct ListPushSubscriptionsResponse {
+  pub push_subscriptions: Vec<PushSubscription>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The public key which has to be passed to `PushManager.subscribe()` as
+/// `applicationServerKey`.
+pub struct GetVapidPublicKeyResponse {
+  pub vapid_public_key: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+pub enum PushMessageType {
+  CommentReply,
+  PersonMention,
+  PrivateMessage,
+  Report,
+  Warning,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The json content of a push message, once it is decrypted by the browser.
+pub struct PushMessage {
+  pub type_: PushMessageType,
+  pub title: String,
+  pub body: String,
+  /// The page which should be opened when the notification is clicked.
+  pub url: String,
+}
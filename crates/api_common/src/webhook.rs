@@ -0,0 +1,112 @@
+use lemmy_db_schema::{
+  newtypes::{CommunityId, WebhookId},
+  source::{
+    community::Community,
+    person::Person,
+    webhook::{Webhook, WebhookDelivery},
+  },
+  WebhookEventType,
+};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+use url::Url;
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Register a webhook. Payloads are signed with HMAC-SHA256 using the secret, the signature is
+/// sent in the `X-Lemmy-Signature` header.
+pub struct CreateWebhook {
+  /// Only receive the events of a community (only doable by its moderators), instead of the whole
+  /// site.
+  pub community_id: Option<CommunityId>,
+  #[cfg_attr(feature = "full", ts(type = "string"))]
+  pub url: Url,
+  pub secret: String,
+  pub events: Vec<WebhookEventType>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Edit a webhook.
+pub struct EditWebhook {
+  pub webhook_id: WebhookId,
+  #[cfg_attr(feature = "full", ts(type = "string"))]
+  pub url: Option<Url>,
+  pub secret: Option<String>,
+  pub events: Option<Vec<WebhookEventType>>,
+  pub enabled: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// Delete a webhook.
+pub struct DeleteWebhook {
+  pub webhook_id: WebhookId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A response for a webhook.
+pub struct WebhookResponse {
+  pub webhook: Webhook,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// List the webhooks of a community, or of the site if no community is given.
+pub struct ListWebhooks {
+  pub community_id: Option<CommunityId>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A list of webhooks.
+pub struct ListWebhooksResponse {
+  pub webhooks: Vec<Webhook>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// List the delivery attempts of a webhook.
+pub struct ListWebhookDeliveries {
+  pub webhook_id: WebhookId,
+  pub page: Option<i64>,
+  pub limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The delivery attempts of a webhook, newest first.
+pub struct ListWebhookDeliveriesResponse {
+  pub deliveries: Vec<WebhookDelivery>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// The data of a `PersonBanned` webhook event. The community is only set for bans from a
+/// community.
+pub struct PersonBannedEvent {
+  pub person: Person,
+  pub moderator: Person,
+  pub community: Option<Community>,
+  pub banned: bool,
+  pub reason: Option<String>,
+  /// The expire time in Unix seconds
+  pub expires: Option<i64>,
+}
@@ -6,11 +6,14 @@ use lemmy_api_common::{
   context::LemmyContext,
   send_activity::{ActivityChannel, SendActivityData},
   utils::{
+    check_community_content_visible,
     check_community_user_action,
     check_post_deleted_or_removed,
+    check_post_published,
     generate_local_apub_endpoint,
     get_post,
     local_site_to_slur_regex,
+    send_webhook_event,
     EndpointType,
   },
 };
@@ -20,12 +23,14 @@ use lemmy_db_schema::{
     actor_language::CommunityLanguage,
     comment::{Comment, CommentInsertForm, CommentLike, CommentLikeForm, CommentUpdateForm},
     comment_reply::{CommentReply, CommentReplyUpdateForm},
+    community::Community,
     local_site::LocalSite,
     person_mention::{PersonMention, PersonMentionUpdateForm},
   },
   traits::{Crud, Likeable},
+  WebhookEventType,
 };
-use lemmy_db_views::structs::LocalUserView;
+use lemmy_db_views::structs::{CommentView, LocalUserView};
 use lemmy_utils::{
   error::{LemmyError, LemmyErrorExt, LemmyErrorType},
   utils::{
@@ -54,9 +59,17 @@ pub async fn create_comment(
   // Check for a community ban
   let post_id = data.post_id;
   let post = get_post(post_id, &mut context.pool()).await?;
+  check_post_published(&post, local_user_view.person.id)?;
   let community_id = post.community_id;
 
   check_community_user_action(&local_user_view.person, community_id, &mut context.pool()).await?;
+  let community = Community::read(&mut context.pool(), community_id).await?;
+  check_community_content_visible(
+    Some(&local_user_view.person),
+    &community,
+    &mut context.pool(),
+  )
+  .await?;
   check_post_deleted_or_removed(&post)?;
 
   // Check if post is locked, no new comments
@@ -157,11 +170,22 @@ pub async fn create_comment(
     .await
     .with_lemmy_type(LemmyErrorType::CouldntLikeComment)?;
 
-  ActivityChannel::submit_activity(
-    SendActivityData::CreateComment(updated_comment.clone()),
-    &context,
-  )
-  .await?;
+  // Other instances only learn about scheduled posts once they get published, so comments of the
+  // creator before that stay local
+  if post.scheduled_publish_time.is_none() {
+    ActivityChannel::submit_activity(
+      SendActivityData::CreateComment(updated_comment.clone()),
+      &context,
+    )
+    .await?;
+    send_webhook_event(
+      WebhookEventType::CommentCreated,
+      Some(community_id),
+      CommentView::read(&mut context.pool(), updated_comment.id, None),
+      &mut context.pool(),
+    )
+    .await;
+  }
 
   // If its a reply, mark the parent as read
   if let Some(parent) = parent_opt {
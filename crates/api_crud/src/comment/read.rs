@@ -3,7 +3,7 @@ use lemmy_api_common::{
   build_response::build_comment_response,
   comment::{CommentResponse, GetComment},
   context::LemmyContext,
-  utils::check_private_instance,
+  utils::{check_community_content_visible, check_private_instance},
 };
 use lemmy_db_schema::source::local_site::LocalSite;
 use lemmy_db_views::structs::LocalUserView;
@@ -19,7 +19,14 @@ pub async fn get_comment(
 
   check_private_instance(&local_user_view, &local_site)?;
 
-  Ok(Json(
-    build_comment_response(&context, data.id, local_user_view, vec![]).await?,
-  ))
+  let person = local_user_view.as_ref().map(|l| l.person.clone());
+  let res = build_comment_response(&context, data.id, local_user_view, vec![]).await?;
+  check_community_content_visible(
+    person.as_ref(),
+    &res.comment_view.community,
+    &mut context.pool(),
+  )
+  .await?;
+
+  Ok(Json(res))
 }
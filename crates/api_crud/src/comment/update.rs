@@ -5,12 +5,12 @@ use lemmy_api_common::{
   comment::{CommentResponse, EditComment},
   context::LemmyContext,
   send_activity::{ActivityChannel, SendActivityData},
-  utils::{check_community_user_action, local_site_to_slur_regex},
+  utils::{check_community_content_visible, check_community_user_action, local_site_to_slur_regex},
 };
 use lemmy_db_schema::{
   source::{
     actor_language::CommunityLanguage,
-    comment::{Comment, CommentUpdateForm},
+    comment::{Comment, CommentRevision, CommentUpdateForm},
     local_site::LocalSite,
   },
   traits::Crud,
@@ -43,6 +43,12 @@ pub async fn update_comment(
     &mut context.pool(),
   )
   .await?;
+  check_community_content_visible(
+    Some(&local_user_view.person),
+    &orig_comment.community,
+    &mut context.pool(),
+  )
+  .await?;
 
   // Verify that only the creator can edit
   if local_user_view.person.id != orig_comment.creator.id {
@@ -74,6 +80,8 @@ pub async fn update_comment(
   let updated_comment = Comment::update(&mut context.pool(), comment_id, &form)
     .await
     .with_lemmy_type(LemmyErrorType::CouldntUpdateComment)?;
+  CommentRevision::create_for_edit(&mut context.pool(), &orig_comment.comment, &updated_comment)
+    .await?;
 
   // Do the mentions / recipients
   let updated_comment_content = updated_comment.content.clone();
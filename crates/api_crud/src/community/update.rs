@@ -48,6 +48,19 @@ pub async fn update_community(
   )
   .await?;
 
+  if [data.warning_ban_window_days, data.warning_ban_days]
+    .iter()
+    .flatten()
+    .any(|days| *days < 1)
+    || data.warning_ban_threshold.is_some_and(|t| t < 0)
+  {
+    Err(LemmyErrorType::InvalidWarningEscalation)?
+  }
+  // A threshold of 0 turns the automatic bans off
+  let warning_ban_threshold = data
+    .warning_ban_threshold
+    .map(|threshold| (threshold > 0).then_some(threshold));
+
   let community_id = data.community_id;
   if let Some(languages) = data.discussion_languages.clone() {
     let site_languages = SiteLanguage::read_local_raw(&mut context.pool()).await?;
@@ -67,6 +80,10 @@ pub async fn update_community(
     banner,
     nsfw: data.nsfw,
     posting_restricted_to_mods: data.posting_restricted_to_mods,
+    visibility: data.visibility,
+    warning_ban_threshold,
+    warning_ban_window_days: data.warning_ban_window_days,
+    warning_ban_days: data.warning_ban_days,
     updated: Some(Some(naive_now())),
     ..Default::default()
   };
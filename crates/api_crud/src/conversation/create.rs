@@ -0,0 +1,65 @@
+use crate::private_message::create::create_message;
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  context::LemmyContext,
+  conversation::{ConversationResponse, CreateConversation},
+  utils::{check_person_block, create_local_conversation, get_or_create_direct_conversation},
+};
+use lemmy_db_schema::{source::person::Person, traits::Crud};
+use lemmy_db_views::structs::{ConversationView, LocalUserView};
+use lemmy_utils::error::{LemmyError, LemmyErrorExt, LemmyErrorType};
+
+/// Maximum number of people in a conversation, including its creator.
+const MAX_CONVERSATION_PARTICIPANTS: usize = 50;
+
+#[tracing::instrument(skip(context))]
+pub async fn create_conversation(
+  data: Json<CreateConversation>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> Result<Json<ConversationResponse>, LemmyError> {
+  let my_person_id = local_user_view.person.id;
+  let mut participant_ids = data.participant_ids.clone();
+  participant_ids.retain(|p| p != &my_person_id);
+  participant_ids.sort_by_key(|p| p.0);
+  participant_ids.dedup();
+  if participant_ids.is_empty() || participant_ids.len() >= MAX_CONVERSATION_PARTICIPANTS {
+    Err(LemmyErrorType::InvalidConversationParticipants)?
+  }
+
+  for participant_id in &participant_ids {
+    Person::read(&mut context.pool(), *participant_id)
+      .await
+      .with_lemmy_type(LemmyErrorType::InvalidConversationParticipants)?;
+    check_person_block(my_person_id, *participant_id, &mut context.pool()).await?;
+  }
+
+  // With a single other participant, the message is a direct message
+  let (conversation, recipient_id) = match participant_ids.as_slice() {
+    [recipient_id] => (
+      get_or_create_direct_conversation(my_person_id, *recipient_id, &context).await?,
+      Some(*recipient_id),
+    ),
+    _ => {
+      participant_ids.push(my_person_id);
+      (
+        create_local_conversation(&participant_ids, &context).await?,
+        None,
+      )
+    }
+  };
+  create_message(
+    &data.content,
+    None,
+    recipient_id,
+    conversation.id,
+    &local_user_view,
+    &context,
+  )
+  .await?;
+
+  let conversation_view =
+    ConversationView::read(&mut context.pool(), conversation.id, my_person_id).await?;
+  Ok(Json(ConversationResponse { conversation_view }))
+}
@@ -0,0 +1,52 @@
+use crate::private_message::create::create_message;
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  context::LemmyContext,
+  conversation::CreateConversationMessage,
+  private_message::PrivateMessageResponse,
+  utils::{check_conversation_participant, check_person_block},
+};
+use lemmy_db_schema::source::conversation::Conversation;
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::LemmyError;
+
+#[tracing::instrument(skip(context))]
+pub async fn create_conversation_message(
+  data: Json<CreateConversationMessage>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> Result<Json<PrivateMessageResponse>, LemmyError> {
+  let my_person_id = local_user_view.person.id;
+  check_conversation_participant(data.conversation_id, my_person_id, &mut context.pool()).await?;
+
+  let others = Conversation::list_participants(&mut context.pool(), data.conversation_id)
+    .await?
+    .into_iter()
+    .filter(|p| p.id != my_person_id)
+    .collect::<Vec<_>>();
+
+  for other in &others {
+    check_person_block(my_person_id, other.id, &mut context.pool()).await?;
+  }
+
+  // Messages in a conversation with only one other person are direct messages
+  let recipient_id = match others.as_slice() {
+    [recipient] => Some(recipient.id),
+    _ => None,
+  };
+
+  let view = create_message(
+    &data.content,
+    None,
+    recipient_id,
+    data.conversation_id,
+    &local_user_view,
+    &context,
+  )
+  .await?;
+
+  Ok(Json(PrivateMessageResponse {
+    private_message_view: view,
+  }))
+}
@@ -0,0 +1,24 @@
+use actix_web::web::{Data, Json, Query};
+use lemmy_api_common::{
+  context::LemmyContext,
+  conversation::{ListConversations, ListConversationsResponse},
+};
+use lemmy_db_views::{conversation_view::ConversationQuery, structs::LocalUserView};
+use lemmy_utils::error::LemmyError;
+
+#[tracing::instrument(skip(context))]
+pub async fn list_conversations(
+  data: Query<ListConversations>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> Result<Json<ListConversationsResponse>, LemmyError> {
+  let conversations = ConversationQuery {
+    unread_only: data.unread_only.unwrap_or_default(),
+    page: data.page,
+    limit: data.limit,
+  }
+  .list(&mut context.pool(), local_user_view.person.id)
+  .await?;
+
+  Ok(Json(ListConversationsResponse { conversations }))
+}
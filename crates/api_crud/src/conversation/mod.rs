@@ -0,0 +1,4 @@
+pub mod create;
+pub mod create_message;
+pub mod list;
+pub mod read;
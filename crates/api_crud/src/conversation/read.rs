@@ -0,0 +1,48 @@
+use actix_web::web::{Data, Json, Query};
+use lemmy_api_common::{
+  context::LemmyContext,
+  conversation::{GetConversation, GetConversationResponse},
+  utils::check_conversation_participant,
+};
+use lemmy_db_views::{
+  private_message_view::PrivateMessageQuery,
+  structs::{ConversationView, LocalUserView},
+};
+use lemmy_utils::error::LemmyError;
+
+#[tracing::instrument(skip(context))]
+pub async fn get_conversation(
+  data: Query<GetConversation>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> Result<Json<GetConversationResponse>, LemmyError> {
+  let person_id = local_user_view.person.id;
+  let participant =
+    check_conversation_participant(data.conversation_id, person_id, &mut context.pool()).await?;
+
+  let conversation_view =
+    ConversationView::read(&mut context.pool(), data.conversation_id, person_id).await?;
+  let mut private_messages = PrivateMessageQuery {
+    conversation_id: Some(data.conversation_id),
+    page: data.page,
+    limit: data.limit,
+    ..Default::default()
+  }
+  .list(&mut context.pool(), person_id)
+  .await?;
+
+  // The `read` column is only used for direct messages. Messages to a group are read once the
+  // participant has read the conversation after they were sent. Own messages are always read.
+  private_messages.iter_mut().for_each(|pmv| {
+    if pmv.creator.id == person_id {
+      pmv.private_message.read = true
+    } else if pmv.private_message.recipient_id.is_none() {
+      pmv.private_message.read = pmv.private_message.published <= participant.last_read
+    }
+  });
+
+  Ok(Json(GetConversationResponse {
+    conversation_view,
+    private_messages,
+  }))
+}
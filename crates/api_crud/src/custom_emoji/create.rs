@@ -1,17 +1,21 @@
+use crate::custom_emoji::check_custom_emoji_manage_allowed;
 use activitypub_federation::config::Data;
 use actix_web::web::Json;
 use lemmy_api_common::{
   context::LemmyContext,
   custom_emoji::{CreateCustomEmoji, CustomEmojiResponse},
-  utils::is_admin,
+  utils::{generate_local_apub_endpoint, EndpointType},
 };
 use lemmy_db_schema::source::{
-  custom_emoji::{CustomEmoji, CustomEmojiInsertForm},
+  custom_emoji::{CustomEmoji, CustomEmojiInsertForm, CustomEmojiUpdateForm},
   custom_emoji_keyword::{CustomEmojiKeyword, CustomEmojiKeywordInsertForm},
   local_site::LocalSite,
 };
 use lemmy_db_views::structs::{CustomEmojiView, LocalUserView};
-use lemmy_utils::error::LemmyError;
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorExt, LemmyErrorType},
+  utils::validation::is_valid_custom_emoji_shortcode,
+};
 
 #[tracing::instrument(skip(context))]
 pub async fn create_custom_emoji(
@@ -20,17 +24,43 @@ pub async fn create_custom_emoji(
   local_user_view: LocalUserView,
 ) -> Result<Json<CustomEmojiResponse>, LemmyError> {
   let local_site = LocalSite::read(&mut context.pool()).await?;
-  // Make sure user is an admin
-  is_admin(&local_user_view)?;
+  // Make sure user is an admin, or a mod when adding to a community
+  check_custom_emoji_manage_allowed(data.community_id, &local_user_view, &context).await?;
+
+  let shortcode = data.shortcode.to_lowercase().trim().to_string();
+  is_valid_custom_emoji_shortcode(&shortcode)?;
 
+  // Emojis belong either to the site or to a community
+  let local_site_id = if data.community_id.is_none() {
+    Some(local_site.id)
+  } else {
+    None
+  };
   let emoji_form = CustomEmojiInsertForm::builder()
-    .local_site_id(local_site.id)
-    .shortcode(data.shortcode.to_lowercase().trim().to_string())
+    .local_site_id(local_site_id)
+    .community_id(data.community_id)
+    .shortcode(shortcode)
     .alt_text(data.alt_text.to_string())
     .category(data.category.to_string())
     .image_url(data.clone().image_url.into())
     .build();
-  let emoji = CustomEmoji::create(&mut context.pool(), &emoji_form).await?;
+  let emoji = CustomEmoji::create(&mut context.pool(), &emoji_form)
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntCreateCustomEmoji)?;
+
+  let ap_id = generate_local_apub_endpoint(
+    EndpointType::CustomEmoji,
+    &emoji.id.0.to_string(),
+    &context.settings().get_protocol_and_hostname(),
+  )?;
+  let update_form = CustomEmojiUpdateForm::builder()
+    .image_url(emoji.image_url.clone())
+    .alt_text(emoji.alt_text.clone())
+    .category(emoji.category.clone())
+    .ap_id(Some(ap_id))
+    .build();
+  let emoji = CustomEmoji::update(&mut context.pool(), emoji.id, &update_form).await?;
+
   let mut keywords = vec![];
   for keyword in &data.keywords {
     let keyword_form = CustomEmojiKeywordInsertForm::builder()
@@ -1,9 +1,9 @@
+use crate::custom_emoji::check_custom_emoji_manage_allowed;
 use activitypub_federation::config::Data;
 use actix_web::web::Json;
 use lemmy_api_common::{
   context::LemmyContext,
   custom_emoji::{DeleteCustomEmoji, DeleteCustomEmojiResponse},
-  utils::is_admin,
 };
 use lemmy_db_schema::source::custom_emoji::CustomEmoji;
 use lemmy_db_views::structs::LocalUserView;
@@ -15,8 +15,9 @@ pub async fn delete_custom_emoji(
   context: Data<LemmyContext>,
   local_user_view: LocalUserView,
 ) -> Result<Json<DeleteCustomEmojiResponse>, LemmyError> {
-  // Make sure user is an admin
-  is_admin(&local_user_view)?;
+  let orig_emoji = CustomEmoji::read(&mut context.pool(), data.id).await?;
+  // Make sure user is an admin, or a mod of the community which the emoji belongs to
+  check_custom_emoji_manage_allowed(orig_emoji.community_id, &local_user_view, &context).await?;
   CustomEmoji::delete(&mut context.pool(), data.id).await?;
   Ok(Json(DeleteCustomEmojiResponse {
     id: data.id,
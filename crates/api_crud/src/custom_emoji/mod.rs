@@ -1,3 +1,25 @@
+use lemmy_api_common::{context::LemmyContext, utils::check_community_mod_action_opt};
+use lemmy_db_schema::{newtypes::CommunityId, source::community::Community, traits::Crud};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyErrorType, LemmyResult};
+
 pub mod create;
 pub mod delete;
 pub mod update;
+
+/// Emojis of the site are managed by admins, those of a community by its moderators.
+async fn check_custom_emoji_manage_allowed(
+  community_id: Option<CommunityId>,
+  local_user_view: &LocalUserView,
+  context: &LemmyContext,
+) -> LemmyResult<()> {
+  check_community_mod_action_opt(local_user_view, community_id, &mut context.pool()).await?;
+  if let Some(community_id) = community_id {
+    // Emojis of remote communities are managed on their home instance
+    let community = Community::read(&mut context.pool(), community_id).await?;
+    if !community.local {
+      Err(LemmyErrorType::ObjectNotLocal)?
+    }
+  }
+  Ok(())
+}
@@ -1,14 +1,13 @@
+use crate::custom_emoji::check_custom_emoji_manage_allowed;
 use activitypub_federation::config::Data;
 use actix_web::web::Json;
 use lemmy_api_common::{
   context::LemmyContext,
   custom_emoji::{CustomEmojiResponse, EditCustomEmoji},
-  utils::is_admin,
 };
 use lemmy_db_schema::source::{
   custom_emoji::{CustomEmoji, CustomEmojiUpdateForm},
   custom_emoji_keyword::{CustomEmojiKeyword, CustomEmojiKeywordInsertForm},
-  local_site::LocalSite,
 };
 use lemmy_db_views::structs::{CustomEmojiView, LocalUserView};
 use lemmy_utils::error::LemmyError;
@@ -19,12 +18,11 @@ pub async fn update_custom_emoji(
   context: Data<LemmyContext>,
   local_user_view: LocalUserView,
 ) -> Result<Json<CustomEmojiResponse>, LemmyError> {
-  let local_site = LocalSite::read(&mut context.pool()).await?;
-  // Make sure user is an admin
-  is_admin(&local_user_view)?;
+  let orig_emoji = CustomEmoji::read(&mut context.pool(), data.id).await?;
+  // Make sure user is an admin, or a mod of the community which the emoji belongs to
+  check_custom_emoji_manage_allowed(orig_emoji.community_id, &local_user_view, &context).await?;
 
   let emoji_form = CustomEmojiUpdateForm::builder()
-    .local_site_id(local_site.id)
     .alt_text(data.alt_text.to_string())
     .category(data.category.to_string())
     .image_url(data.clone().image_url.into())
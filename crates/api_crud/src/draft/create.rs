@@ -0,0 +1,57 @@
+use crate::draft::{check_draft_content, convert_unix_time};
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  context::LemmyContext,
+  draft::{CreateDraft, DraftResponse},
+};
+use lemmy_db_schema::source::draft::{Draft, DraftInsertForm};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyError, LemmyErrorExt, LemmyErrorType};
+
+#[tracing::instrument(skip(context))]
+pub async fn create_draft(
+  data: Json<CreateDraft>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> Result<Json<DraftResponse>, LemmyError> {
+  // Comment drafts belong to a post, post drafts to a community
+  let is_comment = data.post_id.is_some();
+  if (is_comment && data.community_id.is_some()) || (!is_comment && data.parent_id.is_some()) {
+    Err(LemmyErrorType::InvalidDraft)?
+  }
+  check_draft_content(
+    &data.name,
+    &data.url,
+    &data.body,
+    &data.tags,
+    &data.poll,
+    data.scheduled_publish_time,
+    is_comment,
+  )?;
+
+  let poll = data.poll.clone();
+  let draft_form = DraftInsertForm::builder()
+    .local_user_id(local_user_view.local_user.id)
+    .community_id(data.community_id)
+    .name(data.name.clone())
+    .url(data.url.clone().map(Into::into))
+    .body(data.body.clone())
+    .nsfw(data.nsfw)
+    .language_id(data.language_id)
+    .post_id(data.post_id)
+    .parent_id(data.parent_id)
+    .tags(data.tags.clone())
+    .scheduled_publish_time(convert_unix_time(data.scheduled_publish_time)?)
+    .poll_multiple_choice(poll.as_ref().and_then(|p| p.multiple_choice))
+    .poll_end_time(convert_unix_time(poll.as_ref().and_then(|p| p.end_time))?)
+    .poll_hide_results(poll.as_ref().and_then(|p| p.hide_results))
+    .poll_options(poll.map(|p| p.options))
+    .build();
+  let draft = Draft::create_within_limit(&mut context.pool(), &draft_form)
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntSaveDraft)?
+    .ok_or(LemmyErrorType::TooManyDrafts)?;
+
+  Ok(Json(DraftResponse { draft }))
+}
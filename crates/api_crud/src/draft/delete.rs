@@ -0,0 +1,25 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{context::LemmyContext, draft::DeleteDraft, SuccessResponse};
+use lemmy_db_schema::{source::draft::Draft, traits::Crud};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyError, LemmyErrorExt, LemmyErrorType};
+
+#[tracing::instrument(skip(context))]
+pub async fn delete_draft(
+  data: Json<DeleteDraft>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> Result<Json<SuccessResponse>, LemmyError> {
+  let draft_id = data.draft_id;
+  let orig_draft = Draft::read(&mut context.pool(), draft_id).await?;
+  if orig_draft.local_user_id != local_user_view.local_user.id {
+    Err(LemmyErrorType::NoDraftEditAllowed)?
+  }
+
+  Draft::delete(&mut context.pool(), draft_id)
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntSaveDraft)?;
+
+  Ok(Json(SuccessResponse::default()))
+}
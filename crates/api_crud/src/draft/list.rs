@@ -0,0 +1,25 @@
+use actix_web::web::{Data, Json, Query};
+use lemmy_api_common::{
+  context::LemmyContext,
+  draft::{ListDrafts, ListDraftsResponse},
+};
+use lemmy_db_schema::source::draft::Draft;
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::LemmyError;
+
+#[tracing::instrument(skip(context))]
+pub async fn list_drafts(
+  data: Query<ListDrafts>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> Result<Json<ListDraftsResponse>, LemmyError> {
+  let drafts = Draft::list_for_local_user(
+    &mut context.pool(),
+    local_user_view.local_user.id,
+    data.page,
+    data.limit,
+  )
+  .await?;
+
+  Ok(Json(ListDraftsResponse { drafts }))
+}
@@ -0,0 +1,65 @@
+use chrono::{DateTime, TimeZone, Utc};
+use lemmy_api_common::post::CreatePoll;
+use lemmy_db_schema::newtypes::CommunityPostTagId;
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult, MAX_API_PARAM_ELEMENTS},
+  utils::validation::{
+    check_url_scheme,
+    is_valid_body_field,
+    is_valid_poll_option,
+    is_valid_post_title,
+  },
+};
+use url::Url;
+
+pub mod create;
+pub mod delete;
+pub mod list;
+pub mod publish;
+pub mod update;
+
+/// A draft doesn't need to be complete until it gets published, but the fields which are set are
+/// validated like those of a post. Comment drafts can't have any of the post fields.
+fn check_draft_content(
+  name: &Option<String>,
+  url: &Option<Url>,
+  body: &Option<String>,
+  tags: &Option<Vec<CommunityPostTagId>>,
+  poll: &Option<CreatePoll>,
+  scheduled_publish_time: Option<i64>,
+  is_comment: bool,
+) -> LemmyResult<()> {
+  if is_comment && (tags.is_some() || poll.is_some() || scheduled_publish_time.is_some()) {
+    Err(LemmyErrorType::InvalidDraft)?
+  }
+  if is_comment && (name.is_some() || url.is_some()) {
+    Err(LemmyErrorType::InvalidDraft)?
+  }
+  // An empty name clears it
+  if let Some(name) = name.as_deref().filter(|n| !n.is_empty()) {
+    is_valid_post_title(name)?;
+  }
+  check_url_scheme(url)?;
+  is_valid_body_field(body, !is_comment)?;
+  let tag_count = tags.as_ref().map(Vec::len).unwrap_or_default();
+  let option_count = poll.as_ref().map(|p| p.options.len()).unwrap_or_default();
+  if tag_count > MAX_API_PARAM_ELEMENTS || option_count > MAX_API_PARAM_ELEMENTS {
+    Err(LemmyErrorType::TooManyItems)?
+  }
+  // The number of options is only checked when publishing, as the poll may be incomplete
+  poll
+    .iter()
+    .flat_map(|p| &p.options)
+    .try_for_each(|o| is_valid_poll_option(o))
+}
+
+fn convert_unix_time(unix_opt: Option<i64>) -> LemmyResult<Option<DateTime<Utc>>> {
+  unix_opt
+    .map(|unix| {
+      Utc
+        .timestamp_opt(unix, 0)
+        .single()
+        .ok_or(LemmyErrorType::InvalidUnixTime.into())
+    })
+    .transpose()
+}
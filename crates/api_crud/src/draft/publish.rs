@@ -0,0 +1,63 @@
+use crate::{comment::create::create_comment, post::create::create_post};
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  comment::CreateComment,
+  context::LemmyContext,
+  draft::{PublishDraft, PublishDraftResponse},
+  post::{CreatePoll, CreatePost},
+};
+use lemmy_db_schema::{source::draft::Draft, traits::Crud};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyError, LemmyErrorType};
+
+/// Publishes a draft through the regular post or comment creation, so that it goes through all
+/// the usual checks. The draft is only removed if that succeeds.
+#[tracing::instrument(skip(context))]
+pub async fn publish_draft(
+  data: Json<PublishDraft>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> Result<Json<PublishDraftResponse>, LemmyError> {
+  let draft_id = data.draft_id;
+  let draft = Draft::read(&mut context.pool(), draft_id).await?;
+  if draft.local_user_id != local_user_view.local_user.id {
+    Err(LemmyErrorType::NoDraftEditAllowed)?
+  }
+
+  let res = if let Some(post_id) = draft.post_id {
+    let form = CreateComment {
+      content: draft.body.unwrap_or_default(),
+      post_id,
+      parent_id: draft.parent_id,
+      language_id: draft.language_id,
+    };
+    let res = create_comment(Json(form), context.reset_request_count(), local_user_view).await?;
+    PublishDraftResponse::Comment(res.0.comment_view)
+  } else {
+    let community_id = draft.community_id.ok_or(LemmyErrorType::InvalidDraft)?;
+    let form = CreatePost {
+      name: draft.name.unwrap_or_default(),
+      community_id,
+      url: draft.url.map(Into::into),
+      body: draft.body,
+      nsfw: draft.nsfw,
+      language_id: draft.language_id,
+      tags: draft.tags,
+      scheduled_publish_time: draft.scheduled_publish_time.map(|t| t.timestamp()),
+      poll: draft.poll_options.map(|options| CreatePoll {
+        options,
+        multiple_choice: draft.poll_multiple_choice,
+        end_time: draft.poll_end_time.map(|t| t.timestamp()),
+        hide_results: draft.poll_hide_results,
+      }),
+      honeypot: None,
+    };
+    let res = create_post(Json(form), context.reset_request_count(), local_user_view).await?;
+    PublishDraftResponse::Post(res.0.post_view)
+  };
+
+  Draft::delete(&mut context.pool(), draft_id).await?;
+
+  Ok(Json(res))
+}
@@ -0,0 +1,64 @@
+use crate::draft::{check_draft_content, convert_unix_time};
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  context::LemmyContext,
+  draft::{DraftResponse, EditDraft},
+};
+use lemmy_db_schema::{
+  source::draft::{Draft, DraftUpdateForm},
+  traits::Crud,
+  utils::{diesel_option_overwrite, naive_now},
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyError, LemmyErrorExt, LemmyErrorType};
+
+#[tracing::instrument(skip(context))]
+pub async fn update_draft(
+  data: Json<EditDraft>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> Result<Json<DraftResponse>, LemmyError> {
+  let draft_id = data.draft_id;
+  let orig_draft = Draft::read(&mut context.pool(), draft_id).await?;
+  if orig_draft.local_user_id != local_user_view.local_user.id {
+    Err(LemmyErrorType::NoDraftEditAllowed)?
+  }
+  if orig_draft.is_comment() && data.community_id.is_some() {
+    Err(LemmyErrorType::InvalidDraft)?
+  }
+  check_draft_content(
+    &data.name,
+    &data.url,
+    &data.body,
+    &data.tags,
+    &data.poll,
+    data.scheduled_publish_time,
+    orig_draft.is_comment(),
+  )?;
+
+  let mut draft_form = DraftUpdateForm {
+    community_id: data.community_id.map(Some),
+    name: diesel_option_overwrite(data.name.clone()),
+    url: data.url.clone().map(|u| Some(u.into())),
+    body: diesel_option_overwrite(data.body.clone()),
+    nsfw: data.nsfw.map(Some),
+    language_id: data.language_id.map(Some),
+    updated: Some(Some(naive_now())),
+    tags: data.tags.clone().map(Some),
+    scheduled_publish_time: convert_unix_time(data.scheduled_publish_time)?.map(Some),
+    ..Default::default()
+  };
+  // A new poll replaces all the poll fields
+  if let Some(poll) = data.poll.clone() {
+    draft_form.poll_multiple_choice = Some(poll.multiple_choice);
+    draft_form.poll_end_time = Some(convert_unix_time(poll.end_time)?);
+    draft_form.poll_hide_results = Some(poll.hide_results);
+    draft_form.poll_options = Some(Some(poll.options));
+  }
+  let draft = Draft::update(&mut context.pool(), draft_id, &draft_form)
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntSaveDraft)?;
+
+  Ok(Json(DraftResponse { draft }))
+}
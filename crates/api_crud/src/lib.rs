@@ -1,6 +1,9 @@
 pub mod comment;
 pub mod community;
+pub mod conversation;
 pub mod custom_emoji;
+pub mod draft;
+pub mod multi_community;
 pub mod post;
 pub mod private_message;
 pub mod site;
@@ -0,0 +1,71 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  build_response::build_multi_community_response,
+  context::LemmyContext,
+  multi_community::{CreateMultiCommunity, MultiCommunityResponse},
+  utils::{generate_local_apub_endpoint, local_site_to_slur_regex, EndpointType},
+};
+use lemmy_db_schema::{
+  source::{
+    local_site::LocalSite,
+    multi_community::{MultiCommunity, MultiCommunityEntry, MultiCommunityInsertForm},
+  },
+  traits::Crud,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorExt, LemmyErrorType, MAX_API_PARAM_ELEMENTS},
+  utils::{
+    slurs::{check_slurs, check_slurs_opt},
+    validation::{is_valid_actor_name, is_valid_body_field},
+  },
+};
+
+#[tracing::instrument(skip(context))]
+pub async fn create_multi_community(
+  data: Json<CreateMultiCommunity>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> Result<Json<MultiCommunityResponse>, LemmyError> {
+  let local_site = LocalSite::read(&mut context.pool()).await?;
+
+  let slur_regex = local_site_to_slur_regex(&local_site);
+  check_slurs(&data.name, &slur_regex)?;
+  check_slurs(&data.title, &slur_regex)?;
+  check_slurs_opt(&data.description, &slur_regex)?;
+
+  is_valid_actor_name(&data.name, local_site.actor_name_max_length as usize)?;
+  is_valid_body_field(&data.description, false)?;
+  if data.community_ids.len() > MAX_API_PARAM_ELEMENTS {
+    Err(LemmyErrorType::TooManyItems)?;
+  }
+
+  let ap_id = generate_local_apub_endpoint(
+    EndpointType::MultiCommunity,
+    &data.name,
+    &context.settings().get_protocol_and_hostname(),
+  )?;
+
+  let multi_community_form = MultiCommunityInsertForm::builder()
+    .creator_id(local_user_view.person.id)
+    .instance_id(local_user_view.person.instance_id)
+    .name(data.name.clone())
+    .title(data.title.clone())
+    .description(data.description.clone())
+    .ap_id(Some(ap_id))
+    .build();
+  let multi_community = MultiCommunity::create(&mut context.pool(), &multi_community_form)
+    .await
+    .with_lemmy_type(LemmyErrorType::MultiCommunityAlreadyExists)?;
+
+  MultiCommunityEntry::update(
+    &mut context.pool(),
+    multi_community.id,
+    data.community_ids.clone(),
+  )
+  .await
+  .with_lemmy_type(LemmyErrorType::CouldntUpdateMultiCommunity)?;
+
+  build_multi_community_response(&context, multi_community.id).await
+}
@@ -0,0 +1,41 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  build_response::build_multi_community_response,
+  context::LemmyContext,
+  multi_community::{DeleteMultiCommunity, MultiCommunityResponse},
+};
+use lemmy_db_schema::{
+  source::multi_community::{MultiCommunity, MultiCommunityUpdateForm},
+  traits::Crud,
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyError, LemmyErrorExt, LemmyErrorType};
+
+#[tracing::instrument(skip(context))]
+pub async fn delete_multi_community(
+  data: Json<DeleteMultiCommunity>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> Result<Json<MultiCommunityResponse>, LemmyError> {
+  let multi_community_id = data.multi_community_id;
+  let orig_multi_community = MultiCommunity::read(&mut context.pool(), multi_community_id).await?;
+
+  // Only the creator can delete a multi-community
+  if orig_multi_community.creator_id != local_user_view.person.id {
+    Err(LemmyErrorType::NoMultiCommunityEditAllowed)?
+  }
+
+  MultiCommunity::update(
+    &mut context.pool(),
+    multi_community_id,
+    &MultiCommunityUpdateForm {
+      deleted: Some(data.deleted),
+      ..Default::default()
+    },
+  )
+  .await
+  .with_lemmy_type(LemmyErrorType::CouldntUpdateMultiCommunity)?;
+
+  build_multi_community_response(&context, multi_community_id).await
+}
@@ -0,0 +1,25 @@
+use actix_web::web::{Data, Json, Query};
+use lemmy_api_common::{
+  context::LemmyContext,
+  multi_community::{ListMultiCommunities, ListMultiCommunitiesResponse},
+  utils::check_private_instance,
+};
+use lemmy_db_schema::source::local_site::LocalSite;
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_db_views_actor::structs::MultiCommunityView;
+use lemmy_utils::error::LemmyError;
+
+#[tracing::instrument(skip(context))]
+pub async fn list_multi_communities(
+  data: Query<ListMultiCommunities>,
+  context: Data<LemmyContext>,
+  local_user_view: Option<LocalUserView>,
+) -> Result<Json<ListMultiCommunitiesResponse>, LemmyError> {
+  let local_site = LocalSite::read(&mut context.pool()).await?;
+  check_private_instance(&local_user_view, &local_site)?;
+
+  let multi_communities =
+    MultiCommunityView::list(&mut context.pool(), data.creator_id, data.page, data.limit).await?;
+
+  Ok(Json(ListMultiCommunitiesResponse { multi_communities }))
+}
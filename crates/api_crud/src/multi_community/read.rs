@@ -0,0 +1,37 @@
+use actix_web::web::{Data, Json, Query};
+use lemmy_api_common::{
+  build_response::build_multi_community_response,
+  context::LemmyContext,
+  multi_community::{GetMultiCommunity, MultiCommunityResponse},
+  utils::check_private_instance,
+};
+use lemmy_db_schema::source::{local_site::LocalSite, multi_community::MultiCommunity};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::error::{LemmyError, LemmyErrorType};
+
+#[tracing::instrument(skip(context))]
+pub async fn get_multi_community(
+  data: Query<GetMultiCommunity>,
+  context: Data<LemmyContext>,
+  local_user_view: Option<LocalUserView>,
+) -> Result<Json<MultiCommunityResponse>, LemmyError> {
+  let local_site = LocalSite::read(&mut context.pool()).await?;
+
+  if data.name.is_none() && data.id.is_none() {
+    Err(LemmyErrorType::NoIdGiven)?
+  }
+
+  check_private_instance(&local_user_view, &local_site)?;
+
+  let multi_community_id = match data.id {
+    Some(id) => id,
+    None => {
+      let name = data.name.clone().unwrap_or_default();
+      MultiCommunity::read_from_name(&mut context.pool(), &name, false)
+        .await?
+        .id
+    }
+  };
+
+  build_multi_community_response(&context, multi_community_id).await
+}
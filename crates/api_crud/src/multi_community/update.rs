@@ -0,0 +1,66 @@
+use activitypub_federation::config::Data;
+use actix_web::web::Json;
+use lemmy_api_common::{
+  build_response::build_multi_community_response,
+  context::LemmyContext,
+  multi_community::{EditMultiCommunity, MultiCommunityResponse},
+  utils::local_site_to_slur_regex,
+};
+use lemmy_db_schema::{
+  source::{
+    local_site::LocalSite,
+    multi_community::{MultiCommunity, MultiCommunityEntry, MultiCommunityUpdateForm},
+  },
+  traits::Crud,
+  utils::{diesel_option_overwrite, naive_now},
+};
+use lemmy_db_views::structs::LocalUserView;
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorExt, LemmyErrorType, MAX_API_PARAM_ELEMENTS},
+  utils::{slurs::check_slurs_opt, validation::is_valid_body_field},
+};
+
+#[tracing::instrument(skip(context))]
+pub async fn update_multi_community(
+  data: Json<EditMultiCommunity>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> Result<Json<MultiCommunityResponse>, LemmyError> {
+  let local_site = LocalSite::read(&mut context.pool()).await?;
+
+  let slur_regex = local_site_to_slur_regex(&local_site);
+  check_slurs_opt(&data.title, &slur_regex)?;
+  check_slurs_opt(&data.description, &slur_regex)?;
+  is_valid_body_field(&data.description, false)?;
+
+  let multi_community_id = data.multi_community_id;
+  let orig_multi_community = MultiCommunity::read(&mut context.pool(), multi_community_id).await?;
+  if orig_multi_community.creator_id != local_user_view.person.id {
+    Err(LemmyErrorType::NoMultiCommunityEditAllowed)?
+  }
+
+  if let Some(community_ids) = data.community_ids.clone() {
+    if community_ids.len() > MAX_API_PARAM_ELEMENTS {
+      Err(LemmyErrorType::TooManyItems)?;
+    }
+    MultiCommunityEntry::update(&mut context.pool(), multi_community_id, community_ids)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdateMultiCommunity)?;
+  }
+
+  let multi_community_form = MultiCommunityUpdateForm {
+    title: data.title.clone(),
+    description: diesel_option_overwrite(data.description.clone()),
+    updated: Some(Some(naive_now())),
+    ..Default::default()
+  };
+  MultiCommunity::update(
+    &mut context.pool(),
+    multi_community_id,
+    &multi_community_form,
+  )
+  .await
+  .with_lemmy_type(LemmyErrorType::CouldntUpdateMultiCommunity)?;
+
+  build_multi_community_response(&context, multi_community_id).await
+}
@@ -3,15 +3,21 @@ use actix_web::web::Json;
 use lemmy_api_common::{
   build_response::build_post_response,
   context::LemmyContext,
+  live_hub::LiveMessage,
   post::{CreatePost, PostResponse},
   request::fetch_site_data,
   send_activity::{ActivityChannel, SendActivityData},
   utils::{
+    check_community_content_visible,
     check_community_user_action,
+    check_poll,
+    check_post_tags,
+    check_scheduled_publish_time,
     generate_local_apub_endpoint,
     honeypot_check,
     local_site_to_slur_regex,
     mark_post_as_read,
+    send_webhook_event,
     EndpointType,
   },
 };
@@ -20,12 +26,15 @@ use lemmy_db_schema::{
   source::{
     actor_language::CommunityLanguage,
     community::Community,
+    community_post_tag::PostTag,
     local_site::LocalSite,
+    poll::{Poll, PollForm, PollOptionForm},
     post::{Post, PostInsertForm, PostLike, PostLikeForm, PostUpdateForm},
   },
   traits::{Crud, Likeable},
+  WebhookEventType,
 };
-use lemmy_db_views::structs::LocalUserView;
+use lemmy_db_views::structs::{LocalUserView, PostView};
 use lemmy_db_views_actor::structs::CommunityView;
 use lemmy_utils::{
   error::{LemmyError, LemmyErrorExt, LemmyErrorType},
@@ -58,6 +67,13 @@ pub async fn create_post(
   is_valid_post_title(&data.name)?;
   is_valid_body_field(&data.body, true)?;
   check_url_scheme(&data.url)?;
+  let scheduled_publish_time = check_scheduled_publish_time(data.scheduled_publish_time)?;
+  let poll_end_time = data.poll.as_ref().map(check_poll).transpose()?.flatten();
+  if let Some(poll) = &data.poll {
+    for option in &poll.options {
+      check_slurs(option, &slur_regex)?;
+    }
+  }
 
   check_community_user_action(
     &local_user_view.person,
@@ -68,6 +84,12 @@ pub async fn create_post(
 
   let community_id = data.community_id;
   let community = Community::read(&mut context.pool(), community_id).await?;
+  check_community_content_visible(
+    Some(&local_user_view.person),
+    &community,
+    &mut context.pool(),
+  )
+  .await?;
   if community.posting_restricted_to_mods {
     let community_id = data.community_id;
     let is_mod = CommunityView::is_mod_or_admin(
@@ -81,6 +103,10 @@ pub async fn create_post(
     }
   }
 
+  if let Some(tags) = &data.tags {
+    check_post_tags(tags, community_id, &mut context.pool()).await?;
+  }
+
   // Fetch post links and pictrs cached image
   let (metadata_res, thumbnail_url) =
     fetch_site_data(context.client(), context.settings(), data_url, true).await;
@@ -122,6 +148,7 @@ pub async fn create_post(
     .embed_video_url(embed_video_url)
     .language_id(language_id)
     .thumbnail_url(thumbnail_url)
+    .scheduled_publish_time(scheduled_publish_time)
     .build();
 
   let inserted_post = Post::create(&mut context.pool(), &post_form)
@@ -146,6 +173,36 @@ pub async fn create_post(
   .await
   .with_lemmy_type(LemmyErrorType::CouldntCreatePost)?;
 
+  if let Some(tags) = data.tags.clone() {
+    PostTag::set(&mut context.pool(), inserted_post_id, tags)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreatePost)?;
+  }
+
+  if let Some(poll) = data.poll.clone() {
+    let poll_form = PollForm {
+      post_id: inserted_post_id,
+      multiple_choice: poll.multiple_choice.unwrap_or(false),
+      end_time: poll_end_time,
+      hide_results: poll.hide_results.unwrap_or(false),
+      voters_count: None,
+    };
+    let option_forms = poll
+      .options
+      .into_iter()
+      .enumerate()
+      .map(|(position, name)| PollOptionForm {
+        post_id: inserted_post_id,
+        name,
+        position: position as i32,
+        vote_count: None,
+      })
+      .collect();
+    Poll::upsert(&mut context.pool(), &poll_form, option_forms)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreatePost)?;
+  }
+
   // They like their own post by default
   let person_id = local_user_view.person.id;
   let post_id = inserted_post.id;
@@ -159,12 +216,28 @@ pub async fn create_post(
     .await
     .with_lemmy_type(LemmyErrorType::CouldntLikePost)?;
 
-  ActivityChannel::submit_activity(SendActivityData::CreatePost(updated_post.clone()), &context)
-    .await?;
-
   // Mark the post as read
   mark_post_as_read(person_id, post_id, &mut context.pool()).await?;
 
+  // Scheduled posts are federated by the scheduled task once they get published
+  if updated_post.scheduled_publish_time.is_some() {
+    return build_post_response(&context, community_id, &local_user_view.person, post_id).await;
+  }
+
+  ActivityChannel::submit_activity(SendActivityData::CreatePost(updated_post.clone()), &context)
+    .await?;
+  context.live().send(LiveMessage::Post {
+    community_id,
+    post_id,
+  });
+  send_webhook_event(
+    WebhookEventType::PostCreated,
+    Some(community_id),
+    PostView::read(&mut context.pool(), post_id, None, false),
+    &mut context.pool(),
+  )
+  .await;
+
   if let Some(url) = updated_post.url.clone() {
     spawn_try_task(async move {
       let mut webmention =
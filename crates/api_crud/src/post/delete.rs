@@ -51,11 +51,14 @@ pub async fn delete_post(
   )
   .await?;
 
-  ActivityChannel::submit_activity(
-    SendActivityData::DeletePost(post, local_user_view.person.clone(), data.0.clone()),
-    &context,
-  )
-  .await?;
+  // Deleting a scheduled post cancels it, which other instances never knew about
+  if post.scheduled_publish_time.is_none() {
+    ActivityChannel::submit_activity(
+      SendActivityData::DeletePost(post, local_user_view.person.clone(), data.0.clone()),
+      &context,
+    )
+    .await?;
+  }
 
   build_post_response(
     &context,
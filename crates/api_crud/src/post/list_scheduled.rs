@@ -0,0 +1,29 @@
+use actix_web::web::{Data, Json, Query};
+use lemmy_api_common::{
+  context::LemmyContext,
+  post::{ListScheduledPosts, ListScheduledPostsResponse},
+};
+use lemmy_db_schema::SortType;
+use lemmy_db_views::{post_view::PostQuery, structs::LocalUserView};
+use lemmy_utils::error::{LemmyError, LemmyErrorExt, LemmyErrorType};
+
+#[tracing::instrument(skip(context))]
+pub async fn list_scheduled_posts(
+  data: Query<ListScheduledPosts>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> Result<Json<ListScheduledPostsResponse>, LemmyError> {
+  let posts = PostQuery {
+    local_user: Some(&local_user_view),
+    sort: Some(SortType::New),
+    scheduled_only: true,
+    page: data.page,
+    limit: data.limit,
+    ..Default::default()
+  }
+  .list(&mut context.pool())
+  .await
+  .with_lemmy_type(LemmyErrorType::CouldntGetPosts)?;
+
+  Ok(Json(ListScheduledPostsResponse { posts }))
+}
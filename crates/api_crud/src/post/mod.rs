@@ -1,5 +1,6 @@
 pub mod create;
 pub mod delete;
+pub mod list_scheduled;
 pub mod read;
 pub mod remove;
 pub mod update;
@@ -2,11 +2,21 @@ use actix_web::web::{Data, Json, Query};
 use lemmy_api_common::{
   context::LemmyContext,
   post::{GetPost, GetPostResponse},
-  utils::{check_private_instance, is_mod_or_admin_opt, mark_post_as_read},
+  utils::{
+    check_community_content_visible,
+    check_private_instance,
+    is_mod_or_admin_opt,
+    mark_post_as_read,
+  },
 };
 use lemmy_db_schema::{
   aggregates::structs::{PersonPostAggregates, PersonPostAggregatesForm},
-  source::{comment::Comment, local_site::LocalSite, post::Post},
+  source::{
+    comment::Comment,
+    community_post_tag::CommunityPostTag,
+    local_site::LocalSite,
+    post::Post,
+  },
   traits::Crud,
 };
 use lemmy_db_views::{
@@ -53,6 +63,12 @@ pub async fn get_post(
   let post_view = PostView::read(&mut context.pool(), post_id, person_id, is_mod_or_admin)
     .await
     .with_lemmy_type(LemmyErrorType::CouldntFindPost)?;
+  check_community_content_visible(
+    local_user_view.as_ref().map(|l| &l.person),
+    &post_view.community,
+    &mut context.pool(),
+  )
+  .await?;
 
   // Mark the post as read
   let post_id = post_view.post.id;
@@ -86,6 +102,7 @@ pub async fn get_post(
   }
 
   let moderators = CommunityModeratorView::for_community(&mut context.pool(), community_id).await?;
+  let tags = CommunityPostTag::list_for_post(&mut context.pool(), post_id).await?;
 
   // Fetch the cross_posts
   let cross_posts = if let Some(url) = &post_view.post.url {
@@ -109,5 +126,6 @@ pub async fn get_post(
     community_view,
     moderators,
     cross_posts,
+    tags,
   }))
 }
@@ -6,13 +6,21 @@ use lemmy_api_common::{
   post::{EditPost, PostResponse},
   request::fetch_site_data,
   send_activity::{ActivityChannel, SendActivityData},
-  utils::{check_community_user_action, local_site_to_slur_regex},
+  utils::{
+    announce_published_post,
+    check_community_content_visible_by_id,
+    check_community_user_action,
+    check_post_tags,
+    check_scheduled_publish_time,
+    local_site_to_slur_regex,
+  },
 };
 use lemmy_db_schema::{
   source::{
     actor_language::CommunityLanguage,
+    community_post_tag::PostTag,
     local_site::LocalSite,
-    post::{Post, PostUpdateForm},
+    post::{Post, PostRevision, PostUpdateForm},
   },
   traits::Crud,
   utils::{diesel_option_overwrite, naive_now},
@@ -51,6 +59,7 @@ pub async fn update_post(
 
   is_valid_body_field(&data.body, true)?;
   check_url_scheme(&data.url)?;
+  let scheduled_publish_time = check_scheduled_publish_time(data.scheduled_publish_time)?;
 
   let post_id = data.post_id;
   let orig_post = Post::read(&mut context.pool(), post_id).await?;
@@ -61,12 +70,28 @@ pub async fn update_post(
     &mut context.pool(),
   )
   .await?;
+  check_community_content_visible_by_id(
+    Some(&local_user_view.person),
+    orig_post.community_id,
+    &mut context.pool(),
+  )
+  .await?;
 
   // Verify that only the creator can edit
   if !Post::is_post_creator(local_user_view.person.id, orig_post.creator_id) {
     Err(LemmyErrorType::NoPostEditAllowed)?
   }
 
+  // Posts which are already visible can't be scheduled anymore
+  let publish_now = data.publish_now.unwrap_or(false);
+  if (scheduled_publish_time.is_some() || publish_now) && orig_post.scheduled_publish_time.is_none()
+  {
+    Err(LemmyErrorType::PostAlreadyPublished)?
+  }
+  if scheduled_publish_time.is_some() && publish_now {
+    Err(LemmyErrorType::InvalidScheduledPublishTime)?
+  }
+
   // Fetch post links and Pictrs cached image
   let data_url = data.url.as_ref();
   let (metadata_res, thumbnail_url) =
@@ -83,6 +108,10 @@ pub async fn update_post(
   )
   .await?;
 
+  if let Some(tags) = &data.tags {
+    check_post_tags(tags, orig_post.community_id, &mut context.pool()).await?;
+  }
+
   let post_form = PostUpdateForm {
     name: data.name.clone(),
     url,
@@ -94,6 +123,7 @@ pub async fn update_post(
     language_id: data.language_id,
     thumbnail_url: Some(thumbnail_url),
     updated: Some(Some(naive_now())),
+    scheduled_publish_time: scheduled_publish_time.map(Some),
     ..Default::default()
   };
 
@@ -101,8 +131,23 @@ pub async fn update_post(
   let updated_post = Post::update(&mut context.pool(), post_id, &post_form)
     .await
     .with_lemmy_type(LemmyErrorType::CouldntUpdatePost)?;
+  PostRevision::create_for_edit(&mut context.pool(), &orig_post, &updated_post).await?;
 
-  ActivityChannel::submit_activity(SendActivityData::UpdatePost(updated_post), &context).await?;
+  if let Some(tags) = data.tags.clone() {
+    PostTag::set(&mut context.pool(), post_id, tags)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdatePost)?;
+  }
+
+  if publish_now {
+    let published_post = Post::publish_now(&mut context.pool(), post_id)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntUpdatePost)?;
+    announce_published_post(published_post, &context).await?;
+  } else if updated_post.scheduled_publish_time.is_none() {
+    // Scheduled posts are only federated once they get published
+    ActivityChannel::submit_activity(SendActivityData::UpdatePost(updated_post), &context).await?;
+  }
 
   build_post_response(
     context.deref(),
@@ -8,14 +8,19 @@ use lemmy_api_common::{
     check_person_block,
     generate_local_apub_endpoint,
     get_interface_language,
+    get_or_create_direct_conversation,
     local_site_to_slur_regex,
     send_email_to_user,
+    send_private_message_notifications,
     EndpointType,
   },
 };
 use lemmy_db_schema::{
+  newtypes::{ConversationId, PersonId},
   source::{
+    conversation::{Conversation, ConversationParticipant},
     local_site::LocalSite,
+    person::Person,
     private_message::{PrivateMessage, PrivateMessageInsertForm, PrivateMessageUpdateForm},
   },
   traits::Crud,
@@ -23,7 +28,11 @@ use lemmy_db_schema::{
 use lemmy_db_views::structs::{LocalUserView, PrivateMessageView};
 use lemmy_utils::{
   error::{LemmyError, LemmyErrorExt, LemmyErrorType},
-  utils::{markdown::markdown_to_html, slurs::remove_slurs, validation::is_valid_body_field},
+  utils::{
+    markdown::markdown_to_html,
+    slurs::remove_slurs,
+    validation::{is_valid_body_field, is_valid_encrypted_message},
+  },
 };
 
 #[tracing::instrument(skip(context))]
@@ -32,11 +41,6 @@ pub async fn create_private_message(
   context: Data<LemmyContext>,
   local_user_view: LocalUserView,
 ) -> Result<Json<PrivateMessageResponse>, LemmyError> {
-  let local_site = LocalSite::read(&mut context.pool()).await?;
-
-  let content = remove_slurs(&data.content, &local_site_to_slur_regex(&local_site));
-  is_valid_body_field(&Some(content.clone()), false)?;
-
   check_person_block(
     local_user_view.person.id,
     data.recipient_id,
@@ -44,10 +48,66 @@ pub async fn create_private_message(
   )
   .await?;
 
+  let encrypted = data.encrypted.unwrap_or_default();
+  if encrypted {
+    let recipient = Person::read(&mut context.pool(), data.recipient_id).await?;
+    if !recipient.local || recipient.encryption_public_key.is_none() {
+      Err(LemmyErrorType::RecipientCantReceiveEncryptedMessages)?
+    }
+  }
+
+  let conversation =
+    get_or_create_direct_conversation(local_user_view.person.id, data.recipient_id, &context)
+      .await?;
+
+  let view = create_message(
+    &data.content,
+    encrypted.then(|| data.encryption_metadata.clone()),
+    Some(data.recipient_id),
+    conversation.id,
+    &local_user_view,
+    &context,
+  )
+  .await?;
+
+  Ok(Json(PrivateMessageResponse {
+    private_message_view: view,
+  }))
+}
+
+/// Stores a new message in the conversation, emails the local participants and federates it.
+/// Direct messages have a `recipient_id`, messages to a group conversation don't.
+///
+/// For encrypted messages, `encryption` holds the metadata which the recipient needs to decrypt
+/// the content.
+pub(crate) async fn create_message(
+  content: &str,
+  encryption: Option<Option<String>>,
+  recipient_id: Option<PersonId>,
+  conversation_id: ConversationId,
+  local_user_view: &LocalUserView,
+  context: &Data<LemmyContext>,
+) -> Result<PrivateMessageView, LemmyError> {
+  let local_site = LocalSite::read(&mut context.pool()).await?;
+
+  let encrypted = encryption.is_some();
+  let encryption_metadata = encryption.flatten();
+  let content = if encrypted {
+    is_valid_encrypted_message(content, &encryption_metadata)?;
+    content.to_string()
+  } else {
+    let content = remove_slurs(content, &local_site_to_slur_regex(&local_site));
+    is_valid_body_field(&Some(content.clone()), false)?;
+    content
+  };
+
   let private_message_form = PrivateMessageInsertForm::builder()
     .content(content.clone())
     .creator_id(local_user_view.person.id)
-    .recipient_id(data.recipient_id)
+    .recipient_id(recipient_id)
+    .conversation_id(conversation_id)
+    .encrypted(Some(encrypted))
+    .encryption_metadata(encryption_metadata)
     .build();
 
   let inserted_private_message = PrivateMessage::create(&mut context.pool(), &private_message_form)
@@ -74,30 +134,49 @@ pub async fn create_private_message(
 
   let view = PrivateMessageView::read(&mut context.pool(), inserted_private_message.id).await?;
 
-  // Send email to the local recipient, if one exists
-  if view.recipient.local {
-    let recipient_id = data.recipient_id;
-    let local_recipient = LocalUserView::read_person(&mut context.pool(), recipient_id).await?;
+  // Send email to the local participants, unless they muted the conversation
+  let participants = Conversation::list_participants(&mut context.pool(), conversation_id).await?;
+  let inbox_link = format!("{}/inbox", context.settings().get_protocol_and_hostname());
+  let sender_name = &local_user_view.person.name;
+  // The server can't read encrypted messages, so they are left out of the email
+  let content = if encrypted {
+    String::new()
+  } else {
+    markdown_to_html(&content)
+  };
+  for participant in participants
+    .into_iter()
+    .filter(|p| p.local && p.id != local_user_view.person.id)
+  {
+    let muted = ConversationParticipant::read(&mut context.pool(), conversation_id, participant.id)
+      .await?
+      .map(|p| p.muted)
+      .unwrap_or(false);
+    if muted {
+      continue;
+    }
+    let local_recipient = LocalUserView::read_person(&mut context.pool(), participant.id).await?;
     let lang = get_interface_language(&local_recipient);
-    let inbox_link = format!("{}/inbox", context.settings().get_protocol_and_hostname());
-    let sender_name = &local_user_view.person.name;
-    let content = markdown_to_html(&content);
     send_email_to_user(
       &local_recipient,
       &lang.notification_private_message_subject(sender_name),
-      &lang.notification_private_message_body(inbox_link, &content, sender_name),
+      &lang.notification_private_message_body(&inbox_link, &content, sender_name),
       context.settings(),
     )
     .await;
   }
 
-  ActivityChannel::submit_activity(
-    SendActivityData::CreatePrivateMessage(view.clone()),
-    &context,
-  )
-  .await?;
+  send_private_message_notifications(&view.private_message, &local_user_view.person, context)
+    .await?;
 
-  Ok(Json(PrivateMessageResponse {
-    private_message_view: view,
-  }))
+  // Encrypted messages are only sent between local users
+  if !encrypted {
+    ActivityChannel::submit_activity(
+      SendActivityData::CreatePrivateMessage(view.clone()),
+      context,
+    )
+    .await?;
+  }
+
+  Ok(view)
 }
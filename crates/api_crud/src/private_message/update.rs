@@ -17,7 +17,10 @@ use lemmy_db_schema::{
 use lemmy_db_views::structs::{LocalUserView, PrivateMessageView};
 use lemmy_utils::{
   error::{LemmyError, LemmyErrorExt, LemmyErrorType},
-  utils::{slurs::remove_slurs, validation::is_valid_body_field},
+  utils::{
+    slurs::remove_slurs,
+    validation::{is_valid_body_field, is_valid_encrypted_message},
+  },
 };
 
 #[tracing::instrument(skip(context))]
@@ -36,29 +39,38 @@ pub async fn update_private_message(
   }
 
   // Doing the update
-  let content = remove_slurs(&data.content, &local_site_to_slur_regex(&local_site));
-  is_valid_body_field(&Some(content.clone()), false)?;
-
-  let private_message_id = data.private_message_id;
-  PrivateMessage::update(
-    &mut context.pool(),
-    private_message_id,
-    &PrivateMessageUpdateForm {
+  let form = if orig_private_message.encrypted {
+    is_valid_encrypted_message(&data.content, &data.encryption_metadata)?;
+    PrivateMessageUpdateForm {
+      content: Some(data.content.clone()),
+      encryption_metadata: Some(data.encryption_metadata.clone()),
+      updated: Some(Some(naive_now())),
+      ..Default::default()
+    }
+  } else {
+    let content = remove_slurs(&data.content, &local_site_to_slur_regex(&local_site));
+    is_valid_body_field(&Some(content.clone()), false)?;
+    PrivateMessageUpdateForm {
       content: Some(content),
       updated: Some(Some(naive_now())),
       ..Default::default()
-    },
-  )
-  .await
-  .with_lemmy_type(LemmyErrorType::CouldntUpdatePrivateMessage)?;
+    }
+  };
+
+  let private_message_id = data.private_message_id;
+  PrivateMessage::update(&mut context.pool(), private_message_id, &form)
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntUpdatePrivateMessage)?;
 
   let view = PrivateMessageView::read(&mut context.pool(), private_message_id).await?;
 
-  ActivityChannel::submit_activity(
-    SendActivityData::UpdatePrivateMessage(view.clone()),
-    &context,
-  )
-  .await?;
+  if !orig_private_message.encrypted {
+    ActivityChannel::submit_activity(
+      SendActivityData::UpdatePrivateMessage(view.clone()),
+      &context,
+    )
+    .await?;
+  }
 
   Ok(Json(PrivateMessageResponse {
     private_message_view: view,
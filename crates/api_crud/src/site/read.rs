@@ -6,6 +6,7 @@ use lemmy_api_common::{
 use lemmy_db_schema::source::{
   actor_language::{LocalUserLanguage, SiteLanguage},
   language::Language,
+  local_user_keyword_filter::LocalUserKeywordFilter,
   tagline::Tagline,
 };
 use lemmy_db_views::structs::{CustomEmojiView, LocalUserView, SiteView};
@@ -58,6 +59,10 @@ pub async fn get_site(
       .await
       .with_lemmy_type(LemmyErrorType::SystemErrLogin)?;
 
+    let keyword_filters = LocalUserKeywordFilter::list(&mut context.pool(), local_user_id)
+      .await
+      .with_lemmy_type(LemmyErrorType::SystemErrLogin)?;
+
     let discussion_languages = LocalUserLanguage::read(&mut context.pool(), local_user_id)
       .await
       .with_lemmy_type(LemmyErrorType::SystemErrLogin)?;
@@ -69,6 +74,7 @@ pub async fn get_site(
       community_blocks,
       instance_blocks,
       person_blocks,
+      keyword_filters,
       discussion_languages,
     })
   } else {
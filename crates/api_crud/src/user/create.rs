@@ -14,6 +14,7 @@ use lemmy_api_common::{
     password_length_check,
     send_new_applicant_email_to_admins,
     send_verification_email,
+    send_webhook_event,
     EndpointType,
   },
 };
@@ -27,8 +28,9 @@ use lemmy_db_schema::{
   },
   traits::Crud,
   RegistrationMode,
+  WebhookEventType,
 };
-use lemmy_db_views::structs::{LocalUserView, SiteView};
+use lemmy_db_views::structs::{LocalUserView, RegistrationApplicationView, SiteView};
 use lemmy_utils::{
   error::{LemmyError, LemmyErrorExt, LemmyErrorType},
   utils::{
@@ -149,7 +151,14 @@ pub async fn register(
       answer: data.answer.clone().expect("must have an answer"),
     };
 
-    RegistrationApplication::create(&mut context.pool(), &form).await?;
+    let application = RegistrationApplication::create(&mut context.pool(), &form).await?;
+    send_webhook_event(
+      WebhookEventType::RegistrationApplicationCreated,
+      None,
+      RegistrationApplicationView::read(&mut context.pool(), application.id),
+      &mut context.pool(),
+    )
+    .await;
   }
 
   // Email the admins, only if email verification is not required
@@ -105,7 +105,9 @@ impl AnnounceActivity {
   ) -> Result<(), LemmyError> {
     let announce = AnnounceActivity::new(object.clone(), community, context)?;
     let inboxes = ActivitySendTargets::to_local_community_followers(community.id);
-    send_lemmy_activity(context, announce, community, inboxes.clone(), false).await?;
+    // Activities of private communities must not be readable by anyone over HTTP
+    let sensitive = !community.visibility.can_view_without_membership();
+    send_lemmy_activity(context, announce, community, inboxes.clone(), sensitive).await?;
 
     // Pleroma and Mastodon can't handle activities like Announce/Create/Page. So for
     // compatibility, we also send Announce/Page so that they can follow Lemmy communities.
@@ -125,7 +127,7 @@ impl AnnounceActivity {
           .clone(),
       };
       let announce_compat = AnnounceActivity::new(announcable_page, community, context)?;
-      send_lemmy_activity(context, announce_compat, community, inboxes, false).await?;
+      send_lemmy_activity(context, announce_compat, community, inboxes, sensitive).await?;
     }
     Ok(())
   }
@@ -26,7 +26,7 @@ use lemmy_db_schema::{
   source::{
     activity::ActivitySendTargets,
     community::{Community, CommunityModerator, CommunityModeratorForm},
-    moderator::{ModAddCommunity, ModAddCommunityForm},
+    moderator::{ModAddCommunity, ModAddCommunityForm, ModFeaturePost, ModFeaturePostForm},
     post::{Post, PostUpdateForm},
   },
   traits::{Crud, Joinable},
@@ -155,6 +155,16 @@ impl ActivityHandler for CollectionRemove {
           ..Default::default()
         };
         Post::update(&mut context.pool(), post.id, &form).await?;
+
+        // write mod log
+        let actor = self.actor.dereference(context).await?;
+        let form = ModFeaturePostForm {
+          mod_person_id: actor.id,
+          post_id: post.id,
+          featured: false,
+          is_featured_community: true,
+        };
+        ModFeaturePost::create(&mut context.pool(), &form).await?;
       }
     }
     Ok(())
@@ -26,6 +26,7 @@ use lemmy_db_schema::{
   source::{
     activity::ActivitySendTargets,
     community::Community,
+    moderator::{ModLockPost, ModLockPostForm},
     person::Person,
     post::{Post, PostUpdateForm},
   },
@@ -63,6 +64,15 @@ impl ActivityHandler for LockPage {
     };
     let post = self.object.dereference(context).await?;
     Post::update(&mut context.pool(), post.id, &form).await?;
+
+    // write mod log
+    let actor = self.actor.dereference(context).await?;
+    let form = ModLockPostForm {
+      mod_person_id: actor.id,
+      post_id: post.id,
+      locked: Some(true),
+    };
+    ModLockPost::create(&mut context.pool(), &form).await?;
     Ok(())
   }
 }
@@ -97,6 +107,15 @@ impl ActivityHandler for UndoLockPage {
     };
     let post = self.object.object.dereference(context).await?;
     Post::update(&mut context.pool(), post.id, &form).await?;
+
+    // write mod log
+    let actor = self.actor.dereference(context).await?;
+    let form = ModLockPostForm {
+      mod_person_id: actor.id,
+      post_id: post.id,
+      locked: Some(false),
+    };
+    ModLockPost::create(&mut context.pool(), &form).await?;
     Ok(())
   }
 }
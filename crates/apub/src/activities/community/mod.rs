@@ -14,7 +14,9 @@ pub mod collection_add;
 pub mod collection_remove;
 pub mod lock_page;
 pub mod report;
+pub mod resolve_report;
 pub mod update;
+pub mod warn_user;
 
 /// This function sends all activities which are happening in a community to the right inboxes.
 /// For example Create/Page, Add/Mod etc, but not private messages.
@@ -23,6 +25,10 @@ pub mod update;
 /// is local, the activity is directly wrapped into Announce and sent to community followers.
 /// Activities are also sent to those who follow the actor (with exception of moderation activities).
 ///
+/// Activities of local-only communities aren't sent anywhere. Those of private communities only
+/// go to instances with approved followers, so the additional inboxes and actor followers are
+/// left out.
+///
 /// * `activity` - The activity which is being sent
 /// * `actor` - The user who is sending the activity
 /// * `community` - Community inside which the activity is sent
@@ -37,11 +43,20 @@ pub(crate) async fn send_activity_in_community(
   is_mod_action: bool,
   context: &Data<LemmyContext>,
 ) -> Result<(), LemmyError> {
+  if !community.visibility.can_federate() {
+    return Ok(());
+  }
+  let is_private = !community.visibility.can_view_without_membership();
+
   // send to any users which are mentioned or affected directly
-  let mut inboxes = extra_inboxes;
+  let mut inboxes = if is_private {
+    ActivitySendTargets::empty()
+  } else {
+    extra_inboxes
+  };
 
   // send to user followers
-  if !is_mod_action {
+  if !is_mod_action && !is_private {
     inboxes.add_inboxes(
       PersonFollower::list_followers(&mut context.pool(), actor.id)
         .await?
@@ -58,6 +73,6 @@ pub(crate) async fn send_activity_in_community(
     inboxes.add_inbox(community.shared_inbox_or_inbox());
   }
 
-  send_lemmy_activity(context, activity.clone(), actor, inboxes, false).await?;
+  send_lemmy_activity(context, activity.clone(), actor, inboxes, is_private).await?;
   Ok(())
 }
@@ -1,5 +1,5 @@
 use crate::{
-  activities::{generate_activity_id, send_lemmy_activity, verify_person_in_community},
+  activities::{send_lemmy_activity, verify_person_in_community},
   insert_received_activity,
   objects::{community::ApubCommunity, person::ApubPerson},
   protocol::{activities::community::report::Report, InCommunity},
@@ -11,7 +11,10 @@ use activitypub_federation::{
   kinds::activity::FlagType,
   traits::{ActivityHandler, Actor},
 };
-use lemmy_api_common::context::LemmyContext;
+use lemmy_api_common::{
+  context::LemmyContext,
+  utils::{send_new_report_notifications, send_webhook_event},
+};
 use lemmy_db_schema::{
   source::{
     activity::ActivitySendTargets,
@@ -20,14 +23,37 @@ use lemmy_db_schema::{
     person::Person,
     post_report::{PostReport, PostReportForm},
   },
-  traits::Reportable,
+  traits::{Crud, Reportable},
+  WebhookEventType,
 };
+use lemmy_db_views::structs::{CommentReportView, PostReportView};
 use lemmy_utils::error::LemmyError;
 use url::Url;
 
 impl Report {
+  /// Builds the Flag activity for a report. Its id is stored with the report, so that resolving
+  /// the report later can refer to it.
+  pub(crate) fn new(
+    id: Url,
+    object_id: ObjectId<PostOrComment>,
+    actor: &ApubPerson,
+    community: &ApubCommunity,
+    reason: String,
+  ) -> Report {
+    Report {
+      actor: actor.id().into(),
+      to: [community.id().into()],
+      object: object_id,
+      summary: reason,
+      kind: FlagType::Flag,
+      id,
+      audience: Some(community.id().into()),
+    }
+  }
+
   #[tracing::instrument(skip_all)]
   pub(crate) async fn send(
+    id: Url,
     object_id: ObjectId<PostOrComment>,
     actor: Person,
     community: Community,
@@ -36,20 +62,7 @@ impl Report {
   ) -> Result<(), LemmyError> {
     let actor: ApubPerson = actor.into();
     let community: ApubCommunity = community.into();
-    let kind = FlagType::Flag;
-    let id = generate_activity_id(
-      kind.clone(),
-      &context.settings().get_protocol_and_hostname(),
-    )?;
-    let report = Report {
-      actor: actor.id().into(),
-      to: [community.id().into()],
-      object: object_id,
-      summary: reason,
-      kind,
-      id: id.clone(),
-      audience: Some(community.id().into()),
-    };
+    let report = Report::new(id, object_id, &actor, &community, reason);
     let inbox = if community.local {
       ActivitySendTargets::empty()
     } else {
@@ -83,7 +96,8 @@ impl ActivityHandler for Report {
   #[tracing::instrument(skip_all)]
   async fn receive(self, context: &Data<Self::DataType>) -> Result<(), LemmyError> {
     let actor = self.actor.dereference(context).await?;
-    match self.object.dereference(context).await? {
+    let community = self.community(context).await?;
+    let reported_id = match self.object.dereference(context).await? {
       PostOrComment::Post(post) => {
         let report_form = PostReportForm {
           creator_id: actor.id,
@@ -92,8 +106,17 @@ impl ActivityHandler for Report {
           original_post_url: post.url.clone(),
           reason: self.summary.clone(),
           original_post_body: post.body.clone(),
+          ap_id: Some(self.id.clone().into()),
         };
-        PostReport::report(&mut context.pool(), &report_form).await?;
+        let report = PostReport::report(&mut context.pool(), &report_form).await?;
+        send_webhook_event(
+          WebhookEventType::ReportCreated,
+          Some(community.id),
+          PostReportView::read(&mut context.pool(), report.id, actor.id),
+          &mut context.pool(),
+        )
+        .await;
+        post.creator_id
       }
       PostOrComment::Comment(comment) => {
         let report_form = CommentReportForm {
@@ -101,10 +124,28 @@ impl ActivityHandler for Report {
           comment_id: comment.id,
           original_comment_text: comment.content.clone(),
           reason: self.summary.clone(),
+          ap_id: Some(self.id.clone().into()),
         };
-        CommentReport::report(&mut context.pool(), &report_form).await?;
+        let report = CommentReport::report(&mut context.pool(), &report_form).await?;
+        send_webhook_event(
+          WebhookEventType::ReportCreated,
+          Some(community.id),
+          CommentReportView::read(&mut context.pool(), report.id, actor.id),
+          &mut context.pool(),
+        )
+        .await;
+        comment.creator_id
       }
     };
+    let reported = Person::read(&mut context.pool(), reported_id).await?;
+    send_new_report_notifications(
+      Some(community.id),
+      &actor.name,
+      &reported.name,
+      &self.summary,
+      context,
+    )
+    .await?;
     Ok(())
   }
 }
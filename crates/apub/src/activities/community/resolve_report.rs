@@ -0,0 +1,469 @@
+use crate::{
+  activities::{
+    generate_activity_id,
+    send_lemmy_activity,
+    verify_mod_action,
+    verify_person_in_community,
+  },
+  insert_received_activity,
+  objects::{community::ApubCommunity, person::ApubPerson},
+  protocol::{
+    activities::community::{
+      report::Report,
+      resolve_report::{ResolveReport, ResolveType, UndoResolveReport},
+    },
+    InCommunity,
+  },
+};
+use activitypub_federation::{
+  config::Data,
+  fetch::object_id::ObjectId,
+  kinds::activity::UndoType,
+  traits::{ActivityHandler, Actor},
+};
+use lemmy_api_common::{
+  context::LemmyContext,
+  live_hub::LiveMessage,
+  utils::log_report_resolution,
+};
+use lemmy_db_schema::{
+  source::{
+    activity::ActivitySendTargets,
+    comment::Comment,
+    comment_report::CommentReport,
+    community::Community,
+    person::Person,
+    post::Post,
+    post_report::PostReport,
+  },
+  traits::{Crud, Reportable},
+};
+use lemmy_utils::error::{LemmyError, LemmyErrorType};
+use url::Url;
+
+#[async_trait::async_trait]
+impl ActivityHandler for ResolveReport {
+  type DataType = LemmyContext;
+  type Error = LemmyError;
+
+  fn id(&self) -> &Url {
+    &self.id
+  }
+
+  fn actor(&self) -> &Url {
+    self.actor.inner()
+  }
+
+  async fn verify(&self, context: &Data<Self::DataType>) -> Result<(), Self::Error> {
+    insert_received_activity(&self.id, context).await?;
+    let community = self.community(context).await?;
+    verify_person_in_community(&self.actor, &community, context).await?;
+    verify_mod_action(&self.actor, &community, context).await?;
+    Ok(())
+  }
+
+  async fn receive(self, context: &Data<Self::DataType>) -> Result<(), Self::Error> {
+    let community = self.community(context).await?;
+    let actor = self.actor.dereference(context).await?;
+    receive_resolve_report(
+      &self.object,
+      &community,
+      &actor,
+      self.summary,
+      true,
+      context,
+    )
+    .await
+  }
+}
+
+#[async_trait::async_trait]
+impl ActivityHandler for UndoResolveReport {
+  type DataType = LemmyContext;
+  type Error = LemmyError;
+
+  fn id(&self) -> &Url {
+    &self.id
+  }
+
+  fn actor(&self) -> &Url {
+    self.actor.inner()
+  }
+
+  async fn verify(&self, context: &Data<Self::DataType>) -> Result<(), Self::Error> {
+    insert_received_activity(&self.id, context).await?;
+    let community = self.community(context).await?;
+    verify_person_in_community(&self.actor, &community, context).await?;
+    verify_mod_action(&self.actor, &community, context).await?;
+    Ok(())
+  }
+
+  async fn receive(self, context: &Data<Self::DataType>) -> Result<(), Self::Error> {
+    let community = self.community(context).await?;
+    let actor = self.actor.dereference(context).await?;
+    receive_resolve_report(
+      &self.object.object,
+      &community,
+      &actor,
+      None,
+      false,
+      context,
+    )
+    .await
+  }
+}
+
+/// Resolves or reopens the report which was federated with the given Flag activity. Reports which
+/// this instance never received are ignored.
+async fn receive_resolve_report(
+  flag: &Report,
+  community: &ApubCommunity,
+  actor: &ApubPerson,
+  reason: Option<String>,
+  resolved: bool,
+  context: &Data<LemmyContext>,
+) -> Result<(), LemmyError> {
+  let (post, comment) = if let Some(report) =
+    PostReport::read_from_apub_id(&mut context.pool(), flag.id.clone()).await?
+  {
+    let post = Post::read(&mut context.pool(), report.post_id).await?;
+    // The mod was only checked against the community named in the activity
+    if post.community_id != community.id {
+      Err(LemmyErrorType::InvalidCommunity)?
+    }
+    if resolved {
+      PostReport::resolve(&mut context.pool(), report.id, actor.id, reason.clone()).await?;
+    } else {
+      PostReport::unresolve(&mut context.pool(), report.id, actor.id).await?;
+    }
+    (post, None)
+  } else if let Some(report) =
+    CommentReport::read_from_apub_id(&mut context.pool(), flag.id.clone()).await?
+  {
+    let comment = Comment::read(&mut context.pool(), report.comment_id).await?;
+    let post = Post::read(&mut context.pool(), comment.post_id).await?;
+    if post.community_id != community.id {
+      Err(LemmyErrorType::InvalidCommunity)?
+    }
+    if resolved {
+      CommentReport::resolve(&mut context.pool(), report.id, actor.id, reason.clone()).await?;
+    } else {
+      CommentReport::unresolve(&mut context.pool(), report.id, actor.id).await?;
+    }
+    (post, Some(comment))
+  } else {
+    return Ok(());
+  };
+
+  log_report_resolution(
+    actor.id,
+    &post,
+    comment.as_ref(),
+    1,
+    resolved,
+    reason,
+    &mut context.pool(),
+  )
+  .await?;
+  context.live().send(LiveMessage::Report {
+    community_id: Some(post.community_id),
+  });
+  Ok(())
+}
+
+pub(crate) async fn send_resolve_post_report(
+  report: PostReport,
+  actor: Person,
+  resolved: bool,
+  context: Data<LemmyContext>,
+) -> Result<(), LemmyError> {
+  // Reports from before the activity ids were stored can't be referred to
+  let Some(report_id) = report.ap_id else {
+    return Ok(());
+  };
+  let post = Post::read(&mut context.pool(), report.post_id).await?;
+  let reporter: ApubPerson = Person::read(&mut context.pool(), report.creator_id)
+    .await?
+    .into();
+  let community: ApubCommunity = Community::read(&mut context.pool(), post.community_id)
+    .await?
+    .into();
+  let flag = Report::new(
+    report_id.into(),
+    ObjectId::from(post.ap_id),
+    &reporter,
+    &community,
+    report.reason,
+  );
+  send_resolve_report(
+    flag,
+    reporter,
+    community,
+    report.resolution_reason,
+    actor,
+    resolved,
+    context,
+  )
+  .await
+}
+
+pub(crate) async fn send_resolve_comment_report(
+  report: CommentReport,
+  actor: Person,
+  resolved: bool,
+  context: Data<LemmyContext>,
+) -> Result<(), LemmyError> {
+  // Reports from before the activity ids were stored can't be referred to
+  let Some(report_id) = report.ap_id else {
+    return Ok(());
+  };
+  let comment = Comment::read(&mut context.pool(), report.comment_id).await?;
+  let post = Post::read(&mut context.pool(), comment.post_id).await?;
+  let reporter: ApubPerson = Person::read(&mut context.pool(), report.creator_id)
+    .await?
+    .into();
+  let community: ApubCommunity = Community::read(&mut context.pool(), post.community_id)
+    .await?
+    .into();
+  let flag = Report::new(
+    report_id.into(),
+    ObjectId::from(comment.ap_id),
+    &reporter,
+    &community,
+    report.reason,
+  );
+  send_resolve_report(
+    flag,
+    reporter,
+    community,
+    report.resolution_reason,
+    actor,
+    resolved,
+    context,
+  )
+  .await
+}
+
+/// Sends the resolution to the instances of the community and of the reporter, where the same
+/// report is stored.
+async fn send_resolve_report(
+  flag: Report,
+  reporter: ApubPerson,
+  community: ApubCommunity,
+  reason: Option<String>,
+  actor: Person,
+  resolved: bool,
+  context: Data<LemmyContext>,
+) -> Result<(), LemmyError> {
+  let mut inboxes = ActivitySendTargets::empty();
+  if !community.local {
+    inboxes.add_inbox(community.shared_inbox_or_inbox());
+  }
+  if !reporter.local {
+    inboxes.add_inbox(reporter.shared_inbox_or_inbox());
+  }
+
+  let actor: ApubPerson = actor.into();
+  let id = generate_activity_id(
+    ResolveType::Resolve,
+    &context.settings().get_protocol_and_hostname(),
+  )?;
+  let resolve = ResolveReport {
+    actor: actor.id().into(),
+    to: vec![reporter.id(), community.id()],
+    object: flag,
+    summary: reason,
+    kind: ResolveType::Resolve,
+    id,
+    audience: Some(community.id().into()),
+  };
+  if resolved {
+    send_lemmy_activity(&context, resolve, &actor, inboxes, false).await
+  } else {
+    let id = generate_activity_id(
+      UndoType::Undo,
+      &context.settings().get_protocol_and_hostname(),
+    )?;
+    let undo = UndoResolveReport {
+      actor: resolve.actor.clone(),
+      to: resolve.to.clone(),
+      kind: UndoType::Undo,
+      id,
+      audience: resolve.audience.clone(),
+      object: resolve,
+    };
+    send_lemmy_activity(&context, undo, &actor, inboxes, false).await
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  #![allow(clippy::unwrap_used)]
+  #![allow(clippy::indexing_slicing)]
+
+  use super::*;
+  use crate::objects::tests::init_context;
+  use lemmy_db_schema::{
+    newtypes::DbUrl,
+    source::{
+      community::{CommunityInsertForm, CommunityModerator, CommunityModeratorForm},
+      instance::Instance,
+      person::PersonInsertForm,
+      post::PostInsertForm,
+      post_report::PostReportForm,
+    },
+    traits::Joinable,
+  };
+  use serial_test::serial;
+
+  const REMOTE: &str = "https://remote.example";
+
+  fn url(url: &str) -> Url {
+    Url::parse(url).unwrap()
+  }
+
+  async fn create_person(name: &str, context: &Data<LemmyContext>) -> ApubPerson {
+    let instance = Instance::read_or_create(&mut context.pool(), "remote.example".to_string())
+      .await
+      .unwrap();
+    let form = PersonInsertForm::builder()
+      .name(name.to_string())
+      .public_key("pubkey".to_string())
+      .actor_id(Some(url(&format!("{REMOTE}/u/{name}")).into()))
+      .local(Some(false))
+      .instance_id(instance.id)
+      .build();
+    Person::create(&mut context.pool(), &form)
+      .await
+      .unwrap()
+      .into()
+  }
+
+  async fn create_community(name: &str, context: &Data<LemmyContext>) -> ApubCommunity {
+    let instance = Instance::read_or_create(&mut context.pool(), "example.com".to_string())
+      .await
+      .unwrap();
+    let form = CommunityInsertForm::builder()
+      .name(name.to_string())
+      .title(name.to_string())
+      .public_key("pubkey".to_string())
+      .actor_id(Some(url(&format!("https://example.com/c/{name}")).into()))
+      .instance_id(instance.id)
+      .build();
+    Community::create(&mut context.pool(), &form)
+      .await
+      .unwrap()
+      .into()
+  }
+
+  async fn create_report(
+    reporter: &ApubPerson,
+    community: &ApubCommunity,
+    context: &Data<LemmyContext>,
+  ) -> (Post, PostReport) {
+    let form = PostInsertForm::builder()
+      .name("A reported post".to_string())
+      .creator_id(reporter.id)
+      .community_id(community.id)
+      .build();
+    let post = Post::create(&mut context.pool(), &form).await.unwrap();
+    let ap_id: DbUrl = url(&format!("{REMOTE}/report/{}", post.id)).into();
+    let form = PostReportForm {
+      creator_id: reporter.id,
+      post_id: post.id,
+      original_post_name: post.name.clone(),
+      original_post_url: None,
+      original_post_body: None,
+      reason: "spam".to_string(),
+      ap_id: Some(ap_id),
+    };
+    let report = PostReport::report(&mut context.pool(), &form)
+      .await
+      .unwrap();
+    (post, report)
+  }
+
+  /// A resolution of the report, which claims to be about the given community
+  fn resolve_activity(
+    actor: &ApubPerson,
+    reporter: &ApubPerson,
+    community: &ApubCommunity,
+    post: &Post,
+    report: &PostReport,
+  ) -> ResolveReport {
+    let flag = Report::new(
+      report.ap_id.clone().unwrap().into(),
+      ObjectId::from(post.ap_id.clone()),
+      reporter,
+      community,
+      report.reason.clone(),
+    );
+    ResolveReport {
+      actor: actor.id().into(),
+      to: vec![reporter.id(), community.id()],
+      object: flag,
+      summary: Some("resolved".to_string()),
+      kind: ResolveType::Resolve,
+      id: generate_activity_id(ResolveType::Resolve, REMOTE).unwrap(),
+      audience: Some(community.id().into()),
+    }
+  }
+
+  async fn is_resolved(report: &PostReport, context: &Data<LemmyContext>) -> bool {
+    PostReport::read_from_apub_id(&mut context.pool(), report.ap_id.clone().unwrap().into())
+      .await
+      .unwrap()
+      .unwrap()
+      .resolved
+  }
+
+  #[tokio::test]
+  #[serial]
+  async fn test_receive_resolve_report() {
+    let context = init_context().await;
+    let mod_ = create_person("resolve_mod", &context).await;
+    let other = create_person("resolve_other", &context).await;
+    let reporter = create_person("resolve_reporter", &context).await;
+    let community = create_community("resolve_community", &context).await;
+    let other_community = create_community("resolve_other_community", &context).await;
+    let moderator_form = CommunityModeratorForm {
+      community_id: community.id,
+      person_id: mod_.id,
+    };
+    CommunityModerator::join(&mut context.pool(), &moderator_form)
+      .await
+      .unwrap();
+    let (post, report) = create_report(&reporter, &community, &context).await;
+    let (other_post, other_report) = create_report(&reporter, &other_community, &context).await;
+
+    // A person who doesn't moderate the community can't resolve its reports
+    let activity = resolve_activity(&other, &reporter, &community, &post, &report);
+    assert!(activity.verify(&context).await.is_err());
+    assert!(!is_resolved(&report, &context).await);
+
+    // The mod of one community can't resolve the reports of another community by naming their
+    // own community in the activity
+    let activity = resolve_activity(&mod_, &reporter, &community, &other_post, &other_report);
+    activity.verify(&context).await.unwrap();
+    assert!(activity.receive(&context).await.is_err());
+    assert!(!is_resolved(&other_report, &context).await);
+
+    // The mod can resolve the reports of their community
+    let activity = resolve_activity(&mod_, &reporter, &community, &post, &report);
+    activity.verify(&context).await.unwrap();
+    activity.receive(&context).await.unwrap();
+    assert!(is_resolved(&report, &context).await);
+    assert_eq!(0, context.request_count());
+
+    for person in [mod_, other, reporter] {
+      Person::delete(&mut context.pool(), person.id)
+        .await
+        .unwrap();
+    }
+    for community in [community, other_community] {
+      Community::delete(&mut context.pool(), community.id)
+        .await
+        .unwrap();
+    }
+  }
+}
@@ -22,6 +22,7 @@ use lemmy_db_schema::{
   traits::Crud,
 };
 use lemmy_utils::error::LemmyError;
+use tracing::debug;
 use url::Url;
 
 pub(crate) async fn send_update_community(
@@ -84,10 +85,20 @@ impl ActivityHandler for UpdateCommunity {
   #[tracing::instrument(skip_all)]
   async fn receive(self, context: &Data<Self::DataType>) -> Result<(), LemmyError> {
     let community = self.community(context).await?;
+    let wiki = self.object.wiki.clone();
 
     let community_update_form = self.object.into_update_form();
 
     Community::update(&mut context.pool(), community.id, &community_update_form).await?;
+
+    // Wiki pages are sent as part of the community, so fetch them again to receive any changes
+    if let Some(wiki) = wiki {
+      wiki
+        .dereference(&community, context)
+        .await
+        .map_err(|e| debug!("{}", e))
+        .ok();
+    }
     Ok(())
   }
 }
@@ -0,0 +1,118 @@
+use crate::{
+  activities::{generate_activity_id, send_lemmy_activity, verify_mod_action},
+  insert_received_activity,
+  objects::{community::ApubCommunity, person::ApubPerson},
+  protocol::{
+    activities::community::warn_user::{WarnType, WarnUser},
+    InCommunity,
+  },
+  PostOrComment,
+};
+use activitypub_federation::{
+  config::Data,
+  fetch::object_id::ObjectId,
+  traits::{ActivityHandler, Actor},
+};
+use lemmy_api_common::{context::LemmyContext, utils::send_warning_notifications};
+use lemmy_db_schema::{
+  source::{
+    activity::ActivitySendTargets,
+    community::Community,
+    moderator::{ModWarning, ModWarningForm},
+    person::Person,
+    post::Post,
+  },
+  traits::Crud,
+};
+use lemmy_utils::error::{LemmyError, LemmyErrorType};
+use url::Url;
+
+impl WarnUser {
+  #[tracing::instrument(skip_all)]
+  pub(crate) async fn send(
+    object_id: ObjectId<PostOrComment>,
+    mod_: Person,
+    warned_person: Person,
+    community: Community,
+    reason: String,
+    context: Data<LemmyContext>,
+  ) -> Result<(), LemmyError> {
+    let actor: ApubPerson = mod_.into();
+    let warned_person: ApubPerson = warned_person.into();
+    let community: ApubCommunity = community.into();
+    let id = generate_activity_id(
+      WarnType::Warn,
+      &context.settings().get_protocol_and_hostname(),
+    )?;
+    let warn = WarnUser {
+      actor: actor.id().into(),
+      to: [warned_person.id().into()],
+      object: object_id,
+      summary: reason,
+      kind: WarnType::Warn,
+      id,
+      audience: Some(community.id().into()),
+    };
+    let inbox = ActivitySendTargets::to_inbox(warned_person.shared_inbox_or_inbox());
+    send_lemmy_activity(&context, warn, &actor, inbox, false).await
+  }
+}
+
+#[async_trait::async_trait]
+impl ActivityHandler for WarnUser {
+  type DataType = LemmyContext;
+  type Error = LemmyError;
+
+  fn id(&self) -> &Url {
+    &self.id
+  }
+
+  fn actor(&self) -> &Url {
+    self.actor.inner()
+  }
+
+  #[tracing::instrument(skip_all)]
+  async fn verify(&self, context: &Data<Self::DataType>) -> Result<(), LemmyError> {
+    insert_received_activity(&self.id, context).await?;
+    let community = self.community(context).await?;
+    verify_mod_action(&self.actor, &community, context).await?;
+    Ok(())
+  }
+
+  #[tracing::instrument(skip_all)]
+  async fn receive(self, context: &Data<Self::DataType>) -> Result<(), LemmyError> {
+    let actor = self.actor.dereference(context).await?;
+    let warned_person = self.to[0].dereference(context).await?;
+    // Only the home instance of the user takes note of the warning
+    if !warned_person.local {
+      return Ok(());
+    }
+    let community = self.community(context).await?;
+    let (creator_id, post, comment_id) = match self.object.dereference(context).await? {
+      PostOrComment::Post(post) => (post.creator_id, post.0, None),
+      PostOrComment::Comment(comment) => {
+        let post = Post::read(&mut context.pool(), comment.post_id).await?;
+        (comment.creator_id, post, Some(comment.id))
+      }
+    };
+    // The mod was only checked against the community named in the activity
+    if post.community_id != community.id {
+      Err(LemmyErrorType::InvalidCommunity)?
+    }
+    if creator_id != warned_person.id {
+      Err(LemmyErrorType::InvalidWarningTarget)?
+    }
+
+    let form = ModWarningForm {
+      mod_person_id: actor.id,
+      other_person_id: warned_person.id,
+      community_id: community.id,
+      post_id: post.id,
+      comment_id,
+      reason: self.summary,
+    };
+    let mod_warning = ModWarning::create(&mut context.pool(), &form).await?;
+    send_warning_notifications(&mod_warning, &community, context).await?;
+    Ok(())
+  }
+}
@@ -25,7 +25,7 @@ use activitypub_federation::{
 use lemmy_api_common::{
   build_response::send_local_notifs,
   context::LemmyContext,
-  utils::{check_post_deleted_or_removed, is_mod_or_admin},
+  utils::{check_post_deleted_or_removed, is_mod_or_admin, send_webhook_event},
 };
 use lemmy_db_schema::{
   aggregates::structs::CommentAggregates,
@@ -38,7 +38,9 @@ use lemmy_db_schema::{
     post::Post,
   },
   traits::{Crud, Likeable},
+  WebhookEventType,
 };
+use lemmy_db_views::structs::CommentView;
 use lemmy_utils::{error::LemmyError, utils::mention::scrape_text_for_mentions};
 use url::Url;
 
@@ -170,6 +172,16 @@ impl ActivityHandler for CreateOrUpdateNote {
     // TODO: for compatibility with other projects, it would be much better to read this from cc or tags
     let mentions = scrape_text_for_mentions(&comment.content);
     send_local_notifs(mentions, &comment.0, &actor, &post, do_send_email, context).await?;
+
+    if self.kind == CreateOrUpdateType::Create {
+      send_webhook_event(
+        WebhookEventType::CommentCreated,
+        Some(post.community_id),
+        CommentView::read(&mut context.pool(), comment.id, None),
+        &mut context.pool(),
+      )
+      .await;
+    }
     Ok(())
   }
 }
@@ -0,0 +1,152 @@
+use crate::{
+  activities::{
+    generate_activity_id,
+    send_lemmy_activity,
+    verify_person,
+    verify_person_in_community,
+  },
+  insert_received_activity,
+  objects::{community::ApubCommunity, person::ApubPerson, post::ApubPost},
+  protocol::activities::{
+    create_or_update::{
+      page::CreateOrUpdatePage,
+      poll_vote::{CreatePollVote, PollVoteNote},
+    },
+    CreateOrUpdateType,
+  },
+};
+use activitypub_federation::{
+  config::Data,
+  kinds::{activity::CreateType, object::NoteType},
+  protocol::verification::{verify_domains_match, verify_urls_match},
+  traits::{ActivityHandler, Actor},
+};
+use chrono::Utc;
+use lemmy_api_common::context::LemmyContext;
+use lemmy_db_schema::{
+  source::{
+    activity::ActivitySendTargets,
+    community::Community,
+    person::Person,
+    poll::{Poll, PollOption, PollVote, PollVoteForm},
+    post::Post,
+  },
+  traits::Crud,
+};
+use lemmy_utils::error::{LemmyError, LemmyErrorType};
+use url::Url;
+
+/// Sends the votes of a local user in a remote poll to the poll creator, one activity for each
+/// chosen option.
+pub(crate) async fn send_poll_vote(
+  post: Post,
+  person: Person,
+  option_names: Vec<String>,
+  context: Data<LemmyContext>,
+) -> Result<(), LemmyError> {
+  let actor: ApubPerson = person.into();
+  let recipient: ApubPerson = Person::read(&mut context.pool(), post.creator_id)
+    .await?
+    .into();
+  let protocol_and_hostname = context.settings().get_protocol_and_hostname();
+
+  for name in option_names {
+    let vote = CreatePollVote {
+      id: generate_activity_id(CreateType::Create, &protocol_and_hostname)?,
+      actor: actor.id().into(),
+      to: [recipient.id().into()],
+      object: PollVoteNote {
+        kind: NoteType::Note,
+        id: generate_activity_id(NoteType::Note, &protocol_and_hostname)?,
+        attributed_to: actor.id().into(),
+        to: [recipient.id().into()],
+        name,
+        in_reply_to: post.ap_id.clone().into(),
+      },
+      kind: CreateType::Create,
+    };
+    let inbox = ActivitySendTargets::to_inbox(recipient.shared_inbox_or_inbox());
+    send_lemmy_activity(&context, vote, &actor, inbox, true).await?;
+  }
+  Ok(())
+}
+
+#[async_trait::async_trait]
+impl ActivityHandler for CreatePollVote {
+  type DataType = LemmyContext;
+  type Error = LemmyError;
+
+  fn id(&self) -> &Url {
+    &self.id
+  }
+
+  fn actor(&self) -> &Url {
+    self.actor.inner()
+  }
+
+  #[tracing::instrument(skip_all)]
+  async fn verify(&self, context: &Data<Self::DataType>) -> Result<(), LemmyError> {
+    insert_received_activity(&self.id, context).await?;
+    verify_person(&self.actor, context).await?;
+    verify_domains_match(self.actor.inner(), &self.object.id)?;
+    verify_urls_match(self.actor.inner(), self.object.attributed_to.inner())?;
+    Ok(())
+  }
+
+  #[tracing::instrument(skip_all)]
+  async fn receive(self, context: &Data<Self::DataType>) -> Result<(), LemmyError> {
+    // Only votes in local polls are counted, remote polls are updated by their own instance
+    let post = self.object.in_reply_to.dereference_local(context).await?;
+    if !post.local || post.scheduled_publish_time.is_some() {
+      Err(LemmyErrorType::InvalidPoll)?
+    }
+    if post.deleted || post.removed {
+      Err(LemmyErrorType::Deleted)?
+    }
+    if post.locked {
+      Err(LemmyErrorType::Locked)?
+    }
+    // The vote has to be addressed to the poll creator, like the votes which are sent from here
+    let creator = Person::read(&mut context.pool(), post.creator_id).await?;
+    verify_urls_match(self.to[0].inner(), creator.actor_id.inner())?;
+    verify_urls_match(self.object.to[0].inner(), creator.actor_id.inner())?;
+    let community: ApubCommunity = Community::read(&mut context.pool(), post.community_id)
+      .await?
+      .into();
+    verify_person_in_community(&self.actor, &community, context).await?;
+
+    let poll = Poll::read_for_post(&mut context.pool(), post.id)
+      .await?
+      .ok_or(LemmyErrorType::InvalidPoll)?;
+    if poll.end_time.is_some_and(|end_time| end_time < Utc::now()) {
+      Err(LemmyErrorType::PollClosed)?
+    }
+    let option = PollOption::list_for_post(&mut context.pool(), post.id)
+      .await?
+      .into_iter()
+      .find(|o| o.name == self.object.name)
+      .ok_or(LemmyErrorType::InvalidPollOption)?;
+
+    let person = self.actor.dereference(context).await?;
+    if poll.multiple_choice {
+      let form = PollVoteForm {
+        poll_option_id: option.id,
+        person_id: person.id,
+        post_id: post.id,
+      };
+      PollVote::add(&mut context.pool(), &form).await?;
+    } else {
+      PollVote::vote(&mut context.pool(), post.id, person.id, vec![option.id]).await?;
+    }
+
+    // Other instances get the new vote counts with an update of the poll
+    let creator_id = post.creator_id;
+    CreateOrUpdatePage::send(
+      post.0,
+      creator_id,
+      CreateOrUpdateType::Update,
+      context.reset_request_count(),
+    )
+    .await
+  }
+}
@@ -21,7 +21,7 @@ use activitypub_federation::{
   protocol::verification::{verify_domains_match, verify_urls_match},
   traits::{ActivityHandler, Actor, Object},
 };
-use lemmy_api_common::context::LemmyContext;
+use lemmy_api_common::{context::LemmyContext, live_hub::LiveMessage, utils::send_webhook_event};
 use lemmy_db_schema::{
   aggregates::structs::PostAggregates,
   newtypes::PersonId,
@@ -32,7 +32,9 @@ use lemmy_db_schema::{
     post::{Post, PostLike, PostLikeForm},
   },
   traits::{Crud, Likeable},
+  WebhookEventType,
 };
+use lemmy_db_views::structs::PostView;
 use lemmy_utils::error::{LemmyError, LemmyErrorType};
 use url::Url;
 
@@ -153,6 +155,20 @@ impl ActivityHandler for CreateOrUpdatePage {
     // Calculate initial hot_rank for post
     PostAggregates::update_ranks(&mut context.pool(), post.id).await?;
 
+    if self.kind == CreateOrUpdateType::Create {
+      context.live().send(LiveMessage::Post {
+        community_id: post.community_id,
+        post_id: post.id,
+      });
+      send_webhook_event(
+        WebhookEventType::PostCreated,
+        Some(post.community_id),
+        PostView::read(&mut context.pool(), post.id, None, false),
+        &mut context.pool(),
+      )
+      .await;
+    }
+
     Ok(())
   }
 }
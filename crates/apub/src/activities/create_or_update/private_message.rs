@@ -12,10 +12,10 @@ use activitypub_federation::{
   protocol::verification::verify_domains_match,
   traits::{ActivityHandler, Actor, Object},
 };
-use lemmy_api_common::context::LemmyContext;
+use lemmy_api_common::{context::LemmyContext, utils::send_private_message_notifications};
 use lemmy_db_schema::source::activity::ActivitySendTargets;
 use lemmy_db_views::structs::PrivateMessageView;
-use lemmy_utils::error::LemmyError;
+use lemmy_utils::error::{LemmyError, LemmyErrorType};
 use url::Url;
 
 pub(crate) async fn send_create_or_update_pm(
@@ -24,22 +24,26 @@ pub(crate) async fn send_create_or_update_pm(
   context: Data<LemmyContext>,
 ) -> Result<(), LemmyError> {
   let actor: ApubPerson = pm_view.creator.into();
-  let recipient: ApubPerson = pm_view.recipient.into();
+  let object = ApubPrivateMessage(pm_view.private_message.clone())
+    .into_json(&context)
+    .await?;
 
   let id = generate_activity_id(
     kind.clone(),
     &context.settings().get_protocol_and_hostname(),
   )?;
+  let mut inbox = ActivitySendTargets::empty();
+  for recipient in &object.to {
+    let recipient = recipient.dereference_local(&context).await?;
+    inbox.add_inbox(recipient.shared_inbox_or_inbox());
+  }
   let create_or_update = CreateOrUpdateChatMessage {
     id: id.clone(),
     actor: actor.id().into(),
-    to: [recipient.id().into()],
-    object: ApubPrivateMessage(pm_view.private_message.clone())
-      .into_json(&context)
-      .await?,
+    to: object.to.clone(),
+    object,
     kind,
   };
-  let inbox = ActivitySendTargets::to_inbox(recipient.shared_inbox_or_inbox());
   send_lemmy_activity(&context, create_or_update, &actor, inbox, true).await
 }
 
@@ -61,14 +65,20 @@ impl ActivityHandler for CreateOrUpdateChatMessage {
     insert_received_activity(&self.id, context).await?;
     verify_person(&self.actor, context).await?;
     verify_domains_match(self.actor.inner(), self.object.id.inner())?;
-    verify_domains_match(self.to[0].inner(), self.object.to[0].inner())?;
+    if self.to != self.object.to {
+      Err(LemmyErrorType::InvalidConversationParticipants)?
+    }
     ApubPrivateMessage::verify(&self.object, self.actor.inner(), context).await?;
     Ok(())
   }
 
   #[tracing::instrument(skip_all)]
   async fn receive(self, context: &Data<Self::DataType>) -> Result<(), LemmyError> {
-    ApubPrivateMessage::from_json(self.object, context).await?;
+    let creator = self.actor.dereference(context).await?;
+    let private_message = ApubPrivateMessage::from_json(self.object, context).await?;
+    if self.kind == CreateOrUpdateType::Create {
+      send_private_message_notifications(&private_message, &creator, context).await?;
+    }
     Ok(())
   }
 }
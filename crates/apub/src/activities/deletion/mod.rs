@@ -34,6 +34,7 @@ use lemmy_db_schema::{
     activity::ActivitySendTargets,
     comment::{Comment, CommentUpdateForm},
     community::{Community, CommunityUpdateForm},
+    conversation::Conversation,
     person::Person,
     post::{Post, PostUpdateForm},
     private_message::{PrivateMessage, PrivateMessageUpdateForm},
@@ -118,18 +119,32 @@ pub(crate) async fn send_apub_delete_private_message(
   deleted: bool,
   context: Data<LemmyContext>,
 ) -> Result<(), LemmyError> {
-  let recipient_id = pm.recipient_id;
-  let recipient: ApubPerson = Person::read(&mut context.pool(), recipient_id)
-    .await?
-    .into();
+  // Direct messages are addressed to the recipient, group messages to the conversation
+  let to: Url = match pm.recipient_id {
+    Some(recipient_id) => Person::read(&mut context.pool(), recipient_id)
+      .await?
+      .actor_id
+      .into(),
+    None => Conversation::read(&mut context.pool(), pm.conversation_id)
+      .await?
+      .ap_id
+      .into(),
+  };
+  let mut inbox = ActivitySendTargets::empty();
+  inbox.add_inboxes(
+    Conversation::list_participants(&mut context.pool(), pm.conversation_id)
+      .await?
+      .into_iter()
+      .filter(|p| p.id != actor.id)
+      .map(|p| ApubPerson(p).shared_inbox_or_inbox()),
+  );
 
   let deletable = DeletableObjects::PrivateMessage(pm.into());
-  let inbox = ActivitySendTargets::to_inbox(recipient.shared_inbox_or_inbox());
   if deleted {
-    let delete: Delete = Delete::new(actor, deletable, recipient.id(), None, None, &context)?;
+    let delete: Delete = Delete::new(actor, deletable, to, None, None, &context)?;
     send_lemmy_activity(&context, delete, actor, inbox, true).await?;
   } else {
-    let undo = UndoDelete::new(actor, deletable, recipient.id(), None, None, &context)?;
+    let undo = UndoDelete::new(actor, deletable, to, None, None, &context)?;
     send_lemmy_activity(&context, undo, actor, inbox, true).await?;
   };
   Ok(())
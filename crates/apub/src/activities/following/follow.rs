@@ -16,7 +16,7 @@ use activitypub_federation::{
   protocol::verification::verify_urls_match,
   traits::{ActivityHandler, Actor},
 };
-use lemmy_api_common::context::LemmyContext;
+use lemmy_api_common::{context::LemmyContext, utils::check_person_block};
 use lemmy_db_schema::{
   source::{
     activity::ActivitySendTargets,
@@ -24,20 +24,21 @@ use lemmy_db_schema::{
     person::{PersonFollower, PersonFollowerForm},
   },
   traits::Followable,
+  CommunityVisibility,
 };
-use lemmy_utils::error::LemmyError;
+use lemmy_utils::error::{LemmyError, LemmyErrorType};
 use url::Url;
 
 impl Follow {
   pub(in crate::activities::following) fn new(
     actor: &ApubPerson,
-    community: &ApubCommunity,
+    target: &UserOrCommunity,
     context: &Data<LemmyContext>,
   ) -> Result<Follow, LemmyError> {
     Ok(Follow {
       actor: actor.id().into(),
-      object: community.id().into(),
-      to: Some([community.id().into()]),
+      object: target.id().into(),
+      to: Some([target.id().into()]),
       kind: FollowType::Follow,
       id: generate_activity_id(
         FollowType::Follow,
@@ -49,26 +50,48 @@ impl Follow {
   #[tracing::instrument(skip_all)]
   pub async fn send(
     actor: &ApubPerson,
-    community: &ApubCommunity,
+    target: &UserOrCommunity,
     context: &Data<LemmyContext>,
   ) -> Result<(), LemmyError> {
-    let community_follower_form = CommunityFollowerForm {
-      community_id: community.id,
-      person_id: actor.id,
-      pending: true,
+    let local = match target {
+      UserOrCommunity::User(person) => person.local,
+      UserOrCommunity::Community(community) => {
+        // Follows of local communities are already stored by the api, possibly waiting for
+        // approval by a moderator
+        if !community.local {
+          let community_follower_form = CommunityFollowerForm {
+            community_id: community.id,
+            person_id: actor.id,
+            pending: true,
+          };
+          CommunityFollower::follow(&mut context.pool(), &community_follower_form)
+            .await
+            .ok();
+        }
+        community.local
+      }
     };
-    CommunityFollower::follow(&mut context.pool(), &community_follower_form)
-      .await
-      .ok();
 
-    let follow = Follow::new(actor, community, context)?;
-    let inbox = if community.local {
+    let follow = Follow::new(actor, target, context)?;
+    let inbox = if local {
       ActivitySendTargets::empty()
     } else {
-      ActivitySendTargets::to_inbox(community.shared_inbox_or_inbox())
+      ActivitySendTargets::to_inbox(target.shared_inbox_or_inbox())
     };
     send_lemmy_activity(context, follow, actor, inbox, true).await
   }
+
+  /// Accepts the follow request of a remote person, after it was approved by a moderator of the
+  /// private community. The original activity isn't stored, so a new one is generated.
+  pub async fn send_accept(
+    person: &ApubPerson,
+    community: &ApubCommunity,
+    context: &Data<LemmyContext>,
+  ) -> Result<(), LemmyError> {
+    let target = UserOrCommunity::Community(community.clone());
+    let follow = Follow::new(person, &target, context)?;
+    AcceptFollow::send(follow, context).await
+  }
 }
 
 #[async_trait::async_trait]
@@ -90,6 +113,9 @@ impl ActivityHandler for Follow {
     verify_person(&self.actor, context).await?;
     let object = self.object.dereference(context).await?;
     if let UserOrCommunity::Community(c) = object {
+      if !c.visibility.can_federate() {
+        Err(LemmyErrorType::CommunityContentNotVisible)?
+      }
       verify_person_in_community(&self.actor, &c, context).await?;
     }
     if let Some(to) = &self.to {
@@ -104,6 +130,7 @@ impl ActivityHandler for Follow {
     let object = self.object.dereference(context).await?;
     match object {
       UserOrCommunity::User(u) => {
+        check_person_block(actor.id, u.id, &mut context.pool()).await?;
         let form = PersonFollowerForm {
           person_id: u.id,
           follower_id: actor.id,
@@ -112,12 +139,18 @@ impl ActivityHandler for Follow {
         PersonFollower::follow(&mut context.pool(), &form).await?;
       }
       UserOrCommunity::Community(c) => {
+        // Follow requests of private communities are only accepted once a moderator approves them
+        let pending = c.visibility == CommunityVisibility::Private
+          && !CommunityFollower::is_approved_follower(&mut context.pool(), c.id, actor.id).await?;
         let form = CommunityFollowerForm {
           community_id: c.id,
           person_id: actor.id,
-          pending: false,
+          pending,
         };
         CommunityFollower::follow(&mut context.pool(), &form).await?;
+        if pending {
+          return Ok(());
+        }
       }
     }
 
@@ -1,4 +1,5 @@
 use crate::{
+  fetcher::user_or_community::UserOrCommunity,
   objects::{community::ApubCommunity, person::ApubPerson},
   protocol::activities::following::{follow::Follow, undo_follow::UndoFollow},
 };
@@ -18,10 +19,46 @@ pub async fn send_follow_community(
   context: &Data<LemmyContext>,
 ) -> Result<(), LemmyError> {
   let community: ApubCommunity = community.into();
+  send_follow(
+    person,
+    UserOrCommunity::Community(community),
+    follow,
+    context,
+  )
+  .await
+}
+
+/// Accepts the follow of a remote person, after a moderator approved it for a private community.
+pub async fn send_accept_follower(
+  community: Community,
+  person: Person,
+  context: &Data<LemmyContext>,
+) -> Result<(), LemmyError> {
+  let community: ApubCommunity = community.into();
+  let person: ApubPerson = person.into();
+  Follow::send_accept(&person, &community, context).await
+}
+
+pub async fn send_follow_person(
+  target: Person,
+  person: Person,
+  follow: bool,
+  context: &Data<LemmyContext>,
+) -> Result<(), LemmyError> {
+  let target: ApubPerson = target.into();
+  send_follow(person, UserOrCommunity::User(target), follow, context).await
+}
+
+async fn send_follow(
+  person: Person,
+  target: UserOrCommunity,
+  follow: bool,
+  context: &Data<LemmyContext>,
+) -> Result<(), LemmyError> {
   let actor: ApubPerson = person.into();
   if follow {
-    Follow::send(&actor, &community, context).await
+    Follow::send(&actor, &target, context).await
   } else {
-    UndoFollow::send(&actor, &community, context).await
+    UndoFollow::send(&actor, &target, context).await
   }
 }
@@ -2,7 +2,7 @@ use crate::{
   activities::{generate_activity_id, send_lemmy_activity, verify_person},
   fetcher::user_or_community::UserOrCommunity,
   insert_received_activity,
-  objects::{community::ApubCommunity, person::ApubPerson},
+  objects::person::ApubPerson,
   protocol::activities::following::{follow::Follow, undo_follow::UndoFollow},
 };
 use activitypub_federation::{
@@ -27,13 +27,13 @@ impl UndoFollow {
   #[tracing::instrument(skip_all)]
   pub async fn send(
     actor: &ApubPerson,
-    community: &ApubCommunity,
+    target: &UserOrCommunity,
     context: &Data<LemmyContext>,
   ) -> Result<(), LemmyError> {
-    let object = Follow::new(actor, community, context)?;
+    let object = Follow::new(actor, target, context)?;
     let undo = UndoFollow {
       actor: actor.id().into(),
-      to: Some([community.id().into()]),
+      to: Some([target.id().into()]),
       object,
       kind: UndoType::Undo,
       id: generate_activity_id(
@@ -41,10 +41,14 @@ impl UndoFollow {
         &context.settings().get_protocol_and_hostname(),
       )?,
     };
-    let inbox = if community.local {
+    let local = match target {
+      UserOrCommunity::User(person) => person.local,
+      UserOrCommunity::Community(community) => community.local,
+    };
+    let inbox = if local {
       ActivitySendTargets::empty()
     } else {
-      ActivitySendTargets::to_inbox(community.shared_inbox_or_inbox())
+      ActivitySendTargets::to_inbox(target.shared_inbox_or_inbox())
     };
     send_lemmy_activity(context, undo, actor, inbox, true).await
   }
@@ -1,13 +1,14 @@
-use self::following::send_follow_community;
+use self::following::{send_accept_follower, send_follow_community, send_follow_person};
 use crate::{
   activities::{
     block::{send_ban_from_community, send_ban_from_site},
     community::{
       collection_add::{send_add_mod_to_community, send_feature_post},
       lock_page::send_lock_post,
+      resolve_report::{send_resolve_comment_report, send_resolve_post_report},
       update::send_update_community,
     },
-    create_or_update::private_message::send_create_or_update_pm,
+    create_or_update::{poll_vote::send_poll_vote, private_message::send_create_or_update_pm},
     deletion::{
       delete_user::delete_user,
       send_apub_delete_in_community,
@@ -15,11 +16,11 @@ use crate::{
       send_apub_delete_private_message,
       DeletableObjects,
     },
-    voting::send_like_activity,
+    voting::{send_like_activity, send_react_activity},
   },
   objects::{community::ApubCommunity, person::ApubPerson},
   protocol::activities::{
-    community::report::Report,
+    community::{report::Report, warn_user::WarnUser},
     create_or_update::{note::CreateOrUpdateNote, page::CreateOrUpdatePage},
     CreateOrUpdateType,
   },
@@ -298,9 +299,25 @@ pub async fn match_outgoing_activities(
       LikePostOrComment(object_id, person, community, score) => {
         send_like_activity(object_id, person, community, score, context).await
       }
+      ReactPostOrComment(object_id, person, community, emoji, custom_emoji, add) => {
+        send_react_activity(
+          object_id,
+          person,
+          community,
+          emoji,
+          custom_emoji,
+          add,
+          context,
+        )
+        .await
+      }
       FollowCommunity(community, person, follow) => {
         send_follow_community(community, person, follow, &context).await
       }
+      AcceptFollower(community, person) => send_accept_follower(community, person, &context).await,
+      FollowPerson(target, person, follow) => {
+        send_follow_person(target, person, follow, &context).await
+      }
       UpdateCommunity(actor, community) => send_update_community(community, actor, context).await,
       DeleteCommunity(actor, community, removed) => {
         let deletable = DeletableObjects::Community(community.clone().into());
@@ -335,8 +352,36 @@ pub async fn match_outgoing_activities(
         send_apub_delete_private_message(&person.into(), pm, deleted, context).await
       }
       DeleteUser(person, delete_content) => delete_user(person, delete_content, context).await,
-      CreateReport(url, actor, community, reason) => {
-        Report::send(ObjectId::from(url), actor, community, reason, context).await
+      CreateReport(url, report_id, actor, community, reason) => {
+        Report::send(
+          report_id,
+          ObjectId::from(url),
+          actor,
+          community,
+          reason,
+          context,
+        )
+        .await
+      }
+      ResolvePostReport(report, actor, resolved) => {
+        send_resolve_post_report(report, actor, resolved, context).await
+      }
+      ResolveCommentReport(report, actor, resolved) => {
+        send_resolve_comment_report(report, actor, resolved, context).await
+      }
+      VotePoll(post, person, option_names) => {
+        send_poll_vote(post, person, option_names, context).await
+      }
+      WarnPerson(url, mod_, warned_person, community, reason) => {
+        WarnUser::send(
+          ObjectId::from(url),
+          mod_,
+          warned_person,
+          community,
+          reason,
+          context,
+        )
+        .await
       }
     }
   };
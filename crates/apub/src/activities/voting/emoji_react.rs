@@ -0,0 +1,80 @@
+use crate::{
+  activities::{generate_activity_id, verify_person_in_community, voting::react_post_or_comment},
+  insert_received_activity,
+  mentions::MentionOrValue,
+  objects::{community::ApubCommunity, custom_emoji::ApubCustomEmoji, person::ApubPerson},
+  protocol::{
+    activities::voting::emoji_react::{EmojiReact, EmojiReactType},
+    InCommunity,
+  },
+  PostOrComment,
+};
+use activitypub_federation::{
+  config::Data,
+  fetch::object_id::ObjectId,
+  traits::{ActivityHandler, Actor, Object},
+};
+use lemmy_api_common::context::LemmyContext;
+use lemmy_db_schema::source::custom_emoji::CustomEmoji;
+use lemmy_utils::error::LemmyError;
+use url::Url;
+
+impl EmojiReact {
+  pub(in crate::activities::voting) async fn new(
+    object_id: ObjectId<PostOrComment>,
+    actor: &ApubPerson,
+    community: &ApubCommunity,
+    emoji: String,
+    custom_emoji: Option<CustomEmoji>,
+    context: &Data<LemmyContext>,
+  ) -> Result<EmojiReact, LemmyError> {
+    let mut tag = vec![];
+    if let Some(custom_emoji) = custom_emoji {
+      let emoji = ApubCustomEmoji::from(custom_emoji)
+        .into_json(context)
+        .await?;
+      tag.push(MentionOrValue::Emoji(emoji));
+    }
+    Ok(EmojiReact {
+      actor: actor.id().into(),
+      object: object_id,
+      kind: EmojiReactType::EmojiReact,
+      id: generate_activity_id(
+        EmojiReactType::EmojiReact,
+        &context.settings().get_protocol_and_hostname(),
+      )?,
+      content: emoji,
+      tag,
+      audience: Some(community.id().into()),
+    })
+  }
+}
+
+#[async_trait::async_trait]
+impl ActivityHandler for EmojiReact {
+  type DataType = LemmyContext;
+  type Error = LemmyError;
+
+  fn id(&self) -> &Url {
+    &self.id
+  }
+
+  fn actor(&self) -> &Url {
+    self.actor.inner()
+  }
+
+  #[tracing::instrument(skip_all)]
+  async fn verify(&self, context: &Data<LemmyContext>) -> Result<(), LemmyError> {
+    insert_received_activity(&self.id, context).await?;
+    let community = self.community(context).await?;
+    verify_person_in_community(&self.actor, &community, context).await?;
+    Ok(())
+  }
+
+  #[tracing::instrument(skip_all)]
+  async fn receive(self, context: &Data<LemmyContext>) -> Result<(), LemmyError> {
+    let actor = self.actor.dereference(context).await?;
+    let object = self.object.dereference(context).await?;
+    react_post_or_comment(&self.content, self.emojis(), actor, &object, context).await
+  }
+}
@@ -2,27 +2,42 @@ use crate::{
   activities::community::send_activity_in_community,
   activity_lists::AnnouncableActivities,
   fetcher::post_or_comment::PostOrComment,
-  objects::{comment::ApubComment, community::ApubCommunity, person::ApubPerson, post::ApubPost},
-  protocol::activities::voting::{
-    undo_vote::UndoVote,
-    vote::{Vote, VoteType},
+  objects::{
+    comment::ApubComment,
+    community::ApubCommunity,
+    custom_emoji::receive_reaction_emoji,
+    person::ApubPerson,
+    post::ApubPost,
+  },
+  protocol::{
+    activities::voting::{
+      emoji_react::EmojiReact,
+      undo_emoji_react::UndoEmojiReact,
+      undo_vote::UndoVote,
+      vote::{Vote, VoteType},
+    },
+    objects::emoji::Emoji,
   },
 };
 use activitypub_federation::{config::Data, fetch::object_id::ObjectId};
 use lemmy_api_common::context::LemmyContext;
 use lemmy_db_schema::{
-  newtypes::DbUrl,
+  newtypes::{CommentId, DbUrl, PostId},
   source::{
     activity::ActivitySendTargets,
     comment::{CommentLike, CommentLikeForm},
     community::Community,
+    custom_emoji::CustomEmoji,
     person::Person,
     post::{PostLike, PostLikeForm},
+    reaction::{Reaction, ReactionForm},
   },
   traits::Likeable,
 };
-use lemmy_utils::error::LemmyError;
+use lemmy_utils::{error::LemmyError, utils::validation::is_valid_emoji_reaction};
 
+pub mod emoji_react;
+pub mod undo_emoji_react;
 pub mod undo_vote;
 pub mod vote;
 
@@ -52,6 +67,85 @@ pub(crate) async fn send_like_activity(
   }
 }
 
+pub(crate) async fn send_react_activity(
+  object_id: DbUrl,
+  actor: Person,
+  community: Community,
+  emoji: String,
+  custom_emoji: Option<CustomEmoji>,
+  add: bool,
+  context: Data<LemmyContext>,
+) -> Result<(), LemmyError> {
+  let object_id: ObjectId<PostOrComment> = object_id.try_into()?;
+  let actor: ApubPerson = actor.into();
+  let community: ApubCommunity = community.into();
+
+  let empty = ActivitySendTargets::empty();
+  let react = EmojiReact::new(object_id, &actor, &community, emoji, custom_emoji, &context).await?;
+  let activity = if add {
+    AnnouncableActivities::EmojiReact(react)
+  } else {
+    let undo = UndoEmojiReact::new(react, &actor, &community, &context)?;
+    AnnouncableActivities::UndoEmojiReact(undo)
+  };
+  send_activity_in_community(activity, &actor, &community, empty, false, &context).await
+}
+
+/// Returns the post id, and the comment id if the object is a comment.
+fn reaction_target(object: &PostOrComment) -> (PostId, Option<CommentId>) {
+  match object {
+    PostOrComment::Post(p) => (p.id, None),
+    PostOrComment::Comment(c) => (c.post_id, Some(c.id)),
+  }
+}
+
+/// Stores a reaction which was received as `EmojiReact`, or as `Like` with content. Custom emojis
+/// need to be included in the tags of the activity.
+#[tracing::instrument(skip_all)]
+async fn react_post_or_comment(
+  content: &str,
+  tags: Vec<&Emoji>,
+  actor: ApubPerson,
+  object: &PostOrComment,
+  context: &Data<LemmyContext>,
+) -> Result<(), LemmyError> {
+  let emoji = content.trim();
+  is_valid_emoji_reaction(emoji)?;
+  let custom_emoji_id = match emoji.strip_prefix(':').and_then(|e| e.strip_suffix(':')) {
+    Some(shortcode) => Some(receive_reaction_emoji(shortcode, tags, context).await?.id),
+    None => None,
+  };
+  let (post_id, comment_id) = reaction_target(object);
+  let form = ReactionForm {
+    person_id: actor.id,
+    post_id,
+    comment_id,
+    emoji: emoji.to_string(),
+    custom_emoji_id,
+  };
+  Reaction::react(&mut context.pool(), &form).await?;
+  Ok(())
+}
+
+#[tracing::instrument(skip_all)]
+async fn undo_react_post_or_comment(
+  content: &str,
+  actor: ApubPerson,
+  object: &PostOrComment,
+  context: &Data<LemmyContext>,
+) -> Result<(), LemmyError> {
+  let (post_id, comment_id) = reaction_target(object);
+  Reaction::remove(
+    &mut context.pool(),
+    actor.id,
+    post_id,
+    comment_id,
+    content.trim(),
+  )
+  .await?;
+  Ok(())
+}
+
 #[tracing::instrument(skip_all)]
 async fn vote_comment(
   vote_type: &VoteType,
@@ -2,7 +2,7 @@ use crate::{
   activities::{
     generate_activity_id,
     verify_person_in_community,
-    voting::{undo_vote_comment, undo_vote_post},
+    voting::{undo_react_post_or_comment, undo_vote_comment, undo_vote_post},
   },
   insert_received_activity,
   objects::{community::ApubCommunity, person::ApubPerson},
@@ -69,6 +69,9 @@ impl ActivityHandler for UndoVote {
   async fn receive(self, context: &Data<LemmyContext>) -> Result<(), LemmyError> {
     let actor = self.actor.dereference(context).await?;
     let object = self.object.object.dereference(context).await?;
+    if let Some(emoji) = self.object.reaction() {
+      return undo_react_post_or_comment(emoji, actor, &object, context).await;
+    }
     match object {
       PostOrComment::Post(p) => undo_vote_post(actor, &p, context).await,
       PostOrComment::Comment(c) => undo_vote_comment(actor, &c, context).await,
@@ -2,7 +2,7 @@ use crate::{
   activities::{
     generate_activity_id,
     verify_person_in_community,
-    voting::{vote_comment, vote_post},
+    voting::{react_post_or_comment, vote_comment, vote_post},
   },
   insert_received_activity,
   objects::{community::ApubCommunity, person::ApubPerson},
@@ -37,6 +37,8 @@ impl Vote {
       kind: kind.clone(),
       id: generate_activity_id(kind, &context.settings().get_protocol_and_hostname())?,
       audience: Some(community.id().into()),
+      content: None,
+      tag: vec![],
     })
   }
 }
@@ -74,6 +76,9 @@ impl ActivityHandler for Vote {
   async fn receive(self, context: &Data<LemmyContext>) -> Result<(), LemmyError> {
     let actor = self.actor.dereference(context).await?;
     let object = self.object.dereference(context).await?;
+    if let Some(emoji) = self.reaction() {
+      return react_post_or_comment(emoji, self.emojis(), actor, &object, context).await;
+    }
     match object {
       PostOrComment::Post(p) => vote_post(&self.kind, actor, &p, context).await,
       PostOrComment::Comment(c) => vote_comment(&self.kind, actor, &c, context).await,
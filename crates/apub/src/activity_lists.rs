@@ -9,16 +9,24 @@ use crate::{
         collection_remove::CollectionRemove,
         lock_page::{LockPage, UndoLockPage},
         report::Report,
+        resolve_report::{ResolveReport, UndoResolveReport},
         update::UpdateCommunity,
+        warn_user::WarnUser,
       },
       create_or_update::{
         chat_message::CreateOrUpdateChatMessage,
         note::CreateOrUpdateNote,
         page::CreateOrUpdatePage,
+        poll_vote::CreatePollVote,
       },
       deletion::{delete::Delete, delete_user::DeleteUser, undo_delete::UndoDelete},
       following::{accept::AcceptFollow, follow::Follow, undo_follow::UndoFollow},
-      voting::{undo_vote::UndoVote, vote::Vote},
+      voting::{
+        emoji_react::EmojiReact,
+        undo_emoji_react::UndoEmojiReact,
+        undo_vote::UndoVote,
+        vote::Vote,
+      },
     },
     objects::page::Page,
     InCommunity,
@@ -43,7 +51,11 @@ pub enum SharedInboxActivities {
   AcceptFollow(AcceptFollow),
   UndoFollow(UndoFollow),
   CreateOrUpdatePrivateMessage(CreateOrUpdateChatMessage),
+  CreatePollVote(CreatePollVote),
   Report(Report),
+  ResolveReport(ResolveReport),
+  UndoResolveReport(UndoResolveReport),
+  WarnUser(WarnUser),
   AnnounceActivity(AnnounceActivity),
   /// This is a catch-all and needs to be last
   RawAnnouncableActivities(RawAnnouncableActivities),
@@ -57,6 +69,8 @@ pub enum GroupInboxActivities {
   Follow(Follow),
   UndoFollow(UndoFollow),
   Report(Report),
+  ResolveReport(ResolveReport),
+  UndoResolveReport(UndoResolveReport),
   /// This is a catch-all and needs to be last
   AnnouncableActivities(RawAnnouncableActivities),
 }
@@ -70,8 +84,12 @@ pub enum PersonInboxActivities {
   AcceptFollow(AcceptFollow),
   UndoFollow(UndoFollow),
   CreateOrUpdatePrivateMessage(CreateOrUpdateChatMessage),
+  CreatePollVote(CreatePollVote),
   Delete(Delete),
   UndoDelete(UndoDelete),
+  WarnUser(WarnUser),
+  ResolveReport(ResolveReport),
+  UndoResolveReport(UndoResolveReport),
   AnnounceActivity(AnnounceActivity),
   /// User can also receive some "announcable" activities, eg a comment mention.
   AnnouncableActivities(AnnouncableActivities),
@@ -85,6 +103,8 @@ pub enum AnnouncableActivities {
   CreateOrUpdatePost(CreateOrUpdatePage),
   Vote(Vote),
   UndoVote(UndoVote),
+  EmojiReact(EmojiReact),
+  UndoEmojiReact(UndoEmojiReact),
   Delete(Delete),
   UndoDelete(UndoDelete),
   UpdateCommunity(UpdateCommunity),
@@ -118,6 +138,8 @@ impl InCommunity for AnnouncableActivities {
       CreateOrUpdatePost(a) => a.community(context).await,
       Vote(a) => a.community(context).await,
       UndoVote(a) => a.community(context).await,
+      EmojiReact(a) => a.community(context).await,
+      UndoEmojiReact(a) => a.community(context).await,
       Delete(a) => a.community(context).await,
       UndoDelete(a) => a.community(context).await,
       UpdateCommunity(a) => a.community(context).await,
@@ -150,6 +172,14 @@ mod tests {
       "assets/lemmy/activities/create_or_update/create_note.json",
     )
     .unwrap();
+    let undo_resolve = test_parse_lemmy_item::<GroupInboxActivities>(
+      "assets/lemmy/activities/community/undo_resolve_report.json",
+    )
+    .unwrap();
+    assert!(matches!(
+      undo_resolve,
+      GroupInboxActivities::UndoResolveReport(_)
+    ));
   }
 
   #[test]
@@ -165,6 +195,13 @@ mod tests {
     )
     .unwrap();
     test_json::<PersonInboxActivities>("assets/mastodon/activities/follow.json").unwrap();
+    let poll_vote =
+      test_json::<PersonInboxActivities>("assets/mastodon/activities/create_poll_vote.json")
+        .unwrap();
+    assert!(matches!(
+      poll_vote.inner(),
+      PersonInboxActivities::CreatePollVote(_)
+    ));
   }
 
   #[test]
@@ -10,7 +10,10 @@ use lemmy_api_common::{
   post::{GetPosts, GetPostsResponse},
   utils::check_private_instance,
 };
-use lemmy_db_schema::source::{community::Community, local_site::LocalSite};
+use lemmy_db_schema::{
+  source::{community::Community, local_site::LocalSite},
+  ListingType,
+};
 use lemmy_db_views::{
   post_view::PostQuery,
   structs::{LocalUserView, PaginationCursor},
@@ -45,11 +48,17 @@ pub async fn list_posts(
     return Err(LemmyError::from(LemmyErrorType::ContradictingFilters));
   }
 
-  let listing_type = Some(listing_type_with_default(
-    data.type_,
-    &local_site,
-    community_id,
-  )?);
+  let multi_community_id = data.multi_community_id;
+  // a multi-community feed shows all of its communities, unless a listing type is given
+  let listing_type = if multi_community_id.is_some() {
+    Some(data.type_.unwrap_or(ListingType::All))
+  } else {
+    Some(listing_type_with_default(
+      data.type_,
+      &local_site,
+      community_id,
+    )?)
+  };
   // parse pagination token
   let page_after = if let Some(pa) = &data.page_cursor {
     Some(pa.read(&mut context.pool()).await?)
@@ -62,6 +71,8 @@ pub async fn list_posts(
     listing_type,
     sort,
     community_id,
+    multi_community_id,
+    tag_id: data.tag_id,
     saved_only,
     liked_only,
     disliked_only,
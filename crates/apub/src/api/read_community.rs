@@ -9,10 +9,11 @@ use lemmy_api_common::{
 use lemmy_db_schema::source::{
   actor_language::CommunityLanguage,
   community::Community,
+  community_post_tag::CommunityPostTag,
   local_site::LocalSite,
   site::Site,
 };
-use lemmy_db_views::structs::LocalUserView;
+use lemmy_db_views::structs::{CustomEmojiView, LocalUserView};
 use lemmy_db_views_actor::structs::{CommunityModeratorView, CommunityView};
 use lemmy_utils::error::{LemmyError, LemmyErrorExt, LemmyErrorExt2, LemmyErrorType};
 
@@ -76,11 +77,16 @@ pub async fn get_community(
 
   let community_id = community_view.community.id;
   let discussion_languages = CommunityLanguage::read(&mut context.pool(), community_id).await?;
+  let post_tags = CommunityPostTag::list_for_community(&mut context.pool(), community_id).await?;
+  let custom_emojis =
+    CustomEmojiView::list_for_community(&mut context.pool(), community_id).await?;
 
   Ok(Json(GetCommunityResponse {
     community_view,
     site,
     moderators,
     discussion_languages,
+    post_tags,
+    custom_emojis,
   }))
 }
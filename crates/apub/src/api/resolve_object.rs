@@ -13,7 +13,7 @@ use lemmy_api_common::{
 };
 use lemmy_db_schema::{newtypes::PersonId, source::local_site::LocalSite, utils::DbPool};
 use lemmy_db_views::structs::{CommentView, LocalUserView, PostView};
-use lemmy_db_views_actor::structs::{CommunityView, PersonView};
+use lemmy_db_views_actor::structs::{CommunityView, MultiCommunityView, PersonView};
 use lemmy_utils::error::{LemmyError, LemmyErrorExt2, LemmyErrorType};
 
 #[tracing::instrument(skip(context))]
@@ -68,6 +68,10 @@ async fn convert_response(
       removed_or_deleted = c.deleted || c.removed;
       res.comment = Some(CommentView::read(pool, c.id, user_id).await?)
     }
+    MultiCommunity(m) => {
+      removed_or_deleted = m.deleted;
+      res.multi_community = Some(MultiCommunityView::read(pool, m.id).await?)
+    }
   };
   // if the object was deleted from database, dont return it
   if removed_or_deleted {
@@ -1,4 +1,7 @@
-use crate::{fetcher::resolve_actor_identifier, objects::community::ApubCommunity};
+use crate::{
+  fetcher::resolve_actor_identifier,
+  objects::{community::ApubCommunity, person::ApubPerson},
+};
 use activitypub_federation::config::Data;
 use actix_web::web::{Json, Query};
 use lemmy_api_common::{
@@ -7,13 +10,17 @@ use lemmy_api_common::{
   utils::{check_private_instance, is_admin},
 };
 use lemmy_db_schema::{
-  source::{community::Community, local_site::LocalSite},
+  source::{community::Community, local_site::LocalSite, person::Person},
   utils::post_to_comment_sort_type,
   SearchType,
+  SortType,
 };
 use lemmy_db_views::{comment_view::CommentQuery, post_view::PostQuery, structs::LocalUserView};
 use lemmy_db_views_actor::{community_view::CommunityQuery, person_view::PersonQuery};
-use lemmy_utils::error::LemmyError;
+use lemmy_utils::{
+  error::LemmyError,
+  utils::search::{parse_search_query, SearchQuery},
+};
 
 #[tracing::instrument(skip(context))]
 pub async fn search(
@@ -37,13 +44,19 @@ pub async fn search(
 
   // TODO no clean / non-nsfw searching rn
 
-  let q = data.q.clone();
+  let SearchQuery {
+    terms,
+    community,
+    author,
+  } = parse_search_query(&data.q);
+  let q = if terms.is_empty() { None } else { Some(terms) };
   let page = data.page;
   let limit = data.limit;
-  let sort = data.sort;
+  // Search results are ranked by how well they match the query, unless requested otherwise
+  let sort = data.sort.or(Some(SortType::Relevance));
   let listing_type = data.listing_type;
   let search_type = data.type_.unwrap_or(SearchType::All);
-  let community_id = if let Some(name) = &data.community_name {
+  let community_id = if let Some(name) = data.community_name.as_ref().or(community.as_ref()) {
     Some(
       resolve_actor_identifier::<ApubCommunity, Community>(name, &context, &local_user_view, false)
         .await?,
@@ -52,7 +65,15 @@ pub async fn search(
   } else {
     data.community_id
   };
-  let creator_id = data.creator_id;
+  let creator_id = if let (None, Some(name)) = (data.creator_id, &author) {
+    Some(
+      resolve_actor_identifier::<ApubPerson, Person>(name, &context, &local_user_view, false)
+        .await?
+        .id,
+    )
+  } else {
+    data.creator_id
+  };
   let local_user = local_user_view.as_ref().map(|l| l.local_user.clone());
   match search_type {
     SearchType::Posts => {
@@ -62,7 +83,7 @@ pub async fn search(
         community_id: (community_id),
         creator_id: (creator_id),
         local_user: (local_user_view.as_ref()),
-        search_term: (Some(q)),
+        search_term: (q.clone()),
         page: (page),
         limit: (limit),
         ..Default::default()
@@ -74,7 +95,7 @@ pub async fn search(
       comments = CommentQuery {
         sort: (sort.map(post_to_comment_sort_type)),
         listing_type: (listing_type),
-        search_term: (Some(q)),
+        search_term: (q.clone()),
         community_id: (community_id),
         creator_id: (creator_id),
         local_user: (local_user_view.as_ref()),
@@ -89,7 +110,7 @@ pub async fn search(
       communities = CommunityQuery {
         sort: (sort),
         listing_type: (listing_type),
-        search_term: (Some(q)),
+        search_term: (q.clone()),
         local_user: (local_user.as_ref()),
         is_mod_or_admin: (is_admin),
         page: (page),
@@ -102,7 +123,7 @@ pub async fn search(
     SearchType::Users => {
       users = PersonQuery {
         sort,
-        search_term: (Some(q)),
+        search_term: (q.clone()),
         page: (page),
         limit: (limit),
       }
@@ -111,10 +132,7 @@ pub async fn search(
     }
     SearchType::All => {
       // If the community or creator is included, dont search communities or users
-      let community_or_creator_included =
-        data.community_id.is_some() || data.community_name.is_some() || data.creator_id.is_some();
-
-      let q = data.q.clone();
+      let community_or_creator_included = community_id.is_some() || creator_id.is_some();
 
       posts = PostQuery {
         sort: (sort),
@@ -122,7 +140,7 @@ pub async fn search(
         community_id: (community_id),
         creator_id: (creator_id),
         local_user: (local_user_view.as_ref()),
-        search_term: (Some(q)),
+        search_term: (q.clone()),
         page: (page),
         limit: (limit),
         ..Default::default()
@@ -130,12 +148,10 @@ pub async fn search(
       .list(&mut context.pool())
       .await?;
 
-      let q = data.q.clone();
-
       comments = CommentQuery {
         sort: (sort.map(post_to_comment_sort_type)),
         listing_type: (listing_type),
-        search_term: (Some(q)),
+        search_term: (q.clone()),
         community_id: (community_id),
         creator_id: (creator_id),
         local_user: (local_user_view.as_ref()),
@@ -146,15 +162,13 @@ pub async fn search(
       .list(&mut context.pool())
       .await?;
 
-      let q = data.q.clone();
-
       communities = if community_or_creator_included {
         vec![]
       } else {
         CommunityQuery {
           sort: (sort),
           listing_type: (listing_type),
-          search_term: (Some(q)),
+          search_term: (q.clone()),
           local_user: (local_user.as_ref()),
           is_mod_or_admin: (is_admin),
           page: (page),
@@ -165,14 +179,12 @@ pub async fn search(
         .await?
       };
 
-      let q = data.q.clone();
-
       users = if community_or_creator_included {
         vec![]
       } else {
         PersonQuery {
           sort,
-          search_term: (Some(q)),
+          search_term: (q.clone()),
           page: (page),
           limit: (limit),
         }
@@ -186,7 +198,7 @@ pub async fn search(
         listing_type: (listing_type),
         community_id: (community_id),
         creator_id: (creator_id),
-        url_search: (Some(q)),
+        url_search: (Some(data.q.clone())),
         page: (page),
         limit: (limit),
         ..Default::default()
@@ -0,0 +1,155 @@
+use crate::{
+  fetcher::resolve_actor_identifier,
+  objects::{community::ApubCommunity, person::ApubPerson},
+};
+use activitypub_federation::config::Data;
+use actix_web::web::{Json, Query};
+use lemmy_api_common::{
+  context::LemmyContext,
+  site::{SearchCombined, SearchCombinedResponse, SearchResult},
+  utils::check_private_instance,
+};
+use lemmy_db_schema::{
+  source::{community::Community, local_site::LocalSite, person::Person},
+  utils::FETCH_LIMIT_MAX,
+};
+use lemmy_db_views::{
+  combined_search::{CombinedSearchQuery, SearchResultId},
+  comment_view::CommentQuery,
+  post_view::PostQuery,
+  structs::{LocalUserView, PaginationCursor},
+};
+use lemmy_db_views_actor::{community_view::CommunityQuery, structs::PersonView};
+use lemmy_utils::{
+  error::LemmyError,
+  utils::search::{parse_search_query, SearchQuery},
+};
+use std::collections::HashMap;
+
+#[tracing::instrument(skip(context))]
+pub async fn search_combined(
+  data: Query<SearchCombined>,
+  context: Data<LemmyContext>,
+  local_user_view: Option<LocalUserView>,
+) -> Result<Json<SearchCombinedResponse>, LemmyError> {
+  let local_site = LocalSite::read(&mut context.pool()).await?;
+
+  check_private_instance(&local_user_view, &local_site)?;
+
+  let SearchQuery {
+    terms,
+    community,
+    author,
+  } = parse_search_query(&data.q);
+  let community_id = if let Some(name) = data.community_name.as_ref().or(community.as_ref()) {
+    Some(
+      resolve_actor_identifier::<ApubCommunity, Community>(name, &context, &local_user_view, false)
+        .await?,
+    )
+    .map(|c| c.id)
+  } else {
+    data.community_id
+  };
+  let creator_id = if let (None, Some(name)) = (data.creator_id, &author) {
+    Some(
+      resolve_actor_identifier::<ApubPerson, Person>(name, &context, &local_user_view, false)
+        .await?
+        .id,
+    )
+  } else {
+    data.creator_id
+  };
+  let search_query = CombinedSearchQuery {
+    search_term: (!terms.is_empty()).then_some(terms),
+    community_id,
+    creator_id,
+    local_only: data.local_only.unwrap_or_default(),
+    local_user: local_user_view.as_ref(),
+    page_after: data.page_cursor.clone(),
+    limit: data.limit,
+  };
+  let search_results = search_query.list(&mut context.pool()).await?;
+
+  // Read the actual items with one query per kind. The listings use the same filters as the
+  // search, so only items which changed in the meantime are missing.
+  let mut post_ids = vec![];
+  let mut comment_ids = vec![];
+  let mut community_ids = vec![];
+  let mut person_ids = vec![];
+  for search_result in &search_results {
+    match search_result.id {
+      SearchResultId::Post(id) => post_ids.push(id),
+      SearchResultId::Comment(id) => comment_ids.push(id),
+      SearchResultId::Community(id) => community_ids.push(id),
+      SearchResultId::Person(id) => person_ids.push(id),
+    }
+  }
+  let pool = &mut context.pool();
+  let mut posts: HashMap<_, _> = if post_ids.is_empty() {
+    HashMap::new()
+  } else {
+    PostQuery {
+      ids: Some(post_ids),
+      limit: Some(FETCH_LIMIT_MAX),
+      ..search_query.post_query()
+    }
+    .list(pool)
+    .await?
+    .into_iter()
+    .map(|p| (p.post.id, p))
+    .collect()
+  };
+  let mut comments: HashMap<_, _> = if comment_ids.is_empty() {
+    HashMap::new()
+  } else {
+    CommentQuery {
+      ids: Some(comment_ids),
+      limit: Some(FETCH_LIMIT_MAX),
+      ..search_query.comment_query()
+    }
+    .list(pool)
+    .await?
+    .into_iter()
+    .map(|c| (c.comment.id, c))
+    .collect()
+  };
+  let mut communities: HashMap<_, _> = if community_ids.is_empty() {
+    HashMap::new()
+  } else {
+    CommunityQuery {
+      ids: Some(community_ids),
+      local_user: local_user_view.as_ref().map(|l| &l.local_user),
+      limit: Some(FETCH_LIMIT_MAX),
+      ..Default::default()
+    }
+    .list(pool)
+    .await?
+    .into_iter()
+    .map(|c| (c.community.id, c))
+    .collect()
+  };
+  let mut persons: HashMap<_, _> = if person_ids.is_empty() {
+    HashMap::new()
+  } else {
+    PersonView::read_many(pool, person_ids)
+      .await?
+      .into_iter()
+      .map(|p| (p.person.id, p))
+      .collect()
+  };
+
+  let results = search_results
+    .iter()
+    .filter_map(|search_result| match search_result.id {
+      SearchResultId::Post(id) => posts.remove(&id).map(SearchResult::Post),
+      SearchResultId::Comment(id) => comments.remove(&id).map(SearchResult::Comment),
+      SearchResultId::Community(id) => communities.remove(&id).map(SearchResult::Community),
+      SearchResultId::Person(id) => persons.remove(&id).map(SearchResult::Person),
+    })
+    .collect();
+
+  let next_page = search_results
+    .last()
+    .map(PaginationCursor::after_search_result);
+  Ok(Json(SearchCombinedResponse { results, next_page }))
+}
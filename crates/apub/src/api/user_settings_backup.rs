@@ -6,6 +6,7 @@ use crate::objects::{
 };
 use activitypub_federation::{config::Data, fetch::object_id::ObjectId};
 use actix_web::web::Json;
+use chrono::{DateTime, Utc};
 use futures::{future::try_join_all, StreamExt};
 use lemmy_api_common::{context::LemmyContext, SuccessResponse};
 use lemmy_db_schema::{
@@ -15,6 +16,7 @@ use lemmy_db_schema::{
     community::{CommunityFollower, CommunityFollowerForm},
     community_block::{CommunityBlock, CommunityBlockForm},
     local_user::{LocalUser, LocalUserUpdateForm},
+    local_user_keyword_filter::{LocalUserKeywordFilter, LocalUserKeywordFilterForm},
     person::{Person, PersonUpdateForm},
     person_block::{PersonBlock, PersonBlockForm},
     post::{PostSaved, PostSavedForm},
@@ -25,6 +27,7 @@ use lemmy_db_views::structs::LocalUserView;
 use lemmy_utils::{
   error::{LemmyError, LemmyErrorType, LemmyResult, MAX_API_PARAM_ELEMENTS},
   spawn_try_task,
+  utils::validation::{is_valid_keyword_filter, is_valid_keyword_filter_regex},
 };
 use serde::{Deserialize, Serialize};
 use tracing::info;
@@ -58,6 +61,32 @@ pub struct UserSettingsBackup {
   pub blocked_communities: Vec<ObjectId<ApubCommunity>>,
   #[serde(default)]
   pub blocked_users: Vec<ObjectId<ApubPerson>>,
+  #[serde(default)]
+  pub keyword_filters: Vec<KeywordFilterBackup>,
+}
+
+/// A keyword filter without any ids, so that it can be imported by a different user.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct KeywordFilterBackup {
+  pub keyword: String,
+  pub is_regex: bool,
+  pub filter_title: bool,
+  pub filter_body: bool,
+  pub filter_url: bool,
+  pub expires: Option<DateTime<Utc>>,
+}
+
+impl From<LocalUserKeywordFilter> for KeywordFilterBackup {
+  fn from(f: LocalUserKeywordFilter) -> Self {
+    KeywordFilterBackup {
+      keyword: f.keyword,
+      is_regex: f.is_regex,
+      filter_title: f.filter_title,
+      filter_body: f.filter_body,
+      filter_url: f.filter_url,
+      expires: f.expires,
+    }
+  }
 }
 
 #[tracing::instrument(skip(context))]
@@ -66,6 +95,8 @@ pub async fn export_settings(
   context: Data<LemmyContext>,
 ) -> Result<Json<UserSettingsBackup>, LemmyError> {
   let lists = LocalUser::export_backup(&mut context.pool(), local_user_view.person.id).await?;
+  let keyword_filters =
+    LocalUserKeywordFilter::list(&mut context.pool(), local_user_view.local_user.id).await?;
 
   let vec_into = |vec: Vec<_>| vec.into_iter().map(Into::into).collect();
   Ok(Json(UserSettingsBackup {
@@ -81,6 +112,7 @@ pub async fn export_settings(
     blocked_users: lists.blocked_users.into_iter().map(Into::into).collect(),
     saved_posts: lists.saved_posts.into_iter().map(Into::into).collect(),
     saved_comments: lists.saved_comments.into_iter().map(Into::into).collect(),
+    keyword_filters: keyword_filters.into_iter().map(Into::into).collect(),
   }))
 }
 
@@ -118,6 +150,7 @@ pub async fn import_settings(
     auto_expand: data.settings.as_ref().map(|s| s.auto_expand),
     infinite_scroll_enabled: data.settings.as_ref().map(|s| s.infinite_scroll_enabled),
     post_listing_mode: data.settings.as_ref().map(|s| s.post_listing_mode),
+    email_digest: data.settings.as_ref().map(|s| s.email_digest),
     ..Default::default()
   };
   LocalUser::update(
@@ -131,7 +164,8 @@ pub async fn import_settings(
     + data.blocked_communities.len()
     + data.blocked_users.len()
     + data.saved_posts.len()
-    + data.saved_comments.len();
+    + data.saved_comments.len()
+    + data.keyword_filters.len();
   if url_count > MAX_API_PARAM_ELEMENTS {
     Err(LemmyErrorType::TooManyItems)?;
   }
@@ -269,6 +303,35 @@ pub async fn import_settings(
       LemmyResult::Ok(())
     }))
     .await?;
+
+    // Keyword filters which are invalid or already exist are skipped, without failing the import.
+    let local_user_id = local_user_view.local_user.id;
+    for filter in &data.keyword_filters {
+      let res = async {
+        is_valid_keyword_filter(&filter.keyword)?;
+        if filter.is_regex {
+          is_valid_keyword_filter_regex(&filter.keyword)?;
+          LocalUserKeywordFilter::check_regex(&mut context.pool(), &filter.keyword).await?;
+        }
+        let form = LocalUserKeywordFilterForm {
+          local_user_id,
+          keyword: filter.keyword.clone(),
+          is_regex: filter.is_regex,
+          filter_title: filter.filter_title,
+          filter_body: filter.filter_body,
+          filter_url: filter.filter_url,
+          expires: filter.expires,
+        };
+        LocalUserKeywordFilter::create_within_limit(&mut context.pool(), &form)
+          .await?
+          .ok_or(LemmyErrorType::TooManyKeywordFilters)?;
+        LemmyResult::Ok(())
+      }
+      .await;
+      if let Err(e) = res {
+        info!("Failed to import keyword filter: {e}");
+      }
+    }
     Ok(())
   });
 
@@ -291,6 +354,7 @@ mod tests {
       community::{Community, CommunityFollower, CommunityFollowerForm, CommunityInsertForm},
       instance::Instance,
       local_user::{LocalUser, LocalUserInsertForm},
+      local_user_keyword_filter::{LocalUserKeywordFilter, LocalUserKeywordFilterForm},
       person::{Person, PersonInsertForm},
     },
     traits::{Crud, Followable},
@@ -357,6 +421,18 @@ mod tests {
     CommunityFollower::follow(&mut context.pool(), &follower_form)
       .await
       .unwrap();
+    let keyword_filter_form = LocalUserKeywordFilterForm {
+      local_user_id: export_user.local_user.id,
+      keyword: "spoiler".to_string(),
+      is_regex: false,
+      filter_title: true,
+      filter_body: true,
+      filter_url: false,
+      expires: None,
+    };
+    LocalUserKeywordFilter::create(&mut context.pool(), &keyword_filter_form)
+      .await
+      .unwrap();
 
     let backup = export_settings(export_user.clone(), context.reset_request_count())
       .await
@@ -387,6 +463,13 @@ mod tests {
     assert_eq!(follows.len(), 1);
     assert_eq!(follows[0].community.actor_id, community.actor_id);
 
+    let keyword_filters =
+      LocalUserKeywordFilter::list(&mut context.pool(), import_user.local_user.id)
+        .await
+        .unwrap();
+    assert_eq!(keyword_filters.len(), 1);
+    assert_eq!(keyword_filters[0].keyword, "spoiler");
+
     LocalUser::delete(&mut context.pool(), export_user.local_user.id)
       .await
       .unwrap();
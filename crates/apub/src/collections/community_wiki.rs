@@ -0,0 +1,88 @@
+use crate::{
+  objects::{community::ApubCommunity, wiki_page::ApubWikiPage},
+  protocol::collections::group_wiki::GroupWiki,
+};
+use activitypub_federation::{
+  config::Data,
+  kinds::collection::OrderedCollectionType,
+  protocol::verification::verify_domains_match,
+  traits::{Collection, Object},
+};
+use futures::future::try_join_all;
+use lemmy_api_common::{context::LemmyContext, utils::generate_wiki_url};
+use lemmy_db_schema::source::community_wiki::CommunityWikiPage;
+use lemmy_utils::error::LemmyError;
+use url::Url;
+
+#[derive(Clone, Debug)]
+pub(crate) struct ApubCommunityWiki(Vec<ApubWikiPage>);
+
+#[async_trait::async_trait]
+impl Collection for ApubCommunityWiki {
+  type Owner = ApubCommunity;
+  type DataType = LemmyContext;
+  type Kind = GroupWiki;
+  type Error = LemmyError;
+
+  async fn read_local(
+    owner: &Self::Owner,
+    data: &Data<Self::DataType>,
+  ) -> Result<Self::Kind, Self::Error> {
+    let ordered_items = try_join_all(
+      CommunityWikiPage::list_for_community(&mut data.pool(), owner.id)
+        .await?
+        .into_iter()
+        .map(ApubWikiPage::from)
+        .map(|p| p.into_json(data)),
+    )
+    .await?;
+    Ok(GroupWiki {
+      r#type: OrderedCollectionType::OrderedCollection,
+      id: generate_wiki_url(&owner.actor_id)?.into(),
+      total_items: ordered_items.len() as i32,
+      ordered_items,
+    })
+  }
+
+  async fn verify(
+    apub: &Self::Kind,
+    expected_domain: &Url,
+    _data: &Data<Self::DataType>,
+  ) -> Result<(), Self::Error> {
+    verify_domains_match(expected_domain, &apub.id)?;
+    Ok(())
+  }
+
+  async fn from_json(
+    apub: Self::Kind,
+    owner: &Self::Owner,
+    data: &Data<Self::DataType>,
+  ) -> Result<Self, Self::Error>
+  where
+    Self: Sized,
+  {
+    let ap_ids = apub
+      .ordered_items
+      .iter()
+      .map(|page| page.id.clone().into())
+      .collect();
+
+    // Pages which can't be parsed are skipped, so that they don't prevent receiving the others
+    let mut pages = Vec::new();
+    for page in apub.ordered_items {
+      if ApubWikiPage::verify(&page, owner.actor_id.inner(), data)
+        .await
+        .is_ok()
+      {
+        if let Ok(page) = ApubWikiPage::from_json(page, data).await {
+          pages.push(page);
+        }
+      }
+    }
+
+    // Pages which were removed from the wiki are deleted
+    CommunityWikiPage::delete_missing(&mut data.pool(), owner.id, ap_ids).await?;
+
+    Ok(ApubCommunityWiki(pages))
+  }
+}
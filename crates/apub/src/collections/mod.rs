@@ -2,3 +2,4 @@ pub(crate) mod community_featured;
 pub(crate) mod community_follower;
 pub(crate) mod community_moderators;
 pub(crate) mod community_outbox;
+pub(crate) mod community_wiki;
@@ -1,6 +1,12 @@
 use crate::{
-  objects::{comment::ApubComment, community::ApubCommunity, person::ApubPerson, post::ApubPost},
-  protocol::objects::{group::Group, note::Note, page::Page, person::Person},
+  objects::{
+    comment::ApubComment,
+    community::ApubCommunity,
+    multi_community::ApubMultiCommunity,
+    person::ApubPerson,
+    post::ApubPost,
+  },
+  protocol::objects::{feed::Feed, group::Group, note::Note, page::Page, person::Person},
 };
 use activitypub_federation::{
   config::Data,
@@ -63,6 +69,7 @@ pub(crate) enum SearchableObjects {
   Community(ApubCommunity),
   Post(ApubPost),
   Comment(ApubComment),
+  MultiCommunity(ApubMultiCommunity),
 }
 
 #[derive(Deserialize)]
@@ -72,6 +79,7 @@ pub(crate) enum SearchableKinds {
   Person(Person),
   Page(Page),
   Note(Note),
+  Feed(Feed),
 }
 
 #[async_trait::async_trait]
@@ -86,11 +94,12 @@ impl Object for SearchableObjects {
       SearchableObjects::Community(c) => c.last_refreshed_at(),
       SearchableObjects::Post(p) => p.last_refreshed_at(),
       SearchableObjects::Comment(c) => c.last_refreshed_at(),
+      SearchableObjects::MultiCommunity(m) => m.last_refreshed_at(),
     }
   }
 
-  // TODO: this is inefficient, because if the object is not in local db, it will run 4 db queries
-  //       before finally returning an error. it would be nice if we could check all 4 tables in
+  // TODO: this is inefficient, because if the object is not in local db, it will run 5 db queries
+  //       before finally returning an error. it would be nice if we could check all 5 tables in
   //       a single query.
   //       we could skip this and always return an error, but then it would always fetch objects
   //       over http, and not be able to mark objects as deleted that were deleted by remote server.
@@ -111,10 +120,14 @@ impl Object for SearchableObjects {
     if let Some(p) = p {
       return Ok(Some(SearchableObjects::Post(p)));
     }
-    let c = ApubComment::read_from_id(object_id, context).await?;
+    let c = ApubComment::read_from_id(object_id.clone(), context).await?;
     if let Some(c) = c {
       return Ok(Some(SearchableObjects::Comment(c)));
     }
+    let m = ApubMultiCommunity::read_from_id(object_id, context).await?;
+    if let Some(m) = m {
+      return Ok(Some(SearchableObjects::MultiCommunity(m)));
+    }
     Ok(None)
   }
 
@@ -125,6 +138,7 @@ impl Object for SearchableObjects {
       SearchableObjects::Community(c) => c.delete(data).await,
       SearchableObjects::Post(p) => p.delete(data).await,
       SearchableObjects::Comment(c) => c.delete(data).await,
+      SearchableObjects::MultiCommunity(m) => m.delete(data).await,
     }
   }
 
@@ -143,6 +157,7 @@ impl Object for SearchableObjects {
       SearchableKinds::Person(a) => ApubPerson::verify(a, expected_domain, data).await,
       SearchableKinds::Page(a) => ApubPost::verify(a, expected_domain, data).await,
       SearchableKinds::Note(a) => ApubComment::verify(a, expected_domain, data).await,
+      SearchableKinds::Feed(a) => ApubMultiCommunity::verify(a, expected_domain, data).await,
     }
   }
 
@@ -155,6 +170,7 @@ impl Object for SearchableObjects {
       SAT::Person(p) => SO::Person(ApubPerson::from_json(p, context).await?),
       SAT::Page(p) => SO::Post(ApubPost::from_json(p, context).await?),
       SAT::Note(n) => SO::Comment(ApubComment::from_json(n, context).await?),
+      SAT::Feed(f) => SO::MultiCommunity(ApubMultiCommunity::from_json(f, context).await?),
     })
   }
 }
@@ -1,11 +1,20 @@
 use crate::{
-  http::{create_apub_response, create_apub_tombstone_response, err_object_not_local},
+  http::{
+    check_community_public,
+    create_apub_response,
+    create_apub_tombstone_response,
+    err_object_not_local,
+  },
   objects::comment::ApubComment,
 };
 use activitypub_federation::{config::Data, traits::Object};
 use actix_web::{web::Path, HttpResponse};
 use lemmy_api_common::context::LemmyContext;
-use lemmy_db_schema::{newtypes::CommentId, source::comment::Comment, traits::Crud};
+use lemmy_db_schema::{
+  newtypes::CommentId,
+  source::{comment::Comment, post::Post},
+  traits::Crud,
+};
 use lemmy_utils::error::LemmyError;
 use serde::Deserialize;
 
@@ -22,6 +31,8 @@ pub(crate) async fn get_apub_comment(
 ) -> Result<HttpResponse, LemmyError> {
   let id = CommentId(info.comment_id.parse::<i32>()?);
   let comment: ApubComment = Comment::read(&mut context.pool(), id).await?.into();
+  let post = Post::read(&mut context.pool(), comment.post_id).await?;
+  check_community_public(post.community_id, &context).await?;
   if !comment.local {
     Err(err_object_not_local())
   } else if !comment.deleted && !comment.removed {
@@ -5,9 +5,10 @@ use crate::{
     community_follower::ApubCommunityFollower,
     community_moderators::ApubCommunityModerators,
     community_outbox::ApubCommunityOutbox,
+    community_wiki::ApubCommunityWiki,
   },
-  http::{create_apub_response, create_apub_tombstone_response},
-  objects::{community::ApubCommunity, person::ApubPerson},
+  http::{check_community_public, create_apub_response, create_apub_tombstone_response},
+  objects::{community::ApubCommunity, person::ApubPerson, wiki_page::ApubWikiPage},
 };
 use activitypub_federation::{
   actix_web::inbox::receive_activity,
@@ -17,7 +18,10 @@ use activitypub_federation::{
 };
 use actix_web::{web, web::Bytes, HttpRequest, HttpResponse};
 use lemmy_api_common::context::LemmyContext;
-use lemmy_db_schema::{source::community::Community, traits::ApubActor};
+use lemmy_db_schema::{
+  source::{community::Community, community_wiki::CommunityWikiPage},
+  traits::ApubActor,
+};
 use lemmy_utils::error::{LemmyError, LemmyErrorType};
 use serde::Deserialize;
 
@@ -37,6 +41,11 @@ pub(crate) async fn get_apub_community_http(
       .await?
       .into();
 
+  // Private communities can be followed from other instances, local-only ones can't
+  if !community.visibility.can_federate() {
+    Err(LemmyErrorType::CommunityContentNotVisible)?
+  }
+
   if !community.deleted && !community.removed {
     let apub = community.into_json(&context).await?;
 
@@ -83,6 +92,7 @@ pub(crate) async fn get_apub_community_outbox(
   if community.deleted || community.removed {
     Err(LemmyErrorType::Deleted)?
   }
+  check_community_public(community.id, &context).await?;
   let outbox = ApubCommunityOutbox::read_local(&community, &context).await?;
   create_apub_response(&outbox)
 }
@@ -115,6 +125,50 @@ pub(crate) async fn get_apub_community_featured(
   if community.deleted || community.removed {
     Err(LemmyErrorType::Deleted)?
   }
+  check_community_public(community.id, &context).await?;
   let featured = ApubCommunityFeatured::read_local(&community, &context).await?;
   create_apub_response(&featured)
 }
+
+/// Returns collection of wiki pages.
+pub(crate) async fn get_apub_community_wiki(
+  info: web::Path<CommunityQuery>,
+  context: Data<LemmyContext>,
+) -> Result<HttpResponse, LemmyError> {
+  let community: ApubCommunity =
+    Community::read_from_name(&mut context.pool(), &info.community_name, false)
+      .await?
+      .into();
+  if community.deleted || community.removed {
+    Err(LemmyErrorType::Deleted)?
+  }
+  check_community_public(community.id, &context).await?;
+  let wiki = ApubCommunityWiki::read_local(&community, &context).await?;
+  create_apub_response(&wiki)
+}
+
+#[derive(Deserialize)]
+pub(crate) struct WikiPageQuery {
+  community_name: String,
+  slug: String,
+}
+
+/// Return the ActivityPub json representation of a local wiki page over HTTP.
+#[tracing::instrument(skip_all)]
+pub(crate) async fn get_apub_wiki_page(
+  info: web::Path<WikiPageQuery>,
+  context: Data<LemmyContext>,
+) -> Result<HttpResponse, LemmyError> {
+  let community =
+    Community::read_from_name(&mut context.pool(), &info.community_name, false).await?;
+  check_community_public(community.id, &context).await?;
+  let wiki_page: ApubWikiPage =
+    CommunityWikiPage::read_from_slug(&mut context.pool(), community.id, &info.slug)
+      .await?
+      .into();
+  if !wiki_page.deleted {
+    create_apub_response(&wiki_page.into_json(&context).await?)
+  } else {
+    create_apub_tombstone_response(wiki_page.ap_id.clone())
+  }
+}
@@ -0,0 +1,33 @@
+use crate::{
+  http::{check_community_public, create_apub_response, err_object_not_local},
+  objects::custom_emoji::ApubCustomEmoji,
+};
+use activitypub_federation::{config::Data, traits::Object};
+use actix_web::{web, HttpResponse};
+use lemmy_api_common::context::LemmyContext;
+use lemmy_db_schema::{newtypes::CustomEmojiId, source::custom_emoji::CustomEmoji};
+use lemmy_utils::error::LemmyError;
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub(crate) struct CustomEmojiQuery {
+  emoji_id: String,
+}
+
+/// Return the ActivityPub json representation of a local custom emoji over HTTP.
+#[tracing::instrument(skip_all)]
+pub(crate) async fn get_apub_custom_emoji(
+  info: web::Path<CustomEmojiQuery>,
+  context: Data<LemmyContext>,
+) -> Result<HttpResponse, LemmyError> {
+  let id = CustomEmojiId(info.emoji_id.parse::<i32>()?);
+  let emoji: ApubCustomEmoji = CustomEmoji::read(&mut context.pool(), id).await?.into();
+  if let Some(community_id) = emoji.community_id {
+    check_community_public(community_id, &context).await?;
+  }
+  if !emoji.local {
+    Err(err_object_not_local())
+  } else {
+    create_apub_response(&emoji.into_json(&context).await?)
+  }
+}
@@ -13,7 +13,12 @@ use activitypub_federation::{
 use actix_web::{web, web::Bytes, HttpRequest, HttpResponse};
 use http::StatusCode;
 use lemmy_api_common::context::LemmyContext;
-use lemmy_db_schema::source::activity::SentActivity;
+use lemmy_db_schema::{
+  newtypes::CommunityId,
+  source::{activity::SentActivity, community::Community},
+  traits::Crud,
+  CommunityVisibility,
+};
 use lemmy_utils::error::{LemmyError, LemmyErrorType, LemmyResult};
 use serde::{Deserialize, Serialize};
 use std::ops::Deref;
@@ -21,6 +26,8 @@ use url::Url;
 
 mod comment;
 mod community;
+mod custom_emoji;
+mod multi_community;
 mod person;
 mod post;
 pub mod routes;
@@ -68,6 +75,19 @@ fn err_object_not_local() -> LemmyError {
   LemmyErrorType::ObjectNotLocal.into()
 }
 
+/// Only public communities serve their content over HTTP. Activities of private communities are
+/// sent to instances with approved followers, local-only communities don't federate at all.
+async fn check_community_public(
+  community_id: CommunityId,
+  context: &Data<LemmyContext>,
+) -> LemmyResult<()> {
+  let community = Community::read(&mut context.pool(), community_id).await?;
+  if community.visibility != CommunityVisibility::Public {
+    Err(LemmyErrorType::CommunityContentNotVisible)?
+  }
+  Ok(())
+}
+
 #[derive(Deserialize)]
 pub struct ActivityQuery {
   type_: String,
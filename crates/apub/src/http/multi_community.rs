@@ -0,0 +1,33 @@
+use crate::{
+  http::{create_apub_response, create_apub_tombstone_response},
+  objects::multi_community::ApubMultiCommunity,
+};
+use activitypub_federation::{config::Data, traits::Object};
+use actix_web::{web, HttpResponse};
+use lemmy_api_common::context::LemmyContext;
+use lemmy_db_schema::source::multi_community::MultiCommunity;
+use lemmy_utils::error::LemmyError;
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub(crate) struct MultiCommunityQuery {
+  multi_community_name: String,
+}
+
+/// Return the ActivityPub json representation of a local multi-community over HTTP.
+#[tracing::instrument(skip_all)]
+pub(crate) async fn get_apub_multi_community_http(
+  info: web::Path<MultiCommunityQuery>,
+  context: Data<LemmyContext>,
+) -> Result<HttpResponse, LemmyError> {
+  let multi_community: ApubMultiCommunity =
+    MultiCommunity::read_from_name(&mut context.pool(), &info.multi_community_name, true)
+      .await?
+      .into();
+
+  if !multi_community.deleted {
+    create_apub_response(&multi_community.into_json(&context).await?)
+  } else {
+    create_apub_tombstone_response(multi_community.ap_id.clone())
+  }
+}
@@ -1,12 +1,17 @@
 use crate::{
-  http::{create_apub_response, create_apub_tombstone_response, err_object_not_local},
+  http::{
+    check_community_public,
+    create_apub_response,
+    create_apub_tombstone_response,
+    err_object_not_local,
+  },
   objects::post::ApubPost,
 };
 use activitypub_federation::{config::Data, traits::Object};
 use actix_web::{web, HttpResponse};
 use lemmy_api_common::context::LemmyContext;
 use lemmy_db_schema::{newtypes::PostId, source::post::Post, traits::Crud};
-use lemmy_utils::error::LemmyError;
+use lemmy_utils::error::{LemmyError, LemmyErrorType};
 use serde::Deserialize;
 
 #[derive(Deserialize)]
@@ -22,8 +27,12 @@ pub(crate) async fn get_apub_post(
 ) -> Result<HttpResponse, LemmyError> {
   let id = PostId(info.post_id.parse::<i32>()?);
   let post: ApubPost = Post::read(&mut context.pool(), id).await?.into();
+  check_community_public(post.community_id, &context).await?;
   if !post.local {
     Err(err_object_not_local())
+  } else if post.scheduled_publish_time.is_some() {
+    // Scheduled posts must not be visible to other instances before they are published
+    Err(LemmyErrorType::CouldntFindPost)?
   } else if !post.deleted && !post.removed {
     create_apub_response(&post.into_json(&context).await?)
   } else {
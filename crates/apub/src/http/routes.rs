@@ -7,8 +7,12 @@ use crate::http::{
     get_apub_community_http,
     get_apub_community_moderators,
     get_apub_community_outbox,
+    get_apub_community_wiki,
+    get_apub_wiki_page,
   },
+  custom_emoji::get_apub_custom_emoji,
   get_activity,
+  multi_community::get_apub_multi_community_http,
   person::{get_apub_person_http, get_apub_person_outbox, person_inbox},
   post::get_apub_post,
   shared_inbox,
@@ -44,6 +48,18 @@ pub fn config(cfg: &mut web::ServiceConfig) {
       "/c/{community_name}/moderators",
       web::get().to(get_apub_community_moderators),
     )
+    .route(
+      "/c/{community_name}/wiki",
+      web::get().to(get_apub_community_wiki),
+    )
+    .route(
+      "/c/{community_name}/wiki/{slug}",
+      web::get().to(get_apub_wiki_page),
+    )
+    .route(
+      "/m/{multi_community_name}",
+      web::get().to(get_apub_multi_community_http),
+    )
     .route("/u/{user_name}", web::get().to(get_apub_person_http))
     .route(
       "/u/{user_name}/outbox",
@@ -51,6 +67,7 @@ pub fn config(cfg: &mut web::ServiceConfig) {
     )
     .route("/post/{post_id}", web::get().to(get_apub_post))
     .route("/comment/{comment_id}", web::get().to(get_apub_comment))
+    .route("/emoji/{emoji_id}", web::get().to(get_apub_custom_emoji))
     .route("/activities/{type_}/{id}", web::get().to(get_activity));
 
   cfg.service(
@@ -1,4 +1,7 @@
-use crate::objects::{comment::ApubComment, community::ApubCommunity, person::ApubPerson};
+use crate::{
+  objects::{comment::ApubComment, community::ApubCommunity, person::ApubPerson},
+  protocol::objects::emoji::Emoji,
+};
 use activitypub_federation::{
   config::Data,
   fetch::{object_id::ObjectId, webfinger::webfinger_resolve_actor},
@@ -20,6 +23,7 @@ use url::Url;
 #[serde(untagged)]
 pub enum MentionOrValue {
   Mention(Mention),
+  Emoji(Emoji),
   Value(Value),
 }
 
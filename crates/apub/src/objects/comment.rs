@@ -1,8 +1,12 @@
 use crate::{
   activities::{verify_is_public, verify_person_in_community},
   check_apub_id_valid_with_strictness,
-  mentions::collect_non_local_mentions,
-  objects::{read_from_string_or_source, verify_is_remote_object},
+  mentions::{collect_non_local_mentions, MentionOrValue},
+  objects::{
+    custom_emoji::{emoji_tags, receive_emojis},
+    read_from_string_or_source,
+    verify_is_remote_object,
+  },
   protocol::{
     objects::{note::Note, LanguageTag},
     InCommunity,
@@ -19,7 +23,7 @@ use chrono::{DateTime, Utc};
 use lemmy_api_common::{context::LemmyContext, utils::local_site_opt_to_slur_regex};
 use lemmy_db_schema::{
   source::{
-    comment::{Comment, CommentInsertForm, CommentUpdateForm},
+    comment::{Comment, CommentInsertForm, CommentRevision, CommentUpdateForm},
     community::Community,
     local_site::LocalSite,
     person::Person,
@@ -101,7 +105,14 @@ impl Object for ApubComment {
       post.ap_id.into()
     };
     let language = LanguageTag::new_single(self.language_id, &mut context.pool()).await?;
-    let maa = collect_non_local_mentions(&self, community.actor_id.clone().into(), context).await?;
+    let mut maa =
+      collect_non_local_mentions(&self, community.actor_id.clone().into(), context).await?;
+    maa.tags.extend(
+      emoji_tags(&self.content, community.id, context)
+        .await?
+        .into_iter()
+        .map(MentionOrValue::Emoji),
+    );
 
     let note = Note {
       r#type: NoteType::Note,
@@ -159,8 +170,10 @@ impl Object for ApubComment {
     let local_site = LocalSite::read(&mut context.pool()).await.ok();
     let slur_regex = &local_site_opt_to_slur_regex(&local_site);
     let content = remove_slurs(&content, slur_regex);
+    let content = receive_emojis(note.emojis(), content, context).await;
     let language_id =
       LanguageTag::to_language_id_single(note.language, &mut context.pool()).await?;
+    let old_comment = note.id.dereference_local(context).await;
 
     let form = CommentInsertForm {
       creator_id: creator.id,
@@ -177,6 +190,9 @@ impl Object for ApubComment {
     };
     let parent_comment_path = parent_comment.map(|t| t.0.path);
     let comment = Comment::create(&mut context.pool(), &form, parent_comment_path.as_ref()).await?;
+    if let Ok(old_comment) = &old_comment {
+      CommentRevision::create_for_edit(&mut context.pool(), &old_comment.0, &comment).await?;
+    }
     Ok(comment.into())
   }
 }
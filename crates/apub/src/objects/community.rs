@@ -17,7 +17,7 @@ use activitypub_federation::{
 use chrono::{DateTime, Utc};
 use lemmy_api_common::{
   context::LemmyContext,
-  utils::{generate_featured_url, generate_moderators_url, generate_outbox_url},
+  utils::{generate_featured_url, generate_moderators_url, generate_outbox_url, generate_wiki_url},
 };
 use lemmy_db_schema::{
   source::{
@@ -26,6 +26,7 @@ use lemmy_db_schema::{
     community::{Community, CommunityUpdateForm},
   },
   traits::{ApubActor, Crud},
+  CommunityVisibility,
 };
 use lemmy_db_views_actor::structs::CommunityFollowerView;
 use lemmy_utils::{error::LemmyError, utils::markdown::markdown_to_html};
@@ -98,6 +99,7 @@ impl Object for ApubCommunity {
       image: self.banner.clone().map(ImageObject::new),
       sensitive: Some(self.nsfw),
       featured: Some(generate_featured_url(&self.actor_id)?.into()),
+      wiki: Some(generate_wiki_url(&self.actor_id)?.into()),
       inbox: self.inbox_url.clone().into(),
       outbox: generate_outbox_url(&self.actor_id)?.into(),
       followers: self.followers_url.clone().into(),
@@ -109,6 +111,7 @@ impl Object for ApubCommunity {
       published: Some(self.published),
       updated: self.updated,
       posting_restricted_to_mods: Some(self.posting_restricted_to_mods),
+      manually_approves_followers: Some(self.visibility == CommunityVisibility::Private),
       attributed_to: Some(generate_moderators_url(&self.actor_id)?.into()),
     };
     Ok(group)
@@ -158,6 +161,14 @@ impl Object for ApubCommunity {
       res.1.map_err(|e| debug!("{}", e)).ok();
     }
 
+    if let Some(wiki) = group.wiki {
+      wiki
+        .dereference(&community, context)
+        .await
+        .map_err(|e| debug!("{}", e))
+        .ok();
+    }
+
     Ok(community)
   }
 }
@@ -234,6 +245,7 @@ pub(crate) mod tests {
     let mut json: Group = file_to_json_object("assets/lemmy/objects/group.json").unwrap();
     // change these links so they dont fetch over the network
     json.attributed_to = None;
+    json.wiki = None;
     json.outbox =
       CollectionId::parse("https://enterprise.lemmy.ml/c/tenforward/not_outbox").unwrap();
     json.followers =
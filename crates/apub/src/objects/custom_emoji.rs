@@ -0,0 +1,237 @@
+use crate::{
+  check_apub_id_valid_with_strictness,
+  objects::verify_is_remote_object,
+  protocol::{
+    objects::emoji::{Emoji, EmojiType},
+    ImageObject,
+  },
+};
+use activitypub_federation::{
+  config::Data,
+  protocol::verification::verify_domains_match,
+  traits::Object,
+};
+use chrono::{DateTime, Utc};
+use lemmy_api_common::context::LemmyContext;
+use lemmy_db_schema::{
+  newtypes::CommunityId,
+  source::custom_emoji::{CustomEmoji, CustomEmojiInsertForm},
+};
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorType},
+  utils::validation::{check_url_scheme, is_valid_custom_emoji_shortcode},
+};
+use std::ops::Deref;
+use url::Url;
+
+#[derive(Clone, Debug)]
+pub struct ApubCustomEmoji(pub(crate) CustomEmoji);
+
+impl Deref for ApubCustomEmoji {
+  type Target = CustomEmoji;
+  fn deref(&self) -> &Self::Target {
+    &self.0
+  }
+}
+
+impl From<CustomEmoji> for ApubCustomEmoji {
+  fn from(e: CustomEmoji) -> Self {
+    ApubCustomEmoji(e)
+  }
+}
+
+#[async_trait::async_trait]
+impl Object for ApubCustomEmoji {
+  type DataType = LemmyContext;
+  type Kind = Emoji;
+  type Error = LemmyError;
+
+  fn last_refreshed_at(&self) -> Option<DateTime<Utc>> {
+    None
+  }
+
+  #[tracing::instrument(skip_all)]
+  async fn read_from_id(
+    object_id: Url,
+    context: &Data<Self::DataType>,
+  ) -> Result<Option<Self>, LemmyError> {
+    Ok(
+      CustomEmoji::read_from_apub_id(&mut context.pool(), object_id)
+        .await?
+        .map(Into::into),
+    )
+  }
+
+  #[tracing::instrument(skip_all)]
+  async fn delete(self, context: &Data<Self::DataType>) -> Result<(), LemmyError> {
+    CustomEmoji::delete(&mut context.pool(), self.id).await?;
+    Ok(())
+  }
+
+  #[tracing::instrument(skip_all)]
+  async fn into_json(self, _context: &Data<Self::DataType>) -> Result<Emoji, LemmyError> {
+    Ok(Emoji {
+      kind: EmojiType::Emoji,
+      id: self.ap_id.clone().into(),
+      name: format!(":{}:", self.shortcode),
+      icon: ImageObject::new(self.image_url.clone()),
+      updated: self.updated,
+    })
+  }
+
+  #[tracing::instrument(skip_all)]
+  async fn verify(
+    emoji: &Emoji,
+    expected_domain: &Url,
+    context: &Data<Self::DataType>,
+  ) -> Result<(), LemmyError> {
+    verify_domains_match(emoji.id.inner(), expected_domain)?;
+    verify_is_remote_object(emoji.id.inner(), context.settings())?;
+    check_apub_id_valid_with_strictness(emoji.id.inner(), false, context).await?;
+    emoji.shortcode()?;
+    check_url_scheme(&Some(emoji.icon.url.clone()))?;
+    Ok(())
+  }
+
+  #[tracing::instrument(skip_all)]
+  async fn from_json(
+    emoji: Emoji,
+    context: &Data<Self::DataType>,
+  ) -> Result<ApubCustomEmoji, LemmyError> {
+    let shortcode = emoji.shortcode()?;
+    // Remote emojis are grouped by the instance they come from
+    let category = emoji.id.inner().domain().unwrap_or_default().to_string();
+    let form = CustomEmojiInsertForm::builder()
+      .alt_text(shortcode.clone())
+      .shortcode(shortcode)
+      .image_url(emoji.icon.url.into())
+      .category(category)
+      .ap_id(Some(emoji.id.into()))
+      .local(Some(false))
+      .updated(emoji.updated)
+      .build();
+    let emoji = CustomEmoji::upsert(&mut context.pool(), &form).await?;
+    Ok(emoji.into())
+  }
+}
+
+/// Returns the emojis which are used in the given text, so that they can be attached to a post or
+/// comment in the community.
+pub(crate) async fn emoji_tags(
+  text: &str,
+  community_id: CommunityId,
+  context: &Data<LemmyContext>,
+) -> Result<Vec<Emoji>, LemmyError> {
+  let mut tags = vec![];
+  for emoji in CustomEmoji::list_for_community(&mut context.pool(), community_id).await? {
+    if text.contains(&format!(":{}:", emoji.shortcode)) {
+      tags.push(ApubCustomEmoji(emoji).into_json(context).await?);
+    }
+  }
+  Ok(tags)
+}
+
+/// Stores the remote emojis which were attached to a received post or comment. Their shortcodes
+/// in the text are replaced with inline images, as the emojis aren't part of any emoji set which
+/// clients know about. Invalid emojis are skipped and their shortcodes left as they are.
+pub(crate) async fn receive_emojis(
+  tags: Vec<&Emoji>,
+  mut text: String,
+  context: &Data<LemmyContext>,
+) -> String {
+  for tag in tags {
+    let Ok(shortcode) = tag.shortcode() else {
+      continue;
+    };
+    let pattern = format!(":{shortcode}:");
+    if !text.contains(&pattern)
+      || ApubCustomEmoji::verify(tag, tag.id.inner(), context)
+        .await
+        .is_err()
+    {
+      continue;
+    }
+    if let Ok(emoji) = ApubCustomEmoji::from_json(tag.clone(), context).await {
+      if let Some(image) = emoji_markdown(&emoji) {
+        text = text.replace(&pattern, &image);
+      }
+    }
+  }
+  text
+}
+
+/// Renders a remote emoji as markdown image. The characters which would end the link or its title
+/// are percent-encoded in the url, so that it can't inject any other markdown.
+fn emoji_markdown(emoji: &CustomEmoji) -> Option<String> {
+  let shortcode = &emoji.shortcode;
+  is_valid_custom_emoji_shortcode(shortcode).ok()?;
+  let url = emoji
+    .image_url
+    .inner()
+    .as_str()
+    .replace('(', "%28")
+    .replace(')', "%29")
+    .replace('"', "%22");
+  Some(format!("![{shortcode}]({url} \"emoji {shortcode}\")"))
+}
+
+/// Returns the custom emoji with the given shortcode from the tags of a received reaction. Emojis
+/// of this instance are read from the database, remote ones are stored like in [receive_emojis].
+pub(crate) async fn receive_reaction_emoji(
+  shortcode: &str,
+  tags: Vec<&Emoji>,
+  context: &Data<LemmyContext>,
+) -> Result<CustomEmoji, LemmyError> {
+  let tag = tags
+    .into_iter()
+    .find(|t| t.shortcode().ok().as_deref() == Some(shortcode))
+    .ok_or(LemmyErrorType::InvalidEmojiReaction)?;
+  let existing =
+    CustomEmoji::read_from_apub_id(&mut context.pool(), tag.id.inner().clone()).await?;
+  if let Some(emoji) = existing.filter(|e| e.local) {
+    return Ok(emoji);
+  }
+  ApubCustomEmoji::verify(tag, tag.id.inner(), context).await?;
+  Ok(ApubCustomEmoji::from_json(tag.clone(), context).await?.0)
+}
+
+#[cfg(test)]
+mod tests {
+  #![allow(clippy::unwrap_used)]
+
+  use super::*;
+  use lemmy_db_schema::newtypes::CustomEmojiId;
+
+  fn emoji(shortcode: &str, image_url: &str) -> CustomEmoji {
+    CustomEmoji {
+      id: CustomEmojiId(1),
+      local_site_id: None,
+      shortcode: shortcode.to_string(),
+      image_url: Url::parse(image_url).unwrap().into(),
+      alt_text: shortcode.to_string(),
+      category: "example.com".to_string(),
+      published: Utc::now(),
+      updated: None,
+      community_id: None,
+      ap_id: Url::parse("https://example.com/emoji/1").unwrap().into(),
+      local: false,
+    }
+  }
+
+  #[test]
+  fn test_emoji_markdown() {
+    assert_eq!(
+      Some("![blob](https://example.com/blob.png \"emoji blob\")".to_string()),
+      emoji_markdown(&emoji("blob", "https://example.com/blob.png"))
+    );
+    // The link and its title can't be closed by the url
+    assert_eq!(
+      Some("![blob](https://example.com/a%29%22b.png \"emoji blob\")".to_string()),
+      emoji_markdown(&emoji("blob", "https://example.com/a)\"b.png"))
+    );
+    assert_eq!(
+      None,
+      emoji_markdown(&emoji("bl\"ob", "https://example.com/blob.png"))
+    );
+  }
+}
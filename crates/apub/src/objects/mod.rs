@@ -7,10 +7,13 @@ use url::Url;
 
 pub mod comment;
 pub mod community;
+pub mod custom_emoji;
 pub mod instance;
+pub mod multi_community;
 pub mod person;
 pub mod post;
 pub mod private_message;
+pub mod wiki_page;
 
 pub(crate) fn read_from_string_or_source(
   content: &str,
@@ -99,6 +102,8 @@ pub(crate) mod tests {
     let secret = Secret {
       id: 0,
       jwt_secret: String::new(),
+      vapid_private_key: None,
+      vapid_public_key: None,
     };
 
     let rate_limit_config = RateLimitConfig::builder().build();
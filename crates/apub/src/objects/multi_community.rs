@@ -0,0 +1,176 @@
+use crate::{
+  check_apub_id_valid_with_strictness,
+  local_site_data_cached,
+  objects::{
+    community::ApubCommunity,
+    instance::fetch_instance_actor_for_object,
+    person::ApubPerson,
+    read_from_string_or_source_opt,
+    verify_is_remote_object,
+  },
+  protocol::{objects::feed::Feed, Source},
+};
+use activitypub_federation::{
+  config::Data,
+  kinds::collection::CollectionType,
+  protocol::verification::verify_domains_match,
+  traits::Object,
+};
+use chrono::{DateTime, Utc};
+use lemmy_api_common::{context::LemmyContext, utils::local_site_opt_to_slur_regex};
+use lemmy_db_schema::{
+  source::{
+    multi_community::{
+      MultiCommunity,
+      MultiCommunityEntry,
+      MultiCommunityInsertForm,
+      MultiCommunityUpdateForm,
+    },
+    person::Person,
+  },
+  traits::Crud,
+  utils::naive_now,
+};
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorType, MAX_API_PARAM_ELEMENTS},
+  utils::{
+    markdown::markdown_to_html,
+    slurs::{check_slurs, check_slurs_opt},
+  },
+};
+use std::ops::Deref;
+use url::Url;
+
+#[derive(Clone, Debug)]
+pub struct ApubMultiCommunity(MultiCommunity);
+
+impl Deref for ApubMultiCommunity {
+  type Target = MultiCommunity;
+  fn deref(&self) -> &Self::Target {
+    &self.0
+  }
+}
+
+impl From<MultiCommunity> for ApubMultiCommunity {
+  fn from(m: MultiCommunity) -> Self {
+    ApubMultiCommunity(m)
+  }
+}
+
+#[async_trait::async_trait]
+impl Object for ApubMultiCommunity {
+  type DataType = LemmyContext;
+  type Kind = Feed;
+  type Error = LemmyError;
+
+  fn last_refreshed_at(&self) -> Option<DateTime<Utc>> {
+    Some(self.last_refreshed_at)
+  }
+
+  #[tracing::instrument(skip_all)]
+  async fn read_from_id(
+    object_id: Url,
+    context: &Data<Self::DataType>,
+  ) -> Result<Option<Self>, LemmyError> {
+    Ok(
+      MultiCommunity::read_from_apub_id(&mut context.pool(), object_id)
+        .await?
+        .map(Into::into),
+    )
+  }
+
+  #[tracing::instrument(skip_all)]
+  async fn delete(self, context: &Data<Self::DataType>) -> Result<(), LemmyError> {
+    let form = MultiCommunityUpdateForm {
+      deleted: Some(true),
+      ..Default::default()
+    };
+    MultiCommunity::update(&mut context.pool(), self.id, &form).await?;
+    Ok(())
+  }
+
+  #[tracing::instrument(skip_all)]
+  async fn into_json(self, context: &Data<Self::DataType>) -> Result<Feed, LemmyError> {
+    let creator = Person::read(&mut context.pool(), self.creator_id).await?;
+    let items = MultiCommunity::list_communities(&mut context.pool(), self.id)
+      .await?
+      .into_iter()
+      .map(|c| c.actor_id.into())
+      .collect();
+
+    Ok(Feed {
+      kind: CollectionType::Collection,
+      id: self.ap_id.clone().into(),
+      attributed_to: creator.actor_id.into(),
+      preferred_username: self.name.clone(),
+      name: self.title.clone(),
+      summary: self.description.as_ref().map(|d| markdown_to_html(d)),
+      source: self.description.clone().map(Source::new),
+      items,
+      published: Some(self.published),
+      updated: self.updated,
+    })
+  }
+
+  #[tracing::instrument(skip_all)]
+  async fn verify(
+    feed: &Feed,
+    expected_domain: &Url,
+    context: &Data<Self::DataType>,
+  ) -> Result<(), LemmyError> {
+    check_apub_id_valid_with_strictness(feed.id.inner(), true, context).await?;
+    verify_domains_match(expected_domain, feed.id.inner())?;
+    verify_domains_match(feed.attributed_to.inner(), feed.id.inner())?;
+    verify_is_remote_object(feed.id.inner(), context.settings())?;
+    // Every item is dereferenced when the feed is stored, so the same limit applies as in the api
+    if feed.items.len() > MAX_API_PARAM_ELEMENTS {
+      Err(LemmyErrorType::TooManyItems)?
+    }
+
+    let local_site_data = local_site_data_cached(&mut context.pool()).await?;
+    let slur_regex = &local_site_opt_to_slur_regex(&local_site_data.local_site);
+    check_slurs(&feed.preferred_username, slur_regex)?;
+    check_slurs(&feed.name, slur_regex)?;
+    let description = read_from_string_or_source_opt(&feed.summary, &None, &feed.source);
+    check_slurs_opt(&description, slur_regex)?;
+    Ok(())
+  }
+
+  /// Stores the multi-community and replaces its entries with the listed communities. Communities
+  /// which can't be fetched are left out.
+  #[tracing::instrument(skip_all)]
+  async fn from_json(
+    feed: Feed,
+    context: &Data<Self::DataType>,
+  ) -> Result<ApubMultiCommunity, LemmyError> {
+    let instance_id = fetch_instance_actor_for_object(&feed.id, context).await?;
+    let creator = feed.attributed_to.dereference(context).await?;
+
+    let form = MultiCommunityInsertForm::builder()
+      .creator_id(creator.id)
+      .instance_id(instance_id)
+      .name(feed.preferred_username.clone())
+      .title(feed.name.clone())
+      .description(read_from_string_or_source_opt(
+        &feed.summary,
+        &None,
+        &feed.source,
+      ))
+      .local(Some(false))
+      .ap_id(Some(feed.id.clone().into()))
+      .published(feed.published)
+      .updated(feed.updated)
+      .last_refreshed_at(Some(naive_now()))
+      .build();
+    let multi_community = MultiCommunity::upsert(&mut context.pool(), &form).await?;
+
+    let mut community_ids = vec![];
+    for item in &feed.items {
+      let community: Option<ApubCommunity> = item.dereference(context).await.ok();
+      community_ids.extend(community.map(|c| c.id));
+    }
+    MultiCommunityEntry::update(&mut context.pool(), multi_community.id, community_ids).await?;
+
+    Ok(multi_community.into())
+  }
+}
@@ -106,6 +106,7 @@ impl Object for ApubPerson {
       icon: self.avatar.clone().map(ImageObject::new),
       image: self.banner.clone().map(ImageObject::new),
       matrix_user_id: self.matrix_user_id.clone(),
+      encryption_public_key: self.encryption_public_key.clone(),
       published: Some(self.published),
       outbox: generate_outbox_url(&self.actor_id)?.into(),
       endpoints: self.shared_inbox_url.clone().map(|s| Endpoints {
@@ -171,6 +172,7 @@ impl Object for ApubPerson {
       shared_inbox_url: person.endpoints.map(|e| e.shared_inbox.into()),
       matrix_user_id: person.matrix_user_id,
       instance_id,
+      encryption_public_key: person.encryption_public_key,
     };
     let person = DbPerson::upsert(&mut context.pool(), &person_form).await?;
 
@@ -2,10 +2,24 @@ use crate::{
   activities::{verify_is_public, verify_person_in_community},
   check_apub_id_valid_with_strictness,
   local_site_data_cached,
-  objects::{read_from_string_or_source_opt, verify_is_remote_object},
+  objects::{
+    custom_emoji::{emoji_tags, receive_emojis},
+    read_from_string_or_source_opt,
+    verify_is_remote_object,
+  },
   protocol::{
     objects::{
-      page::{Attachment, AttributedTo, Page, PageType},
+      page::{
+        Attachment,
+        AttributedTo,
+        CommunityPostTag,
+        CommunityPostTagType,
+        Page,
+        PageTag,
+        PageType,
+        QuestionOption,
+        QuestionOptionReplies,
+      },
       LanguageTag,
     },
     ImageObject,
@@ -15,7 +29,7 @@ use crate::{
 };
 use activitypub_federation::{
   config::Data,
-  kinds::public,
+  kinds::{collection::CollectionType, object::NoteType, public},
   protocol::{values::MediaTypeMarkdownOrHtml, verification::verify_domains_match},
   traits::Object,
 };
@@ -29,12 +43,15 @@ use lemmy_api_common::{
 };
 use lemmy_db_schema::{
   self,
+  newtypes::CommunityPostTagId,
   source::{
     community::Community,
+    community_post_tag::{self, CommunityPostTagInsertForm, PostTag},
     local_site::LocalSite,
     moderator::{ModLockPost, ModLockPostForm},
     person::Person,
-    post::{Post, PostInsertForm, PostUpdateForm},
+    poll::{Poll, PollForm, PollOption, PollOptionForm},
+    post::{Post, PostInsertForm, PostRevision, PostUpdateForm},
   },
   traits::Crud,
 };
@@ -43,7 +60,7 @@ use lemmy_utils::{
   utils::{
     markdown::markdown_to_html,
     slurs::{check_slurs_opt, remove_slurs},
-    validation::check_url_scheme,
+    validation::{check_url_scheme, is_valid_poll_options, is_valid_post_tag_name},
   },
 };
 use std::ops::Deref;
@@ -110,9 +127,52 @@ impl Object for ApubPost {
     let community_id = self.community_id;
     let community = Community::read(&mut context.pool(), community_id).await?;
     let language = LanguageTag::new_single(self.language_id, &mut context.pool()).await?;
+    let mut tag: Vec<_> =
+      community_post_tag::CommunityPostTag::list_for_post(&mut context.pool(), self.id)
+        .await?
+        .into_iter()
+        .map(|t| {
+          PageTag::CommunityPostTag(CommunityPostTag {
+            kind: CommunityPostTagType::CommunityPostTag,
+            id: t.ap_id.into(),
+            name: t.display_name,
+          })
+        })
+        .collect();
+    let text = format!("{} {}", self.name, self.body.as_deref().unwrap_or_default());
+    tag.extend(
+      emoji_tags(&text, community.id, context)
+        .await?
+        .into_iter()
+        .map(PageTag::Emoji),
+    );
+    let poll = Poll::read_for_post(&mut context.pool(), self.id).await?;
+    let (kind, one_of, any_of) = if let Some(poll) = &poll {
+      // Don't leak vote counts which are hidden until the poll is closed
+      let hide_results = poll.hide_results && poll.end_time.map_or(true, |e| e > Utc::now());
+      let options = PollOption::list_for_post(&mut context.pool(), self.id)
+        .await?
+        .into_iter()
+        .map(|o| QuestionOption {
+          kind: NoteType::Note,
+          name: o.name,
+          replies: Some(QuestionOptionReplies {
+            kind: CollectionType::Collection,
+            total_items: if hide_results { 0 } else { o.vote_count },
+          }),
+        })
+        .collect();
+      if poll.multiple_choice {
+        (PageType::Question, None, Some(options))
+      } else {
+        (PageType::Question, Some(options), None)
+      }
+    } else {
+      (PageType::Page, None, None)
+    };
 
     let page = Page {
-      kind: PageType::Page,
+      kind,
       id: self.ap_id.clone().into(),
       attributed_to: AttributedTo::Lemmy(creator.actor_id.into()),
       to: vec![community.actor_id.clone().into(), public()],
@@ -130,6 +190,12 @@ impl Object for ApubPost {
       updated: self.updated,
       audience: Some(community.actor_id.into()),
       in_reply_to: None,
+      tag,
+      one_of,
+      any_of,
+      end_time: poll.as_ref().and_then(|p| p.end_time),
+      voters_count: poll.as_ref().map(|p| p.voters_count),
+      hide_results: poll.as_ref().map(|p| p.hide_results),
     };
     Ok(page)
   }
@@ -195,8 +261,9 @@ impl Object for ApubPost {
     // read existing, local post if any (for generating mod log)
     let old_post = page.id.dereference_local(context).await;
 
-    let form = if !page.is_mod_action(context).await? {
-      let first_attachment = page.attachment.into_iter().map(Attachment::url).next();
+    let is_mod_action = page.is_mod_action(context).await?;
+    let form = if !is_mod_action {
+      let first_attachment = page.attachment.first().cloned().map(Attachment::url);
       let url = if first_attachment.is_some() {
         first_attachment
       } else if page.kind == PageType::Video {
@@ -228,7 +295,7 @@ impl Object for ApubPost {
         _ => (None, None),
       };
       // If no image was included with metadata, use post image instead when available.
-      let thumbnail_url = thumbnail.or_else(|| page.image.map(|i| i.url.into()));
+      let thumbnail_url = thumbnail.or_else(|| page.image.as_ref().map(|i| i.url.clone().into()));
 
       let (embed_title, embed_description, embed_video_url) = metadata_res
         .map(|u| (u.title, u.description, u.embed_video_url))
@@ -237,8 +304,12 @@ impl Object for ApubPost {
 
       let body = read_from_string_or_source_opt(&page.content, &page.media_type, &page.source)
         .map(|s| remove_slurs(&s, slur_regex));
+      let body = match body {
+        Some(body) => Some(receive_emojis(page.emojis(), body, context).await),
+        None => None,
+      };
       let language_id =
-        LanguageTag::to_language_id_single(page.language, &mut context.pool()).await?;
+        LanguageTag::to_language_id_single(page.language.clone(), &mut context.pool()).await?;
 
       PostInsertForm {
         name,
@@ -261,6 +332,7 @@ impl Object for ApubPost {
         language_id,
         featured_community: None,
         featured_local: None,
+        scheduled_publish_time: None,
       }
     } else {
       // if is mod action, only update locked/stickied fields, nothing else
@@ -276,6 +348,15 @@ impl Object for ApubPost {
 
     let post = Post::create(&mut context.pool(), &form).await?;
 
+    if !is_mod_action {
+      let tag_ids = receive_post_tags(&page.tag, &community, context).await?;
+      PostTag::set(&mut context.pool(), post.id, tag_ids).await?;
+      receive_poll(&page, &post, context).await?;
+      if let Ok(old_post) = &old_post {
+        PostRevision::create_for_edit(&mut context.pool(), &old_post.0, &post).await?;
+      }
+    }
+
     // write mod log entry for lock
     if Page::is_locked_changed(&old_post, &page.comments_enabled) {
       let form = ModLockPostForm {
@@ -290,6 +371,84 @@ impl Object for ApubPost {
   }
 }
 
+/// Returns the ids of the community post tags which are attached to a received post. Tags of
+/// remote communities are stored when first seen, while those of local communities need to exist
+/// already.
+async fn receive_post_tags(
+  tags: &[PageTag],
+  community: &Community,
+  context: &Data<LemmyContext>,
+) -> Result<Vec<CommunityPostTagId>, LemmyError> {
+  let mut tag_ids = vec![];
+  for tag in tags {
+    let PageTag::CommunityPostTag(tag) = tag else {
+      continue;
+    };
+    if community.local {
+      let local_tag = community_post_tag::CommunityPostTag::read_from_apub_id(
+        &mut context.pool(),
+        tag.id.clone(),
+      )
+      .await?;
+      if let Some(local_tag) = local_tag {
+        if local_tag.community_id == community.id && !local_tag.deleted {
+          tag_ids.push(local_tag.id);
+        }
+      }
+    } else {
+      // The tag must belong to the community it was defined in
+      if verify_domains_match(&tag.id, community.actor_id.inner()).is_err()
+        || is_valid_post_tag_name(&tag.name).is_err()
+      {
+        continue;
+      }
+      let form = CommunityPostTagInsertForm::builder()
+        .community_id(community.id)
+        .display_name(tag.name.clone())
+        .ap_id(Some(tag.id.clone().into()))
+        .build();
+      let tag = community_post_tag::CommunityPostTag::upsert(&mut context.pool(), &form).await?;
+      tag_ids.extend(tag.map(|t| t.id));
+    }
+  }
+  Ok(tag_ids)
+}
+
+/// Stores the poll of a received post, including the vote counts of the remote instance. Polls
+/// with invalid options are ignored.
+async fn receive_poll(
+  page: &Page,
+  post: &Post,
+  context: &Data<LemmyContext>,
+) -> Result<(), LemmyError> {
+  let Some((options, multiple_choice)) = page.poll_options() else {
+    return Ok(());
+  };
+  let names = options.iter().map(|o| o.name.clone()).collect::<Vec<_>>();
+  if is_valid_poll_options(&names).is_err() {
+    return Ok(());
+  }
+  let poll_form = PollForm {
+    post_id: post.id,
+    multiple_choice,
+    end_time: page.end_time,
+    hide_results: page.hide_results.unwrap_or(false),
+    voters_count: Some(page.voters_count.unwrap_or(0)),
+  };
+  let option_forms = options
+    .iter()
+    .enumerate()
+    .map(|(position, o)| PollOptionForm {
+      post_id: post.id,
+      name: o.name.clone(),
+      position: position as i32,
+      vote_count: Some(o.replies.as_ref().map(|r| r.total_items).unwrap_or(0)),
+    })
+    .collect();
+  Poll::upsert(&mut context.pool(), &poll_form, option_forms).await?;
+  Ok(())
+}
+
 #[cfg(test)]
 mod tests {
   #![allow(clippy::unwrap_used)]
@@ -329,6 +488,12 @@ mod tests {
     assert!(!post.featured_community);
     assert_eq!(context.request_count(), 0);
 
+    let tags = community_post_tag::CommunityPostTag::list_for_post(&mut context.pool(), post.id)
+      .await
+      .unwrap();
+    assert_eq!(1, tags.len());
+    assert_eq!("Question", tags[0].display_name);
+
     cleanup(&context, person, site, community, post).await;
   }
 
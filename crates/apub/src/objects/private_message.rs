@@ -1,6 +1,6 @@
 use crate::{
   check_apub_id_valid_with_strictness,
-  objects::read_from_string_or_source,
+  objects::{person::ApubPerson, read_from_string_or_source},
   protocol::{
     objects::chat_message::{ChatMessage, ChatMessageType},
     Source,
@@ -12,9 +12,13 @@ use activitypub_federation::{
   traits::Object,
 };
 use chrono::{DateTime, Utc};
-use lemmy_api_common::{context::LemmyContext, utils::check_person_block};
+use lemmy_api_common::{
+  context::LemmyContext,
+  utils::{check_conversation_participant, check_person_block, get_or_create_direct_conversation},
+};
 use lemmy_db_schema::{
   source::{
+    conversation::{Conversation, ConversationInsertForm, ConversationParticipant},
     person::Person,
     private_message::{PrivateMessage, PrivateMessageInsertForm},
   },
@@ -75,19 +79,36 @@ impl Object for ApubPrivateMessage {
     let creator_id = self.creator_id;
     let creator = Person::read(&mut context.pool(), creator_id).await?;
 
-    let recipient_id = self.recipient_id;
-    let recipient = Person::read(&mut context.pool(), recipient_id).await?;
+    // Messages to a group conversation are sent to all other participants, along with the id of
+    // the conversation
+    let (to, conversation_id) = match self.recipient_id {
+      Some(recipient_id) => {
+        let recipient = Person::read(&mut context.pool(), recipient_id).await?;
+        (vec![recipient.actor_id.into()], None)
+      }
+      None => {
+        let conversation = Conversation::read(&mut context.pool(), self.conversation_id).await?;
+        let to = Conversation::list_participants(&mut context.pool(), conversation.id)
+          .await?
+          .into_iter()
+          .filter(|p| p.id != creator_id)
+          .map(|p| p.actor_id.into())
+          .collect();
+        (to, Some(conversation.ap_id.into()))
+      }
+    };
 
     let note = ChatMessage {
       r#type: ChatMessageType::ChatMessage,
       id: self.ap_id.clone().into(),
       attributed_to: creator.actor_id.into(),
-      to: [recipient.actor_id.into()],
+      to,
       content: markdown_to_html(&self.content),
       media_type: Some(MediaTypeHtml::Html),
       source: Some(Source::new(self.content.clone())),
       published: Some(self.published),
       updated: self.updated,
+      context: conversation_id,
     };
     Ok(note)
   }
@@ -118,14 +139,32 @@ impl Object for ApubPrivateMessage {
     context: &Data<Self::DataType>,
   ) -> Result<ApubPrivateMessage, LemmyError> {
     let creator = note.attributed_to.dereference(context).await?;
-    let recipient = note.to[0].dereference(context).await?;
-    check_person_block(creator.id, recipient.id, &mut context.pool()).await?;
+    let mut recipients = Vec::with_capacity(note.to.len());
+    for recipient in &note.to {
+      recipients.push(recipient.dereference(context).await?);
+    }
+
+    let (conversation, recipient_id) = match (&note.context, recipients.as_slice()) {
+      (Some(conversation_id), _) => {
+        let conversation =
+          receive_group_conversation(conversation_id, &note, &creator, &recipients, context)
+            .await?;
+        (conversation, None)
+      }
+      (None, [recipient]) => {
+        check_person_block(creator.id, recipient.id, &mut context.pool()).await?;
+        let conversation =
+          get_or_create_direct_conversation(creator.id, recipient.id, context).await?;
+        (conversation, Some(recipient.id))
+      }
+      (None, _) => Err(LemmyErrorType::InvalidConversationParticipants)?,
+    };
 
     let content = read_from_string_or_source(&note.content, &None, &note.source);
 
     let form = PrivateMessageInsertForm {
       creator_id: creator.id,
-      recipient_id: recipient.id,
+      recipient_id,
       content,
       published: note.published.map(Into::into),
       updated: note.updated.map(Into::into),
@@ -133,12 +172,66 @@ impl Object for ApubPrivateMessage {
       read: None,
       ap_id: Some(note.id.into()),
       local: Some(false),
+      conversation_id: conversation.id,
+      encrypted: None,
+      encryption_metadata: None,
     };
     let pm = PrivateMessage::create(&mut context.pool(), &form).await?;
     Ok(pm.into())
   }
 }
 
+/// Finds the group conversation which a received message belongs to. Remote conversations are
+/// created when their first message is received, and learn about new participants from the
+/// messages of their own instance. Messages from other instances, and all messages in local
+/// conversations, have to be written by a participant.
+async fn receive_group_conversation(
+  conversation_id: &Url,
+  note: &ChatMessage,
+  creator: &Person,
+  recipients: &[ApubPerson],
+  context: &Data<LemmyContext>,
+) -> Result<Conversation, LemmyError> {
+  for recipient in recipients.iter().filter(|r| r.local) {
+    check_person_block(creator.id, recipient.id, &mut context.pool()).await?;
+  }
+
+  let existing =
+    Conversation::read_from_apub_id(&mut context.pool(), conversation_id.clone()).await?;
+  let (conversation, new_participants) = match existing {
+    Some(conversation)
+      if conversation.local
+        || verify_domains_match(conversation.ap_id.inner(), note.id.inner()).is_err() =>
+    {
+      check_conversation_participant(conversation.id, creator.id, &mut context.pool()).await?;
+      return Ok(conversation);
+    }
+    // Local participants who left a remote conversation are not added again
+    Some(conversation) => {
+      let remote = recipients
+        .iter()
+        .filter(|r| !r.local)
+        .map(|r| r.id)
+        .collect::<Vec<_>>();
+      (conversation, remote)
+    }
+    None => {
+      // The conversation was started on the same instance as its first message
+      verify_domains_match(conversation_id, note.id.inner())?;
+      let form = ConversationInsertForm::builder()
+        .ap_id(Some(conversation_id.clone().into()))
+        .local(Some(false))
+        .build();
+      let conversation = Conversation::upsert(&mut context.pool(), &form).await?;
+      (conversation, recipients.iter().map(|r| r.id).collect())
+    }
+  };
+
+  ConversationParticipant::add(&mut context.pool(), conversation.id, &[creator.id]).await?;
+  ConversationParticipant::add(&mut context.pool(), conversation.id, &new_participants).await?;
+  Ok(conversation)
+}
+
 #[cfg(test)]
 mod tests {
   #![allow(clippy::unwrap_used)]
@@ -0,0 +1,165 @@
+use crate::{
+  check_apub_id_valid_with_strictness,
+  objects::read_from_string_or_source,
+  protocol::{objects::article::Article, Source},
+};
+use activitypub_federation::{
+  config::Data,
+  kinds::object::ArticleType,
+  protocol::{values::MediaTypeHtml, verification::verify_domains_match},
+  traits::Object,
+};
+use chrono::{DateTime, Utc};
+use lemmy_api_common::context::LemmyContext;
+use lemmy_db_schema::{
+  source::{
+    community::Community,
+    community_wiki::{
+      CommunityWikiPage,
+      CommunityWikiPageInsertForm,
+      CommunityWikiPageRevision,
+      CommunityWikiPageRevisionForm,
+      CommunityWikiPageUpdateForm,
+    },
+    person::Person,
+  },
+  traits::Crud,
+};
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorType},
+  utils::markdown::markdown_to_html,
+};
+use std::ops::Deref;
+use url::Url;
+
+#[derive(Clone, Debug)]
+pub struct ApubWikiPage(pub(crate) CommunityWikiPage);
+
+impl Deref for ApubWikiPage {
+  type Target = CommunityWikiPage;
+  fn deref(&self) -> &Self::Target {
+    &self.0
+  }
+}
+
+impl From<CommunityWikiPage> for ApubWikiPage {
+  fn from(p: CommunityWikiPage) -> Self {
+    ApubWikiPage(p)
+  }
+}
+
+#[async_trait::async_trait]
+impl Object for ApubWikiPage {
+  type DataType = LemmyContext;
+  type Kind = Article;
+  type Error = LemmyError;
+
+  fn last_refreshed_at(&self) -> Option<DateTime<Utc>> {
+    None
+  }
+
+  #[tracing::instrument(skip_all)]
+  async fn read_from_id(
+    object_id: Url,
+    context: &Data<Self::DataType>,
+  ) -> Result<Option<Self>, LemmyError> {
+    Ok(
+      CommunityWikiPage::read_from_apub_id(&mut context.pool(), object_id)
+        .await?
+        .map(Into::into),
+    )
+  }
+
+  #[tracing::instrument(skip_all)]
+  async fn delete(self, context: &Data<Self::DataType>) -> Result<(), LemmyError> {
+    let form = CommunityWikiPageUpdateForm {
+      deleted: Some(true),
+      ..Default::default()
+    };
+    CommunityWikiPage::update(&mut context.pool(), self.id, &form).await?;
+    Ok(())
+  }
+
+  #[tracing::instrument(skip_all)]
+  async fn into_json(self, context: &Data<Self::DataType>) -> Result<Article, LemmyError> {
+    let community = Community::read(&mut context.pool(), self.community_id).await?;
+    let revision =
+      CommunityWikiPageRevision::read_latest_for_page(&mut context.pool(), self.id).await?;
+    let editor = Person::read(&mut context.pool(), revision.editor_id).await?;
+
+    Ok(Article {
+      kind: ArticleType::Article,
+      id: self.ap_id.clone().into(),
+      attributed_to: editor.actor_id.into(),
+      audience: community.actor_id.into(),
+      name: self.title.clone(),
+      content: markdown_to_html(&self.body),
+      media_type: Some(MediaTypeHtml::Html),
+      source: Some(Source::new(self.body.clone())),
+      members_can_edit: Some(self.members_can_edit),
+      published: Some(self.published),
+      updated: self.updated,
+    })
+  }
+
+  #[tracing::instrument(skip_all)]
+  async fn verify(
+    article: &Article,
+    expected_domain: &Url,
+    context: &Data<Self::DataType>,
+  ) -> Result<(), LemmyError> {
+    verify_domains_match(article.id.inner(), expected_domain)?;
+    // Wiki pages are hosted by the instance of their community
+    verify_domains_match(article.audience.inner(), article.id.inner())?;
+    check_apub_id_valid_with_strictness(article.id.inner(), true, context).await?;
+    article.slug()?;
+    Ok(())
+  }
+
+  #[tracing::instrument(skip_all)]
+  async fn from_json(
+    article: Article,
+    context: &Data<Self::DataType>,
+  ) -> Result<ApubWikiPage, LemmyError> {
+    let community = article.audience.dereference(context).await?;
+    if community.local {
+      Err(LemmyErrorType::ObjectNotLocal)?
+    }
+
+    let slug = article.slug()?;
+    let body = read_from_string_or_source(&article.content, &None, &article.source);
+    let existing =
+      CommunityWikiPage::read_from_apub_id(&mut context.pool(), article.id.inner().clone()).await?;
+
+    let form = CommunityWikiPageInsertForm::builder()
+      .community_id(community.id)
+      .slug(slug)
+      .title(article.name.clone())
+      .body(body)
+      .ap_id(Some(article.id.clone().into()))
+      .members_can_edit(article.members_can_edit)
+      .local(Some(false))
+      .deleted(Some(false))
+      .published(article.published)
+      .updated(article.updated)
+      .build();
+    let wiki_page = CommunityWikiPage::upsert(&mut context.pool(), &form).await?;
+
+    // Remote pages only get a revision for each version which this instance received
+    let changed = existing
+      .map(|e| e.title != wiki_page.title || e.body != wiki_page.body)
+      .unwrap_or(true);
+    if changed {
+      let editor = article.attributed_to.dereference(context).await?;
+      let form = CommunityWikiPageRevisionForm {
+        wiki_page_id: wiki_page.id,
+        editor_id: editor.id,
+        title: wiki_page.title.clone(),
+        body: wiki_page.body.clone(),
+      };
+      CommunityWikiPageRevision::create(&mut context.pool(), &form).await?;
+    }
+
+    Ok(wiki_page.into())
+  }
+}
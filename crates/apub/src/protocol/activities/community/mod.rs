@@ -3,7 +3,9 @@ pub mod collection_add;
 pub mod collection_remove;
 pub mod lock_page;
 pub mod report;
+pub mod resolve_report;
 pub mod update;
+pub mod warn_user;
 
 #[cfg(test)]
 mod tests {
@@ -17,7 +19,9 @@ mod tests {
       collection_remove::CollectionRemove,
       lock_page::{LockPage, UndoLockPage},
       report::Report,
+      resolve_report::{ResolveReport, UndoResolveReport},
       update::UpdateCommunity,
+      warn_user::WarnUser,
     },
     tests::test_parse_lemmy_item,
   };
@@ -53,5 +57,12 @@ mod tests {
     .unwrap();
 
     test_parse_lemmy_item::<Report>("assets/lemmy/activities/community/report_page.json").unwrap();
+    test_parse_lemmy_item::<ResolveReport>("assets/lemmy/activities/community/resolve_report.json")
+      .unwrap();
+    test_parse_lemmy_item::<UndoResolveReport>(
+      "assets/lemmy/activities/community/undo_resolve_report.json",
+    )
+    .unwrap();
+    test_parse_lemmy_item::<WarnUser>("assets/lemmy/activities/community/warn_user.json").unwrap();
   }
 }
@@ -0,0 +1,72 @@
+use crate::{
+  activities::verify_community_matches,
+  objects::{community::ApubCommunity, person::ApubPerson},
+  protocol::{activities::community::report::Report, InCommunity},
+};
+use activitypub_federation::{
+  config::Data,
+  fetch::object_id::ObjectId,
+  kinds::activity::UndoType,
+  protocol::helpers::deserialize_one_or_many,
+};
+use lemmy_api_common::context::LemmyContext;
+use lemmy_utils::error::LemmyError;
+use serde::{Deserialize, Serialize};
+use strum_macros::Display;
+use url::Url;
+
+#[derive(Clone, Debug, Deserialize, Serialize, Display)]
+pub enum ResolveType {
+  Resolve,
+}
+
+/// Sent by a mod who resolved a report, to the instances of the community and of the reporter.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolveReport {
+  pub(crate) actor: ObjectId<ApubPerson>,
+  #[serde(deserialize_with = "deserialize_one_or_many")]
+  pub(crate) to: Vec<Url>,
+  pub(crate) object: Report,
+  /// Why the report was resolved
+  pub(crate) summary: Option<String>,
+  #[serde(rename = "type")]
+  pub(crate) kind: ResolveType,
+  pub(crate) id: Url,
+  pub(crate) audience: Option<ObjectId<ApubCommunity>>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UndoResolveReport {
+  pub(crate) actor: ObjectId<ApubPerson>,
+  #[serde(deserialize_with = "deserialize_one_or_many")]
+  pub(crate) to: Vec<Url>,
+  pub(crate) object: ResolveReport,
+  #[serde(rename = "type")]
+  pub(crate) kind: UndoType,
+  pub(crate) id: Url,
+  pub(crate) audience: Option<ObjectId<ApubCommunity>>,
+}
+
+#[async_trait::async_trait]
+impl InCommunity for ResolveReport {
+  async fn community(&self, context: &Data<LemmyContext>) -> Result<ApubCommunity, LemmyError> {
+    let community = self.object.community(context).await?;
+    if let Some(audience) = &self.audience {
+      verify_community_matches(audience, community.actor_id.clone())?;
+    }
+    Ok(community)
+  }
+}
+
+#[async_trait::async_trait]
+impl InCommunity for UndoResolveReport {
+  async fn community(&self, context: &Data<LemmyContext>) -> Result<ApubCommunity, LemmyError> {
+    let community = self.object.community(context).await?;
+    if let Some(audience) = &self.audience {
+      verify_community_matches(audience, community.actor_id.clone())?;
+    }
+    Ok(community)
+  }
+}
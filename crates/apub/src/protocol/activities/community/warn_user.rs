@@ -0,0 +1,54 @@
+use crate::{
+  activities::verify_community_matches,
+  fetcher::post_or_comment::PostOrComment,
+  objects::{community::ApubCommunity, person::ApubPerson},
+  protocol::InCommunity,
+};
+use activitypub_federation::{
+  config::Data,
+  fetch::object_id::ObjectId,
+  protocol::helpers::deserialize_one,
+};
+use lemmy_api_common::context::LemmyContext;
+use lemmy_utils::error::LemmyError;
+use serde::{Deserialize, Serialize};
+use strum_macros::Display;
+use url::Url;
+
+#[derive(Clone, Debug, Deserialize, Serialize, Display)]
+pub enum WarnType {
+  Warn,
+}
+
+/// A moderator warns the creator of a post or comment. It is sent directly to the instance of the
+/// warned user, so that they get notified.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WarnUser {
+  pub(crate) actor: ObjectId<ApubPerson>,
+  #[serde(deserialize_with = "deserialize_one")]
+  pub(crate) to: [ObjectId<ApubPerson>; 1],
+  pub(crate) object: ObjectId<PostOrComment>,
+  /// warning reason
+  pub(crate) summary: String,
+  #[serde(rename = "type")]
+  pub(crate) kind: WarnType,
+  pub(crate) id: Url,
+  pub(crate) audience: Option<ObjectId<ApubCommunity>>,
+}
+
+#[async_trait::async_trait]
+impl InCommunity for WarnUser {
+  async fn community(&self, context: &Data<LemmyContext>) -> Result<ApubCommunity, LemmyError> {
+    let community = self
+      .object
+      .dereference(context)
+      .await?
+      .community(context)
+      .await?;
+    if let Some(audience) = &self.audience {
+      verify_community_matches(audience, community.actor_id.clone())?;
+    }
+    Ok(community)
+  }
+}
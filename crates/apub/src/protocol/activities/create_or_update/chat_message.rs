@@ -2,7 +2,10 @@ use crate::{
   objects::person::ApubPerson,
   protocol::{activities::CreateOrUpdateType, objects::chat_message::ChatMessage},
 };
-use activitypub_federation::{fetch::object_id::ObjectId, protocol::helpers::deserialize_one};
+use activitypub_federation::{
+  fetch::object_id::ObjectId,
+  protocol::helpers::deserialize_one_or_many,
+};
 use serde::{Deserialize, Serialize};
 use url::Url;
 
@@ -11,8 +14,8 @@ use url::Url;
 pub struct CreateOrUpdateChatMessage {
   pub(crate) id: Url,
   pub(crate) actor: ObjectId<ApubPerson>,
-  #[serde(deserialize_with = "deserialize_one")]
-  pub(crate) to: [ObjectId<ApubPerson>; 1],
+  #[serde(deserialize_with = "deserialize_one_or_many")]
+  pub(crate) to: Vec<ObjectId<ApubPerson>>,
   pub(crate) object: ChatMessage,
   #[serde(rename = "type")]
   pub(crate) kind: CreateOrUpdateType,
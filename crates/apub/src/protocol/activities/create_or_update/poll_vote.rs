@@ -0,0 +1,36 @@
+use crate::objects::{person::ApubPerson, post::ApubPost};
+use activitypub_federation::{
+  fetch::object_id::ObjectId,
+  kinds::{activity::CreateType, object::NoteType},
+  protocol::helpers::deserialize_one,
+};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+/// A vote in a poll, sent to the poll creator. This is the format which Mastodon uses, with one
+/// activity for each chosen option.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreatePollVote {
+  pub(crate) id: Url,
+  pub(crate) actor: ObjectId<ApubPerson>,
+  #[serde(deserialize_with = "deserialize_one")]
+  pub(crate) to: [ObjectId<ApubPerson>; 1],
+  pub(crate) object: PollVoteNote,
+  #[serde(rename = "type")]
+  pub(crate) kind: CreateType,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PollVoteNote {
+  #[serde(rename = "type")]
+  pub(crate) kind: NoteType,
+  pub(crate) id: Url,
+  pub(crate) attributed_to: ObjectId<ApubPerson>,
+  #[serde(deserialize_with = "deserialize_one")]
+  pub(crate) to: [ObjectId<ApubPerson>; 1],
+  /// The name of the chosen option
+  pub(crate) name: String,
+  pub(crate) in_reply_to: ObjectId<ApubPost>,
+}
@@ -22,10 +22,14 @@ mod tests {
   use crate::protocol::{
     activities::{
       community::announce::AnnounceActivity,
-      create_or_update::{note::CreateOrUpdateNote, page::CreateOrUpdatePage},
+      create_or_update::{
+        note::CreateOrUpdateNote,
+        page::CreateOrUpdatePage,
+        poll_vote::CreatePollVote,
+      },
       deletion::delete::Delete,
       following::{follow::Follow, undo_follow::UndoFollow},
-      voting::{undo_vote::UndoVote, vote::Vote},
+      voting::{emoji_react::EmojiReact, undo_vote::UndoVote, vote::Vote},
     },
     tests::test_json,
   };
@@ -40,6 +44,7 @@ mod tests {
     test_json::<CreateOrUpdateNote>("assets/pleroma/activities/create_note.json").unwrap();
     test_json::<Delete>("assets/pleroma/activities/delete.json").unwrap();
     test_json::<Follow>("assets/pleroma/activities/follow.json").unwrap();
+    test_json::<EmojiReact>("assets/pleroma/activities/emoji_react.json").unwrap();
   }
 
   #[test]
@@ -50,6 +55,14 @@ mod tests {
     test_json::<UndoFollow>("assets/mastodon/activities/undo_follow.json").unwrap();
     test_json::<Vote>("assets/mastodon/activities/like_page.json").unwrap();
     test_json::<UndoVote>("assets/mastodon/activities/undo_like_page.json").unwrap();
+    test_json::<CreatePollVote>("assets/mastodon/activities/create_poll_vote.json").unwrap();
+  }
+
+  #[test]
+  fn test_parse_misskey_activities() {
+    let like = test_json::<Vote>("assets/misskey/activities/like_reaction.json").unwrap();
+    assert_eq!(Some(":blobcat:"), like.inner().reaction());
+    assert_eq!(1, like.inner().emojis().len());
   }
 
   #[test]
@@ -0,0 +1,70 @@
+use crate::{
+  activities::verify_community_matches,
+  fetcher::post_or_comment::PostOrComment,
+  mentions::MentionOrValue,
+  objects::{community::ApubCommunity, person::ApubPerson},
+  protocol::{objects::emoji::Emoji, InCommunity},
+};
+use activitypub_federation::{config::Data, fetch::object_id::ObjectId};
+use lemmy_api_common::context::LemmyContext;
+use lemmy_utils::error::LemmyError;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use strum_macros::Display;
+use url::Url;
+
+/// An emoji reaction to a post or comment, in the format used by Pleroma. The custom emoji which
+/// is used for the reaction, if any, is included as tag.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmojiReact {
+  pub(crate) actor: ObjectId<ApubPerson>,
+  pub(crate) object: ObjectId<PostOrComment>,
+  #[serde(rename = "type")]
+  pub(crate) kind: EmojiReactType,
+  pub(crate) id: Url,
+  /// A unicode emoji, or the shortcode of a custom emoji surrounded by colons.
+  pub(crate) content: String,
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub(crate) tag: Vec<MentionOrValue>,
+  pub(crate) audience: Option<ObjectId<ApubCommunity>>,
+}
+
+#[derive(Clone, Debug, Display, Deserialize, Serialize, PartialEq, Eq)]
+pub enum EmojiReactType {
+  EmojiReact,
+}
+
+impl EmojiReact {
+  pub(crate) fn emojis(&self) -> Vec<&Emoji> {
+    emojis(&self.tag)
+  }
+}
+
+/// Returns the custom emojis from the tags of a reaction.
+pub(crate) fn emojis(tag: &[MentionOrValue]) -> Vec<&Emoji> {
+  tag
+    .iter()
+    .filter_map(|t| match t {
+      MentionOrValue::Emoji(e) => Some(e),
+      _ => None,
+    })
+    .collect()
+}
+
+#[async_trait::async_trait]
+impl InCommunity for EmojiReact {
+  async fn community(&self, context: &Data<LemmyContext>) -> Result<ApubCommunity, LemmyError> {
+    let community = self
+      .object
+      .dereference(context)
+      .await?
+      .community(context)
+      .await?;
+    if let Some(audience) = &self.audience {
+      verify_community_matches(audience, community.actor_id.clone())?;
+    }
+    Ok(community)
+  }
+}
@@ -1,3 +1,5 @@
+pub mod emoji_react;
+pub mod undo_emoji_react;
 pub mod undo_vote;
 pub mod vote;
 
@@ -7,7 +9,12 @@ mod tests {
   #![allow(clippy::indexing_slicing)]
 
   use crate::protocol::{
-    activities::voting::{undo_vote::UndoVote, vote::Vote},
+    activities::voting::{
+      emoji_react::EmojiReact,
+      undo_emoji_react::UndoEmojiReact,
+      undo_vote::UndoVote,
+      vote::Vote,
+    },
     tests::test_parse_lemmy_item,
   };
 
@@ -20,5 +27,12 @@ mod tests {
       .unwrap();
     test_parse_lemmy_item::<UndoVote>("assets/lemmy/activities/voting/undo_dislike_page.json")
       .unwrap();
+
+    test_parse_lemmy_item::<EmojiReact>("assets/lemmy/activities/voting/emoji_react_page.json")
+      .unwrap();
+    test_parse_lemmy_item::<UndoEmojiReact>(
+      "assets/lemmy/activities/voting/undo_emoji_react_note.json",
+    )
+    .unwrap();
   }
 }
@@ -0,0 +1,32 @@
+use crate::{
+  activities::verify_community_matches,
+  objects::{community::ApubCommunity, person::ApubPerson},
+  protocol::{activities::voting::emoji_react::EmojiReact, InCommunity},
+};
+use activitypub_federation::{config::Data, fetch::object_id::ObjectId, kinds::activity::UndoType};
+use lemmy_api_common::context::LemmyContext;
+use lemmy_utils::error::LemmyError;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UndoEmojiReact {
+  pub(crate) actor: ObjectId<ApubPerson>,
+  pub(crate) object: EmojiReact,
+  #[serde(rename = "type")]
+  pub(crate) kind: UndoType,
+  pub(crate) id: Url,
+  pub(crate) audience: Option<ObjectId<ApubCommunity>>,
+}
+
+#[async_trait::async_trait]
+impl InCommunity for UndoEmojiReact {
+  async fn community(&self, context: &Data<LemmyContext>) -> Result<ApubCommunity, LemmyError> {
+    let community = self.object.community(context).await?;
+    if let Some(audience) = &self.audience {
+      verify_community_matches(audience, community.actor_id.clone())?;
+    }
+    Ok(community)
+  }
+}
@@ -1,17 +1,20 @@
 use crate::{
   activities::verify_community_matches,
   fetcher::post_or_comment::PostOrComment,
+  mentions::MentionOrValue,
   objects::{community::ApubCommunity, person::ApubPerson},
-  protocol::InCommunity,
+  protocol::{activities::voting::emoji_react::emojis, objects::emoji::Emoji, InCommunity},
 };
 use activitypub_federation::{config::Data, fetch::object_id::ObjectId};
 use lemmy_api_common::context::LemmyContext;
 use lemmy_utils::error::{LemmyError, LemmyErrorType};
 use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
 use std::convert::TryFrom;
 use strum_macros::Display;
 use url::Url;
 
+#[skip_serializing_none]
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Vote {
@@ -21,6 +24,24 @@ pub struct Vote {
   pub(crate) kind: VoteType,
   pub(crate) id: Url,
   pub(crate) audience: Option<ObjectId<ApubCommunity>>,
+  /// Misskey sends emoji reactions as likes with the emoji as content.
+  pub(crate) content: Option<String>,
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub(crate) tag: Vec<MentionOrValue>,
+}
+
+impl Vote {
+  /// The emoji if this is a reaction rather than a vote.
+  pub(crate) fn reaction(&self) -> Option<&str> {
+    match self.kind {
+      VoteType::Like => self.content.as_deref(),
+      VoteType::Dislike => None,
+    }
+  }
+
+  pub(crate) fn emojis(&self) -> Vec<&Emoji> {
+    emojis(&self.tag)
+  }
 }
 
 #[derive(Clone, Debug, Display, Deserialize, Serialize, PartialEq, Eq)]
@@ -3,6 +3,7 @@ pub(crate) mod group_featured;
 pub(crate) mod group_followers;
 pub(crate) mod group_moderators;
 pub(crate) mod group_outbox;
+pub(crate) mod group_wiki;
 
 #[cfg(test)]
 mod tests {
@@ -16,6 +17,7 @@ mod tests {
       group_followers::GroupFollowers,
       group_moderators::GroupModerators,
       group_outbox::GroupOutbox,
+      group_wiki::GroupWiki,
     },
     tests::{test_json, test_parse_lemmy_item},
   };
@@ -31,6 +33,9 @@ mod tests {
       .unwrap();
     test_parse_lemmy_item::<GroupModerators>("assets/lemmy/collections/group_moderators.json")
       .unwrap();
+    let wiki =
+      test_parse_lemmy_item::<GroupWiki>("assets/lemmy/collections/group_wiki.json").unwrap();
+    assert_eq!(wiki.ordered_items.len() as i32, wiki.total_items);
     test_parse_lemmy_item::<EmptyOutbox>("assets/lemmy/collections/person_outbox.json").unwrap();
   }
 
@@ -0,0 +1,50 @@
+use crate::{
+  objects::{community::ApubCommunity, person::ApubPerson, wiki_page::ApubWikiPage},
+  protocol::Source,
+};
+use activitypub_federation::{
+  fetch::object_id::ObjectId,
+  kinds::object::ArticleType,
+  protocol::{helpers::deserialize_skip_error, values::MediaTypeHtml},
+};
+use chrono::{DateTime, Utc};
+use lemmy_utils::{error::LemmyError, utils::validation::is_valid_wiki_page_slug};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+/// A page in the wiki of a community. The last segment of its id is the slug of the page.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Article {
+  #[serde(rename = "type")]
+  pub(crate) kind: ArticleType,
+  pub(crate) id: ObjectId<ApubWikiPage>,
+  /// The person who wrote the current version of the page
+  pub(crate) attributed_to: ObjectId<ApubPerson>,
+  /// The community whose wiki contains the page
+  pub(crate) audience: ObjectId<ApubCommunity>,
+  /// title
+  pub(crate) name: String,
+  pub(crate) content: String,
+  pub(crate) media_type: Option<MediaTypeHtml>,
+  #[serde(deserialize_with = "deserialize_skip_error", default)]
+  pub(crate) source: Option<Source>,
+  // lemmy extension
+  pub(crate) members_can_edit: Option<bool>,
+  pub(crate) published: Option<DateTime<Utc>>,
+  pub(crate) updated: Option<DateTime<Utc>>,
+}
+
+impl Article {
+  pub(crate) fn slug(&self) -> Result<String, LemmyError> {
+    let slug = self
+      .id
+      .inner()
+      .path_segments()
+      .and_then(Iterator::last)
+      .unwrap_or_default();
+    is_valid_wiki_page_slug(slug)?;
+    Ok(slug.to_string())
+  }
+}
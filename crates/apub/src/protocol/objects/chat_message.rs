@@ -5,13 +5,14 @@ use crate::{
 use activitypub_federation::{
   fetch::object_id::ObjectId,
   protocol::{
-    helpers::{deserialize_one, deserialize_skip_error},
+    helpers::{deserialize_one_or_many, deserialize_skip_error},
     values::MediaTypeHtml,
   },
 };
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
+use url::Url;
 
 #[skip_serializing_none]
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -20,8 +21,10 @@ pub struct ChatMessage {
   pub(crate) r#type: ChatMessageType,
   pub(crate) id: ObjectId<ApubPrivateMessage>,
   pub(crate) attributed_to: ObjectId<ApubPerson>,
-  #[serde(deserialize_with = "deserialize_one")]
-  pub(crate) to: [ObjectId<ApubPerson>; 1],
+  /// All participants of the conversation except the creator, or the single recipient of a
+  /// direct message.
+  #[serde(deserialize_with = "deserialize_one_or_many")]
+  pub(crate) to: Vec<ObjectId<ApubPerson>>,
   pub(crate) content: String,
 
   pub(crate) media_type: Option<MediaTypeHtml>,
@@ -29,6 +32,8 @@ pub struct ChatMessage {
   pub(crate) source: Option<Source>,
   pub(crate) published: Option<DateTime<Utc>>,
   pub(crate) updated: Option<DateTime<Utc>>,
+  /// Id of the conversation, only sent for messages to a group conversation.
+  pub(crate) context: Option<Url>,
 }
 
 /// https://docs.pleroma.social/backend/development/ap_extensions/#chatmessages
@@ -0,0 +1,34 @@
+use crate::{objects::custom_emoji::ApubCustomEmoji, protocol::ImageObject};
+use activitypub_federation::fetch::object_id::ObjectId;
+use chrono::{DateTime, Utc};
+use lemmy_utils::{error::LemmyResult, utils::validation::is_valid_custom_emoji_shortcode};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum EmojiType {
+  Emoji,
+}
+
+/// A custom emoji which is used in the content of a post or comment. This is the format used by
+/// Mastodon, so that emojis are also displayed on other platforms.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Emoji {
+  #[serde(rename = "type")]
+  pub(crate) kind: EmojiType,
+  pub(crate) id: ObjectId<ApubCustomEmoji>,
+  /// The shortcode surrounded by colons, eg `:lemmy:`
+  pub(crate) name: String,
+  pub(crate) icon: ImageObject,
+  pub(crate) updated: Option<DateTime<Utc>>,
+}
+
+impl Emoji {
+  pub(crate) fn shortcode(&self) -> LemmyResult<String> {
+    let shortcode = self.name.trim_matches(':').to_string();
+    is_valid_custom_emoji_shortcode(&shortcode)?;
+    Ok(shortcode)
+  }
+}
@@ -0,0 +1,33 @@
+use crate::{
+  objects::{community::ApubCommunity, multi_community::ApubMultiCommunity, person::ApubPerson},
+  protocol::Source,
+};
+use activitypub_federation::{
+  fetch::object_id::ObjectId,
+  kinds::collection::CollectionType,
+  protocol::helpers::deserialize_skip_error,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+/// A multi-community, which is federated as a collection of the communities it contains.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Feed {
+  #[serde(rename = "type")]
+  pub(crate) kind: CollectionType,
+  pub(crate) id: ObjectId<ApubMultiCommunity>,
+  pub(crate) attributed_to: ObjectId<ApubPerson>,
+  /// name used in urls
+  pub(crate) preferred_username: String,
+  /// title
+  pub(crate) name: String,
+  pub(crate) summary: Option<String>,
+  #[serde(deserialize_with = "deserialize_skip_error", default)]
+  pub(crate) source: Option<Source>,
+  pub(crate) items: Vec<ObjectId<ApubCommunity>>,
+  pub(crate) published: Option<DateTime<Utc>>,
+  pub(crate) updated: Option<DateTime<Utc>>,
+}
@@ -5,6 +5,7 @@ use crate::{
     community_follower::ApubCommunityFollower,
     community_moderators::ApubCommunityModerators,
     community_outbox::ApubCommunityOutbox,
+    community_wiki::ApubCommunityWiki,
   },
   local_site_data_cached,
   objects::{community::ApubCommunity, read_from_string_or_source_opt},
@@ -29,6 +30,7 @@ use lemmy_db_schema::{
   newtypes::InstanceId,
   source::community::{CommunityInsertForm, CommunityUpdateForm},
   utils::naive_now,
+  CommunityVisibility,
 };
 use lemmy_utils::{
   error::LemmyError,
@@ -66,9 +68,13 @@ pub struct Group {
   pub(crate) attributed_to: Option<CollectionId<ApubCommunityModerators>>,
   // lemmy extension
   pub(crate) posting_restricted_to_mods: Option<bool>,
+  /// Set for private communities, whose follow requests need to be approved by a moderator
+  pub(crate) manually_approves_followers: Option<bool>,
   pub(crate) outbox: CollectionId<ApubCommunityOutbox>,
   pub(crate) endpoints: Option<Endpoints>,
   pub(crate) featured: Option<CollectionId<ApubCommunityFeatured>>,
+  // lemmy extension
+  pub(crate) wiki: Option<CollectionId<ApubCommunityWiki>>,
   #[serde(default)]
   pub(crate) language: Vec<LanguageTag>,
   pub(crate) published: Option<DateTime<Utc>>,
@@ -94,7 +100,17 @@ impl Group {
     Ok(())
   }
 
+  /// Local-only communities aren't federated, so remote communities are either public or private.
+  fn visibility(&self) -> CommunityVisibility {
+    if self.manually_approves_followers.unwrap_or(false) {
+      CommunityVisibility::Private
+    } else {
+      CommunityVisibility::Public
+    }
+  }
+
   pub(crate) fn into_insert_form(self, instance_id: InstanceId) -> CommunityInsertForm {
+    let visibility = self.visibility();
     let description = read_from_string_or_source_opt(&self.summary, &None, &self.source);
 
     CommunityInsertForm {
@@ -121,10 +137,15 @@ impl Group {
       posting_restricted_to_mods: self.posting_restricted_to_mods,
       instance_id,
       featured_url: self.featured.map(Into::into),
+      visibility: Some(visibility),
+      warning_ban_threshold: None,
+      warning_ban_window_days: None,
+      warning_ban_days: None,
     }
   }
 
   pub(crate) fn into_update_form(self) -> CommunityUpdateForm {
+    let visibility = self.visibility();
     CommunityUpdateForm {
       title: Some(self.name.unwrap_or(self.preferred_username)),
       description: Some(read_from_string_or_source_opt(
@@ -151,6 +172,10 @@ impl Group {
       moderators_url: self.attributed_to.map(Into::into),
       posting_restricted_to_mods: self.posting_restricted_to_mods,
       featured_url: self.featured.map(Into::into),
+      visibility: Some(visibility),
+      warning_ban_threshold: None,
+      warning_ban_window_days: None,
+      warning_ban_days: None,
     }
   }
 }
@@ -8,7 +8,10 @@ use lemmy_utils::error::LemmyError;
 use serde::{Deserialize, Serialize};
 use url::Url;
 
+pub(crate) mod article;
 pub(crate) mod chat_message;
+pub(crate) mod emoji;
+pub(crate) mod feed;
 pub(crate) mod group;
 pub(crate) mod instance;
 pub(crate) mod note;
@@ -98,17 +101,22 @@ mod tests {
   #![allow(clippy::unwrap_used)]
   #![allow(clippy::indexing_slicing)]
 
-  use crate::protocol::{
-    objects::{
-      chat_message::ChatMessage,
-      group::Group,
-      instance::Instance,
-      note::Note,
-      page::Page,
-      person::Person,
-      tombstone::Tombstone,
+  use crate::{
+    mentions::MentionOrValue,
+    protocol::{
+      objects::{
+        chat_message::ChatMessage,
+        emoji::Emoji,
+        feed::Feed,
+        group::Group,
+        instance::Instance,
+        note::Note,
+        page::Page,
+        person::Person,
+        tombstone::Tombstone,
+      },
+      tests::{test_json, test_parse_lemmy_item},
     },
-    tests::{test_json, test_parse_lemmy_item},
   };
 
   #[test]
@@ -119,7 +127,10 @@ mod tests {
     test_parse_lemmy_item::<Page>("assets/lemmy/objects/page.json").unwrap();
     test_parse_lemmy_item::<Note>("assets/lemmy/objects/note.json").unwrap();
     test_parse_lemmy_item::<ChatMessage>("assets/lemmy/objects/chat_message.json").unwrap();
+    test_parse_lemmy_item::<ChatMessage>("assets/lemmy/objects/group_chat_message.json").unwrap();
     test_parse_lemmy_item::<Tombstone>("assets/lemmy/objects/tombstone.json").unwrap();
+    test_parse_lemmy_item::<Feed>("assets/lemmy/objects/feed.json").unwrap();
+    test_parse_lemmy_item::<Emoji>("assets/lemmy/objects/emoji.json").unwrap();
   }
 
   #[test]
@@ -138,8 +149,19 @@ mod tests {
   #[test]
   fn test_parse_objects_mastodon() {
     test_json::<Person>("assets/mastodon/objects/person.json").unwrap();
-    test_json::<Note>("assets/mastodon/objects/note.json").unwrap();
+    let note = test_json::<Note>("assets/mastodon/objects/note.json").unwrap();
+    let emoji = note.inner().tag.iter().find_map(|t| match t {
+      MentionOrValue::Emoji(e) => Some(e),
+      _ => None,
+    });
+    assert_eq!("thinkpad", emoji.unwrap().shortcode().unwrap());
     test_json::<Page>("assets/mastodon/objects/page.json").unwrap();
+
+    let question = test_json::<Page>("assets/mastodon/objects/question.json").unwrap();
+    let (options, multiple_choice) = question.inner().poll_options().unwrap();
+    assert!(!multiple_choice);
+    assert_eq!("d20", options[0].name);
+    assert_eq!(Some(5), options[0].replies.as_ref().map(|r| r.total_items));
   }
 
   #[test]
@@ -3,7 +3,11 @@ use crate::{
   fetcher::post_or_comment::PostOrComment,
   mentions::MentionOrValue,
   objects::{comment::ApubComment, community::ApubCommunity, person::ApubPerson, post::ApubPost},
-  protocol::{objects::LanguageTag, InCommunity, Source},
+  protocol::{
+    objects::{emoji::Emoji, LanguageTag},
+    InCommunity,
+    Source,
+  },
 };
 use activitypub_federation::{
   config::Data,
@@ -54,6 +58,18 @@ pub struct Note {
 }
 
 impl Note {
+  /// The custom emojis which are used in the content.
+  pub(crate) fn emojis(&self) -> Vec<&Emoji> {
+    self
+      .tag
+      .iter()
+      .filter_map(|t| match t {
+        MentionOrValue::Emoji(e) => Some(e),
+        _ => None,
+      })
+      .collect()
+  }
+
   pub(crate) async fn get_parents(
     &self,
     context: &Data<LemmyContext>,
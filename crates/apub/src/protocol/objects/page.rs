@@ -2,14 +2,20 @@ use crate::{
   activities::verify_community_matches,
   fetcher::user_or_community::{PersonOrGroupType, UserOrCommunity},
   objects::{community::ApubCommunity, person::ApubPerson, post::ApubPost},
-  protocol::{objects::LanguageTag, ImageObject, InCommunity, Source},
+  protocol::{
+    objects::{emoji::Emoji, LanguageTag},
+    ImageObject,
+    InCommunity,
+    Source,
+  },
 };
 use activitypub_federation::{
   config::Data,
   fetch::object_id::ObjectId,
   kinds::{
+    collection::CollectionType,
     link::LinkType,
-    object::{DocumentType, ImageType},
+    object::{DocumentType, ImageType, NoteType},
   },
   protocol::{
     helpers::{deserialize_one_or_many, deserialize_skip_error},
@@ -23,6 +29,7 @@ use lemmy_api_common::context::LemmyContext;
 use lemmy_db_schema::newtypes::DbUrl;
 use lemmy_utils::error::{LemmyError, LemmyErrorType};
 use serde::{de::Error, Deserialize, Deserializer, Serialize};
+use serde_json::Value;
 use serde_with::skip_serializing_none;
 use url::Url;
 
@@ -33,6 +40,7 @@ pub enum PageType {
   Note,
   Video,
   Event,
+  Question,
 }
 
 #[skip_serializing_none]
@@ -67,6 +75,15 @@ pub struct Page {
   pub(crate) updated: Option<DateTime<Utc>>,
   pub(crate) language: Option<LanguageTag>,
   pub(crate) audience: Option<ObjectId<ApubCommunity>>,
+  #[serde(deserialize_with = "deserialize_one_or_many", default)]
+  pub(crate) tag: Vec<PageTag>,
+  /// Options of a single choice poll
+  pub(crate) one_of: Option<Vec<QuestionOption>>,
+  /// Options of a multiple choice poll
+  pub(crate) any_of: Option<Vec<QuestionOption>>,
+  pub(crate) end_time: Option<DateTime<Utc>>,
+  pub(crate) voters_count: Option<i32>,
+  pub(crate) hide_results: Option<bool>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -113,6 +130,49 @@ impl Attachment {
   }
 }
 
+/// A poll option, in the format used by Mastodon. The number of votes is given as the size of
+/// the replies collection.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct QuestionOption {
+  #[serde(rename = "type")]
+  pub(crate) kind: NoteType,
+  pub(crate) name: String,
+  pub(crate) replies: Option<QuestionOptionReplies>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct QuestionOptionReplies {
+  #[serde(rename = "type")]
+  pub(crate) kind: CollectionType,
+  pub(crate) total_items: i32,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum CommunityPostTagType {
+  CommunityPostTag,
+}
+
+/// A tag which the moderators of the community defined, and which applies to this post.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub(crate) struct CommunityPostTag {
+  #[serde(rename = "type")]
+  pub(crate) kind: CommunityPostTagType,
+  pub(crate) id: Url,
+  pub(crate) name: String,
+}
+
+/// Other software uses the tag field for hashtags and mentions, which are ignored.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub(crate) enum PageTag {
+  CommunityPostTag(CommunityPostTag),
+  Emoji(Emoji),
+  Value(Value),
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(untagged)]
 pub(crate) enum AttributedTo {
@@ -129,6 +189,18 @@ pub(crate) struct AttributedToPeertube {
 }
 
 impl Page {
+  /// The custom emojis which are used in the content.
+  pub(crate) fn emojis(&self) -> Vec<&Emoji> {
+    self
+      .tag
+      .iter()
+      .filter_map(|t| match t {
+        PageTag::Emoji(e) => Some(e),
+        _ => None,
+      })
+      .collect()
+  }
+
   /// Only mods can change the post's locked status. So if it is changed from the default value,
   /// it is a mod action and needs to be verified as such.
   ///
@@ -154,6 +226,15 @@ impl Page {
     false
   }
 
+  /// Returns the poll options and whether multiple of them can be chosen, if this is a poll.
+  pub(crate) fn poll_options(&self) -> Option<(&Vec<QuestionOption>, bool)> {
+    match (&self.one_of, &self.any_of) {
+      (Some(one_of), _) => Some((one_of, false)),
+      (None, Some(any_of)) => Some((any_of, true)),
+      (None, None) => None,
+    }
+  }
+
   pub(crate) fn creator(&self) -> Result<ObjectId<ApubPerson>, LemmyError> {
     match &self.attributed_to {
       AttributedTo::Lemmy(l) => Ok(l.clone()),
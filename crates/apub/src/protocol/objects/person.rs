@@ -42,6 +42,8 @@ pub struct Person {
   /// user banner
   pub(crate) image: Option<ImageObject>,
   pub(crate) matrix_user_id: Option<String>,
+  /// Public key for end-to-end encrypted private messages
+  pub(crate) encryption_public_key: Option<String>,
   pub(crate) endpoints: Option<Endpoints>,
   pub(crate) published: Option<DateTime<Utc>>,
   pub(crate) updated: Option<DateTime<Utc>>,
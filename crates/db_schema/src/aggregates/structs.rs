@@ -10,6 +10,7 @@ use crate::schema::{
 };
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 #[cfg(feature = "full")]
 use ts_rs::TS;
 #[derive(PartialEq, Debug, Serialize, Deserialize, Clone)]
@@ -31,6 +32,12 @@ pub struct CommentAggregates {
   pub hot_rank: f64,
   #[serde(skip)]
   pub controversy_rank: f64,
+  /// The number of reactions for each emoji.
+  #[cfg_attr(
+    feature = "full",
+    diesel(deserialize_as = crate::source::reaction::ReactionCounts)
+  )]
+  pub reaction_counts: BTreeMap<String, i64>,
 }
 
 #[derive(PartialEq, Debug, Serialize, Deserialize, Clone)]
@@ -74,6 +81,10 @@ pub struct PersonAggregates {
   pub post_score: i64,
   pub comment_count: i64,
   pub comment_score: i64,
+  /// Number of people following this person. Remote followers are only counted if they are
+  /// known to this instance.
+  pub follower_count: i64,
+  pub following_count: i64,
 }
 
 #[derive(PartialEq, Debug, Serialize, Deserialize, Clone)]
@@ -117,6 +128,12 @@ pub struct PostAggregates {
   /// A rank that amplifies smaller communities
   #[serde(skip)]
   pub scaled_rank: f64,
+  /// The number of reactions for each emoji.
+  #[cfg_attr(
+    feature = "full",
+    diesel(deserialize_as = crate::source::reaction::ReactionCounts)
+  )]
+  pub reaction_counts: BTreeMap<String, i64>,
 }
 
 #[derive(PartialEq, Eq, Debug, Serialize, Deserialize, Clone)]
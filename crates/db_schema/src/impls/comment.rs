@@ -1,11 +1,16 @@
 use crate::{
   newtypes::{CommentId, DbUrl, PersonId},
-  schema::comment::dsl::{ap_id, comment, content, creator_id, deleted, path, removed, updated},
+  schema::{
+    comment::dsl::{ap_id, comment, content, creator_id, deleted, path, removed, updated},
+    comment_revision,
+  },
   source::comment::{
     Comment,
     CommentInsertForm,
     CommentLike,
     CommentLikeForm,
+    CommentRevision,
+    CommentRevisionForm,
     CommentSaved,
     CommentSavedForm,
     CommentUpdateForm,
@@ -30,6 +35,19 @@ impl Comment {
   ) -> Result<Vec<Self>, Error> {
     let conn = &mut get_conn(pool).await?;
 
+    // Earlier versions of the comments need to be removed as well
+    diesel::delete(
+      comment_revision::table.filter(
+        comment_revision::comment_id.eq_any(
+          comment
+            .filter(creator_id.eq(for_creator_id))
+            .select(crate::schema::comment::id),
+        ),
+      ),
+    )
+    .execute(conn)
+    .await?;
+
     diesel::update(comment.filter(creator_id.eq(for_creator_id)))
       .set((
         content.eq(DELETED_REPLACEMENT_TEXT),
@@ -240,6 +258,44 @@ impl Saveable for CommentSaved {
   }
 }
 
+impl CommentRevision {
+  /// Stores the previous version of an edited comment, if the edit changed its content.
+  pub async fn create_for_edit(
+    pool: &mut DbPool<'_>,
+    orig_comment: &Comment,
+    updated_comment: &Comment,
+  ) -> Result<Option<Self>, Error> {
+    if orig_comment.content == updated_comment.content {
+      return Ok(None);
+    }
+
+    let conn = &mut get_conn(pool).await?;
+    let form = CommentRevisionForm {
+      comment_id: orig_comment.id,
+      content: orig_comment.content.clone(),
+      published: orig_comment.updated.unwrap_or(orig_comment.published),
+    };
+    insert_into(comment_revision::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+      .map(Some)
+  }
+
+  /// Lists the previous versions of a comment, oldest first.
+  pub async fn list_for_comment(
+    pool: &mut DbPool<'_>,
+    for_comment_id: CommentId,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    comment_revision::table
+      .filter(comment_revision::comment_id.eq(for_comment_id))
+      .order_by((comment_revision::edited, comment_revision::id))
+      .load::<Self>(conn)
+      .await
+  }
+}
+
 #[cfg(test)]
 mod tests {
   #![allow(clippy::unwrap_used)]
@@ -1,6 +1,14 @@
 use crate::{
-  newtypes::{CommentReportId, PersonId},
-  schema::comment_report::dsl::{comment_report, resolved, resolver_id, updated},
+  newtypes::{CommentId, CommentReportId, DbUrl, PersonId},
+  schema::comment_report::dsl::{
+    ap_id,
+    comment_id,
+    comment_report,
+    resolution_reason,
+    resolved,
+    resolver_id,
+    updated,
+  },
   source::comment_report::{CommentReport, CommentReportForm},
   traits::Reportable,
   utils::{get_conn, naive_now, DbPool},
@@ -9,9 +17,11 @@ use diesel::{
   dsl::{insert_into, update},
   result::Error,
   ExpressionMethods,
+  OptionalExtension,
   QueryDsl,
 };
 use diesel_async::RunQueryDsl;
+use url::Url;
 
 #[async_trait]
 impl Reportable for CommentReport {
@@ -37,16 +47,19 @@ impl Reportable for CommentReport {
   /// * `conn` - the postgres connection
   /// * `report_id` - the id of the report to resolve
   /// * `by_resolver_id` - the id of the user resolving the report
+  /// * `reason` - why the report was resolved
   async fn resolve(
     pool: &mut DbPool<'_>,
     report_id_: Self::IdType,
     by_resolver_id: PersonId,
+    reason: Option<String>,
   ) -> Result<usize, Error> {
     let conn = &mut get_conn(pool).await?;
     update(comment_report.find(report_id_))
       .set((
         resolved.eq(true),
         resolver_id.eq(by_resolver_id),
+        resolution_reason.eq(reason),
         updated.eq(naive_now()),
       ))
       .execute(conn)
@@ -68,9 +81,71 @@ impl Reportable for CommentReport {
       .set((
         resolved.eq(false),
         resolver_id.eq(by_resolver_id),
+        resolution_reason.eq(None::<String>),
         updated.eq(naive_now()),
       ))
       .execute(conn)
       .await
   }
 }
+
+impl CommentReport {
+  /// Finds the report which was federated with the given activity id.
+  pub async fn read_from_apub_id(
+    pool: &mut DbPool<'_>,
+    object_id: Url,
+  ) -> Result<Option<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let object_id: DbUrl = object_id.into();
+    comment_report
+      .filter(ap_id.eq(object_id))
+      .first::<Self>(conn)
+      .await
+      .optional()
+  }
+
+  /// Resolves all unresolved reports about the comment, and returns them.
+  pub async fn resolve_all_for_object(
+    pool: &mut DbPool<'_>,
+    for_comment_id: CommentId,
+    by_resolver_id: PersonId,
+    reason: Option<String>,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    update(
+      comment_report
+        .filter(comment_id.eq(for_comment_id))
+        .filter(resolved.eq(false)),
+    )
+    .set((
+      resolved.eq(true),
+      resolver_id.eq(by_resolver_id),
+      resolution_reason.eq(reason),
+      updated.eq(naive_now()),
+    ))
+    .get_results::<Self>(conn)
+    .await
+  }
+
+  /// Unresolves all resolved reports about the comment, and returns them.
+  pub async fn unresolve_all_for_object(
+    pool: &mut DbPool<'_>,
+    for_comment_id: CommentId,
+    by_resolver_id: PersonId,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    update(
+      comment_report
+        .filter(comment_id.eq(for_comment_id))
+        .filter(resolved.eq(true)),
+    )
+    .set((
+      resolved.eq(false),
+      resolver_id.eq(by_resolver_id),
+      resolution_reason.eq(None::<String>),
+      updated.eq(naive_now()),
+    ))
+    .get_results::<Self>(conn)
+    .await
+  }
+}
@@ -22,12 +22,13 @@ use crate::{
 use diesel::{
   deserialize,
   dsl,
-  dsl::insert_into,
+  dsl::{insert_into, now},
   pg::Pg,
   result::Error,
   sql_types,
   ExpressionMethods,
   NullableExpressionMethods,
+  OptionalExtension,
   QueryDsl,
   Queryable,
 };
@@ -177,6 +178,23 @@ impl CommunityModerator {
   }
 }
 
+impl CommunityPersonBan {
+  pub async fn read(
+    pool: &mut DbPool<'_>,
+    for_person_id: PersonId,
+    for_community_id: CommunityId,
+  ) -> Result<Option<Self>, Error> {
+    use crate::schema::community_person_ban::dsl::{community_id, community_person_ban, person_id};
+    let conn = &mut get_conn(pool).await?;
+    community_person_ban
+      .filter(community_id.eq(for_community_id))
+      .filter(person_id.eq(for_person_id))
+      .first::<Self>(conn)
+      .await
+      .optional()
+  }
+}
+
 #[async_trait]
 impl Bannable for CommunityPersonBan {
   type Form = CommunityPersonBanForm;
@@ -245,6 +263,75 @@ impl CommunityFollower {
     .get_result(conn)
     .await
   }
+
+  /// Check if the person is a follower of the community, and not only waiting for approval.
+  pub async fn is_approved_follower(
+    pool: &mut DbPool<'_>,
+    community_id_: CommunityId,
+    person_id_: PersonId,
+  ) -> Result<bool, Error> {
+    use crate::schema::community_follower::dsl::{
+      community_follower,
+      community_id,
+      pending,
+      person_id,
+    };
+    use diesel::dsl::{exists, select};
+    let conn = &mut get_conn(pool).await?;
+    select(exists(
+      community_follower
+        .filter(community_id.eq(community_id_))
+        .filter(person_id.eq(person_id_))
+        .filter(pending.eq(false)),
+    ))
+    .get_result(conn)
+    .await
+  }
+
+  /// The communities which the person follows, without pending follow requests and communities
+  /// which were deleted or removed.
+  pub async fn list_approved_community_ids(
+    pool: &mut DbPool<'_>,
+    person_id_: PersonId,
+  ) -> Result<Vec<CommunityId>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    community_follower::table
+      .inner_join(community::table)
+      .filter(community_follower::person_id.eq(person_id_))
+      .filter(community_follower::pending.eq(false))
+      .filter(community::deleted.eq(false))
+      .filter(community::removed.eq(false))
+      .select(community::id)
+      .load::<CommunityId>(conn)
+      .await
+  }
+
+  /// Approves a pending follow request of a private community. The follow date is reset, so that
+  /// the federation worker picks up the new follower and starts sending activities to its
+  /// instance.
+  pub async fn approve(
+    pool: &mut DbPool<'_>,
+    community_id_: CommunityId,
+    person_id_: PersonId,
+  ) -> Result<Self, Error> {
+    use crate::schema::community_follower::dsl::{
+      community_follower,
+      community_id,
+      pending,
+      person_id,
+      published,
+    };
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(
+      community_follower
+        .filter(community_id.eq(community_id_))
+        .filter(person_id.eq(person_id_))
+        .filter(pending.eq(true)),
+    )
+    .set((pending.eq(false), published.eq(now)))
+    .get_result::<Self>(conn)
+    .await
+  }
 }
 
 impl Queryable<sql_types::Nullable<sql_types::Bool>, Pg> for SubscribedType {
@@ -380,6 +467,7 @@ mod tests {
     },
     traits::{Bannable, Crud, Followable, Joinable},
     utils::build_db_pool_for_tests,
+    CommunityVisibility,
   };
   use serial_test::serial;
 
@@ -432,6 +520,10 @@ mod tests {
       shared_inbox_url: None,
       moderators_url: None,
       featured_url: None,
+      visibility: CommunityVisibility::Public,
+      warning_ban_threshold: None,
+      warning_ban_window_days: 30,
+      warning_ban_days: 7,
       hidden: false,
       posting_restricted_to_mods: false,
       instance_id: inserted_instance.id,
@@ -443,6 +535,46 @@ mod tests {
       pending: false,
     };
 
+    // A pending follow request has to be approved first
+    let pending_follower_form = CommunityFollowerForm {
+      pending: true,
+      ..community_follower_form.clone()
+    };
+    CommunityFollower::follow(pool, &pending_follower_form)
+      .await
+      .unwrap();
+    assert!(!CommunityFollower::is_approved_follower(
+      pool,
+      inserted_community.id,
+      inserted_person.id
+    )
+    .await
+    .unwrap());
+    assert!(
+      CommunityFollower::list_approved_community_ids(pool, inserted_person.id)
+        .await
+        .unwrap()
+        .is_empty()
+    );
+    let approved_follower =
+      CommunityFollower::approve(pool, inserted_community.id, inserted_person.id)
+        .await
+        .unwrap();
+    assert!(!approved_follower.pending);
+    assert_eq!(
+      vec![inserted_community.id],
+      CommunityFollower::list_approved_community_ids(pool, inserted_person.id)
+        .await
+        .unwrap()
+    );
+    assert!(CommunityFollower::is_approved_follower(
+      pool,
+      inserted_community.id,
+      inserted_person.id
+    )
+    .await
+    .unwrap());
+
     let inserted_community_follower = CommunityFollower::follow(pool, &community_follower_form)
       .await
       .unwrap();
@@ -0,0 +1,276 @@
+use crate::{
+  newtypes::{CommunityId, CommunityPostTagId, DbUrl, PostId},
+  schema::{community_post_tag, post_tag},
+  source::community_post_tag::{
+    CommunityPostTag,
+    CommunityPostTagInsertForm,
+    CommunityPostTagUpdateForm,
+    PostTag,
+    PostTagForm,
+  },
+  traits::Crud,
+  utils::{get_conn, DbPool},
+};
+use diesel::{
+  delete,
+  dsl::insert_into,
+  result::Error,
+  ExpressionMethods,
+  OptionalExtension,
+  QueryDsl,
+};
+use diesel_async::{AsyncPgConnection, RunQueryDsl};
+use url::Url;
+
+#[async_trait]
+impl Crud for CommunityPostTag {
+  type InsertForm = CommunityPostTagInsertForm;
+  type UpdateForm = CommunityPostTagUpdateForm;
+  type IdType = CommunityPostTagId;
+
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(community_post_tag::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    community_post_tag_id: CommunityPostTagId,
+    form: &Self::UpdateForm,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(community_post_tag::table.find(community_post_tag_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+}
+
+impl CommunityPostTag {
+  /// Update or insert a tag received over federation. A tag can't be moved to another community,
+  /// so `None` is returned if one with the same `ap_id` already belongs to a different community.
+  pub async fn upsert(
+    pool: &mut DbPool<'_>,
+    form: &CommunityPostTagInsertForm,
+  ) -> Result<Option<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(community_post_tag::table)
+      .values(form)
+      .on_conflict(community_post_tag::ap_id)
+      .do_update()
+      .set(form)
+      .filter(community_post_tag::community_id.eq(form.community_id))
+      .get_result::<Self>(conn)
+      .await
+      .optional()
+  }
+
+  pub async fn read_from_apub_id(
+    pool: &mut DbPool<'_>,
+    object_id: Url,
+  ) -> Result<Option<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let object_id: DbUrl = object_id.into();
+    Ok(
+      community_post_tag::table
+        .filter(community_post_tag::ap_id.eq(object_id))
+        .first::<Self>(conn)
+        .await
+        .ok(),
+    )
+  }
+
+  /// Returns the tags which can currently be used for posts in a community.
+  pub async fn list_for_community(
+    pool: &mut DbPool<'_>,
+    for_community_id: CommunityId,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    community_post_tag::table
+      .filter(community_post_tag::community_id.eq(for_community_id))
+      .filter(community_post_tag::deleted.eq(false))
+      .order_by(community_post_tag::display_name)
+      .load::<Self>(conn)
+      .await
+  }
+
+  /// Returns the tags of a post, without those which were deleted by the community mods.
+  pub async fn list_for_post(
+    pool: &mut DbPool<'_>,
+    for_post_id: PostId,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    post_tag::table
+      .inner_join(community_post_tag::table)
+      .filter(post_tag::post_id.eq(for_post_id))
+      .filter(community_post_tag::deleted.eq(false))
+      .order_by(community_post_tag::display_name)
+      .select(community_post_tag::all_columns)
+      .load::<Self>(conn)
+      .await
+  }
+}
+
+impl PostTag {
+  /// Replaces the tags of a post with the given ones.
+  pub async fn set(
+    pool: &mut DbPool<'_>,
+    for_post_id: PostId,
+    tag_ids: Vec<CommunityPostTagId>,
+  ) -> Result<(), Error> {
+    let conn = &mut get_conn(pool).await?;
+    conn
+      .build_transaction()
+      .run(|conn| {
+        Box::pin(async move {
+          Self::clear(conn, for_post_id).await?;
+          if tag_ids.is_empty() {
+            return Ok(());
+          }
+
+          let forms = tag_ids
+            .into_iter()
+            .map(|community_post_tag_id| PostTagForm {
+              post_id: for_post_id,
+              community_post_tag_id,
+            })
+            .collect::<Vec<_>>();
+          insert_into(post_tag::table)
+            .values(forms)
+            .on_conflict_do_nothing()
+            .execute(conn)
+            .await?;
+          Ok(())
+        }) as _
+      })
+      .await
+  }
+
+  async fn clear(conn: &mut AsyncPgConnection, for_post_id: PostId) -> Result<usize, Error> {
+    delete(post_tag::table.filter(post_tag::post_id.eq(for_post_id)))
+      .execute(conn)
+      .await
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  #![allow(clippy::unwrap_used)]
+  #![allow(clippy::indexing_slicing)]
+
+  use crate::{
+    source::{
+      community::{Community, CommunityInsertForm},
+      community_post_tag::{
+        CommunityPostTag,
+        CommunityPostTagInsertForm,
+        CommunityPostTagUpdateForm,
+        PostTag,
+      },
+      instance::Instance,
+      person::{Person, PersonInsertForm},
+      post::{Post, PostInsertForm},
+    },
+    traits::Crud,
+    utils::build_db_pool_for_tests,
+  };
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_crud() {
+    let pool = &build_db_pool_for_tests().await;
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld".to_string())
+      .await
+      .unwrap();
+
+    let new_person = PersonInsertForm::builder()
+      .name("tagger".into())
+      .public_key("pubkey".to_string())
+      .instance_id(inserted_instance.id)
+      .build();
+    let inserted_person = Person::create(pool, &new_person).await.unwrap();
+
+    let new_community = CommunityInsertForm::builder()
+      .name("test community_tags".to_string())
+      .title("nada".to_owned())
+      .public_key("pubkey".to_string())
+      .instance_id(inserted_instance.id)
+      .build();
+    let inserted_community = Community::create(pool, &new_community).await.unwrap();
+
+    let new_post = PostInsertForm::builder()
+      .name("A test post".into())
+      .creator_id(inserted_person.id)
+      .community_id(inserted_community.id)
+      .build();
+    let inserted_post = Post::create(pool, &new_post).await.unwrap();
+
+    let mut tag_ids = vec![];
+    for display_name in ["Solved", "Question"] {
+      let tag_form = CommunityPostTagInsertForm::builder()
+        .community_id(inserted_community.id)
+        .display_name(display_name.into())
+        .build();
+      tag_ids.push(CommunityPostTag::create(pool, &tag_form).await.unwrap().id);
+    }
+
+    let community_tags = CommunityPostTag::list_for_community(pool, inserted_community.id)
+      .await
+      .unwrap();
+    assert_eq!(
+      vec!["Question", "Solved"],
+      community_tags
+        .iter()
+        .map(|t| t.display_name.as_str())
+        .collect::<Vec<_>>()
+    );
+
+    PostTag::set(pool, inserted_post.id, tag_ids.clone())
+      .await
+      .unwrap();
+    let post_tags = CommunityPostTag::list_for_post(pool, inserted_post.id)
+      .await
+      .unwrap();
+    assert_eq!(2, post_tags.len());
+
+    // Replacing the tags removes the previous ones
+    PostTag::set(pool, inserted_post.id, vec![tag_ids[0]])
+      .await
+      .unwrap();
+    let post_tags = CommunityPostTag::list_for_post(pool, inserted_post.id)
+      .await
+      .unwrap();
+    assert_eq!(1, post_tags.len());
+    assert_eq!(tag_ids[0], post_tags[0].id);
+
+    // Deleted tags are not shown anymore
+    let update_form = CommunityPostTagUpdateForm {
+      deleted: Some(true),
+      ..Default::default()
+    };
+    CommunityPostTag::update(pool, tag_ids[0], &update_form)
+      .await
+      .unwrap();
+    let post_tags = CommunityPostTag::list_for_post(pool, inserted_post.id)
+      .await
+      .unwrap();
+    assert!(post_tags.is_empty());
+    let community_tags = CommunityPostTag::list_for_community(pool, inserted_community.id)
+      .await
+      .unwrap();
+    assert_eq!(1, community_tags.len());
+
+    Post::delete(pool, inserted_post.id).await.unwrap();
+    Community::delete(pool, inserted_community.id)
+      .await
+      .unwrap();
+    Person::delete(pool, inserted_person.id).await.unwrap();
+    Instance::delete(pool, inserted_instance.id).await.unwrap();
+  }
+}
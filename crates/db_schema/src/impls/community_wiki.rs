@@ -0,0 +1,335 @@
+use crate::{
+  newtypes::{CommunityId, CommunityWikiPageId, CommunityWikiPageRevisionId, DbUrl},
+  schema::{community_wiki_page, community_wiki_page_revision, person},
+  source::{
+    community_wiki::{
+      CommunityWikiPage,
+      CommunityWikiPageInsertForm,
+      CommunityWikiPageRevision,
+      CommunityWikiPageRevisionForm,
+      CommunityWikiPageUpdateForm,
+    },
+    person::Person,
+  },
+  traits::Crud,
+  utils::{get_conn, naive_now, DbPool},
+};
+use diesel::{dsl::insert_into, result::Error, ExpressionMethods, QueryDsl};
+use diesel_async::RunQueryDsl;
+use url::Url;
+
+#[async_trait]
+impl Crud for CommunityWikiPage {
+  type InsertForm = CommunityWikiPageInsertForm;
+  type UpdateForm = CommunityWikiPageUpdateForm;
+  type IdType = CommunityWikiPageId;
+
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(community_wiki_page::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    wiki_page_id: CommunityWikiPageId,
+    form: &Self::UpdateForm,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(community_wiki_page::table.find(wiki_page_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+}
+
+impl CommunityWikiPage {
+  /// Update or insert a wiki page received over federation.
+  pub async fn upsert(
+    pool: &mut DbPool<'_>,
+    form: &CommunityWikiPageInsertForm,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(community_wiki_page::table)
+      .values(form)
+      .on_conflict(community_wiki_page::ap_id)
+      .do_update()
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  pub async fn read_from_apub_id(
+    pool: &mut DbPool<'_>,
+    object_id: Url,
+  ) -> Result<Option<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let object_id: DbUrl = object_id.into();
+    Ok(
+      community_wiki_page::table
+        .filter(community_wiki_page::ap_id.eq(object_id))
+        .first::<Self>(conn)
+        .await
+        .ok(),
+    )
+  }
+
+  pub async fn read_from_slug(
+    pool: &mut DbPool<'_>,
+    for_community_id: CommunityId,
+    slug: &str,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    community_wiki_page::table
+      .filter(community_wiki_page::community_id.eq(for_community_id))
+      .filter(community_wiki_page::slug.eq(slug))
+      .first::<Self>(conn)
+      .await
+  }
+
+  /// Lists the pages of a community wiki which aren't deleted, ordered by title.
+  pub async fn list_for_community(
+    pool: &mut DbPool<'_>,
+    for_community_id: CommunityId,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    community_wiki_page::table
+      .filter(community_wiki_page::community_id.eq(for_community_id))
+      .filter(community_wiki_page::deleted.eq(false))
+      .order_by((community_wiki_page::title, community_wiki_page::id))
+      .load::<Self>(conn)
+      .await
+  }
+
+  /// Marks the pages of a remote community as deleted which aren't part of its wiki anymore.
+  pub async fn delete_missing(
+    pool: &mut DbPool<'_>,
+    for_community_id: CommunityId,
+    keep_ap_ids: Vec<DbUrl>,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(
+      community_wiki_page::table
+        .filter(community_wiki_page::community_id.eq(for_community_id))
+        .filter(community_wiki_page::local.eq(false))
+        .filter(community_wiki_page::deleted.eq(false))
+        .filter(community_wiki_page::ap_id.ne_all(keep_ap_ids)),
+    )
+    .set((
+      community_wiki_page::deleted.eq(true),
+      community_wiki_page::updated.eq(naive_now()),
+    ))
+    .execute(conn)
+    .await
+  }
+}
+
+impl CommunityWikiPageRevision {
+  pub async fn create(
+    pool: &mut DbPool<'_>,
+    form: &CommunityWikiPageRevisionForm,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(community_wiki_page_revision::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  pub async fn read(
+    pool: &mut DbPool<'_>,
+    revision_id: CommunityWikiPageRevisionId,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    community_wiki_page_revision::table
+      .find(revision_id)
+      .first::<Self>(conn)
+      .await
+  }
+
+  /// Reads the current version of a wiki page.
+  pub async fn read_latest_for_page(
+    pool: &mut DbPool<'_>,
+    for_wiki_page_id: CommunityWikiPageId,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    community_wiki_page_revision::table
+      .filter(community_wiki_page_revision::wiki_page_id.eq(for_wiki_page_id))
+      .order_by((
+        community_wiki_page_revision::published.desc(),
+        community_wiki_page_revision::id.desc(),
+      ))
+      .first::<Self>(conn)
+      .await
+  }
+
+  /// Lists all versions of a wiki page together with their editors, oldest first.
+  pub async fn list_for_page(
+    pool: &mut DbPool<'_>,
+    for_wiki_page_id: CommunityWikiPageId,
+  ) -> Result<Vec<(Self, Person)>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    community_wiki_page_revision::table
+      .inner_join(person::table)
+      .filter(community_wiki_page_revision::wiki_page_id.eq(for_wiki_page_id))
+      .order_by((
+        community_wiki_page_revision::published,
+        community_wiki_page_revision::id,
+      ))
+      .select((
+        community_wiki_page_revision::all_columns,
+        person::all_columns,
+      ))
+      .load::<(Self, Person)>(conn)
+      .await
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  #![allow(clippy::unwrap_used)]
+  #![allow(clippy::indexing_slicing)]
+
+  use crate::{
+    source::{
+      community::{Community, CommunityInsertForm},
+      community_wiki::{
+        CommunityWikiPage,
+        CommunityWikiPageInsertForm,
+        CommunityWikiPageRevision,
+        CommunityWikiPageRevisionForm,
+        CommunityWikiPageUpdateForm,
+      },
+      instance::Instance,
+      person::{Person, PersonInsertForm},
+    },
+    traits::Crud,
+    utils::build_db_pool_for_tests,
+  };
+  use serial_test::serial;
+  use url::Url;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_crud() {
+    let pool = &build_db_pool_for_tests().await;
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld".to_string())
+      .await
+      .unwrap();
+
+    let new_person = PersonInsertForm::builder()
+      .name("wiki_editor".into())
+      .public_key("pubkey".to_string())
+      .instance_id(inserted_instance.id)
+      .build();
+    let inserted_person = Person::create(pool, &new_person).await.unwrap();
+
+    let new_community = CommunityInsertForm::builder()
+      .name("test_community_wiki".to_string())
+      .title("nada".to_owned())
+      .public_key("pubkey".to_string())
+      .instance_id(inserted_instance.id)
+      .build();
+    let inserted_community = Community::create(pool, &new_community).await.unwrap();
+
+    let page_form = CommunityWikiPageInsertForm::builder()
+      .community_id(inserted_community.id)
+      .slug("rules".into())
+      .title("Rules".into())
+      .body("Be nice".into())
+      .build();
+    let page = CommunityWikiPage::create(pool, &page_form).await.unwrap();
+    CommunityWikiPageRevision::create(
+      pool,
+      &CommunityWikiPageRevisionForm {
+        wiki_page_id: page.id,
+        editor_id: inserted_person.id,
+        title: page.title.clone(),
+        body: page.body.clone(),
+      },
+    )
+    .await
+    .unwrap();
+
+    // Slugs are unique within a community
+    assert!(CommunityWikiPage::create(pool, &page_form).await.is_err());
+
+    let read_page = CommunityWikiPage::read_from_slug(pool, inserted_community.id, "rules")
+      .await
+      .unwrap();
+    assert_eq!(page, read_page);
+
+    let update_form = CommunityWikiPageUpdateForm {
+      body: Some("Be very nice".into()),
+      ..Default::default()
+    };
+    let updated_page = CommunityWikiPage::update(pool, page.id, &update_form)
+      .await
+      .unwrap();
+    CommunityWikiPageRevision::create(
+      pool,
+      &CommunityWikiPageRevisionForm {
+        wiki_page_id: page.id,
+        editor_id: inserted_person.id,
+        title: updated_page.title.clone(),
+        body: updated_page.body.clone(),
+      },
+    )
+    .await
+    .unwrap();
+
+    let revisions = CommunityWikiPageRevision::list_for_page(pool, page.id)
+      .await
+      .unwrap();
+    assert_eq!(
+      vec!["Be nice", "Be very nice"],
+      revisions
+        .iter()
+        .map(|(r, _)| r.body.as_str())
+        .collect::<Vec<_>>()
+    );
+    assert!(revisions
+      .iter()
+      .all(|(_, editor)| editor.id == inserted_person.id));
+    let latest = CommunityWikiPageRevision::read_latest_for_page(pool, page.id)
+      .await
+      .unwrap();
+    assert_eq!("Be very nice", latest.body);
+
+    let pages = CommunityWikiPage::list_for_community(pool, inserted_community.id)
+      .await
+      .unwrap();
+    assert_eq!(1, pages.len());
+
+    // Pages of remote communities which aren't in the wiki anymore get deleted
+    let remote_form = CommunityWikiPageInsertForm::builder()
+      .community_id(inserted_community.id)
+      .slug("faq".into())
+      .title("FAQ".into())
+      .body("Nothing yet".into())
+      .ap_id(Some(
+        Url::parse("https://example.com/c/test/wiki/faq")
+          .unwrap()
+          .into(),
+      ))
+      .local(Some(false))
+      .build();
+    let remote_page = CommunityWikiPage::upsert(pool, &remote_form).await.unwrap();
+    let deleted = CommunityWikiPage::delete_missing(pool, inserted_community.id, vec![])
+      .await
+      .unwrap();
+    assert_eq!(1, deleted);
+    let remote_page = CommunityWikiPage::read(pool, remote_page.id).await.unwrap();
+    assert!(remote_page.deleted);
+
+    Community::delete(pool, inserted_community.id)
+      .await
+      .unwrap();
+    Person::delete(pool, inserted_person.id).await.unwrap();
+    Instance::delete(pool, inserted_instance.id).await.unwrap();
+  }
+}
@@ -0,0 +1,337 @@
+use crate::{
+  newtypes::{ConversationId, DbUrl, PersonId},
+  schema::{conversation, conversation_participant, person, private_message},
+  source::{
+    conversation::{
+      Conversation,
+      ConversationInsertForm,
+      ConversationParticipant,
+      ConversationParticipantForm,
+      ConversationUpdateForm,
+    },
+    person::Person,
+  },
+  traits::Crud,
+  utils::{get_conn, naive_now, DbPool},
+};
+use diesel::{
+  dsl::{count_star, insert_into},
+  result::Error,
+  ExpressionMethods,
+  OptionalExtension,
+  QueryDsl,
+};
+use diesel_async::RunQueryDsl;
+use url::Url;
+
+#[async_trait]
+impl Crud for Conversation {
+  type InsertForm = ConversationInsertForm;
+  type UpdateForm = ConversationUpdateForm;
+  type IdType = ConversationId;
+
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(conversation::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    conversation_id: ConversationId,
+    form: &Self::UpdateForm,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(conversation::table.find(conversation_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+}
+
+impl Conversation {
+  /// Update or insert a conversation received over federation.
+  pub async fn upsert(pool: &mut DbPool<'_>, form: &ConversationInsertForm) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(conversation::table)
+      .values(form)
+      .on_conflict(conversation::ap_id)
+      .do_update()
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  pub async fn read_from_apub_id(
+    pool: &mut DbPool<'_>,
+    object_id: Url,
+  ) -> Result<Option<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let object_id: DbUrl = object_id.into();
+    conversation::table
+      .filter(conversation::ap_id.eq(object_id))
+      .first::<Self>(conn)
+      .await
+      .optional()
+  }
+
+  /// Returns the conversation which has exactly the given participants, if there is one.
+  pub async fn read_for_participants(
+    pool: &mut DbPool<'_>,
+    person_ids: &[PersonId],
+  ) -> Result<Option<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let mut person_ids = person_ids.to_vec();
+    person_ids.sort_by_key(|p| p.0);
+    person_ids.dedup();
+    let participant_count = person_ids.len() as i64;
+
+    // Conversations which all of the people are part of
+    let candidates = conversation_participant::table
+      .filter(conversation_participant::person_id.eq_any(person_ids))
+      .group_by(conversation_participant::conversation_id)
+      .having(count_star().eq(participant_count))
+      .select(conversation_participant::conversation_id)
+      .load::<ConversationId>(conn)
+      .await?;
+
+    // Of those, the one which has nobody else in it
+    let conversation_id = conversation_participant::table
+      .filter(conversation_participant::conversation_id.eq_any(candidates))
+      .group_by(conversation_participant::conversation_id)
+      .having(count_star().eq(participant_count))
+      .select(conversation_participant::conversation_id)
+      .first::<ConversationId>(conn)
+      .await
+      .optional()?;
+
+    match conversation_id {
+      Some(conversation_id) => conversation::table
+        .find(conversation_id)
+        .first::<Self>(conn)
+        .await
+        .optional(),
+      None => Ok(None),
+    }
+  }
+
+  pub async fn list_participants(
+    pool: &mut DbPool<'_>,
+    conversation_id: ConversationId,
+  ) -> Result<Vec<Person>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    conversation_participant::table
+      .inner_join(person::table)
+      .filter(conversation_participant::conversation_id.eq(conversation_id))
+      .order_by(conversation_participant::id)
+      .select(person::all_columns)
+      .load::<Person>(conn)
+      .await
+  }
+}
+
+impl ConversationParticipant {
+  /// Adds people to a conversation, ignoring those who are already in it.
+  pub async fn add(
+    pool: &mut DbPool<'_>,
+    conversation_id: ConversationId,
+    person_ids: &[PersonId],
+  ) -> Result<(), Error> {
+    if person_ids.is_empty() {
+      return Ok(());
+    }
+    let conn = &mut get_conn(pool).await?;
+    let forms = person_ids
+      .iter()
+      .map(|person_id| ConversationParticipantForm {
+        conversation_id,
+        person_id: *person_id,
+      })
+      .collect::<Vec<_>>();
+    insert_into(conversation_participant::table)
+      .values(forms)
+      .on_conflict_do_nothing()
+      .execute(conn)
+      .await?;
+    Ok(())
+  }
+
+  pub async fn read(
+    pool: &mut DbPool<'_>,
+    conversation_id: ConversationId,
+    person_id: PersonId,
+  ) -> Result<Option<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    conversation_participant::table
+      .filter(conversation_participant::conversation_id.eq(conversation_id))
+      .filter(conversation_participant::person_id.eq(person_id))
+      .first::<Self>(conn)
+      .await
+      .optional()
+  }
+
+  pub async fn leave(
+    pool: &mut DbPool<'_>,
+    conversation_id: ConversationId,
+    person_id: PersonId,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::delete(
+      conversation_participant::table
+        .filter(conversation_participant::conversation_id.eq(conversation_id))
+        .filter(conversation_participant::person_id.eq(person_id)),
+    )
+    .execute(conn)
+    .await
+  }
+
+  pub async fn set_muted(
+    pool: &mut DbPool<'_>,
+    conversation_id: ConversationId,
+    person_id: PersonId,
+    muted: bool,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(
+      conversation_participant::table
+        .filter(conversation_participant::conversation_id.eq(conversation_id))
+        .filter(conversation_participant::person_id.eq(person_id)),
+    )
+    .set(conversation_participant::muted.eq(muted))
+    .get_result::<Self>(conn)
+    .await
+  }
+
+  /// Marks all messages of the conversation as read for the participant, including the read
+  /// flag of direct messages.
+  pub async fn mark_as_read(
+    pool: &mut DbPool<'_>,
+    conversation_id: ConversationId,
+    person_id: PersonId,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(
+      private_message::table
+        .filter(private_message::conversation_id.eq(conversation_id))
+        .filter(private_message::recipient_id.eq(person_id))
+        .filter(private_message::read.eq(false)),
+    )
+    .set(private_message::read.eq(true))
+    .execute(conn)
+    .await?;
+
+    diesel::update(
+      conversation_participant::table
+        .filter(conversation_participant::conversation_id.eq(conversation_id))
+        .filter(conversation_participant::person_id.eq(person_id)),
+    )
+    .set(conversation_participant::last_read.eq(naive_now()))
+    .get_result::<Self>(conn)
+    .await
+  }
+
+  /// Marks all conversations of the person as read. The read flag of direct messages is updated
+  /// separately by `PrivateMessage::mark_all_as_read`.
+  pub async fn mark_all_as_read(
+    pool: &mut DbPool<'_>,
+    person_id: PersonId,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(
+      conversation_participant::table.filter(conversation_participant::person_id.eq(person_id)),
+    )
+    .set(conversation_participant::last_read.eq(naive_now()))
+    .execute(conn)
+    .await
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  #![allow(clippy::unwrap_used)]
+  #![allow(clippy::indexing_slicing)]
+
+  use crate::{
+    source::{
+      conversation::{Conversation, ConversationInsertForm, ConversationParticipant},
+      instance::Instance,
+      person::{Person, PersonInsertForm},
+    },
+    traits::Crud,
+    utils::build_db_pool_for_tests,
+  };
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_participants() {
+    let pool = &build_db_pool_for_tests().await;
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld".to_string())
+      .await
+      .unwrap();
+
+    let mut persons = vec![];
+    for name in ["conv_a", "conv_b", "conv_c"] {
+      let form = PersonInsertForm::builder()
+        .name(name.into())
+        .public_key("pubkey".to_string())
+        .instance_id(inserted_instance.id)
+        .build();
+      persons.push(Person::create(pool, &form).await.unwrap());
+    }
+    let (a, b, c) = (persons[0].id, persons[1].id, persons[2].id);
+
+    let direct = Conversation::create(pool, &ConversationInsertForm::builder().build())
+      .await
+      .unwrap();
+    ConversationParticipant::add(pool, direct.id, &[a, b])
+      .await
+      .unwrap();
+    let group = Conversation::create(pool, &ConversationInsertForm::builder().build())
+      .await
+      .unwrap();
+    ConversationParticipant::add(pool, group.id, &[a, b, c])
+      .await
+      .unwrap();
+
+    // Lookup only matches conversations without any other participants
+    let found = Conversation::read_for_participants(pool, &[b, a])
+      .await
+      .unwrap();
+    assert_eq!(Some(direct.id), found.map(|c| c.id));
+    let found = Conversation::read_for_participants(pool, &[a, b, c])
+      .await
+      .unwrap();
+    assert_eq!(Some(group.id), found.map(|c| c.id));
+    let found = Conversation::read_for_participants(pool, &[a, c])
+      .await
+      .unwrap();
+    assert!(found.is_none());
+
+    let muted = ConversationParticipant::set_muted(pool, group.id, c, true)
+      .await
+      .unwrap();
+    assert!(muted.muted);
+    ConversationParticipant::leave(pool, group.id, c)
+      .await
+      .unwrap();
+    let participants = Conversation::list_participants(pool, group.id)
+      .await
+      .unwrap();
+    assert_eq!(
+      vec![a, b],
+      participants.iter().map(|p| p.id).collect::<Vec<_>>()
+    );
+
+    for person in persons {
+      Person::delete(pool, person.id).await.unwrap();
+    }
+    Conversation::delete(pool, direct.id).await.unwrap();
+    Conversation::delete(pool, group.id).await.unwrap();
+    Instance::delete(pool, inserted_instance.id).await.unwrap();
+  }
+}
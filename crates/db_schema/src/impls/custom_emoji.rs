@@ -1,7 +1,7 @@
 use crate::{
-  newtypes::CustomEmojiId,
+  newtypes::{CommunityId, CustomEmojiId, DbUrl},
   schema::{
-    custom_emoji::dsl::custom_emoji,
+    custom_emoji::{self, dsl::custom_emoji},
     custom_emoji_keyword::dsl::{custom_emoji_id, custom_emoji_keyword},
   },
   source::{
@@ -10,8 +10,9 @@ use crate::{
   },
   utils::{get_conn, DbPool},
 };
-use diesel::{dsl::insert_into, result::Error, ExpressionMethods, QueryDsl};
+use diesel::{dsl::insert_into, result::Error, BoolExpressionMethods, ExpressionMethods, QueryDsl};
 use diesel_async::RunQueryDsl;
+use url::Url;
 
 impl CustomEmoji {
   pub async fn create(pool: &mut DbPool<'_>, form: &CustomEmojiInsertForm) -> Result<Self, Error> {
@@ -38,6 +39,54 @@ impl CustomEmoji {
       .execute(conn)
       .await
   }
+  pub async fn read(pool: &mut DbPool<'_>, emoji_id: CustomEmojiId) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    custom_emoji.find(emoji_id).first::<Self>(conn).await
+  }
+
+  /// Update or insert a remote emoji which was received over federation.
+  pub async fn upsert(pool: &mut DbPool<'_>, form: &CustomEmojiInsertForm) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(custom_emoji)
+      .values(form)
+      .on_conflict(custom_emoji::ap_id)
+      .do_update()
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  pub async fn read_from_apub_id(
+    pool: &mut DbPool<'_>,
+    object_id: Url,
+  ) -> Result<Option<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let object_id: DbUrl = object_id.into();
+    Ok(
+      custom_emoji
+        .filter(custom_emoji::ap_id.eq(object_id))
+        .first::<Self>(conn)
+        .await
+        .ok(),
+    )
+  }
+
+  /// Lists the emojis which can be used in the given community, meaning those of the site and
+  /// those which the community moderators added.
+  pub async fn list_for_community(
+    pool: &mut DbPool<'_>,
+    for_community_id: CommunityId,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    custom_emoji
+      .filter(
+        custom_emoji::local_site_id
+          .is_not_null()
+          .or(custom_emoji::community_id.eq(for_community_id)),
+      )
+      .load::<Self>(conn)
+      .await
+  }
 }
 
 impl CustomEmojiKeyword {
@@ -58,3 +107,107 @@ impl CustomEmojiKeyword {
       .await
   }
 }
+
+#[cfg(test)]
+mod tests {
+  #![allow(clippy::unwrap_used)]
+  #![allow(clippy::indexing_slicing)]
+
+  use crate::{
+    source::{
+      community::{Community, CommunityInsertForm},
+      custom_emoji::{CustomEmoji, CustomEmojiInsertForm},
+      instance::Instance,
+      local_site::{LocalSite, LocalSiteInsertForm},
+      site::{Site, SiteInsertForm},
+    },
+    traits::Crud,
+    utils::build_db_pool_for_tests,
+  };
+  use serial_test::serial;
+  use url::Url;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_emoji_sets() {
+    let pool = &build_db_pool_for_tests().await;
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld".to_string())
+      .await
+      .unwrap();
+    let site_form = SiteInsertForm::builder()
+      .name("test site".to_string())
+      .instance_id(inserted_instance.id)
+      .build();
+    let site = Site::create(pool, &site_form).await.unwrap();
+    let local_site_form = LocalSiteInsertForm::builder().site_id(site.id).build();
+    let local_site = LocalSite::create(pool, &local_site_form).await.unwrap();
+
+    let new_community = CommunityInsertForm::builder()
+      .name("test_community_emoji".to_string())
+      .title("nada".to_owned())
+      .public_key("pubkey".to_string())
+      .instance_id(inserted_instance.id)
+      .build();
+    let inserted_community = Community::create(pool, &new_community).await.unwrap();
+
+    let image_url: Url = Url::parse("https://my_domain.tld/pictrs/image/lemmy.png").unwrap();
+    let site_form = CustomEmojiInsertForm::builder()
+      .local_site_id(Some(local_site.id))
+      .shortcode("lemmy".into())
+      .image_url(image_url.clone().into())
+      .alt_text("lemmy".into())
+      .category("logos".into())
+      .build();
+    let site_emoji = CustomEmoji::create(pool, &site_form).await.unwrap();
+    // Shortcodes are unique within the site
+    assert!(CustomEmoji::create(pool, &site_form).await.is_err());
+
+    // But a community can have an emoji with the same shortcode
+    let community_form = CustomEmojiInsertForm::builder()
+      .community_id(Some(inserted_community.id))
+      .shortcode("lemmy".into())
+      .image_url(image_url.clone().into())
+      .alt_text("lemmy".into())
+      .category("logos".into())
+      .build();
+    let community_emoji = CustomEmoji::create(pool, &community_form).await.unwrap();
+
+    // Remote emojis are cached, and updated when received again
+    let remote_form = CustomEmojiInsertForm::builder()
+      .shortcode("lemmy".into())
+      .image_url(image_url.into())
+      .alt_text("lemmy".into())
+      .category("example.com".into())
+      .ap_id(Some(
+        Url::parse("https://example.com/emoji/1").unwrap().into(),
+      ))
+      .local(Some(false))
+      .build();
+    let remote_emoji = CustomEmoji::upsert(pool, &remote_form).await.unwrap();
+    let remote_emoji_again = CustomEmoji::upsert(pool, &remote_form).await.unwrap();
+    assert_eq!(remote_emoji.id, remote_emoji_again.id);
+    let read_remote_emoji =
+      CustomEmoji::read_from_apub_id(pool, Url::parse("https://example.com/emoji/1").unwrap())
+        .await
+        .unwrap();
+    assert_eq!(Some(remote_emoji.clone()), read_remote_emoji);
+
+    let available = CustomEmoji::list_for_community(pool, inserted_community.id)
+      .await
+      .unwrap();
+    let mut available_ids: Vec<_> = available.iter().map(|e| e.id).collect();
+    available_ids.sort_by_key(|id| id.0);
+    assert_eq!(vec![site_emoji.id, community_emoji.id], available_ids);
+
+    CustomEmoji::delete(pool, remote_emoji.id).await.unwrap();
+    CustomEmoji::delete(pool, site_emoji.id).await.unwrap();
+    Community::delete(pool, inserted_community.id)
+      .await
+      .unwrap();
+    Site::delete(pool, site.id).await.unwrap();
+    LocalSite::delete(pool).await.unwrap();
+    Instance::delete(pool, inserted_instance.id).await.unwrap();
+  }
+}
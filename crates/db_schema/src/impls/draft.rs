@@ -0,0 +1,193 @@
+use crate::{
+  newtypes::{DraftId, LocalUserId},
+  schema::{draft, local_user},
+  source::draft::{Draft, DraftInsertForm, DraftUpdateForm},
+  traits::Crud,
+  utils::{get_conn, limit_and_offset, DbPool},
+};
+use diesel::{dsl::insert_into, result::Error, ExpressionMethods, QueryDsl};
+use diesel_async::RunQueryDsl;
+
+#[async_trait]
+impl Crud for Draft {
+  type InsertForm = DraftInsertForm;
+  type UpdateForm = DraftUpdateForm;
+  type IdType = DraftId;
+
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(draft::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    draft_id: DraftId,
+    form: &Self::UpdateForm,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(draft::table.find(draft_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+}
+
+impl Draft {
+  /// Drafts are only checked completely when they get published, so their number is limited.
+  pub const MAX_PER_USER: i64 = 100;
+
+  /// Drafts which have a post id are comment drafts, all others are post drafts.
+  pub fn is_comment(&self) -> bool {
+    self.post_id.is_some()
+  }
+
+  /// Creates the draft, unless the user already has [Self::MAX_PER_USER] drafts in which case
+  /// `None` is returned. The local user row is locked while counting, so that concurrent requests
+  /// can't exceed the limit.
+  pub async fn create_within_limit(
+    pool: &mut DbPool<'_>,
+    form: &DraftInsertForm,
+  ) -> Result<Option<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    conn
+      .build_transaction()
+      .run(|conn| {
+        Box::pin(async move {
+          local_user::table
+            .find(form.local_user_id)
+            .select(local_user::id)
+            .for_update()
+            .first::<LocalUserId>(conn)
+            .await?;
+          let count = draft::table
+            .filter(draft::local_user_id.eq(form.local_user_id))
+            .count()
+            .get_result::<i64>(conn)
+            .await?;
+          if count >= Self::MAX_PER_USER {
+            return Ok(None);
+          }
+          insert_into(draft::table)
+            .values(form)
+            .get_result::<Self>(conn)
+            .await
+            .map(Some)
+        }) as _
+      })
+      .await
+  }
+
+  /// Lists the drafts of a user, the most recently created first.
+  pub async fn list_for_local_user(
+    pool: &mut DbPool<'_>,
+    for_local_user_id: LocalUserId,
+    page: Option<i64>,
+    limit: Option<i64>,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let (limit, offset) = limit_and_offset(page, limit)?;
+    draft::table
+      .filter(draft::local_user_id.eq(for_local_user_id))
+      .order_by(draft::published.desc())
+      .limit(limit)
+      .offset(offset)
+      .load::<Self>(conn)
+      .await
+  }
+
+  pub async fn count(pool: &mut DbPool<'_>, for_local_user_id: LocalUserId) -> Result<i64, Error> {
+    let conn = &mut get_conn(pool).await?;
+    draft::table
+      .filter(draft::local_user_id.eq(for_local_user_id))
+      .count()
+      .get_result::<i64>(conn)
+      .await
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  #![allow(clippy::unwrap_used)]
+  #![allow(clippy::indexing_slicing)]
+
+  use crate::{
+    source::{
+      draft::{Draft, DraftInsertForm, DraftUpdateForm},
+      instance::Instance,
+      local_user::{LocalUser, LocalUserInsertForm},
+      person::{Person, PersonInsertForm},
+    },
+    traits::Crud,
+    utils::build_db_pool_for_tests,
+  };
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_crud() {
+    let pool = &build_db_pool_for_tests().await;
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld".to_string())
+      .await
+      .unwrap();
+
+    let new_person = PersonInsertForm::builder()
+      .name("drafter".into())
+      .public_key("pubkey".to_string())
+      .instance_id(inserted_instance.id)
+      .build();
+    let inserted_person = Person::create(pool, &new_person).await.unwrap();
+
+    let local_user_form = LocalUserInsertForm::builder()
+      .person_id(inserted_person.id)
+      .password_encrypted("123456".to_string())
+      .build();
+    let inserted_local_user = LocalUser::create(pool, &local_user_form).await.unwrap();
+
+    let form = DraftInsertForm::builder()
+      .local_user_id(inserted_local_user.id)
+      .name(Some("An unfinished post".to_string()))
+      .poll_options(Some(vec!["Yes".to_string(), "No".to_string()]))
+      .build();
+    let inserted_draft = Draft::create(pool, &form).await.unwrap();
+    assert!(!inserted_draft.is_comment());
+
+    let update_form = DraftUpdateForm {
+      body: Some(Some("Some more text".to_string())),
+      ..Default::default()
+    };
+    let updated_draft = Draft::update(pool, inserted_draft.id, &update_form)
+      .await
+      .unwrap();
+    assert_eq!(Some("An unfinished post".to_string()), updated_draft.name);
+    assert_eq!(Some("Some more text".to_string()), updated_draft.body);
+    assert_eq!(
+      Some(vec!["Yes".to_string(), "No".to_string()]),
+      updated_draft.poll_options
+    );
+
+    let drafts = Draft::list_for_local_user(pool, inserted_local_user.id, None, None)
+      .await
+      .unwrap();
+    assert_eq!(vec![updated_draft], drafts);
+    assert_eq!(1, Draft::count(pool, inserted_local_user.id).await.unwrap());
+
+    // Drafts can only be created until the limit is reached
+    for _ in 1..Draft::MAX_PER_USER {
+      let created = Draft::create_within_limit(pool, &form).await.unwrap();
+      assert!(created.is_some());
+    }
+    let over_limit = Draft::create_within_limit(pool, &form).await.unwrap();
+    assert!(over_limit.is_none());
+
+    let num_deleted = Draft::delete(pool, inserted_draft.id).await.unwrap();
+    assert_eq!(1, num_deleted);
+
+    Person::delete(pool, inserted_person.id).await.unwrap();
+    Instance::delete(pool, inserted_instance.id).await.unwrap();
+  }
+}
@@ -0,0 +1,213 @@
+use crate::{
+  newtypes::{LocalUserId, LocalUserKeywordFilterId},
+  schema::{local_user, local_user_keyword_filter},
+  source::local_user_keyword_filter::{LocalUserKeywordFilter, LocalUserKeywordFilterForm},
+  utils::{functions::keyword_filter_matches, get_conn, DbPool},
+};
+use diesel::{dsl::insert_into, result::Error, ExpressionMethods, QueryDsl};
+use diesel_async::RunQueryDsl;
+
+impl LocalUserKeywordFilter {
+  /// Every filter is checked against every post in the listings of the user, so their number is
+  /// limited.
+  pub const MAX_PER_USER: i64 = 50;
+
+  pub async fn create(
+    pool: &mut DbPool<'_>,
+    form: &LocalUserKeywordFilterForm,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(local_user_keyword_filter::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  /// Creates the filter, unless the user already has [Self::MAX_PER_USER] filters in which case
+  /// `None` is returned. The local user row is locked while counting, so that concurrent requests
+  /// can't exceed the limit.
+  pub async fn create_within_limit(
+    pool: &mut DbPool<'_>,
+    form: &LocalUserKeywordFilterForm,
+  ) -> Result<Option<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    conn
+      .build_transaction()
+      .run(|conn| {
+        Box::pin(async move {
+          local_user::table
+            .find(form.local_user_id)
+            .select(local_user::id)
+            .for_update()
+            .first::<LocalUserId>(conn)
+            .await?;
+          let count = local_user_keyword_filter::table
+            .filter(local_user_keyword_filter::local_user_id.eq(form.local_user_id))
+            .count()
+            .get_result::<i64>(conn)
+            .await?;
+          if count >= Self::MAX_PER_USER {
+            return Ok(None);
+          }
+          insert_into(local_user_keyword_filter::table)
+            .values(form)
+            .get_result::<Self>(conn)
+            .await
+            .map(Some)
+        }) as _
+      })
+      .await
+  }
+
+  /// Deletes a filter, but only if it belongs to the given user.
+  pub async fn delete(
+    pool: &mut DbPool<'_>,
+    filter_id: LocalUserKeywordFilterId,
+    for_local_user_id: LocalUserId,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::delete(
+      local_user_keyword_filter::table
+        .find(filter_id)
+        .filter(local_user_keyword_filter::local_user_id.eq(for_local_user_id)),
+    )
+    .execute(conn)
+    .await
+  }
+
+  pub async fn list(
+    pool: &mut DbPool<'_>,
+    for_local_user_id: LocalUserId,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    local_user_keyword_filter::table
+      .filter(local_user_keyword_filter::local_user_id.eq(for_local_user_id))
+      .order_by(local_user_keyword_filter::keyword)
+      .load::<Self>(conn)
+      .await
+  }
+
+  pub async fn count(pool: &mut DbPool<'_>, for_local_user_id: LocalUserId) -> Result<i64, Error> {
+    let conn = &mut get_conn(pool).await?;
+    local_user_keyword_filter::table
+      .filter(local_user_keyword_filter::local_user_id.eq(for_local_user_id))
+      .count()
+      .get_result::<i64>(conn)
+      .await
+  }
+
+  /// Filters are applied with the regex engine of the database, so that is also used to check
+  /// that a regex is valid. Otherwise an invalid regex would break all listings of the user.
+  pub async fn check_regex(pool: &mut DbPool<'_>, regex: &str) -> Result<(), Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::select(keyword_filter_matches(Some(""), regex, true))
+      .get_result::<bool>(conn)
+      .await
+      .map(|_| ())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  #![allow(clippy::unwrap_used)]
+  #![allow(clippy::indexing_slicing)]
+
+  use crate::{
+    source::{
+      instance::Instance,
+      local_user::{LocalUser, LocalUserInsertForm},
+      local_user_keyword_filter::{LocalUserKeywordFilter, LocalUserKeywordFilterForm},
+      person::{Person, PersonInsertForm},
+    },
+    traits::Crud,
+    utils::build_db_pool_for_tests,
+  };
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_crud() {
+    let pool = &build_db_pool_for_tests().await;
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld".to_string())
+      .await
+      .unwrap();
+
+    let new_person = PersonInsertForm::builder()
+      .name("keyword_filter_person".into())
+      .public_key("pubkey".to_string())
+      .instance_id(inserted_instance.id)
+      .build();
+    let inserted_person = Person::create(pool, &new_person).await.unwrap();
+
+    let local_user_form = LocalUserInsertForm::builder()
+      .person_id(inserted_person.id)
+      .password_encrypted("123456".to_string())
+      .build();
+    let inserted_local_user = LocalUser::create(pool, &local_user_form).await.unwrap();
+
+    let filter_form = LocalUserKeywordFilterForm {
+      local_user_id: inserted_local_user.id,
+      keyword: "spoiler".to_string(),
+      is_regex: false,
+      filter_title: true,
+      filter_body: true,
+      filter_url: false,
+      expires: None,
+    };
+    let inserted_filter = LocalUserKeywordFilter::create(pool, &filter_form)
+      .await
+      .unwrap();
+
+    // The same keyword can't be added twice
+    assert!(LocalUserKeywordFilter::create(pool, &filter_form)
+      .await
+      .is_err());
+
+    let filters = LocalUserKeywordFilter::list(pool, inserted_local_user.id)
+      .await
+      .unwrap();
+    assert_eq!(vec![inserted_filter.clone()], filters);
+    let count = LocalUserKeywordFilter::count(pool, inserted_local_user.id)
+      .await
+      .unwrap();
+    assert_eq!(1, count);
+
+    // Filters can only be created until the limit is reached
+    for i in count..LocalUserKeywordFilter::MAX_PER_USER {
+      let form = LocalUserKeywordFilterForm {
+        keyword: format!("keyword {i}"),
+        ..filter_form.clone()
+      };
+      let created = LocalUserKeywordFilter::create_within_limit(pool, &form)
+        .await
+        .unwrap();
+      assert!(created.is_some());
+    }
+    let over_limit_form = LocalUserKeywordFilterForm {
+      keyword: "over limit".to_string(),
+      ..filter_form.clone()
+    };
+    let over_limit = LocalUserKeywordFilter::create_within_limit(pool, &over_limit_form)
+      .await
+      .unwrap();
+    assert!(over_limit.is_none());
+
+    assert!(LocalUserKeywordFilter::check_regex(pool, "^s(pa|po)il")
+      .await
+      .is_ok());
+    assert!(LocalUserKeywordFilter::check_regex(pool, "(unclosed")
+      .await
+      .is_err());
+
+    let num_deleted =
+      LocalUserKeywordFilter::delete(pool, inserted_filter.id, inserted_local_user.id)
+        .await
+        .unwrap();
+    assert_eq!(1, num_deleted);
+
+    Person::delete(pool, inserted_person.id).await.unwrap();
+    Instance::delete(pool, inserted_instance.id).await.unwrap();
+  }
+}
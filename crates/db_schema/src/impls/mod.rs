@@ -6,7 +6,11 @@ pub mod comment_reply;
 pub mod comment_report;
 pub mod community;
 pub mod community_block;
+pub mod community_post_tag;
+pub mod community_wiki;
+pub mod conversation;
 pub mod custom_emoji;
+pub mod draft;
 pub mod email_verification;
 pub mod federation_allowlist;
 pub mod federation_blocklist;
@@ -17,17 +21,26 @@ pub mod language;
 pub mod local_site;
 pub mod local_site_rate_limit;
 pub mod local_user;
+pub mod local_user_keyword_filter;
 pub mod login_token;
 pub mod moderator;
+pub mod multi_community;
 pub mod password_reset_request;
 pub mod person;
 pub mod person_block;
 pub mod person_mention;
+pub mod person_note;
+pub mod poll;
 pub mod post;
 pub mod post_report;
 pub mod private_message;
 pub mod private_message_report;
+pub mod push_subscription;
+pub mod reaction;
 pub mod registration_application;
+pub mod report_combined;
+pub mod report_note;
 pub mod secret;
 pub mod site;
 pub mod tagline;
+pub mod webhook;
@@ -1,4 +1,5 @@
 use crate::{
+  newtypes::{CommunityId, PersonId},
   source::moderator::{
     AdminPurgeComment,
     AdminPurgeCommentForm,
@@ -16,6 +17,8 @@ use crate::{
     ModBanForm,
     ModBanFromCommunity,
     ModBanFromCommunityForm,
+    ModCommunityPostTag,
+    ModCommunityPostTagForm,
     ModFeaturePost,
     ModFeaturePostForm,
     ModHideCommunity,
@@ -28,13 +31,18 @@ use crate::{
     ModRemoveCommunityForm,
     ModRemovePost,
     ModRemovePostForm,
+    ModResolveReport,
+    ModResolveReportForm,
     ModTransferCommunity,
     ModTransferCommunityForm,
+    ModWarning,
+    ModWarningForm,
   },
   traits::Crud,
   utils::{get_conn, DbPool},
 };
-use diesel::{dsl::insert_into, result::Error, QueryDsl};
+use chrono::{DateTime, Utc};
+use diesel::{dsl::insert_into, result::Error, ExpressionMethods, QueryDsl};
 use diesel_async::RunQueryDsl;
 
 #[async_trait]
@@ -265,6 +273,113 @@ impl Crud for ModHideCommunity {
   }
 }
 
+#[async_trait]
+impl Crud for ModCommunityPostTag {
+  type InsertForm = ModCommunityPostTagForm;
+  type UpdateForm = ModCommunityPostTagForm;
+  type IdType = i32;
+
+  async fn create(pool: &mut DbPool<'_>, form: &ModCommunityPostTagForm) -> Result<Self, Error> {
+    use crate::schema::mod_community_post_tag::dsl::mod_community_post_tag;
+    let conn = &mut get_conn(pool).await?;
+    insert_into(mod_community_post_tag)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    from_id: i32,
+    form: &ModCommunityPostTagForm,
+  ) -> Result<Self, Error> {
+    use crate::schema::mod_community_post_tag::dsl::mod_community_post_tag;
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(mod_community_post_tag.find(from_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+}
+
+#[async_trait]
+impl Crud for ModWarning {
+  type InsertForm = ModWarningForm;
+  type UpdateForm = ModWarningForm;
+  type IdType = i32;
+
+  async fn create(pool: &mut DbPool<'_>, form: &ModWarningForm) -> Result<Self, Error> {
+    use crate::schema::mod_warning::dsl::mod_warning;
+    let conn = &mut get_conn(pool).await?;
+    insert_into(mod_warning)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    from_id: i32,
+    form: &ModWarningForm,
+  ) -> Result<Self, Error> {
+    use crate::schema::mod_warning::dsl::mod_warning;
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(mod_warning.find(from_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+}
+
+#[async_trait]
+impl Crud for ModResolveReport {
+  type InsertForm = ModResolveReportForm;
+  type UpdateForm = ModResolveReportForm;
+  type IdType = i32;
+
+  async fn create(pool: &mut DbPool<'_>, form: &ModResolveReportForm) -> Result<Self, Error> {
+    use crate::schema::mod_resolve_report::dsl::mod_resolve_report;
+    let conn = &mut get_conn(pool).await?;
+    insert_into(mod_resolve_report)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    from_id: i32,
+    form: &ModResolveReportForm,
+  ) -> Result<Self, Error> {
+    use crate::schema::mod_resolve_report::dsl::mod_resolve_report;
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(mod_resolve_report.find(from_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+}
+
+impl ModWarning {
+  /// How many warnings a person got in a community since the given time.
+  pub async fn count_since(
+    pool: &mut DbPool<'_>,
+    for_person_id: PersonId,
+    for_community_id: CommunityId,
+    since: DateTime<Utc>,
+  ) -> Result<i64, Error> {
+    use crate::schema::mod_warning::dsl::{community_id, mod_warning, other_person_id, when_};
+    let conn = &mut get_conn(pool).await?;
+    mod_warning
+      .filter(other_person_id.eq(for_person_id))
+      .filter(community_id.eq(for_community_id))
+      .filter(when_.ge(since))
+      .count()
+      .get_result(conn)
+      .await
+  }
+}
+
 #[async_trait]
 impl Crud for ModAddCommunity {
   type InsertForm = ModAddCommunityForm;
@@ -493,6 +608,10 @@ mod tests {
         ModRemoveCommunityForm,
         ModRemovePost,
         ModRemovePostForm,
+        ModResolveReport,
+        ModResolveReportForm,
+        ModWarning,
+        ModWarningForm,
       },
       person::{Person, PersonInsertForm},
       post::{Post, PostInsertForm},
@@ -759,6 +878,78 @@ mod tests {
       when_: inserted_mod_add.when_,
     };
 
+    // warning
+
+    let mod_warning_form = ModWarningForm {
+      mod_person_id: inserted_mod.id,
+      other_person_id: inserted_person.id,
+      community_id: inserted_community.id,
+      post_id: inserted_post.id,
+      comment_id: Some(inserted_comment.id),
+      reason: "rule 1".to_string(),
+    };
+    let inserted_mod_warning = ModWarning::create(pool, &mod_warning_form).await.unwrap();
+    let read_mod_warning = ModWarning::read(pool, inserted_mod_warning.id)
+      .await
+      .unwrap();
+    let expected_mod_warning = ModWarning {
+      id: inserted_mod_warning.id,
+      mod_person_id: inserted_mod.id,
+      other_person_id: inserted_person.id,
+      community_id: inserted_community.id,
+      post_id: inserted_post.id,
+      comment_id: Some(inserted_comment.id),
+      reason: "rule 1".to_string(),
+      when_: inserted_mod_warning.when_,
+    };
+    let warning_count = ModWarning::count_since(
+      pool,
+      inserted_person.id,
+      inserted_community.id,
+      inserted_mod_warning.when_,
+    )
+    .await
+    .unwrap();
+    let later_warning_count = ModWarning::count_since(
+      pool,
+      inserted_person.id,
+      inserted_community.id,
+      inserted_mod_warning.when_ + chrono::Duration::seconds(1),
+    )
+    .await
+    .unwrap();
+
+    // resolve report
+
+    let mod_resolve_report_form = ModResolveReportForm {
+      mod_person_id: inserted_mod.id,
+      other_person_id: inserted_person.id,
+      community_id: inserted_community.id,
+      post_id: inserted_post.id,
+      comment_id: Some(inserted_comment.id),
+      report_count: 2,
+      resolved: None,
+      reason: Some("not against the rules".to_string()),
+    };
+    let inserted_mod_resolve_report = ModResolveReport::create(pool, &mod_resolve_report_form)
+      .await
+      .unwrap();
+    let read_mod_resolve_report = ModResolveReport::read(pool, inserted_mod_resolve_report.id)
+      .await
+      .unwrap();
+    let expected_mod_resolve_report = ModResolveReport {
+      id: inserted_mod_resolve_report.id,
+      mod_person_id: inserted_mod.id,
+      other_person_id: inserted_person.id,
+      community_id: inserted_community.id,
+      post_id: inserted_post.id,
+      comment_id: Some(inserted_comment.id),
+      report_count: 2,
+      resolved: true,
+      reason: Some("not against the rules".to_string()),
+      when_: inserted_mod_resolve_report.when_,
+    };
+
     Comment::delete(pool, inserted_comment.id).await.unwrap();
     Post::delete(pool, inserted_post.id).await.unwrap();
     Community::delete(pool, inserted_community.id)
@@ -777,5 +968,9 @@ mod tests {
     assert_eq!(expected_mod_ban, read_mod_ban);
     assert_eq!(expected_mod_add_community, read_mod_add_community);
     assert_eq!(expected_mod_add, read_mod_add);
+    assert_eq!(expected_mod_warning, read_mod_warning);
+    assert_eq!(expected_mod_resolve_report, read_mod_resolve_report);
+    assert_eq!(1, warning_count);
+    assert_eq!(0, later_warning_count);
   }
 }
@@ -0,0 +1,293 @@
+use crate::{
+  newtypes::{CommunityId, DbUrl, MultiCommunityId, PersonId},
+  schema::{community, multi_community, multi_community_entry},
+  source::{
+    community::Community,
+    multi_community::{
+      MultiCommunity,
+      MultiCommunityEntry,
+      MultiCommunityEntryForm,
+      MultiCommunityInsertForm,
+      MultiCommunityUpdateForm,
+    },
+  },
+  traits::Crud,
+  utils::{functions::lower, get_conn, DbPool},
+};
+use diesel::{
+  delete,
+  dsl::insert_into,
+  result::Error,
+  ExpressionMethods,
+  OptionalExtension,
+  QueryDsl,
+};
+use diesel_async::{AsyncPgConnection, RunQueryDsl};
+use url::Url;
+
+#[async_trait]
+impl Crud for MultiCommunity {
+  type InsertForm = MultiCommunityInsertForm;
+  type UpdateForm = MultiCommunityUpdateForm;
+  type IdType = MultiCommunityId;
+
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(multi_community::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    multi_community_id: MultiCommunityId,
+    form: &Self::UpdateForm,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(multi_community::table.find(multi_community_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+}
+
+impl MultiCommunity {
+  /// Update or insert a multi-community received over federation.
+  pub async fn upsert(
+    pool: &mut DbPool<'_>,
+    form: &MultiCommunityInsertForm,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(multi_community::table)
+      .values(form)
+      .on_conflict(multi_community::ap_id)
+      .do_update()
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  /// Reads a local multi-community by its name.
+  pub async fn read_from_name(
+    pool: &mut DbPool<'_>,
+    name: &str,
+    include_deleted: bool,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let mut query = multi_community::table
+      .into_boxed()
+      .filter(multi_community::local.eq(true))
+      .filter(lower(multi_community::name).eq(name.to_lowercase()));
+    if !include_deleted {
+      query = query.filter(multi_community::deleted.eq(false));
+    }
+    query.first::<Self>(conn).await
+  }
+
+  pub async fn read_from_apub_id(
+    pool: &mut DbPool<'_>,
+    object_id: Url,
+  ) -> Result<Option<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let object_id: DbUrl = object_id.into();
+    multi_community::table
+      .filter(multi_community::ap_id.eq(object_id))
+      .first::<Self>(conn)
+      .await
+      .optional()
+  }
+
+  pub async fn list_for_creator(
+    pool: &mut DbPool<'_>,
+    creator_id: PersonId,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    multi_community::table
+      .filter(multi_community::creator_id.eq(creator_id))
+      .filter(multi_community::deleted.eq(false))
+      .order_by(multi_community::name)
+      .load::<Self>(conn)
+      .await
+  }
+
+  /// Returns the communities which are part of the multi-community, ordered by name.
+  pub async fn list_communities(
+    pool: &mut DbPool<'_>,
+    multi_community_id: MultiCommunityId,
+  ) -> Result<Vec<Community>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    multi_community_entry::table
+      .inner_join(community::table)
+      .filter(multi_community_entry::multi_community_id.eq(multi_community_id))
+      .order_by(community::name)
+      .select(community::all_columns)
+      .load::<Community>(conn)
+      .await
+  }
+}
+
+impl MultiCommunityEntry {
+  /// Replaces the communities of a multi-community with the given ones.
+  pub async fn update(
+    pool: &mut DbPool<'_>,
+    for_multi_community_id: MultiCommunityId,
+    community_ids: Vec<CommunityId>,
+  ) -> Result<(), Error> {
+    let conn = &mut get_conn(pool).await?;
+    conn
+      .build_transaction()
+      .run(|conn| {
+        Box::pin(async move {
+          Self::clear(conn, for_multi_community_id).await?;
+          if community_ids.is_empty() {
+            return Ok(());
+          }
+
+          let forms = community_ids
+            .into_iter()
+            .map(|community_id| MultiCommunityEntryForm {
+              multi_community_id: for_multi_community_id,
+              community_id,
+            })
+            .collect::<Vec<_>>();
+          insert_into(multi_community_entry::table)
+            .values(forms)
+            .on_conflict_do_nothing()
+            .execute(conn)
+            .await?;
+          Ok(())
+        }) as _
+      })
+      .await
+  }
+
+  async fn clear(
+    conn: &mut AsyncPgConnection,
+    for_multi_community_id: MultiCommunityId,
+  ) -> Result<usize, Error> {
+    delete(
+      multi_community_entry::table
+        .filter(multi_community_entry::multi_community_id.eq(for_multi_community_id)),
+    )
+    .execute(conn)
+    .await
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  #![allow(clippy::unwrap_used)]
+  #![allow(clippy::indexing_slicing)]
+
+  use crate::{
+    source::{
+      community::{Community, CommunityInsertForm},
+      instance::Instance,
+      multi_community::{
+        MultiCommunity,
+        MultiCommunityEntry,
+        MultiCommunityInsertForm,
+        MultiCommunityUpdateForm,
+      },
+      person::{Person, PersonInsertForm},
+    },
+    traits::Crud,
+    utils::build_db_pool_for_tests,
+  };
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_crud() {
+    let pool = &build_db_pool_for_tests().await;
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld".to_string())
+      .await
+      .unwrap();
+
+    let new_person = PersonInsertForm::builder()
+      .name("frederick".into())
+      .public_key("pubkey".to_string())
+      .instance_id(inserted_instance.id)
+      .build();
+    let inserted_person = Person::create(pool, &new_person).await.unwrap();
+
+    let mut community_ids = vec![];
+    for name in ["debian", "arch"] {
+      let new_community = CommunityInsertForm::builder()
+        .name(name.into())
+        .title(name.into())
+        .public_key("pubkey".to_string())
+        .instance_id(inserted_instance.id)
+        .build();
+      community_ids.push(Community::create(pool, &new_community).await.unwrap().id);
+    }
+
+    let multi_community_form = MultiCommunityInsertForm::builder()
+      .creator_id(inserted_person.id)
+      .instance_id(inserted_instance.id)
+      .name("linux".into())
+      .title("All Linux communities".into())
+      .build();
+    let inserted_multi_community = MultiCommunity::create(pool, &multi_community_form)
+      .await
+      .unwrap();
+    assert!(inserted_multi_community.local);
+
+    MultiCommunityEntry::update(pool, inserted_multi_community.id, community_ids.clone())
+      .await
+      .unwrap();
+    let communities = MultiCommunity::list_communities(pool, inserted_multi_community.id)
+      .await
+      .unwrap();
+    assert_eq!(
+      vec!["arch", "debian"],
+      communities
+        .iter()
+        .map(|c| c.name.as_str())
+        .collect::<Vec<_>>()
+    );
+
+    // Replacing the entries removes the previous ones
+    MultiCommunityEntry::update(pool, inserted_multi_community.id, vec![community_ids[0]])
+      .await
+      .unwrap();
+    let communities = MultiCommunity::list_communities(pool, inserted_multi_community.id)
+      .await
+      .unwrap();
+    assert_eq!(1, communities.len());
+    assert_eq!(community_ids[0], communities[0].id);
+
+    let read_multi_community = MultiCommunity::read_from_name(pool, "Linux", false)
+      .await
+      .unwrap();
+    assert_eq!(inserted_multi_community, read_multi_community);
+
+    let update_form = MultiCommunityUpdateForm {
+      deleted: Some(true),
+      ..Default::default()
+    };
+    MultiCommunity::update(pool, inserted_multi_community.id, &update_form)
+      .await
+      .unwrap();
+    assert!(MultiCommunity::read_from_name(pool, "linux", false)
+      .await
+      .is_err());
+    let created_by_person = MultiCommunity::list_for_creator(pool, inserted_person.id)
+      .await
+      .unwrap();
+    assert!(created_by_person.is_empty());
+
+    let num_deleted = MultiCommunity::delete(pool, inserted_multi_community.id)
+      .await
+      .unwrap();
+    assert_eq!(1, num_deleted);
+    for community_id in community_ids {
+      Community::delete(pool, community_id).await.unwrap();
+    }
+    Person::delete(pool, inserted_person.id).await.unwrap();
+    Instance::delete(pool, inserted_instance.id).await.unwrap();
+  }
+}
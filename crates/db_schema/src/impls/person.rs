@@ -168,6 +168,23 @@ impl Followable for PersonFollower {
 }
 
 impl PersonFollower {
+  /// Marks the follow of a remote person as accepted, after receiving an `Accept` activity.
+  pub async fn follow_accepted_by_person(
+    pool: &mut DbPool<'_>,
+    person_id: PersonId,
+    follower_id: PersonId,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(
+      person_follower::table
+        .filter(person_follower::person_id.eq(person_id))
+        .filter(person_follower::follower_id.eq(follower_id)),
+    )
+    .set(person_follower::pending.eq(false))
+    .get_result::<Self>(conn)
+    .await
+  }
+
   pub async fn list_followers(
     pool: &mut DbPool<'_>,
     for_person_id: PersonId,
@@ -188,6 +205,7 @@ mod tests {
   #![allow(clippy::indexing_slicing)]
 
   use crate::{
+    aggregates::structs::PersonAggregates,
     source::{
       instance::Instance,
       person::{Person, PersonFollower, PersonFollowerForm, PersonInsertForm, PersonUpdateForm},
@@ -237,6 +255,7 @@ mod tests {
       matrix_user_id: None,
       ban_expires: None,
       instance_id: inserted_instance.id,
+      encryption_public_key: None,
     };
 
     let read_person = Person::read(pool, inserted_person.id).await.unwrap();
@@ -293,9 +312,36 @@ mod tests {
     let followers = PersonFollower::list_followers(pool, person_1.id)
       .await
       .unwrap();
-    assert_eq!(vec![person_2], followers);
+    assert_eq!(vec![person_2.clone()], followers);
+
+    let person_1_counts = PersonAggregates::read(pool, person_1.id).await.unwrap();
+    assert_eq!(1, person_1_counts.follower_count);
+    assert_eq!(0, person_1_counts.following_count);
+    let person_2_counts = PersonAggregates::read(pool, person_2.id).await.unwrap();
+    assert_eq!(0, person_2_counts.follower_count);
+    assert_eq!(1, person_2_counts.following_count);
 
     let unfollow = PersonFollower::unfollow(pool, &follow_form).await.unwrap();
     assert_eq!(1, unfollow);
+
+    // follows of remote persons are pending until accepted, and aren't counted until then
+    let pending_form = PersonFollowerForm {
+      person_id: person_1.id,
+      follower_id: person_2.id,
+      pending: true,
+    };
+    let pending_follow = PersonFollower::follow(pool, &pending_form).await.unwrap();
+    assert!(pending_follow.pending);
+    let person_1_counts = PersonAggregates::read(pool, person_1.id).await.unwrap();
+    assert_eq!(0, person_1_counts.follower_count);
+
+    let accepted_follow = PersonFollower::follow_accepted_by_person(pool, person_1.id, person_2.id)
+      .await
+      .unwrap();
+    assert!(!accepted_follow.pending);
+    let person_1_counts = PersonAggregates::read(pool, person_1.id).await.unwrap();
+    assert_eq!(1, person_1_counts.follower_count);
+
+    PersonFollower::unfollow(pool, &pending_form).await.unwrap();
   }
 }
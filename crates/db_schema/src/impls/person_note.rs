@@ -0,0 +1,105 @@
+use crate::{
+  newtypes::PersonNoteId,
+  schema::person_note,
+  source::person_note::{PersonNote, PersonNoteInsertForm, PersonNoteUpdateForm},
+  traits::Crud,
+  utils::{get_conn, DbPool},
+};
+use diesel::{dsl::insert_into, result::Error, QueryDsl};
+use diesel_async::RunQueryDsl;
+
+#[async_trait]
+impl Crud for PersonNote {
+  type InsertForm = PersonNoteInsertForm;
+  type UpdateForm = PersonNoteUpdateForm;
+  type IdType = PersonNoteId;
+
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(person_note::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    person_note_id: PersonNoteId,
+    form: &Self::UpdateForm,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(person_note::table.find(person_note_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  #![allow(clippy::unwrap_used)]
+  #![allow(clippy::indexing_slicing)]
+
+  use crate::{
+    source::{
+      instance::Instance,
+      person::{Person, PersonInsertForm},
+      person_note::{PersonNote, PersonNoteInsertForm, PersonNoteUpdateForm},
+    },
+    traits::Crud,
+    utils::build_db_pool_for_tests,
+  };
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_crud() {
+    let pool = &build_db_pool_for_tests().await;
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld".to_string())
+      .await
+      .unwrap();
+
+    let new_person = PersonInsertForm::builder()
+      .name("person_note_target".into())
+      .public_key("pubkey".to_string())
+      .instance_id(inserted_instance.id)
+      .build();
+    let inserted_person = Person::create(pool, &new_person).await.unwrap();
+
+    let new_mod = PersonInsertForm::builder()
+      .name("person_note_mod".into())
+      .public_key("pubkey".to_string())
+      .instance_id(inserted_instance.id)
+      .build();
+    let inserted_mod = Person::create(pool, &new_mod).await.unwrap();
+
+    let form = PersonNoteInsertForm {
+      person_id: inserted_person.id,
+      creator_id: inserted_mod.id,
+      community_id: None,
+      content: "warned twice for spam".to_string(),
+    };
+    let inserted_note = PersonNote::create(pool, &form).await.unwrap();
+    assert_eq!(inserted_person.id, inserted_note.person_id);
+    assert_eq!(None, inserted_note.updated);
+
+    let update_form = PersonNoteUpdateForm {
+      content: Some("warned three times for spam".to_string()),
+      updated: Some(Some(crate::utils::naive_now())),
+    };
+    let updated_note = PersonNote::update(pool, inserted_note.id, &update_form)
+      .await
+      .unwrap();
+    assert_eq!("warned three times for spam", updated_note.content);
+    assert!(updated_note.updated.is_some());
+
+    // Notes are removed together with the person they are about
+    Person::delete(pool, inserted_person.id).await.unwrap();
+    assert!(PersonNote::read(pool, inserted_note.id).await.is_err());
+
+    Person::delete(pool, inserted_mod.id).await.unwrap();
+    Instance::delete(pool, inserted_instance.id).await.unwrap();
+  }
+}
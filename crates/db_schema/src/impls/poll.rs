@@ -0,0 +1,357 @@
+use crate::{
+  newtypes::{PersonId, PollOptionId, PostId},
+  schema::{poll, poll_option, poll_vote},
+  source::poll::{
+    Poll,
+    PollForm,
+    PollOption,
+    PollOptionForm,
+    PollOptionResults,
+    PollVote,
+    PollVoteForm,
+  },
+  utils::{get_conn, DbPool},
+};
+use diesel::{
+  delete,
+  deserialize::FromSql,
+  dsl::{exists, insert_into},
+  pg::{Pg, PgValue},
+  result::Error,
+  select,
+  sql_types::Jsonb,
+  ExpressionMethods,
+  OptionalExtension,
+  QueryDsl,
+};
+use diesel_async::RunQueryDsl;
+
+impl Poll {
+  /// Creates or updates the poll of a post together with its options. Options which are not in
+  /// the list anymore are removed, along with their votes.
+  pub async fn upsert(
+    pool: &mut DbPool<'_>,
+    form: &PollForm,
+    options: Vec<PollOptionForm>,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let form = form.clone();
+    conn
+      .build_transaction()
+      .run(|conn| {
+        Box::pin(async move {
+          let poll = insert_into(poll::table)
+            .values(&form)
+            .on_conflict(poll::post_id)
+            .do_update()
+            .set(&form)
+            .get_result::<Self>(conn)
+            .await?;
+
+          let names = options.iter().map(|o| o.name.clone()).collect::<Vec<_>>();
+          delete(
+            poll_option::table
+              .filter(poll_option::post_id.eq(poll.post_id))
+              .filter(poll_option::name.ne_all(names)),
+          )
+          .execute(conn)
+          .await?;
+
+          for option in options {
+            insert_into(poll_option::table)
+              .values(&option)
+              .on_conflict((poll_option::post_id, poll_option::name))
+              .do_update()
+              .set(&option)
+              .execute(conn)
+              .await?;
+          }
+          Ok(poll)
+        }) as _
+      })
+      .await
+  }
+
+  pub async fn read_for_post(
+    pool: &mut DbPool<'_>,
+    for_post_id: PostId,
+  ) -> Result<Option<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    poll::table
+      .find(for_post_id)
+      .first::<Self>(conn)
+      .await
+      .optional()
+  }
+}
+
+impl PollOption {
+  pub async fn list_for_post(
+    pool: &mut DbPool<'_>,
+    for_post_id: PostId,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    poll_option::table
+      .filter(poll_option::post_id.eq(for_post_id))
+      .order_by(poll_option::position)
+      .load::<Self>(conn)
+      .await
+  }
+}
+
+impl PollVote {
+  /// Replaces the votes of a person in a poll with the given options.
+  pub async fn vote(
+    pool: &mut DbPool<'_>,
+    for_post_id: PostId,
+    for_person_id: PersonId,
+    option_ids: Vec<PollOptionId>,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    conn
+      .build_transaction()
+      .run(|conn| {
+        Box::pin(async move {
+          delete(
+            poll_vote::table
+              .filter(poll_vote::post_id.eq(for_post_id))
+              .filter(poll_vote::person_id.eq(for_person_id)),
+          )
+          .execute(conn)
+          .await?;
+          if option_ids.is_empty() {
+            return Ok(vec![]);
+          }
+
+          let forms = option_ids
+            .into_iter()
+            .map(|poll_option_id| PollVoteForm {
+              poll_option_id,
+              person_id: for_person_id,
+              post_id: for_post_id,
+            })
+            .collect::<Vec<_>>();
+          insert_into(poll_vote::table)
+            .values(forms)
+            .on_conflict_do_nothing()
+            .get_results::<Self>(conn)
+            .await
+        }) as _
+      })
+      .await
+  }
+
+  /// Whether the person voted for any option of the poll.
+  pub async fn has_voted(
+    pool: &mut DbPool<'_>,
+    for_post_id: PostId,
+    for_person_id: PersonId,
+  ) -> Result<bool, Error> {
+    let conn = &mut get_conn(pool).await?;
+    select(exists(
+      poll_vote::table
+        .filter(poll_vote::post_id.eq(for_post_id))
+        .filter(poll_vote::person_id.eq(for_person_id)),
+    ))
+    .get_result::<bool>(conn)
+    .await
+  }
+
+  /// Adds a single vote to the existing votes of a person, used for votes received over
+  /// federation which only ever contain one option.
+  pub async fn add(pool: &mut DbPool<'_>, form: &PollVoteForm) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(poll_vote::table)
+      .values(form)
+      .on_conflict_do_nothing()
+      .execute(conn)
+      .await
+  }
+}
+
+impl FromSql<Jsonb, Pg> for PollOptionResults {
+  fn from_sql(bytes: PgValue<'_>) -> diesel::deserialize::Result<Self> {
+    let value = <serde_json::Value as FromSql<Jsonb, Pg>>::from_sql(bytes)?;
+    Ok(PollOptionResults(serde_json::from_value(value)?))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  #![allow(clippy::unwrap_used)]
+  #![allow(clippy::indexing_slicing)]
+
+  use crate::{
+    source::{
+      community::{Community, CommunityInsertForm},
+      instance::Instance,
+      person::{Person, PersonInsertForm},
+      poll::{Poll, PollForm, PollOption, PollOptionForm, PollVote, PollVoteForm},
+      post::{Post, PostInsertForm},
+    },
+    traits::Crud,
+    utils::build_db_pool_for_tests,
+  };
+  use serial_test::serial;
+
+  fn option_forms(post: &Post, names: &[&str]) -> Vec<PollOptionForm> {
+    names
+      .iter()
+      .enumerate()
+      .map(|(position, name)| PollOptionForm {
+        post_id: post.id,
+        name: (*name).to_string(),
+        position: position as i32,
+        vote_count: None,
+      })
+      .collect()
+  }
+
+  #[tokio::test]
+  #[serial]
+  async fn test_crud() {
+    let pool = &build_db_pool_for_tests().await;
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld".to_string())
+      .await
+      .unwrap();
+
+    let new_person = PersonInsertForm::builder()
+      .name("pollster".into())
+      .public_key("pubkey".to_string())
+      .instance_id(inserted_instance.id)
+      .build();
+    let inserted_person = Person::create(pool, &new_person).await.unwrap();
+
+    let new_person_2 = PersonInsertForm::builder()
+      .name("poll_voter".into())
+      .public_key("pubkey".to_string())
+      .instance_id(inserted_instance.id)
+      .build();
+    let inserted_person_2 = Person::create(pool, &new_person_2).await.unwrap();
+
+    let new_community = CommunityInsertForm::builder()
+      .name("test community_poll".to_string())
+      .title("nada".to_owned())
+      .public_key("pubkey".to_string())
+      .instance_id(inserted_instance.id)
+      .build();
+    let inserted_community = Community::create(pool, &new_community).await.unwrap();
+
+    let new_post = PostInsertForm::builder()
+      .name("A test poll".into())
+      .creator_id(inserted_person.id)
+      .community_id(inserted_community.id)
+      .build();
+    let inserted_post = Post::create(pool, &new_post).await.unwrap();
+
+    let poll_form = PollForm {
+      post_id: inserted_post.id,
+      multiple_choice: true,
+      end_time: None,
+      hide_results: false,
+      voters_count: None,
+    };
+    let inserted_poll = Poll::upsert(
+      pool,
+      &poll_form,
+      option_forms(&inserted_post, &["Yes", "No", "Maybe"]),
+    )
+    .await
+    .unwrap();
+    assert!(inserted_poll.multiple_choice);
+    let options = PollOption::list_for_post(pool, inserted_post.id)
+      .await
+      .unwrap();
+    assert_eq!(
+      vec!["Yes", "No", "Maybe"],
+      options.iter().map(|o| o.name.as_str()).collect::<Vec<_>>()
+    );
+
+    // Vote counts are updated by the trigger
+    PollVote::vote(
+      pool,
+      inserted_post.id,
+      inserted_person.id,
+      vec![options[0].id, options[2].id],
+    )
+    .await
+    .unwrap();
+    let form = PollVoteForm {
+      poll_option_id: options[0].id,
+      person_id: inserted_person_2.id,
+      post_id: inserted_post.id,
+    };
+    PollVote::add(pool, &form).await.unwrap();
+    let options = PollOption::list_for_post(pool, inserted_post.id)
+      .await
+      .unwrap();
+    assert_eq!(
+      vec![2, 0, 1],
+      options.iter().map(|o| o.vote_count).collect::<Vec<_>>()
+    );
+    let read_poll = Poll::read_for_post(pool, inserted_post.id)
+      .await
+      .unwrap()
+      .unwrap();
+    assert_eq!(2, read_poll.voters_count);
+    assert!(
+      PollVote::has_voted(pool, inserted_post.id, inserted_person_2.id)
+        .await
+        .unwrap()
+    );
+
+    // Voting again replaces the previous votes
+    PollVote::vote(
+      pool,
+      inserted_post.id,
+      inserted_person.id,
+      vec![options[1].id],
+    )
+    .await
+    .unwrap();
+    let options = PollOption::list_for_post(pool, inserted_post.id)
+      .await
+      .unwrap();
+    assert_eq!(
+      vec![1, 1, 0],
+      options.iter().map(|o| o.vote_count).collect::<Vec<_>>()
+    );
+
+    // Voting for nothing removes the votes
+    PollVote::vote(pool, inserted_post.id, inserted_person_2.id, vec![])
+      .await
+      .unwrap();
+    assert!(
+      !PollVote::has_voted(pool, inserted_post.id, inserted_person_2.id)
+        .await
+        .unwrap()
+    );
+
+    // Options which are left out are removed
+    Poll::upsert(
+      pool,
+      &poll_form,
+      option_forms(&inserted_post, &["Yes", "No"]),
+    )
+    .await
+    .unwrap();
+    let options = PollOption::list_for_post(pool, inserted_post.id)
+      .await
+      .unwrap();
+    assert_eq!(2, options.len());
+
+    Post::delete(pool, inserted_post.id).await.unwrap();
+    assert!(Poll::read_for_post(pool, inserted_post.id)
+      .await
+      .unwrap()
+      .is_none());
+    Community::delete(pool, inserted_community.id)
+      .await
+      .unwrap();
+    Person::delete(pool, inserted_person.id).await.unwrap();
+    Person::delete(pool, inserted_person_2.id).await.unwrap();
+    Instance::delete(pool, inserted_instance.id).await.unwrap();
+  }
+}
@@ -1,40 +1,52 @@
 use super::instance::coalesce;
 use crate::{
   newtypes::{CommunityId, DbUrl, PersonId, PostId},
-  schema::post::dsl::{
-    ap_id,
-    body,
-    community_id,
-    creator_id,
-    deleted,
-    featured_community,
-    local,
-    name,
-    post,
-    published,
-    removed,
-    thumbnail_url,
-    updated,
-    url,
+  schema::{
+    community,
+    post::dsl::{
+      ap_id,
+      body,
+      community_id,
+      creator_id,
+      deleted,
+      featured_community,
+      local,
+      name,
+      post,
+      published,
+      removed,
+      scheduled_publish_time,
+      thumbnail_url,
+      updated,
+      url,
+    },
+    post_aggregates,
+    post_revision,
   },
-  source::post::{
-    Post,
-    PostInsertForm,
-    PostLike,
-    PostLikeForm,
-    PostRead,
-    PostReadForm,
-    PostSaved,
-    PostSavedForm,
-    PostUpdateForm,
+  source::{
+    community::Community,
+    post::{
+      Post,
+      PostInsertForm,
+      PostLike,
+      PostLikeForm,
+      PostRead,
+      PostReadForm,
+      PostRevision,
+      PostRevisionForm,
+      PostSaved,
+      PostSavedForm,
+      PostUpdateForm,
+    },
   },
   traits::{Crud, Likeable, Saveable},
   utils::{get_conn, naive_now, DbPool, DELETED_REPLACEMENT_TEXT, FETCH_LIMIT_MAX},
+  CommunityVisibility,
 };
 use ::url::Url;
-use chrono::{Duration, Utc};
+use chrono::{DateTime, Duration, Utc};
 use diesel::{dsl::insert_into, result::Error, ExpressionMethods, QueryDsl, TextExpressionMethods};
-use diesel_async::RunQueryDsl;
+use diesel_async::{AsyncPgConnection, RunQueryDsl};
 use std::collections::HashSet;
 
 #[async_trait]
@@ -77,6 +89,7 @@ impl Post {
       .filter(community_id.eq(the_community_id))
       .filter(deleted.eq(false))
       .filter(removed.eq(false))
+      .filter(scheduled_publish_time.is_null())
       .then_order_by(featured_community.desc())
       .then_order_by(published.desc())
       .limit(FETCH_LIMIT_MAX)
@@ -94,12 +107,42 @@ impl Post {
       .filter(deleted.eq(false))
       .filter(removed.eq(false))
       .filter(featured_community.eq(true))
+      .filter(scheduled_publish_time.is_null())
       .then_order_by(published.desc())
       .limit(FETCH_LIMIT_MAX)
       .load::<Self>(conn)
       .await
   }
 
+  pub async fn read_many(
+    pool: &mut DbPool<'_>,
+    post_ids: &HashSet<PostId>,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    post
+      .filter(crate::schema::post::id.eq_any(post_ids.iter().copied()))
+      .load::<Self>(conn)
+      .await
+  }
+
+  /// The communities which the given posts belong to.
+  pub async fn list_communities(
+    pool: &mut DbPool<'_>,
+    post_ids: &HashSet<PostId>,
+  ) -> Result<Vec<Community>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    community::table
+      .filter(
+        community::id.eq_any(
+          post
+            .filter(crate::schema::post::id.eq_any(post_ids.iter().copied()))
+            .select(community_id),
+        ),
+      )
+      .load::<Community>(conn)
+      .await
+  }
+
   pub async fn list_for_sitemap(
     pool: &mut DbPool<'_>,
   ) -> Result<Vec<(DbUrl, chrono::DateTime<Utc>)>, Error> {
@@ -109,18 +152,112 @@ impl Post {
       .filter(local.eq(true))
       .filter(deleted.eq(false))
       .filter(removed.eq(false))
+      .filter(scheduled_publish_time.is_null())
+      .filter(
+        community_id.eq_any(
+          community::table
+            .select(community::id)
+            .filter(community::visibility.eq(CommunityVisibility::Public)),
+        ),
+      )
       .filter(published.ge(Utc::now().naive_utc() - Duration::days(1)))
       .order(published.desc())
       .load::<(DbUrl, chrono::DateTime<Utc>)>(conn)
       .await
   }
 
+  /// Publishes the scheduled posts whose publish time was reached, and returns them. Their
+  /// published time is reset, so that they show up as new posts.
+  pub async fn publish_scheduled(pool: &mut DbPool<'_>) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    conn
+      .build_transaction()
+      .run(|conn| {
+        Box::pin(async move {
+          let now = naive_now();
+          let posts = diesel::update(
+            post
+              .filter(scheduled_publish_time.le(now))
+              .filter(deleted.eq(false))
+              .filter(removed.eq(false)),
+          )
+          .set((
+            scheduled_publish_time.eq(None::<DateTime<Utc>>),
+            published.eq(now),
+          ))
+          .get_results::<Self>(conn)
+          .await?;
+
+          let post_ids: Vec<PostId> = posts.iter().map(|p| p.id).collect();
+          Self::reset_published_aggregates(conn, post_ids, now).await?;
+          Ok(posts)
+        }) as _
+      })
+      .await
+  }
+
+  /// Publishes a post which is still scheduled right away, in the same way as
+  /// [`Post::publish_scheduled`].
+  pub async fn publish_now(pool: &mut DbPool<'_>, post_id: PostId) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    conn
+      .build_transaction()
+      .run(|conn| {
+        Box::pin(async move {
+          let now = naive_now();
+          let updated_post = diesel::update(
+            post
+              .find(post_id)
+              .filter(scheduled_publish_time.is_not_null()),
+          )
+          .set((
+            scheduled_publish_time.eq(None::<DateTime<Utc>>),
+            published.eq(now),
+          ))
+          .get_result::<Self>(conn)
+          .await?;
+
+          Self::reset_published_aggregates(conn, vec![post_id], now).await?;
+          Ok(updated_post)
+        }) as _
+      })
+      .await
+  }
+
+  async fn reset_published_aggregates(
+    conn: &mut AsyncPgConnection,
+    post_ids: Vec<PostId>,
+    now: DateTime<Utc>,
+  ) -> Result<usize, Error> {
+    diesel::update(post_aggregates::table.filter(post_aggregates::post_id.eq_any(post_ids)))
+      .set((
+        post_aggregates::published.eq(now),
+        post_aggregates::newest_comment_time.eq(now),
+        post_aggregates::newest_comment_time_necro.eq(now),
+      ))
+      .execute(conn)
+      .await
+  }
+
   pub async fn permadelete_for_creator(
     pool: &mut DbPool<'_>,
     for_creator_id: PersonId,
   ) -> Result<Vec<Self>, Error> {
     let conn = &mut get_conn(pool).await?;
 
+    // Earlier versions of the posts need to be removed as well
+    diesel::delete(
+      post_revision::table.filter(
+        post_revision::post_id.eq_any(
+          post
+            .filter(creator_id.eq(for_creator_id))
+            .select(crate::schema::post::id),
+        ),
+      ),
+    )
+    .execute(conn)
+    .await?;
+
     diesel::update(post.filter(creator_id.eq(for_creator_id)))
       .set((
         name.eq(DELETED_REPLACEMENT_TEXT),
@@ -341,6 +478,50 @@ impl PostRead {
   }
 }
 
+impl PostRevision {
+  /// Stores the previous version of an edited post, if the edit changed its content. Edits of
+  /// scheduled posts are not stored, as nobody else could see the earlier versions.
+  pub async fn create_for_edit(
+    pool: &mut DbPool<'_>,
+    orig_post: &Post,
+    updated_post: &Post,
+  ) -> Result<Option<Self>, Error> {
+    let unchanged = orig_post.name == updated_post.name
+      && orig_post.url == updated_post.url
+      && orig_post.body == updated_post.body;
+    if unchanged || orig_post.scheduled_publish_time.is_some() {
+      return Ok(None);
+    }
+
+    let conn = &mut get_conn(pool).await?;
+    let form = PostRevisionForm {
+      post_id: orig_post.id,
+      name: orig_post.name.clone(),
+      url: orig_post.url.clone(),
+      body: orig_post.body.clone(),
+      published: orig_post.updated.unwrap_or(orig_post.published),
+    };
+    insert_into(post_revision::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+      .map(Some)
+  }
+
+  /// Lists the previous versions of a post, oldest first.
+  pub async fn list_for_post(
+    pool: &mut DbPool<'_>,
+    for_post_id: PostId,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    post_revision::table
+      .filter(post_revision::post_id.eq(for_post_id))
+      .order_by((post_revision::edited, post_revision::id))
+      .load::<Self>(conn)
+      .await
+  }
+}
+
 #[cfg(test)]
 mod tests {
   #![allow(clippy::unwrap_used)]
@@ -357,6 +538,7 @@ mod tests {
         PostLike,
         PostLikeForm,
         PostRead,
+        PostRevision,
         PostSaved,
         PostSavedForm,
         PostUpdateForm,
@@ -410,6 +592,13 @@ mod tests {
       .build();
     let inserted_post2 = Post::create(pool, &new_post2).await.unwrap();
 
+    // Both posts are in the same community, which is only listed once
+    let communities =
+      Post::list_communities(pool, &HashSet::from([inserted_post.id, inserted_post2.id]))
+        .await
+        .unwrap();
+    assert_eq!(vec![inserted_community.clone()], communities);
+
     let expected_post = Post {
       id: inserted_post.id,
       name: "A test post".into(),
@@ -432,6 +621,7 @@ mod tests {
       language_id: Default::default(),
       featured_community: false,
       featured_local: false,
+      scheduled_publish_time: None,
     };
 
     // Post Like
@@ -516,4 +706,143 @@ mod tests {
     assert_eq!(expected_post_like, inserted_post_like);
     assert_eq!(expected_post_saved, inserted_post_saved);
   }
+
+  #[tokio::test]
+  #[serial]
+  async fn test_publish_scheduled() {
+    let pool = &build_db_pool_for_tests().await;
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld".to_string())
+      .await
+      .unwrap();
+
+    let new_person = PersonInsertForm::builder()
+      .name("scheduler".into())
+      .public_key("pubkey".to_string())
+      .instance_id(inserted_instance.id)
+      .build();
+    let inserted_person = Person::create(pool, &new_person).await.unwrap();
+
+    let new_community = CommunityInsertForm::builder()
+      .name("test community_scheduled".to_string())
+      .title("nada".to_owned())
+      .public_key("pubkey".to_string())
+      .instance_id(inserted_instance.id)
+      .build();
+    let inserted_community = Community::create(pool, &new_community).await.unwrap();
+
+    let mut post_ids = vec![];
+    for time in ["2020-01-01T00:00:00Z", "2099-01-01T00:00:00Z"] {
+      let new_post = PostInsertForm::builder()
+        .name("A scheduled post".into())
+        .creator_id(inserted_person.id)
+        .community_id(inserted_community.id)
+        .scheduled_publish_time(Some(time.parse().unwrap()))
+        .build();
+      post_ids.push(Post::create(pool, &new_post).await.unwrap().id);
+    }
+
+    // Only the post whose time was reached gets published
+    let published_posts = Post::publish_scheduled(pool).await.unwrap();
+    assert_eq!(
+      vec![post_ids[0]],
+      published_posts.iter().map(|p| p.id).collect::<Vec<_>>()
+    );
+    assert!(published_posts[0].scheduled_publish_time.is_none());
+    let future_post = Post::read(pool, post_ids[1]).await.unwrap();
+    assert!(future_post.scheduled_publish_time.is_some());
+
+    // The other one can be published early, but only once
+    let published_post = Post::publish_now(pool, post_ids[1]).await.unwrap();
+    assert!(published_post.scheduled_publish_time.is_none());
+    assert!(published_post.published > future_post.published);
+    assert!(Post::publish_now(pool, post_ids[1]).await.is_err());
+
+    Community::delete(pool, inserted_community.id)
+      .await
+      .unwrap();
+    Person::delete(pool, inserted_person.id).await.unwrap();
+    Instance::delete(pool, inserted_instance.id).await.unwrap();
+  }
+
+  #[tokio::test]
+  #[serial]
+  async fn test_revisions() {
+    let pool = &build_db_pool_for_tests().await;
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld".to_string())
+      .await
+      .unwrap();
+
+    let new_person = PersonInsertForm::builder()
+      .name("reviser".into())
+      .public_key("pubkey".to_string())
+      .instance_id(inserted_instance.id)
+      .build();
+    let inserted_person = Person::create(pool, &new_person).await.unwrap();
+
+    let new_community = CommunityInsertForm::builder()
+      .name("test community_revisions".to_string())
+      .title("nada".to_owned())
+      .public_key("pubkey".to_string())
+      .instance_id(inserted_instance.id)
+      .build();
+    let inserted_community = Community::create(pool, &new_community).await.unwrap();
+
+    let new_post = PostInsertForm::builder()
+      .name("A post".into())
+      .body(Some("first version".into()))
+      .creator_id(inserted_person.id)
+      .community_id(inserted_community.id)
+      .build();
+    let inserted_post = Post::create(pool, &new_post).await.unwrap();
+
+    // Edits which don't change the content are not stored
+    let nsfw_form = PostUpdateForm {
+      nsfw: Some(true),
+      ..Default::default()
+    };
+    let nsfw_post = Post::update(pool, inserted_post.id, &nsfw_form)
+      .await
+      .unwrap();
+    let revision = PostRevision::create_for_edit(pool, &inserted_post, &nsfw_post)
+      .await
+      .unwrap();
+    assert!(revision.is_none());
+
+    let body_form = PostUpdateForm {
+      body: Some(Some("second version".into())),
+      ..Default::default()
+    };
+    let edited_post = Post::update(pool, inserted_post.id, &body_form)
+      .await
+      .unwrap();
+    PostRevision::create_for_edit(pool, &nsfw_post, &edited_post)
+      .await
+      .unwrap();
+    let revisions = PostRevision::list_for_post(pool, inserted_post.id)
+      .await
+      .unwrap();
+    assert_eq!(1, revisions.len());
+    assert_eq!(Some("first version".to_string()), revisions[0].body);
+    assert_eq!(inserted_post.published, revisions[0].published);
+
+    // Earlier versions are removed together with the content of the creator
+    Post::permadelete_for_creator(pool, inserted_person.id)
+      .await
+      .unwrap();
+    let revisions = PostRevision::list_for_post(pool, inserted_post.id)
+      .await
+      .unwrap();
+    assert!(revisions.is_empty());
+
+    Post::delete(pool, inserted_post.id).await.unwrap();
+    Community::delete(pool, inserted_community.id)
+      .await
+      .unwrap();
+    Person::delete(pool, inserted_person.id).await.unwrap();
+    Instance::delete(pool, inserted_instance.id).await.unwrap();
+  }
 }
@@ -1,6 +1,14 @@
 use crate::{
-  newtypes::{PersonId, PostReportId},
-  schema::post_report::dsl::{post_report, resolved, resolver_id, updated},
+  newtypes::{DbUrl, PersonId, PostId, PostReportId},
+  schema::post_report::dsl::{
+    ap_id,
+    post_id,
+    post_report,
+    resolution_reason,
+    resolved,
+    resolver_id,
+    updated,
+  },
   source::post_report::{PostReport, PostReportForm},
   traits::Reportable,
   utils::{get_conn, naive_now, DbPool},
@@ -9,9 +17,11 @@ use diesel::{
   dsl::{insert_into, update},
   result::Error,
   ExpressionMethods,
+  OptionalExtension,
   QueryDsl,
 };
 use diesel_async::RunQueryDsl;
+use url::Url;
 
 #[async_trait]
 impl Reportable for PostReport {
@@ -30,12 +40,14 @@ impl Reportable for PostReport {
     pool: &mut DbPool<'_>,
     report_id: Self::IdType,
     by_resolver_id: PersonId,
+    reason: Option<String>,
   ) -> Result<usize, Error> {
     let conn = &mut get_conn(pool).await?;
     update(post_report.find(report_id))
       .set((
         resolved.eq(true),
         resolver_id.eq(by_resolver_id),
+        resolution_reason.eq(reason),
         updated.eq(naive_now()),
       ))
       .execute(conn)
@@ -52,6 +64,7 @@ impl Reportable for PostReport {
       .set((
         resolved.eq(false),
         resolver_id.eq(by_resolver_id),
+        resolution_reason.eq(None::<String>),
         updated.eq(naive_now()),
       ))
       .execute(conn)
@@ -59,6 +72,67 @@ impl Reportable for PostReport {
   }
 }
 
+impl PostReport {
+  /// Finds the report which was federated with the given activity id.
+  pub async fn read_from_apub_id(
+    pool: &mut DbPool<'_>,
+    object_id: Url,
+  ) -> Result<Option<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let object_id: DbUrl = object_id.into();
+    post_report
+      .filter(ap_id.eq(object_id))
+      .first::<Self>(conn)
+      .await
+      .optional()
+  }
+
+  /// Resolves all unresolved reports about the post, and returns them.
+  pub async fn resolve_all_for_object(
+    pool: &mut DbPool<'_>,
+    for_post_id: PostId,
+    by_resolver_id: PersonId,
+    reason: Option<String>,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    update(
+      post_report
+        .filter(post_id.eq(for_post_id))
+        .filter(resolved.eq(false)),
+    )
+    .set((
+      resolved.eq(true),
+      resolver_id.eq(by_resolver_id),
+      resolution_reason.eq(reason),
+      updated.eq(naive_now()),
+    ))
+    .get_results::<Self>(conn)
+    .await
+  }
+
+  /// Unresolves all resolved reports about the post, and returns them.
+  pub async fn unresolve_all_for_object(
+    pool: &mut DbPool<'_>,
+    for_post_id: PostId,
+    by_resolver_id: PersonId,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    update(
+      post_report
+        .filter(post_id.eq(for_post_id))
+        .filter(resolved.eq(true)),
+    )
+    .set((
+      resolved.eq(false),
+      resolver_id.eq(by_resolver_id),
+      resolution_reason.eq(None::<String>),
+      updated.eq(naive_now()),
+    ))
+    .get_results::<Self>(conn)
+    .await
+  }
+}
+
 #[cfg(test)]
 mod tests {
   #![allow(clippy::unwrap_used)]
@@ -121,7 +195,7 @@ mod tests {
 
     let (person, report) = init(pool).await;
 
-    let resolved_count = PostReport::resolve(pool, report.id, person.id)
+    let resolved_count = PostReport::resolve(pool, report.id, person.id, Some("spam".to_string()))
       .await
       .unwrap();
     assert_eq!(resolved_count, 1);
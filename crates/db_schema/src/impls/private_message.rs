@@ -80,6 +80,7 @@ mod tests {
 
   use crate::{
     source::{
+      conversation::{Conversation, ConversationInsertForm},
       instance::Instance,
       person::{Person, PersonInsertForm},
       private_message::{PrivateMessage, PrivateMessageInsertForm, PrivateMessageUpdateForm},
@@ -115,10 +116,15 @@ mod tests {
 
     let inserted_recipient = Person::create(pool, &recipient_form).await.unwrap();
 
+    let conversation = Conversation::create(pool, &ConversationInsertForm::builder().build())
+      .await
+      .unwrap();
+
     let private_message_form = PrivateMessageInsertForm::builder()
       .content("A test private message".into())
       .creator_id(inserted_creator.id)
-      .recipient_id(inserted_recipient.id)
+      .recipient_id(Some(inserted_recipient.id))
+      .conversation_id(conversation.id)
       .build();
 
     let inserted_private_message = PrivateMessage::create(pool, &private_message_form)
@@ -129,13 +135,16 @@ mod tests {
       id: inserted_private_message.id,
       content: "A test private message".into(),
       creator_id: inserted_creator.id,
-      recipient_id: inserted_recipient.id,
+      recipient_id: Some(inserted_recipient.id),
       deleted: false,
       read: false,
       updated: None,
       published: inserted_private_message.published,
       ap_id: inserted_private_message.ap_id.clone(),
       local: true,
+      conversation_id: conversation.id,
+      encrypted: false,
+      encryption_metadata: None,
     };
 
     let read_private_message = PrivateMessage::read(pool, inserted_private_message.id)
@@ -176,6 +185,7 @@ mod tests {
     .unwrap();
     Person::delete(pool, inserted_creator.id).await.unwrap();
     Person::delete(pool, inserted_recipient.id).await.unwrap();
+    Conversation::delete(pool, conversation.id).await.unwrap();
     Instance::delete(pool, inserted_instance.id).await.unwrap();
 
     assert_eq!(expected_private_message, read_private_message);
@@ -1,6 +1,13 @@
 use crate::{
-  newtypes::{PersonId, PrivateMessageReportId},
-  schema::private_message_report::dsl::{private_message_report, resolved, resolver_id, updated},
+  newtypes::{PersonId, PrivateMessageId, PrivateMessageReportId},
+  schema::private_message_report::dsl::{
+    private_message_id,
+    private_message_report,
+    resolution_reason,
+    resolved,
+    resolver_id,
+    updated,
+  },
   source::private_message_report::{PrivateMessageReport, PrivateMessageReportForm},
   traits::Reportable,
   utils::{get_conn, naive_now, DbPool},
@@ -33,12 +40,14 @@ impl Reportable for PrivateMessageReport {
     pool: &mut DbPool<'_>,
     report_id: Self::IdType,
     by_resolver_id: PersonId,
+    reason: Option<String>,
   ) -> Result<usize, Error> {
     let conn = &mut get_conn(pool).await?;
     update(private_message_report.find(report_id))
       .set((
         resolved.eq(true),
         resolver_id.eq(by_resolver_id),
+        resolution_reason.eq(reason),
         updated.eq(naive_now()),
       ))
       .execute(conn)
@@ -55,9 +64,57 @@ impl Reportable for PrivateMessageReport {
       .set((
         resolved.eq(false),
         resolver_id.eq(by_resolver_id),
+        resolution_reason.eq(None::<String>),
         updated.eq(naive_now()),
       ))
       .execute(conn)
       .await
   }
 }
+
+impl PrivateMessageReport {
+  /// Resolves all unresolved reports about the private message, and returns them.
+  pub async fn resolve_all_for_object(
+    pool: &mut DbPool<'_>,
+    for_private_message_id: PrivateMessageId,
+    by_resolver_id: PersonId,
+    reason: Option<String>,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    update(
+      private_message_report
+        .filter(private_message_id.eq(for_private_message_id))
+        .filter(resolved.eq(false)),
+    )
+    .set((
+      resolved.eq(true),
+      resolver_id.eq(by_resolver_id),
+      resolution_reason.eq(reason),
+      updated.eq(naive_now()),
+    ))
+    .get_results::<Self>(conn)
+    .await
+  }
+
+  /// Unresolves all resolved reports about the private message, and returns them.
+  pub async fn unresolve_all_for_object(
+    pool: &mut DbPool<'_>,
+    for_private_message_id: PrivateMessageId,
+    by_resolver_id: PersonId,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    update(
+      private_message_report
+        .filter(private_message_id.eq(for_private_message_id))
+        .filter(resolved.eq(true)),
+    )
+    .set((
+      resolved.eq(false),
+      resolver_id.eq(by_resolver_id),
+      resolution_reason.eq(None::<String>),
+      updated.eq(naive_now()),
+    ))
+    .get_results::<Self>(conn)
+    .await
+  }
+}
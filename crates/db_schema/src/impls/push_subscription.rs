@@ -0,0 +1,247 @@
+use crate::{
+  newtypes::{LocalUserId, PersonId, PushSubscriptionId},
+  schema::{local_user, push_notification, push_subscription},
+  source::push_subscription::{
+    PushNotification,
+    PushNotificationForm,
+    PushSubscription,
+    PushSubscriptionInsertForm,
+    PushSubscriptionUpdateForm,
+  },
+  traits::Crud,
+  utils::{get_conn, DbPool},
+};
+use chrono::{DateTime, Utc};
+use diesel::{dsl::insert_into, result::Error, ExpressionMethods, QueryDsl};
+use diesel_async::RunQueryDsl;
+
+#[async_trait]
+impl Crud for PushSubscription {
+  type InsertForm = PushSubscriptionInsertForm;
+  type UpdateForm = PushSubscriptionUpdateForm;
+  type IdType = PushSubscriptionId;
+
+  /// Browsers keep the endpoint when subscribing again, so an existing subscription with the same
+  /// endpoint is replaced.
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(push_subscription::table)
+      .values(form)
+      .on_conflict(push_subscription::endpoint)
+      .do_update()
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    push_subscription_id: PushSubscriptionId,
+    form: &Self::UpdateForm,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(push_subscription::table.find(push_subscription_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+}
+
+impl PushSubscription {
+  pub async fn list_for_local_user(
+    pool: &mut DbPool<'_>,
+    for_local_user_id: LocalUserId,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    push_subscription::table
+      .filter(push_subscription::local_user_id.eq(for_local_user_id))
+      .order_by(push_subscription::id)
+      .load::<Self>(conn)
+      .await
+  }
+
+  /// The subscriptions of a local person, which is useful as notifications are addressed to
+  /// persons.
+  pub async fn list_for_person(
+    pool: &mut DbPool<'_>,
+    for_person_id: PersonId,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    push_subscription::table
+      .inner_join(local_user::table)
+      .filter(local_user::person_id.eq(for_person_id))
+      .select(push_subscription::all_columns)
+      .order_by(push_subscription::id)
+      .load::<Self>(conn)
+      .await
+  }
+}
+
+impl PushNotification {
+  pub async fn create(
+    pool: &mut DbPool<'_>,
+    forms: &[PushNotificationForm],
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(push_notification::table)
+      .values(forms)
+      .execute(conn)
+      .await
+  }
+
+  /// The notifications which are due for delivery together with their subscription, oldest
+  /// first.
+  pub async fn list_due(
+    pool: &mut DbPool<'_>,
+    limit: i64,
+  ) -> Result<Vec<(Self, PushSubscription)>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    push_notification::table
+      .inner_join(push_subscription::table)
+      .filter(push_notification::next_attempt.le(Utc::now()))
+      .order_by(push_notification::id)
+      .limit(limit)
+      .load::<(Self, PushSubscription)>(conn)
+      .await
+  }
+
+  pub async fn delete(pool: &mut DbPool<'_>, id: i64) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::delete(push_notification::table.find(id))
+      .execute(conn)
+      .await
+  }
+
+  /// Stores a failed delivery attempt, and when to try again.
+  pub async fn retry_later(
+    pool: &mut DbPool<'_>,
+    id: i64,
+    fail_count: i32,
+    next_attempt: DateTime<Utc>,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(push_notification::table.find(id))
+      .set((
+        push_notification::fail_count.eq(fail_count),
+        push_notification::next_attempt.eq(next_attempt),
+      ))
+      .execute(conn)
+      .await
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  #![allow(clippy::unwrap_used)]
+  #![allow(clippy::indexing_slicing)]
+
+  use crate::{
+    source::{
+      instance::Instance,
+      local_user::{LocalUser, LocalUserInsertForm},
+      person::{Person, PersonInsertForm},
+      push_subscription::{
+        PushNotification,
+        PushNotificationForm,
+        PushSubscription,
+        PushSubscriptionInsertForm,
+      },
+    },
+    traits::Crud,
+    utils::build_db_pool_for_tests,
+  };
+  use chrono::{Duration, Utc};
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_push_subscriptions() {
+    let pool = &build_db_pool_for_tests().await;
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld".to_string())
+      .await
+      .unwrap();
+
+    let mut local_users = vec![];
+    for name in ["push_alice", "push_bob"] {
+      let new_person = PersonInsertForm::builder()
+        .name(name.into())
+        .public_key("pubkey".to_string())
+        .instance_id(inserted_instance.id)
+        .build();
+      let person = Person::create(pool, &new_person).await.unwrap();
+      let local_user_form = LocalUserInsertForm::builder()
+        .person_id(person.id)
+        .password_encrypted("pass".to_string())
+        .build();
+      local_users.push(LocalUser::create(pool, &local_user_form).await.unwrap());
+    }
+    let (alice, bob) = (&local_users[0], &local_users[1]);
+
+    let form = PushSubscriptionInsertForm {
+      local_user_id: alice.id,
+      endpoint: "https://push.example.com/abc".into(),
+      p256dh: "key".into(),
+      auth: "auth".into(),
+      notify_replies: None,
+      notify_mentions: Some(false),
+      notify_private_messages: None,
+      notify_reports: None,
+    };
+    let subscription = PushSubscription::create(pool, &form).await.unwrap();
+    assert!(subscription.notify_replies);
+    assert!(!subscription.notify_mentions);
+    assert_eq!(
+      vec![subscription.clone()],
+      PushSubscription::list_for_person(pool, alice.person_id)
+        .await
+        .unwrap()
+    );
+
+    // Subscribing again with the same endpoint replaces the subscription
+    let form = PushSubscriptionInsertForm {
+      local_user_id: bob.id,
+      p256dh: "new_key".into(),
+      ..form
+    };
+    let replaced = PushSubscription::create(pool, &form).await.unwrap();
+    assert_eq!(subscription.id, replaced.id);
+    assert_eq!(bob.id, replaced.local_user_id);
+    assert_eq!("new_key", replaced.p256dh);
+    assert!(PushSubscription::list_for_local_user(pool, alice.id)
+      .await
+      .unwrap()
+      .is_empty());
+
+    let notification_form = PushNotificationForm {
+      subscription_id: replaced.id,
+      payload: "{}".into(),
+    };
+    PushNotification::create(pool, &[notification_form.clone(), notification_form])
+      .await
+      .unwrap();
+    let due = PushNotification::list_due(pool, 10).await.unwrap();
+    assert_eq!(2, due.len());
+    assert_eq!(replaced, due[0].1);
+
+    // Notifications which are retried later aren't due
+    let (first, _) = &due[0];
+    PushNotification::retry_later(pool, first.id, 1, Utc::now() + Duration::minutes(1))
+      .await
+      .unwrap();
+    let (second, _) = &due[1];
+    PushNotification::delete(pool, second.id).await.unwrap();
+    assert!(PushNotification::list_due(pool, 10)
+      .await
+      .unwrap()
+      .is_empty());
+
+    // Deleting the subscription also deletes its queue
+    PushSubscription::delete(pool, replaced.id).await.unwrap();
+    for local_user in &local_users {
+      Person::delete(pool, local_user.person_id).await.unwrap();
+    }
+    Instance::delete(pool, inserted_instance.id).await.unwrap();
+  }
+}
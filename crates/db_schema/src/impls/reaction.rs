@@ -0,0 +1,237 @@
+use crate::{
+  newtypes::{CommentId, PersonId, PostId, ReactionId},
+  schema::{comment, post, reaction},
+  source::reaction::{Reaction, ReactionCounts, ReactionForm, ReactionUpdateForm},
+  traits::Crud,
+  utils::{get_conn, DbPool},
+};
+use diesel::{
+  deserialize::FromSql,
+  dsl::insert_into,
+  pg::{Pg, PgValue},
+  result::Error,
+  sql_types::Jsonb,
+  BoolExpressionMethods,
+  ExpressionMethods,
+  QueryDsl,
+};
+use diesel_async::RunQueryDsl;
+
+#[async_trait]
+impl Crud for Reaction {
+  type InsertForm = ReactionForm;
+  type UpdateForm = ReactionUpdateForm;
+  type IdType = ReactionId;
+
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(reaction::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    reaction_id: ReactionId,
+    form: &Self::UpdateForm,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(reaction::table.find(reaction_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+}
+
+impl Reaction {
+  /// Adds a reaction, or does nothing if the person already reacted with the same emoji.
+  pub async fn react(pool: &mut DbPool<'_>, form: &ReactionForm) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(reaction::table)
+      .values(form)
+      .on_conflict_do_nothing()
+      .execute(conn)
+      .await
+  }
+
+  /// Removes a reaction from a post, or from a comment if `comment_id` is set.
+  pub async fn remove(
+    pool: &mut DbPool<'_>,
+    for_person_id: PersonId,
+    for_post_id: PostId,
+    for_comment_id: Option<CommentId>,
+    emoji: &str,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let query = diesel::delete(reaction::table)
+      .filter(reaction::person_id.eq(for_person_id))
+      .filter(reaction::post_id.eq(for_post_id))
+      .filter(reaction::emoji.eq(emoji))
+      .into_boxed();
+    let query = match for_comment_id {
+      Some(comment_id) => query.filter(reaction::comment_id.eq(comment_id)),
+      None => query.filter(reaction::comment_id.is_null()),
+    };
+    query.execute(conn).await
+  }
+
+  /// Marks all reactions by others on the posts and comments of a person as read.
+  pub async fn mark_all_as_read(
+    pool: &mut DbPool<'_>,
+    for_recipient_id: PersonId,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let post_reactions = reaction::table
+      .inner_join(post::table)
+      .filter(reaction::comment_id.is_null())
+      .filter(post::creator_id.eq(for_recipient_id))
+      .select(reaction::id);
+    let comment_reactions = reaction::table
+      .inner_join(comment::table)
+      .filter(comment::creator_id.eq(for_recipient_id))
+      .select(reaction::id);
+    diesel::update(
+      reaction::table
+        .filter(reaction::read.eq(false))
+        .filter(reaction::person_id.ne(for_recipient_id))
+        .filter(
+          reaction::id
+            .eq_any(post_reactions)
+            .or(reaction::id.eq_any(comment_reactions)),
+        ),
+    )
+    .set(reaction::read.eq(true))
+    .execute(conn)
+    .await
+  }
+}
+
+impl FromSql<Jsonb, Pg> for ReactionCounts {
+  fn from_sql(bytes: PgValue<'_>) -> diesel::deserialize::Result<Self> {
+    let value = <serde_json::Value as FromSql<Jsonb, Pg>>::from_sql(bytes)?;
+    Ok(ReactionCounts(serde_json::from_value(value)?))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  #![allow(clippy::unwrap_used)]
+  #![allow(clippy::indexing_slicing)]
+
+  use crate::{
+    aggregates::structs::{CommentAggregates, PostAggregates},
+    source::{
+      comment::{Comment, CommentInsertForm},
+      community::{Community, CommunityInsertForm},
+      instance::Instance,
+      person::{Person, PersonInsertForm},
+      post::{Post, PostInsertForm},
+      reaction::{Reaction, ReactionForm},
+    },
+    traits::Crud,
+    utils::build_db_pool_for_tests,
+  };
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_reactions() {
+    let pool = &build_db_pool_for_tests().await;
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld".to_string())
+      .await
+      .unwrap();
+
+    let new_person = PersonInsertForm::builder()
+      .name("reaction_author".into())
+      .public_key("pubkey".to_string())
+      .instance_id(inserted_instance.id)
+      .build();
+    let author = Person::create(pool, &new_person).await.unwrap();
+
+    let new_person = PersonInsertForm::builder()
+      .name("reaction_reactor".into())
+      .public_key("pubkey".to_string())
+      .instance_id(inserted_instance.id)
+      .build();
+    let reactor = Person::create(pool, &new_person).await.unwrap();
+
+    let new_community = CommunityInsertForm::builder()
+      .name("test_community_reactions".to_string())
+      .title("nada".to_owned())
+      .public_key("pubkey".to_string())
+      .instance_id(inserted_instance.id)
+      .build();
+    let inserted_community = Community::create(pool, &new_community).await.unwrap();
+
+    let new_post = PostInsertForm::builder()
+      .name("A test post".into())
+      .creator_id(author.id)
+      .community_id(inserted_community.id)
+      .build();
+    let inserted_post = Post::create(pool, &new_post).await.unwrap();
+
+    let comment_form = CommentInsertForm::builder()
+      .content("A test comment".into())
+      .creator_id(author.id)
+      .post_id(inserted_post.id)
+      .build();
+    let inserted_comment = Comment::create(pool, &comment_form, None).await.unwrap();
+
+    let post_form = |emoji: &str| ReactionForm {
+      person_id: reactor.id,
+      post_id: inserted_post.id,
+      comment_id: None,
+      emoji: emoji.to_string(),
+      custom_emoji_id: None,
+    };
+    assert_eq!(1, Reaction::react(pool, &post_form("👍")).await.unwrap());
+    assert_eq!(1, Reaction::react(pool, &post_form("🎉")).await.unwrap());
+    // Reacting twice with the same emoji has no effect
+    assert_eq!(0, Reaction::react(pool, &post_form("👍")).await.unwrap());
+
+    // Reactions on a comment are counted separately from the post
+    let comment_form = ReactionForm {
+      comment_id: Some(inserted_comment.id),
+      ..post_form("👍")
+    };
+    assert_eq!(1, Reaction::react(pool, &comment_form).await.unwrap());
+
+    let post_agg = PostAggregates::read(pool, inserted_post.id).await.unwrap();
+    assert_eq!(2, post_agg.reaction_counts.len());
+    assert_eq!(Some(&1), post_agg.reaction_counts.get("👍"));
+    let comment_agg = CommentAggregates::read(pool, inserted_comment.id)
+      .await
+      .unwrap();
+    assert_eq!(Some(&1), comment_agg.reaction_counts.get("👍"));
+
+    assert_eq!(
+      3,
+      Reaction::mark_all_as_read(pool, author.id).await.unwrap()
+    );
+    assert_eq!(
+      0,
+      Reaction::mark_all_as_read(pool, reactor.id).await.unwrap()
+    );
+
+    let removed = Reaction::remove(pool, reactor.id, inserted_post.id, None, "👍")
+      .await
+      .unwrap();
+    assert_eq!(1, removed);
+    let post_agg = PostAggregates::read(pool, inserted_post.id).await.unwrap();
+    assert_eq!(None, post_agg.reaction_counts.get("👍"));
+    let comment_agg = CommentAggregates::read(pool, inserted_comment.id)
+      .await
+      .unwrap();
+    assert_eq!(Some(&1), comment_agg.reaction_counts.get("👍"));
+
+    Community::delete(pool, inserted_community.id)
+      .await
+      .unwrap();
+    Person::delete(pool, author.id).await.unwrap();
+    Person::delete(pool, reactor.id).await.unwrap();
+    Instance::delete(pool, inserted_instance.id).await.unwrap();
+  }
+}
@@ -0,0 +1,192 @@
+use crate::{
+  newtypes::ReportCombinedId,
+  schema::report_combined,
+  source::report_combined::{ReportCombined, ReportCombinedUpdateForm},
+  utils::{get_conn, DbPool},
+};
+use diesel::{result::Error, QueryDsl};
+use diesel_async::RunQueryDsl;
+
+impl ReportCombined {
+  pub async fn read(
+    pool: &mut DbPool<'_>,
+    report_combined_id: ReportCombinedId,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    report_combined::table
+      .find(report_combined_id)
+      .first::<Self>(conn)
+      .await
+  }
+
+  /// Entries are created by the database when something is reported, so only the assignee can be
+  /// changed.
+  pub async fn update(
+    pool: &mut DbPool<'_>,
+    report_combined_id: ReportCombinedId,
+    form: &ReportCombinedUpdateForm,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(report_combined::table.find(report_combined_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  #![allow(clippy::unwrap_used)]
+  #![allow(clippy::indexing_slicing)]
+
+  use crate::{
+    newtypes::ReportCombinedId,
+    schema::{post_report, report_combined},
+    source::{
+      community::{Community, CommunityInsertForm},
+      instance::Instance,
+      person::{Person, PersonInsertForm},
+      post::{Post, PostInsertForm},
+      post_report::{PostReport, PostReportForm},
+      report_combined::{ReportCombined, ReportCombinedUpdateForm},
+      report_note::{ReportNote, ReportNoteInsertForm},
+    },
+    traits::{Crud, Reportable},
+    utils::{build_db_pool_for_tests, get_conn, naive_now},
+  };
+  use diesel::{ExpressionMethods, QueryDsl};
+  use diesel_async::RunQueryDsl;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_grouped_reports() {
+    let pool = &build_db_pool_for_tests().await;
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld".to_string())
+      .await
+      .unwrap();
+
+    let mut persons = vec![];
+    for name in [
+      "report_queue_creator",
+      "report_queue_sara",
+      "report_queue_jessica",
+    ] {
+      let form = PersonInsertForm::builder()
+        .name(name.into())
+        .public_key("pubkey".to_string())
+        .instance_id(inserted_instance.id)
+        .build();
+      persons.push(Person::create(pool, &form).await.unwrap());
+    }
+
+    let community_form = CommunityInsertForm::builder()
+      .name("report_queue_community".to_string())
+      .title("nada".to_owned())
+      .public_key("pubkey".to_string())
+      .instance_id(inserted_instance.id)
+      .build();
+    let community = Community::create(pool, &community_form).await.unwrap();
+
+    let post_form = PostInsertForm::builder()
+      .name("A reported post".into())
+      .creator_id(persons[0].id)
+      .community_id(community.id)
+      .build();
+    let post = Post::create(pool, &post_form).await.unwrap();
+
+    let mut reports = vec![];
+    for reporter in &persons[1..] {
+      let form = PostReportForm {
+        creator_id: reporter.id,
+        post_id: post.id,
+        original_post_name: post.name.clone(),
+        reason: "spam".to_string(),
+        ..Default::default()
+      };
+      reports.push(PostReport::report(pool, &form).await.unwrap());
+    }
+
+    // Both reports are collapsed into one entry, which points to the newest report
+    let entry_id = {
+      let conn = &mut get_conn(pool).await.unwrap();
+      report_combined::table
+        .filter(report_combined::post_id.eq(post.id))
+        .select(report_combined::id)
+        .first::<ReportCombinedId>(conn)
+        .await
+        .unwrap()
+    };
+    let entry = ReportCombined::read(pool, entry_id).await.unwrap();
+    assert_eq!(Some(community.id), entry.community_id);
+    assert_eq!(Some(reports[1].id), entry.post_report_id);
+    assert_eq!(2, entry.report_count);
+    assert_eq!(2, entry.unresolved_report_count);
+
+    let resolved = PostReport::resolve_all_for_object(
+      pool,
+      post.id,
+      persons[1].id,
+      Some("removed the post".to_string()),
+    )
+    .await
+    .unwrap();
+    assert_eq!(2, resolved.len());
+    assert_eq!(
+      Some("removed the post".to_string()),
+      resolved[0].resolution_reason
+    );
+    let entry = ReportCombined::read(pool, entry_id).await.unwrap();
+    assert_eq!(2, entry.report_count);
+    assert_eq!(0, entry.unresolved_report_count);
+
+    let update_form = ReportCombinedUpdateForm {
+      assignee_id: Some(Some(persons[1].id)),
+      assigned: Some(Some(naive_now())),
+    };
+    let entry = ReportCombined::update(pool, entry_id, &update_form)
+      .await
+      .unwrap();
+    assert_eq!(Some(persons[1].id), entry.assignee_id);
+
+    let note_form = ReportNoteInsertForm {
+      report_combined_id: entry_id,
+      creator_id: persons[1].id,
+      content: "already removed".to_string(),
+    };
+    let note = ReportNote::create(pool, &note_form).await.unwrap();
+
+    // Deleting the newest report keeps the entry and its notes
+    {
+      let conn = &mut get_conn(pool).await.unwrap();
+      diesel::delete(post_report::table.find(reports[1].id))
+        .execute(conn)
+        .await
+        .unwrap();
+    }
+    let entry = ReportCombined::read(pool, entry_id).await.unwrap();
+    assert_eq!(Some(reports[0].id), entry.post_report_id);
+    assert_eq!(1, entry.report_count);
+    assert!(ReportNote::read(pool, note.id).await.is_ok());
+
+    // Once the last report is gone, so is the entry
+    {
+      let conn = &mut get_conn(pool).await.unwrap();
+      diesel::delete(post_report::table.find(reports[0].id))
+        .execute(conn)
+        .await
+        .unwrap();
+    }
+    assert!(ReportCombined::read(pool, entry_id).await.is_err());
+    assert!(ReportNote::read(pool, note.id).await.is_err());
+
+    Post::delete(pool, post.id).await.unwrap();
+    Community::delete(pool, community.id).await.unwrap();
+    for person in persons {
+      Person::delete(pool, person.id).await.unwrap();
+    }
+    Instance::delete(pool, inserted_instance.id).await.unwrap();
+  }
+}
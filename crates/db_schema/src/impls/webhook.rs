@@ -0,0 +1,334 @@
+use crate::{
+  newtypes::{CommunityId, WebhookId},
+  schema::{webhook, webhook_delivery, webhook_event},
+  source::webhook::{
+    Webhook,
+    WebhookDelivery,
+    WebhookDeliveryForm,
+    WebhookEvent,
+    WebhookEventForm,
+    WebhookInsertForm,
+    WebhookUpdateForm,
+  },
+  traits::Crud,
+  utils::{get_conn, limit_and_offset, DbPool},
+  WebhookEventType,
+};
+use diesel::{
+  dsl::{count, insert_into},
+  result::Error,
+  BoolExpressionMethods,
+  ExpressionMethods,
+  PgArrayExpressionMethods,
+  QueryDsl,
+};
+use diesel_async::RunQueryDsl;
+
+#[async_trait]
+impl Crud for Webhook {
+  type InsertForm = WebhookInsertForm;
+  type UpdateForm = WebhookUpdateForm;
+  type IdType = WebhookId;
+
+  async fn create(pool: &mut DbPool<'_>, form: &Self::InsertForm) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(webhook::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  async fn update(
+    pool: &mut DbPool<'_>,
+    webhook_id: WebhookId,
+    form: &Self::UpdateForm,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(webhook::table.find(webhook_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+}
+
+impl Webhook {
+  /// Lists the webhooks of a community, or the site webhooks if no community is given.
+  pub async fn list(
+    pool: &mut DbPool<'_>,
+    for_community_id: Option<CommunityId>,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let query = webhook::table.into_boxed();
+    let query = match for_community_id {
+      Some(community_id) => query.filter(webhook::community_id.eq(community_id)),
+      None => query.filter(webhook::community_id.is_null()),
+    };
+    query.order_by(webhook::id).load::<Self>(conn).await
+  }
+
+  pub async fn list_enabled(pool: &mut DbPool<'_>) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    webhook::table
+      .filter(webhook::enabled.eq(true))
+      .order_by(webhook::id)
+      .load::<Self>(conn)
+      .await
+  }
+
+  /// Whether any enabled webhook would receive the given event. Site webhooks receive the events
+  /// of all communities.
+  pub async fn is_subscribed(
+    pool: &mut DbPool<'_>,
+    event_type: WebhookEventType,
+    for_community_id: Option<CommunityId>,
+  ) -> Result<bool, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let query = webhook::table
+      .filter(webhook::enabled.eq(true))
+      .filter(webhook::events.contains(vec![event_type]))
+      .into_boxed();
+    let query = match for_community_id {
+      Some(community_id) => query.filter(
+        webhook::community_id
+          .is_null()
+          .or(webhook::community_id.eq(community_id)),
+      ),
+      None => query.filter(webhook::community_id.is_null()),
+    };
+    let subscribed = query.select(count(webhook::id)).first::<i64>(conn).await?;
+    Ok(subscribed > 0)
+  }
+}
+
+impl WebhookEvent {
+  pub async fn create(pool: &mut DbPool<'_>, form: &WebhookEventForm) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(webhook_event::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  /// Returns the events after the given id which the webhook is subscribed to, oldest first.
+  /// Events from before the webhook was created are skipped.
+  pub async fn list_for_webhook(
+    pool: &mut DbPool<'_>,
+    webhook: &Webhook,
+    after_id: i64,
+    limit: i64,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let mut query = webhook_event::table
+      .filter(webhook_event::id.gt(after_id))
+      .filter(webhook_event::published.ge(webhook.published))
+      .filter(webhook_event::event_type.eq_any(webhook.events.clone()))
+      .into_boxed();
+    if let Some(community_id) = webhook.community_id {
+      query = query.filter(webhook_event::community_id.eq(community_id));
+    }
+    query
+      .order_by(webhook_event::id)
+      .limit(limit)
+      .load::<Self>(conn)
+      .await
+  }
+}
+
+impl WebhookDelivery {
+  pub async fn create(pool: &mut DbPool<'_>, form: &WebhookDeliveryForm) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(webhook_delivery::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  /// Lists the delivery attempts of a webhook, newest first.
+  pub async fn list(
+    pool: &mut DbPool<'_>,
+    for_webhook_id: WebhookId,
+    page: Option<i64>,
+    limit: Option<i64>,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let (limit, offset) = limit_and_offset(page, limit)?;
+    webhook_delivery::table
+      .filter(webhook_delivery::webhook_id.eq(for_webhook_id))
+      .order_by((
+        webhook_delivery::published.desc(),
+        webhook_delivery::id.desc(),
+      ))
+      .limit(limit)
+      .offset(offset)
+      .load::<Self>(conn)
+      .await
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  #![allow(clippy::unwrap_used)]
+  #![allow(clippy::indexing_slicing)]
+
+  use crate::{
+    source::{
+      community::{Community, CommunityInsertForm},
+      instance::Instance,
+      person::{Person, PersonInsertForm},
+      webhook::{
+        Webhook,
+        WebhookDelivery,
+        WebhookDeliveryForm,
+        WebhookEvent,
+        WebhookEventForm,
+        WebhookInsertForm,
+        WebhookUpdateForm,
+      },
+    },
+    traits::Crud,
+    utils::build_db_pool_for_tests,
+    WebhookEventType,
+  };
+  use serde_json::json;
+  use serial_test::serial;
+  use url::Url;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_webhooks() {
+    let pool = &build_db_pool_for_tests().await;
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld".to_string())
+      .await
+      .unwrap();
+
+    let new_person = PersonInsertForm::builder()
+      .name("webhook_mod".into())
+      .public_key("pubkey".to_string())
+      .instance_id(inserted_instance.id)
+      .build();
+    let inserted_person = Person::create(pool, &new_person).await.unwrap();
+
+    let new_community = CommunityInsertForm::builder()
+      .name("test_community_webhooks".to_string())
+      .title("nada".to_owned())
+      .public_key("pubkey".to_string())
+      .instance_id(inserted_instance.id)
+      .build();
+    let community = Community::create(pool, &new_community).await.unwrap();
+
+    let new_community = CommunityInsertForm::builder()
+      .name("test_community_webhooks_2".to_string())
+      .title("nada".to_owned())
+      .public_key("pubkey".to_string())
+      .instance_id(inserted_instance.id)
+      .build();
+    let other_community = Community::create(pool, &new_community).await.unwrap();
+
+    let webhook_form = WebhookInsertForm::builder()
+      .creator_id(inserted_person.id)
+      .community_id(Some(community.id))
+      .url(Url::parse("https://example.com/hook").unwrap().into())
+      .secret("my_secret_value".into())
+      .events(vec![WebhookEventType::PostCreated])
+      .build();
+    let webhook = Webhook::create(pool, &webhook_form).await.unwrap();
+
+    assert!(
+      Webhook::is_subscribed(pool, WebhookEventType::PostCreated, Some(community.id))
+        .await
+        .unwrap()
+    );
+    assert!(
+      !Webhook::is_subscribed(pool, WebhookEventType::CommentCreated, Some(community.id))
+        .await
+        .unwrap()
+    );
+    assert!(!Webhook::is_subscribed(
+      pool,
+      WebhookEventType::PostCreated,
+      Some(other_community.id)
+    )
+    .await
+    .unwrap());
+
+    let webhooks = Webhook::list(pool, Some(community.id)).await.unwrap();
+    assert_eq!(vec![webhook.clone()], webhooks);
+    assert!(Webhook::list(pool, None).await.unwrap().is_empty());
+
+    // Only the events of the community with the subscribed type are delivered
+    let event_form = |event_type, community_id| WebhookEventForm {
+      event_type,
+      community_id,
+      data: json!({ "test": true }),
+    };
+    let post_event = WebhookEvent::create(
+      pool,
+      &event_form(WebhookEventType::PostCreated, Some(community.id)),
+    )
+    .await
+    .unwrap();
+    WebhookEvent::create(
+      pool,
+      &event_form(WebhookEventType::CommentCreated, Some(community.id)),
+    )
+    .await
+    .unwrap();
+    WebhookEvent::create(
+      pool,
+      &event_form(WebhookEventType::PostCreated, Some(other_community.id)),
+    )
+    .await
+    .unwrap();
+    let events = WebhookEvent::list_for_webhook(pool, &webhook, 0, 10)
+      .await
+      .unwrap();
+    assert_eq!(vec![post_event.clone()], events);
+    let events = WebhookEvent::list_for_webhook(pool, &webhook, post_event.id, 10)
+      .await
+      .unwrap();
+    assert!(events.is_empty());
+
+    let delivery_form = WebhookDeliveryForm {
+      webhook_id: webhook.id,
+      event_id: post_event.id,
+      event_type: post_event.event_type,
+      success: false,
+      status_code: Some(500),
+      error: None,
+    };
+    WebhookDelivery::create(pool, &delivery_form).await.unwrap();
+    let delivery_form = WebhookDeliveryForm {
+      success: true,
+      status_code: Some(200),
+      ..delivery_form
+    };
+    WebhookDelivery::create(pool, &delivery_form).await.unwrap();
+    let deliveries = WebhookDelivery::list(pool, webhook.id, None, None)
+      .await
+      .unwrap();
+    assert_eq!(2, deliveries.len());
+    assert!(deliveries[0].success);
+
+    let update_form = WebhookUpdateForm {
+      enabled: Some(false),
+      ..Default::default()
+    };
+    Webhook::update(pool, webhook.id, &update_form)
+      .await
+      .unwrap();
+    assert!(
+      !Webhook::is_subscribed(pool, WebhookEventType::PostCreated, Some(community.id))
+        .await
+        .unwrap()
+    );
+    assert!(Webhook::list_enabled(pool).await.unwrap().is_empty());
+
+    Webhook::delete(pool, webhook.id).await.unwrap();
+    Community::delete(pool, community.id).await.unwrap();
+    Community::delete(pool, other_community.id).await.unwrap();
+    Person::delete(pool, inserted_person.id).await.unwrap();
+    Instance::delete(pool, inserted_instance.id).await.unwrap();
+  }
+}
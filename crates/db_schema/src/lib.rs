@@ -31,7 +31,11 @@ pub mod schema;
 #[cfg(feature = "full")]
 pub mod aliases {
   use crate::schema::person;
-  diesel::alias!(person as person1: Person1, person as person2: Person2);
+  diesel::alias!(
+    person as person1: Person1,
+    person as person2: Person2,
+    person as person3: Person3
+  );
 }
 pub mod source;
 #[cfg(feature = "full")]
@@ -77,6 +81,9 @@ pub enum SortType {
   TopNineMonths,
   Controversial,
   Scaled,
+  /// Orders search results by how well they match the search term. Behaves like `Hot` when no
+  /// search term is given.
+  Relevance,
 }
 
 #[derive(EnumString, Display, Debug, Serialize, Deserialize, Clone, Copy)]
@@ -89,6 +96,7 @@ pub enum CommentSortType {
   New,
   Old,
   Controversial,
+  Relevance,
 }
 
 #[derive(
@@ -112,6 +120,8 @@ pub enum ListingType {
   Subscribed,
   /// Content that you can moderate (because you are a moderator of the community it is posted to)
   ModeratorView,
+  /// Content created by people you follow.
+  FollowedPeople,
 }
 
 #[derive(EnumString, Display, Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
@@ -150,6 +160,40 @@ pub enum PostListingMode {
   SmallCard,
 }
 
+#[derive(
+  EnumString, Display, Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default,
+)]
+#[cfg_attr(feature = "full", derive(DbEnum, TS))]
+#[cfg_attr(
+  feature = "full",
+  ExistingTypePath = "crate::schema::sql_types::CommunityVisibility"
+)]
+#[cfg_attr(feature = "full", DbValueStyle = "verbatim")]
+#[cfg_attr(feature = "full", ts(export))]
+/// Who can see the content of a community.
+pub enum CommunityVisibility {
+  /// Visible to everyone, and federated.
+  #[default]
+  Public,
+  /// Only visible to logged in users of the local instance, not federated.
+  LocalOnly,
+  /// Only visible to followers which were approved by a moderator. Federated only to instances
+  /// with approved followers.
+  Private,
+}
+
+impl CommunityVisibility {
+  /// Whether activities and objects of the community can be sent to other instances.
+  pub fn can_federate(&self) -> bool {
+    self != &CommunityVisibility::LocalOnly
+  }
+
+  /// Whether the content can be viewed without being an approved follower.
+  pub fn can_view_without_membership(&self) -> bool {
+    self != &CommunityVisibility::Private
+  }
+}
+
 #[derive(EnumString, Display, Debug, Serialize, Deserialize, Clone, Copy)]
 #[cfg_attr(feature = "full", derive(TS))]
 #[cfg_attr(feature = "full", ts(export))]
@@ -190,10 +234,13 @@ pub enum ModlogActionType {
   ModAdd,
   ModBan,
   ModHideCommunity,
+  ModCommunityPostTag,
   AdminPurgePerson,
   AdminPurgeCommunity,
   AdminPurgePost,
   AdminPurgeComment,
+  ModWarning,
+  ModResolveReport,
 }
 
 #[derive(
@@ -209,3 +256,48 @@ pub enum PostFeatureType {
   /// Features to the top of the community.
   Community,
 }
+
+#[derive(
+  EnumString, Display, Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default,
+)]
+#[cfg_attr(feature = "full", derive(DbEnum, TS))]
+#[cfg_attr(
+  feature = "full",
+  ExistingTypePath = "crate::schema::sql_types::EmailDigestFrequency"
+)]
+#[cfg_attr(feature = "full", DbValueStyle = "verbatim")]
+#[cfg_attr(feature = "full", ts(export))]
+/// How often a user receives an email with the top posts of their subscribed communities.
+pub enum EmailDigestFrequency {
+  #[default]
+  Never,
+  Daily,
+  Weekly,
+}
+
+#[derive(EnumString, Display, Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "full", derive(DbEnum, TS))]
+#[cfg_attr(
+  feature = "full",
+  ExistingTypePath = "crate::schema::sql_types::WebhookEventType"
+)]
+#[cfg_attr(feature = "full", DbValueStyle = "verbatim")]
+#[cfg_attr(feature = "full", ts(export))]
+/// The events which can be delivered to a webhook.
+pub enum WebhookEventType {
+  PostCreated,
+  CommentCreated,
+  /// A post, comment or private message was reported.
+  ReportCreated,
+  /// A person was banned or unbanned from the site or a community.
+  PersonBanned,
+  RegistrationApplicationCreated,
+}
+
+impl WebhookEventType {
+  /// Whether the event can be received by the webhooks of a community, and not only by those of
+  /// the site.
+  pub fn for_communities(&self) -> bool {
+    self != &WebhookEventType::RegistrationApplicationCreated
+  }
+}
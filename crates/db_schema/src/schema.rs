@@ -5,6 +5,14 @@ pub mod sql_types {
     #[diesel(postgres_type(name = "actor_type_enum"))]
     pub struct ActorTypeEnum;
 
+    #[derive(diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "community_visibility"))]
+    pub struct CommunityVisibility;
+
+    #[derive(diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "email_digest_frequency"))]
+    pub struct EmailDigestFrequency;
+
     #[derive(diesel::sql_types::SqlType)]
     #[diesel(postgres_type(name = "listing_type_enum"))]
     pub struct ListingTypeEnum;
@@ -24,6 +32,10 @@ pub mod sql_types {
     #[derive(diesel::sql_types::SqlType)]
     #[diesel(postgres_type(name = "sort_type_enum"))]
     pub struct SortTypeEnum;
+
+    #[derive(diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "webhook_event_type"))]
+    pub struct WebhookEventType;
 }
 
 diesel::table! {
@@ -106,6 +118,7 @@ diesel::table! {
         child_count -> Int4,
         hot_rank -> Float8,
         controversy_rank -> Float8,
+        reaction_counts -> Jsonb,
     }
 }
 
@@ -141,6 +154,18 @@ diesel::table! {
         resolver_id -> Nullable<Int4>,
         published -> Timestamptz,
         updated -> Nullable<Timestamptz>,
+        resolution_reason -> Nullable<Text>,
+        ap_id -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    comment_revision (id) {
+        id -> Int4,
+        comment_id -> Int4,
+        content -> Text,
+        published -> Timestamptz,
+        edited -> Timestamptz,
     }
 }
 
@@ -154,6 +179,9 @@ diesel::table! {
 }
 
 diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::CommunityVisibility;
+
     community (id) {
         id -> Int4,
         #[max_length = 255]
@@ -187,6 +215,10 @@ diesel::table! {
         moderators_url -> Nullable<Varchar>,
         #[max_length = 255]
         featured_url -> Nullable<Varchar>,
+        visibility -> CommunityVisibility,
+        warning_ban_threshold -> Nullable<Int4>,
+        warning_ban_window_days -> Int4,
+        warning_ban_days -> Int4,
     }
 }
 
@@ -252,10 +284,77 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    community_post_tag (id) {
+        id -> Int4,
+        #[max_length = 255]
+        ap_id -> Varchar,
+        community_id -> Int4,
+        #[max_length = 255]
+        display_name -> Varchar,
+        deleted -> Bool,
+        published -> Timestamptz,
+        updated -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    community_wiki_page (id) {
+        id -> Int4,
+        #[max_length = 255]
+        ap_id -> Varchar,
+        community_id -> Int4,
+        #[max_length = 100]
+        slug -> Varchar,
+        #[max_length = 200]
+        title -> Varchar,
+        body -> Text,
+        members_can_edit -> Bool,
+        local -> Bool,
+        deleted -> Bool,
+        published -> Timestamptz,
+        updated -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    community_wiki_page_revision (id) {
+        id -> Int4,
+        wiki_page_id -> Int4,
+        editor_id -> Int4,
+        #[max_length = 200]
+        title -> Varchar,
+        body -> Text,
+        published -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    conversation (id) {
+        id -> Int4,
+        #[max_length = 255]
+        ap_id -> Varchar,
+        local -> Bool,
+        published -> Timestamptz,
+        updated -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    conversation_participant (id) {
+        id -> Int4,
+        conversation_id -> Int4,
+        person_id -> Int4,
+        last_read -> Timestamptz,
+        muted -> Bool,
+        published -> Timestamptz,
+    }
+}
+
 diesel::table! {
     custom_emoji (id) {
         id -> Int4,
-        local_site_id -> Int4,
+        local_site_id -> Nullable<Int4>,
         #[max_length = 128]
         shortcode -> Varchar,
         image_url -> Text,
@@ -263,6 +362,10 @@ diesel::table! {
         category -> Text,
         published -> Timestamptz,
         updated -> Nullable<Timestamptz>,
+        community_id -> Nullable<Int4>,
+        #[max_length = 255]
+        ap_id -> Varchar,
+        local -> Bool,
     }
 }
 
@@ -275,6 +378,29 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    draft (id) {
+        id -> Int4,
+        local_user_id -> Int4,
+        community_id -> Nullable<Int4>,
+        name -> Nullable<Text>,
+        url -> Nullable<Text>,
+        body -> Nullable<Text>,
+        nsfw -> Nullable<Bool>,
+        language_id -> Nullable<Int4>,
+        post_id -> Nullable<Int4>,
+        parent_id -> Nullable<Int4>,
+        published -> Timestamptz,
+        updated -> Nullable<Timestamptz>,
+        tags -> Nullable<Array<Int4>>,
+        scheduled_publish_time -> Nullable<Timestamptz>,
+        poll_options -> Nullable<Array<Text>>,
+        poll_multiple_choice -> Nullable<Bool>,
+        poll_end_time -> Nullable<Timestamptz>,
+        poll_hide_results -> Nullable<Bool>,
+    }
+}
+
 diesel::table! {
     email_verification (id) {
         id -> Int4,
@@ -416,6 +542,7 @@ diesel::table! {
     use super::sql_types::SortTypeEnum;
     use super::sql_types::ListingTypeEnum;
     use super::sql_types::PostListingModeEnum;
+    use super::sql_types::EmailDigestFrequency;
 
     local_user (id) {
         id -> Int4,
@@ -445,6 +572,23 @@ diesel::table! {
         totp_2fa_enabled -> Bool,
         enable_keyboard_navigation -> Bool,
         enable_animated_images -> Bool,
+        email_digest -> EmailDigestFrequency,
+        last_email_digest -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    local_user_keyword_filter (id) {
+        id -> Int4,
+        local_user_id -> Int4,
+        #[max_length = 255]
+        keyword -> Varchar,
+        is_regex -> Bool,
+        filter_title -> Bool,
+        filter_body -> Bool,
+        filter_url -> Bool,
+        expires -> Nullable<Timestamptz>,
+        published -> Timestamptz,
     }
 }
 
@@ -513,6 +657,18 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    mod_community_post_tag (id) {
+        id -> Int4,
+        mod_person_id -> Int4,
+        community_post_tag_id -> Int4,
+        #[max_length = 255]
+        display_name -> Varchar,
+        deleted -> Bool,
+        when_ -> Timestamptz,
+    }
+}
+
 diesel::table! {
     mod_feature_post (id) {
         id -> Int4,
@@ -578,6 +734,21 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    mod_resolve_report (id) {
+        id -> Int4,
+        mod_person_id -> Int4,
+        other_person_id -> Int4,
+        community_id -> Int4,
+        post_id -> Int4,
+        comment_id -> Nullable<Int4>,
+        report_count -> Int4,
+        resolved -> Bool,
+        reason -> Nullable<Text>,
+        when_ -> Timestamptz,
+    }
+}
+
 diesel::table! {
     mod_transfer_community (id) {
         id -> Int4,
@@ -588,6 +759,78 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    mod_warning (id) {
+        id -> Int4,
+        mod_person_id -> Int4,
+        other_person_id -> Int4,
+        community_id -> Int4,
+        post_id -> Int4,
+        comment_id -> Nullable<Int4>,
+        reason -> Text,
+        when_ -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    modlog_combined (id) {
+        id -> Int4,
+        published -> Timestamptz,
+        mod_person_id -> Int4,
+        other_person_id -> Nullable<Int4>,
+        community_id -> Nullable<Int4>,
+        post_id -> Nullable<Int4>,
+        comment_id -> Nullable<Int4>,
+        community_post_tag_id -> Nullable<Int4>,
+        mod_remove_post_id -> Nullable<Int4>,
+        mod_lock_post_id -> Nullable<Int4>,
+        mod_feature_post_id -> Nullable<Int4>,
+        mod_remove_comment_id -> Nullable<Int4>,
+        mod_remove_community_id -> Nullable<Int4>,
+        mod_ban_from_community_id -> Nullable<Int4>,
+        mod_ban_id -> Nullable<Int4>,
+        mod_add_community_id -> Nullable<Int4>,
+        mod_transfer_community_id -> Nullable<Int4>,
+        mod_add_id -> Nullable<Int4>,
+        mod_hide_community_id -> Nullable<Int4>,
+        mod_community_post_tag_id -> Nullable<Int4>,
+        admin_purge_person_id -> Nullable<Int4>,
+        admin_purge_community_id -> Nullable<Int4>,
+        admin_purge_post_id -> Nullable<Int4>,
+        admin_purge_comment_id -> Nullable<Int4>,
+        mod_warning_id -> Nullable<Int4>,
+        mod_resolve_report_id -> Nullable<Int4>,
+    }
+}
+
+diesel::table! {
+    multi_community (id) {
+        id -> Int4,
+        creator_id -> Int4,
+        instance_id -> Int4,
+        #[max_length = 255]
+        name -> Varchar,
+        #[max_length = 255]
+        title -> Varchar,
+        description -> Nullable<Text>,
+        local -> Bool,
+        deleted -> Bool,
+        #[max_length = 255]
+        ap_id -> Varchar,
+        published -> Timestamptz,
+        updated -> Nullable<Timestamptz>,
+        last_refreshed_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    multi_community_entry (id) {
+        id -> Int4,
+        multi_community_id -> Int4,
+        community_id -> Int4,
+    }
+}
+
 diesel::table! {
     password_reset_request (id) {
         id -> Int4,
@@ -625,6 +868,7 @@ diesel::table! {
         bot_account -> Bool,
         ban_expires -> Nullable<Timestamptz>,
         instance_id -> Int4,
+        encryption_public_key -> Nullable<Text>,
     }
 }
 
@@ -636,6 +880,8 @@ diesel::table! {
         post_score -> Int8,
         comment_count -> Int8,
         comment_score -> Int8,
+        follower_count -> Int8,
+        following_count -> Int8,
     }
 }
 
@@ -676,6 +922,18 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    person_note (id) {
+        id -> Int4,
+        person_id -> Int4,
+        creator_id -> Int4,
+        community_id -> Nullable<Int4>,
+        content -> Text,
+        published -> Timestamptz,
+        updated -> Nullable<Timestamptz>,
+    }
+}
+
 diesel::table! {
     person_post_aggregates (id) {
         id -> Int4,
@@ -686,6 +944,36 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    poll (post_id) {
+        post_id -> Int4,
+        multiple_choice -> Bool,
+        end_time -> Nullable<Timestamptz>,
+        hide_results -> Bool,
+        voters_count -> Int4,
+    }
+}
+
+diesel::table! {
+    poll_option (id) {
+        id -> Int4,
+        post_id -> Int4,
+        #[max_length = 255]
+        name -> Varchar,
+        position -> Int4,
+        vote_count -> Int4,
+    }
+}
+
+diesel::table! {
+    poll_vote (poll_option_id, person_id) {
+        poll_option_id -> Int4,
+        person_id -> Int4,
+        post_id -> Int4,
+        published -> Timestamptz,
+    }
+}
+
 diesel::table! {
     post (id) {
         id -> Int4,
@@ -712,6 +1000,7 @@ diesel::table! {
         language_id -> Int4,
         featured_community -> Bool,
         featured_local -> Bool,
+        scheduled_publish_time -> Nullable<Timestamptz>,
     }
 }
 
@@ -735,6 +1024,7 @@ diesel::table! {
         controversy_rank -> Float8,
         instance_id -> Int4,
         scaled_rank -> Float8,
+        reaction_counts -> Jsonb,
     }
 }
 
@@ -771,6 +1061,22 @@ diesel::table! {
         resolver_id -> Nullable<Int4>,
         published -> Timestamptz,
         updated -> Nullable<Timestamptz>,
+        resolution_reason -> Nullable<Text>,
+        ap_id -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    post_revision (id) {
+        id -> Int4,
+        post_id -> Int4,
+        #[max_length = 200]
+        name -> Varchar,
+        #[max_length = 512]
+        url -> Nullable<Varchar>,
+        body -> Nullable<Text>,
+        published -> Timestamptz,
+        edited -> Timestamptz,
     }
 }
 
@@ -783,11 +1089,19 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    post_tag (id) {
+        id -> Int4,
+        post_id -> Int4,
+        community_post_tag_id -> Int4,
+    }
+}
+
 diesel::table! {
     private_message (id) {
         id -> Int4,
         creator_id -> Int4,
-        recipient_id -> Int4,
+        recipient_id -> Nullable<Int4>,
         content -> Text,
         deleted -> Bool,
         read -> Bool,
@@ -796,6 +1110,9 @@ diesel::table! {
         #[max_length = 255]
         ap_id -> Varchar,
         local -> Bool,
+        conversation_id -> Int4,
+        encrypted -> Bool,
+        encryption_metadata -> Nullable<Text>,
     }
 }
 
@@ -810,6 +1127,48 @@ diesel::table! {
         resolver_id -> Nullable<Int4>,
         published -> Timestamptz,
         updated -> Nullable<Timestamptz>,
+        resolution_reason -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    push_notification (id) {
+        id -> Int8,
+        subscription_id -> Int4,
+        payload -> Text,
+        fail_count -> Int4,
+        next_attempt -> Timestamptz,
+        published -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    push_subscription (id) {
+        id -> Int4,
+        local_user_id -> Int4,
+        endpoint -> Text,
+        p256dh -> Text,
+        auth -> Text,
+        notify_replies -> Bool,
+        notify_mentions -> Bool,
+        notify_private_messages -> Bool,
+        notify_reports -> Bool,
+        published -> Timestamptz,
+        updated -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    reaction (id) {
+        id -> Int4,
+        person_id -> Int4,
+        post_id -> Int4,
+        comment_id -> Nullable<Int4>,
+        #[max_length = 130]
+        emoji -> Varchar,
+        custom_emoji_id -> Nullable<Int4>,
+        read -> Bool,
+        published -> Timestamptz,
     }
 }
 
@@ -832,10 +1191,41 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    report_combined (id) {
+        id -> Int4,
+        published -> Timestamptz,
+        community_id -> Nullable<Int4>,
+        post_id -> Nullable<Int4>,
+        comment_id -> Nullable<Int4>,
+        private_message_id -> Nullable<Int4>,
+        post_report_id -> Nullable<Int4>,
+        comment_report_id -> Nullable<Int4>,
+        private_message_report_id -> Nullable<Int4>,
+        report_count -> Int4,
+        unresolved_report_count -> Int4,
+        assignee_id -> Nullable<Int4>,
+        assigned -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    report_note (id) {
+        id -> Int4,
+        report_combined_id -> Int4,
+        creator_id -> Int4,
+        content -> Text,
+        published -> Timestamptz,
+        updated -> Nullable<Timestamptz>,
+    }
+}
+
 diesel::table! {
     secret (id) {
         id -> Int4,
         jwt_secret -> Varchar,
+        vapid_private_key -> Nullable<Text>,
+        vapid_public_key -> Nullable<Text>,
     }
 }
 
@@ -913,6 +1303,62 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::WebhookEventType;
+
+    webhook (id) {
+        id -> Int4,
+        creator_id -> Int4,
+        community_id -> Nullable<Int4>,
+        url -> Text,
+        secret -> Text,
+        events -> Array<WebhookEventType>,
+        enabled -> Bool,
+        published -> Timestamptz,
+        updated -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::WebhookEventType;
+
+    webhook_delivery (id) {
+        id -> Int8,
+        webhook_id -> Int4,
+        event_id -> Int8,
+        event_type -> WebhookEventType,
+        success -> Bool,
+        status_code -> Nullable<Int4>,
+        error -> Nullable<Text>,
+        published -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::WebhookEventType;
+
+    webhook_event (id) {
+        id -> Int8,
+        event_type -> WebhookEventType,
+        community_id -> Nullable<Int4>,
+        data -> Jsonb,
+        published -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    webhook_queue_state (id) {
+        id -> Int4,
+        webhook_id -> Int4,
+        last_successful_id -> Int8,
+        fail_count -> Int4,
+        last_retry -> Timestamptz,
+    }
+}
+
 diesel::joinable!(admin_purge_comment -> person (admin_person_id));
 diesel::joinable!(admin_purge_comment -> post (post_id));
 diesel::joinable!(admin_purge_community -> person (admin_person_id));
@@ -929,6 +1375,7 @@ diesel::joinable!(comment_like -> post (post_id));
 diesel::joinable!(comment_reply -> comment (comment_id));
 diesel::joinable!(comment_reply -> person (recipient_id));
 diesel::joinable!(comment_report -> comment (comment_id));
+diesel::joinable!(comment_revision -> comment (comment_id));
 diesel::joinable!(comment_saved -> comment (comment_id));
 diesel::joinable!(comment_saved -> person (person_id));
 diesel::joinable!(community -> instance (instance_id));
@@ -943,8 +1390,20 @@ diesel::joinable!(community_moderator -> community (community_id));
 diesel::joinable!(community_moderator -> person (person_id));
 diesel::joinable!(community_person_ban -> community (community_id));
 diesel::joinable!(community_person_ban -> person (person_id));
+diesel::joinable!(community_post_tag -> community (community_id));
+diesel::joinable!(community_wiki_page -> community (community_id));
+diesel::joinable!(community_wiki_page_revision -> community_wiki_page (wiki_page_id));
+diesel::joinable!(community_wiki_page_revision -> person (editor_id));
+diesel::joinable!(conversation_participant -> conversation (conversation_id));
+diesel::joinable!(conversation_participant -> person (person_id));
+diesel::joinable!(custom_emoji -> community (community_id));
 diesel::joinable!(custom_emoji -> local_site (local_site_id));
 diesel::joinable!(custom_emoji_keyword -> custom_emoji (custom_emoji_id));
+diesel::joinable!(draft -> comment (parent_id));
+diesel::joinable!(draft -> community (community_id));
+diesel::joinable!(draft -> language (language_id));
+diesel::joinable!(draft -> local_user (local_user_id));
+diesel::joinable!(draft -> post (post_id));
 diesel::joinable!(email_verification -> local_user (local_user_id));
 diesel::joinable!(federation_allowlist -> instance (instance_id));
 diesel::joinable!(federation_blocklist -> instance (instance_id));
@@ -955,11 +1414,14 @@ diesel::joinable!(instance_block -> person (person_id));
 diesel::joinable!(local_site -> site (site_id));
 diesel::joinable!(local_site_rate_limit -> local_site (local_site_id));
 diesel::joinable!(local_user -> person (person_id));
+diesel::joinable!(local_user_keyword_filter -> local_user (local_user_id));
 diesel::joinable!(local_user_language -> language (language_id));
 diesel::joinable!(local_user_language -> local_user (local_user_id));
 diesel::joinable!(login_token -> local_user (user_id));
 diesel::joinable!(mod_add_community -> community (community_id));
 diesel::joinable!(mod_ban_from_community -> community (community_id));
+diesel::joinable!(mod_community_post_tag -> community_post_tag (community_post_tag_id));
+diesel::joinable!(mod_community_post_tag -> person (mod_person_id));
 diesel::joinable!(mod_feature_post -> person (mod_person_id));
 diesel::joinable!(mod_feature_post -> post (post_id));
 diesel::joinable!(mod_hide_community -> community (community_id));
@@ -972,15 +1434,53 @@ diesel::joinable!(mod_remove_community -> community (community_id));
 diesel::joinable!(mod_remove_community -> person (mod_person_id));
 diesel::joinable!(mod_remove_post -> person (mod_person_id));
 diesel::joinable!(mod_remove_post -> post (post_id));
+diesel::joinable!(mod_resolve_report -> comment (comment_id));
+diesel::joinable!(mod_resolve_report -> community (community_id));
+diesel::joinable!(mod_resolve_report -> post (post_id));
 diesel::joinable!(mod_transfer_community -> community (community_id));
+diesel::joinable!(mod_warning -> comment (comment_id));
+diesel::joinable!(mod_warning -> community (community_id));
+diesel::joinable!(mod_warning -> post (post_id));
+diesel::joinable!(modlog_combined -> admin_purge_comment (admin_purge_comment_id));
+diesel::joinable!(modlog_combined -> admin_purge_community (admin_purge_community_id));
+diesel::joinable!(modlog_combined -> admin_purge_person (admin_purge_person_id));
+diesel::joinable!(modlog_combined -> admin_purge_post (admin_purge_post_id));
+diesel::joinable!(modlog_combined -> comment (comment_id));
+diesel::joinable!(modlog_combined -> community (community_id));
+diesel::joinable!(modlog_combined -> community_post_tag (community_post_tag_id));
+diesel::joinable!(modlog_combined -> mod_add (mod_add_id));
+diesel::joinable!(modlog_combined -> mod_add_community (mod_add_community_id));
+diesel::joinable!(modlog_combined -> mod_ban (mod_ban_id));
+diesel::joinable!(modlog_combined -> mod_ban_from_community (mod_ban_from_community_id));
+diesel::joinable!(modlog_combined -> mod_community_post_tag (mod_community_post_tag_id));
+diesel::joinable!(modlog_combined -> mod_feature_post (mod_feature_post_id));
+diesel::joinable!(modlog_combined -> mod_hide_community (mod_hide_community_id));
+diesel::joinable!(modlog_combined -> mod_lock_post (mod_lock_post_id));
+diesel::joinable!(modlog_combined -> mod_remove_comment (mod_remove_comment_id));
+diesel::joinable!(modlog_combined -> mod_remove_community (mod_remove_community_id));
+diesel::joinable!(modlog_combined -> mod_remove_post (mod_remove_post_id));
+diesel::joinable!(modlog_combined -> mod_resolve_report (mod_resolve_report_id));
+diesel::joinable!(modlog_combined -> mod_transfer_community (mod_transfer_community_id));
+diesel::joinable!(modlog_combined -> mod_warning (mod_warning_id));
+diesel::joinable!(modlog_combined -> post (post_id));
+diesel::joinable!(multi_community -> instance (instance_id));
+diesel::joinable!(multi_community -> person (creator_id));
+diesel::joinable!(multi_community_entry -> community (community_id));
+diesel::joinable!(multi_community_entry -> multi_community (multi_community_id));
 diesel::joinable!(password_reset_request -> local_user (local_user_id));
 diesel::joinable!(person -> instance (instance_id));
 diesel::joinable!(person_aggregates -> person (person_id));
 diesel::joinable!(person_ban -> person (person_id));
 diesel::joinable!(person_mention -> comment (comment_id));
 diesel::joinable!(person_mention -> person (recipient_id));
+diesel::joinable!(person_note -> community (community_id));
 diesel::joinable!(person_post_aggregates -> person (person_id));
 diesel::joinable!(person_post_aggregates -> post (post_id));
+diesel::joinable!(poll -> post (post_id));
+diesel::joinable!(poll_option -> poll (post_id));
+diesel::joinable!(poll_vote -> person (person_id));
+diesel::joinable!(poll_vote -> poll (post_id));
+diesel::joinable!(poll_vote -> poll_option (poll_option_id));
 diesel::joinable!(post -> community (community_id));
 diesel::joinable!(post -> language (language_id));
 diesel::joinable!(post -> person (creator_id));
@@ -993,16 +1493,38 @@ diesel::joinable!(post_like -> post (post_id));
 diesel::joinable!(post_read -> person (person_id));
 diesel::joinable!(post_read -> post (post_id));
 diesel::joinable!(post_report -> post (post_id));
+diesel::joinable!(post_revision -> post (post_id));
 diesel::joinable!(post_saved -> person (person_id));
 diesel::joinable!(post_saved -> post (post_id));
+diesel::joinable!(post_tag -> community_post_tag (community_post_tag_id));
+diesel::joinable!(post_tag -> post (post_id));
+diesel::joinable!(private_message -> conversation (conversation_id));
 diesel::joinable!(private_message_report -> private_message (private_message_id));
+diesel::joinable!(push_notification -> push_subscription (subscription_id));
+diesel::joinable!(push_subscription -> local_user (local_user_id));
+diesel::joinable!(reaction -> comment (comment_id));
+diesel::joinable!(reaction -> custom_emoji (custom_emoji_id));
+diesel::joinable!(reaction -> person (person_id));
+diesel::joinable!(reaction -> post (post_id));
 diesel::joinable!(registration_application -> local_user (local_user_id));
 diesel::joinable!(registration_application -> person (admin_id));
+diesel::joinable!(report_combined -> comment (comment_id));
+diesel::joinable!(report_combined -> community (community_id));
+diesel::joinable!(report_combined -> person (assignee_id));
+diesel::joinable!(report_combined -> post (post_id));
+diesel::joinable!(report_combined -> private_message (private_message_id));
+diesel::joinable!(report_note -> person (creator_id));
+diesel::joinable!(report_note -> report_combined (report_combined_id));
 diesel::joinable!(site -> instance (instance_id));
 diesel::joinable!(site_aggregates -> site (site_id));
 diesel::joinable!(site_language -> language (language_id));
 diesel::joinable!(site_language -> site (site_id));
 diesel::joinable!(tagline -> local_site (local_site_id));
+diesel::joinable!(webhook -> community (community_id));
+diesel::joinable!(webhook -> person (creator_id));
+diesel::joinable!(webhook_delivery -> webhook (webhook_id));
+diesel::joinable!(webhook_event -> community (community_id));
+diesel::joinable!(webhook_queue_state -> webhook (webhook_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
     admin_purge_comment,
@@ -1015,6 +1537,7 @@ diesel::allow_tables_to_appear_in_same_query!(
     comment_like,
     comment_reply,
     comment_report,
+    comment_revision,
     comment_saved,
     community,
     community_aggregates,
@@ -1023,8 +1546,14 @@ diesel::allow_tables_to_appear_in_same_query!(
     community_language,
     community_moderator,
     community_person_ban,
+    community_post_tag,
+    community_wiki_page,
+    community_wiki_page_revision,
+    conversation,
+    conversation_participant,
     custom_emoji,
     custom_emoji_keyword,
+    draft,
     email_verification,
     federation_allowlist,
     federation_blocklist,
@@ -1036,19 +1565,26 @@ diesel::allow_tables_to_appear_in_same_query!(
     local_site,
     local_site_rate_limit,
     local_user,
+    local_user_keyword_filter,
     local_user_language,
     login_token,
     mod_add,
     mod_add_community,
     mod_ban,
     mod_ban_from_community,
+    mod_community_post_tag,
     mod_feature_post,
     mod_hide_community,
     mod_lock_post,
     mod_remove_comment,
     mod_remove_community,
     mod_remove_post,
+    mod_resolve_report,
     mod_transfer_community,
+    mod_warning,
+    modlog_combined,
+    multi_community,
+    multi_community_entry,
     password_reset_request,
     person,
     person_aggregates,
@@ -1056,21 +1592,36 @@ diesel::allow_tables_to_appear_in_same_query!(
     person_block,
     person_follower,
     person_mention,
+    person_note,
     person_post_aggregates,
+    poll,
+    poll_option,
+    poll_vote,
     post,
     post_aggregates,
     post_like,
     post_read,
     post_report,
+    post_revision,
     post_saved,
+    post_tag,
     private_message,
     private_message_report,
+    push_notification,
+    push_subscription,
+    reaction,
     received_activity,
     registration_application,
+    report_combined,
+    report_note,
     secret,
     sent_activity,
     site,
     site_aggregates,
     site_language,
     tagline,
+    webhook,
+    webhook_delivery,
+    webhook_event,
+    webhook_queue_state,
 );
@@ -2,7 +2,7 @@
 use crate::newtypes::LtreeDef;
 use crate::newtypes::{CommentId, DbUrl, LanguageId, PersonId, PostId};
 #[cfg(feature = "full")]
-use crate::schema::{comment, comment_like, comment_saved};
+use crate::schema::{comment, comment_like, comment_revision, comment_saved};
 use chrono::{DateTime, Utc};
 #[cfg(feature = "full")]
 use diesel_ltree::Ltree;
@@ -122,3 +122,26 @@ pub struct CommentSavedForm {
   pub comment_id: CommentId,
   pub person_id: PersonId,
 }
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = comment_revision))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A previous version of an edited comment.
+pub struct CommentRevision {
+  pub id: i32,
+  pub comment_id: CommentId,
+  pub content: String,
+  /// When this version was written.
+  pub published: DateTime<Utc>,
+  /// When this version was replaced by an edit.
+  pub edited: DateTime<Utc>,
+}
+
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = comment_revision))]
+pub(crate) struct CommentRevisionForm {
+  pub comment_id: CommentId,
+  pub content: String,
+  pub published: DateTime<Utc>,
+}
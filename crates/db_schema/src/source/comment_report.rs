@@ -1,4 +1,4 @@
-use crate::newtypes::{CommentId, CommentReportId, PersonId};
+use crate::newtypes::{CommentId, CommentReportId, DbUrl, PersonId};
 #[cfg(feature = "full")]
 use crate::schema::comment_report;
 use chrono::{DateTime, Utc};
@@ -24,6 +24,11 @@ pub struct CommentReport {
   pub resolver_id: Option<PersonId>,
   pub published: DateTime<Utc>,
   pub updated: Option<DateTime<Utc>>,
+  /// Why the report was resolved, given by the resolver.
+  pub resolution_reason: Option<String>,
+  #[cfg_attr(feature = "full", ts(type = "string"))]
+  /// The id of the activity which federated the report.
+  pub ap_id: Option<DbUrl>,
 }
 
 #[derive(Clone)]
@@ -34,4 +39,5 @@ pub struct CommentReportForm {
   pub comment_id: CommentId,
   pub original_comment_text: String,
   pub reason: String,
+  pub ap_id: Option<DbUrl>,
 }
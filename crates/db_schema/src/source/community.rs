@@ -3,6 +3,7 @@ use crate::schema::{community, community_follower, community_moderator, communit
 use crate::{
   newtypes::{CommunityId, DbUrl, InstanceId, PersonId},
   source::placeholder_apub_url,
+  CommunityVisibility,
 };
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -65,6 +66,12 @@ pub struct Community {
   /// Url where featured posts collection is served over Activitypub
   #[serde(skip)]
   pub featured_url: Option<DbUrl>,
+  pub visibility: CommunityVisibility,
+  /// After this many warnings within `warning_ban_window_days`, users are banned from the
+  /// community for `warning_ban_days`. Never if empty.
+  pub warning_ban_threshold: Option<i32>,
+  pub warning_ban_window_days: i32,
+  pub warning_ban_days: i32,
 }
 
 #[derive(Debug, Clone, TypedBuilder)]
@@ -98,6 +105,10 @@ pub struct CommunityInsertForm {
   pub posting_restricted_to_mods: Option<bool>,
   #[builder(!default)]
   pub instance_id: InstanceId,
+  pub visibility: Option<CommunityVisibility>,
+  pub warning_ban_threshold: Option<i32>,
+  pub warning_ban_window_days: Option<i32>,
+  pub warning_ban_days: Option<i32>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -125,6 +136,10 @@ pub struct CommunityUpdateForm {
   pub featured_url: Option<DbUrl>,
   pub hidden: Option<bool>,
   pub posting_restricted_to_mods: Option<bool>,
+  pub visibility: Option<CommunityVisibility>,
+  pub warning_ban_threshold: Option<Option<i32>>,
+  pub warning_ban_window_days: Option<i32>,
+  pub warning_ban_days: Option<i32>,
 }
 
 #[derive(PartialEq, Eq, Debug)]
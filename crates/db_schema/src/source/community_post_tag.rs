@@ -0,0 +1,70 @@
+use crate::newtypes::{CommunityId, CommunityPostTagId, DbUrl, PostId};
+#[cfg(feature = "full")]
+use crate::schema::{community_post_tag, post_tag};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+use typed_builder::TypedBuilder;
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = community_post_tag))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A tag which the moderators of a community have defined for posts in it.
+pub struct CommunityPostTag {
+  pub id: CommunityPostTagId,
+  /// The federated ap_id.
+  pub ap_id: DbUrl,
+  pub community_id: CommunityId,
+  pub display_name: String,
+  pub deleted: bool,
+  pub published: DateTime<Utc>,
+  pub updated: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, TypedBuilder)]
+#[builder(field_defaults(default))]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = community_post_tag))]
+pub struct CommunityPostTagInsertForm {
+  #[builder(!default)]
+  pub community_id: CommunityId,
+  #[builder(!default)]
+  pub display_name: String,
+  pub ap_id: Option<DbUrl>,
+  pub deleted: Option<bool>,
+  pub published: Option<DateTime<Utc>>,
+  pub updated: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "full", derive(AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = community_post_tag))]
+pub struct CommunityPostTagUpdateForm {
+  pub ap_id: Option<DbUrl>,
+  pub display_name: Option<String>,
+  pub deleted: Option<bool>,
+  pub updated: Option<Option<DateTime<Utc>>>,
+}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+#[cfg_attr(feature = "full", derive(Identifiable, Queryable, Associations))]
+#[cfg_attr(feature = "full", diesel(belongs_to(crate::source::post::Post)))]
+#[cfg_attr(feature = "full", diesel(table_name = post_tag))]
+/// Associates a post with one of the tags of its community.
+pub struct PostTag {
+  pub id: i32,
+  pub post_id: PostId,
+  pub community_post_tag_id: CommunityPostTagId,
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = post_tag))]
+pub struct PostTagForm {
+  pub post_id: PostId,
+  pub community_post_tag_id: CommunityPostTagId,
+}
@@ -0,0 +1,96 @@
+use crate::newtypes::{
+  CommunityId,
+  CommunityWikiPageId,
+  CommunityWikiPageRevisionId,
+  DbUrl,
+  PersonId,
+};
+#[cfg(feature = "full")]
+use crate::schema::{community_wiki_page, community_wiki_page_revision};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+use typed_builder::TypedBuilder;
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = community_wiki_page))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A markdown page in the wiki of a community.
+pub struct CommunityWikiPage {
+  pub id: CommunityWikiPageId,
+  /// The federated ap_id.
+  pub ap_id: DbUrl,
+  pub community_id: CommunityId,
+  /// Identifies the page within its community, used in urls.
+  pub slug: String,
+  pub title: String,
+  pub body: String,
+  /// Whether approved followers can edit the page. Otherwise only mods can edit it.
+  pub members_can_edit: bool,
+  pub local: bool,
+  pub deleted: bool,
+  pub published: DateTime<Utc>,
+  pub updated: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, TypedBuilder)]
+#[builder(field_defaults(default))]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = community_wiki_page))]
+pub struct CommunityWikiPageInsertForm {
+  #[builder(!default)]
+  pub community_id: CommunityId,
+  #[builder(!default)]
+  pub slug: String,
+  #[builder(!default)]
+  pub title: String,
+  #[builder(!default)]
+  pub body: String,
+  pub ap_id: Option<DbUrl>,
+  pub members_can_edit: Option<bool>,
+  pub local: Option<bool>,
+  pub deleted: Option<bool>,
+  pub published: Option<DateTime<Utc>>,
+  pub updated: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "full", derive(AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = community_wiki_page))]
+pub struct CommunityWikiPageUpdateForm {
+  pub ap_id: Option<DbUrl>,
+  pub title: Option<String>,
+  pub body: Option<String>,
+  pub members_can_edit: Option<bool>,
+  pub deleted: Option<bool>,
+  pub updated: Option<Option<DateTime<Utc>>>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = community_wiki_page_revision))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A version of a wiki page. The newest revision has the same content as the page itself.
+pub struct CommunityWikiPageRevision {
+  pub id: CommunityWikiPageRevisionId,
+  pub wiki_page_id: CommunityWikiPageId,
+  /// The person who wrote this version.
+  pub editor_id: PersonId,
+  pub title: String,
+  pub body: String,
+  pub published: DateTime<Utc>,
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = community_wiki_page_revision))]
+pub struct CommunityWikiPageRevisionForm {
+  pub wiki_page_id: CommunityWikiPageId,
+  pub editor_id: PersonId,
+  pub title: String,
+  pub body: String,
+}
@@ -0,0 +1,64 @@
+use crate::newtypes::{ConversationId, DbUrl, PersonId};
+#[cfg(feature = "full")]
+use crate::schema::{conversation, conversation_participant};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+use typed_builder::TypedBuilder;
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = conversation))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A conversation, which groups the private messages between two or more people.
+pub struct Conversation {
+  pub id: ConversationId,
+  /// Sent along with the messages as their context, so that other instances can group them.
+  pub ap_id: DbUrl,
+  pub local: bool,
+  pub published: DateTime<Utc>,
+  /// The time of the latest message.
+  pub updated: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, TypedBuilder)]
+#[builder(field_defaults(default))]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = conversation))]
+pub struct ConversationInsertForm {
+  pub ap_id: Option<DbUrl>,
+  pub local: Option<bool>,
+  pub published: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "full", derive(AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = conversation))]
+pub struct ConversationUpdateForm {
+  pub ap_id: Option<DbUrl>,
+  pub updated: Option<Option<DateTime<Utc>>>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = conversation_participant))]
+pub struct ConversationParticipant {
+  pub id: i32,
+  pub conversation_id: ConversationId,
+  pub person_id: PersonId,
+  /// Messages published after this time are unread for the participant.
+  pub last_read: DateTime<Utc>,
+  pub muted: bool,
+  pub published: DateTime<Utc>,
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = conversation_participant))]
+pub struct ConversationParticipantForm {
+  pub conversation_id: ConversationId,
+  pub person_id: PersonId,
+}
@@ -1,4 +1,4 @@
-use crate::newtypes::{CustomEmojiId, DbUrl, LocalSiteId};
+use crate::newtypes::{CommunityId, CustomEmojiId, DbUrl, LocalSiteId};
 #[cfg(feature = "full")]
 use crate::schema::custom_emoji;
 use chrono::{DateTime, Utc};
@@ -20,32 +20,49 @@ use typed_builder::TypedBuilder;
 /// A custom emoji.
 pub struct CustomEmoji {
   pub id: CustomEmojiId,
-  pub local_site_id: LocalSiteId,
+  /// Set for the emojis of the site.
+  pub local_site_id: Option<LocalSiteId>,
   pub shortcode: String,
   pub image_url: DbUrl,
   pub alt_text: String,
   pub category: String,
   pub published: DateTime<Utc>,
   pub updated: Option<DateTime<Utc>>,
+  /// Set for the emojis which the moderators of a community added.
+  pub community_id: Option<CommunityId>,
+  /// The federated ap_id.
+  pub ap_id: DbUrl,
+  /// Remote emojis which were received with posts or comments don't belong to any emoji set.
+  pub local: bool,
 }
 
 #[derive(Debug, Clone, TypedBuilder)]
 #[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
 #[cfg_attr(feature = "full", diesel(table_name = custom_emoji))]
 pub struct CustomEmojiInsertForm {
-  pub local_site_id: LocalSiteId,
+  #[builder(default)]
+  pub local_site_id: Option<LocalSiteId>,
+  #[builder(default)]
+  pub community_id: Option<CommunityId>,
   pub shortcode: String,
   pub image_url: DbUrl,
   pub alt_text: String,
   pub category: String,
+  #[builder(default)]
+  pub ap_id: Option<DbUrl>,
+  #[builder(default)]
+  pub local: Option<bool>,
+  #[builder(default)]
+  pub updated: Option<DateTime<Utc>>,
 }
 
 #[derive(Debug, Clone, TypedBuilder)]
 #[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
 #[cfg_attr(feature = "full", diesel(table_name = custom_emoji))]
 pub struct CustomEmojiUpdateForm {
-  pub local_site_id: LocalSiteId,
   pub image_url: DbUrl,
   pub alt_text: String,
   pub category: String,
+  #[builder(default)]
+  pub ap_id: Option<DbUrl>,
 }
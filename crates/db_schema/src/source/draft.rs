@@ -0,0 +1,92 @@
+use crate::newtypes::{
+  CommentId,
+  CommunityId,
+  CommunityPostTagId,
+  DbUrl,
+  DraftId,
+  LanguageId,
+  LocalUserId,
+  PostId,
+};
+#[cfg(feature = "full")]
+use crate::schema::draft;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+use typed_builder::TypedBuilder;
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = draft))]
+#[cfg_attr(feature = "full", ts(export))]
+/// An unpublished post or comment of a local user. Drafts with a `post_id` are comment drafts,
+/// all others are post drafts.
+pub struct Draft {
+  pub id: DraftId,
+  pub local_user_id: LocalUserId,
+  pub community_id: Option<CommunityId>,
+  pub name: Option<String>,
+  #[cfg_attr(feature = "full", ts(type = "string"))]
+  pub url: Option<DbUrl>,
+  /// The post body, or the comment content.
+  pub body: Option<String>,
+  pub nsfw: Option<bool>,
+  pub language_id: Option<LanguageId>,
+  /// The post which a comment draft replies to.
+  pub post_id: Option<PostId>,
+  pub parent_id: Option<CommentId>,
+  pub published: DateTime<Utc>,
+  pub updated: Option<DateTime<Utc>>,
+  pub tags: Option<Vec<CommunityPostTagId>>,
+  pub scheduled_publish_time: Option<DateTime<Utc>>,
+  /// The options of a poll which is created together with the post.
+  pub poll_options: Option<Vec<String>>,
+  pub poll_multiple_choice: Option<bool>,
+  pub poll_end_time: Option<DateTime<Utc>>,
+  pub poll_hide_results: Option<bool>,
+}
+
+#[derive(Debug, Clone, TypedBuilder)]
+#[builder(field_defaults(default))]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = draft))]
+pub struct DraftInsertForm {
+  #[builder(!default)]
+  pub local_user_id: LocalUserId,
+  pub community_id: Option<CommunityId>,
+  pub name: Option<String>,
+  pub url: Option<DbUrl>,
+  pub body: Option<String>,
+  pub nsfw: Option<bool>,
+  pub language_id: Option<LanguageId>,
+  pub post_id: Option<PostId>,
+  pub parent_id: Option<CommentId>,
+  pub tags: Option<Vec<CommunityPostTagId>>,
+  pub scheduled_publish_time: Option<DateTime<Utc>>,
+  pub poll_options: Option<Vec<String>>,
+  pub poll_multiple_choice: Option<bool>,
+  pub poll_end_time: Option<DateTime<Utc>>,
+  pub poll_hide_results: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "full", derive(AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = draft))]
+pub struct DraftUpdateForm {
+  pub community_id: Option<Option<CommunityId>>,
+  pub name: Option<Option<String>>,
+  pub url: Option<Option<DbUrl>>,
+  pub body: Option<Option<String>>,
+  pub nsfw: Option<Option<bool>>,
+  pub language_id: Option<Option<LanguageId>>,
+  pub updated: Option<Option<DateTime<Utc>>>,
+  pub tags: Option<Option<Vec<CommunityPostTagId>>>,
+  pub scheduled_publish_time: Option<Option<DateTime<Utc>>>,
+  pub poll_options: Option<Option<Vec<String>>>,
+  pub poll_multiple_choice: Option<Option<bool>>,
+  pub poll_end_time: Option<Option<DateTime<Utc>>>,
+  pub poll_hide_results: Option<Option<bool>>,
+}
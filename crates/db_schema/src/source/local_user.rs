@@ -2,10 +2,12 @@
 use crate::schema::local_user;
 use crate::{
   newtypes::{LocalUserId, PersonId},
+  EmailDigestFrequency,
   ListingType,
   PostListingMode,
   SortType,
 };
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 #[cfg(feature = "full")]
@@ -60,6 +62,11 @@ pub struct LocalUser {
   pub enable_keyboard_navigation: bool,
   /// Whether user avatars and inline images in the UI that are gifs should be allowed to play or should be paused
   pub enable_animated_images: bool,
+  /// How often to receive an email digest. Missing in older settings backups.
+  #[serde(default)]
+  pub email_digest: EmailDigestFrequency,
+  #[serde(skip)]
+  pub last_email_digest: Option<DateTime<Utc>>,
 }
 
 #[derive(Clone, TypedBuilder)]
@@ -94,6 +101,7 @@ pub struct LocalUserInsertForm {
   pub totp_2fa_enabled: Option<bool>,
   pub enable_keyboard_navigation: Option<bool>,
   pub enable_animated_images: Option<bool>,
+  pub email_digest: Option<EmailDigestFrequency>,
 }
 
 #[derive(Clone, Default)]
@@ -124,4 +132,6 @@ pub struct LocalUserUpdateForm {
   pub totp_2fa_enabled: Option<bool>,
   pub enable_keyboard_navigation: Option<bool>,
   pub enable_animated_images: Option<bool>,
+  pub email_digest: Option<EmailDigestFrequency>,
+  pub last_email_digest: Option<Option<DateTime<Utc>>>,
 }
@@ -0,0 +1,44 @@
+use crate::newtypes::{LocalUserId, LocalUserKeywordFilterId};
+#[cfg(feature = "full")]
+use crate::schema::local_user_keyword_filter;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = local_user_keyword_filter))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A keyword or regex which hides matching posts and comments from a user.
+pub struct LocalUserKeywordFilter {
+  pub id: LocalUserKeywordFilterId,
+  pub local_user_id: LocalUserId,
+  pub keyword: String,
+  /// The keyword is a case-insensitive POSIX regular expression, instead of plain text.
+  pub is_regex: bool,
+  /// Applies to post titles.
+  pub filter_title: bool,
+  /// Applies to post bodies and comments.
+  pub filter_body: bool,
+  /// Applies to post urls.
+  pub filter_url: bool,
+  /// The filter doesn't apply anymore after this time.
+  pub expires: Option<DateTime<Utc>>,
+  pub published: DateTime<Utc>,
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = local_user_keyword_filter))]
+pub struct LocalUserKeywordFilterForm {
+  pub local_user_id: LocalUserId,
+  pub keyword: String,
+  pub is_regex: bool,
+  pub filter_title: bool,
+  pub filter_body: bool,
+  pub filter_url: bool,
+  pub expires: Option<DateTime<Utc>>,
+}
@@ -1,4 +1,4 @@
-use crate::newtypes::{CommentId, CommunityId, PersonId, PostId};
+use crate::newtypes::{CommentId, CommunityId, CommunityPostTagId, PersonId, PostId};
 #[cfg(feature = "full")]
 use crate::schema::{
   admin_purge_comment,
@@ -9,13 +9,16 @@ use crate::schema::{
   mod_add_community,
   mod_ban,
   mod_ban_from_community,
+  mod_community_post_tag,
   mod_feature_post,
   mod_hide_community,
   mod_lock_post,
   mod_remove_comment,
   mod_remove_community,
   mod_remove_post,
+  mod_resolve_report,
   mod_transfer_community,
+  mod_warning,
 };
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -207,6 +210,95 @@ pub struct ModHideCommunity {
   pub hidden: bool,
 }
 
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = mod_community_post_tag))]
+#[cfg_attr(feature = "full", ts(export))]
+/// When a community post tag is created, renamed or deleted.
+pub struct ModCommunityPostTag {
+  pub id: i32,
+  pub mod_person_id: PersonId,
+  pub community_post_tag_id: CommunityPostTagId,
+  /// The display name of the tag after the change.
+  pub display_name: String,
+  pub deleted: bool,
+  pub when_: DateTime<Utc>,
+}
+
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = mod_community_post_tag))]
+pub struct ModCommunityPostTagForm {
+  pub mod_person_id: PersonId,
+  pub community_post_tag_id: CommunityPostTagId,
+  pub display_name: String,
+  pub deleted: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = mod_warning))]
+#[cfg_attr(feature = "full", ts(export))]
+/// When someone is warned by a mod because of a post or comment.
+pub struct ModWarning {
+  pub id: i32,
+  pub mod_person_id: PersonId,
+  pub other_person_id: PersonId,
+  pub community_id: CommunityId,
+  pub post_id: PostId,
+  /// Set if the warning is about a comment of the post.
+  pub comment_id: Option<CommentId>,
+  pub reason: String,
+  pub when_: DateTime<Utc>,
+}
+
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = mod_warning))]
+pub struct ModWarningForm {
+  pub mod_person_id: PersonId,
+  pub other_person_id: PersonId,
+  pub community_id: CommunityId,
+  pub post_id: PostId,
+  pub comment_id: Option<CommentId>,
+  pub reason: String,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = mod_resolve_report))]
+#[cfg_attr(feature = "full", ts(export))]
+/// When a mod resolves or reopens the reports about a post or comment.
+pub struct ModResolveReport {
+  pub id: i32,
+  pub mod_person_id: PersonId,
+  /// The creator of the reported post or comment.
+  pub other_person_id: PersonId,
+  pub community_id: CommunityId,
+  pub post_id: PostId,
+  /// Set if the reports are about a comment of the post.
+  pub comment_id: Option<CommentId>,
+  /// How many reports were resolved.
+  pub report_count: i32,
+  pub resolved: bool,
+  pub reason: Option<String>,
+  pub when_: DateTime<Utc>,
+}
+
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = mod_resolve_report))]
+pub struct ModResolveReportForm {
+  pub mod_person_id: PersonId,
+  pub other_person_id: PersonId,
+  pub community_id: CommunityId,
+  pub post_id: PostId,
+  pub comment_id: Option<CommentId>,
+  pub report_count: i32,
+  pub resolved: Option<bool>,
+  pub reason: Option<String>,
+}
+
 #[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
 #[cfg_attr(feature = "full", diesel(table_name = mod_ban))]
 pub struct ModBanForm {
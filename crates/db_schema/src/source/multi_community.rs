@@ -0,0 +1,85 @@
+use crate::newtypes::{CommunityId, DbUrl, InstanceId, MultiCommunityId, PersonId};
+#[cfg(feature = "full")]
+use crate::schema::{multi_community, multi_community_entry};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+use typed_builder::TypedBuilder;
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = multi_community))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A multi-community, which combines the posts of multiple communities into a single feed.
+pub struct MultiCommunity {
+  pub id: MultiCommunityId,
+  pub creator_id: PersonId,
+  pub instance_id: InstanceId,
+  /// The name used in urls, which is unique per instance.
+  pub name: String,
+  pub title: String,
+  pub description: Option<String>,
+  pub local: bool,
+  pub deleted: bool,
+  /// The federated ap_id.
+  pub ap_id: DbUrl,
+  pub published: DateTime<Utc>,
+  pub updated: Option<DateTime<Utc>>,
+  #[serde(skip)]
+  pub last_refreshed_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, TypedBuilder)]
+#[builder(field_defaults(default))]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = multi_community))]
+pub struct MultiCommunityInsertForm {
+  #[builder(!default)]
+  pub creator_id: PersonId,
+  #[builder(!default)]
+  pub instance_id: InstanceId,
+  #[builder(!default)]
+  pub name: String,
+  #[builder(!default)]
+  pub title: String,
+  pub description: Option<String>,
+  pub local: Option<bool>,
+  pub ap_id: Option<DbUrl>,
+  pub published: Option<DateTime<Utc>>,
+  pub updated: Option<DateTime<Utc>>,
+  pub last_refreshed_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "full", derive(AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = multi_community))]
+pub struct MultiCommunityUpdateForm {
+  pub title: Option<String>,
+  pub description: Option<Option<String>>,
+  pub deleted: Option<bool>,
+  pub updated: Option<Option<DateTime<Utc>>>,
+}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+#[cfg_attr(feature = "full", derive(Identifiable, Queryable, Associations))]
+#[cfg_attr(
+  feature = "full",
+  diesel(belongs_to(crate::source::multi_community::MultiCommunity))
+)]
+#[cfg_attr(feature = "full", diesel(table_name = multi_community_entry))]
+pub struct MultiCommunityEntry {
+  pub id: i32,
+  pub multi_community_id: MultiCommunityId,
+  pub community_id: CommunityId,
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = multi_community_entry))]
+pub struct MultiCommunityEntryForm {
+  pub multi_community_id: MultiCommunityId,
+  pub community_id: CommunityId,
+}
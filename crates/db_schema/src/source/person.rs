@@ -56,6 +56,8 @@ pub struct Person {
   /// When their ban, if it exists, expires, if at all.
   pub ban_expires: Option<DateTime<Utc>>,
   pub instance_id: InstanceId,
+  /// A public key for end-to-end encrypted private messages to this person.
+  pub encryption_public_key: Option<String>,
 }
 
 #[derive(Clone, TypedBuilder)]
@@ -86,6 +88,7 @@ pub struct PersonInsertForm {
   pub matrix_user_id: Option<String>,
   pub bot_account: Option<bool>,
   pub ban_expires: Option<DateTime<Utc>>,
+  pub encryption_public_key: Option<String>,
 }
 
 #[derive(Clone, Default)]
@@ -109,6 +112,7 @@ pub struct PersonUpdateForm {
   pub matrix_user_id: Option<Option<String>>,
   pub bot_account: Option<bool>,
   pub ban_expires: Option<Option<DateTime<Utc>>>,
+  pub encryption_public_key: Option<Option<String>>,
 }
 
 #[derive(PartialEq, Eq, Debug)]
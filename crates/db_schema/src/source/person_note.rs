@@ -0,0 +1,45 @@
+use crate::newtypes::{CommunityId, PersonId, PersonNoteId};
+#[cfg(feature = "full")]
+use crate::schema::person_note;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = person_note))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A private note of a moderator about a person.
+pub struct PersonNote {
+  pub id: PersonNoteId,
+  /// The person which the note is about.
+  pub person_id: PersonId,
+  pub creator_id: PersonId,
+  /// The community in which the note is visible to moderators. Notes without a community are
+  /// only visible to admins.
+  pub community_id: Option<CommunityId>,
+  pub content: String,
+  pub published: DateTime<Utc>,
+  pub updated: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = person_note))]
+pub struct PersonNoteInsertForm {
+  pub person_id: PersonId,
+  pub creator_id: PersonId,
+  pub community_id: Option<CommunityId>,
+  pub content: String,
+}
+
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "full", derive(AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = person_note))]
+pub struct PersonNoteUpdateForm {
+  pub content: Option<String>,
+  pub updated: Option<Option<DateTime<Utc>>>,
+}
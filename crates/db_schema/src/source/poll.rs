@@ -0,0 +1,105 @@
+use crate::newtypes::{PersonId, PollOptionId, PostId};
+#[cfg(feature = "full")]
+use crate::schema::{poll, poll_option, poll_vote};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = poll))]
+#[cfg_attr(feature = "full", diesel(primary_key(post_id)))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A poll which is attached to a post.
+pub struct Poll {
+  pub post_id: PostId,
+  /// Whether voters can pick more than one option.
+  pub multiple_choice: bool,
+  /// No more votes are accepted after this time.
+  pub end_time: Option<DateTime<Utc>>,
+  /// Whether the vote counts are hidden until the poll is closed.
+  pub hide_results: bool,
+  pub voters_count: i32,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = poll))]
+pub struct PollForm {
+  pub post_id: PostId,
+  pub multiple_choice: bool,
+  pub end_time: Option<DateTime<Utc>>,
+  pub hide_results: bool,
+  /// Only set for remote polls, the count of local polls is kept up to date by a trigger.
+  pub voters_count: Option<i32>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = poll_option))]
+pub struct PollOption {
+  pub id: PollOptionId,
+  pub post_id: PostId,
+  pub name: String,
+  pub position: i32,
+  pub vote_count: i32,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = poll_option))]
+pub struct PollOptionForm {
+  pub post_id: PostId,
+  pub name: String,
+  pub position: i32,
+  /// Only set for remote polls, the count of local polls is kept up to date by a trigger.
+  pub vote_count: Option<i32>,
+}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+#[cfg_attr(feature = "full", derive(Identifiable, Queryable))]
+#[cfg_attr(feature = "full", diesel(table_name = poll_vote))]
+#[cfg_attr(feature = "full", diesel(primary_key(poll_option_id, person_id)))]
+pub struct PollVote {
+  pub poll_option_id: PollOptionId,
+  pub person_id: PersonId,
+  pub post_id: PostId,
+  pub published: DateTime<Utc>,
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = poll_vote))]
+pub struct PollVoteForm {
+  pub poll_option_id: PollOptionId,
+  pub person_id: PersonId,
+  pub post_id: PostId,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A poll option, as seen by a given person.
+pub struct PollOptionResult {
+  pub id: PollOptionId,
+  pub name: String,
+  /// Hidden while the poll is open if the poll creator chose so.
+  pub vote_count: Option<i32>,
+  /// Whether the person voted for this option.
+  pub voted: bool,
+}
+
+/// The options of a poll, which are loaded as a single json column.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "full", derive(FromSqlRow))]
+pub struct PollOptionResults(pub Vec<PollOptionResult>);
+
+impl From<PollOptionResults> for Vec<PollOptionResult> {
+  fn from(results: PollOptionResults) -> Self {
+    results.0
+  }
+}
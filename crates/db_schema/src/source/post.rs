@@ -1,6 +1,6 @@
 use crate::newtypes::{CommunityId, DbUrl, LanguageId, PersonId, PostId};
 #[cfg(feature = "full")]
-use crate::schema::{post, post_like, post_read, post_saved};
+use crate::schema::{post, post_like, post_read, post_revision, post_saved};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -54,6 +54,8 @@ pub struct Post {
   pub featured_community: bool,
   /// Whether the post is featured to its site.
   pub featured_local: bool,
+  /// If set, the post is only visible to its creator until this time, when it gets published.
+  pub scheduled_publish_time: Option<DateTime<Utc>>,
 }
 
 #[derive(Debug, Clone, TypedBuilder)]
@@ -84,6 +86,7 @@ pub struct PostInsertForm {
   pub language_id: Option<LanguageId>,
   pub featured_community: Option<bool>,
   pub featured_local: Option<bool>,
+  pub scheduled_publish_time: Option<DateTime<Utc>>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -108,6 +111,7 @@ pub struct PostUpdateForm {
   pub language_id: Option<LanguageId>,
   pub featured_community: Option<bool>,
   pub featured_local: Option<bool>,
+  pub scheduled_publish_time: Option<Option<DateTime<Utc>>>,
 }
 
 #[derive(PartialEq, Eq, Debug)]
@@ -166,3 +170,32 @@ pub(crate) struct PostReadForm {
   pub post_id: PostId,
   pub person_id: PersonId,
 }
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = post_revision))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A previous version of an edited post.
+pub struct PostRevision {
+  pub id: i32,
+  pub post_id: PostId,
+  pub name: String,
+  #[cfg_attr(feature = "full", ts(type = "string"))]
+  pub url: Option<DbUrl>,
+  pub body: Option<String>,
+  /// When this version was written.
+  pub published: DateTime<Utc>,
+  /// When this version was replaced by an edit.
+  pub edited: DateTime<Utc>,
+}
+
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = post_revision))]
+pub(crate) struct PostRevisionForm {
+  pub post_id: PostId,
+  pub name: String,
+  pub url: Option<DbUrl>,
+  pub body: Option<String>,
+  pub published: DateTime<Utc>,
+}
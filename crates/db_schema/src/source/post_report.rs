@@ -29,6 +29,11 @@ pub struct PostReport {
   pub resolver_id: Option<PersonId>,
   pub published: DateTime<Utc>,
   pub updated: Option<DateTime<Utc>>,
+  /// Why the report was resolved, given by the resolver.
+  pub resolution_reason: Option<String>,
+  #[cfg_attr(feature = "full", ts(type = "string"))]
+  /// The id of the activity which federated the report.
+  pub ap_id: Option<DbUrl>,
 }
 
 #[derive(Clone, Default)]
@@ -41,4 +46,5 @@ pub struct PostReportForm {
   pub original_post_url: Option<DbUrl>,
   pub original_post_body: Option<String>,
   pub reason: String,
+  pub ap_id: Option<DbUrl>,
 }
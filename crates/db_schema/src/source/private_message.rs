@@ -1,4 +1,4 @@
-use crate::newtypes::{DbUrl, PersonId, PrivateMessageId};
+use crate::newtypes::{ConversationId, DbUrl, PersonId, PrivateMessageId};
 #[cfg(feature = "full")]
 use crate::schema::private_message;
 use chrono::{DateTime, Utc};
@@ -21,14 +21,22 @@ use typed_builder::TypedBuilder;
 pub struct PrivateMessage {
   pub id: PrivateMessageId,
   pub creator_id: PersonId,
-  pub recipient_id: PersonId,
+  /// Only set for direct messages, messages to a group conversation have no single recipient.
+  pub recipient_id: Option<PersonId>,
   pub content: String,
   pub deleted: bool,
+  /// Whether the recipient of a direct message has read it. The read state of group
+  /// conversations is kept per participant.
   pub read: bool,
   pub published: DateTime<Utc>,
   pub updated: Option<DateTime<Utc>>,
   pub ap_id: DbUrl,
   pub local: bool,
+  pub conversation_id: ConversationId,
+  /// Whether the content is ciphertext, which only the creator and recipient can decrypt.
+  pub encrypted: bool,
+  /// Opaque data which clients need to decrypt the content, for example a nonce.
+  pub encryption_metadata: Option<String>,
 }
 
 #[derive(Clone, TypedBuilder)]
@@ -38,8 +46,7 @@ pub struct PrivateMessage {
 pub struct PrivateMessageInsertForm {
   #[builder(!default)]
   pub creator_id: PersonId,
-  #[builder(!default)]
-  pub recipient_id: PersonId,
+  pub recipient_id: Option<PersonId>,
   #[builder(!default)]
   pub content: String,
   pub deleted: Option<bool>,
@@ -48,6 +55,10 @@ pub struct PrivateMessageInsertForm {
   pub updated: Option<DateTime<Utc>>,
   pub ap_id: Option<DbUrl>,
   pub local: Option<bool>,
+  #[builder(!default)]
+  pub conversation_id: ConversationId,
+  pub encrypted: Option<bool>,
+  pub encryption_metadata: Option<String>,
 }
 
 #[derive(Clone, Default)]
@@ -61,4 +72,5 @@ pub struct PrivateMessageUpdateForm {
   pub updated: Option<Option<DateTime<Utc>>>,
   pub ap_id: Option<DbUrl>,
   pub local: Option<bool>,
+  pub encryption_metadata: Option<Option<String>>,
 }
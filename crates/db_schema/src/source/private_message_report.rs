@@ -21,13 +21,15 @@ pub struct PrivateMessageReport {
   pub id: PrivateMessageReportId,
   pub creator_id: PersonId,
   pub private_message_id: PrivateMessageId,
-  /// The original text.
+  /// The original text. For encrypted messages, this is the decrypted text given by the reporter.
   pub original_pm_text: String,
   pub reason: String,
   pub resolved: bool,
   pub resolver_id: Option<PersonId>,
   pub published: DateTime<Utc>,
   pub updated: Option<DateTime<Utc>>,
+  /// Why the report was resolved, given by the resolver.
+  pub resolution_reason: Option<String>,
 }
 
 #[derive(Clone)]
@@ -0,0 +1,79 @@
+use crate::newtypes::{LocalUserId, PushSubscriptionId};
+#[cfg(feature = "full")]
+use crate::schema::{push_notification, push_subscription};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+
+#[skip_serializing_none]
+#[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = push_subscription))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A Web Push subscription of a browser or app, with the notifications it receives.
+pub struct PushSubscription {
+  pub id: PushSubscriptionId,
+  pub local_user_id: LocalUserId,
+  pub endpoint: String,
+  /// The keys which messages are encrypted with, they are only needed by the server.
+  #[serde(skip)]
+  pub p256dh: String,
+  #[serde(skip)]
+  pub auth: String,
+  pub notify_replies: bool,
+  pub notify_mentions: bool,
+  pub notify_private_messages: bool,
+  /// Only used for moderators and admins.
+  pub notify_reports: bool,
+  pub published: DateTime<Utc>,
+  pub updated: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = push_subscription))]
+pub struct PushSubscriptionInsertForm {
+  pub local_user_id: LocalUserId,
+  pub endpoint: String,
+  pub p256dh: String,
+  pub auth: String,
+  pub notify_replies: Option<bool>,
+  pub notify_mentions: Option<bool>,
+  pub notify_private_messages: Option<bool>,
+  pub notify_reports: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "full", derive(AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = push_subscription))]
+pub struct PushSubscriptionUpdateForm {
+  pub notify_replies: Option<bool>,
+  pub notify_mentions: Option<bool>,
+  pub notify_private_messages: Option<bool>,
+  pub notify_reports: Option<bool>,
+  pub updated: Option<Option<DateTime<Utc>>>,
+}
+
+#[cfg(feature = "full")]
+#[derive(PartialEq, Eq, Debug, Clone, Queryable, Identifiable)]
+#[diesel(table_name = push_notification)]
+/// A push message which is waiting to be delivered.
+pub struct PushNotification {
+  pub id: i64,
+  pub subscription_id: PushSubscriptionId,
+  /// The json which is encrypted and sent to the subscription.
+  pub payload: String,
+  pub fail_count: i32,
+  pub next_attempt: DateTime<Utc>,
+  pub published: DateTime<Utc>,
+}
+
+#[cfg(feature = "full")]
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = push_notification)]
+pub struct PushNotificationForm {
+  pub subscription_id: PushSubscriptionId,
+  pub payload: String,
+}
@@ -0,0 +1,57 @@
+use crate::newtypes::{CommentId, CustomEmojiId, PersonId, PostId, ReactionId};
+#[cfg(feature = "full")]
+use crate::schema::reaction;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use std::collections::BTreeMap;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = reaction))]
+#[cfg_attr(feature = "full", ts(export))]
+/// An emoji reaction on a post, or on a comment if `comment_id` is set.
+pub struct Reaction {
+  pub id: ReactionId,
+  pub person_id: PersonId,
+  pub post_id: PostId,
+  pub comment_id: Option<CommentId>,
+  /// A unicode emoji, or the shortcode of a custom emoji surrounded by colons.
+  pub emoji: String,
+  pub custom_emoji_id: Option<CustomEmojiId>,
+  /// Whether the author of the post or comment has seen the reaction.
+  pub read: bool,
+  pub published: DateTime<Utc>,
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = reaction))]
+pub struct ReactionForm {
+  pub person_id: PersonId,
+  pub post_id: PostId,
+  pub comment_id: Option<CommentId>,
+  pub emoji: String,
+  pub custom_emoji_id: Option<CustomEmojiId>,
+}
+
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "full", derive(AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = reaction))]
+pub struct ReactionUpdateForm {
+  pub read: Option<bool>,
+}
+
+/// The number of reactions for each emoji, which are stored as a single json column.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "full", derive(FromSqlRow))]
+pub struct ReactionCounts(pub BTreeMap<String, i64>);
+
+impl From<ReactionCounts> for BTreeMap<String, i64> {
+  fn from(counts: ReactionCounts) -> Self {
+    counts.0
+  }
+}
@@ -0,0 +1,55 @@
+use crate::newtypes::{
+  CommentId,
+  CommentReportId,
+  CommunityId,
+  PersonId,
+  PostId,
+  PostReportId,
+  PrivateMessageId,
+  PrivateMessageReportId,
+  ReportCombinedId,
+};
+#[cfg(feature = "full")]
+use crate::schema::report_combined;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = report_combined))]
+#[cfg_attr(feature = "full", ts(export))]
+/// An entry of the report queue, which groups all reports about the same post, comment or
+/// private message. It is kept up to date by the database.
+pub struct ReportCombined {
+  pub id: ReportCombinedId,
+  /// When the newest report was made.
+  pub published: DateTime<Utc>,
+  /// Not set for private messages.
+  pub community_id: Option<CommunityId>,
+  pub post_id: Option<PostId>,
+  pub comment_id: Option<CommentId>,
+  pub private_message_id: Option<PrivateMessageId>,
+  /// The newest report about the post.
+  pub post_report_id: Option<PostReportId>,
+  /// The newest report about the comment.
+  pub comment_report_id: Option<CommentReportId>,
+  /// The newest report about the private message.
+  pub private_message_report_id: Option<PrivateMessageReportId>,
+  pub report_count: i32,
+  pub unresolved_report_count: i32,
+  /// The mod who claimed the entry.
+  pub assignee_id: Option<PersonId>,
+  pub assigned: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "full", derive(AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = report_combined))]
+pub struct ReportCombinedUpdateForm {
+  pub assignee_id: Option<Option<PersonId>>,
+  pub assigned: Option<Option<DateTime<Utc>>>,
+}
@@ -0,0 +1,40 @@
+use crate::newtypes::{PersonId, ReportCombinedId, ReportNoteId};
+#[cfg(feature = "full")]
+use crate::schema::report_note;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+
+#[skip_serializing_none]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = report_note))]
+#[cfg_attr(feature = "full", ts(export))]
+/// An internal note of a moderator about a report queue entry, visible to the other mods.
+pub struct ReportNote {
+  pub id: ReportNoteId,
+  pub report_combined_id: ReportCombinedId,
+  pub creator_id: PersonId,
+  pub content: String,
+  pub published: DateTime<Utc>,
+  pub updated: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = report_note))]
+pub struct ReportNoteInsertForm {
+  pub report_combined_id: ReportCombinedId,
+  pub creator_id: PersonId,
+  pub content: String,
+}
+
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "full", derive(AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = report_note))]
+pub struct ReportNoteUpdateForm {
+  pub content: Option<String>,
+  pub updated: Option<Option<DateTime<Utc>>>,
+}
@@ -0,0 +1,110 @@
+#[cfg(feature = "full")]
+use crate::schema::{webhook, webhook_delivery, webhook_event};
+use crate::{
+  newtypes::{CommunityId, DbUrl, PersonId, WebhookId},
+  WebhookEventType,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "full")]
+use serde_json::Value;
+use serde_with::skip_serializing_none;
+#[cfg(feature = "full")]
+use ts_rs::TS;
+use typed_builder::TypedBuilder;
+
+#[skip_serializing_none]
+#[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = webhook))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A webhook which receives events of a community, or of the whole site.
+pub struct Webhook {
+  pub id: WebhookId,
+  pub creator_id: PersonId,
+  /// Set for the webhooks which the moderators of a community registered. Webhooks without a
+  /// community are managed by admins and receive the events of all communities.
+  pub community_id: Option<CommunityId>,
+  pub url: DbUrl,
+  /// Used to sign the payloads, it is never returned by the API.
+  #[serde(skip)]
+  pub secret: String,
+  pub events: Vec<WebhookEventType>,
+  pub enabled: bool,
+  pub published: DateTime<Utc>,
+  pub updated: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, TypedBuilder)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = webhook))]
+pub struct WebhookInsertForm {
+  pub creator_id: PersonId,
+  #[builder(default)]
+  pub community_id: Option<CommunityId>,
+  pub url: DbUrl,
+  pub secret: String,
+  pub events: Vec<WebhookEventType>,
+}
+
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "full", derive(AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = webhook))]
+pub struct WebhookUpdateForm {
+  pub url: Option<DbUrl>,
+  pub secret: Option<String>,
+  pub events: Option<Vec<WebhookEventType>>,
+  pub enabled: Option<bool>,
+  pub updated: Option<Option<DateTime<Utc>>>,
+}
+
+#[cfg(feature = "full")]
+#[derive(PartialEq, Eq, Debug, Clone, Queryable)]
+#[diesel(table_name = webhook_event)]
+/// An event in the delivery queue of the webhooks.
+pub struct WebhookEvent {
+  pub id: i64,
+  pub event_type: WebhookEventType,
+  pub community_id: Option<CommunityId>,
+  pub data: Value,
+  pub published: DateTime<Utc>,
+}
+
+#[cfg(feature = "full")]
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = webhook_event)]
+pub struct WebhookEventForm {
+  pub event_type: WebhookEventType,
+  pub community_id: Option<CommunityId>,
+  pub data: Value,
+}
+
+#[skip_serializing_none]
+#[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Identifiable, TS))]
+#[cfg_attr(feature = "full", diesel(table_name = webhook_delivery))]
+#[cfg_attr(feature = "full", ts(export))]
+/// An attempt to deliver an event to a webhook.
+pub struct WebhookDelivery {
+  pub id: i64,
+  pub webhook_id: WebhookId,
+  pub event_id: i64,
+  pub event_type: WebhookEventType,
+  pub success: bool,
+  /// The HTTP status of the response, if any was received.
+  pub status_code: Option<i32>,
+  pub error: Option<String>,
+  pub published: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = webhook_delivery))]
+pub struct WebhookDeliveryForm {
+  pub webhook_id: WebhookId,
+  pub event_id: i64,
+  pub event_type: WebhookEventType,
+  pub success: bool,
+  pub status_code: Option<i32>,
+  pub error: Option<String>,
+}
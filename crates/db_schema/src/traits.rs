@@ -151,6 +151,7 @@ pub trait Reportable {
     pool: &mut DbPool<'_>,
     report_id: Self::IdType,
     resolver_id: PersonId,
+    resolution_reason: Option<String>,
   ) -> Result<usize, Error>
   where
     Self: Sized;
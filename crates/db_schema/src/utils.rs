@@ -188,6 +188,34 @@ pub fn limit_and_offset(
   Ok((limit, offset))
 }
 
+/// Builds an opaque pagination cursor out of the values which identify the position in a list.
+/// The prefix tells the cursors of different lists apart, the values are hex encoded.
+pub fn format_cursor<const N: usize>(prefix: char, values: [i64; N]) -> String {
+  let values = values.map(|v| format!("{v:x}"));
+  format!("{prefix}{}", values.join("-"))
+}
+
+/// Reads back the values of a cursor which was built with [format_cursor], and converts them with
+/// the given function.
+pub fn parse_cursor<T, const N: usize>(
+  cursor: &str,
+  prefix: char,
+  convert: impl FnOnce([i64; N]) -> Option<T>,
+) -> Result<T, DieselError> {
+  let parse = || {
+    let mut parts = cursor.strip_prefix(prefix)?.split('-');
+    let mut values = [0; N];
+    for value in &mut values {
+      // negative values are formatted as two's complement
+      *value = u64::from_str_radix(parts.next()?, 16).ok()? as i64;
+    }
+    parts.next().is_none().then_some(values)
+  };
+  parse()
+    .and_then(convert)
+    .ok_or_else(|| QueryBuilderError("Could not parse pagination token".into()))
+}
+
 pub fn limit_and_offset_unlimited(page: Option<i64>, limit: Option<i64>) -> (i64, i64) {
   let limit = limit.unwrap_or(FETCH_LIMIT_DEFAULT);
   let offset = limit * (page.unwrap_or(1) - 1);
@@ -350,6 +378,7 @@ pub fn post_to_comment_sort_type(sort: SortType) -> CommentSortType {
     SortType::New | SortType::NewComments | SortType::MostComments => CommentSortType::New,
     SortType::Old => CommentSortType::Old,
     SortType::Controversial => CommentSortType::Controversial,
+    SortType::Relevance => CommentSortType::Relevance,
     SortType::TopHour
     | SortType::TopSixHour
     | SortType::TopTwelveHour
@@ -370,7 +399,7 @@ static EMAIL_REGEX: Lazy<Regex> = Lazy::new(|| {
 });
 
 pub mod functions {
-  use diesel::sql_types::{BigInt, Text, Timestamptz};
+  use diesel::sql_types::{BigInt, Bool, Nullable, Text, Timestamptz};
 
   sql_function! {
     fn hot_rank(score: BigInt, time: Timestamptz) -> Double;
@@ -386,10 +415,64 @@ pub mod functions {
 
   sql_function!(fn lower(x: Text) -> Text);
 
+  sql_function! {
+    fn keyword_filter_matches(content: Nullable<Text>, keyword: Text, is_regex: Bool) -> Bool;
+  }
+
   // really this function is variadic, this just adds the two-argument version
   sql_function!(fn coalesce<T: diesel::sql_types::SqlType + diesel::sql_types::SingleValue>(x: diesel::sql_types::Nullable<T>, y: T) -> T);
 }
 
+/// Helpers for the generated `search_vector` columns of post, comment, community and person.
+///
+/// These columns are deliberately not part of `schema.rs`, otherwise they would be loaded into
+/// the source structs on every query. `diesel_ltree.patch` removes them from the output of
+/// `diesel print-schema`, so that it still matches. The search term uses the
+/// `websearch_to_tsquery` syntax, so `"exact phrase"`, `-excluded` and `or` work as expected.
+pub mod full_text_search {
+  use diesel::{
+    dsl::sql,
+    expression::{SqlLiteral, TypedExpressionType, UncheckedBind},
+    helper_types::AsExprOf,
+    sql_types::{Bool, Float, Text},
+  };
+
+  pub type SearchExpression<ST> =
+    SqlLiteral<ST, UncheckedBind<SqlLiteral<ST>, AsExprOf<String, Text>>>;
+
+  fn search_expression<ST: TypedExpressionType>(
+    prefix: &str,
+    search_term: &str,
+  ) -> SearchExpression<ST> {
+    sql::<ST>(prefix)
+      .bind::<Text, _>(search_term.to_string())
+      .sql("))")
+  }
+
+  /// Whether the `search_vector` of the given table matches the search term.
+  pub fn matches(table: &'static str, search_term: &str) -> SearchExpression<Bool> {
+    search_expression(
+      &format!("({table}.search_vector @@ websearch_to_tsquery('simple', "),
+      search_term,
+    )
+  }
+
+  /// How well the `search_vector` of the given table matches the search term, higher is better.
+  pub fn rank(table: &'static str, search_term: &str) -> SearchExpression<Float> {
+    search_expression(
+      &format!("ts_rank_cd({table}.search_vector, websearch_to_tsquery('simple', "),
+      search_term,
+    )
+  }
+
+  /// The same rank for every row, for when there is no search term. It has the same type as
+  /// [rank], so that either can be used in a query.
+  pub fn no_rank() -> SearchExpression<Float> {
+    // the length of the empty string is always zero
+    search_expression("float4(length(", "")
+  }
+}
+
 pub const DELETED_REPLACEMENT_TEXT: &str = "*Permanently Deleted*";
 
 impl ToSql<Text, Pg> for DbUrl {
@@ -507,6 +590,27 @@ mod tests {
     assert!(!is_email_regex("nada_neutho"));
   }
 
+  #[test]
+  fn test_cursor() {
+    let cursor = format_cursor('T', [1_700_000_000_000_000, 26, -1]);
+    assert_eq!("T60a24181e4000-1a-ffffffffffffffff", cursor);
+    assert_eq!(
+      [1_700_000_000_000_000, 26, -1],
+      parse_cursor(&cursor, 'T', Some).unwrap()
+    );
+    let id_cursor = format_cursor('T', [26]);
+    let id = parse_cursor(&id_cursor, 'T', |[id]| i32::try_from(id).ok()).unwrap();
+    assert_eq!(26, id);
+
+    // The prefix and number of values need to match
+    assert!(parse_cursor(&cursor, 'P', Some::<[i64; 3]>).is_err());
+    assert!(parse_cursor(&cursor, 'T', Some::<[i64; 2]>).is_err());
+    assert!(parse_cursor("T1-x-2", 'T', Some::<[i64; 3]>).is_err());
+    // Values which can't be converted are rejected too
+    let too_large = format_cursor('T', [i64::from(i32::MAX) + 1]);
+    assert!(parse_cursor(&too_large, 'T', |[id]| i32::try_from(id).ok()).is_err());
+  }
+
   #[test]
   fn test_diesel_option_overwrite() {
     assert_eq!(diesel_option_overwrite(None), None);
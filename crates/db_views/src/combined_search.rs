@@ -0,0 +1,519 @@
+use crate::{
+  comment_view::{comment_joins, filter_comment_list, CommentQuery},
+  post_view::{filter_post_list, post_joins, PostQuery},
+  structs::{LocalUserView, PaginationCursor},
+};
+use diesel::{
+  dsl::{self, exists, not},
+  query_dsl::methods::FilterDsl,
+  result::Error,
+  sql_types::{Float, Integer},
+  BoolExpressionMethods,
+  CombineDsl,
+  Expression,
+  ExpressionMethods,
+  IntoSql,
+  PgTextExpressionMethods,
+  QueryDsl,
+  Queryable,
+};
+use diesel_async::RunQueryDsl;
+use lemmy_db_schema::{
+  newtypes::{CommentId, CommunityId, PersonId, PostId},
+  schema::{
+    comment,
+    community,
+    community_block,
+    instance_block,
+    person,
+    person_block,
+    post_aggregates,
+  },
+  utils::{
+    format_cursor,
+    full_text_search::{self, SearchExpression},
+    fuzzy_search,
+    get_conn,
+    limit_and_offset,
+    parse_cursor,
+    DbPool,
+  },
+  ListingType,
+};
+
+const KIND_POST: i32 = 0;
+const KIND_COMMENT: i32 = 1;
+const KIND_COMMUNITY: i32 = 2;
+const KIND_PERSON: i32 = 3;
+
+/// The id of a single combined search result, which needs to be read with the matching view.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchResultId {
+  Post(PostId),
+  Comment(CommentId),
+  Community(CommunityId),
+  Person(PersonId),
+}
+
+#[derive(Queryable, Debug, Clone, Copy)]
+struct CombinedSearchRow {
+  kind: i32,
+  id: i32,
+  rank: f32,
+}
+
+/// A single combined search result, together with its position in the result list.
+#[derive(Debug, Clone, Copy)]
+pub struct CombinedSearchResult {
+  pub id: SearchResultId,
+  rank: f32,
+  kind: i32,
+  raw_id: i32,
+}
+
+impl TryFrom<CombinedSearchRow> for CombinedSearchResult {
+  type Error = Error;
+
+  fn try_from(row: CombinedSearchRow) -> Result<Self, Self::Error> {
+    let id = match row.kind {
+      KIND_POST => SearchResultId::Post(PostId(row.id)),
+      KIND_COMMENT => SearchResultId::Comment(CommentId(row.id)),
+      KIND_COMMUNITY => SearchResultId::Community(CommunityId(row.id)),
+      KIND_PERSON => SearchResultId::Person(PersonId(row.id)),
+      _ => {
+        return Err(Error::QueryBuilderError(
+          "Unknown search result type".into(),
+        ))
+      }
+    };
+    Ok(CombinedSearchResult {
+      id,
+      rank: row.rank,
+      kind: row.kind,
+      raw_id: row.id,
+    })
+  }
+}
+
+impl PaginationCursor {
+  pub fn after_search_result(result: &CombinedSearchResult) -> PaginationCursor {
+    // the rank is stored as its bit pattern so that it is read back exactly
+    PaginationCursor(format_cursor(
+      'S',
+      [
+        i64::from(result.rank.to_bits()),
+        i64::from(result.kind),
+        i64::from(result.raw_id),
+      ],
+    ))
+  }
+
+  fn read_search_result(&self) -> Result<(f32, i32, i32), Error> {
+    parse_cursor(&self.0, 'S', |[rank, kind, id]| {
+      let rank = f32::from_bits(u32::try_from(rank).ok()?);
+      Some((rank, i32::try_from(kind).ok()?, i32::try_from(id).ok()?))
+    })
+  }
+}
+
+#[derive(Default)]
+pub struct CombinedSearchQuery<'a> {
+  /// Without a search term, all items match and they are ordered by kind and id
+  pub search_term: Option<String>,
+  pub community_id: Option<CommunityId>,
+  pub creator_id: Option<PersonId>,
+  pub local_only: bool,
+  /// The user who is searching, whose settings and permissions apply like in the post and
+  /// comment listings
+  pub local_user: Option<&'a LocalUserView>,
+  pub page_after: Option<PaginationCursor>,
+  pub limit: Option<i64>,
+}
+
+/// Only keeps the results of the given kind which come after the cursor. Results are ordered by
+/// rank, then by kind and id, and the kind is the same for the whole query.
+fn filter_page_after<Q, I>(
+  query: Q,
+  kind: i32,
+  rank: impl Fn() -> SearchExpression<Float>,
+  id: I,
+  page_after: Option<(f32, i32, i32)>,
+) -> Q
+where
+  Q: FilterDsl<dsl::Lt<SearchExpression<Float>, f32>, Output = Q>
+    + FilterDsl<dsl::LtEq<SearchExpression<Float>, f32>, Output = Q>
+    + FilterDsl<
+      dsl::Or<
+        dsl::Lt<SearchExpression<Float>, f32>,
+        dsl::And<dsl::Eq<SearchExpression<Float>, f32>, dsl::Lt<I, i32>>,
+      >,
+      Output = Q,
+    >,
+  I: Expression<SqlType = Integer>,
+{
+  let Some((after_rank, after_kind, after_id)) = page_after else {
+    return query;
+  };
+  if kind < after_kind {
+    query.filter(rank().lt(after_rank))
+  } else if kind > after_kind {
+    query.filter(rank().le(after_rank))
+  } else {
+    query.filter(
+      rank()
+        .lt(after_rank)
+        .or(rank().eq(after_rank).and(id.lt(after_id))),
+    )
+  }
+}
+
+impl<'a> CombinedSearchQuery<'a> {
+  /// The post listing with the same filters as this search, which is also used to read the posts
+  /// of the results.
+  pub fn post_query(&self) -> PostQuery<'a> {
+    PostQuery {
+      listing_type: self.local_only.then_some(ListingType::Local),
+      community_id: self.community_id,
+      creator_id: self.creator_id,
+      local_user: self.local_user,
+      search_term: self.search_term.clone(),
+      ..Default::default()
+    }
+  }
+
+  /// The comment listing with the same filters as this search, which is also used to read the
+  /// comments of the results.
+  pub fn comment_query(&self) -> CommentQuery<'a> {
+    CommentQuery {
+      listing_type: self.local_only.then_some(ListingType::Local),
+      community_id: self.community_id,
+      creator_id: self.creator_id,
+      local_user: self.local_user,
+      search_term: self.search_term.clone(),
+      ..Default::default()
+    }
+  }
+
+  /// Ranks posts, comments, communities and persons against the same query, so that all of them
+  /// can be returned in a single list. Posts and comments are filtered exactly like in
+  /// [PostQuery] and [CommentQuery], and blocked communities and persons are left out.
+  pub async fn list(&self, pool: &mut DbPool<'_>) -> Result<Vec<CombinedSearchResult>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let (limit, _) = limit_and_offset(None, self.limit)?;
+    let page_after = self
+      .page_after
+      .as_ref()
+      .map(PaginationCursor::read_search_result)
+      .transpose()?;
+    let search_term = self.search_term.as_deref();
+    let rank = |table: &'static str| match search_term {
+      Some(search_term) => full_text_search::rank(table, search_term),
+      None => full_text_search::no_rank(),
+    };
+    // The subqueries below won't match anything in this case
+    let person_id_join = self.local_user.map(|l| l.person.id).unwrap_or(PersonId(-1));
+
+    // Every part of the union is sorted and limited by itself, the results are merged below
+    let post_rank = || rank("post");
+    let mut posts = filter_post_list(
+      post_joins(post_aggregates::table.into_boxed()),
+      &self.post_query(),
+    )
+    .select((
+      KIND_POST.into_sql::<Integer>(),
+      post_aggregates::post_id,
+      post_rank(),
+    ))
+    .order_by(post_rank().desc())
+    .then_order_by(post_aggregates::post_id.desc())
+    .limit(limit);
+    // The post listing only applies the listing type for logged in users
+    if self.local_only {
+      posts = posts.filter(community::local.eq(true));
+    }
+    let posts = filter_page_after(
+      posts,
+      KIND_POST,
+      post_rank,
+      post_aggregates::post_id,
+      page_after,
+    );
+
+    // Deleted and removed comments are only hidden by the frontend in the comment listing
+    let comment_rank = || rank("comment");
+    let comments = filter_comment_list(
+      comment_joins(comment::table.into_boxed()),
+      &self.comment_query(),
+    )
+    .filter(comment::deleted.eq(false))
+    .filter(comment::removed.eq(false))
+    .select((
+      KIND_COMMENT.into_sql::<Integer>(),
+      comment::id,
+      comment_rank(),
+    ))
+    .order_by(comment_rank().desc())
+    .then_order_by(comment::id.desc())
+    .limit(limit);
+    let comments = filter_page_after(
+      comments,
+      KIND_COMMENT,
+      comment_rank,
+      comment::id,
+      page_after,
+    );
+
+    let posts_and_comments = posts.union_all(comments);
+    // Communities and persons aren't returned when searching within a community or for a creator
+    let mut rows = if self.community_id.is_none() && self.creator_id.is_none() {
+      let show_nsfw = self
+        .local_user
+        .map(|l| l.local_user.show_nsfw)
+        .unwrap_or(false);
+
+      let community_rank = || rank("community");
+      let mut communities = community::table
+        .filter(community::deleted.eq(false))
+        .filter(community::removed.eq(false))
+        .filter(community::hidden.eq(false))
+        .select((
+          KIND_COMMUNITY.into_sql::<Integer>(),
+          community::id,
+          community_rank(),
+        ))
+        .order_by(community_rank().desc())
+        .then_order_by(community::id.desc())
+        .limit(limit)
+        .into_boxed();
+      if let Some(search_term) = search_term {
+        // Full-text search only matches whole words, so names are also matched as substrings
+        let searcher = fuzzy_search(search_term);
+        communities = communities.filter(
+          full_text_search::matches("community", search_term)
+            .or(community::name.ilike(searcher.clone()))
+            .or(community::title.ilike(searcher)),
+        );
+      }
+      if self.local_only {
+        communities = communities.filter(community::local.eq(true));
+      }
+      if !show_nsfw {
+        communities = communities.filter(community::nsfw.eq(false));
+      }
+      if self.local_user.is_some() {
+        communities = communities
+          .filter(not(exists(
+            community_block::table.filter(
+              community::id
+                .eq(community_block::community_id)
+                .and(community_block::person_id.eq(person_id_join)),
+            ),
+          )))
+          .filter(not(exists(
+            instance_block::table.filter(
+              community::instance_id
+                .eq(instance_block::instance_id)
+                .and(instance_block::person_id.eq(person_id_join)),
+            ),
+          )));
+      }
+      let communities = filter_page_after(
+        communities,
+        KIND_COMMUNITY,
+        community_rank,
+        community::id,
+        page_after,
+      );
+
+      let person_rank = || rank("person");
+      let mut persons = person::table
+        .filter(person::deleted.eq(false))
+        .select((KIND_PERSON.into_sql::<Integer>(), person::id, person_rank()))
+        .order_by(person_rank().desc())
+        .then_order_by(person::id.desc())
+        .limit(limit)
+        .into_boxed();
+      if let Some(search_term) = search_term {
+        let searcher = fuzzy_search(search_term);
+        persons = persons.filter(
+          full_text_search::matches("person", search_term)
+            .or(person::name.ilike(searcher.clone()))
+            .or(person::display_name.ilike(searcher)),
+        );
+      }
+      if self.local_only {
+        persons = persons.filter(person::local.eq(true));
+      }
+      if self.local_user.is_some() {
+        persons = persons.filter(not(exists(
+          person_block::table.filter(
+            person::id
+              .eq(person_block::target_id)
+              .and(person_block::person_id.eq(person_id_join)),
+          ),
+        )));
+      }
+      let persons = filter_page_after(persons, KIND_PERSON, person_rank, person::id, page_after);
+
+      posts_and_comments
+        .union_all(communities)
+        .union_all(persons)
+        .load::<CombinedSearchRow>(conn)
+        .await?
+    } else {
+      posts_and_comments.load::<CombinedSearchRow>(conn).await?
+    };
+
+    rows.sort_by(|a, b| {
+      b.rank
+        .total_cmp(&a.rank)
+        .then(a.kind.cmp(&b.kind))
+        .then(b.id.cmp(&a.id))
+    });
+    rows
+      .into_iter()
+      .take(limit as usize)
+      .map(TryInto::try_into)
+      .collect()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  #![allow(clippy::unwrap_used)]
+  #![allow(clippy::indexing_slicing)]
+
+  use crate::{
+    combined_search::{CombinedSearchQuery, SearchResultId},
+    structs::PaginationCursor,
+  };
+  use lemmy_db_schema::{
+    source::{
+      comment::{Comment, CommentInsertForm},
+      community::{Community, CommunityInsertForm},
+      instance::Instance,
+      person::{Person, PersonInsertForm},
+      post::{Post, PostInsertForm},
+    },
+    traits::Crud,
+    utils::build_db_pool_for_tests,
+  };
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_combined_search() {
+    let pool = &build_db_pool_for_tests().await;
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld".to_string())
+      .await
+      .unwrap();
+
+    let new_person = PersonInsertForm::builder()
+      .name("rustacean".to_string())
+      .public_key("pubkey".to_string())
+      .instance_id(inserted_instance.id)
+      .build();
+    let inserted_person = Person::create(pool, &new_person).await.unwrap();
+
+    let new_community = CommunityInsertForm::builder()
+      .name("rust".to_string())
+      .title("The rust programming language".to_owned())
+      .public_key("pubkey".to_string())
+      .instance_id(inserted_instance.id)
+      .build();
+    let inserted_community = Community::create(pool, &new_community).await.unwrap();
+
+    let new_post = PostInsertForm::builder()
+      .name("Learning rust".to_string())
+      .creator_id(inserted_person.id)
+      .community_id(inserted_community.id)
+      .build();
+    let inserted_post = Post::create(pool, &new_post).await.unwrap();
+
+    let new_comment = CommentInsertForm::builder()
+      .content("I like rust a lot, and also go".to_string())
+      .creator_id(inserted_person.id)
+      .post_id(inserted_post.id)
+      .build();
+    let inserted_comment = Comment::create(pool, &new_comment, None).await.unwrap();
+
+    let all_results = CombinedSearchQuery {
+      search_term: Some("rust".to_string()),
+      ..Default::default()
+    }
+    .list(pool)
+    .await
+    .unwrap();
+    let all_ids = all_results.iter().map(|r| r.id).collect::<Vec<_>>();
+    assert_eq!(3, all_ids.len());
+    assert!(all_ids.contains(&SearchResultId::Post(inserted_post.id)));
+    assert!(all_ids.contains(&SearchResultId::Comment(inserted_comment.id)));
+    assert!(all_ids.contains(&SearchResultId::Community(inserted_community.id)));
+
+    // Page through the results one by one, which needs to give the same order
+    let mut paged_ids = vec![];
+    let mut page_after = None;
+    loop {
+      let page = CombinedSearchQuery {
+        search_term: Some("rust".to_string()),
+        page_after,
+        limit: Some(1),
+        ..Default::default()
+      }
+      .list(pool)
+      .await
+      .unwrap();
+      let Some(last) = page.last() else {
+        break;
+      };
+      paged_ids.push(last.id);
+      page_after = Some(PaginationCursor::after_search_result(last));
+    }
+    assert_eq!(all_ids, paged_ids);
+
+    // Communities and persons aren't returned when searching within a community
+    let community_results = CombinedSearchQuery {
+      search_term: Some("rust".to_string()),
+      community_id: Some(inserted_community.id),
+      ..Default::default()
+    }
+    .list(pool)
+    .await
+    .unwrap();
+    assert_eq!(2, community_results.len());
+
+    // Without a search term everything matches, here all the content of the creator
+    let creator_results = CombinedSearchQuery {
+      creator_id: Some(inserted_person.id),
+      ..Default::default()
+    }
+    .list(pool)
+    .await
+    .unwrap();
+    let creator_ids = creator_results.iter().map(|r| r.id).collect::<Vec<_>>();
+    assert_eq!(
+      vec![
+        SearchResultId::Post(inserted_post.id),
+        SearchResultId::Comment(inserted_comment.id)
+      ],
+      creator_ids
+    );
+
+    let invalid_cursor = CombinedSearchQuery {
+      search_term: Some("rust".to_string()),
+      page_after: Some(PaginationCursor("P1".to_string())),
+      ..Default::default()
+    }
+    .list(pool)
+    .await;
+    assert!(invalid_cursor.is_err());
+
+    Person::delete(pool, inserted_person.id).await.unwrap();
+    Community::delete(pool, inserted_community.id)
+      .await
+      .unwrap();
+    Instance::delete(pool, inserted_instance.id).await.unwrap();
+  }
+}
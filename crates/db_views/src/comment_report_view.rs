@@ -1,6 +1,6 @@
 use crate::structs::{CommentReportView, LocalUserView};
 use diesel::{
-  dsl::now,
+  dsl::{exists, now},
   pg::Pg,
   result::Error,
   BoolExpressionMethods,
@@ -18,6 +18,7 @@ use lemmy_db_schema::{
     comment_aggregates,
     comment_like,
     comment_report,
+    comment_revision,
     community,
     community_moderator,
     community_person_ban,
@@ -65,6 +66,13 @@ fn queries<'a>() -> Queries<
     community_person_ban::id.nullable().is_not_null(),
     comment_like::score.nullable(),
     aliases::person2.fields(person::all_columns).nullable(),
+    exists(
+      comment_revision::table.filter(
+        comment_revision::comment_id
+          .eq(comment_report::comment_id)
+          .and(comment_revision::edited.ge(comment_report::published)),
+      ),
+    ),
   );
 
   let read = move |mut conn: DbConn<'a>, (report_id, my_person_id): (CommentReportId, PersonId)| async move {
@@ -105,10 +113,18 @@ fn queries<'a>() -> Queries<
       query = query.filter(post::community_id.eq(community_id));
     }
 
+    if let Some(reported_person_id) = options.reported_person_id {
+      query = query.filter(comment::creator_id.eq(reported_person_id));
+    }
+
     if options.unresolved_only {
       query = query.filter(comment_report::resolved.eq(false));
     }
 
+    if options.resolved_only {
+      query = query.filter(comment_report::resolved.eq(true));
+    }
+
     let (limit, offset) = limit_and_offset(options.page, options.limit)?;
 
     query = query
@@ -194,9 +210,12 @@ impl CommentReportView {
 #[derive(Default)]
 pub struct CommentReportQuery {
   pub community_id: Option<CommunityId>,
+  /// Only reports about content of this person
+  pub reported_person_id: Option<PersonId>,
   pub page: Option<i64>,
   pub limit: Option<i64>,
   pub unresolved_only: bool,
+  pub resolved_only: bool,
 }
 
 impl CommentReportQuery {
@@ -231,6 +250,7 @@ mod tests {
     },
     traits::{Crud, Joinable, Reportable},
     utils::build_db_pool_for_tests,
+    CommunityVisibility,
   };
   use serial_test::serial;
 
@@ -321,6 +341,7 @@ mod tests {
       comment_id: inserted_comment.id,
       original_comment_text: "this was it at time of creation".into(),
       reason: "from sara".into(),
+      ap_id: None,
     };
 
     let inserted_sara_report = CommentReport::report(pool, &sara_report_form)
@@ -333,6 +354,7 @@ mod tests {
       comment_id: inserted_comment.id,
       original_comment_text: "this was it at time of creation".into(),
       reason: "from jessica".into(),
+      ap_id: None,
     };
 
     let inserted_jessica_report = CommentReport::report(pool, &jessica_report_form)
@@ -375,6 +397,10 @@ mod tests {
         shared_inbox_url: inserted_community.shared_inbox_url,
         moderators_url: inserted_community.moderators_url,
         featured_url: inserted_community.featured_url,
+        visibility: CommunityVisibility::Public,
+        warning_ban_threshold: None,
+        warning_ban_window_days: 30,
+        warning_ban_days: 7,
         instance_id: inserted_instance.id,
       },
       creator: Person {
@@ -396,6 +422,7 @@ mod tests {
         matrix_user_id: None,
         ban_expires: None,
         instance_id: inserted_instance.id,
+        encryption_public_key: None,
         private_key: inserted_jessica.private_key,
         public_key: inserted_jessica.public_key,
         last_refreshed_at: inserted_jessica.last_refreshed_at,
@@ -419,6 +446,7 @@ mod tests {
         matrix_user_id: None,
         ban_expires: None,
         instance_id: inserted_instance.id,
+        encryption_public_key: None,
         private_key: inserted_timmy.private_key.clone(),
         public_key: inserted_timmy.public_key.clone(),
         last_refreshed_at: inserted_timmy.last_refreshed_at,
@@ -434,9 +462,11 @@ mod tests {
         child_count: 0,
         hot_rank: 0.1728,
         controversy_rank: 0.0,
+        reaction_counts: Default::default(),
       },
       my_vote: None,
       resolver: None,
+      edited_after_report: false,
     };
 
     assert_eq!(read_jessica_report_view, expected_jessica_report_view);
@@ -462,6 +492,7 @@ mod tests {
       matrix_user_id: None,
       ban_expires: None,
       instance_id: inserted_instance.id,
+      encryption_public_key: None,
       private_key: inserted_sara.private_key,
       public_key: inserted_sara.public_key,
       last_refreshed_at: inserted_sara.last_refreshed_at,
@@ -488,7 +519,7 @@ mod tests {
     assert_eq!(2, report_count);
 
     // Try to resolve the report
-    CommentReport::resolve(pool, inserted_jessica_report.id, inserted_timmy.id)
+    CommentReport::resolve(pool, inserted_jessica_report.id, inserted_timmy.id, None)
       .await
       .unwrap();
     let read_jessica_report_view_after_resolve =
@@ -530,6 +561,7 @@ mod tests {
       matrix_user_id: None,
       ban_expires: None,
       instance_id: inserted_instance.id,
+      encryption_public_key: None,
     });
 
     assert_eq!(
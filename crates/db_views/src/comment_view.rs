@@ -1,12 +1,14 @@
 use crate::structs::{CommentView, LocalUserView};
 use diesel::{
+  dsl::{self, exists, not, now},
   pg::Pg,
   result::Error,
+  sql_types::Timestamptz,
   BoolExpressionMethods,
   ExpressionMethods,
+  IntoSql,
   JoinOnDsl,
   NullableExpressionMethods,
-  PgTextExpressionMethods,
   QueryDsl,
 };
 use diesel_async::RunQueryDsl;
@@ -24,29 +26,251 @@ use lemmy_db_schema::{
     community_moderator,
     community_person_ban,
     instance_block,
+    local_user_keyword_filter,
     local_user_language,
     person,
     person_block,
+    person_follower,
     post,
   },
   source::community::CommunityFollower,
-  utils::{fuzzy_search, limit_and_offset, DbConn, DbPool, ListFn, Queries, ReadFn},
+  utils::{
+    full_text_search,
+    functions::keyword_filter_matches,
+    limit_and_offset,
+    DbConn,
+    DbPool,
+    ListFn,
+    Queries,
+    ReadFn,
+  },
   CommentSortType,
+  CommunityVisibility,
   ListingType,
 };
 
+/// The tables which are needed to filter the comments of a listing.
+pub(crate) type CommentListQuery<'a> = dsl::InnerJoin<
+  dsl::InnerJoinOn<
+    dsl::InnerJoin<dsl::InnerJoin<comment::BoxedQuery<'a, Pg>, person::table>, post::table>,
+    community::table,
+    dsl::Eq<post::community_id, community::id>,
+  >,
+  comment_aggregates::table,
+>;
+
+pub(crate) fn comment_joins(query: comment::BoxedQuery<'_, Pg>) -> CommentListQuery<'_> {
+  query
+    .inner_join(person::table)
+    .inner_join(post::table)
+    .inner_join(community::table.on(post::community_id.eq(community::id)))
+    .inner_join(comment_aggregates::table)
+}
+
+/// Applies the filters of the given options, without any sorting or paging. This is shared with
+/// the combined search, so that both show the same comments.
+pub(crate) fn filter_comment_list<'a>(
+  mut query: CommentListQuery<'a>,
+  options: &CommentQuery<'a>,
+) -> CommentListQuery<'a> {
+  let person_id = options.local_user.map(|l| l.person.id);
+  let local_user_id = options.local_user.map(|l| l.local_user.id);
+
+  // The subqueries below won't match anything in this case
+  let person_id_join = person_id.unwrap_or(PersonId(-1));
+  let local_user_id_join = local_user_id.unwrap_or(LocalUserId(-1));
+
+  let is_follower = || {
+    exists(
+      community_follower::table.filter(
+        post::community_id
+          .eq(community_follower::community_id)
+          .and(community_follower::person_id.eq(person_id_join)),
+      ),
+    )
+  };
+  let is_community_moderator = || {
+    exists(
+      community_moderator::table.filter(
+        post::community_id
+          .eq(community_moderator::community_id)
+          .and(community_moderator::person_id.eq(person_id_join)),
+      ),
+    )
+  };
+  let my_score_is = |score: i16| {
+    exists(
+      comment_like::table.filter(
+        comment::id
+          .eq(comment_like::comment_id)
+          .and(comment_like::person_id.eq(person_id_join))
+          .and(comment_like::score.eq(score)),
+      ),
+    )
+  };
+
+  if let Some(creator_id) = options.creator_id {
+    query = query.filter(comment::creator_id.eq(creator_id));
+  };
+
+  if let Some(post_id) = options.post_id {
+    query = query.filter(comment::post_id.eq(post_id));
+  };
+
+  if let Some(ids) = &options.ids {
+    query = query.filter(comment::id.eq_any(ids.clone()));
+  };
+
+  if let Some(parent_path) = options.parent_path.as_ref() {
+    query = query.filter(comment::path.contained_by(parent_path));
+  };
+
+  if let Some(search_term) = &options.search_term {
+    query = query.filter(full_text_search::matches("comment", search_term));
+  };
+
+  if let Some(community_id) = options.community_id {
+    query = query.filter(post::community_id.eq(community_id));
+  }
+
+  // Comments of private communities are only visible to approved followers and moderators,
+  // those of local-only communities only to logged in users
+  if let Some(local_user) = options.local_user {
+    if !local_user.local_user.admin {
+      query = query.filter(
+        community::visibility
+          .ne(CommunityVisibility::Private)
+          .or(exists(
+            community_follower::table.filter(
+              post::community_id
+                .eq(community_follower::community_id)
+                .and(community_follower::person_id.eq(person_id_join))
+                .and(community_follower::pending.eq(false)),
+            ),
+          ))
+          .or(is_community_moderator()),
+      );
+    }
+  } else {
+    query = query.filter(community::visibility.eq(CommunityVisibility::Public));
+  }
+
+  if let Some(listing_type) = options.listing_type {
+    match listing_type {
+      ListingType::Subscribed => query = query.filter(is_follower()),
+      ListingType::Local => {
+        query = query
+          .filter(community::local.eq(true))
+          .filter(community::hidden.eq(false).or(is_follower()))
+      }
+      ListingType::All => query = query.filter(community::hidden.eq(false).or(is_follower())),
+      ListingType::ModeratorView => {
+        query = query.filter(is_community_moderator());
+      }
+      ListingType::FollowedPeople => {
+        query = query.filter(exists(
+          person_follower::table.filter(
+            comment::creator_id
+              .eq(person_follower::person_id)
+              .and(person_follower::follower_id.eq(person_id_join))
+              .and(person_follower::pending.eq(false)),
+          ),
+        ));
+      }
+    }
+  }
+
+  if options.saved_only {
+    query = query.filter(exists(
+      comment_saved::table.filter(
+        comment::id
+          .eq(comment_saved::comment_id)
+          .and(comment_saved::person_id.eq(person_id_join)),
+      ),
+    ));
+  }
+
+  if options.liked_only {
+    query = query.filter(my_score_is(1));
+  } else if options.disliked_only {
+    query = query.filter(my_score_is(-1));
+  }
+
+  if !options
+    .local_user
+    .map(|l| l.local_user.show_bot_accounts)
+    .unwrap_or(true)
+  {
+    query = query.filter(person::bot_account.eq(false));
+  };
+
+  if options.local_user.is_some()
+    && options.listing_type.unwrap_or_default() != ListingType::ModeratorView
+  {
+    // Filter out the rows with missing languages
+    query = query.filter(exists(
+      local_user_language::table.filter(
+        comment::language_id
+          .eq(local_user_language::language_id)
+          .and(local_user_language::local_user_id.eq(local_user_id_join)),
+      ),
+    ));
+
+    // Don't show blocked communities or persons
+    if options.post_id.is_none() {
+      query = query.filter(not(exists(
+        instance_block::table.filter(
+          community::instance_id
+            .eq(instance_block::instance_id)
+            .and(instance_block::person_id.eq(person_id_join)),
+        ),
+      )));
+      query = query.filter(not(exists(
+        community_block::table.filter(
+          community::id
+            .eq(community_block::community_id)
+            .and(community_block::person_id.eq(person_id_join)),
+        ),
+      )));
+    }
+    query = query.filter(not(exists(
+      person_block::table.filter(
+        comment::creator_id
+          .eq(person_block::target_id)
+          .and(person_block::person_id.eq(person_id_join)),
+      ),
+    )));
+
+    // Don't show comments which match one of the user's keyword filters
+    query = query.filter(not(exists(
+      local_user_keyword_filter::table.filter(
+        local_user_keyword_filter::local_user_id
+          .eq(local_user_id_join)
+          .and(local_user_keyword_filter::filter_body)
+          .and(
+            local_user_keyword_filter::expires
+              .is_null()
+              .or(local_user_keyword_filter::expires.gt(now.into_sql::<Timestamptz>().nullable())),
+          )
+          .and(keyword_filter_matches(
+            comment::content.nullable(),
+            local_user_keyword_filter::keyword,
+            local_user_keyword_filter::is_regex,
+          )),
+      ),
+    )));
+  }
+  query
+}
+
 fn queries<'a>() -> Queries<
   impl ReadFn<'a, CommentView, (CommentId, Option<PersonId>)>,
   impl ListFn<'a, CommentView, CommentQuery<'a>>,
 > {
-  let all_joins = |query: comment::BoxedQuery<'a, Pg>, my_person_id: Option<PersonId>| {
+  let all_joins = |query: CommentListQuery<'a>, my_person_id: Option<PersonId>| {
     // The left join below will return None in this case
     let person_id_join = my_person_id.unwrap_or(PersonId(-1));
     query
-      .inner_join(person::table)
-      .inner_join(post::table)
-      .inner_join(community::table.on(post::community_id.eq(community::id)))
-      .inner_join(comment_aggregates::table)
       .left_join(
         community_person_ban::table.on(
           community::id
@@ -82,14 +306,6 @@ fn queries<'a>() -> Queries<
             .and(comment_like::person_id.eq(person_id_join)),
         ),
       )
-      .left_join(
-        community_moderator::table.on(
-          post::id
-            .eq(comment::post_id)
-            .and(post::community_id.eq(community_moderator::community_id))
-            .and(community_moderator::person_id.eq(person_id_join)),
-        ),
-      )
   };
 
   let selection = (
@@ -107,118 +323,21 @@ fn queries<'a>() -> Queries<
 
   let read = move |mut conn: DbConn<'a>,
                    (comment_id, my_person_id): (CommentId, Option<PersonId>)| async move {
-    all_joins(comment::table.find(comment_id).into_boxed(), my_person_id)
-      .select(selection)
-      .first::<CommentView>(&mut conn)
-      .await
+    all_joins(
+      comment_joins(comment::table.find(comment_id).into_boxed()),
+      my_person_id,
+    )
+    .select(selection)
+    .first::<CommentView>(&mut conn)
+    .await
   };
 
   let list = move |mut conn: DbConn<'a>, options: CommentQuery<'a>| async move {
-    let person_id = options.local_user.map(|l| l.person.id);
-    let local_user_id = options.local_user.map(|l| l.local_user.id);
-
-    // The left join below will return None in this case
-    let person_id_join = person_id.unwrap_or(PersonId(-1));
-    let local_user_id_join = local_user_id.unwrap_or(LocalUserId(-1));
-
-    let mut query = all_joins(comment::table.into_boxed(), person_id)
-      .left_join(
-        instance_block::table.on(
-          community::instance_id
-            .eq(instance_block::instance_id)
-            .and(instance_block::person_id.eq(person_id_join)),
-        ),
-      )
-      .left_join(
-        community_block::table.on(
-          community::id
-            .eq(community_block::community_id)
-            .and(community_block::person_id.eq(person_id_join)),
-        ),
-      )
-      .left_join(
-        local_user_language::table.on(
-          comment::language_id
-            .eq(local_user_language::language_id)
-            .and(local_user_language::local_user_id.eq(local_user_id_join)),
-        ),
-      )
-      .select(selection);
-
-    if let Some(creator_id) = options.creator_id {
-      query = query.filter(comment::creator_id.eq(creator_id));
-    };
-
-    if let Some(post_id) = options.post_id {
-      query = query.filter(comment::post_id.eq(post_id));
-    };
-
-    if let Some(parent_path) = options.parent_path.as_ref() {
-      query = query.filter(comment::path.contained_by(parent_path));
-    };
-
-    if let Some(search_term) = options.search_term {
-      query = query.filter(comment::content.ilike(fuzzy_search(&search_term)));
-    };
-
-    if let Some(community_id) = options.community_id {
-      query = query.filter(post::community_id.eq(community_id));
-    }
-
-    if let Some(listing_type) = options.listing_type {
-      match listing_type {
-        ListingType::Subscribed => query = query.filter(community_follower::pending.is_not_null()), // TODO could be this: and(community_follower::person_id.eq(person_id_join)),
-        ListingType::Local => {
-          query = query.filter(community::local.eq(true)).filter(
-            community::hidden
-              .eq(false)
-              .or(community_follower::person_id.eq(person_id_join)),
-          )
-        }
-        ListingType::All => {
-          query = query.filter(
-            community::hidden
-              .eq(false)
-              .or(community_follower::person_id.eq(person_id_join)),
-          )
-        }
-        ListingType::ModeratorView => {
-          query = query.filter(community_moderator::person_id.is_not_null());
-        }
-      }
-    }
-
-    if options.saved_only {
-      query = query.filter(comment_saved::comment_id.is_not_null());
-    }
-
-    if options.liked_only {
-      query = query.filter(comment_like::score.eq(1));
-    } else if options.disliked_only {
-      query = query.filter(comment_like::score.eq(-1));
-    }
-
-    if !options
-      .local_user
-      .map(|l| l.local_user.show_bot_accounts)
-      .unwrap_or(true)
-    {
-      query = query.filter(person::bot_account.eq(false));
-    };
-
-    if options.local_user.is_some()
-      && options.listing_type.unwrap_or_default() != ListingType::ModeratorView
-    {
-      // Filter out the rows with missing languages
-      query = query.filter(local_user_language::language_id.is_not_null());
-
-      // Don't show blocked communities or persons
-      if options.post_id.is_none() {
-        query = query.filter(instance_block::person_id.is_null());
-        query = query.filter(community_block::person_id.is_null());
-      }
-      query = query.filter(person_block::person_id.is_null());
-    }
+    let mut query = all_joins(
+      filter_comment_list(comment_joins(comment::table.into_boxed()), &options),
+      options.local_user.map(|l| l.person.id),
+    )
+    .select(selection);
 
     // A Max depth given means its a tree fetch
     let (limit, offset) = if let Some(max_depth) = options.max_depth {
@@ -268,6 +387,14 @@ fn queries<'a>() -> Queries<
       CommentSortType::New => query.then_order_by(comment::published.desc()),
       CommentSortType::Old => query.then_order_by(comment::published.asc()),
       CommentSortType::Top => query.then_order_by(comment_aggregates::score.desc()),
+      CommentSortType::Relevance => match &options.search_term {
+        Some(search_term) => query
+          .then_order_by(full_text_search::rank("comment", search_term).desc())
+          .then_order_by(comment::published.desc()),
+        None => query
+          .then_order_by(comment_aggregates::hot_rank.desc())
+          .then_order_by(comment_aggregates::score.desc()),
+      },
     };
 
     // Note: deleted and removed comments are done on the front side
@@ -305,6 +432,8 @@ pub struct CommentQuery<'a> {
   pub post_id: Option<PostId>,
   pub parent_path: Option<Ltree>,
   pub creator_id: Option<PersonId>,
+  /// Only show the comments with these ids, like the results of a combined search
+  pub ids: Option<Vec<CommentId>>,
   pub local_user: Option<&'a LocalUserView>,
   pub search_term: Option<String>,
   pub saved_only: bool,
@@ -348,6 +477,7 @@ mod tests {
     },
     traits::{Blockable, Crud, Likeable},
     utils::build_db_pool_for_tests,
+    CommunityVisibility,
     SubscribedType,
   };
   use serial_test::serial;
@@ -852,6 +982,7 @@ mod tests {
         matrix_user_id: None,
         ban_expires: None,
         instance_id: data.inserted_instance.id,
+        encryption_public_key: None,
         private_key: data.local_user_view.person.private_key.clone(),
         public_key: data.local_user_view.person.public_key.clone(),
         last_refreshed_at: data.local_user_view.person.last_refreshed_at,
@@ -878,6 +1009,7 @@ mod tests {
         language_id: Default::default(),
         featured_community: false,
         featured_local: false,
+        scheduled_publish_time: None,
       },
       community: Community {
         id: data.inserted_community.id,
@@ -904,6 +1036,10 @@ mod tests {
         shared_inbox_url: data.inserted_community.shared_inbox_url.clone(),
         moderators_url: data.inserted_community.moderators_url.clone(),
         featured_url: data.inserted_community.featured_url.clone(),
+        visibility: CommunityVisibility::Public,
+        warning_ban_threshold: None,
+        warning_ban_window_days: 30,
+        warning_ban_days: 7,
       },
       counts: CommentAggregates {
         id: agg.id,
@@ -915,6 +1051,7 @@ mod tests {
         child_count: 5,
         hot_rank: 0.1728,
         controversy_rank: 0.0,
+        reaction_counts: Default::default(),
       },
     }
   }
@@ -0,0 +1,245 @@
+use crate::{private_message_view::PrivateMessageQuery, structs::ConversationView};
+use diesel::{dsl::exists, result::Error, BoolExpressionMethods, ExpressionMethods, QueryDsl};
+use diesel_async::RunQueryDsl;
+use lemmy_db_schema::{
+  newtypes::{ConversationId, PersonId},
+  schema::{conversation, conversation_participant, private_message},
+  source::conversation::{Conversation, ConversationParticipant},
+  traits::Crud,
+  utils::{functions::coalesce, get_conn, limit_and_offset, DbPool},
+};
+
+impl ConversationView {
+  /// Reads a conversation for one of its participants. Returns `NotFound` if the person doesn't
+  /// take part in the conversation.
+  pub async fn read(
+    pool: &mut DbPool<'_>,
+    conversation_id: ConversationId,
+    my_person_id: PersonId,
+  ) -> Result<Self, Error> {
+    let participant = ConversationParticipant::read(pool, conversation_id, my_person_id)
+      .await?
+      .ok_or(Error::NotFound)?;
+    let conversation = Conversation::read(pool, conversation_id).await?;
+    Self::from_participant(pool, conversation, participant).await
+  }
+
+  async fn from_participant(
+    pool: &mut DbPool<'_>,
+    conversation: Conversation,
+    participant: ConversationParticipant,
+  ) -> Result<Self, Error> {
+    let participants = Conversation::list_participants(pool, conversation.id).await?;
+    let unread_count = Self::unread_count(pool, &participant).await?;
+    let last_message = PrivateMessageQuery {
+      conversation_id: Some(conversation.id),
+      limit: Some(1),
+      ..Default::default()
+    }
+    .list(pool, participant.person_id)
+    .await?
+    .into_iter()
+    .next();
+
+    Ok(ConversationView {
+      conversation,
+      participants,
+      muted: participant.muted,
+      unread_count,
+      last_message,
+    })
+  }
+
+  /// Counts the messages of the conversation which the participant hasn't read yet.
+  async fn unread_count(
+    pool: &mut DbPool<'_>,
+    participant: &ConversationParticipant,
+  ) -> Result<i64, Error> {
+    let conn = &mut get_conn(pool).await?;
+    private_message::table
+      .filter(private_message::conversation_id.eq(participant.conversation_id))
+      .filter(private_message::creator_id.ne(participant.person_id))
+      .filter(private_message::deleted.eq(false))
+      .filter(
+        private_message::recipient_id
+          .eq(participant.person_id)
+          .and(private_message::read.eq(false))
+          .or(
+            private_message::recipient_id
+              .is_null()
+              .and(private_message::published.gt(participant.last_read)),
+          ),
+      )
+      .count()
+      .get_result::<i64>(conn)
+      .await
+  }
+}
+
+#[derive(Default)]
+pub struct ConversationQuery {
+  /// Only list conversations which contain unread messages
+  pub unread_only: bool,
+  pub page: Option<i64>,
+  pub limit: Option<i64>,
+}
+
+impl ConversationQuery {
+  /// Lists the conversations of a person, the most recently active ones first.
+  pub async fn list(
+    self,
+    pool: &mut DbPool<'_>,
+    my_person_id: PersonId,
+  ) -> Result<Vec<ConversationView>, Error> {
+    let conversations = {
+      let conn = &mut get_conn(pool).await?;
+      let mut query = conversation_participant::table
+        .inner_join(conversation::table)
+        .filter(conversation_participant::person_id.eq(my_person_id))
+        .select((
+          conversation::all_columns,
+          conversation_participant::all_columns,
+        ))
+        .into_boxed();
+
+      if self.unread_only {
+        query = query.filter(exists(
+          private_message::table
+            .filter(private_message::conversation_id.eq(conversation_participant::conversation_id))
+            .filter(private_message::creator_id.ne(my_person_id))
+            .filter(private_message::deleted.eq(false))
+            .filter(
+              private_message::recipient_id
+                .eq(my_person_id)
+                .and(private_message::read.eq(false))
+                .or(
+                  private_message::recipient_id
+                    .is_null()
+                    .and(private_message::published.gt(conversation_participant::last_read)),
+                ),
+            ),
+        ));
+      }
+
+      let (limit, offset) = limit_and_offset(self.page, self.limit)?;
+      query
+        .order_by(coalesce(conversation::updated, conversation::published).desc())
+        .limit(limit)
+        .offset(offset)
+        .load::<(Conversation, ConversationParticipant)>(conn)
+        .await
+    }?;
+
+    let mut views = Vec::with_capacity(conversations.len());
+    for (conversation, participant) in conversations {
+      views.push(ConversationView::from_participant(pool, conversation, participant).await?);
+    }
+    Ok(views)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  #![allow(clippy::unwrap_used)]
+  #![allow(clippy::indexing_slicing)]
+
+  use crate::conversation_view::ConversationQuery;
+  use lemmy_db_schema::{
+    source::{
+      conversation::{Conversation, ConversationInsertForm, ConversationParticipant},
+      instance::Instance,
+      person::{Person, PersonInsertForm},
+      private_message::{PrivateMessage, PrivateMessageInsertForm},
+    },
+    traits::Crud,
+    utils::build_db_pool_for_tests,
+  };
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_list() {
+    let pool = &build_db_pool_for_tests().await;
+    let pool = &mut pool.into();
+
+    let instance = Instance::read_or_create(pool, "my_domain.tld".to_string())
+      .await
+      .unwrap();
+
+    let mut persons = Vec::new();
+    for name in ["anna_cv", "ben_cv", "carl_cv"] {
+      let form = PersonInsertForm::builder()
+        .name(name.into())
+        .public_key("pubkey".to_string())
+        .instance_id(instance.id)
+        .build();
+      persons.push(Person::create(pool, &form).await.unwrap());
+    }
+    let person_ids = persons.iter().map(|p| p.id).collect::<Vec<_>>();
+
+    let conversation = Conversation::create(pool, &ConversationInsertForm::builder().build())
+      .await
+      .unwrap();
+    ConversationParticipant::add(pool, conversation.id, &person_ids)
+      .await
+      .unwrap();
+
+    let message_form = PrivateMessageInsertForm::builder()
+      .creator_id(persons[0].id)
+      .content("hello all".to_string())
+      .conversation_id(conversation.id)
+      .build();
+    let message = PrivateMessage::create(pool, &message_form).await.unwrap();
+
+    let ben_conversations = ConversationQuery {
+      unread_only: true,
+      ..Default::default()
+    }
+    .list(pool, persons[1].id)
+    .await
+    .unwrap();
+    assert_eq!(1, ben_conversations.len());
+    assert_eq!(3, ben_conversations[0].participants.len());
+    assert_eq!(1, ben_conversations[0].unread_count);
+    assert_eq!(
+      Some(message.id),
+      ben_conversations[0]
+        .last_message
+        .as_ref()
+        .map(|m| m.private_message.id)
+    );
+
+    // The sender has nothing to read
+    let anna_unread = ConversationQuery {
+      unread_only: true,
+      ..Default::default()
+    }
+    .list(pool, persons[0].id)
+    .await
+    .unwrap();
+    assert!(anna_unread.is_empty());
+
+    ConversationParticipant::mark_as_read(pool, conversation.id, persons[1].id)
+      .await
+      .unwrap();
+    let ben_conversations = ConversationQuery::default()
+      .list(pool, persons[1].id)
+      .await
+      .unwrap();
+    assert_eq!(1, ben_conversations.len());
+    assert_eq!(0, ben_conversations[0].unread_count);
+
+    // After leaving, the conversation isn't listed anymore
+    ConversationParticipant::leave(pool, conversation.id, persons[2].id)
+      .await
+      .unwrap();
+    let carl_conversations = ConversationQuery::default()
+      .list(pool, persons[2].id)
+      .await
+      .unwrap();
+    assert!(carl_conversations.is_empty());
+
+    Instance::delete(pool, instance.id).await.unwrap();
+    Conversation::delete(pool, conversation.id).await.unwrap();
+  }
+}
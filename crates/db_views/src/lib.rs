@@ -1,11 +1,15 @@
 #[cfg(test)]
 extern crate serial_test;
 
+#[cfg(feature = "full")]
+pub mod combined_search;
 #[cfg(feature = "full")]
 pub mod comment_report_view;
 #[cfg(feature = "full")]
 pub mod comment_view;
 #[cfg(feature = "full")]
+pub mod conversation_view;
+#[cfg(feature = "full")]
 pub mod custom_emoji_view;
 #[cfg(feature = "full")]
 pub mod local_user_view;
@@ -18,7 +22,13 @@ pub mod private_message_report_view;
 #[cfg(feature = "full")]
 pub mod private_message_view;
 #[cfg(feature = "full")]
+pub mod reaction_view;
+#[cfg(feature = "full")]
 pub mod registration_application_view;
 #[cfg(feature = "full")]
+pub mod report_combined_view;
+#[cfg(feature = "full")]
+pub mod report_note_view;
+#[cfg(feature = "full")]
 pub mod site_view;
 pub mod structs;
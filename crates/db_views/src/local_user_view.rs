@@ -1,11 +1,20 @@
 use crate::structs::LocalUserView;
 use actix_web::{dev::Payload, FromRequest, HttpMessage, HttpRequest};
-use diesel::{result::Error, BoolExpressionMethods, ExpressionMethods, JoinOnDsl, QueryDsl};
+use diesel::{
+  dsl::IntervalDsl,
+  result::Error,
+  BoolExpressionMethods,
+  ExpressionMethods,
+  JoinOnDsl,
+  NullableExpressionMethods,
+  QueryDsl,
+};
 use diesel_async::RunQueryDsl;
 use lemmy_db_schema::{
   newtypes::{LocalUserId, PersonId},
   schema::{local_user, person, person_aggregates},
-  utils::{functions::lower, DbConn, DbPool, ListFn, Queries, ReadFn},
+  utils::{functions::lower, now, DbConn, DbPool, ListFn, Queries, ReadFn},
+  EmailDigestFrequency,
 };
 use lemmy_utils::error::{LemmyError, LemmyErrorType};
 use std::future::{ready, Ready};
@@ -20,6 +29,10 @@ enum ReadBy<'a> {
 
 enum ListMode {
   AdminsWithEmails,
+  EmailDigest {
+    frequency: EmailDigestFrequency,
+    require_email_verified: bool,
+  },
 }
 
 fn queries<'a>(
@@ -67,6 +80,33 @@ fn queries<'a>(
           .load::<LocalUserView>(&mut conn)
           .await
       }
+      ListMode::EmailDigest {
+        frequency,
+        require_email_verified,
+      } => {
+        // The task runs every hour, an hour of slack keeps the digest at the same time of day
+        let interval = match frequency {
+          EmailDigestFrequency::Never => return Ok(vec![]),
+          EmailDigestFrequency::Daily => 23.hours(),
+          EmailDigestFrequency::Weekly => (7 * 24 - 1).hours(),
+        };
+        local_user::table
+          .filter(local_user::email.is_not_null())
+          .filter(local_user::email_verified.or(!require_email_verified))
+          .filter(local_user::email_digest.eq(frequency))
+          .filter(
+            local_user::last_email_digest
+              .is_null()
+              .or(local_user::last_email_digest.lt((now() - interval).nullable())),
+          )
+          .inner_join(person::table)
+          .filter(person::deleted.eq(false))
+          .filter(person::banned.eq(false))
+          .inner_join(person_aggregates::table.on(person::id.eq(person_aggregates::person_id)))
+          .select(selection)
+          .load::<LocalUserView>(&mut conn)
+          .await
+      }
     }
   };
 
@@ -102,6 +142,24 @@ impl LocalUserView {
   pub async fn list_admins_with_emails(pool: &mut DbPool<'_>) -> Result<Vec<Self>, Error> {
     queries().list(pool, ListMode::AdminsWithEmails).await
   }
+
+  /// The users with an email address who chose this digest frequency, and are due for their next
+  /// digest. If the site requires email verification, the address also has to be verified.
+  pub async fn list_for_email_digest(
+    pool: &mut DbPool<'_>,
+    frequency: EmailDigestFrequency,
+    require_email_verified: bool,
+  ) -> Result<Vec<Self>, Error> {
+    queries()
+      .list(
+        pool,
+        ListMode::EmailDigest {
+          frequency,
+          require_email_verified,
+        },
+      )
+      .await
+  }
 }
 
 impl FromRequest for LocalUserView {
@@ -1,5 +1,6 @@
 use crate::structs::{LocalUserView, PostReportView};
 use diesel::{
+  dsl::exists,
   pg::Pg,
   result::Error,
   BoolExpressionMethods,
@@ -21,6 +22,7 @@ use lemmy_db_schema::{
     post_aggregates,
     post_like,
     post_report,
+    post_revision,
   },
   utils::{get_conn, limit_and_offset, DbConn, DbPool, ListFn, Queries, ReadFn},
 };
@@ -64,6 +66,13 @@ fn queries<'a>() -> Queries<
         post_like::score.nullable(),
         post_aggregates::all_columns,
         aliases::person2.fields(person::all_columns.nullable()),
+        exists(
+          post_revision::table.filter(
+            post_revision::post_id
+              .eq(post_report::post_id)
+              .and(post_revision::edited.ge(post_report::published)),
+          ),
+        ),
       ))
   };
 
@@ -83,10 +92,18 @@ fn queries<'a>() -> Queries<
       query = query.filter(post::community_id.eq(community_id));
     }
 
+    if let Some(reported_person_id) = options.reported_person_id {
+      query = query.filter(post::creator_id.eq(reported_person_id));
+    }
+
     if options.unresolved_only {
       query = query.filter(post_report::resolved.eq(false));
     }
 
+    if options.resolved_only {
+      query = query.filter(post_report::resolved.eq(true));
+    }
+
     let (limit, offset) = limit_and_offset(options.page, options.limit)?;
 
     query = query
@@ -169,9 +186,12 @@ impl PostReportView {
 #[derive(Default)]
 pub struct PostReportQuery {
   pub community_id: Option<CommunityId>,
+  /// Only reports about content of this person
+  pub reported_person_id: Option<PersonId>,
   pub page: Option<i64>,
   pub limit: Option<i64>,
   pub unresolved_only: bool,
+  pub resolved_only: bool,
 }
 
 impl PostReportQuery {
@@ -289,6 +309,7 @@ mod tests {
       original_post_url: None,
       original_post_body: None,
       reason: "from sara".into(),
+      ap_id: None,
     };
 
     PostReport::report(pool, &sara_report_form).await.unwrap();
@@ -309,6 +330,7 @@ mod tests {
       original_post_url: None,
       original_post_body: None,
       reason: "from jessica".into(),
+      ap_id: None,
     };
 
     let inserted_jessica_report = PostReport::report(pool, &jessica_report_form)
@@ -330,6 +352,7 @@ mod tests {
     assert_eq!(read_jessica_report_view.post_creator.id, inserted_timmy.id);
     assert_eq!(read_jessica_report_view.my_vote, None);
     assert_eq!(read_jessica_report_view.resolver, None);
+    assert!(!read_jessica_report_view.edited_after_report);
 
     // Do a batch read of timmys reports
     let reports = PostReportQuery::default()
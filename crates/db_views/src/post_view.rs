@@ -1,7 +1,7 @@
 use crate::structs::{LocalUserView, PaginationCursor, PostView};
 use diesel::{
   debug_query,
-  dsl::{self, exists, not, IntervalDsl},
+  dsl::{self, exists, not, sql, IntervalDsl},
   expression::AsExpression,
   pg::Pg,
   result::Error,
@@ -15,13 +15,12 @@ use diesel::{
   JoinOnDsl,
   NullableExpressionMethods,
   OptionalExtension,
-  PgTextExpressionMethods,
   QueryDsl,
 };
 use diesel_async::RunQueryDsl;
 use lemmy_db_schema::{
   aggregates::structs::PostAggregates,
-  newtypes::{CommunityId, LocalUserId, PersonId, PostId},
+  newtypes::{CommunityId, CommunityPostTagId, LocalUserId, MultiCommunityId, PersonId, PostId},
   schema::{
     community,
     community_block,
@@ -29,17 +28,33 @@ use lemmy_db_schema::{
     community_moderator,
     community_person_ban,
     instance_block,
+    local_user_keyword_filter,
     local_user_language,
+    multi_community_entry,
     person,
     person_block,
+    person_follower,
     person_post_aggregates,
+    poll,
     post,
     post_aggregates::{self, newest_comment_time},
     post_like,
     post_read,
     post_saved,
+    post_tag,
   },
-  utils::{fuzzy_search, get_conn, limit_and_offset, DbConn, DbPool, ListFn, Queries, ReadFn},
+  utils::{
+    full_text_search,
+    functions::keyword_filter_matches,
+    get_conn,
+    limit_and_offset,
+    DbConn,
+    DbPool,
+    ListFn,
+    Queries,
+    ReadFn,
+  },
+  CommunityVisibility,
   ListingType,
   SortType,
 };
@@ -96,60 +111,373 @@ where
   query
 }
 
-fn queries<'a>() -> Queries<
-  impl ReadFn<'a, PostView, (PostId, Option<PersonId>, bool)>,
-  impl ListFn<'a, PostView, PostQuery<'a>>,
-> {
-  let is_creator_banned_from_community = exists(
-    community_person_ban::table.filter(
-      post_aggregates::community_id
-        .eq(community_person_ban::community_id)
-        .and(community_person_ban::person_id.eq(post_aggregates::creator_id)),
-    ),
-  );
+/// The tables which are needed to filter the posts of a listing.
+pub(crate) type PostListQuery<'a> = dsl::InnerJoin<
+  dsl::InnerJoin<
+    dsl::InnerJoin<post_aggregates::BoxedQuery<'a, Pg>, person::table>,
+    community::table,
+  >,
+  post::table,
+>;
+
+pub(crate) fn post_joins(query: post_aggregates::BoxedQuery<'_, Pg>) -> PostListQuery<'_> {
+  query
+    .inner_join(person::table)
+    .inner_join(community::table)
+    .inner_join(post::table)
+}
 
-  let is_saved = |person_id| {
-    exists(
-      post_saved::table.filter(
+fn is_saved(person_id: PersonId) -> dsl::exists<post_saved::BoxedQuery<'static, Pg>> {
+  exists(
+    post_saved::table
+      .filter(
         post_aggregates::post_id
           .eq(post_saved::post_id)
           .and(post_saved::person_id.eq(person_id)),
-      ),
-    )
-  };
+      )
+      .into_boxed(),
+  )
+}
 
-  let is_read = |person_id| {
-    exists(
-      post_read::table.filter(
+fn is_read(person_id: PersonId) -> dsl::exists<post_read::BoxedQuery<'static, Pg>> {
+  exists(
+    post_read::table
+      .filter(
         post_aggregates::post_id
           .eq(post_read::post_id)
           .and(post_read::person_id.eq(person_id)),
-      ),
-    )
-  };
+      )
+      .into_boxed(),
+  )
+}
 
-  let is_creator_blocked = |person_id| {
-    exists(
-      person_block::table.filter(
+fn is_creator_blocked(person_id: PersonId) -> dsl::exists<person_block::BoxedQuery<'static, Pg>> {
+  exists(
+    person_block::table
+      .filter(
         post_aggregates::creator_id
           .eq(person_block::target_id)
           .and(person_block::person_id.eq(person_id)),
-      ),
-    )
-  };
+      )
+      .into_boxed(),
+  )
+}
 
-  let score = |person_id| {
-    post_like::table
+fn is_approved_follower(
+  person_id: PersonId,
+) -> dsl::exists<community_follower::BoxedQuery<'static, Pg>> {
+  exists(
+    community_follower::table
       .filter(
-        post_aggregates::post_id
-          .eq(post_like::post_id)
-          .and(post_like::person_id.eq(person_id)),
+        post_aggregates::community_id
+          .eq(community_follower::community_id)
+          .and(community_follower::person_id.eq(person_id))
+          .and(community_follower::pending.eq(false)),
+      )
+      .into_boxed(),
+  )
+}
+
+fn is_community_moderator(
+  person_id: PersonId,
+) -> dsl::exists<community_moderator::BoxedQuery<'static, Pg>> {
+  exists(
+    community_moderator::table
+      .filter(
+        post_aggregates::community_id
+          .eq(community_moderator::community_id)
+          .and(community_moderator::person_id.eq(person_id)),
       )
-      .select(post_like::score.nullable())
-      .single_value()
+      .into_boxed(),
+  )
+}
+
+fn score(
+  person_id: PersonId,
+) -> dsl::SingleValue<post_like::BoxedQuery<'static, Pg, sql_types::Nullable<sql_types::SmallInt>>>
+{
+  post_like::table
+    .filter(
+      post_aggregates::post_id
+        .eq(post_like::post_id)
+        .and(post_like::person_id.eq(person_id)),
+    )
+    .select(post_like::score.nullable())
+    .into_boxed()
+    .single_value()
+}
+
+/// Applies the filters of the given options, without any sorting or paging. This is shared with
+/// the combined search, so that both show the same posts.
+pub(crate) fn filter_post_list<'a>(
+  mut query: PostListQuery<'a>,
+  options: &PostQuery<'a>,
+) -> PostListQuery<'a> {
+  let person_id = options.local_user.map(|l| l.person.id);
+  let local_user_id = options.local_user.map(|l| l.local_user.id);
+
+  // The left join below will return None in this case
+  let person_id_join = person_id.unwrap_or(PersonId(-1));
+  let local_user_id_join = local_user_id.unwrap_or(LocalUserId(-1));
+
+  let is_creator = options.creator_id == options.local_user.map(|l| l.person.id);
+  // only show deleted posts to creator
+  if is_creator {
+    query = query
+      .filter(community::deleted.eq(false))
+      .filter(post::deleted.eq(false));
+  }
+
+  let is_admin = options
+    .local_user
+    .map(|l| l.local_user.admin)
+    .unwrap_or(false);
+  // only show removed posts to admin when viewing user profile
+  if !(options.is_profile_view && is_admin) {
+    query = query
+      .filter(community::removed.eq(false))
+      .filter(post::removed.eq(false));
+  }
+
+  // Posts of private communities are only visible to approved followers and moderators, those of
+  // local-only communities only to logged in users
+  if let Some(person_id) = person_id {
+    if !is_admin {
+      query = query.filter(
+        community::visibility
+          .ne(CommunityVisibility::Private)
+          .or(is_approved_follower(person_id))
+          .or(is_community_moderator(person_id)),
+      );
+    }
+  } else {
+    query = query.filter(community::visibility.eq(CommunityVisibility::Public));
+  }
+
+  if options.scheduled_only {
+    query = query
+      .filter(post::scheduled_publish_time.is_not_null())
+      .filter(post::creator_id.eq(person_id_join));
+  } else {
+    query = query.filter(post::scheduled_publish_time.is_null());
+  }
+
+  if let Some(community_id) = options.community_id {
+    query = query.filter(post_aggregates::community_id.eq(community_id));
+  }
+
+  if let Some(multi_community_id) = options.multi_community_id {
+    query = query.filter(exists(
+      multi_community_entry::table.filter(
+        post_aggregates::community_id
+          .eq(multi_community_entry::community_id)
+          .and(multi_community_entry::multi_community_id.eq(multi_community_id)),
+      ),
+    ));
+  }
+
+  if let Some(tag_id) = options.tag_id {
+    query = query.filter(exists(
+      post_tag::table.filter(
+        post_aggregates::post_id
+          .eq(post_tag::post_id)
+          .and(post_tag::community_post_tag_id.eq(tag_id)),
+      ),
+    ));
+  }
+
+  if let Some(creator_id) = options.creator_id {
+    query = query.filter(post_aggregates::creator_id.eq(creator_id));
+  }
+
+  if let Some(ids) = &options.ids {
+    query = query.filter(post_aggregates::post_id.eq_any(ids.clone()));
+  }
+
+  if let (Some(listing_type), Some(person_id)) = (options.listing_type, person_id) {
+    let is_subscribed = exists(
+      community_follower::table.filter(
+        post_aggregates::community_id
+          .eq(community_follower::community_id)
+          .and(community_follower::person_id.eq(person_id)),
+      ),
+    );
+    match listing_type {
+      ListingType::Subscribed => query = query.filter(is_subscribed),
+      ListingType::Local => {
+        query = query
+          .filter(community::local.eq(true))
+          .filter(community::hidden.eq(false).or(is_subscribed));
+      }
+      ListingType::All => query = query.filter(community::hidden.eq(false).or(is_subscribed)),
+      ListingType::ModeratorView => {
+        query = query.filter(exists(
+          community_moderator::table.filter(
+            post::community_id
+              .eq(community_moderator::community_id)
+              .and(community_moderator::person_id.eq(person_id)),
+          ),
+        ));
+      }
+      ListingType::FollowedPeople => {
+        query = query.filter(exists(
+          person_follower::table.filter(
+            post_aggregates::creator_id
+              .eq(person_follower::person_id)
+              .and(person_follower::follower_id.eq(person_id))
+              .and(person_follower::pending.eq(false)),
+          ),
+        ));
+      }
+    }
+  }
+
+  if let Some(url_search) = &options.url_search {
+    query = query.filter(post::url.eq(url_search));
+  }
+
+  if let Some(search_term) = &options.search_term {
+    query = query.filter(full_text_search::matches("post", search_term));
+  }
+
+  if !options
+    .local_user
+    .map(|l| l.local_user.show_nsfw)
+    .unwrap_or(false)
+  {
+    query = query
+      .filter(post::nsfw.eq(false))
+      .filter(community::nsfw.eq(false));
+  };
+
+  if !options
+    .local_user
+    .map(|l| l.local_user.show_bot_accounts)
+    .unwrap_or(true)
+  {
+    query = query.filter(person::bot_account.eq(false));
+  };
+
+  if let (true, Some(person_id)) = (options.saved_only, person_id) {
+    query = query.filter(is_saved(person_id));
+  }
+  // Only hide the read posts, if the saved_only is false. Otherwise ppl with the hide_read
+  // setting wont be able to see saved posts.
+  else if !options
+    .local_user
+    .map(|l| l.local_user.show_read_posts)
+    .unwrap_or(true)
+  {
+    // Do not hide read posts when it is a user profile view
+    if let (false, Some(person_id)) = (options.is_profile_view, person_id) {
+      query = query.filter(not(is_read(person_id)));
+    }
+  }
+
+  if let Some(person_id) = person_id {
+    if options.liked_only {
+      query = query.filter(score(person_id).eq(1));
+    } else if options.disliked_only {
+      query = query.filter(score(person_id).eq(-1));
+    }
   };
 
-  let all_joins = move |query: post_aggregates::BoxedQuery<'a, Pg>,
+  // Dont filter blocks or missing languages for moderator view type
+  if let (Some(person_id), false) = (
+    person_id,
+    options.listing_type.unwrap_or_default() == ListingType::ModeratorView,
+  ) {
+    // Filter out the rows with missing languages
+    query = query.filter(exists(
+      local_user_language::table.filter(
+        post::language_id
+          .eq(local_user_language::language_id)
+          .and(local_user_language::local_user_id.eq(local_user_id_join)),
+      ),
+    ));
+
+    // Don't show blocked instances, communities or persons
+    query = query.filter(not(exists(
+      community_block::table.filter(
+        post_aggregates::community_id
+          .eq(community_block::community_id)
+          .and(community_block::person_id.eq(person_id_join)),
+      ),
+    )));
+    query = query.filter(not(exists(
+      instance_block::table.filter(
+        post_aggregates::instance_id
+          .eq(instance_block::instance_id)
+          .and(instance_block::person_id.eq(person_id_join)),
+      ),
+    )));
+    query = query.filter(not(is_creator_blocked(person_id)));
+
+    // Don't show posts which match one of the user's keyword filters
+    query = query.filter(not(exists(
+      local_user_keyword_filter::table.filter(
+        local_user_keyword_filter::local_user_id
+          .eq(local_user_id_join)
+          .and(local_user_keyword_filter::expires.is_null().or(
+            local_user_keyword_filter::expires.gt(dsl::now.into_sql::<Timestamptz>().nullable()),
+          ))
+          .and(
+            local_user_keyword_filter::filter_title
+              .and(keyword_filter_matches(
+                post::name.nullable(),
+                local_user_keyword_filter::keyword,
+                local_user_keyword_filter::is_regex,
+              ))
+              .or(
+                local_user_keyword_filter::filter_body.and(keyword_filter_matches(
+                  post::body,
+                  local_user_keyword_filter::keyword,
+                  local_user_keyword_filter::is_regex,
+                )),
+              )
+              .or(
+                local_user_keyword_filter::filter_url.and(keyword_filter_matches(
+                  post::url,
+                  local_user_keyword_filter::keyword,
+                  local_user_keyword_filter::is_regex,
+                )),
+              ),
+          ),
+      ),
+    )));
+  }
+  query
+}
+
+fn queries<'a>() -> Queries<
+  impl ReadFn<'a, PostView, (PostId, Option<PersonId>, bool)>,
+  impl ListFn<'a, PostView, PostQuery<'a>>,
+> {
+  let is_creator_banned_from_community = exists(
+    community_person_ban::table.filter(
+      post_aggregates::community_id
+        .eq(community_person_ban::community_id)
+        .and(community_person_ban::person_id.eq(post_aggregates::creator_id)),
+    ),
+  );
+
+  // The poll options are aggregated into a single json column. Vote counts are hidden until the
+  // poll is closed if the poll creator chose so.
+  let poll_options = |person_id: PersonId| {
+    sql::<sql_types::Jsonb>(
+      "coalesce((SELECT jsonb_agg(jsonb_build_object('id', po.id, 'name', po.name, \
+       'vote_count', CASE WHEN poll.hide_results AND coalesce(poll.end_time > now(), TRUE) \
+       THEN NULL ELSE po.vote_count END, 'voted', EXISTS (SELECT 1 FROM poll_vote pv \
+       WHERE pv.poll_option_id = po.id AND pv.person_id = ",
+    )
+    .bind::<sql_types::Integer, _>(person_id.0)
+    .sql(
+      ")) ORDER BY po.position) FROM poll_option po \
+       WHERE po.post_id = post_aggregates.post_id), '[]')",
+    )
+  };
+
+  let all_joins = move |query: PostListQuery<'a>,
                         my_person_id: Option<PersonId>,
                         saved_only: bool| {
     let is_saved_selection: Box<dyn BoxableExpression<_, Pg, SqlType = sql_types::Bool>> =
@@ -218,9 +546,7 @@ fn queries<'a>() -> Queries<
     };
 
     query
-      .inner_join(person::table)
-      .inner_join(community::table)
-      .inner_join(post::table)
+      .left_join(poll::table.on(poll::post_id.eq(post_aggregates::post_id)))
       .select((
         post::all_columns,
         person::all_columns,
@@ -236,6 +562,8 @@ fn queries<'a>() -> Queries<
           post_aggregates::comments.nullable() - read_comments,
           post_aggregates::comments,
         ),
+        poll::all_columns.nullable(),
+        poll_options(my_person_id.unwrap_or(PersonId(-1))),
       ))
   };
 
@@ -246,9 +574,11 @@ fn queries<'a>() -> Queries<
       let person_id_join = my_person_id.unwrap_or(PersonId(-1));
 
       let mut query = all_joins(
-        post_aggregates::table
-          .filter(post_aggregates::post_id.eq(post_id))
-          .into_boxed(),
+        post_joins(
+          post_aggregates::table
+            .filter(post_aggregates::post_id.eq(post_id))
+            .into_boxed(),
+        ),
         my_person_id,
         false,
       );
@@ -271,41 +601,23 @@ fn queries<'a>() -> Queries<
           );
       }
 
+      // Scheduled posts are only visible to their creator until they get published
+      query = query.filter(
+        post::scheduled_publish_time
+          .is_null()
+          .or(post::creator_id.eq(person_id_join)),
+      );
+
       query.first::<PostView>(&mut conn).await
     };
 
   let list = move |mut conn: DbConn<'a>, options: PostQuery<'a>| async move {
-    let person_id = options.local_user.map(|l| l.person.id);
-    let local_user_id = options.local_user.map(|l| l.local_user.id);
-
-    // The left join below will return None in this case
-    let person_id_join = person_id.unwrap_or(PersonId(-1));
-    let local_user_id_join = local_user_id.unwrap_or(LocalUserId(-1));
-
     let mut query = all_joins(
-      post_aggregates::table.into_boxed(),
-      person_id,
+      filter_post_list(post_joins(post_aggregates::table.into_boxed()), &options),
+      options.local_user.map(|l| l.person.id),
       options.saved_only,
     );
 
-    let is_creator = options.creator_id == options.local_user.map(|l| l.person.id);
-    // only show deleted posts to creator
-    if is_creator {
-      query = query
-        .filter(community::deleted.eq(false))
-        .filter(post::deleted.eq(false));
-    }
-
-    let is_admin = options
-      .local_user
-      .map(|l| l.local_user.admin)
-      .unwrap_or(false);
-    // only show removed posts to admin when viewing user profile
-    if !(options.is_profile_view && is_admin) {
-      query = query
-        .filter(community::removed.eq(false))
-        .filter(post::removed.eq(false));
-    }
     if options.community_id.is_none() || options.community_id_just_for_prefetch {
       query = order_and_page_filter_desc(query, post_aggregates::featured_local, &options, |e| {
         e.featured_local
@@ -316,128 +628,7 @@ fn queries<'a>() -> Queries<
           e.featured_community
         });
     }
-    if let Some(community_id) = options.community_id {
-      query = query.filter(post_aggregates::community_id.eq(community_id));
-    }
-
-    if let Some(creator_id) = options.creator_id {
-      query = query.filter(post_aggregates::creator_id.eq(creator_id));
-    }
-
-    if let (Some(listing_type), Some(person_id)) = (options.listing_type, person_id) {
-      let is_subscribed = exists(
-        community_follower::table.filter(
-          post_aggregates::community_id
-            .eq(community_follower::community_id)
-            .and(community_follower::person_id.eq(person_id)),
-        ),
-      );
-      match listing_type {
-        ListingType::Subscribed => query = query.filter(is_subscribed),
-        ListingType::Local => {
-          query = query
-            .filter(community::local.eq(true))
-            .filter(community::hidden.eq(false).or(is_subscribed));
-        }
-        ListingType::All => query = query.filter(community::hidden.eq(false).or(is_subscribed)),
-        ListingType::ModeratorView => {
-          query = query.filter(exists(
-            community_moderator::table.filter(
-              post::community_id
-                .eq(community_moderator::community_id)
-                .and(community_moderator::person_id.eq(person_id)),
-            ),
-          ));
-        }
-      }
-    }
-
-    if let Some(url_search) = &options.url_search {
-      query = query.filter(post::url.eq(url_search));
-    }
-
-    if let Some(search_term) = &options.search_term {
-      let searcher = fuzzy_search(search_term);
-      query = query.filter(
-        post::name
-          .ilike(searcher.clone())
-          .or(post::body.ilike(searcher)),
-      );
-    }
-
-    if !options
-      .local_user
-      .map(|l| l.local_user.show_nsfw)
-      .unwrap_or(false)
-    {
-      query = query
-        .filter(post::nsfw.eq(false))
-        .filter(community::nsfw.eq(false));
-    };
-
-    if !options
-      .local_user
-      .map(|l| l.local_user.show_bot_accounts)
-      .unwrap_or(true)
-    {
-      query = query.filter(person::bot_account.eq(false));
-    };
-
-    if let (true, Some(person_id)) = (options.saved_only, person_id) {
-      query = query.filter(is_saved(person_id));
-    }
-    // Only hide the read posts, if the saved_only is false. Otherwise ppl with the hide_read
-    // setting wont be able to see saved posts.
-    else if !options
-      .local_user
-      .map(|l| l.local_user.show_read_posts)
-      .unwrap_or(true)
-    {
-      // Do not hide read posts when it is a user profile view
-      if let (false, Some(person_id)) = (options.is_profile_view, person_id) {
-        query = query.filter(not(is_read(person_id)));
-      }
-    }
 
-    if let Some(person_id) = person_id {
-      if options.liked_only {
-        query = query.filter(score(person_id).eq(1));
-      } else if options.disliked_only {
-        query = query.filter(score(person_id).eq(-1));
-      }
-    };
-
-    // Dont filter blocks or missing languages for moderator view type
-    if let (Some(person_id), false) = (
-      person_id,
-      options.listing_type.unwrap_or_default() == ListingType::ModeratorView,
-    ) {
-      // Filter out the rows with missing languages
-      query = query.filter(exists(
-        local_user_language::table.filter(
-          post::language_id
-            .eq(local_user_language::language_id)
-            .and(local_user_language::local_user_id.eq(local_user_id_join)),
-        ),
-      ));
-
-      // Don't show blocked instances, communities or persons
-      query = query.filter(not(exists(
-        community_block::table.filter(
-          post_aggregates::community_id
-            .eq(community_block::community_id)
-            .and(community_block::person_id.eq(person_id_join)),
-        ),
-      )));
-      query = query.filter(not(exists(
-        instance_block::table.filter(
-          post_aggregates::instance_id
-            .eq(instance_block::instance_id)
-            .and(instance_block::person_id.eq(person_id_join)),
-        ),
-      )));
-      query = query.filter(not(is_creator_blocked(person_id)));
-    }
     let now = diesel::dsl::now.into_sql::<Timestamptz>();
 
     {
@@ -460,6 +651,20 @@ fn queries<'a>() -> Queries<
           query = order_and_page_filter_desc(query, hot_rank, &options, |e| e.hot_rank);
           query = order_and_page_filter_desc(query, published, &options, |e| e.published);
         }
+        SortType::Relevance => {
+          if let Some(search_term) = &options.search_term {
+            // the rank depends on the search term, so it can't be stored in a pagination cursor
+            if options.page_after.is_some() {
+              return Err(Error::QueryBuilderError(
+                "relevance sort cannot be combined with cursor pagination".into(),
+              ));
+            }
+            query = query.then_order_by(full_text_search::rank("post", search_term).desc());
+          } else {
+            query = order_and_page_filter_desc(query, hot_rank, &options, |e| e.hot_rank);
+          }
+          query = order_and_page_filter_desc(query, published, &options, |e| e.published);
+        }
         SortType::Scaled => {
           query = order_and_page_filter_desc(query, scaled_rank, &options, |e| e.scaled_rank);
           query = order_and_page_filter_desc(query, published, &options, |e| e.published);
@@ -592,6 +797,12 @@ pub struct PostQuery<'a> {
   pub community_id: Option<CommunityId>,
   // if true, the query should be handled as if community_id was not given except adding the literal filter
   pub community_id_just_for_prefetch: bool,
+  /// Only show posts from the communities of this multi-community
+  pub multi_community_id: Option<MultiCommunityId>,
+  /// Only show posts which have this community post tag
+  pub tag_id: Option<CommunityPostTagId>,
+  /// Only show the posts with these ids, like the results of a combined search
+  pub ids: Option<Vec<PostId>>,
   pub local_user: Option<&'a LocalUserView>,
   pub search_term: Option<String>,
   pub url_search: Option<String>,
@@ -600,6 +811,8 @@ pub struct PostQuery<'a> {
   pub disliked_only: bool,
   pub moderator_view: bool,
   pub is_profile_view: bool,
+  /// Only show the scheduled posts of the local user, which are hidden otherwise
+  pub scheduled_only: bool,
   pub page: Option<i64>,
   pub limit: Option<i64>,
   pub page_after: Option<PaginationCursorData>,
@@ -680,10 +893,13 @@ impl<'a> PostQuery<'a> {
   }
 
   pub async fn list(self, pool: &mut DbPool<'_>) -> Result<Vec<PostView>, Error> {
+    // the prefetch bounds the page by post aggregates, which says nothing about search relevance
+    let sorted_by_relevance = self.sort == Some(SortType::Relevance) && self.search_term.is_some();
     if self.listing_type == Some(ListingType::Subscribed)
       && self.community_id.is_none()
       && self.local_user.is_some()
       && self.page_before_or_equal.is_none()
+      && !sorted_by_relevance
     {
       if let Some(query) = self.prefetch_upper_bound_for_page_before(pool).await? {
         queries().list(pool, query).await
@@ -711,18 +927,30 @@ mod tests {
     newtypes::LanguageId,
     source::{
       actor_language::LocalUserLanguage,
-      community::{Community, CommunityInsertForm},
+      community::{
+        Community,
+        CommunityFollower,
+        CommunityFollowerForm,
+        CommunityInsertForm,
+        CommunityUpdateForm,
+      },
       community_block::{CommunityBlock, CommunityBlockForm},
+      community_post_tag::{CommunityPostTag, CommunityPostTagInsertForm, PostTag},
       instance::Instance,
       instance_block::{InstanceBlock, InstanceBlockForm},
       language::Language,
       local_user::{LocalUser, LocalUserInsertForm, LocalUserUpdateForm},
-      person::{Person, PersonInsertForm},
+      local_user_keyword_filter::{LocalUserKeywordFilter, LocalUserKeywordFilterForm},
+      multi_community::{MultiCommunity, MultiCommunityEntry, MultiCommunityInsertForm},
+      person::{Person, PersonFollower, PersonFollowerForm, PersonInsertForm},
       person_block::{PersonBlock, PersonBlockForm},
+      poll::{Poll, PollForm, PollOptionForm, PollVote},
       post::{Post, PostInsertForm, PostLike, PostLikeForm, PostUpdateForm},
     },
-    traits::{Blockable, Crud, Likeable},
+    traits::{Blockable, Crud, Followable, Likeable},
     utils::{build_db_pool_for_tests, DbPool},
+    CommunityVisibility,
+    ListingType,
     SortType,
     SubscribedType,
   };
@@ -1326,6 +1554,551 @@ mod tests {
     cleanup(data, pool).await;
   }
 
+  #[tokio::test]
+  #[serial]
+  async fn post_listing_followed_people() {
+    let pool = &build_db_pool_for_tests().await;
+    let pool = &mut pool.into();
+    let data = init_data(pool).await;
+
+    let follow_form = PersonFollowerForm {
+      person_id: data.inserted_bot.id,
+      follower_id: data.local_user_view.person.id,
+      pending: false,
+    };
+    PersonFollower::follow(pool, &follow_form).await.unwrap();
+
+    let post_listings_followed = PostQuery {
+      listing_type: Some(ListingType::FollowedPeople),
+      local_user: Some(&data.local_user_view),
+      ..Default::default()
+    }
+    .list(pool)
+    .await
+    .unwrap();
+    assert_eq!(1, post_listings_followed.len());
+    assert_eq!(data.inserted_bot.id, post_listings_followed[0].creator.id);
+
+    // Pending follows don't count yet
+    let pending_form = PersonFollowerForm {
+      pending: true,
+      ..follow_form
+    };
+    PersonFollower::follow(pool, &pending_form).await.unwrap();
+    let post_listings_pending = PostQuery {
+      listing_type: Some(ListingType::FollowedPeople),
+      local_user: Some(&data.local_user_view),
+      ..Default::default()
+    }
+    .list(pool)
+    .await
+    .unwrap();
+    assert!(post_listings_pending.is_empty());
+
+    PersonFollower::unfollow(pool, &follow_form).await.unwrap();
+    cleanup(data, pool).await;
+  }
+
+  #[tokio::test]
+  #[serial]
+  async fn post_listing_search() {
+    let pool = &build_db_pool_for_tests().await;
+    let pool = &mut pool.into();
+    let data = init_data(pool).await;
+
+    let post_listings_word = PostQuery {
+      community_id: Some(data.inserted_community.id),
+      search_term: Some("bot".to_string()),
+      ..Default::default()
+    }
+    .list(pool)
+    .await
+    .unwrap();
+    assert_eq!(1, post_listings_word.len());
+    assert_eq!("test bot post", post_listings_word[0].post.name);
+
+    let post_listings_phrase = PostQuery {
+      community_id: Some(data.inserted_community.id),
+      search_term: Some("\"test post\"".to_string()),
+      ..Default::default()
+    }
+    .list(pool)
+    .await
+    .unwrap();
+    assert_eq!(1, post_listings_phrase.len());
+    assert_eq!(data.inserted_post.id, post_listings_phrase[0].post.id);
+
+    let post_listings_excluded = PostQuery {
+      community_id: Some(data.inserted_community.id),
+      search_term: Some("test -bot".to_string()),
+      ..Default::default()
+    }
+    .list(pool)
+    .await
+    .unwrap();
+    assert_eq!(1, post_listings_excluded.len());
+    assert_eq!(data.inserted_post.id, post_listings_excluded[0].post.id);
+
+    // the post where both words are next to each other ranks higher
+    let post_listings_relevance = PostQuery {
+      community_id: Some(data.inserted_community.id),
+      sort: Some(SortType::Relevance),
+      search_term: Some("test post".to_string()),
+      ..Default::default()
+    }
+    .list(pool)
+    .await
+    .unwrap();
+    assert_eq!(2, post_listings_relevance.len());
+    assert_eq!(data.inserted_post.id, post_listings_relevance[0].post.id);
+    assert_eq!("test bot post", post_listings_relevance[1].post.name);
+
+    cleanup(data, pool).await;
+  }
+
+  #[tokio::test]
+  #[serial]
+  async fn post_listing_multi_community() {
+    let pool = &build_db_pool_for_tests().await;
+    let pool = &mut pool.into();
+    let data = init_data(pool).await;
+
+    // A post in another community, which isn't part of the multi-community
+    let other_community_form = CommunityInsertForm::builder()
+      .name("test_community_other".to_string())
+      .title("other".to_owned())
+      .public_key("pubkey".to_string())
+      .instance_id(data.inserted_instance.id)
+      .build();
+    let other_community = Community::create(pool, &other_community_form)
+      .await
+      .unwrap();
+    let other_post_form = PostInsertForm::builder()
+      .name("other community post".to_string())
+      .creator_id(data.local_user_view.person.id)
+      .community_id(other_community.id)
+      .build();
+    Post::create(pool, &other_post_form).await.unwrap();
+
+    let multi_community_form = MultiCommunityInsertForm::builder()
+      .creator_id(data.local_user_view.person.id)
+      .instance_id(data.inserted_instance.id)
+      .name("test_multi".to_string())
+      .title("test multi".to_string())
+      .build();
+    let multi_community = MultiCommunity::create(pool, &multi_community_form)
+      .await
+      .unwrap();
+    MultiCommunityEntry::update(pool, multi_community.id, vec![data.inserted_community.id])
+      .await
+      .unwrap();
+
+    let post_listings_multi = PostQuery {
+      multi_community_id: Some(multi_community.id),
+      ..Default::default()
+    }
+    .list(pool)
+    .await
+    .unwrap();
+    assert_eq!(3, post_listings_multi.len());
+    assert!(post_listings_multi
+      .iter()
+      .all(|p| p.community.id == data.inserted_community.id));
+
+    // Adding the other community includes its posts as well
+    MultiCommunityEntry::update(
+      pool,
+      multi_community.id,
+      vec![data.inserted_community.id, other_community.id],
+    )
+    .await
+    .unwrap();
+    let post_listings_multi = PostQuery {
+      multi_community_id: Some(multi_community.id),
+      ..Default::default()
+    }
+    .list(pool)
+    .await
+    .unwrap();
+    assert_eq!(4, post_listings_multi.len());
+
+    Community::delete(pool, other_community.id).await.unwrap();
+    cleanup(data, pool).await;
+  }
+
+  #[tokio::test]
+  #[serial]
+  async fn post_listing_scheduled() {
+    let pool = &build_db_pool_for_tests().await;
+    let pool = &mut pool.into();
+    let data = init_data(pool).await;
+
+    let scheduled_post_form = PostInsertForm::builder()
+      .name("weekly discussion".to_string())
+      .creator_id(data.local_user_view.person.id)
+      .community_id(data.inserted_community.id)
+      .scheduled_publish_time(Some("2099-01-01T00:00:00Z".parse().unwrap()))
+      .build();
+    let scheduled_post = Post::create(pool, &scheduled_post_form).await.unwrap();
+
+    // Scheduled posts are hidden from the listing and from other users
+    let post_listings = PostQuery {
+      community_id: Some(data.inserted_community.id),
+      ..Default::default()
+    }
+    .list(pool)
+    .await
+    .unwrap();
+    assert_eq!(3, post_listings.len());
+    assert!(PostView::read(pool, scheduled_post.id, None, false)
+      .await
+      .is_err());
+
+    // But the creator can see them
+    let read_scheduled_post = PostView::read(
+      pool,
+      scheduled_post.id,
+      Some(data.local_user_view.person.id),
+      false,
+    )
+    .await
+    .unwrap();
+    assert_eq!(scheduled_post.id, read_scheduled_post.post.id);
+    let scheduled_listings = PostQuery {
+      local_user: Some(&data.local_user_view),
+      scheduled_only: true,
+      ..Default::default()
+    }
+    .list(pool)
+    .await
+    .unwrap();
+    assert_eq!(
+      vec![scheduled_post.id],
+      scheduled_listings
+        .iter()
+        .map(|p| p.post.id)
+        .collect::<Vec<_>>()
+    );
+
+    // Once published, the post shows up in the listing
+    let update_form = PostUpdateForm {
+      scheduled_publish_time: Some(None),
+      ..Default::default()
+    };
+    Post::update(pool, scheduled_post.id, &update_form)
+      .await
+      .unwrap();
+    let post_listings = PostQuery {
+      community_id: Some(data.inserted_community.id),
+      ..Default::default()
+    }
+    .list(pool)
+    .await
+    .unwrap();
+    assert_eq!(4, post_listings.len());
+
+    Post::delete(pool, scheduled_post.id).await.unwrap();
+    cleanup(data, pool).await;
+  }
+
+  #[tokio::test]
+  #[serial]
+  async fn post_listing_poll() {
+    let pool = &build_db_pool_for_tests().await;
+    let pool = &mut pool.into();
+    let data = init_data(pool).await;
+
+    let poll_form = PollForm {
+      post_id: data.inserted_post.id,
+      multiple_choice: false,
+      end_time: Some("2099-01-01T00:00:00Z".parse().unwrap()),
+      hide_results: false,
+      voters_count: None,
+    };
+    let option_forms = ["Tea", "Coffee"]
+      .iter()
+      .enumerate()
+      .map(|(position, name)| PollOptionForm {
+        post_id: data.inserted_post.id,
+        name: (*name).to_string(),
+        position: position as i32,
+        vote_count: None,
+      })
+      .collect::<Vec<_>>();
+    Poll::upsert(pool, &poll_form, option_forms.clone())
+      .await
+      .unwrap();
+
+    let post_view = PostView::read(pool, data.inserted_post.id, None, false)
+      .await
+      .unwrap();
+    assert!(post_view.poll.is_some());
+    assert_eq!(
+      vec!["Tea", "Coffee"],
+      post_view
+        .poll_options
+        .iter()
+        .map(|o| o.name.as_str())
+        .collect::<Vec<_>>()
+    );
+
+    let person_id = data.local_user_view.person.id;
+    PollVote::vote(
+      pool,
+      data.inserted_post.id,
+      person_id,
+      vec![post_view.poll_options[1].id],
+    )
+    .await
+    .unwrap();
+    let post_view = PostView::read(pool, data.inserted_post.id, Some(person_id), false)
+      .await
+      .unwrap();
+    assert_eq!(
+      vec![(Some(0), false), (Some(1), true)],
+      post_view
+        .poll_options
+        .iter()
+        .map(|o| (o.vote_count, o.voted))
+        .collect::<Vec<_>>()
+    );
+
+    // Results are hidden until the poll is closed
+    let hidden_poll_form = PollForm {
+      hide_results: true,
+      ..poll_form
+    };
+    Poll::upsert(pool, &hidden_poll_form, option_forms)
+      .await
+      .unwrap();
+    let post_listings = PostQuery {
+      community_id: Some(data.inserted_community.id),
+      local_user: Some(&data.local_user_view),
+      ..Default::default()
+    }
+    .list(pool)
+    .await
+    .unwrap();
+    let poll_post = post_listings
+      .iter()
+      .find(|p| p.post.id == data.inserted_post.id)
+      .unwrap();
+    assert_eq!(
+      vec![(None, false), (None, true)],
+      poll_post
+        .poll_options
+        .iter()
+        .map(|o| (o.vote_count, o.voted))
+        .collect::<Vec<_>>()
+    );
+
+    cleanup(data, pool).await;
+  }
+
+  #[tokio::test]
+  #[serial]
+  async fn post_listing_tag() {
+    let pool = &build_db_pool_for_tests().await;
+    let pool = &mut pool.into();
+    let data = init_data(pool).await;
+
+    let tag_form = CommunityPostTagInsertForm::builder()
+      .community_id(data.inserted_community.id)
+      .display_name("Solved".to_string())
+      .build();
+    let tag = CommunityPostTag::create(pool, &tag_form).await.unwrap();
+
+    let post_listings_tag = PostQuery {
+      tag_id: Some(tag.id),
+      ..Default::default()
+    }
+    .list(pool)
+    .await
+    .unwrap();
+    assert!(post_listings_tag.is_empty());
+
+    PostTag::set(pool, data.inserted_post.id, vec![tag.id])
+      .await
+      .unwrap();
+    let post_listings_tag = PostQuery {
+      tag_id: Some(tag.id),
+      ..Default::default()
+    }
+    .list(pool)
+    .await
+    .unwrap();
+    assert_eq!(
+      vec![data.inserted_post.id],
+      post_listings_tag
+        .iter()
+        .map(|p| p.post.id)
+        .collect::<Vec<_>>()
+    );
+
+    cleanup(data, pool).await;
+  }
+
+  #[tokio::test]
+  #[serial]
+  async fn post_listing_keyword_filter() {
+    let pool = &build_db_pool_for_tests().await;
+    let pool = &mut pool.into();
+    let mut data = init_data(pool).await;
+
+    let local_user_form = LocalUserUpdateForm {
+      show_bot_accounts: Some(true),
+      ..Default::default()
+    };
+    data.local_user_view.local_user =
+      LocalUser::update(pool, data.local_user_view.local_user.id, &local_user_form)
+        .await
+        .unwrap();
+
+    let keyword_form = LocalUserKeywordFilterForm {
+      local_user_id: data.local_user_view.local_user.id,
+      keyword: "BOT".to_string(),
+      is_regex: false,
+      filter_title: true,
+      filter_body: false,
+      filter_url: false,
+      expires: None,
+    };
+    let keyword_filter = LocalUserKeywordFilter::create(pool, &keyword_form)
+      .await
+      .unwrap();
+
+    let post_listings_keyword = PostQuery {
+      community_id: Some(data.inserted_community.id),
+      local_user: Some(&data.local_user_view),
+      ..Default::default()
+    }
+    .list(pool)
+    .await
+    .unwrap();
+    assert_eq!(1, post_listings_keyword.len());
+    assert_eq!(data.inserted_post.id, post_listings_keyword[0].post.id);
+
+    // Regex filters, and expired filters which don't apply anymore
+    LocalUserKeywordFilter::delete(pool, keyword_filter.id, data.local_user_view.local_user.id)
+      .await
+      .unwrap();
+    let regex_form = LocalUserKeywordFilterForm {
+      keyword: "post [0-9]$".to_string(),
+      is_regex: true,
+      ..keyword_form.clone()
+    };
+    LocalUserKeywordFilter::create(pool, &regex_form)
+      .await
+      .unwrap();
+    let expired_form = LocalUserKeywordFilterForm {
+      expires: Some("2020-01-01T00:00:00Z".parse().unwrap()),
+      ..keyword_form
+    };
+    LocalUserKeywordFilter::create(pool, &expired_form)
+      .await
+      .unwrap();
+
+    let post_listings_regex = PostQuery {
+      community_id: Some(data.inserted_community.id),
+      local_user: Some(&data.local_user_view),
+      ..Default::default()
+    }
+    .list(pool)
+    .await
+    .unwrap();
+    assert_eq!(1, post_listings_regex.len());
+    assert_eq!("test bot post", post_listings_regex[0].post.name);
+
+    // Filters don't apply to anonymous users
+    let post_listings_anonymous = PostQuery {
+      community_id: Some(data.inserted_community.id),
+      ..Default::default()
+    }
+    .list(pool)
+    .await
+    .unwrap();
+    assert_eq!(3, post_listings_anonymous.len());
+
+    cleanup(data, pool).await;
+  }
+
+  #[tokio::test]
+  #[serial]
+  async fn post_listing_community_visibility() {
+    let pool = &build_db_pool_for_tests().await;
+    let pool = &mut pool.into();
+    let data = init_data(pool).await;
+    let community_id = data.inserted_community.id;
+
+    let set_visibility = |visibility| CommunityUpdateForm {
+      visibility: Some(visibility),
+      ..Default::default()
+    };
+    let list = |local_user| PostQuery {
+      community_id: Some(community_id),
+      local_user,
+      ..Default::default()
+    };
+
+    // Local-only communities are hidden from logged out users
+    Community::update(
+      pool,
+      community_id,
+      &set_visibility(CommunityVisibility::LocalOnly),
+    )
+    .await
+    .unwrap();
+    assert!(list(None).list(pool).await.unwrap().is_empty());
+    assert!(!list(Some(&data.local_user_view))
+      .list(pool)
+      .await
+      .unwrap()
+      .is_empty());
+
+    // Private communities are only visible to approved followers
+    Community::update(
+      pool,
+      community_id,
+      &set_visibility(CommunityVisibility::Private),
+    )
+    .await
+    .unwrap();
+    assert!(list(None).list(pool).await.unwrap().is_empty());
+    assert!(list(Some(&data.local_user_view))
+      .list(pool)
+      .await
+      .unwrap()
+      .is_empty());
+
+    let follower_form = CommunityFollowerForm {
+      community_id,
+      person_id: data.local_user_view.person.id,
+      pending: true,
+    };
+    CommunityFollower::follow(pool, &follower_form)
+      .await
+      .unwrap();
+    assert!(list(Some(&data.local_user_view))
+      .list(pool)
+      .await
+      .unwrap()
+      .is_empty());
+
+    CommunityFollower::approve(pool, community_id, data.local_user_view.person.id)
+      .await
+      .unwrap();
+    assert!(!list(Some(&data.local_user_view))
+      .list(pool)
+      .await
+      .unwrap()
+      .is_empty());
+
+    CommunityFollower::unfollow(pool, &follower_form)
+      .await
+      .unwrap();
+    cleanup(data, pool).await;
+  }
+
   async fn cleanup(data: Data, pool: &mut DbPool<'_>) {
     let num_deleted = Post::delete(pool, data.inserted_post.id).await.unwrap();
     Community::delete(pool, data.inserted_community.id)
@@ -1375,9 +2148,12 @@ mod tests {
         language_id: LanguageId(47),
         featured_community: false,
         featured_local: false,
+        scheduled_publish_time: None,
       },
       my_vote: None,
       unread_comments: 0,
+      poll: None,
+      poll_options: vec![],
       creator: Person {
         id: inserted_person.id,
         name: inserted_person.name.clone(),
@@ -1397,6 +2173,7 @@ mod tests {
         matrix_user_id: None,
         ban_expires: None,
         instance_id: data.inserted_instance.id,
+        encryption_public_key: None,
         private_key: inserted_person.private_key.clone(),
         public_key: inserted_person.public_key.clone(),
         last_refreshed_at: inserted_person.last_refreshed_at,
@@ -1427,6 +2204,10 @@ mod tests {
         shared_inbox_url: inserted_community.shared_inbox_url.clone(),
         moderators_url: inserted_community.moderators_url.clone(),
         featured_url: inserted_community.featured_url.clone(),
+        visibility: CommunityVisibility::Public,
+        warning_ban_threshold: None,
+        warning_ban_window_days: 30,
+        warning_ban_days: 7,
       },
       counts: PostAggregates {
         id: agg.id,
@@ -1447,6 +2228,7 @@ mod tests {
         community_id: inserted_post.community_id,
         creator_id: inserted_post.creator_id,
         instance_id: data.inserted_instance.id,
+        reaction_counts: Default::default(),
       },
       subscribed: SubscribedType::NotSubscribed,
       read: false,
@@ -113,6 +113,7 @@ mod tests {
   use crate::private_message_report_view::PrivateMessageReportQuery;
   use lemmy_db_schema::{
     source::{
+      conversation::{Conversation, ConversationInsertForm},
       instance::Instance,
       person::{Person, PersonInsertForm},
       private_message::{PrivateMessage, PrivateMessageInsertForm},
@@ -148,9 +149,13 @@ mod tests {
     let inserted_jessica = Person::create(pool, &new_person_2).await.unwrap();
 
     // timmy sends private message to jessica
+    let conversation = Conversation::create(pool, &ConversationInsertForm::builder().build())
+      .await
+      .unwrap();
     let pm_form = PrivateMessageInsertForm::builder()
       .creator_id(inserted_timmy.id)
-      .recipient_id(inserted_jessica.id)
+      .recipient_id(Some(inserted_jessica.id))
+      .conversation_id(conversation.id)
       .content("something offensive".to_string())
       .build();
     let pm = PrivateMessage::create(pool, &pm_form).await.unwrap();
@@ -185,7 +190,7 @@ mod tests {
     let inserted_admin = Person::create(pool, &new_person_3).await.unwrap();
 
     // admin resolves the report (after taking appropriate action)
-    PrivateMessageReport::resolve(pool, pm_report.id, inserted_admin.id)
+    PrivateMessageReport::resolve(pool, pm_report.id, inserted_admin.id, None)
       .await
       .unwrap();
 
@@ -205,5 +210,6 @@ mod tests {
     );
 
     Instance::delete(pool, inserted_instance.id).await.unwrap();
+    Conversation::delete(pool, conversation.id).await.unwrap();
   }
 }
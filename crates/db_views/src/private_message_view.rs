@@ -1,19 +1,30 @@
 use crate::structs::PrivateMessageView;
 use diesel::{
   debug_query,
+  dsl::exists,
   pg::Pg,
   result::Error,
   BoolExpressionMethods,
   ExpressionMethods,
   JoinOnDsl,
+  NullableExpressionMethods,
   QueryDsl,
 };
 use diesel_async::RunQueryDsl;
 use lemmy_db_schema::{
   aliases,
-  newtypes::{PersonId, PrivateMessageId},
-  schema::{person, private_message},
-  utils::{get_conn, limit_and_offset, DbConn, DbPool, ListFn, Queries, ReadFn},
+  newtypes::{ConversationId, PersonId, PrivateMessageId},
+  schema::{conversation, conversation_participant, person, private_message},
+  utils::{
+    functions::coalesce,
+    get_conn,
+    limit_and_offset,
+    DbConn,
+    DbPool,
+    ListFn,
+    Queries,
+    ReadFn,
+  },
 };
 use tracing::debug;
 
@@ -25,7 +36,17 @@ fn queries<'a>() -> Queries<
     query
       .inner_join(person::table.on(private_message::creator_id.eq(person::id)))
       .inner_join(
-        aliases::person1.on(private_message::recipient_id.eq(aliases::person1.field(person::id))),
+        aliases::person1.on(
+          coalesce(private_message::recipient_id, private_message::creator_id)
+            .eq(aliases::person1.field(person::id)),
+        ),
+      )
+      .left_join(
+        conversation::table.on(
+          private_message::conversation_id
+            .eq(conversation::id)
+            .and(private_message::recipient_id.is_null()),
+        ),
       )
   };
 
@@ -33,6 +54,7 @@ fn queries<'a>() -> Queries<
     private_message::all_columns,
     person::all_columns,
     aliases::person1.fields(person::all_columns),
+    conversation::all_columns.nullable(),
   );
 
   let read = move |mut conn: DbConn<'a>, private_message_id: PrivateMessageId| async move {
@@ -47,20 +69,43 @@ fn queries<'a>() -> Queries<
                    (options, recipient_id): (PrivateMessageQuery, PersonId)| async move {
     let mut query = all_joins(private_message::table.into_boxed()).select(selection);
 
+    // Group messages are unread for a participant if they were sent after the participant last
+    // read the conversation
+    let unread_group_message = private_message::recipient_id.is_null().and(exists(
+      conversation_participant::table.filter(
+        conversation_participant::conversation_id
+          .eq(private_message::conversation_id)
+          .and(conversation_participant::person_id.eq(recipient_id))
+          .and(conversation_participant::last_read.lt(private_message::published)),
+      ),
+    ));
+
     // If its unread, I only want the ones to me
     if options.unread_only {
-      query = query.filter(private_message::read.eq(false));
+      query = query.filter(
+        private_message::recipient_id
+          .eq(recipient_id)
+          .and(private_message::read.eq(false))
+          .or(unread_group_message),
+      );
+      query = query.filter(private_message::creator_id.ne(recipient_id));
       if let Some(i) = options.creator_id {
         query = query.filter(private_message::creator_id.eq(i))
       }
-      query = query.filter(private_message::recipient_id.eq(recipient_id));
     }
     // Otherwise, I want the ALL view to show both sent and received
     else {
       query = query.filter(
         private_message::recipient_id
           .eq(recipient_id)
-          .or(private_message::creator_id.eq(recipient_id)),
+          .or(private_message::creator_id.eq(recipient_id))
+          .or(exists(
+            conversation_participant::table.filter(
+              conversation_participant::conversation_id
+                .eq(private_message::conversation_id)
+                .and(conversation_participant::person_id.eq(recipient_id)),
+            ),
+          )),
       );
       if let Some(i) = options.creator_id {
         query = query.filter(
@@ -71,6 +116,10 @@ fn queries<'a>() -> Queries<
       }
     }
 
+    if let Some(conversation_id) = options.conversation_id {
+      query = query.filter(private_message::conversation_id.eq(conversation_id));
+    }
+
     let (limit, offset) = limit_and_offset(options.page, options.limit)?;
 
     query = query
@@ -105,9 +154,28 @@ impl PrivateMessageView {
   ) -> Result<i64, Error> {
     use diesel::dsl::count;
     let conn = &mut get_conn(pool).await?;
+    // Messages of muted conversations are not counted
+    let participant = conversation_participant::table.filter(
+      conversation_participant::conversation_id
+        .eq(private_message::conversation_id)
+        .and(conversation_participant::person_id.eq(my_person_id))
+        .and(conversation_participant::muted.eq(false)),
+    );
     private_message::table
-      .filter(private_message::read.eq(false))
-      .filter(private_message::recipient_id.eq(my_person_id))
+      .filter(
+        private_message::recipient_id
+          .eq(my_person_id)
+          .and(private_message::read.eq(false))
+          .and(exists(participant))
+          .or(
+            private_message::recipient_id
+              .is_null()
+              .and(private_message::creator_id.ne(my_person_id))
+              .and(exists(participant.filter(
+                conversation_participant::last_read.lt(private_message::published),
+              ))),
+          ),
+      )
       .filter(private_message::deleted.eq(false))
       .select(count(private_message::id))
       .first::<i64>(conn)
@@ -121,6 +189,8 @@ pub struct PrivateMessageQuery {
   pub page: Option<i64>,
   pub limit: Option<i64>,
   pub creator_id: Option<PersonId>,
+  /// Only list the messages of this conversation
+  pub conversation_id: Option<ConversationId>,
 }
 
 impl PrivateMessageQuery {
@@ -138,18 +208,30 @@ mod tests {
   #![allow(clippy::unwrap_used)]
   #![allow(clippy::indexing_slicing)]
 
-  use crate::private_message_view::PrivateMessageQuery;
+  use crate::{private_message_view::PrivateMessageQuery, structs::PrivateMessageView};
   use lemmy_db_schema::{
+    newtypes::{ConversationId, PersonId},
     source::{
+      conversation::{Conversation, ConversationInsertForm, ConversationParticipant},
       instance::Instance,
       person::{Person, PersonInsertForm},
       private_message::{PrivateMessage, PrivateMessageInsertForm},
     },
     traits::Crud,
-    utils::build_db_pool_for_tests,
+    utils::{build_db_pool_for_tests, DbPool},
   };
   use serial_test::serial;
 
+  async fn create_conversation(pool: &mut DbPool<'_>, person_ids: &[PersonId]) -> ConversationId {
+    let conversation = Conversation::create(pool, &ConversationInsertForm::builder().build())
+      .await
+      .unwrap();
+    ConversationParticipant::add(pool, conversation.id, person_ids)
+      .await
+      .unwrap();
+    conversation.id
+  }
+
   #[tokio::test]
   #[serial]
   async fn test_crud() {
@@ -185,9 +267,14 @@ mod tests {
 
     let jess = Person::create(pool, &jess_form).await.unwrap();
 
+    let sara_timmy = create_conversation(pool, &[sara.id, timmy.id]).await;
+    let sara_jess = create_conversation(pool, &[sara.id, jess.id]).await;
+    let jess_timmy = create_conversation(pool, &[jess.id, timmy.id]).await;
+
     let sara_timmy_message_form = PrivateMessageInsertForm::builder()
       .creator_id(sara.id)
-      .recipient_id(timmy.id)
+      .recipient_id(Some(timmy.id))
+      .conversation_id(sara_timmy)
       .content(message_content.clone())
       .build();
     let _inserted_sara_timmy_message_form = PrivateMessage::create(pool, &sara_timmy_message_form)
@@ -196,7 +283,8 @@ mod tests {
 
     let sara_jess_message_form = PrivateMessageInsertForm::builder()
       .creator_id(sara.id)
-      .recipient_id(jess.id)
+      .recipient_id(Some(jess.id))
+      .conversation_id(sara_jess)
       .content(message_content.clone())
       .build();
     let _inserted_sara_jess_message_form = PrivateMessage::create(pool, &sara_jess_message_form)
@@ -205,7 +293,8 @@ mod tests {
 
     let timmy_sara_message_form = PrivateMessageInsertForm::builder()
       .creator_id(timmy.id)
-      .recipient_id(sara.id)
+      .recipient_id(Some(sara.id))
+      .conversation_id(sara_timmy)
       .content(message_content.clone())
       .build();
     let _inserted_timmy_sara_message_form = PrivateMessage::create(pool, &timmy_sara_message_form)
@@ -214,7 +303,8 @@ mod tests {
 
     let jess_timmy_message_form = PrivateMessageInsertForm::builder()
       .creator_id(jess.id)
-      .recipient_id(timmy.id)
+      .recipient_id(Some(timmy.id))
+      .conversation_id(jess_timmy)
       .content(message_content.clone())
       .build();
     let _inserted_jess_timmy_message_form = PrivateMessage::create(pool, &jess_timmy_message_form)
@@ -280,5 +370,61 @@ mod tests {
     assert_eq!(timmy_sara_unread_messages.len(), 1);
     assert_eq!(timmy_sara_unread_messages[0].creator.id, sara.id);
     assert_eq!(timmy_sara_unread_messages[0].recipient.id, timmy.id);
+
+    // Group messages are unread until the conversation is marked as read
+    let group = create_conversation(pool, &[sara.id, timmy.id, jess.id]).await;
+    let group_message_form = PrivateMessageInsertForm::builder()
+      .creator_id(sara.id)
+      .content(message_content.clone())
+      .conversation_id(group)
+      .build();
+    PrivateMessage::create(pool, &group_message_form)
+      .await
+      .unwrap();
+
+    let timmy_group_messages = PrivateMessageQuery {
+      conversation_id: Some(group),
+      ..Default::default()
+    }
+    .list(pool, timmy.id)
+    .await
+    .unwrap();
+    assert_eq!(timmy_group_messages.len(), 1);
+    assert_eq!(timmy_group_messages[0].creator.id, sara.id);
+    assert_eq!(timmy_group_messages[0].recipient.id, sara.id);
+    assert_eq!(
+      timmy_group_messages[0]
+        .group_conversation
+        .as_ref()
+        .map(|c| c.id),
+      Some(group)
+    );
+    assert!(timmy_messages[0].group_conversation.is_none());
+
+    let timmy_unread_count = PrivateMessageView::get_unread_messages(pool, timmy.id)
+      .await
+      .unwrap();
+    assert_eq!(timmy_unread_count, 3);
+    let sara_unread_count = PrivateMessageView::get_unread_messages(pool, sara.id)
+      .await
+      .unwrap();
+    assert_eq!(sara_unread_count, 1);
+
+    ConversationParticipant::mark_as_read(pool, group, timmy.id)
+      .await
+      .unwrap();
+    let timmy_unread_count = PrivateMessageView::get_unread_messages(pool, timmy.id)
+      .await
+      .unwrap();
+    assert_eq!(timmy_unread_count, 2);
+
+    // Muted conversations are not counted
+    ConversationParticipant::set_muted(pool, sara_timmy, timmy.id, true)
+      .await
+      .unwrap();
+    let timmy_unread_count = PrivateMessageView::get_unread_messages(pool, timmy.id)
+      .await
+      .unwrap();
+    assert_eq!(timmy_unread_count, 1);
   }
 }
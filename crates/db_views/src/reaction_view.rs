@@ -0,0 +1,263 @@
+use crate::structs::ReactionView;
+use diesel::{
+  dsl::count,
+  pg::Pg,
+  result::Error,
+  BoolExpressionMethods,
+  ExpressionMethods,
+  JoinOnDsl,
+  NullableExpressionMethods,
+  QueryDsl,
+};
+use diesel_async::RunQueryDsl;
+use lemmy_db_schema::{
+  newtypes::{CommentId, PersonId, PostId, ReactionId},
+  schema::{comment, custom_emoji, person, post, reaction},
+  utils::{get_conn, limit_and_offset, DbConn, DbPool, ListFn, Queries, ReadFn},
+};
+
+fn queries<'a>(
+) -> Queries<impl ReadFn<'a, ReactionView, ReactionId>, impl ListFn<'a, ReactionView, ReactionQuery>>
+{
+  let all_joins = |query: reaction::BoxedQuery<'a, Pg>| {
+    query
+      .inner_join(person::table.on(reaction::person_id.eq(person::id)))
+      .inner_join(post::table.on(reaction::post_id.eq(post::id)))
+      .left_join(comment::table.on(reaction::comment_id.eq(comment::id.nullable())))
+      .left_join(custom_emoji::table.on(reaction::custom_emoji_id.eq(custom_emoji::id.nullable())))
+      .select((
+        reaction::all_columns,
+        person::all_columns,
+        post::all_columns,
+        comment::all_columns.nullable(),
+        custom_emoji::all_columns.nullable(),
+      ))
+  };
+
+  let read = move |mut conn: DbConn<'a>, reaction_id: ReactionId| async move {
+    all_joins(reaction::table.find(reaction_id).into_boxed())
+      .first::<ReactionView>(&mut conn)
+      .await
+  };
+
+  let list = move |mut conn: DbConn<'a>, options: ReactionQuery| async move {
+    let mut query = all_joins(reaction::table.into_boxed());
+
+    if let Some(comment_id) = options.comment_id {
+      query = query.filter(reaction::comment_id.eq(comment_id));
+    } else if let Some(post_id) = options.post_id {
+      query = query
+        .filter(reaction::post_id.eq(post_id))
+        .filter(reaction::comment_id.is_null());
+    }
+
+    // Reactions by others on the posts and comments of the recipient
+    if let Some(recipient_id) = options.recipient_id {
+      query = query.filter(reaction::person_id.ne(recipient_id)).filter(
+        reaction::comment_id
+          .is_null()
+          .and(post::creator_id.eq(recipient_id))
+          .or(comment::creator_id.nullable().eq(recipient_id)),
+      );
+    }
+
+    if options.unread_only {
+      query = query.filter(reaction::read.eq(false));
+    }
+
+    let (limit, offset) = limit_and_offset(options.page, options.limit)?;
+
+    query
+      .limit(limit)
+      .offset(offset)
+      .order_by(reaction::published.desc())
+      .load::<ReactionView>(&mut conn)
+      .await
+  };
+
+  Queries::new(read, list)
+}
+
+impl ReactionView {
+  pub async fn read(pool: &mut DbPool<'_>, reaction_id: ReactionId) -> Result<Self, Error> {
+    queries().read(pool, reaction_id).await
+  }
+
+  /// Gets the number of unread reactions by others on the posts and comments of a person.
+  pub async fn get_unread_count(
+    pool: &mut DbPool<'_>,
+    recipient_id: PersonId,
+  ) -> Result<i64, Error> {
+    let conn = &mut get_conn(pool).await?;
+    reaction::table
+      .inner_join(post::table.on(reaction::post_id.eq(post::id)))
+      .left_join(comment::table.on(reaction::comment_id.eq(comment::id.nullable())))
+      .filter(reaction::read.eq(false))
+      .filter(reaction::person_id.ne(recipient_id))
+      .filter(
+        reaction::comment_id
+          .is_null()
+          .and(post::creator_id.eq(recipient_id))
+          .or(comment::creator_id.nullable().eq(recipient_id)),
+      )
+      .select(count(reaction::id))
+      .first::<i64>(conn)
+      .await
+  }
+}
+
+#[derive(Default)]
+pub struct ReactionQuery {
+  /// Only list the reactions on this post, not those on its comments.
+  pub post_id: Option<PostId>,
+  pub comment_id: Option<CommentId>,
+  /// Only list reactions by others on the posts and comments of this person.
+  pub recipient_id: Option<PersonId>,
+  pub unread_only: bool,
+  pub page: Option<i64>,
+  pub limit: Option<i64>,
+}
+
+impl ReactionQuery {
+  pub async fn list(self, pool: &mut DbPool<'_>) -> Result<Vec<ReactionView>, Error> {
+    queries().list(pool, self).await
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  #![allow(clippy::unwrap_used)]
+  #![allow(clippy::indexing_slicing)]
+
+  use crate::{reaction_view::ReactionQuery, structs::ReactionView};
+  use lemmy_db_schema::{
+    source::{
+      comment::{Comment, CommentInsertForm},
+      community::{Community, CommunityInsertForm},
+      instance::Instance,
+      person::{Person, PersonInsertForm},
+      post::{Post, PostInsertForm},
+      reaction::{Reaction, ReactionForm},
+    },
+    traits::Crud,
+    utils::build_db_pool_for_tests,
+  };
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_reaction_notifications() {
+    let pool = &build_db_pool_for_tests().await;
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld".to_string())
+      .await
+      .unwrap();
+
+    let new_person = PersonInsertForm::builder()
+      .name("reaction_view_author".into())
+      .public_key("pubkey".to_string())
+      .instance_id(inserted_instance.id)
+      .build();
+    let author = Person::create(pool, &new_person).await.unwrap();
+
+    let new_person = PersonInsertForm::builder()
+      .name("reaction_view_reactor".into())
+      .public_key("pubkey".to_string())
+      .instance_id(inserted_instance.id)
+      .build();
+    let reactor = Person::create(pool, &new_person).await.unwrap();
+
+    let new_community = CommunityInsertForm::builder()
+      .name("test_community_reaction_view".to_string())
+      .title("nada".to_owned())
+      .public_key("pubkey".to_string())
+      .instance_id(inserted_instance.id)
+      .build();
+    let inserted_community = Community::create(pool, &new_community).await.unwrap();
+
+    let new_post = PostInsertForm::builder()
+      .name("A test post".into())
+      .creator_id(author.id)
+      .community_id(inserted_community.id)
+      .build();
+    let inserted_post = Post::create(pool, &new_post).await.unwrap();
+
+    // The reactor replies to the post, and the author reacts to that
+    let comment_form = CommentInsertForm::builder()
+      .content("A test comment".into())
+      .creator_id(reactor.id)
+      .post_id(inserted_post.id)
+      .build();
+    let inserted_comment = Comment::create(pool, &comment_form, None).await.unwrap();
+
+    let post_reaction = ReactionForm {
+      person_id: reactor.id,
+      post_id: inserted_post.id,
+      comment_id: None,
+      emoji: "👍".to_string(),
+      custom_emoji_id: None,
+    };
+    Reaction::react(pool, &post_reaction).await.unwrap();
+    let comment_reaction = ReactionForm {
+      person_id: author.id,
+      comment_id: Some(inserted_comment.id),
+      ..post_reaction.clone()
+    };
+    Reaction::react(pool, &comment_reaction).await.unwrap();
+
+    let post_reactions = ReactionQuery {
+      post_id: Some(inserted_post.id),
+      ..Default::default()
+    }
+    .list(pool)
+    .await
+    .unwrap();
+    assert_eq!(1, post_reactions.len());
+    assert_eq!(reactor.id, post_reactions[0].creator.id);
+    assert!(post_reactions[0].comment.is_none());
+
+    let comment_reactions = ReactionQuery {
+      comment_id: Some(inserted_comment.id),
+      ..Default::default()
+    }
+    .list(pool)
+    .await
+    .unwrap();
+    assert_eq!(1, comment_reactions.len());
+    assert_eq!(author.id, comment_reactions[0].creator.id);
+
+    // Each person is only notified about the reaction on their own content
+    let author_unread = ReactionQuery {
+      recipient_id: Some(author.id),
+      unread_only: true,
+      ..Default::default()
+    }
+    .list(pool)
+    .await
+    .unwrap();
+    assert_eq!(1, author_unread.len());
+    assert_eq!(post_reactions[0].reaction.id, author_unread[0].reaction.id);
+    assert_eq!(
+      1,
+      ReactionView::get_unread_count(pool, reactor.id)
+        .await
+        .unwrap()
+    );
+
+    Reaction::mark_all_as_read(pool, author.id).await.unwrap();
+    assert_eq!(
+      0,
+      ReactionView::get_unread_count(pool, author.id)
+        .await
+        .unwrap()
+    );
+
+    Community::delete(pool, inserted_community.id)
+      .await
+      .unwrap();
+    Person::delete(pool, author.id).await.unwrap();
+    Person::delete(pool, reactor.id).await.unwrap();
+    Instance::delete(pool, inserted_instance.id).await.unwrap();
+  }
+}
@@ -268,6 +268,8 @@ mod tests {
         totp_2fa_enabled: inserted_sara_local_user.totp_2fa_enabled,
         enable_keyboard_navigation: inserted_sara_local_user.enable_keyboard_navigation,
         enable_animated_images: inserted_sara_local_user.enable_animated_images,
+        email_digest: inserted_sara_local_user.email_digest,
+        last_email_digest: inserted_sara_local_user.last_email_digest,
       },
       creator: Person {
         id: inserted_sara_person.id,
@@ -279,6 +281,7 @@ mod tests {
         local: true,
         banned: false,
         ban_expires: None,
+        encryption_public_key: None,
         deleted: false,
         bot_account: false,
         bio: None,
@@ -357,6 +360,7 @@ mod tests {
       local: true,
       banned: false,
       ban_expires: None,
+      encryption_public_key: None,
       deleted: false,
       bot_account: false,
       bio: None,
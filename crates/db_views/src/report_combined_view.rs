@@ -0,0 +1,613 @@
+use crate::structs::{
+  CommentReportView,
+  LocalUserView,
+  PaginationCursor,
+  PostReportView,
+  PrivateMessageReportView,
+  ReportCombinedView,
+  ReportQueueItem,
+};
+use diesel::{
+  dsl::exists,
+  pg::Pg,
+  result::Error,
+  BoolExpressionMethods,
+  ExpressionMethods,
+  JoinOnDsl,
+  NullableExpressionMethods,
+  QueryDsl,
+};
+use diesel_async::RunQueryDsl;
+use lemmy_db_schema::{
+  aggregates::structs::{CommentAggregates, PostAggregates},
+  aliases,
+  newtypes::{CommunityId, PersonId, ReportCombinedId},
+  schema::{
+    comment,
+    comment_aggregates,
+    comment_like,
+    comment_report,
+    comment_revision,
+    community,
+    community_moderator,
+    community_person_ban,
+    person,
+    post,
+    post_aggregates,
+    post_like,
+    post_report,
+    post_revision,
+    private_message,
+    private_message_report,
+    report_combined,
+  },
+  source::{
+    comment::Comment,
+    comment_report::CommentReport,
+    community::Community,
+    person::Person,
+    post::Post,
+    post_report::PostReport,
+    private_message::PrivateMessage,
+    private_message_report::PrivateMessageReport,
+    report_combined::ReportCombined,
+  },
+  utils::{format_cursor, limit_and_offset, parse_cursor, DbConn, DbPool, ListFn, Queries, ReadFn},
+};
+
+type ReportCombinedRow = (
+  ReportCombined,
+  Option<Person>,
+  Option<PostReport>,
+  Option<CommentReport>,
+  Option<PrivateMessageReport>,
+  Option<Post>,
+  Option<Comment>,
+  Option<PrivateMessage>,
+  Option<Community>,
+  Option<Person>,
+  Option<Person>,
+  Option<Person>,
+  bool,
+  Option<i16>,
+  Option<i16>,
+  Option<PostAggregates>,
+  Option<CommentAggregates>,
+  bool,
+  bool,
+);
+
+impl TryFrom<ReportCombinedRow> for ReportCombinedView {
+  type Error = Error;
+
+  fn try_from(row: ReportCombinedRow) -> Result<Self, Self::Error> {
+    let (
+      report_combined,
+      assignee,
+      post_report,
+      comment_report,
+      private_message_report,
+      post,
+      comment,
+      private_message,
+      community,
+      reporter,
+      creator,
+      resolver,
+      creator_banned_from_community,
+      my_post_vote,
+      my_comment_vote,
+      post_counts,
+      comment_counts,
+      post_edited_after_report,
+      comment_edited_after_report,
+    ) = row;
+
+    // Every entry points to exactly one report, which the database keeps up to date
+    let report = if let Some(post_report) = post_report {
+      post
+        .zip(community)
+        .zip(reporter.zip(creator))
+        .zip(post_counts)
+        .map(|(((post, community), (reporter, creator)), counts)| {
+          ReportQueueItem::PostReport(PostReportView {
+            post_report,
+            post,
+            community,
+            creator: reporter,
+            post_creator: creator,
+            creator_banned_from_community,
+            my_vote: my_post_vote,
+            counts,
+            resolver,
+            edited_after_report: post_edited_after_report,
+          })
+        })
+    } else if let Some(comment_report) = comment_report {
+      comment
+        .zip(post.zip(community))
+        .zip(reporter.zip(creator))
+        .zip(comment_counts)
+        .map(
+          |(((comment, (post, community)), (reporter, creator)), counts)| {
+            ReportQueueItem::CommentReport(CommentReportView {
+              comment_report,
+              comment,
+              post,
+              community,
+              creator: reporter,
+              comment_creator: creator,
+              counts,
+              creator_banned_from_community,
+              my_vote: my_comment_vote,
+              resolver,
+              edited_after_report: comment_edited_after_report,
+            })
+          },
+        )
+    } else if let Some(private_message_report) = private_message_report {
+      private_message
+        .zip(reporter.zip(creator))
+        .map(|(private_message, (reporter, creator))| {
+          ReportQueueItem::PrivateMessageReport(PrivateMessageReportView {
+            private_message_report,
+            private_message,
+            private_message_creator: creator,
+            creator: reporter,
+            resolver,
+          })
+        })
+    } else {
+      None
+    };
+
+    let report =
+      report.ok_or_else(|| Error::QueryBuilderError("Incomplete report queue entry".into()))?;
+    Ok(ReportCombinedView {
+      report_combined,
+      report,
+      assignee,
+    })
+  }
+}
+
+fn queries<'a>() -> Queries<
+  impl ReadFn<'a, ReportCombinedView, (ReportCombinedId, PersonId)>,
+  impl ListFn<'a, ReportCombinedView, (ReportCombinedQuery, &'a LocalUserView)>,
+> {
+  // Reads the newest report of every entry together with everything its view needs. Only the
+  // tables of one kind of report are joined for an entry, the others stay empty.
+  let all_joins = |query: report_combined::BoxedQuery<'a, Pg>, my_person_id: PersonId| {
+    query
+      .left_join(
+        aliases::person3
+          .on(report_combined::assignee_id.eq(aliases::person3.field(person::id).nullable())),
+      )
+      .left_join(
+        post_report::table.on(report_combined::post_report_id.eq(post_report::id.nullable())),
+      )
+      .left_join(
+        comment_report::table
+          .on(report_combined::comment_report_id.eq(comment_report::id.nullable())),
+      )
+      .left_join(
+        private_message_report::table
+          .on(report_combined::private_message_report_id.eq(private_message_report::id.nullable())),
+      )
+      .left_join(comment::table.on(report_combined::comment_id.eq(comment::id.nullable())))
+      .left_join(
+        post::table.on(
+          report_combined::post_id
+            .eq(post::id.nullable())
+            .or(comment::post_id.nullable().eq(post::id.nullable())),
+        ),
+      )
+      .left_join(
+        private_message::table
+          .on(report_combined::private_message_id.eq(private_message::id.nullable())),
+      )
+      .left_join(community::table.on(report_combined::community_id.eq(community::id.nullable())))
+      .left_join(
+        person::table.on(
+          post_report::creator_id
+            .eq(person::id)
+            .or(comment_report::creator_id.eq(person::id))
+            .or(private_message_report::creator_id.eq(person::id)),
+        ),
+      )
+      .left_join(
+        aliases::person1.on(
+          report_combined::post_id
+            .is_not_null()
+            .and(post::creator_id.eq(aliases::person1.field(person::id)))
+            .or(
+              report_combined::comment_id
+                .is_not_null()
+                .and(comment::creator_id.eq(aliases::person1.field(person::id))),
+            )
+            .or(
+              report_combined::private_message_id
+                .is_not_null()
+                .and(private_message::creator_id.eq(aliases::person1.field(person::id))),
+            ),
+        ),
+      )
+      .left_join(
+        aliases::person2.on(
+          post_report::resolver_id
+            .eq(aliases::person2.field(person::id).nullable())
+            .or(comment_report::resolver_id.eq(aliases::person2.field(person::id).nullable()))
+            .or(
+              private_message_report::resolver_id.eq(aliases::person2.field(person::id).nullable()),
+            ),
+        ),
+      )
+      .left_join(
+        community_person_ban::table.on(
+          report_combined::community_id
+            .eq(community_person_ban::community_id.nullable())
+            .and(community_person_ban::person_id.eq(aliases::person1.field(person::id))),
+        ),
+      )
+      .left_join(
+        post_like::table.on(
+          report_combined::post_id
+            .eq(post_like::post_id.nullable())
+            .and(post_like::person_id.eq(my_person_id)),
+        ),
+      )
+      .left_join(
+        comment_like::table.on(
+          report_combined::comment_id
+            .eq(comment_like::comment_id.nullable())
+            .and(comment_like::person_id.eq(my_person_id)),
+        ),
+      )
+      .left_join(
+        post_aggregates::table.on(report_combined::post_id.eq(post_aggregates::post_id.nullable())),
+      )
+      .left_join(
+        comment_aggregates::table
+          .on(report_combined::comment_id.eq(comment_aggregates::comment_id.nullable())),
+      )
+      .select((
+        report_combined::all_columns,
+        aliases::person3.fields(person::all_columns).nullable(),
+        post_report::all_columns.nullable(),
+        comment_report::all_columns.nullable(),
+        private_message_report::all_columns.nullable(),
+        post::all_columns.nullable(),
+        comment::all_columns.nullable(),
+        private_message::all_columns.nullable(),
+        community::all_columns.nullable(),
+        person::all_columns.nullable(),
+        aliases::person1.fields(person::all_columns).nullable(),
+        aliases::person2.fields(person::all_columns).nullable(),
+        community_person_ban::id.nullable().is_not_null(),
+        post_like::score.nullable(),
+        comment_like::score.nullable(),
+        post_aggregates::all_columns.nullable(),
+        comment_aggregates::all_columns.nullable(),
+        exists(
+          post_revision::table.filter(
+            post_revision::post_id
+              .eq(post_report::post_id)
+              .and(post_revision::edited.ge(post_report::published)),
+          ),
+        ),
+        exists(
+          comment_revision::table.filter(
+            comment_revision::comment_id
+              .eq(comment_report::comment_id)
+              .and(comment_revision::edited.ge(comment_report::published)),
+          ),
+        ),
+      ))
+  };
+
+  let read = move |mut conn: DbConn<'a>,
+                   (report_combined_id, my_person_id): (ReportCombinedId, PersonId)| async move {
+    all_joins(
+      report_combined::table.find(report_combined_id).into_boxed(),
+      my_person_id,
+    )
+    .first::<ReportCombinedRow>(&mut conn)
+    .await?
+    .try_into()
+  };
+
+  let list = move |mut conn: DbConn<'a>,
+                   (options, user): (ReportCombinedQuery, &'a LocalUserView)| async move {
+    let (limit, _) = limit_and_offset(None, options.limit)?;
+    let page_after = options
+      .page_after
+      .as_ref()
+      .map(PaginationCursor::read_report)
+      .transpose()?;
+
+    let mut query = all_joins(report_combined::table.into_boxed(), user.person.id);
+
+    // If its not an admin, get only the ones you mod
+    if !user.local_user.admin {
+      query = query.filter(
+        report_combined::community_id.eq_any(
+          community_moderator::table
+            .filter(community_moderator::person_id.eq(user.person.id))
+            .select(community_moderator::community_id.nullable()),
+        ),
+      );
+    }
+
+    if let Some(community_id) = options.community_id {
+      query = query.filter(report_combined::community_id.eq(community_id));
+    }
+
+    if options.unresolved_only {
+      query = query.filter(report_combined::unresolved_report_count.gt(0));
+    }
+
+    if let Some(assignee_id) = options.assignee_id {
+      query = query.filter(report_combined::assignee_id.eq(assignee_id));
+    } else if options.unassigned_only {
+      query = query.filter(report_combined::assignee_id.is_null());
+    }
+
+    // The id is used because published changes with every new report, which would move entries
+    // between pages
+    if let Some(id) = page_after {
+      query = query.filter(report_combined::id.lt(id));
+    }
+
+    query
+      .order_by(report_combined::id.desc())
+      .limit(limit)
+      .load::<ReportCombinedRow>(&mut conn)
+      .await?
+      .into_iter()
+      .map(TryInto::try_into)
+      .collect()
+  };
+
+  Queries::new(read, list)
+}
+
+impl ReportCombinedView {
+  pub async fn read(
+    pool: &mut DbPool<'_>,
+    report_combined_id: ReportCombinedId,
+    my_person_id: PersonId,
+  ) -> Result<Self, Error> {
+    queries()
+      .read(pool, (report_combined_id, my_person_id))
+      .await
+  }
+}
+
+impl PaginationCursor {
+  pub fn after_report(view: &ReportCombinedView) -> PaginationCursor {
+    PaginationCursor(format_cursor('R', [i64::from(view.report_combined.id.0)]))
+  }
+
+  fn read_report(&self) -> Result<i32, Error> {
+    parse_cursor(&self.0, 'R', |[id]| i32::try_from(id).ok())
+  }
+}
+
+/// Lists the report queue, newest entries first. Admins see all entries, mods
+/// only those of the communities they moderate.
+#[derive(Default)]
+pub struct ReportCombinedQuery {
+  pub community_id: Option<CommunityId>,
+  /// Only entries which still have unresolved reports
+  pub unresolved_only: bool,
+  pub assignee_id: Option<PersonId>,
+  pub unassigned_only: bool,
+  pub page_after: Option<PaginationCursor>,
+  pub limit: Option<i64>,
+}
+
+impl ReportCombinedQuery {
+  pub async fn list(
+    self,
+    pool: &mut DbPool<'_>,
+    user: &LocalUserView,
+  ) -> Result<Vec<ReportCombinedView>, Error> {
+    queries().list(pool, (self, user)).await
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  #![allow(clippy::unwrap_used)]
+  #![allow(clippy::indexing_slicing)]
+
+  use crate::{
+    report_combined_view::ReportCombinedQuery,
+    structs::{LocalUserView, PaginationCursor, ReportCombinedView, ReportQueueItem},
+  };
+  use lemmy_db_schema::{
+    source::{
+      comment::{Comment, CommentInsertForm},
+      comment_report::{CommentReport, CommentReportForm},
+      community::{Community, CommunityInsertForm},
+      instance::Instance,
+      local_user::{LocalUser, LocalUserInsertForm},
+      person::{Person, PersonInsertForm},
+      post::{Post, PostInsertForm},
+      post_report::{PostReport, PostReportForm},
+    },
+    traits::{Crud, Reportable},
+    utils::build_db_pool_for_tests,
+  };
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_report_queue() {
+    let pool = &build_db_pool_for_tests().await;
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld".to_string())
+      .await
+      .unwrap();
+
+    let new_admin = PersonInsertForm::builder()
+      .name("queue_admin".into())
+      .public_key("pubkey".to_string())
+      .instance_id(inserted_instance.id)
+      .build();
+    let inserted_admin = Person::create(pool, &new_admin).await.unwrap();
+    let new_local_user = LocalUserInsertForm::builder()
+      .person_id(inserted_admin.id)
+      .password_encrypted("123".to_string())
+      .admin(Some(true))
+      .build();
+    let admin_local_user = LocalUser::create(pool, &new_local_user).await.unwrap();
+    let admin_view = LocalUserView {
+      local_user: admin_local_user,
+      person: inserted_admin.clone(),
+      counts: Default::default(),
+    };
+
+    let new_person = PersonInsertForm::builder()
+      .name("queue_reporter".into())
+      .public_key("pubkey".to_string())
+      .instance_id(inserted_instance.id)
+      .build();
+    let inserted_reporter = Person::create(pool, &new_person).await.unwrap();
+
+    let new_person = PersonInsertForm::builder()
+      .name("queue_creator".into())
+      .public_key("pubkey".to_string())
+      .instance_id(inserted_instance.id)
+      .build();
+    let inserted_creator = Person::create(pool, &new_person).await.unwrap();
+
+    let new_community = CommunityInsertForm::builder()
+      .name("queue_community".to_string())
+      .title("nada".to_owned())
+      .public_key("pubkey".to_string())
+      .instance_id(inserted_instance.id)
+      .build();
+    let inserted_community = Community::create(pool, &new_community).await.unwrap();
+
+    let new_post = PostInsertForm::builder()
+      .name("A reported post".into())
+      .creator_id(inserted_creator.id)
+      .community_id(inserted_community.id)
+      .build();
+    let inserted_post = Post::create(pool, &new_post).await.unwrap();
+
+    let comment_form = CommentInsertForm::builder()
+      .content("A reported comment".into())
+      .creator_id(inserted_creator.id)
+      .post_id(inserted_post.id)
+      .build();
+    let inserted_comment = Comment::create(pool, &comment_form, None).await.unwrap();
+
+    let post_report_form = PostReportForm {
+      creator_id: inserted_reporter.id,
+      post_id: inserted_post.id,
+      original_post_name: "A reported post".into(),
+      original_post_url: None,
+      original_post_body: None,
+      reason: "spam".into(),
+      ap_id: None,
+    };
+    let post_report = PostReport::report(pool, &post_report_form).await.unwrap();
+
+    let comment_report_form = CommentReportForm {
+      creator_id: inserted_reporter.id,
+      comment_id: inserted_comment.id,
+      original_comment_text: "A reported comment".into(),
+      reason: "rude".into(),
+      ap_id: None,
+    };
+    let comment_report = CommentReport::report(pool, &comment_report_form)
+      .await
+      .unwrap();
+
+    let reports = ReportCombinedQuery::default()
+      .list(pool, &admin_view)
+      .await
+      .unwrap();
+    assert_eq!(2, reports.len());
+    let ReportQueueItem::CommentReport(comment_report_view) = &reports[0].report else {
+      panic!("expected comment report, got {:?}", reports[0].report);
+    };
+    assert_eq!(comment_report.id, comment_report_view.comment_report.id);
+    assert_eq!(inserted_comment.id, comment_report_view.comment.id);
+    assert_eq!(inserted_post.id, comment_report_view.post.id);
+    assert_eq!(inserted_reporter.id, comment_report_view.creator.id);
+    assert_eq!(inserted_creator.id, comment_report_view.comment_creator.id);
+    let ReportQueueItem::PostReport(post_report_view) = &reports[1].report else {
+      panic!("expected post report, got {:?}", reports[1].report);
+    };
+    assert_eq!(post_report.id, post_report_view.post_report.id);
+    assert_eq!(inserted_reporter.id, post_report_view.creator.id);
+    assert_eq!(inserted_creator.id, post_report_view.post_creator.id);
+    assert!(post_report_view.resolver.is_none());
+
+    // The list reads the same views as reading a single entry
+    let read_view =
+      ReportCombinedView::read(pool, reports[1].report_combined.id, inserted_admin.id)
+        .await
+        .unwrap();
+    assert_eq!(reports[1].report, read_view.report);
+
+    // A new report about the post doesn't move its entry to another page
+    let first_page = ReportCombinedQuery {
+      limit: Some(1),
+      ..Default::default()
+    }
+    .list(pool, &admin_view)
+    .await
+    .unwrap();
+    assert_eq!(1, first_page.len());
+    let post_report_form = PostReportForm {
+      creator_id: inserted_admin.id,
+      reason: "still spam".into(),
+      ..post_report_form
+    };
+    PostReport::report(pool, &post_report_form).await.unwrap();
+    let second_page = ReportCombinedQuery {
+      page_after: first_page.last().map(PaginationCursor::after_report),
+      limit: Some(1),
+      ..Default::default()
+    }
+    .list(pool, &admin_view)
+    .await
+    .unwrap();
+    assert_eq!(1, second_page.len());
+    assert_eq!(
+      reports[1].report_combined.id,
+      second_page[0].report_combined.id
+    );
+    assert_eq!(2, second_page[0].report_combined.report_count);
+    let last_page = ReportCombinedQuery {
+      page_after: second_page.last().map(PaginationCursor::after_report),
+      ..Default::default()
+    }
+    .list(pool, &admin_view)
+    .await
+    .unwrap();
+    assert!(last_page.is_empty());
+
+    // Mods only see the reports of their communities
+    let mut non_mod_view = admin_view.clone();
+    non_mod_view.local_user.admin = false;
+    let reports = ReportCombinedQuery::default()
+      .list(pool, &non_mod_view)
+      .await
+      .unwrap();
+    assert!(reports.is_empty());
+
+    Person::delete(pool, inserted_admin.id).await.unwrap();
+    Person::delete(pool, inserted_reporter.id).await.unwrap();
+    Person::delete(pool, inserted_creator.id).await.unwrap();
+    Community::delete(pool, inserted_community.id)
+      .await
+      .unwrap();
+    Instance::delete(pool, inserted_instance.id).await.unwrap();
+  }
+}
@@ -0,0 +1,36 @@
+use crate::structs::ReportNoteView;
+use diesel::{result::Error, ExpressionMethods, JoinOnDsl, QueryDsl};
+use diesel_async::RunQueryDsl;
+use lemmy_db_schema::{
+  newtypes::{ReportCombinedId, ReportNoteId},
+  schema::{person, report_note},
+  utils::{get_conn, DbPool},
+};
+
+impl ReportNoteView {
+  pub async fn read(pool: &mut DbPool<'_>, report_note_id: ReportNoteId) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    report_note::table
+      .find(report_note_id)
+      .inner_join(person::table.on(report_note::creator_id.eq(person::id)))
+      .select((report_note::all_columns, person::all_columns))
+      .first::<Self>(conn)
+      .await
+  }
+
+  /// Lists the notes about a report queue entry, oldest first so that they read like a
+  /// conversation.
+  pub async fn list(
+    pool: &mut DbPool<'_>,
+    report_combined_id: ReportCombinedId,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    report_note::table
+      .inner_join(person::table.on(report_note::creator_id.eq(person::id)))
+      .filter(report_note::report_combined_id.eq(report_combined_id))
+      .select((report_note::all_columns, person::all_columns))
+      .order_by(report_note::published.asc())
+      .load::<Self>(conn)
+      .await
+  }
+}
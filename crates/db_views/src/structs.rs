@@ -6,17 +6,22 @@ use lemmy_db_schema::{
     comment::Comment,
     comment_report::CommentReport,
     community::Community,
+    conversation::Conversation,
     custom_emoji::CustomEmoji,
     custom_emoji_keyword::CustomEmojiKeyword,
     local_site::LocalSite,
     local_site_rate_limit::LocalSiteRateLimit,
     local_user::LocalUser,
     person::Person,
+    poll::{Poll, PollOptionResult},
     post::Post,
     post_report::PostReport,
     private_message::PrivateMessage,
     private_message_report::PrivateMessageReport,
+    reaction::Reaction,
     registration_application::RegistrationApplication,
+    report_combined::ReportCombined,
+    report_note::ReportNote,
     site::Site,
   },
   SubscribedType,
@@ -42,6 +47,9 @@ pub struct CommentReportView {
   pub creator_banned_from_community: bool,
   pub my_vote: Option<i16>,
   pub resolver: Option<Person>,
+  /// Whether the comment was edited after the report was made. The reported content is in
+  /// `original_comment_text`.
+  pub edited_after_report: bool,
 }
 
 #[skip_serializing_none]
@@ -87,6 +95,9 @@ pub struct PostReportView {
   pub my_vote: Option<i16>,
   pub counts: PostAggregates,
   pub resolver: Option<Person>,
+  /// Whether the post was edited after the report was made. The reported content is in the
+  /// `original_post_*` fields.
+  pub edited_after_report: bool,
 }
 
 /// currently this is just a wrapper around post id, but should be seen as opaque from the client's perspective
@@ -114,8 +125,15 @@ pub struct PostView {
   pub creator_blocked: bool,
   pub my_vote: Option<i16>,
   pub unread_comments: i64,
+  pub poll: Option<Poll>,
+  #[cfg_attr(
+    feature = "full",
+    diesel(deserialize_as = lemmy_db_schema::source::poll::PollOptionResults)
+  )]
+  pub poll_options: Vec<PollOptionResult>,
 }
 
+#[skip_serializing_none]
 #[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone)]
 #[cfg_attr(feature = "full", derive(TS, Queryable))]
 #[cfg_attr(feature = "full", ts(export))]
@@ -123,7 +141,10 @@ pub struct PostView {
 pub struct PrivateMessageView {
   pub private_message: PrivateMessage,
   pub creator: Person,
+  /// For group messages, which have no single recipient, this is the creator.
   pub recipient: Person,
+  /// Only set for group messages, which are sent to all participants of the conversation.
+  pub group_conversation: Option<Conversation>,
 }
 
 #[skip_serializing_none]
@@ -170,3 +191,61 @@ pub struct CustomEmojiView {
   pub custom_emoji: CustomEmoji,
   pub keywords: Vec<CustomEmojiKeyword>,
 }
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A conversation view, as seen by one of its participants.
+pub struct ConversationView {
+  pub conversation: Conversation,
+  pub participants: Vec<Person>,
+  pub muted: bool,
+  pub unread_count: i64,
+  pub last_message: Option<PrivateMessageView>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS, Queryable))]
+#[cfg_attr(feature = "full", ts(export))]
+/// An emoji reaction view.
+pub struct ReactionView {
+  pub reaction: Reaction,
+  pub creator: Person,
+  pub post: Post,
+  pub comment: Option<Comment>,
+  pub custom_emoji: Option<CustomEmoji>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+/// An entry of the report queue, with the newest report about the item.
+pub struct ReportCombinedView {
+  pub report_combined: ReportCombined,
+  pub report: ReportQueueItem,
+  /// The mod who claimed the entry.
+  pub assignee: Option<Person>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+#[serde(tag = "type_")]
+/// The newest report of a report queue entry.
+pub enum ReportQueueItem {
+  PostReport(PostReportView),
+  CommentReport(CommentReportView),
+  PrivateMessageReport(PrivateMessageReportView),
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS, Queryable))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A note of a moderator about a report queue entry.
+pub struct ReportNoteView {
+  pub report_note: ReportNote,
+  pub creator: Person,
+}
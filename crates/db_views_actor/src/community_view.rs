@@ -21,7 +21,16 @@ use lemmy_db_schema::{
     local_user,
   },
   source::{community::CommunityFollower, local_user::LocalUser},
-  utils::{fuzzy_search, limit_and_offset, DbConn, DbPool, ListFn, Queries, ReadFn},
+  utils::{
+    full_text_search,
+    fuzzy_search,
+    limit_and_offset,
+    DbConn,
+    DbPool,
+    ListFn,
+    Queries,
+    ReadFn,
+  },
   ListingType,
   SortType,
 };
@@ -102,11 +111,18 @@ fn queries<'a>() -> Queries<
       .left_join(local_user::table.on(local_user::person_id.eq(person_id_join)))
       .select(selection);
 
-    if let Some(search_term) = options.search_term {
-      let searcher = fuzzy_search(&search_term);
-      query = query
-        .filter(community::name.ilike(searcher.clone()))
-        .or_filter(community::title.ilike(searcher))
+    if let Some(ids) = options.ids {
+      query = query.filter(community::id.eq_any(ids));
+    }
+
+    if let Some(search_term) = &options.search_term {
+      // Full-text search only matches whole words, so names are also matched as substrings
+      let searcher = fuzzy_search(search_term);
+      query = query.filter(
+        full_text_search::matches("community", search_term)
+          .or(community::name.ilike(searcher.clone()))
+          .or(community::title.ilike(searcher)),
+      );
     }
 
     // Hide deleted and removed for non-admins or mods
@@ -135,6 +151,14 @@ fn queries<'a>() -> Queries<
       }
       TopMonth => query = query.order_by(community_aggregates::users_active_month.desc()),
       TopWeek => query = query.order_by(community_aggregates::users_active_week.desc()),
+      Relevance => {
+        query = match &options.search_term {
+          Some(search_term) => {
+            query.order_by(full_text_search::rank("community", search_term).desc())
+          }
+          None => query.order_by(community_aggregates::hot_rank.desc()),
+        }
+      }
     };
 
     if let Some(listing_type) = options.listing_type {
@@ -202,6 +226,8 @@ pub struct CommunityQuery<'a> {
   pub sort: Option<SortType>,
   pub local_user: Option<&'a LocalUser>,
   pub search_term: Option<String>,
+  /// Only show the communities with these ids, like the results of a combined search
+  pub ids: Option<Vec<CommunityId>>,
   pub is_mod_or_admin: bool,
   pub show_nsfw: bool,
   pub page: Option<i64>,
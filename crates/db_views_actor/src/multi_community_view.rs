@@ -0,0 +1,48 @@
+use crate::structs::MultiCommunityView;
+use diesel::{result::Error, ExpressionMethods, QueryDsl};
+use diesel_async::RunQueryDsl;
+use lemmy_db_schema::{
+  newtypes::{MultiCommunityId, PersonId},
+  schema::{multi_community, person},
+  utils::{get_conn, limit_and_offset, DbPool},
+};
+
+impl MultiCommunityView {
+  pub async fn read(
+    pool: &mut DbPool<'_>,
+    multi_community_id: MultiCommunityId,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    multi_community::table
+      .find(multi_community_id)
+      .inner_join(person::table)
+      .select((multi_community::all_columns, person::all_columns))
+      .first::<MultiCommunityView>(conn)
+      .await
+  }
+
+  /// Lists multi-communities which aren't deleted, optionally only those of a single creator.
+  pub async fn list(
+    pool: &mut DbPool<'_>,
+    creator_id: Option<PersonId>,
+    page: Option<i64>,
+    limit: Option<i64>,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let (limit, offset) = limit_and_offset(page, limit)?;
+    let mut query = multi_community::table
+      .inner_join(person::table)
+      .select((multi_community::all_columns, person::all_columns))
+      .filter(multi_community::deleted.eq(false))
+      .into_boxed();
+    if let Some(creator_id) = creator_id {
+      query = query.filter(multi_community::creator_id.eq(creator_id));
+    }
+    query
+      .order_by(multi_community::name)
+      .limit(limit)
+      .offset(offset)
+      .load::<MultiCommunityView>(conn)
+      .await
+  }
+}
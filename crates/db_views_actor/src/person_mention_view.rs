@@ -139,7 +139,9 @@ fn queries<'a>() -> Queries<
     };
 
     query = match options.sort.unwrap_or(CommentSortType::Hot) {
-      CommentSortType::Hot => query.then_order_by(comment_aggregates::hot_rank.desc()),
+      CommentSortType::Hot | CommentSortType::Relevance => {
+        query.then_order_by(comment_aggregates::hot_rank.desc())
+      }
       CommentSortType::Controversial => {
         query.then_order_by(comment_aggregates::controversy_rank.desc())
       }
@@ -13,7 +13,18 @@ use lemmy_db_schema::{
   newtypes::PersonId,
   schema,
   schema::{local_user, person, person_aggregates},
-  utils::{fuzzy_search, get_conn, limit_and_offset, now, DbConn, DbPool, ListFn, Queries, ReadFn},
+  utils::{
+    full_text_search,
+    fuzzy_search,
+    get_conn,
+    limit_and_offset,
+    now,
+    DbConn,
+    DbPool,
+    ListFn,
+    Queries,
+    ReadFn,
+  },
   SortType,
 };
 use serde::{Deserialize, Serialize};
@@ -22,6 +33,7 @@ use strum_macros::{Display, EnumString};
 enum ListMode {
   Admins,
   Banned,
+  Ids(Vec<PersonId>),
   Query(PersonQuery),
 }
 
@@ -34,6 +46,7 @@ enum PersonSortType {
   CommentScore,
   PostScore,
   PostCount,
+  Relevance,
 }
 
 fn post_to_person_sort_type(sort: SortType) -> PersonSortType {
@@ -42,6 +55,7 @@ fn post_to_person_sort_type(sort: SortType) -> PersonSortType {
     SortType::New | SortType::NewComments => PersonSortType::New,
     SortType::MostComments => PersonSortType::MostComments,
     SortType::Old => PersonSortType::Old,
+    SortType::Relevance => PersonSortType::Relevance,
     _ => PersonSortType::CommentScore,
   }
 }
@@ -81,12 +95,18 @@ fn queries<'a>(
           )
           .filter(person::deleted.eq(false));
       }
+      ListMode::Ids(ids) => {
+        query = query.filter(person::id.eq_any(ids));
+      }
       ListMode::Query(options) => {
-        if let Some(search_term) = options.search_term {
-          let searcher = fuzzy_search(&search_term);
-          query = query
-            .filter(person::name.ilike(searcher.clone()))
-            .or_filter(person::display_name.ilike(searcher));
+        if let Some(search_term) = &options.search_term {
+          // Full-text search only matches whole words, so names are also matched as substrings
+          let searcher = fuzzy_search(search_term);
+          query = query.filter(
+            full_text_search::matches("person", search_term)
+              .or(person::name.ilike(searcher.clone()))
+              .or(person::display_name.ilike(searcher)),
+          );
         }
 
         let sort = options.sort.map(post_to_person_sort_type);
@@ -97,6 +117,12 @@ fn queries<'a>(
           PersonSortType::CommentScore => query.order_by(person_aggregates::comment_score.desc()),
           PersonSortType::PostScore => query.order_by(person_aggregates::post_score.desc()),
           PersonSortType::PostCount => query.order_by(person_aggregates::post_count.desc()),
+          PersonSortType::Relevance => match &options.search_term {
+            Some(search_term) => {
+              query.order_by(full_text_search::rank("person", search_term).desc())
+            }
+            None => query.order_by(person_aggregates::comment_score.desc()),
+          },
         };
 
         let (limit, offset) = limit_and_offset(options.page, options.limit)?;
@@ -136,6 +162,11 @@ impl PersonView {
   pub async fn banned(pool: &mut DbPool<'_>) -> Result<Vec<Self>, Error> {
     queries().list(pool, ListMode::Banned).await
   }
+
+  /// Reads the persons with the given ids, in no particular order.
+  pub async fn read_many(pool: &mut DbPool<'_>, ids: Vec<PersonId>) -> Result<Vec<Self>, Error> {
+    queries().list(pool, ListMode::Ids(ids)).await
+  }
 }
 
 #[derive(Default)]
@@ -7,6 +7,7 @@ use lemmy_db_schema::{
     comment_reply::CommentReply,
     community::Community,
     instance::Instance,
+    multi_community::MultiCommunity,
     person::Person,
     person_mention::PersonMention,
     post::Post,
@@ -133,3 +134,12 @@ pub struct PersonView {
   pub person: Person,
   pub counts: PersonAggregates,
 }
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS, Queryable))]
+#[cfg_attr(feature = "full", ts(export))]
+/// A multi-community view.
+pub struct MultiCommunityView {
+  pub multi_community: MultiCommunity,
+  pub creator: Person,
+}
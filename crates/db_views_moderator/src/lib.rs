@@ -1,31 +1,10 @@
+#[cfg(test)]
+extern crate serial_test;
+
 #[cfg(feature = "full")]
-pub mod admin_purge_comment_view;
+pub mod mod_warning_view;
 #[cfg(feature = "full")]
-pub mod admin_purge_community_view;
+pub mod modlog_combined_view;
 #[cfg(feature = "full")]
-pub mod admin_purge_person_view;
-#[cfg(feature = "full")]
-pub mod admin_purge_post_view;
-#[cfg(feature = "full")]
-pub mod mod_add_community_view;
-#[cfg(feature = "full")]
-pub mod mod_add_view;
-#[cfg(feature = "full")]
-pub mod mod_ban_from_community_view;
-#[cfg(feature = "full")]
-pub mod mod_ban_view;
-#[cfg(feature = "full")]
-pub mod mod_feature_post_view;
-#[cfg(feature = "full")]
-pub mod mod_hide_community_view;
-#[cfg(feature = "full")]
-pub mod mod_lock_post_view;
-#[cfg(feature = "full")]
-pub mod mod_remove_comment_view;
-#[cfg(feature = "full")]
-pub mod mod_remove_community_view;
-#[cfg(feature = "full")]
-pub mod mod_remove_post_view;
-#[cfg(feature = "full")]
-pub mod mod_transfer_community_view;
+pub mod person_note_view;
 pub mod structs;
@@ -0,0 +1,42 @@
+use crate::structs::ModWarningView;
+use diesel::{result::Error, ExpressionMethods, JoinOnDsl, NullableExpressionMethods, QueryDsl};
+use diesel_async::RunQueryDsl;
+use lemmy_db_schema::{
+  schema::{comment, community, mod_warning, person, post},
+  utils::{get_conn, DbPool},
+};
+
+impl ModWarningView {
+  /// Reads a single warning, without the moderator if mod names are hidden.
+  pub async fn read(
+    pool: &mut DbPool<'_>,
+    mod_warning_id: i32,
+    hide_modlog_names: bool,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let person_alias_1 = diesel::alias!(person as person1);
+    let mut view = mod_warning::table
+      .find(mod_warning_id)
+      .inner_join(person::table.on(mod_warning::mod_person_id.eq(person::id)))
+      .inner_join(
+        person_alias_1.on(mod_warning::other_person_id.eq(person_alias_1.field(person::id))),
+      )
+      .inner_join(community::table)
+      .inner_join(post::table)
+      .left_join(comment::table)
+      .select((
+        mod_warning::all_columns,
+        person::all_columns.nullable(),
+        person_alias_1.fields(person::all_columns),
+        community::all_columns,
+        post::all_columns,
+        comment::all_columns.nullable(),
+      ))
+      .first::<Self>(conn)
+      .await?;
+    if hide_modlog_names {
+      view.moderator = None;
+    }
+    Ok(view)
+  }
+}
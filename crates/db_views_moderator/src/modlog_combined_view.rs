@@ -0,0 +1,840 @@
+use crate::structs::{
+  AdminPurgeCommentView,
+  AdminPurgeCommunityView,
+  AdminPurgePersonView,
+  AdminPurgePostView,
+  ModAddCommunityView,
+  ModAddView,
+  ModBanFromCommunityView,
+  ModBanView,
+  ModCommunityPostTagView,
+  ModFeaturePostView,
+  ModHideCommunityView,
+  ModLockPostView,
+  ModRemoveCommentView,
+  ModRemoveCommunityView,
+  ModRemovePostView,
+  ModResolveReportView,
+  ModTransferCommunityView,
+  ModWarningView,
+  ModlogCursor,
+  ModlogEntry,
+};
+use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
+use diesel::{
+  result::Error,
+  BoolExpressionMethods,
+  ExpressionMethods,
+  IntoSql,
+  JoinOnDsl,
+  NullableExpressionMethods,
+  QueryDsl,
+};
+use diesel_async::RunQueryDsl;
+use lemmy_db_schema::{
+  newtypes::{CommunityId, PersonId},
+  schema::{
+    admin_purge_comment,
+    admin_purge_community,
+    admin_purge_person,
+    admin_purge_post,
+    comment,
+    community,
+    community_post_tag,
+    mod_add,
+    mod_add_community,
+    mod_ban,
+    mod_ban_from_community,
+    mod_community_post_tag,
+    mod_feature_post,
+    mod_hide_community,
+    mod_lock_post,
+    mod_remove_comment,
+    mod_remove_community,
+    mod_remove_post,
+    mod_resolve_report,
+    mod_transfer_community,
+    mod_warning,
+    modlog_combined,
+    person,
+    post,
+  },
+  source::{
+    comment::Comment,
+    community::Community,
+    community_post_tag::CommunityPostTag,
+    moderator::{
+      AdminPurgeComment,
+      AdminPurgeCommunity,
+      AdminPurgePerson,
+      AdminPurgePost,
+      ModAdd,
+      ModAddCommunity,
+      ModBan,
+      ModBanFromCommunity,
+      ModCommunityPostTag,
+      ModFeaturePost,
+      ModHideCommunity,
+      ModLockPost,
+      ModRemoveComment,
+      ModRemoveCommunity,
+      ModRemovePost,
+      ModResolveReport,
+      ModTransferCommunity,
+      ModWarning,
+    },
+    person::Person,
+    post::Post,
+  },
+  utils::{format_cursor, get_conn, limit_and_offset, parse_cursor, DbPool},
+  ModlogActionType,
+};
+
+type ModlogCombinedRow = (
+  (i32, DateTime<Utc>),
+  Option<ModRemovePost>,
+  Option<ModLockPost>,
+  Option<ModFeaturePost>,
+  Option<ModRemoveComment>,
+  Option<ModRemoveCommunity>,
+  Option<ModBanFromCommunity>,
+  Option<ModBan>,
+  Option<ModAddCommunity>,
+  Option<ModTransferCommunity>,
+  Option<ModAdd>,
+  Option<ModHideCommunity>,
+  Option<ModCommunityPostTag>,
+  Option<AdminPurgePerson>,
+  Option<AdminPurgeCommunity>,
+  Option<AdminPurgePost>,
+  Option<AdminPurgeComment>,
+  Option<ModWarning>,
+  Option<ModResolveReport>,
+  Option<Person>,
+  Option<Person>,
+  Option<Community>,
+  Option<Post>,
+  Option<Comment>,
+  Option<CommunityPostTag>,
+);
+
+/// A single modlog entry, together with its position in the combined modlog.
+#[derive(Debug, Clone)]
+pub struct ModlogCombinedView {
+  pub entry: ModlogEntry,
+  id: i32,
+  published: DateTime<Utc>,
+}
+
+impl TryFrom<ModlogCombinedRow> for ModlogCombinedView {
+  type Error = Error;
+
+  fn try_from(row: ModlogCombinedRow) -> Result<Self, Self::Error> {
+    let (
+      (id, published),
+      mod_remove_post,
+      mod_lock_post,
+      mod_feature_post,
+      mod_remove_comment,
+      mod_remove_community,
+      mod_ban_from_community,
+      mod_ban,
+      mod_add_community,
+      mod_transfer_community,
+      mod_add,
+      mod_hide_community,
+      mod_community_post_tag,
+      admin_purge_person,
+      admin_purge_community,
+      admin_purge_post,
+      admin_purge_comment,
+      mod_warning,
+      mod_resolve_report,
+      moderator,
+      other_person,
+      community,
+      post,
+      comment,
+      community_post_tag,
+    ) = row;
+
+    // The moderator is left out when mod names are hidden, everything else is guaranteed to exist
+    // by the foreign keys of the combined table.
+    let entry = if let Some(mod_remove_post) = mod_remove_post {
+      post.zip(community).map(|(post, community)| {
+        ModlogEntry::ModRemovePost(ModRemovePostView {
+          mod_remove_post,
+          moderator,
+          post,
+          community,
+        })
+      })
+    } else if let Some(mod_lock_post) = mod_lock_post {
+      post.zip(community).map(|(post, community)| {
+        ModlogEntry::ModLockPost(ModLockPostView {
+          mod_lock_post,
+          moderator,
+          post,
+          community,
+        })
+      })
+    } else if let Some(mod_feature_post) = mod_feature_post {
+      post.zip(community).map(|(post, community)| {
+        ModlogEntry::ModFeaturePost(ModFeaturePostView {
+          mod_feature_post,
+          moderator,
+          post,
+          community,
+        })
+      })
+    } else if let Some(mod_remove_comment) = mod_remove_comment {
+      comment.zip(other_person).zip(post.zip(community)).map(
+        |((comment, commenter), (post, community))| {
+          ModlogEntry::ModRemoveComment(ModRemoveCommentView {
+            mod_remove_comment,
+            moderator,
+            comment,
+            commenter,
+            post,
+            community,
+          })
+        },
+      )
+    } else if let Some(mod_remove_community) = mod_remove_community {
+      community.map(|community| {
+        ModlogEntry::ModRemoveCommunity(ModRemoveCommunityView {
+          mod_remove_community,
+          moderator,
+          community,
+        })
+      })
+    } else if let Some(mod_ban_from_community) = mod_ban_from_community {
+      community
+        .zip(other_person)
+        .map(|(community, banned_person)| {
+          ModlogEntry::ModBanFromCommunity(ModBanFromCommunityView {
+            mod_ban_from_community,
+            moderator,
+            community,
+            banned_person,
+          })
+        })
+    } else if let Some(mod_ban) = mod_ban {
+      other_person.map(|banned_person| {
+        ModlogEntry::ModBan(ModBanView {
+          mod_ban,
+          moderator,
+          banned_person,
+        })
+      })
+    } else if let Some(mod_add_community) = mod_add_community {
+      community
+        .zip(other_person)
+        .map(|(community, modded_person)| {
+          ModlogEntry::ModAddCommunity(ModAddCommunityView {
+            mod_add_community,
+            moderator,
+            community,
+            modded_person,
+          })
+        })
+    } else if let Some(mod_transfer_community) = mod_transfer_community {
+      community
+        .zip(other_person)
+        .map(|(community, modded_person)| {
+          ModlogEntry::ModTransferCommunity(ModTransferCommunityView {
+            mod_transfer_community,
+            moderator,
+            community,
+            modded_person,
+          })
+        })
+    } else if let Some(mod_add) = mod_add {
+      other_person.map(|modded_person| {
+        ModlogEntry::ModAdd(ModAddView {
+          mod_add,
+          moderator,
+          modded_person,
+        })
+      })
+    } else if let Some(mod_hide_community) = mod_hide_community {
+      community.map(|community| {
+        ModlogEntry::ModHideCommunity(ModHideCommunityView {
+          mod_hide_community,
+          admin: moderator,
+          community,
+        })
+      })
+    } else if let Some(mod_community_post_tag) = mod_community_post_tag {
+      community_post_tag
+        .zip(community)
+        .map(|(community_post_tag, community)| {
+          ModlogEntry::ModCommunityPostTag(ModCommunityPostTagView {
+            mod_community_post_tag,
+            moderator,
+            community_post_tag,
+            community,
+          })
+        })
+    } else if let Some(admin_purge_person) = admin_purge_person {
+      Some(ModlogEntry::AdminPurgePerson(AdminPurgePersonView {
+        admin_purge_person,
+        admin: moderator,
+      }))
+    } else if let Some(admin_purge_community) = admin_purge_community {
+      Some(ModlogEntry::AdminPurgeCommunity(AdminPurgeCommunityView {
+        admin_purge_community,
+        admin: moderator,
+      }))
+    } else if let Some(admin_purge_post) = admin_purge_post {
+      community.map(|community| {
+        ModlogEntry::AdminPurgePost(AdminPurgePostView {
+          admin_purge_post,
+          admin: moderator,
+          community,
+        })
+      })
+    } else if let Some(admin_purge_comment) = admin_purge_comment {
+      post.map(|post| {
+        ModlogEntry::AdminPurgeComment(AdminPurgeCommentView {
+          admin_purge_comment,
+          admin: moderator,
+          post,
+        })
+      })
+    } else if let Some(mod_warning) = mod_warning {
+      other_person
+        .zip(community)
+        .zip(post)
+        .map(|((warned_person, community), post)| {
+          ModlogEntry::ModWarning(ModWarningView {
+            mod_warning,
+            moderator,
+            warned_person,
+            community,
+            post,
+            comment,
+          })
+        })
+    } else if let Some(mod_resolve_report) = mod_resolve_report {
+      other_person
+        .zip(community)
+        .zip(post)
+        .map(|((reported_person, community), post)| {
+          ModlogEntry::ModResolveReport(ModResolveReportView {
+            mod_resolve_report,
+            moderator,
+            reported_person,
+            community,
+            post,
+            comment,
+          })
+        })
+    } else {
+      None
+    };
+
+    let entry = entry.ok_or_else(|| Error::QueryBuilderError("Incomplete modlog entry".into()))?;
+    Ok(ModlogCombinedView {
+      entry,
+      id,
+      published,
+    })
+  }
+}
+
+impl ModlogCursor {
+  pub fn after_entry(view: &ModlogCombinedView) -> ModlogCursor {
+    ModlogCursor(format_cursor(
+      'M',
+      [view.published.timestamp_micros(), i64::from(view.id)],
+    ))
+  }
+
+  fn read(&self) -> Result<(DateTime<Utc>, i32), Error> {
+    parse_cursor(&self.0, 'M', |[micros, id]| {
+      let published = Utc.from_utc_datetime(&NaiveDateTime::from_timestamp_micros(micros)?);
+      Some((published, i32::try_from(id).ok()?))
+    })
+  }
+}
+
+/// Lists the modlog, newest entries first.
+#[derive(Default)]
+pub struct ModlogCombinedQuery {
+  pub type_: Option<ModlogActionType>,
+  pub community_id: Option<CommunityId>,
+  pub mod_person_id: Option<PersonId>,
+  /// The person who was affected by the action, like the banned person or the creator of a
+  /// removed post
+  pub other_person_id: Option<PersonId>,
+  /// Only entries which were created at or after this time
+  pub since: Option<DateTime<Utc>>,
+  /// Only entries which were created before this time
+  pub until: Option<DateTime<Utc>>,
+  pub hide_modlog_names: bool,
+  pub page_after: Option<ModlogCursor>,
+  pub limit: Option<i64>,
+}
+
+impl ModlogCombinedQuery {
+  pub async fn list(self, pool: &mut DbPool<'_>) -> Result<Vec<ModlogCombinedView>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let (limit, _) = limit_and_offset(None, self.limit)?;
+    let page_after = self
+      .page_after
+      .as_ref()
+      .map(ModlogCursor::read)
+      .transpose()?;
+
+    let person_alias_1 = diesel::alias!(person as person1);
+    let admin_person_id_join = self.mod_person_id.unwrap_or(PersonId(-1));
+    let show_mod_names = !self.hide_modlog_names;
+    let show_mod_names_expr = show_mod_names.as_sql::<diesel::sql_types::Bool>();
+
+    let admin_names_join = modlog_combined::mod_person_id
+      .eq(person::id)
+      .and(show_mod_names_expr.or(person::id.eq(admin_person_id_join)));
+    let mut query = modlog_combined::table
+      .left_join(mod_remove_post::table)
+      .left_join(mod_lock_post::table)
+      .left_join(mod_feature_post::table)
+      .left_join(mod_remove_comment::table)
+      .left_join(mod_remove_community::table)
+      .left_join(mod_ban_from_community::table)
+      .left_join(mod_ban::table)
+      .left_join(mod_add_community::table)
+      .left_join(mod_transfer_community::table)
+      .left_join(mod_add::table)
+      .left_join(mod_hide_community::table)
+      .left_join(mod_community_post_tag::table)
+      .left_join(admin_purge_person::table)
+      .left_join(admin_purge_community::table)
+      .left_join(admin_purge_post::table)
+      .left_join(admin_purge_comment::table)
+      .left_join(mod_warning::table)
+      .left_join(mod_resolve_report::table)
+      .left_join(person::table.on(admin_names_join))
+      .left_join(
+        person_alias_1
+          .on(modlog_combined::other_person_id.eq(person_alias_1.field(person::id).nullable())),
+      )
+      .left_join(community::table)
+      .left_join(post::table)
+      .left_join(comment::table)
+      .left_join(community_post_tag::table)
+      .select((
+        (modlog_combined::id, modlog_combined::published),
+        mod_remove_post::all_columns.nullable(),
+        mod_lock_post::all_columns.nullable(),
+        mod_feature_post::all_columns.nullable(),
+        mod_remove_comment::all_columns.nullable(),
+        mod_remove_community::all_columns.nullable(),
+        mod_ban_from_community::all_columns.nullable(),
+        mod_ban::all_columns.nullable(),
+        mod_add_community::all_columns.nullable(),
+        mod_transfer_community::all_columns.nullable(),
+        mod_add::all_columns.nullable(),
+        mod_hide_community::all_columns.nullable(),
+        mod_community_post_tag::all_columns.nullable(),
+        admin_purge_person::all_columns.nullable(),
+        admin_purge_community::all_columns.nullable(),
+        admin_purge_post::all_columns.nullable(),
+        admin_purge_comment::all_columns.nullable(),
+        mod_warning::all_columns.nullable(),
+        mod_resolve_report::all_columns.nullable(),
+        person::all_columns.nullable(),
+        person_alias_1.fields(person::all_columns).nullable(),
+        community::all_columns.nullable(),
+        post::all_columns.nullable(),
+        comment::all_columns.nullable(),
+        community_post_tag::all_columns.nullable(),
+      ))
+      .into_boxed();
+
+    query = match self.type_.unwrap_or(ModlogActionType::All) {
+      ModlogActionType::All => query,
+      ModlogActionType::ModRemovePost => {
+        query.filter(modlog_combined::mod_remove_post_id.is_not_null())
+      }
+      ModlogActionType::ModLockPost => {
+        query.filter(modlog_combined::mod_lock_post_id.is_not_null())
+      }
+      ModlogActionType::ModFeaturePost => {
+        query.filter(modlog_combined::mod_feature_post_id.is_not_null())
+      }
+      ModlogActionType::ModRemoveComment => {
+        query.filter(modlog_combined::mod_remove_comment_id.is_not_null())
+      }
+      ModlogActionType::ModRemoveCommunity => {
+        query.filter(modlog_combined::mod_remove_community_id.is_not_null())
+      }
+      ModlogActionType::ModBanFromCommunity => {
+        query.filter(modlog_combined::mod_ban_from_community_id.is_not_null())
+      }
+      ModlogActionType::ModAddCommunity => {
+        query.filter(modlog_combined::mod_add_community_id.is_not_null())
+      }
+      ModlogActionType::ModTransferCommunity => {
+        query.filter(modlog_combined::mod_transfer_community_id.is_not_null())
+      }
+      ModlogActionType::ModAdd => query.filter(modlog_combined::mod_add_id.is_not_null()),
+      ModlogActionType::ModBan => query.filter(modlog_combined::mod_ban_id.is_not_null()),
+      ModlogActionType::ModHideCommunity => {
+        query.filter(modlog_combined::mod_hide_community_id.is_not_null())
+      }
+      ModlogActionType::ModCommunityPostTag => {
+        query.filter(modlog_combined::mod_community_post_tag_id.is_not_null())
+      }
+      ModlogActionType::AdminPurgePerson => {
+        query.filter(modlog_combined::admin_purge_person_id.is_not_null())
+      }
+      ModlogActionType::AdminPurgeCommunity => {
+        query.filter(modlog_combined::admin_purge_community_id.is_not_null())
+      }
+      ModlogActionType::AdminPurgePost => {
+        query.filter(modlog_combined::admin_purge_post_id.is_not_null())
+      }
+      ModlogActionType::AdminPurgeComment => {
+        query.filter(modlog_combined::admin_purge_comment_id.is_not_null())
+      }
+      ModlogActionType::ModWarning => query.filter(modlog_combined::mod_warning_id.is_not_null()),
+      ModlogActionType::ModResolveReport => {
+        query.filter(modlog_combined::mod_resolve_report_id.is_not_null())
+      }
+    };
+
+    if let Some(community_id) = self.community_id {
+      query = query.filter(modlog_combined::community_id.eq(community_id));
+    }
+
+    if let Some(mod_person_id) = self.mod_person_id {
+      query = query.filter(modlog_combined::mod_person_id.eq(mod_person_id));
+    }
+
+    if let Some(other_person_id) = self.other_person_id {
+      query = query.filter(modlog_combined::other_person_id.eq(other_person_id));
+    }
+
+    if let Some(since) = self.since {
+      query = query.filter(modlog_combined::published.ge(since));
+    }
+
+    if let Some(until) = self.until {
+      query = query.filter(modlog_combined::published.lt(until));
+    }
+
+    if let Some((published, id)) = page_after {
+      query = query.filter(
+        modlog_combined::published.lt(published).or(
+          modlog_combined::published
+            .eq(published)
+            .and(modlog_combined::id.lt(id)),
+        ),
+      );
+    }
+
+    let rows = query
+      .order_by((
+        modlog_combined::published.desc(),
+        modlog_combined::id.desc(),
+      ))
+      .limit(limit)
+      .load::<ModlogCombinedRow>(conn)
+      .await?;
+
+    rows.into_iter().map(TryInto::try_into).collect()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  #![allow(clippy::unwrap_used)]
+  #![allow(clippy::indexing_slicing)]
+
+  use crate::{
+    modlog_combined_view::ModlogCombinedQuery,
+    structs::{ModlogCursor, ModlogEntry},
+  };
+  use lemmy_db_schema::{
+    newtypes::PersonId,
+    source::{
+      community::{Community, CommunityInsertForm},
+      instance::Instance,
+      moderator::{
+        ModAdd,
+        ModAddForm,
+        ModBan,
+        ModBanForm,
+        ModBanFromCommunity,
+        ModBanFromCommunityForm,
+        ModLockPost,
+        ModLockPostForm,
+        ModRemovePost,
+        ModRemovePostForm,
+      },
+      person::{Person, PersonInsertForm},
+      post::{Post, PostInsertForm},
+    },
+    traits::Crud,
+    utils::build_db_pool_for_tests,
+    ModlogActionType,
+  };
+  use serial_test::serial;
+
+  fn moderator_id(entry: &ModlogEntry) -> Option<PersonId> {
+    let moderator = match entry {
+      ModlogEntry::ModRemovePost(v) => &v.moderator,
+      ModlogEntry::ModLockPost(v) => &v.moderator,
+      ModlogEntry::ModBanFromCommunity(v) => &v.moderator,
+      ModlogEntry::ModBan(v) => &v.moderator,
+      ModlogEntry::ModAdd(v) => &v.moderator,
+      _ => panic!("unexpected modlog entry {entry:?}"),
+    };
+    moderator.as_ref().map(|m| m.id)
+  }
+
+  #[tokio::test]
+  #[serial]
+  async fn test_modlog_combined() {
+    let pool = &build_db_pool_for_tests().await;
+    let pool = &mut pool.into();
+
+    let inserted_instance = Instance::read_or_create(pool, "my_domain.tld".to_string())
+      .await
+      .unwrap();
+
+    let new_admin = PersonInsertForm::builder()
+      .name("modlog_admin".into())
+      .public_key("pubkey".to_string())
+      .instance_id(inserted_instance.id)
+      .build();
+    let inserted_admin = Person::create(pool, &new_admin).await.unwrap();
+
+    let new_mod = PersonInsertForm::builder()
+      .name("modlog_mod".into())
+      .public_key("pubkey".to_string())
+      .instance_id(inserted_instance.id)
+      .build();
+    let inserted_mod = Person::create(pool, &new_mod).await.unwrap();
+
+    let new_person = PersonInsertForm::builder()
+      .name("modlog_person".into())
+      .public_key("pubkey".to_string())
+      .instance_id(inserted_instance.id)
+      .build();
+    let inserted_person = Person::create(pool, &new_person).await.unwrap();
+
+    let new_community = CommunityInsertForm::builder()
+      .name("modlog_community".to_string())
+      .title("nada".to_owned())
+      .public_key("pubkey".to_string())
+      .instance_id(inserted_instance.id)
+      .build();
+    let inserted_community = Community::create(pool, &new_community).await.unwrap();
+
+    let new_community_2 = CommunityInsertForm::builder()
+      .name("modlog_community_2".to_string())
+      .title("nada".to_owned())
+      .public_key("pubkey".to_string())
+      .instance_id(inserted_instance.id)
+      .build();
+    let inserted_community_2 = Community::create(pool, &new_community_2).await.unwrap();
+
+    let new_post = PostInsertForm::builder()
+      .name("A modlog post".into())
+      .creator_id(inserted_person.id)
+      .community_id(inserted_community.id)
+      .build();
+    let inserted_post = Post::create(pool, &new_post).await.unwrap();
+
+    let new_post_2 = PostInsertForm::builder()
+      .name("Another modlog post".into())
+      .creator_id(inserted_person.id)
+      .community_id(inserted_community_2.id)
+      .build();
+    let inserted_post_2 = Post::create(pool, &new_post_2).await.unwrap();
+
+    // The combined table is filled by triggers on the specific modlog tables
+    let mod_remove_post_form = ModRemovePostForm {
+      mod_person_id: inserted_mod.id,
+      post_id: inserted_post.id,
+      reason: None,
+      removed: None,
+    };
+    ModRemovePost::create(pool, &mod_remove_post_form)
+      .await
+      .unwrap();
+    let mod_lock_post_form = ModLockPostForm {
+      mod_person_id: inserted_mod.id,
+      post_id: inserted_post_2.id,
+      locked: None,
+    };
+    ModLockPost::create(pool, &mod_lock_post_form)
+      .await
+      .unwrap();
+    let mod_ban_from_community_form = ModBanFromCommunityForm {
+      mod_person_id: inserted_mod.id,
+      other_person_id: inserted_person.id,
+      community_id: inserted_community.id,
+      reason: None,
+      banned: None,
+      expires: None,
+    };
+    ModBanFromCommunity::create(pool, &mod_ban_from_community_form)
+      .await
+      .unwrap();
+    let mod_add_form = ModAddForm {
+      mod_person_id: inserted_admin.id,
+      other_person_id: inserted_mod.id,
+      removed: None,
+    };
+    ModAdd::create(pool, &mod_add_form).await.unwrap();
+    let mod_ban_form = ModBanForm {
+      mod_person_id: inserted_admin.id,
+      other_person_id: inserted_person.id,
+      reason: None,
+      banned: None,
+      expires: None,
+    };
+    ModBan::create(pool, &mod_ban_form).await.unwrap();
+
+    let all = ModlogCombinedQuery::default().list(pool).await.unwrap();
+    assert_eq!(5, all.len());
+    // Newest first
+    assert!(matches!(all[0].entry, ModlogEntry::ModBan(_)));
+    assert!(matches!(all[4].entry, ModlogEntry::ModRemovePost(_)));
+    assert!(all
+      .windows(2)
+      .all(|w| (w[0].published, w[0].id) > (w[1].published, w[1].id)));
+
+    // Filters
+    let by_type = ModlogCombinedQuery {
+      type_: Some(ModlogActionType::ModRemovePost),
+      ..Default::default()
+    }
+    .list(pool)
+    .await
+    .unwrap();
+    assert_eq!(1, by_type.len());
+    assert!(matches!(by_type[0].entry, ModlogEntry::ModRemovePost(_)));
+
+    let by_community = ModlogCombinedQuery {
+      community_id: Some(inserted_community.id),
+      ..Default::default()
+    }
+    .list(pool)
+    .await
+    .unwrap();
+    assert_eq!(2, by_community.len());
+    assert!(matches!(
+      by_community[0].entry,
+      ModlogEntry::ModBanFromCommunity(_)
+    ));
+    assert!(matches!(
+      by_community[1].entry,
+      ModlogEntry::ModRemovePost(_)
+    ));
+
+    let by_mod = ModlogCombinedQuery {
+      mod_person_id: Some(inserted_admin.id),
+      ..Default::default()
+    }
+    .list(pool)
+    .await
+    .unwrap();
+    assert_eq!(2, by_mod.len());
+    assert!(by_mod
+      .iter()
+      .all(|v| moderator_id(&v.entry) == Some(inserted_admin.id)));
+
+    let by_other_person = ModlogCombinedQuery {
+      other_person_id: Some(inserted_person.id),
+      ..Default::default()
+    }
+    .list(pool)
+    .await
+    .unwrap();
+    assert_eq!(4, by_other_person.len());
+    assert!(by_other_person
+      .iter()
+      .all(|v| !matches!(v.entry, ModlogEntry::ModAdd(_))));
+
+    let by_mod_and_type = ModlogCombinedQuery {
+      type_: Some(ModlogActionType::ModBan),
+      mod_person_id: Some(inserted_mod.id),
+      ..Default::default()
+    }
+    .list(pool)
+    .await
+    .unwrap();
+    assert!(by_mod_and_type.is_empty());
+
+    // Paging needs to return every entry exactly once, in the same order
+    let mut paged_ids = vec![];
+    let mut page_after = None;
+    loop {
+      let page = ModlogCombinedQuery {
+        page_after,
+        limit: Some(2),
+        ..Default::default()
+      }
+      .list(pool)
+      .await
+      .unwrap();
+      let Some(last) = page.last() else {
+        break;
+      };
+      assert!(page.len() <= 2);
+      page_after = Some(ModlogCursor::after_entry(last));
+      paged_ids.extend(page.iter().map(|v| v.id));
+    }
+    let all_ids = all.iter().map(|v| v.id).collect::<Vec<_>>();
+    assert_eq!(all_ids, paged_ids);
+
+    let invalid_cursor = ModlogCombinedQuery {
+      page_after: Some(ModlogCursor("P1".to_string())),
+      ..Default::default()
+    }
+    .list(pool)
+    .await;
+    assert!(invalid_cursor.is_err());
+
+    // Moderators are visible by default, and hidden when mod names are hidden
+    assert!(all.iter().all(|v| moderator_id(&v.entry).is_some()));
+    let hidden = ModlogCombinedQuery {
+      hide_modlog_names: true,
+      ..Default::default()
+    }
+    .list(pool)
+    .await
+    .unwrap();
+    assert_eq!(5, hidden.len());
+    assert!(hidden.iter().all(|v| moderator_id(&v.entry).is_none()));
+
+    // Except when listing the actions of the given moderator
+    let hidden_by_mod = ModlogCombinedQuery {
+      mod_person_id: Some(inserted_mod.id),
+      hide_modlog_names: true,
+      ..Default::default()
+    }
+    .list(pool)
+    .await
+    .unwrap();
+    assert_eq!(3, hidden_by_mod.len());
+    assert!(hidden_by_mod
+      .iter()
+      .all(|v| moderator_id(&v.entry) == Some(inserted_mod.id)));
+
+    Person::delete(pool, inserted_admin.id).await.unwrap();
+    Person::delete(pool, inserted_mod.id).await.unwrap();
+    Person::delete(pool, inserted_person.id).await.unwrap();
+    Community::delete(pool, inserted_community.id)
+      .await
+      .unwrap();
+    Community::delete(pool, inserted_community_2.id)
+      .await
+      .unwrap();
+    Instance::delete(pool, inserted_instance.id).await.unwrap();
+  }
+}
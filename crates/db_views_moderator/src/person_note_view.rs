@@ -0,0 +1,54 @@
+use crate::structs::PersonNoteView;
+use diesel::{result::Error, ExpressionMethods, JoinOnDsl, NullableExpressionMethods, QueryDsl};
+use diesel_async::RunQueryDsl;
+use lemmy_db_schema::{
+  newtypes::{CommunityId, PersonId, PersonNoteId},
+  schema::{community, person, person_note},
+  utils::{get_conn, DbPool},
+};
+
+impl PersonNoteView {
+  pub async fn read(pool: &mut DbPool<'_>, person_note_id: PersonNoteId) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    person_note::table
+      .find(person_note_id)
+      .inner_join(person::table.on(person_note::creator_id.eq(person::id)))
+      .left_join(community::table)
+      .select((
+        person_note::all_columns,
+        person::all_columns,
+        community::all_columns.nullable(),
+      ))
+      .first::<Self>(conn)
+      .await
+  }
+
+  /// Lists the notes about a person, newest first. With a community only the notes of that
+  /// community are returned, otherwise all of them, which is meant for admins.
+  pub async fn list(
+    pool: &mut DbPool<'_>,
+    person_id: PersonId,
+    community_id: Option<CommunityId>,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let mut query = person_note::table
+      .inner_join(person::table.on(person_note::creator_id.eq(person::id)))
+      .left_join(community::table)
+      .filter(person_note::person_id.eq(person_id))
+      .select((
+        person_note::all_columns,
+        person::all_columns,
+        community::all_columns.nullable(),
+      ))
+      .into_boxed();
+
+    if let Some(community_id) = community_id {
+      query = query.filter(person_note::community_id.eq(community_id));
+    }
+
+    query
+      .order_by(person_note::published.desc())
+      .load::<Self>(conn)
+      .await
+  }
+}
@@ -1,30 +1,31 @@
 #[cfg(feature = "full")]
 use diesel::Queryable;
-use lemmy_db_schema::{
-  newtypes::{CommunityId, PersonId},
-  source::{
-    comment::Comment,
-    community::Community,
-    moderator::{
-      AdminPurgeComment,
-      AdminPurgeCommunity,
-      AdminPurgePerson,
-      AdminPurgePost,
-      ModAdd,
-      ModAddCommunity,
-      ModBan,
-      ModBanFromCommunity,
-      ModFeaturePost,
-      ModHideCommunity,
-      ModLockPost,
-      ModRemoveComment,
-      ModRemoveCommunity,
-      ModRemovePost,
-      ModTransferCommunity,
-    },
-    person::Person,
-    post::Post,
+use lemmy_db_schema::source::{
+  comment::Comment,
+  community::Community,
+  community_post_tag::CommunityPostTag,
+  moderator::{
+    AdminPurgeComment,
+    AdminPurgeCommunity,
+    AdminPurgePerson,
+    AdminPurgePost,
+    ModAdd,
+    ModAddCommunity,
+    ModBan,
+    ModBanFromCommunity,
+    ModCommunityPostTag,
+    ModFeaturePost,
+    ModHideCommunity,
+    ModLockPost,
+    ModRemoveComment,
+    ModRemoveCommunity,
+    ModRemovePost,
+    ModResolveReport,
+    ModTransferCommunity,
+    ModWarning,
   },
+  person::Person,
+  post::Post,
 };
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -88,6 +89,46 @@ pub struct ModHideCommunityView {
   pub community: Community,
 }
 
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS, Queryable))]
+#[cfg_attr(feature = "full", ts(export))]
+/// When the reports about a post or comment are resolved or reopened.
+pub struct ModResolveReportView {
+  pub mod_resolve_report: ModResolveReport,
+  pub moderator: Option<Person>,
+  pub reported_person: Person,
+  pub community: Community,
+  pub post: Post,
+  pub comment: Option<Comment>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS, Queryable))]
+#[cfg_attr(feature = "full", ts(export))]
+/// When someone is warned because of a post or comment.
+pub struct ModWarningView {
+  pub mod_warning: ModWarning,
+  pub moderator: Option<Person>,
+  pub warned_person: Person,
+  pub community: Community,
+  pub post: Post,
+  pub comment: Option<Comment>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS, Queryable))]
+#[cfg_attr(feature = "full", ts(export))]
+/// When a moderator creates, renames or deletes a community post tag.
+pub struct ModCommunityPostTagView {
+  pub mod_community_post_tag: ModCommunityPostTag,
+  pub moderator: Option<Person>,
+  pub community_post_tag: CommunityPostTag,
+  pub community: Community,
+}
+
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[cfg_attr(feature = "full", derive(TS, Queryable))]
@@ -204,15 +245,45 @@ pub struct AdminPurgePostView {
 }
 
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize, Clone, Copy)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[cfg_attr(feature = "full", derive(TS, Queryable))]
 #[cfg_attr(feature = "full", ts(export))]
-/// Querying / filtering the modlog.
-pub struct ModlogListParams {
-  pub community_id: Option<CommunityId>,
-  pub mod_person_id: Option<PersonId>,
-  pub other_person_id: Option<PersonId>,
-  pub page: Option<i64>,
-  pub limit: Option<i64>,
-  pub hide_modlog_names: bool,
+/// A private note of a moderator about a person.
+pub struct PersonNoteView {
+  pub person_note: PersonNote,
+  pub creator: Person,
+  pub community: Option<Community>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+#[serde(tag = "type_")]
+/// A single entry of the modlog.
+pub enum ModlogEntry {
+  ModRemovePost(ModRemovePostView),
+  ModLockPost(ModLockPostView),
+  ModFeaturePost(ModFeaturePostView),
+  ModRemoveComment(ModRemoveCommentView),
+  ModRemoveCommunity(ModRemoveCommunityView),
+  ModBanFromCommunity(ModBanFromCommunityView),
+  ModBan(ModBanView),
+  ModAddCommunity(ModAddCommunityView),
+  ModTransferCommunity(ModTransferCommunityView),
+  ModAdd(ModAddView),
+  ModHideCommunity(ModHideCommunityView),
+  ModCommunityPostTag(ModCommunityPostTagView),
+  AdminPurgePerson(AdminPurgePersonView),
+  AdminPurgeCommunity(AdminPurgeCommunityView),
+  AdminPurgePost(AdminPurgePostView),
+  AdminPurgeComment(AdminPurgeCommentView),
+  ModWarning(ModWarningView),
+  ModResolveReport(ModResolveReportView),
 }
+
+/// The position after which the next page of the modlog starts. It should be treated as opaque
+/// by clients.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "full", derive(TS))]
+#[cfg_attr(feature = "full", ts(export))]
+pub struct ModlogCursor(pub(crate) String);
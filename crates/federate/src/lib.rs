@@ -1,14 +1,16 @@
 use crate::{
+  push_worker::PushWorker,
   util::{retry_sleep_duration, CancellableTask},
+  webhook_worker::WebhookWorker,
   worker::InstanceWorker,
 };
 use activitypub_federation::config::FederationConfig;
-use chrono::{Local, Timelike};
+use chrono::{DateTime, Local, Timelike, Utc};
 use federation_queue_state::FederationQueueState;
 use lemmy_api_common::context::LemmyContext;
 use lemmy_db_schema::{
-  newtypes::InstanceId,
-  source::instance::Instance,
+  newtypes::{InstanceId, WebhookId},
+  source::{instance::Instance, webhook::Webhook},
   utils::{ActualDbPool, DbPool},
 };
 use std::{collections::HashMap, time::Duration};
@@ -19,7 +21,10 @@ use tokio::{
 use tokio_util::sync::CancellationToken;
 
 mod federation_queue_state;
+mod push_worker;
 mod util;
+mod webhook_queue_state;
+mod webhook_worker;
 mod worker;
 
 static WORKER_EXIT_TIMEOUT: Duration = Duration::from_secs(30);
@@ -144,6 +149,90 @@ pub fn start_stop_federation_workers_cancellable(
   })
 }
 
+async fn start_stop_webhook_workers(
+  opts: Opts,
+  pool: ActualDbPool,
+  federation_config: FederationConfig<LemmyContext>,
+  cancel: CancellationToken,
+) -> anyhow::Result<()> {
+  // the last update time is kept, so that workers are restarted when their webhook is edited
+  let mut workers = HashMap::<WebhookId, (Option<DateTime<Utc>>, CancellableTask<_>)>::new();
+  let pool2 = &mut DbPool::Pool(&pool);
+  let process_index = opts.process_index - 1;
+  loop {
+    let webhooks: Vec<Webhook> = Webhook::list_enabled(pool2)
+      .await?
+      .into_iter()
+      .filter(|w| w.id.0 % opts.process_count == process_index)
+      .collect();
+    // stop the workers of webhooks which were edited, disabled or deleted, and those which errored
+    let stale: Vec<WebhookId> = workers
+      .iter()
+      .filter(|(id, (updated, worker))| {
+        worker.has_ended()
+          || !webhooks
+            .iter()
+            .any(|w| w.id == **id && w.updated == *updated)
+      })
+      .map(|(id, _)| *id)
+      .collect();
+    for id in stale {
+      if let Some((_, worker)) = workers.remove(&id) {
+        if let Err(e) = worker.cancel().await {
+          tracing::error!("error stopping webhook worker: {e}");
+        }
+      }
+    }
+    for webhook in webhooks {
+      if workers.contains_key(&webhook.id) {
+        continue;
+      }
+      let context = federation_config.to_request_data();
+      let pool = pool.clone();
+      workers.insert(
+        webhook.id,
+        (
+          webhook.updated,
+          CancellableTask::spawn(WORKER_EXIT_TIMEOUT, |stop| async move {
+            WebhookWorker::init_and_loop(webhook, context, &mut DbPool::Pool(&pool), stop).await?;
+            Ok(())
+          }),
+        ),
+      );
+    }
+    tracing::debug!("Delivering events to {} webhooks", workers.len());
+    tokio::select! {
+      () = sleep(INSTANCES_RECHECK_DELAY) => {},
+      _ = cancel.cancelled() => { break; }
+    }
+  }
+  futures::future::join_all(workers.into_values().map(|(_, w)| w.cancel())).await;
+  Ok(())
+}
+
+/// starts and stops the workers which deliver events to the enabled webhooks, the same way as
+/// [start_stop_federation_workers_cancellable]
+pub fn start_stop_webhook_workers_cancellable(
+  opts: Opts,
+  pool: ActualDbPool,
+  config: FederationConfig<LemmyContext>,
+) -> CancellableTask<()> {
+  CancellableTask::spawn(WORKER_EXIT_TIMEOUT, move |c| {
+    start_stop_webhook_workers(opts, pool, config, c)
+  })
+}
+
+/// starts the worker which delivers the queued push notifications. unlike webhooks, there is a
+/// single worker for all subscriptions, so this should only run in one process.
+pub fn start_push_worker_cancellable(
+  pool: ActualDbPool,
+  config: FederationConfig<LemmyContext>,
+) -> CancellableTask<()> {
+  CancellableTask::spawn(WORKER_EXIT_TIMEOUT, move |stop| async move {
+    PushWorker::init_and_loop(config.to_request_data(), pool, stop).await
+  })
+}
+
 /// every 60s, print the state for every instance. exits if the receiver is done (all senders dropped)
 async fn receive_print_stats(
   pool: ActualDbPool,
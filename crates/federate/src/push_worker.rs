@@ -0,0 +1,181 @@
+use crate::util::{public_url_client, retry_sleep_duration, WORK_FINISHED_RECHECK_DELAY};
+use activitypub_federation::config::Data;
+use anyhow::{Context, Result};
+use chrono::Utc;
+use futures::{stream, StreamExt};
+use lemmy_api_common::context::LemmyContext;
+use lemmy_db_schema::{
+  source::push_subscription::{PushNotification, PushSubscription},
+  traits::Crud,
+  utils::{ActualDbPool, DbPool},
+};
+use lemmy_utils::{
+  error::LemmyErrorExt2,
+  web_push::{encrypt_push_message, vapid_authorization, VapidKeypair},
+};
+use reqwest::{StatusCode, Url};
+use std::time::Duration;
+use tokio::time::sleep;
+use tokio_util::sync::CancellationToken;
+
+/// How many notifications are read from the db at once
+static NOTIFICATION_BATCH_SIZE: i64 = 100;
+/// How many notifications are delivered at the same time. They go to different push services, so
+/// a slow one shouldn't hold back the others.
+static CONCURRENT_DELIVERIES: usize = 10;
+/// A notification is dropped after this many failed attempts
+static MAX_DELIVERY_RETRIES: i32 = 8;
+/// Time to wait for the response of the push service
+static DELIVERY_TIMEOUT: Duration = Duration::from_secs(10);
+/// How long the push service keeps a message for a device which is offline, in seconds
+static MESSAGE_TTL: u32 = 86400;
+
+pub(crate) struct PushWorker {
+  context: Data<LemmyContext>,
+  pool: ActualDbPool,
+  keypair: VapidKeypair,
+  /// Contact for the operators of push services, which is part of the VAPID authorization
+  subject: String,
+  stop: CancellationToken,
+}
+
+impl PushWorker {
+  pub(crate) async fn init_and_loop(
+    context: Data<LemmyContext>,
+    pool: ActualDbPool,
+    stop: CancellationToken,
+  ) -> Result<()> {
+    let secret = context.secret();
+    let keypair = VapidKeypair {
+      private_key: secret
+        .vapid_private_key
+        .clone()
+        .context("vapid private key is missing")?,
+      public_key: secret
+        .vapid_public_key
+        .clone()
+        .context("vapid public key is missing")?,
+    };
+    let subject = context.settings().get_protocol_and_hostname();
+    let worker = PushWorker {
+      context,
+      pool,
+      keypair,
+      subject,
+      stop,
+    };
+    worker.loop_until_stopped().await
+  }
+
+  /// fetch due notifications from db and deliver them to the push services, until the
+  /// cancellation token is cancelled
+  async fn loop_until_stopped(&self) -> Result<()> {
+    while !self.stop.is_cancelled() {
+      let due = PushNotification::list_due(&mut DbPool::Pool(&self.pool), NOTIFICATION_BATCH_SIZE)
+        .await
+        .unwrap_or_else(|e| {
+          // nothing restarts this worker, so it keeps going until the db is reachable again
+          tracing::error!("failed reading push notifications from db: {e}");
+          vec![]
+        });
+      if due.is_empty() {
+        // no more work to be done, wait before rechecking
+        tokio::select! {
+          () = sleep(*WORK_FINISHED_RECHECK_DELAY) => {},
+          () = self.stop.cancelled() => {}
+        }
+        continue;
+      }
+      stream::iter(due)
+        .for_each_concurrent(
+          CONCURRENT_DELIVERIES,
+          |(notification, subscription)| async move {
+            if let Err(e) = self.deliver(&notification, &subscription).await {
+              tracing::warn!(
+                "failed delivering push notification {}: {e}",
+                notification.id
+              );
+            }
+          },
+        )
+        .await;
+    }
+    Ok(())
+  }
+
+  /// Sends a notification, and removes it from the queue or schedules the next attempt depending
+  /// on the response.
+  async fn deliver(
+    &self,
+    notification: &PushNotification,
+    subscription: &PushSubscription,
+  ) -> Result<()> {
+    let pool = &mut DbPool::Pool(&self.pool);
+    tracing::debug!(
+      "delivering push notification {} to {}",
+      notification.id,
+      subscription.endpoint
+    );
+    match self.send(notification, subscription).await {
+      Ok(status) if status.is_success() => {
+        PushNotification::delete(pool, notification.id).await?;
+      }
+      // The subscription expired, or the user revoked the permission
+      Ok(StatusCode::NOT_FOUND | StatusCode::GONE) => {
+        tracing::info!("removing expired push subscription {}", subscription.id.0);
+        PushSubscription::delete(pool, subscription.id).await?;
+      }
+      // Sending the same message again can't succeed
+      Ok(status @ (StatusCode::BAD_REQUEST | StatusCode::PAYLOAD_TOO_LARGE)) => {
+        tracing::warn!(
+          "push service rejected notification {} with status {status}",
+          notification.id
+        );
+        PushNotification::delete(pool, notification.id).await?;
+      }
+      res => {
+        let fail_count = notification.fail_count + 1;
+        if fail_count > MAX_DELIVERY_RETRIES {
+          tracing::warn!(
+            "giving up on push notification {} after {fail_count} attempts: {res:?}",
+            notification.id
+          );
+          PushNotification::delete(pool, notification.id).await?;
+        } else {
+          let next_attempt =
+            Utc::now() + chrono::Duration::from_std(retry_sleep_duration(fail_count))?;
+          PushNotification::retry_later(pool, notification.id, fail_count, next_attempt).await?;
+        }
+      }
+    }
+    Ok(())
+  }
+
+  async fn send(
+    &self,
+    notification: &PushNotification,
+    subscription: &PushSubscription,
+  ) -> Result<StatusCode> {
+    let endpoint = Url::parse(&subscription.endpoint)?;
+    let authorization =
+      vapid_authorization(&endpoint, &self.keypair, &self.subject).into_anyhow()?;
+    let body = encrypt_push_message(
+      &subscription.p256dh,
+      &subscription.auth,
+      notification.payload.as_bytes(),
+    )
+    .into_anyhow()?;
+    let res = public_url_client(&endpoint, self.context.settings(), DELIVERY_TIMEOUT)
+      .await
+      .into_anyhow()?
+      .post(endpoint)
+      .header("TTL", MESSAGE_TTL.to_string())
+      .header("Content-Encoding", "aes128gcm")
+      .header("Content-Type", "application/octet-stream")
+      .header("Authorization", authorization)
+      .body(body)
+      .send()
+      .await?;
+    Ok(res.status())
+  }
+}
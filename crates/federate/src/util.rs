@@ -1,6 +1,7 @@
 use anyhow::{anyhow, Context, Result};
 use diesel::prelude::*;
 use diesel_async::RunQueryDsl;
+use lemmy_api_common::request::build_user_agent;
 use lemmy_apub::{
   activity_lists::SharedInboxActivities,
   fetcher::{site_or_community_or_user::SiteOrCommunityOrUser, user_or_community::UserOrCommunity},
@@ -15,17 +16,23 @@ use lemmy_db_schema::{
   traits::ApubActor,
   utils::{get_conn, DbPool},
 };
+use lemmy_utils::{
+  error::{LemmyErrorType, LemmyResult},
+  settings::structs::Settings,
+  utils::validation::{check_url_host_public, is_public_ip},
+};
 use moka::future::Cache;
 use once_cell::sync::Lazy;
-use reqwest::Url;
+use reqwest::{redirect::Policy, Client, Url};
 use serde_json::Value;
 use std::{
   future::Future,
+  net::SocketAddr,
   pin::Pin,
   sync::{Arc, RwLock},
   time::Duration,
 };
-use tokio::{task::JoinHandle, time::sleep};
+use tokio::{net::lookup_host, task::JoinHandle, time::sleep};
 use tokio_util::sync::CancellationToken;
 
 /// Decrease the delays of the federation queue.
@@ -203,3 +210,32 @@ pub(crate) async fn get_latest_activity_id(pool: &mut DbPool<'_>) -> Result<Acti
 pub(crate) fn retry_sleep_duration(retry_count: i32) -> Duration {
   Duration::from_secs_f64(10.0 * 2.0_f64.powf(f64::from(retry_count)))
 }
+
+/// Builds a client for requests to a url which was given by a user, like that of a webhook. It
+/// can only connect to the public addresses which the host resolved to right now, so that the url
+/// can't be used to reach the server itself or its private network, even if the DNS records change
+/// after the check. Redirects aren't followed for the same reason.
+pub(crate) async fn public_url_client(
+  url: &Url,
+  settings: &Settings,
+  timeout: Duration,
+) -> LemmyResult<Client> {
+  check_url_host_public(url)?;
+  let mut builder = Client::builder()
+    .user_agent(build_user_agent(settings))
+    .timeout(timeout)
+    .redirect(Policy::none());
+  if let Some(domain) = url.domain() {
+    let port = url
+      .port_or_known_default()
+      .ok_or(LemmyErrorType::InvalidUrl)?;
+    let addrs = lookup_host((domain, port))
+      .await?
+      .collect::<Vec<SocketAddr>>();
+    if addrs.is_empty() || !addrs.iter().all(|addr| is_public_ip(addr.ip())) {
+      Err(LemmyErrorType::UrlHostNotAllowed)?
+    }
+    builder = builder.resolve_to_addrs(domain, &addrs);
+  }
+  Ok(builder.build()?)
+}
@@ -0,0 +1,54 @@
+use anyhow::Result;
+use chrono::{DateTime, TimeZone, Utc};
+use diesel::prelude::*;
+use diesel_async::RunQueryDsl;
+use lemmy_db_schema::{
+  newtypes::WebhookId,
+  utils::{get_conn, DbPool},
+};
+
+#[derive(Queryable, Selectable, Insertable, AsChangeset, Clone)]
+#[diesel(table_name = lemmy_db_schema::schema::webhook_queue_state)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct WebhookQueueState {
+  pub webhook_id: WebhookId,
+  pub last_successful_id: i64,
+  pub fail_count: i32,
+  pub last_retry: DateTime<Utc>,
+}
+
+impl WebhookQueueState {
+  /// load state or return a default empty value
+  pub async fn load(pool: &mut DbPool<'_>, webhook_id_: WebhookId) -> Result<WebhookQueueState> {
+    use lemmy_db_schema::schema::webhook_queue_state::dsl::{webhook_id, webhook_queue_state};
+    let conn = &mut get_conn(pool).await?;
+    Ok(
+      webhook_queue_state
+        .filter(webhook_id.eq(&webhook_id_))
+        .select(WebhookQueueState::as_select())
+        .get_result(conn)
+        .await
+        .optional()?
+        .unwrap_or(WebhookQueueState {
+          webhook_id: webhook_id_,
+          fail_count: 0,
+          last_retry: Utc.timestamp_nanos(0),
+          // events from before the webhook was created are skipped when listing them
+          last_successful_id: 0,
+        }),
+    )
+  }
+  pub async fn upsert(pool: &mut DbPool<'_>, state: &WebhookQueueState) -> Result<()> {
+    use lemmy_db_schema::schema::webhook_queue_state::dsl::{webhook_id, webhook_queue_state};
+    let conn = &mut get_conn(pool).await?;
+
+    state
+      .insert_into(webhook_queue_state)
+      .on_conflict(webhook_id)
+      .do_update()
+      .set(state)
+      .execute(conn)
+      .await?;
+    Ok(())
+  }
+}
@@ -0,0 +1,301 @@
+use crate::{
+  util::{public_url_client, retry_sleep_duration, WORK_FINISHED_RECHECK_DELAY},
+  webhook_queue_state::WebhookQueueState,
+};
+use activitypub_federation::config::Data;
+use anyhow::{Context, Result};
+use chrono::{DateTime, TimeZone, Utc};
+use lemmy_api_common::context::LemmyContext;
+use lemmy_db_schema::{
+  newtypes::CommunityId,
+  source::webhook::{Webhook, WebhookDelivery, WebhookDeliveryForm, WebhookEvent},
+  utils::DbPool,
+  WebhookEventType,
+};
+use lemmy_utils::error::{LemmyError, LemmyErrorType, LemmyResult};
+use openssl::{hash::MessageDigest, pkey::PKey, sign::Signer};
+use serde::Serialize;
+use serde_json::Value;
+use std::time::Duration;
+use tokio::time::sleep;
+use tokio_util::sync::CancellationToken;
+
+/// How many events are read from the db at once
+static EVENT_BATCH_SIZE: i64 = 100;
+/// Save state to db after this time has passed since the last state
+static SAVE_STATE_EVERY_TIME: Duration = Duration::from_secs(60);
+/// Unlike federation, an event is skipped after this many failed attempts. The last retry happens
+/// after about 40 minutes, so an endpoint which is down doesn't block the queue forever.
+static MAX_DELIVERY_RETRIES: i32 = 8;
+/// Time to wait for the response of the webhook endpoint
+static DELIVERY_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The body which is posted to the webhook url
+#[derive(Serialize)]
+struct WebhookPayload<'a> {
+  id: i64,
+  event: WebhookEventType,
+  community_id: Option<CommunityId>,
+  published: DateTime<Utc>,
+  data: &'a Value,
+}
+
+pub(crate) struct WebhookWorker {
+  webhook: Webhook,
+  stop: CancellationToken,
+  context: Data<LemmyContext>,
+  state: WebhookQueueState,
+  last_state_insert: DateTime<Utc>,
+}
+
+impl WebhookWorker {
+  pub(crate) async fn init_and_loop(
+    webhook: Webhook,
+    context: Data<LemmyContext>,
+    pool: &mut DbPool<'_>,
+    stop: CancellationToken,
+  ) -> Result<(), anyhow::Error> {
+    let state = WebhookQueueState::load(pool, webhook.id).await?;
+    let mut worker = WebhookWorker {
+      webhook,
+      stop,
+      context,
+      state,
+      last_state_insert: Utc.timestamp_nanos(0),
+    };
+    worker.loop_until_stopped(pool).await
+  }
+
+  /// loop fetch new events from db and deliver them to the webhook url
+  /// this worker only returns if (a) there is an internal error or (b) the cancellation token is cancelled (graceful exit)
+  async fn loop_until_stopped(&mut self, pool: &mut DbPool<'_>) -> Result<(), anyhow::Error> {
+    let save_state_every = chrono::Duration::from_std(SAVE_STATE_EVERY_TIME).expect("not negative");
+
+    self.initial_fail_sleep().await?;
+    while !self.stop.is_cancelled() {
+      self.loop_batch(pool).await?;
+      if self.stop.is_cancelled() {
+        break;
+      }
+      if (Utc::now() - self.last_state_insert) > save_state_every {
+        self.save_state(pool).await?;
+      }
+    }
+    // final update of state in db
+    self.save_state(pool).await?;
+    Ok(())
+  }
+
+  async fn initial_fail_sleep(&mut self) -> Result<()> {
+    // before starting queue, sleep remaining duration if last request failed
+    if self.state.fail_count > 0 {
+      let elapsed = (Utc::now() - self.state.last_retry).to_std()?;
+      let required = retry_sleep_duration(self.state.fail_count);
+      if elapsed >= required {
+        return Ok(());
+      }
+      let remaining = required - elapsed;
+      tokio::select! {
+        () = sleep(remaining) => {},
+        () = self.stop.cancelled() => {}
+      }
+    }
+    Ok(())
+  }
+
+  /// deliver a batch of EVENT_BATCH_SIZE events
+  async fn loop_batch(&mut self, pool: &mut DbPool<'_>) -> Result<()> {
+    let events = WebhookEvent::list_for_webhook(
+      pool,
+      &self.webhook,
+      self.state.last_successful_id,
+      EVENT_BATCH_SIZE,
+    )
+    .await
+    .context("failed reading webhook events from db")?;
+    if events.is_empty() {
+      // no more work to be done, wait before rechecking
+      tokio::select! {
+        () = sleep(*WORK_FINISHED_RECHECK_DELAY) => {},
+        () = self.stop.cancelled() => {}
+      }
+      return Ok(());
+    }
+    for event in events {
+      self.send_retry_loop(pool, &event).await?;
+      if self.stop.is_cancelled() {
+        return Ok(());
+      }
+      self.state.last_successful_id = event.id;
+      self.state.fail_count = 0;
+    }
+    Ok(())
+  }
+
+  // this function will return successfully when (a) delivery succeeded, (b) the retries are used
+  // up or (c) worker cancelled. it returns an error if an internal error occurred
+  async fn send_retry_loop(&mut self, pool: &mut DbPool<'_>, event: &WebhookEvent) -> Result<()> {
+    let payload = WebhookPayload {
+      id: event.id,
+      event: event.event_type,
+      community_id: event.community_id,
+      published: event.published,
+      data: &event.data,
+    };
+    let body = serde_json::to_vec(&payload)?;
+    let signature = sign_payload(&self.webhook.secret, &body)?;
+    loop {
+      tracing::debug!(
+        "delivering webhook event {} to {}",
+        event.id,
+        self.webhook.url
+      );
+      let (status_code, error) = match self.send(event, &body, &signature).await {
+        Ok(status) if status.is_success() => (Some(status.as_u16()), None),
+        Ok(status) => (
+          Some(status.as_u16()),
+          Some(format!("Unexpected status {status}")),
+        ),
+        Err(e) => {
+          tracing::info!(
+            "webhook {}: delivery of event {} failed: {e}",
+            self.webhook.id.0,
+            event.id
+          );
+          (None, Some(delivery_error(&e)))
+        }
+      };
+      let success = error.is_none();
+      let form = WebhookDeliveryForm {
+        webhook_id: self.webhook.id,
+        event_id: event.id,
+        event_type: event.event_type,
+        success,
+        status_code: status_code.map(i32::from),
+        error,
+      };
+      WebhookDelivery::create(pool, &form)
+        .await
+        .context("failed logging webhook delivery")?;
+      if success {
+        return Ok(());
+      }
+
+      self.state.fail_count += 1;
+      self.state.last_retry = Utc::now();
+      if self.state.fail_count > MAX_DELIVERY_RETRIES {
+        tracing::warn!(
+          "webhook {}: giving up on event {} after {} attempts",
+          self.webhook.id.0,
+          event.id,
+          self.state.fail_count
+        );
+        return Ok(());
+      }
+      let retry_delay = retry_sleep_duration(self.state.fail_count);
+      tracing::info!(
+        "webhook {}: retrying event {} attempt {} with delay {retry_delay:.2?}",
+        self.webhook.id.0,
+        event.id,
+        self.state.fail_count
+      );
+      self.save_state(pool).await?;
+      tokio::select! {
+        () = sleep(retry_delay) => {},
+        () = self.stop.cancelled() => {
+          // save state to db and exit
+          return Ok(());
+        }
+      }
+    }
+  }
+
+  async fn send(
+    &self,
+    event: &WebhookEvent,
+    body: &[u8],
+    signature: &str,
+  ) -> LemmyResult<reqwest::StatusCode> {
+    let url = self.webhook.url.inner();
+    let res = public_url_client(url, self.context.settings(), DELIVERY_TIMEOUT)
+      .await?
+      .post(url.clone())
+      .header("Content-Type", "application/json")
+      .header("X-Lemmy-Event", event.event_type.to_string())
+      .header("X-Lemmy-Delivery", event.id.to_string())
+      .header("X-Lemmy-Signature", format!("sha256={signature}"))
+      .body(body.to_vec())
+      .send()
+      .await?;
+    Ok(res.status())
+  }
+
+  async fn save_state(&mut self, pool: &mut DbPool<'_>) -> Result<()> {
+    self.last_state_insert = Utc::now();
+    WebhookQueueState::upsert(pool, &self.state).await?;
+    Ok(())
+  }
+}
+
+/// The error which is stored for a failed delivery. It is shown to the moderators who manage the
+/// webhook, so it only says what kind of failure happened and nothing about the network of the
+/// server. The full error is logged.
+fn delivery_error(e: &LemmyError) -> String {
+  let message = if e.error_type == LemmyErrorType::UrlHostNotAllowed {
+    "Address not allowed"
+  } else if let Some(e) = e.inner.downcast_ref::<reqwest::Error>() {
+    if e.is_timeout() {
+      "Request timed out"
+    } else if e.is_connect() {
+      "Connection failed"
+    } else {
+      "Request failed"
+    }
+  } else {
+    "Delivery failed"
+  };
+  message.to_string()
+}
+
+/// HMAC-SHA256 of the request body with the secret of the webhook, as lowercase hex
+fn sign_payload(secret: &str, body: &[u8]) -> Result<String> {
+  let key = PKey::hmac(secret.as_bytes())?;
+  let mut signer = Signer::new(MessageDigest::sha256(), &key)?;
+  signer.update(body)?;
+  Ok(
+    signer
+      .sign_to_vec()?
+      .iter()
+      .map(|b| format!("{b:02x}"))
+      .collect(),
+  )
+}
+
+#[cfg(test)]
+mod tests {
+  #![allow(clippy::unwrap_used)]
+
+  use super::{delivery_error, sign_payload};
+  use lemmy_utils::error::{LemmyError, LemmyErrorType};
+
+  #[test]
+  fn test_sign_payload() {
+    let signature = sign_payload("key", b"The quick brown fox jumps over the lazy dog").unwrap();
+    assert_eq!(
+      "f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8",
+      signature
+    );
+  }
+  #[test]
+  fn test_delivery_error() {
+    let not_allowed = LemmyError::from(LemmyErrorType::UrlHostNotAllowed);
+    assert_eq!("Address not allowed", delivery_error(&not_allowed));
+
+    // Other errors could contain internal details
+    let io_error = std::io::Error::other("connect to 10.0.0.5:8080 refused");
+    assert_eq!(
+      "Delivery failed",
+      delivery_error(&LemmyError::from(io_error))
+    );
+  }
+}
@@ -4,9 +4,10 @@ use anyhow::anyhow;
 use chrono::{DateTime, Utc};
 use lemmy_api_common::context::LemmyContext;
 use lemmy_db_schema::{
-  source::{community::Community, person::Person},
+  source::{community::Community, multi_community::MultiCommunity, person::Person},
   traits::ApubActor,
   CommentSortType,
+  CommunityVisibility,
   ListingType,
   SortType,
 };
@@ -21,7 +22,7 @@ use lemmy_db_views_actor::{
 };
 use lemmy_utils::{
   cache_header::cache_1hour,
-  error::LemmyError,
+  error::{LemmyError, LemmyErrorType},
   utils::markdown::{markdown_to_html, sanitize_html},
 };
 use once_cell::sync::Lazy;
@@ -62,6 +63,7 @@ impl Params {
 
 enum RequestType {
   Community,
+  MultiCommunity,
   User,
   Front,
   Inbox,
@@ -175,6 +177,7 @@ async fn get_feed(
   let request_type = match req_type.as_str() {
     "u" => RequestType::User,
     "c" => RequestType::Community,
+    "m" => RequestType::MultiCommunity,
     "front" => RequestType::Front,
     "inbox" => RequestType::Inbox,
     _ => return Err(ErrorBadRequest(LemmyError::from(anyhow!("wrong_type")))),
@@ -201,6 +204,16 @@ async fn get_feed(
       )
       .await
     }
+    RequestType::MultiCommunity => {
+      get_feed_multi_community(
+        &context,
+        &info.sort_type()?,
+        &info.get_limit(),
+        &info.get_page(),
+        &param,
+      )
+      .await
+    }
     RequestType::Front => {
       get_feed_front(
         &context,
@@ -268,6 +281,9 @@ async fn get_feed_community(
 ) -> Result<ChannelBuilder, LemmyError> {
   let site_view = SiteView::read_local(&mut context.pool()).await?;
   let community = Community::read_from_name(&mut context.pool(), community_name, false).await?;
+  if community.visibility != CommunityVisibility::Public {
+    Err(LemmyErrorType::CommunityContentNotVisible)?
+  }
 
   let posts = PostQuery {
     sort: (Some(*sort_type)),
@@ -295,6 +311,47 @@ async fn get_feed_community(
   Ok(channel_builder)
 }
 
+#[tracing::instrument(skip_all)]
+async fn get_feed_multi_community(
+  context: &LemmyContext,
+  sort_type: &SortType,
+  limit: &i64,
+  page: &i64,
+  multi_community_name: &str,
+) -> Result<ChannelBuilder, LemmyError> {
+  let site_view = SiteView::read_local(&mut context.pool()).await?;
+  let multi_community =
+    MultiCommunity::read_from_name(&mut context.pool(), multi_community_name, false).await?;
+
+  let posts = PostQuery {
+    sort: (Some(*sort_type)),
+    multi_community_id: (Some(multi_community.id)),
+    limit: (Some(*limit)),
+    page: (Some(*page)),
+    ..Default::default()
+  }
+  .list(&mut context.pool())
+  .await?;
+
+  let items = create_post_items(posts, &context.settings().get_protocol_and_hostname())?;
+
+  let mut channel_builder = ChannelBuilder::default();
+  channel_builder
+    .namespaces(RSS_NAMESPACE.clone())
+    .title(&format!(
+      "{} - {}",
+      site_view.site.name, multi_community.title
+    ))
+    .link(multi_community.ap_id.to_string())
+    .items(items);
+
+  if let Some(multi_community_desc) = multi_community.description {
+    channel_builder.description(markdown_to_html(&multi_community_desc));
+  }
+
+  Ok(channel_builder)
+}
+
 #[tracing::instrument(skip_all)]
 async fn get_feed_front(
   context: &LemmyContext,
@@ -390,6 +447,7 @@ fn create_reply_and_mention_items(
 ) -> Result<Vec<Item>, LemmyError> {
   let mut reply_items: Vec<Item> = replies
     .iter()
+    .filter(|r| r.community.visibility == CommunityVisibility::Public)
     .map(|r| {
       let reply_url = format!("{}/comment/{}", protocol_and_hostname, r.comment.id);
       build_item(
@@ -404,6 +462,7 @@ fn create_reply_and_mention_items(
 
   let mut mention_items: Vec<Item> = mentions
     .iter()
+    .filter(|m| m.community.visibility == CommunityVisibility::Public)
     .map(|m| {
       let mention_url = format!("{}/comment/{}", protocol_and_hostname, m.comment.id);
       build_item(
@@ -453,7 +512,11 @@ fn create_post_items(
 ) -> Result<Vec<Item>, LemmyError> {
   let mut items: Vec<Item> = Vec::new();
 
-  for p in posts {
+  // Feeds can be read by anyone who knows their url, so they only contain public content
+  for p in posts
+    .into_iter()
+    .filter(|p| p.community.visibility == CommunityVisibility::Public)
+  {
     let mut i = ItemBuilder::default();
     let mut dc_extension = DublinCoreExtensionBuilder::default();
 
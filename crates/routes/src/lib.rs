@@ -4,6 +4,7 @@ use lemmy_utils::error::LemmyError;
 
 pub mod feeds;
 pub mod images;
+pub mod live;
 pub mod nodeinfo;
 pub mod webfinger;
 
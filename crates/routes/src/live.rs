@@ -0,0 +1,227 @@
+use actix_web::{
+  http::header::CONTENT_ENCODING,
+  web::{Bytes, Data, Query},
+  HttpResponse,
+};
+use futures::stream;
+use lemmy_api_common::{
+  context::LemmyContext,
+  live::{GetLiveEvents, LiveEvent},
+  live_hub::LiveMessage,
+  person::GetReportCountResponse,
+  utils::check_community_content_visible,
+};
+use lemmy_db_schema::{
+  newtypes::CommunityId,
+  source::{
+    community::{CommunityFollower, CommunityModerator},
+    local_site::LocalSite,
+  },
+};
+use lemmy_db_views::structs::{
+  CommentReportView,
+  LocalUserView,
+  PostReportView,
+  PostView,
+  PrivateMessageReportView,
+  PrivateMessageView,
+};
+use lemmy_db_views_actor::structs::{CommentReplyView, PersonMentionView};
+use lemmy_db_views_moderator::structs::ModWarningView;
+use lemmy_utils::error::LemmyError;
+use std::{collections::HashSet, convert::Infallible, time::Duration};
+use tokio::{
+  sync::broadcast::{error::RecvError, Receiver},
+  time::{interval, Interval},
+};
+use tracing::warn;
+
+/// Comments are sent in this interval, so that proxies don't close idle connections. The
+/// subscribed and moderated communities are reloaded at the same time.
+const KEEPALIVE_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Streams the notifications of the user as server-sent events, until the client disconnects.
+#[tracing::instrument(skip(context))]
+pub async fn get_live_events(
+  data: Query<GetLiveEvents>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+) -> Result<HttpResponse, LemmyError> {
+  let connection = LiveConnection {
+    receiver: context.live().subscribe(),
+    context,
+    local_user_view,
+    include_posts: data.posts.unwrap_or_default(),
+    followed_communities: HashSet::new(),
+    moderated_communities: HashSet::new(),
+    keepalive: interval(KEEPALIVE_INTERVAL),
+  };
+  let events = stream::unfold(connection, |mut connection| async move {
+    let event = connection.next_event().await?;
+    Some((Ok::<_, Infallible>(event), connection))
+  });
+
+  Ok(
+    HttpResponse::Ok()
+      .content_type("text/event-stream")
+      // The compression middleware would hold back the events
+      .insert_header((CONTENT_ENCODING, "identity"))
+      .streaming(events),
+  )
+}
+
+struct LiveConnection {
+  receiver: Receiver<LiveMessage>,
+  context: Data<LemmyContext>,
+  local_user_view: LocalUserView,
+  include_posts: bool,
+  followed_communities: HashSet<CommunityId>,
+  /// Report counts are only sent for these communities, unless the user is an admin.
+  moderated_communities: HashSet<CommunityId>,
+  keepalive: Interval,
+}
+
+impl LiveConnection {
+  /// Waits for the next event of this user, or the next keepalive. Returns `None` once the server
+  /// shuts down.
+  async fn next_event(&mut self) -> Option<Bytes> {
+    loop {
+      tokio::select! {
+        message = self.receiver.recv() => match message {
+          Ok(message) => match self.read_event(message).await {
+            Ok(Some(event)) => match serde_json::to_string(&event) {
+              Ok(json) => return Some(Bytes::from(format!("data: {json}\n\n"))),
+              Err(e) => warn!("Failed to serialize live event: {e}"),
+            },
+            Ok(None) => {}
+            Err(e) => warn!("Failed to read live event: {e}"),
+          },
+          // The client has to refetch anything it missed
+          Err(RecvError::Lagged(skipped)) => warn!("Live connection skipped {skipped} messages"),
+          Err(RecvError::Closed) => return None,
+        },
+        // The first tick completes immediately, so the client gets a response right away
+        _ = self.keepalive.tick() => {
+          if self.include_posts {
+            if let Err(e) = self.load_followed_communities().await {
+              warn!("Failed to load followed communities: {e}");
+            }
+          }
+          if !self.local_user_view.local_user.admin {
+            if let Err(e) = self.load_moderated_communities().await {
+              warn!("Failed to load moderated communities: {e}");
+            }
+          }
+          return Some(Bytes::from_static(b": keepalive\n\n"));
+        }
+      }
+    }
+  }
+
+  /// Reads the event for a message, if it is relevant for this user.
+  async fn read_event(&self, message: LiveMessage) -> Result<Option<LiveEvent>, LemmyError> {
+    let person_id = self.local_user_view.person.id;
+    let pool = &mut self.context.pool();
+    let event = match message {
+      LiveMessage::CommentReply {
+        recipient_id,
+        comment_reply_id,
+      } if recipient_id == person_id => Some(LiveEvent::CommentReply(
+        CommentReplyView::read(pool, comment_reply_id, Some(person_id)).await?,
+      )),
+      LiveMessage::PersonMention {
+        recipient_id,
+        person_mention_id,
+      } if recipient_id == person_id => Some(LiveEvent::PersonMention(
+        PersonMentionView::read(pool, person_mention_id, Some(person_id)).await?,
+      )),
+      LiveMessage::PrivateMessage {
+        recipient_ids,
+        private_message_id,
+      } if recipient_ids.contains(&person_id) => Some(LiveEvent::PrivateMessage(
+        PrivateMessageView::read(pool, private_message_id).await?,
+      )),
+      LiveMessage::Report { community_id } => self
+        .read_report_count(community_id)
+        .await?
+        .map(LiveEvent::ReportCount),
+      LiveMessage::Post {
+        community_id,
+        post_id,
+      } if self.include_posts && self.followed_communities.contains(&community_id) => {
+        let post_view = PostView::read(pool, post_id, Some(person_id), false).await?;
+        // The follow may have been revoked since the communities were loaded
+        let visible = check_community_content_visible(
+          Some(&self.local_user_view.person),
+          &post_view.community,
+          pool,
+        )
+        .await
+        .is_ok();
+        (visible && !post_view.creator_blocked).then_some(LiveEvent::Post(post_view))
+      }
+      LiveMessage::Warning {
+        recipient_id,
+        mod_warning_id,
+      } if recipient_id == person_id => {
+        let hide_modlog_names = LocalSite::read(pool).await?.hide_modlog_mod_names;
+        Some(LiveEvent::Warning(
+          ModWarningView::read(pool, mod_warning_id, hide_modlog_names).await?,
+        ))
+      }
+      _ => None,
+    };
+    Ok(event)
+  }
+
+  /// The report counts of all communities, if the user moderates the community of the report, or
+  /// is an admin.
+  async fn read_report_count(
+    &self,
+    community_id: Option<CommunityId>,
+  ) -> Result<Option<GetReportCountResponse>, LemmyError> {
+    let person_id = self.local_user_view.person.id;
+    let admin = self.local_user_view.local_user.admin;
+    let pool = &mut self.context.pool();
+    let is_mod = community_id.is_some_and(|c| self.moderated_communities.contains(&c));
+    if !admin && !is_mod {
+      return Ok(None);
+    }
+
+    let comment_reports = CommentReportView::get_report_count(pool, person_id, admin, None).await?;
+    let post_reports = PostReportView::get_report_count(pool, person_id, admin, None).await?;
+    let private_message_reports = if admin {
+      Some(PrivateMessageReportView::get_report_count(pool).await?)
+    } else {
+      None
+    };
+    Ok(Some(GetReportCountResponse {
+      community_id: None,
+      comment_reports,
+      post_reports,
+      private_message_reports,
+    }))
+  }
+
+  /// Pending follow requests are left out, as the posts of private communities are only visible
+  /// to approved followers.
+  async fn load_followed_communities(&mut self) -> Result<(), LemmyError> {
+    let person_id = self.local_user_view.person.id;
+    self.followed_communities =
+      CommunityFollower::list_approved_community_ids(&mut self.context.pool(), person_id)
+        .await?
+        .into_iter()
+        .collect();
+    Ok(())
+  }
+
+  async fn load_moderated_communities(&mut self) -> Result<(), LemmyError> {
+    let person_id = self.local_user_view.person.id;
+    self.moderated_communities =
+      CommunityModerator::get_person_moderated_communities(&mut self.context.pool(), person_id)
+        .await?
+        .into_iter()
+        .collect();
+    Ok(())
+  }
+}
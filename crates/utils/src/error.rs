@@ -223,6 +223,70 @@ pub enum LemmyErrorType {
   CommunityHasNoFollowers,
   BanExpirationInPast,
   InvalidUnixTime,
+  CantFollowYourself,
+  PersonFollowerAlreadyExists,
+  MultiCommunityAlreadyExists,
+  CouldntUpdateMultiCommunity,
+  NoMultiCommunityEditAllowed,
+  InvalidKeywordFilter,
+  KeywordFilterAlreadyExists,
+  CouldntCreatePostTag,
+  CouldntUpdatePostTag,
+  InvalidPostTag,
+  InvalidScheduledPublishTime,
+  PostAlreadyPublished,
+  InvalidPoll,
+  InvalidPollOption,
+  PollClosed,
+  InvalidDraft,
+  NoDraftEditAllowed,
+  CouldntSaveDraft,
+  CouldntCreateConversation,
+  NotAConversationParticipant,
+  InvalidConversationParticipants,
+  InvalidEncryptionPublicKey,
+  InvalidEncryptedMessage,
+  /// Encrypted messages can only be sent to local users who published an encryption key
+  RecipientCantReceiveEncryptedMessages,
+  /// Reports of encrypted messages need to include the decrypted text
+  DecryptedTextRequired,
+  /// The content of private communities is only visible to approved followers, that of local-only
+  /// communities only to logged in users
+  CommunityContentNotVisible,
+  /// There is no follow request for the community which waits for approval
+  NoPendingFollowRequest,
+  InvalidWikiPageSlug,
+  CouldntCreateWikiPage,
+  CouldntUpdateWikiPage,
+  /// Only moderators can edit this wiki page, or the page allows edits by members and you are not
+  /// an approved follower of the community
+  NoWikiPageEditAllowed,
+  InvalidCustomEmojiShortcode,
+  CouldntCreateCustomEmoji,
+  InvalidEmojiReaction,
+  InvalidWebhookSecret,
+  NoWebhookEvents,
+  InvalidWebhookEvent,
+  CouldntCreateWebhook,
+  CouldntUpdateWebhook,
+  InvalidPushSubscription,
+  CouldntCreatePushSubscription,
+  CouldntUpdatePushSubscription,
+  NoPushSubscriptionEditAllowed,
+  WebPushNotConfigured,
+  InvalidUnsubscribeToken,
+  CouldntCreatePersonNote,
+  CouldntUpdatePersonNote,
+  CouldntCreateWarning,
+  InvalidWarningTarget,
+  InvalidWarningEscalation,
+  InvalidReportAssignee,
+  CouldntCreateReportNote,
+  TooManyKeywordFilters,
+  TooManyDrafts,
+  UrlHostNotAllowed,
+  CouldntUnfollowPerson,
+  CantChangeRemotePollVote,
   Unknown(String),
 }
 
@@ -13,6 +13,7 @@ pub mod response;
 pub mod settings;
 pub mod utils;
 pub mod version;
+pub mod web_push;
 
 use error::LemmyError;
 use futures::Future;
@@ -0,0 +1,71 @@
+/// Above this many line comparisons the texts are not diffed line by line, but shown as
+/// completely replaced.
+const MAX_DIFF_CELLS: usize = 1_000_000;
+
+/// Creates a line based diff between two texts. Removed lines are prefixed with `- `, added
+/// lines with `+ ` and unchanged lines with two spaces.
+pub fn line_diff(old: &str, new: &str) -> String {
+  let old_lines: Vec<&str> = old.lines().collect();
+  let new_lines: Vec<&str> = new.lines().collect();
+  let (n, m) = (old_lines.len(), new_lines.len());
+
+  let mut out = Vec::with_capacity(n + m);
+  if n.saturating_mul(m) > MAX_DIFF_CELLS {
+    out.extend(old_lines.iter().map(|l| format!("- {l}")));
+    out.extend(new_lines.iter().map(|l| format!("+ {l}")));
+    return out.join("\n");
+  }
+
+  // The cell (i, j) holds the length of the longest common subsequence of old_lines[i..] and
+  // new_lines[j..]
+  let width = m + 1;
+  let mut lcs = vec![0usize; (n + 1) * width];
+  for (i, old_line) in old_lines.iter().enumerate().rev() {
+    for (j, new_line) in new_lines.iter().enumerate().rev() {
+      let value = if old_line == new_line {
+        lcs_at(&lcs, width, i + 1, j + 1) + 1
+      } else {
+        lcs_at(&lcs, width, i + 1, j).max(lcs_at(&lcs, width, i, j + 1))
+      };
+      if let Some(cell) = lcs.get_mut(i * width + j) {
+        *cell = value;
+      }
+    }
+  }
+
+  let (mut i, mut j) = (0, 0);
+  while let (Some(old_line), Some(new_line)) = (old_lines.get(i), new_lines.get(j)) {
+    if old_line == new_line {
+      out.push(format!("  {old_line}"));
+      i += 1;
+      j += 1;
+    } else if lcs_at(&lcs, width, i + 1, j) >= lcs_at(&lcs, width, i, j + 1) {
+      out.push(format!("- {old_line}"));
+      i += 1;
+    } else {
+      out.push(format!("+ {new_line}"));
+      j += 1;
+    }
+  }
+  out.extend(old_lines.iter().skip(i).map(|l| format!("- {l}")));
+  out.extend(new_lines.iter().skip(j).map(|l| format!("+ {l}")));
+  out.join("\n")
+}
+
+fn lcs_at(lcs: &[usize], width: usize, i: usize, j: usize) -> usize {
+  lcs.get(i * width + j).copied().unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::utils::diff::line_diff;
+
+  #[test]
+  fn test_line_diff() {
+    assert_eq!("  a\n  b", line_diff("a\nb", "a\nb"));
+    assert_eq!("  a\n- b\n+ c\n  d", line_diff("a\nb\nd", "a\nc\nd"));
+    assert_eq!("+ first", line_diff("", "first"));
+    assert_eq!("- gone", line_diff("gone", ""));
+    assert_eq!("  a\n+ b\n  c", line_diff("a\nc", "a\nb\nc"));
+  }
+}
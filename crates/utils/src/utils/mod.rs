@@ -1,4 +1,6 @@
+pub mod diff;
 pub mod markdown;
 pub mod mention;
+pub mod search;
 pub mod slurs;
 pub mod validation;
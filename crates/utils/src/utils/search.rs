@@ -0,0 +1,102 @@
+/// A search query split into free text and the operators which are supported on top of it.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct SearchQuery {
+  /// The remaining search text. Quoted phrases and `-excluded` words are kept as they are, so
+  /// that they can be handled by postgres `websearch_to_tsquery`.
+  pub terms: String,
+  /// Community given with `community:name` or `community:name@example.com`.
+  pub community: Option<String>,
+  /// Content creator given with `author:name` or `author:name@example.com`.
+  pub author: Option<String>,
+}
+
+const COMMUNITY_OPERATOR: &str = "community:";
+const AUTHOR_OPERATOR: &str = "author:";
+
+/// Parses a search query like `"exact phrase" -word community:foo author:bar`.
+///
+/// If an operator is given multiple times, the last one wins. Operators without a value are
+/// treated as normal search terms.
+pub fn parse_search_query(q: &str) -> SearchQuery {
+  let mut query = SearchQuery::default();
+  let mut terms = Vec::new();
+  for token in tokenize(q) {
+    if let Some(community) = operator_value(&token, COMMUNITY_OPERATOR) {
+      query.community = Some(community.trim_start_matches('!').to_string());
+    } else if let Some(author) = operator_value(&token, AUTHOR_OPERATOR) {
+      query.author = Some(author.trim_start_matches('@').to_string());
+    } else {
+      terms.push(token);
+    }
+  }
+  query.terms = terms.join(" ");
+  query
+}
+
+/// Splits the query at whitespace, except inside of quotes.
+fn tokenize(q: &str) -> Vec<String> {
+  let mut tokens = Vec::new();
+  let mut current = String::new();
+  let mut in_quotes = false;
+  for c in q.chars() {
+    if c == '"' {
+      in_quotes = !in_quotes;
+      current.push(c);
+    } else if c.is_whitespace() && !in_quotes {
+      if !current.is_empty() {
+        tokens.push(std::mem::take(&mut current));
+      }
+    } else {
+      current.push(c);
+    }
+  }
+  if !current.is_empty() {
+    tokens.push(current);
+  }
+  tokens
+}
+
+fn operator_value<'a>(token: &'a str, operator: &str) -> Option<&'a str> {
+  token
+    .get(..operator.len())
+    .filter(|prefix| prefix.eq_ignore_ascii_case(operator))
+    .and_then(|_| token.get(operator.len()..))
+    .filter(|value| !value.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+  #![allow(clippy::unwrap_used)]
+  #![allow(clippy::indexing_slicing)]
+
+  use crate::utils::search::{parse_search_query, SearchQuery};
+
+  #[test]
+  fn test_parse_search_query() {
+    let query = parse_search_query(r#""exact phrase" -word community:foo author:bar"#);
+    assert_eq!(
+      SearchQuery {
+        terms: r#""exact phrase" -word"#.to_string(),
+        community: Some("foo".to_string()),
+        author: Some("bar".to_string()),
+      },
+      query
+    );
+  }
+
+  #[test]
+  fn test_parse_search_query_remote_actors() {
+    let query = parse_search_query("rust Community:!rust@lemmy.ml author:@alice@example.com");
+    assert_eq!("rust", query.terms);
+    assert_eq!(Some("rust@lemmy.ml".to_string()), query.community);
+    assert_eq!(Some("alice@example.com".to_string()), query.author);
+  }
+
+  #[test]
+  fn test_parse_search_query_plain() {
+    let query = parse_search_query("  lemmy   \"community: is\"  author: ");
+    assert_eq!(r#"lemmy "community: is" author:"#, query.terms);
+    assert_eq!(None, query.community);
+    assert_eq!(None, query.author);
+  }
+}
@@ -2,12 +2,17 @@ use crate::error::{LemmyErrorExt, LemmyErrorType, LemmyResult};
 use itertools::Itertools;
 use once_cell::sync::Lazy;
 use regex::{Regex, RegexBuilder};
-use url::Url;
+use std::net::{IpAddr, Ipv4Addr};
+use url::{Host, Url};
 
 static VALID_ACTOR_NAME_REGEX: Lazy<Regex> =
   Lazy::new(|| Regex::new(r"^[a-zA-Z0-9_]{3,}$").expect("compile regex"));
 static VALID_POST_TITLE_REGEX: Lazy<Regex> =
   Lazy::new(|| Regex::new(r".*\S{3,200}.*").expect("compile regex"));
+static VALID_WIKI_PAGE_SLUG_REGEX: Lazy<Regex> =
+  Lazy::new(|| Regex::new(r"^[a-z0-9_-]{1,100}$").expect("compile regex"));
+static VALID_CUSTOM_EMOJI_SHORTCODE_REGEX: Lazy<Regex> =
+  Lazy::new(|| Regex::new(r"^[a-zA-Z0-9_-]{1,128}$").expect("compile regex"));
 static VALID_MATRIX_ID_REGEX: Lazy<Regex> = Lazy::new(|| {
   Regex::new(r"^@[A-Za-z0-9._=-]+:[A-Za-z0-9.-]+\.[A-Za-z]{2,}$").expect("compile regex")
 });
@@ -23,6 +28,12 @@ const BIO_MAX_LENGTH: usize = 300;
 const SITE_NAME_MAX_LENGTH: usize = 20;
 const SITE_NAME_MIN_LENGTH: usize = 1;
 const SITE_DESCRIPTION_MAX_LENGTH: usize = 150;
+const KEYWORD_FILTER_MAX_LENGTH: usize = 255;
+const POST_TAG_MAX_LENGTH: usize = 64;
+const POLL_OPTION_MAX_LENGTH: usize = 200;
+const POLL_MAX_OPTIONS: usize = 20;
+const ENCRYPTION_PUBLIC_KEY_MAX_LENGTH: usize = 8192;
+const ENCRYPTED_MESSAGE_MAX_LENGTH: usize = 20000;
 //Invisible unicode characters, taken from https://invisible-characters.com/
 const FORBIDDEN_DISPLAY_CHARS: [char; 53] = [
   '\u{0009}',
@@ -143,6 +154,140 @@ pub fn is_valid_bio_field(bio: &str) -> LemmyResult<()> {
   max_length_check(bio, BIO_MAX_LENGTH, LemmyErrorType::BioLengthOverflow)
 }
 
+/// Checks that a keyword filter isn't blank and fits into the DB column.
+pub fn is_valid_keyword_filter(keyword: &str) -> LemmyResult<()> {
+  if keyword.trim().is_empty() || has_newline(keyword) {
+    Err(LemmyErrorType::InvalidKeywordFilter)?
+  }
+  max_length_check(
+    keyword,
+    KEYWORD_FILTER_MAX_LENGTH,
+    LemmyErrorType::InvalidKeywordFilter,
+  )
+}
+
+/// Keyword filters are matched by the database against every post in a listing. Back-references
+/// can make that take exponential time, so they aren't allowed in regex filters.
+pub fn is_valid_keyword_filter_regex(regex: &str) -> LemmyResult<()> {
+  let mut chars = regex.chars();
+  while let Some(c) = chars.next() {
+    if c == '\\' && chars.next().is_some_and(|n| matches!(n, '1'..='9')) {
+      Err(LemmyErrorType::InvalidRegex)?
+    }
+  }
+  Ok(())
+}
+
+pub fn is_valid_post_tag_name(name: &str) -> LemmyResult<()> {
+  if name.trim().is_empty() || has_newline(name) {
+    Err(LemmyErrorType::InvalidPostTag)?
+  }
+  max_length_check(name, POST_TAG_MAX_LENGTH, LemmyErrorType::InvalidPostTag)
+}
+
+/// Wiki page slugs are used in urls, so they may only contain lowercase letters, digits, dashes
+/// and underscores.
+pub fn is_valid_wiki_page_slug(slug: &str) -> LemmyResult<()> {
+  if VALID_WIKI_PAGE_SLUG_REGEX.is_match(slug) {
+    Ok(())
+  } else {
+    Err(LemmyErrorType::InvalidWikiPageSlug)?
+  }
+}
+
+pub fn is_valid_custom_emoji_shortcode(shortcode: &str) -> LemmyResult<()> {
+  if VALID_CUSTOM_EMOJI_SHORTCODE_REGEX.is_match(shortcode) {
+    Ok(())
+  } else {
+    Err(LemmyErrorType::InvalidCustomEmojiShortcode)?
+  }
+}
+
+/// Reactions are either a single unicode emoji (which may consist of several code points), or the
+/// shortcode of a custom emoji surrounded by colons.
+pub fn is_valid_emoji_reaction(emoji: &str) -> LemmyResult<()> {
+  let valid = match emoji.strip_prefix(':').and_then(|e| e.strip_suffix(':')) {
+    Some(shortcode) => is_valid_custom_emoji_shortcode(shortcode).is_ok(),
+    None => {
+      !emoji.is_ascii() && emoji.chars().count() <= 10 && !emoji.chars().any(char::is_whitespace)
+    }
+  };
+  if valid {
+    Ok(())
+  } else {
+    Err(LemmyErrorType::InvalidEmojiReaction)?
+  }
+}
+
+/// Webhook secrets need to be long enough that signatures can't be guessed.
+pub fn is_valid_webhook_secret(secret: &str) -> LemmyResult<()> {
+  let len = secret.chars().count();
+  if (16..=256).contains(&len) && !secret.chars().any(char::is_whitespace) {
+    Ok(())
+  } else {
+    Err(LemmyErrorType::InvalidWebhookSecret)?
+  }
+}
+
+pub fn is_valid_encryption_public_key(key: &str) -> LemmyResult<()> {
+  if key.trim().is_empty() {
+    Err(LemmyErrorType::InvalidEncryptionPublicKey)?
+  }
+  max_length_check(
+    key,
+    ENCRYPTION_PUBLIC_KEY_MAX_LENGTH,
+    LemmyErrorType::InvalidEncryptionPublicKey,
+  )
+}
+
+/// The server can't look into encrypted messages, so only their size is checked. Ciphertext is
+/// usually longer than the plaintext, which is why the limit is higher than for other messages.
+pub fn is_valid_encrypted_message(content: &str, metadata: &Option<String>) -> LemmyResult<()> {
+  if content.is_empty() {
+    Err(LemmyErrorType::InvalidEncryptedMessage)?
+  }
+  max_length_check(
+    content,
+    ENCRYPTED_MESSAGE_MAX_LENGTH,
+    LemmyErrorType::InvalidEncryptedMessage,
+  )?;
+  if let Some(metadata) = metadata {
+    max_length_check(
+      metadata,
+      BODY_MAX_LENGTH,
+      LemmyErrorType::InvalidEncryptedMessage,
+    )?;
+  }
+  Ok(())
+}
+
+/// Checks a single poll option, it can't be empty or span multiple lines.
+pub fn is_valid_poll_option(option: &str) -> LemmyResult<()> {
+  if option.trim().is_empty() || has_newline(option) {
+    Err(LemmyErrorType::InvalidPollOption)?
+  }
+  max_length_check(
+    option,
+    POLL_OPTION_MAX_LENGTH,
+    LemmyErrorType::InvalidPollOption,
+  )
+}
+
+/// Checks the options of a new poll. There need to be at least two options, and each of them
+/// has to be unique.
+pub fn is_valid_poll_options(options: &[String]) -> LemmyResult<()> {
+  if options.len() < 2 || options.len() > POLL_MAX_OPTIONS {
+    Err(LemmyErrorType::InvalidPoll)?
+  }
+  for option in options {
+    is_valid_poll_option(option)?;
+  }
+  if options.iter().unique().count() != options.len() {
+    Err(LemmyErrorType::InvalidPollOption)?
+  }
+  Ok(())
+}
+
 /// Checks the site name length, the limit as defined in the DB.
 pub fn site_name_length_check(name: &str) -> LemmyResult<()> {
   min_length_check(name, SITE_NAME_MIN_LENGTH, LemmyErrorType::SiteNameRequired)?;
@@ -257,6 +402,64 @@ pub fn check_url_scheme(url: &Option<Url>) -> LemmyResult<()> {
   }
 }
 
+/// Checks that a url which the server sends requests to, like that of a webhook, doesn't point
+/// to the server itself or its private network. Domains also need to be checked again after they
+/// are resolved, see [`is_public_ip`].
+pub fn check_url_host_public(url: &Url) -> LemmyResult<()> {
+  let public = match url.host() {
+    Some(Host::Domain(domain)) => {
+      let domain = domain.trim_end_matches('.').to_lowercase();
+      !["localhost", "local", "internal"]
+        .iter()
+        .any(|d| domain == *d || domain.ends_with(&format!(".{d}")))
+    }
+    Some(Host::Ipv4(ip)) => is_public_ip(IpAddr::V4(ip)),
+    Some(Host::Ipv6(ip)) => is_public_ip(IpAddr::V6(ip)),
+    None => false,
+  };
+  if !public {
+    Err(LemmyErrorType::UrlHostNotAllowed)?
+  }
+  Ok(())
+}
+
+/// Loopback, private, link-local and other special purpose addresses aren't public.
+pub fn is_public_ip(ip: IpAddr) -> bool {
+  match ip {
+    IpAddr::V4(ip) => {
+      let [a, b, ..] = ip.octets();
+      !(ip.is_private()
+        || ip.is_loopback()
+        || ip.is_link_local()
+        || ip.is_unspecified()
+        || ip.is_documentation()
+        || ip.is_multicast()
+        // "This network", shared address space, benchmarking and reserved ranges
+        || a == 0
+        || (a == 100 && (b & 0xc0) == 64)
+        || (a == 198 && (b & 0xfe) == 18)
+        || a >= 240)
+    }
+    IpAddr::V6(ip) => {
+      let segments = ip.segments();
+      // IPv4-mapped and NAT64 addresses lead to an IPv4 address
+      if let Some(ip) = ip.to_ipv4_mapped() {
+        return is_public_ip(IpAddr::V4(ip));
+      }
+      if segments[..6] == [0x64, 0xff9b, 0, 0, 0, 0] {
+        let [.., a, b, c, d] = ip.octets();
+        return is_public_ip(IpAddr::V4(Ipv4Addr::new(a, b, c, d)));
+      }
+      !(ip.is_loopback()
+        || ip.is_unspecified()
+        || ip.is_multicast()
+        // Unique local and link-local addresses
+        || (segments[0] & 0xfe00) == 0xfc00
+        || (segments[0] & 0xffc0) == 0xfe80)
+    }
+  }
+}
+
 #[cfg(test)]
 mod tests {
   #![allow(clippy::unwrap_used)]
@@ -267,13 +470,25 @@ mod tests {
     utils::validation::{
       build_and_check_regex,
       check_site_visibility_valid,
+      check_url_host_public,
       check_url_scheme,
       clean_url_params,
       is_valid_actor_name,
       is_valid_bio_field,
+      is_valid_custom_emoji_shortcode,
       is_valid_display_name,
+      is_valid_emoji_reaction,
+      is_valid_encrypted_message,
+      is_valid_encryption_public_key,
+      is_valid_keyword_filter,
+      is_valid_keyword_filter_regex,
       is_valid_matrix_id,
+      is_valid_poll_option,
+      is_valid_poll_options,
+      is_valid_post_tag_name,
       is_valid_post_title,
+      is_valid_webhook_secret,
+      is_valid_wiki_page_slug,
       site_description_length_check,
       site_name_length_check,
       BIO_MAX_LENGTH,
@@ -333,6 +548,49 @@ mod tests {
     assert!(is_valid_post_title("\n \n \n \n    		").is_err()); // tabs/spaces/newlines
   }
 
+  #[test]
+  fn test_valid_wiki_page_slug() {
+    assert!(is_valid_wiki_page_slug("rules").is_ok());
+    assert!(is_valid_wiki_page_slug("getting-started_2").is_ok());
+    assert!(is_valid_wiki_page_slug("").is_err());
+    assert!(is_valid_wiki_page_slug("Rules").is_err());
+    assert!(is_valid_wiki_page_slug("a/b").is_err());
+    assert!(is_valid_wiki_page_slug(&"a".repeat(101)).is_err());
+  }
+
+  #[test]
+  fn test_valid_custom_emoji_shortcode() {
+    assert!(is_valid_custom_emoji_shortcode("lemmy").is_ok());
+    assert!(is_valid_custom_emoji_shortcode("blob_cat-2").is_ok());
+    assert!(is_valid_custom_emoji_shortcode("BlobCat").is_ok());
+    assert!(is_valid_custom_emoji_shortcode("").is_err());
+    assert!(is_valid_custom_emoji_shortcode("blob:cat").is_err());
+    assert!(is_valid_custom_emoji_shortcode("blob cat").is_err());
+    assert!(is_valid_custom_emoji_shortcode(&"a".repeat(129)).is_err());
+  }
+
+  #[test]
+  fn test_valid_emoji_reaction() {
+    assert!(is_valid_emoji_reaction("👍").is_ok());
+    assert!(is_valid_emoji_reaction("👍🏽").is_ok());
+    assert!(is_valid_emoji_reaction("👨‍👩‍👧").is_ok());
+    assert!(is_valid_emoji_reaction(":blob_cat:").is_ok());
+    assert!(is_valid_emoji_reaction("").is_err());
+    assert!(is_valid_emoji_reaction("+1").is_err());
+    assert!(is_valid_emoji_reaction("::").is_err());
+    assert!(is_valid_emoji_reaction(":blob cat:").is_err());
+    assert!(is_valid_emoji_reaction("👍 👍").is_err());
+    assert!(is_valid_emoji_reaction(&"👍".repeat(11)).is_err());
+  }
+
+  #[test]
+  fn test_valid_webhook_secret() {
+    assert!(is_valid_webhook_secret("0123456789abcdef").is_ok());
+    assert!(is_valid_webhook_secret("too_short").is_err());
+    assert!(is_valid_webhook_secret("0123456789 abcdef").is_err());
+    assert!(is_valid_webhook_secret(&"a".repeat(257)).is_err());
+  }
+
   #[test]
   fn test_valid_matrix_id() {
     assert!(is_valid_matrix_id("@dess:matrix.org").is_ok());
@@ -341,6 +599,49 @@ mod tests {
     assert!(is_valid_matrix_id("@dess:matrix.org t").is_err());
   }
 
+  #[test]
+  fn test_valid_encryption() {
+    assert!(is_valid_encryption_public_key("MCowBQYDK2VuAyEA9u1n3sL1dSjzCDl3Eo7nRJy0Plh").is_ok());
+    assert!(is_valid_encryption_public_key(" \n ").is_err());
+    assert!(is_valid_encrypted_message("bm90IHJlYWxseSBlbmNyeXB0ZWQ=", &None).is_ok());
+    assert!(is_valid_encrypted_message("", &Some("nonce".to_string())).is_err());
+    assert!(is_valid_encrypted_message(&"A".repeat(20001), &None).is_err());
+  }
+
+  #[test]
+  fn test_valid_keyword_filter() {
+    assert!(is_valid_keyword_filter("spoiler").is_ok());
+    assert!(is_valid_keyword_filter("^(spoiler|leak)s?$").is_ok());
+    assert!(is_valid_keyword_filter("   ").is_err());
+    assert!(is_valid_keyword_filter("two\nlines").is_err());
+    assert!(is_valid_keyword_filter(&"a".repeat(256)).is_err());
+
+    assert!(is_valid_keyword_filter_regex("^(spoiler|leak)s?$").is_ok());
+    assert!(is_valid_keyword_filter_regex(r"\\1 and \d+").is_ok());
+    assert!(is_valid_keyword_filter_regex(r"(a+)\1").is_err());
+    assert!(is_valid_keyword_filter_regex(r"^(x|y)*\2$").is_err());
+  }
+
+  #[test]
+  fn test_valid_post_tag_name() {
+    assert!(is_valid_post_tag_name("Solved").is_ok());
+    assert!(is_valid_post_tag_name("").is_err());
+    assert!(is_valid_post_tag_name("two\nlines").is_err());
+    assert!(is_valid_post_tag_name(&"a".repeat(65)).is_err());
+  }
+
+  #[test]
+  fn test_valid_poll_options() {
+    let options = |o: &[&str]| o.iter().map(ToString::to_string).collect::<Vec<_>>();
+    assert!(is_valid_poll_options(&options(&["Yes", "No"])).is_ok());
+    assert!(is_valid_poll_options(&options(&["Yes"])).is_err());
+    assert!(is_valid_poll_options(&options(&["Yes", "Yes"])).is_err());
+    assert!(is_valid_poll_options(&options(&["Yes", " "])).is_err());
+    assert!(is_valid_poll_options(&["a".repeat(201), "b".to_string()]).is_err());
+    assert!(is_valid_poll_option("Maybe").is_ok());
+    assert!(is_valid_poll_option("two\nlines").is_err());
+  }
+
   #[test]
   fn test_valid_site_name() {
     let valid_names = [
@@ -475,4 +776,29 @@ mod tests {
     assert!(check_url_scheme(&Some(Url::parse("ftp://example.com").unwrap())).is_err());
     assert!(check_url_scheme(&Some(Url::parse("javascript:void").unwrap())).is_err());
   }
+  #[test]
+  fn test_check_url_host_public() {
+    let check = |url: &str| check_url_host_public(&Url::parse(url).unwrap()).is_ok();
+    assert!(check("https://example.com/hook"));
+    assert!(check("https://93.184.216.34/hook"));
+    assert!(check("https://[2606:2800:220:1:248:1893:25c8:1946]/hook"));
+
+    assert!(!check("http://localhost:8536/"));
+    assert!(!check("http://LOCALHOST./"));
+    assert!(!check("http://api.localhost/"));
+    assert!(!check("http://printer.local/"));
+    assert!(!check("http://127.0.0.1/"));
+    assert!(!check("http://0x7f.1/"));
+    assert!(!check("http://10.1.2.3/"));
+    assert!(!check("http://172.16.0.1/"));
+    assert!(!check("http://192.168.1.1/"));
+    assert!(!check("http://169.254.169.254/latest/meta-data"));
+    assert!(!check("http://100.64.0.1/"));
+    assert!(!check("http://0.0.0.0/"));
+    assert!(!check("http://[::1]/"));
+    assert!(!check("http://[::ffff:127.0.0.1]/"));
+    assert!(!check("http://[64:ff9b::a00:1]/"));
+    assert!(!check("http://[fd00::1]/"));
+    assert!(!check("http://[fe80::1]/"));
+  }
 }
@@ -0,0 +1,270 @@
+use crate::error::{LemmyErrorType, LemmyResult};
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use chrono::{Duration, Utc};
+use openssl::{
+  bn::BigNumContext,
+  derive::Deriver,
+  ec::{EcGroup, EcKey, EcKeyRef, EcPoint, PointConversionForm},
+  ecdsa::EcdsaSig,
+  hash::MessageDigest,
+  nid::Nid,
+  pkey::{PKey, Private},
+  rand::rand_bytes,
+  sha::sha256,
+  sign::Signer,
+  symm::{encrypt_aead, Cipher},
+};
+use serde_json::json;
+use url::Url;
+
+/// Size of the single record which the payload is encrypted into. Push services accept at most
+/// 4096 bytes.
+const RECORD_SIZE: u32 = 4096;
+/// How long the VAPID authorization is valid, the maximum is 24 hours.
+const VAPID_VALIDITY_HOURS: i64 = 12;
+
+/// The keypair which identifies this server to the push services (VAPID, RFC 8292).
+pub struct VapidKeypair {
+  /// The EC private key as PEM
+  pub private_key: String,
+  /// The uncompressed public key as unpadded base64url, which clients pass to
+  /// `PushManager.subscribe()` as `applicationServerKey`
+  pub public_key: String,
+}
+
+fn p256_group() -> LemmyResult<EcGroup> {
+  Ok(EcGroup::from_curve_name(Nid::X9_62_PRIME256V1)?)
+}
+
+fn public_key_bytes<T>(key: &EcKeyRef<T>) -> LemmyResult<Vec<u8>> {
+  let group = p256_group()?;
+  let mut ctx = BigNumContext::new()?;
+  Ok(
+    key
+      .public_key()
+      .to_bytes(&group, PointConversionForm::UNCOMPRESSED, &mut ctx)?,
+  )
+}
+
+fn decode_base64(value: &str) -> LemmyResult<Vec<u8>> {
+  // Browsers don't add padding, but some clients do
+  URL_SAFE_NO_PAD
+    .decode(value.trim_end_matches('='))
+    .map_err(|_| LemmyErrorType::InvalidPushSubscription.into())
+}
+
+pub fn generate_vapid_keypair() -> LemmyResult<VapidKeypair> {
+  let key = EcKey::generate(&p256_group()?)?;
+  Ok(VapidKeypair {
+    private_key: String::from_utf8(key.private_key_to_pem()?)?,
+    public_key: URL_SAFE_NO_PAD.encode(public_key_bytes(&key)?),
+  })
+}
+
+/// Decodes the keys of a push subscription, which browsers return as unpadded base64url.
+fn decode_subscription_keys(p256dh: &str, auth: &str) -> LemmyResult<(Vec<u8>, Vec<u8>)> {
+  let ua_public = decode_base64(p256dh)?;
+  let auth_secret = decode_base64(auth)?;
+  // An uncompressed P-256 point, and 16 bytes of authentication secret
+  if ua_public.len() != 65 || ua_public.first() != Some(&4) || auth_secret.len() != 16 {
+    Err(LemmyErrorType::InvalidPushSubscription)?
+  }
+  let group = p256_group()?;
+  let mut ctx = BigNumContext::new()?;
+  EcPoint::from_bytes(&group, &ua_public, &mut ctx)
+    .map_err(|_| LemmyErrorType::InvalidPushSubscription)?;
+  Ok((ua_public, auth_secret))
+}
+
+/// Checks that the keys of a push subscription can be used to encrypt messages.
+pub fn check_push_subscription_keys(p256dh: &str, auth: &str) -> LemmyResult<()> {
+  decode_subscription_keys(p256dh, auth)?;
+  Ok(())
+}
+
+/// Encrypts a push message for a subscription with the `aes128gcm` content encoding, as described
+/// in RFC 8291. The result is the request body for the push service.
+pub fn encrypt_push_message(p256dh: &str, auth: &str, message: &[u8]) -> LemmyResult<Vec<u8>> {
+  let (ua_public, auth_secret) = decode_subscription_keys(p256dh, auth)?;
+  let as_key = EcKey::generate(&p256_group()?)?;
+  let mut salt = [0; 16];
+  rand_bytes(&mut salt)?;
+  encrypt_with(&as_key, &salt, &ua_public, &auth_secret, message)
+}
+
+fn hmac_sha256(key: &[u8], data: &[&[u8]]) -> LemmyResult<Vec<u8>> {
+  let key = PKey::hmac(key)?;
+  let mut signer = Signer::new(MessageDigest::sha256(), &key)?;
+  for d in data {
+    signer.update(d)?;
+  }
+  Ok(signer.sign_to_vec()?)
+}
+
+fn encrypt_with(
+  as_key: &EcKeyRef<Private>,
+  salt: &[u8],
+  ua_public: &[u8],
+  auth_secret: &[u8],
+  message: &[u8],
+) -> LemmyResult<Vec<u8>> {
+  let group = p256_group()?;
+  let mut ctx = BigNumContext::new()?;
+  let as_public = public_key_bytes(as_key)?;
+
+  let ua_point = EcPoint::from_bytes(&group, ua_public, &mut ctx)?;
+  let ua_key = PKey::from_ec_key(EcKey::from_public_key(&group, &ua_point)?)?;
+  let as_pkey = PKey::from_ec_key(as_key.to_owned())?;
+  let mut deriver = Deriver::new(&as_pkey)?;
+  deriver.set_peer(&ua_key)?;
+  let ecdh_secret = deriver.derive_to_vec()?;
+
+  // HKDF with SHA-256, every output is at most one hash long so a single expand step is enough
+  let prk_key = hmac_sha256(auth_secret, &[&ecdh_secret])?;
+  let ikm = hmac_sha256(&prk_key, &[b"WebPush: info\0", ua_public, &as_public, &[1]])?;
+  let prk = hmac_sha256(salt, &[&ikm])?;
+  let cek = hmac_sha256(&prk, &[b"Content-Encoding: aes128gcm\0", &[1]])?;
+  let nonce = hmac_sha256(&prk, &[b"Content-Encoding: nonce\0", &[1]])?;
+
+  // The whole message is a single record, which ends with the padding delimiter
+  let mut plaintext = message.to_vec();
+  plaintext.push(2);
+  let mut tag = [0; 16];
+  let ciphertext = encrypt_aead(
+    Cipher::aes_128_gcm(),
+    cek.get(..16).unwrap_or_default(),
+    nonce.get(..12),
+    &[],
+    &plaintext,
+    &mut tag,
+  )?;
+
+  let mut body = salt.to_vec();
+  body.extend_from_slice(&RECORD_SIZE.to_be_bytes());
+  body.push(as_public.len().try_into()?);
+  body.extend_from_slice(&as_public);
+  body.extend_from_slice(&ciphertext);
+  body.extend_from_slice(&tag);
+  Ok(body)
+}
+
+/// The value of the `Authorization` header for a push service, which proves that the message
+/// comes from the server which the subscription was created for (RFC 8292).
+pub fn vapid_authorization(
+  endpoint: &Url,
+  keypair: &VapidKeypair,
+  subject: &str,
+) -> LemmyResult<String> {
+  let header = json!({ "typ": "JWT", "alg": "ES256" });
+  let claims = json!({
+    "aud": endpoint.origin().ascii_serialization(),
+    "exp": (Utc::now() + Duration::hours(VAPID_VALIDITY_HOURS)).timestamp(),
+    "sub": subject,
+  });
+  let signing_input = format!(
+    "{}.{}",
+    URL_SAFE_NO_PAD.encode(header.to_string()),
+    URL_SAFE_NO_PAD.encode(claims.to_string())
+  );
+
+  // JWS uses the raw r and s values instead of the DER encoding
+  let key = EcKey::private_key_from_pem(keypair.private_key.as_bytes())?;
+  let signature = EcdsaSig::sign(&sha256(signing_input.as_bytes()), &key)?;
+  let mut raw_signature = signature.r().to_vec_padded(32)?;
+  raw_signature.extend(signature.s().to_vec_padded(32)?);
+
+  Ok(format!(
+    "vapid t={signing_input}.{}, k={}",
+    URL_SAFE_NO_PAD.encode(raw_signature),
+    keypair.public_key
+  ))
+}
+
+#[cfg(test)]
+mod tests {
+  #![allow(clippy::unwrap_used)]
+  #![allow(clippy::indexing_slicing)]
+
+  use super::*;
+  use openssl::bn::BigNum;
+
+  #[test]
+  fn test_encrypt_push_message() {
+    // The example from RFC 8291, section 5
+    let group = p256_group().unwrap();
+    let ctx = BigNumContext::new().unwrap();
+    let private_number =
+      BigNum::from_slice(&decode_base64("yfWPiYE-n46HLnH0KqZOF1fJJU3MYrct3AELtAQ-oRw").unwrap())
+        .unwrap();
+    let mut public_point = EcPoint::new(&group).unwrap();
+    public_point
+      .mul_generator(&group, &private_number, &ctx)
+      .unwrap();
+    let as_key = EcKey::from_private_components(&group, &private_number, &public_point).unwrap();
+
+    let (ua_public, auth_secret) = decode_subscription_keys(
+      "BCVxsr7N_eNgVRqvHtD0zTZsEc6-VV-JvLexhqUzORcxaOzi6-AYWXvTBHm4bjyPjs7Vd8pZGH6SRpkNtoIAiw4",
+      "BTBZMqHH6r4Tts7J_aSIgg",
+    )
+    .unwrap();
+    let salt = decode_base64("DGv6ra1nlYgDCS1FRnbzlw").unwrap();
+    let body = encrypt_with(
+      &as_key,
+      &salt,
+      &ua_public,
+      &auth_secret,
+      b"When I grow up, I want to be a watermelon",
+    )
+    .unwrap();
+    assert_eq!(
+      "DGv6ra1nlYgDCS1FRnbzlwAAEABBBP4z9KsN6nGRTbVYI_c7VJSPQTBtkgcy27mlmlMoZIIgDll6e3vCYLocInmYWAmS6TlzAC8wEqKK6PBru3jl7A_yl95bQpu6cVPTpK4Mqgkf1CXztLVBSt2Ks3oZwbuwXPXLWyouBWLVWGNWQexSgSxsj_Qulcy4a-fN",
+      URL_SAFE_NO_PAD.encode(body)
+    );
+  }
+
+  #[test]
+  fn test_check_push_subscription_keys() {
+    assert!(check_push_subscription_keys(
+      "BCVxsr7N_eNgVRqvHtD0zTZsEc6-VV-JvLexhqUzORcxaOzi6-AYWXvTBHm4bjyPjs7Vd8pZGH6SRpkNtoIAiw4",
+      "BTBZMqHH6r4Tts7J_aSIgg==",
+    )
+    .is_ok());
+    assert!(check_push_subscription_keys("BCVxsr7N", "BTBZMqHH6r4Tts7J_aSIgg").is_err());
+    assert!(check_push_subscription_keys(
+      "BCVxsr7N_eNgVRqvHtD0zTZsEc6-VV-JvLexhqUzORcxaOzi6-AYWXvTBHm4bjyPjs7Vd8pZGH6SRpkNtoIAiw4",
+      "not base64!",
+    )
+    .is_err());
+  }
+
+  #[test]
+  fn test_vapid_authorization() {
+    let keypair = generate_vapid_keypair().unwrap();
+    let endpoint = Url::parse("https://push.example.com/send/abc").unwrap();
+    let authorization =
+      vapid_authorization(&endpoint, &keypair, "https://lemmy.example.com").unwrap();
+    let (token, public_key) = authorization
+      .strip_prefix("vapid t=")
+      .unwrap()
+      .split_once(", k=")
+      .unwrap();
+    assert_eq!(keypair.public_key, public_key);
+
+    let parts: Vec<&str> = token.split('.').collect();
+    assert_eq!(3, parts.len());
+    let claims: serde_json::Value =
+      serde_json::from_slice(&URL_SAFE_NO_PAD.decode(parts[1]).unwrap()).unwrap();
+    assert_eq!("https://push.example.com", claims["aud"]);
+
+    // The signature verifies with the public key
+    let raw_signature = URL_SAFE_NO_PAD.decode(parts[2]).unwrap();
+    let signature = EcdsaSig::from_private_components(
+      BigNum::from_slice(&raw_signature[..32]).unwrap(),
+      BigNum::from_slice(&raw_signature[32..]).unwrap(),
+    )
+    .unwrap();
+    let key = EcKey::private_key_from_pem(keypair.private_key.as_bytes()).unwrap();
+    let digest = sha256(format!("{}.{}", parts[0], parts[1]).as_bytes());
+    assert!(signature.verify(&digest, &key).unwrap());
+  }
+}
@@ -0,0 +1,25 @@
+-- Generated tsvector columns for full-text search. The 'simple' configuration is used because
+-- federated content can be written in any language, so stemming or stop words would do more harm
+-- than good. Titles and names are weighted higher than bodies for ranking.
+ALTER TABLE post
+    ADD COLUMN search_vector tsvector GENERATED ALWAYS AS (setweight(to_tsvector('simple', coalesce(name, '')), 'A') || setweight(to_tsvector('simple', coalesce(body, '')), 'B')) STORED;
+
+ALTER TABLE comment
+    ADD COLUMN search_vector tsvector GENERATED ALWAYS AS (to_tsvector('simple', content)) STORED;
+
+ALTER TABLE community
+    ADD COLUMN search_vector tsvector GENERATED ALWAYS AS (setweight(to_tsvector('simple', coalesce(name, '')), 'A') || setweight(to_tsvector('simple', coalesce(title, '')), 'A') || setweight(to_tsvector('simple', coalesce(description, '')), 'B')) STORED;
+
+ALTER TABLE person
+    ADD COLUMN search_vector tsvector GENERATED ALWAYS AS (setweight(to_tsvector('simple', coalesce(name, '')), 'A') || setweight(to_tsvector('simple', coalesce(display_name, '')), 'A') || setweight(to_tsvector('simple', coalesce(bio, '')), 'B')) STORED;
+
+CREATE INDEX idx_post_search_vector ON post USING gin (search_vector);
+
+CREATE INDEX idx_comment_search_vector ON comment USING gin (search_vector);
+
+CREATE INDEX idx_community_search_vector ON community USING gin (search_vector);
+
+CREATE INDEX idx_person_search_vector ON person USING gin (search_vector);
+
+ALTER TYPE sort_type_enum
+    ADD VALUE 'Relevance';
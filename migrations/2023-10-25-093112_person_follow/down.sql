@@ -0,0 +1,57 @@
+DROP TRIGGER person_aggregates_follow_count ON person_follower;
+
+DROP FUNCTION person_aggregates_follow_count;
+
+ALTER TABLE person_aggregates
+    DROP COLUMN follower_count,
+    DROP COLUMN following_count;
+
+ALTER TABLE local_user
+    ALTER default_listing_type DROP DEFAULT;
+
+ALTER TABLE local_site
+    ALTER default_post_listing_type DROP DEFAULT;
+
+UPDATE
+    local_user
+SET
+    default_listing_type = 'Local'
+WHERE
+    default_listing_type = 'FollowedPeople';
+
+UPDATE
+    local_site
+SET
+    default_post_listing_type = 'Local'
+WHERE
+    default_post_listing_type = 'FollowedPeople';
+
+-- rename the old enum
+ALTER TYPE listing_type_enum RENAME TO listing_type_enum__;
+
+-- create the new enum
+CREATE TYPE listing_type_enum AS ENUM (
+    'All',
+    'Local',
+    'Subscribed',
+    'ModeratorView'
+);
+
+-- alter all your enum columns
+ALTER TABLE local_user
+    ALTER COLUMN default_listing_type TYPE listing_type_enum
+    USING default_listing_type::text::listing_type_enum;
+
+ALTER TABLE local_site
+    ALTER COLUMN default_post_listing_type TYPE listing_type_enum
+    USING default_post_listing_type::text::listing_type_enum;
+
+-- Add back in the default
+ALTER TABLE local_user
+    ALTER default_listing_type SET DEFAULT 'Local';
+
+ALTER TABLE local_site
+    ALTER default_post_listing_type SET DEFAULT 'Local';
+
+-- drop the old enum
+DROP TYPE listing_type_enum__;
@@ -0,0 +1,63 @@
+ALTER TYPE listing_type_enum
+    ADD VALUE 'FollowedPeople';
+
+ALTER TABLE person_aggregates
+    ADD COLUMN follower_count bigint NOT NULL DEFAULT 0,
+    ADD COLUMN following_count bigint NOT NULL DEFAULT 0;
+
+-- Only accepted follows are counted. Counts are recalculated because a follow can change from
+-- pending to accepted.
+CREATE FUNCTION person_aggregates_follow_count ()
+    RETURNS TRIGGER
+    LANGUAGE plpgsql
+    AS $$
+BEGIN
+    UPDATE
+        person_aggregates pa
+    SET
+        follower_count = (
+            SELECT
+                count(*)
+            FROM
+                person_follower pf
+            WHERE
+                pf.person_id = pa.person_id
+                AND NOT pf.pending),
+        following_count = (
+            SELECT
+                count(*)
+            FROM
+                person_follower pf
+            WHERE
+                pf.follower_id = pa.person_id
+                AND NOT pf.pending)
+    WHERE
+        pa.person_id IN (OLD.person_id, OLD.follower_id, NEW.person_id, NEW.follower_id);
+    RETURN NULL;
+END
+$$;
+
+CREATE TRIGGER person_aggregates_follow_count
+    AFTER INSERT OR UPDATE OR DELETE ON person_follower
+    FOR EACH ROW
+    EXECUTE PROCEDURE person_aggregates_follow_count ();
+
+UPDATE
+    person_aggregates pa
+SET
+    follower_count = (
+        SELECT
+            count(*)
+        FROM
+            person_follower pf
+        WHERE
+            pf.person_id = pa.person_id
+            AND NOT pf.pending),
+    following_count = (
+        SELECT
+            count(*)
+        FROM
+            person_follower pf
+        WHERE
+            pf.follower_id = pa.person_id
+            AND NOT pf.pending);
@@ -0,0 +1,3 @@
+DROP TABLE multi_community_entry;
+
+DROP TABLE multi_community;
@@ -0,0 +1,26 @@
+CREATE TABLE multi_community (
+    id serial PRIMARY KEY,
+    creator_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    instance_id int REFERENCES instance ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    name varchar(255) NOT NULL,
+    title varchar(255) NOT NULL,
+    description text,
+    local boolean NOT NULL DEFAULT TRUE,
+    deleted boolean NOT NULL DEFAULT FALSE,
+    ap_id varchar(255) NOT NULL UNIQUE DEFAULT generate_unique_changeme (),
+    published timestamptz NOT NULL DEFAULT now(),
+    updated timestamptz,
+    last_refreshed_at timestamptz NOT NULL DEFAULT now(),
+    UNIQUE (instance_id, name)
+);
+
+CREATE INDEX idx_multi_community_creator ON multi_community (creator_id);
+
+CREATE TABLE multi_community_entry (
+    id serial PRIMARY KEY,
+    multi_community_id int REFERENCES multi_community ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    community_id int REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    UNIQUE (multi_community_id, community_id)
+);
+
+CREATE INDEX idx_multi_community_entry_community ON multi_community_entry (community_id);
@@ -0,0 +1,3 @@
+DROP FUNCTION keyword_filter_matches (text, text, boolean);
+
+DROP TABLE local_user_keyword_filter;
@@ -0,0 +1,28 @@
+CREATE TABLE local_user_keyword_filter (
+    id serial PRIMARY KEY,
+    local_user_id int REFERENCES local_user ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    keyword varchar(255) NOT NULL,
+    is_regex boolean NOT NULL DEFAULT FALSE,
+    filter_title boolean NOT NULL DEFAULT TRUE,
+    filter_body boolean NOT NULL DEFAULT TRUE,
+    filter_url boolean NOT NULL DEFAULT FALSE,
+    expires timestamptz,
+    published timestamptz NOT NULL DEFAULT now(),
+    UNIQUE (local_user_id, keyword)
+);
+
+-- Checks if the content matches a keyword filter. Plain keywords are matched case-insensitively
+-- anywhere in the content, regexes use the case-insensitive POSIX regex operator.
+CREATE FUNCTION keyword_filter_matches (content text, keyword text, is_regex boolean)
+    RETURNS boolean
+    AS $$
+    SELECT
+        coalesce(
+            CASE WHEN is_regex THEN
+                content ~* keyword
+            ELSE
+                strpos(lower(content), lower(keyword)) > 0
+            END, FALSE)
+$$
+LANGUAGE sql
+IMMUTABLE PARALLEL SAFE;
@@ -0,0 +1,5 @@
+DROP TABLE mod_community_post_tag;
+
+DROP TABLE post_tag;
+
+DROP TABLE community_post_tag;
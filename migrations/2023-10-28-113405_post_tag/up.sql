@@ -0,0 +1,30 @@
+-- Tags which the moderators of a community define for posts in it
+CREATE TABLE community_post_tag (
+    id serial PRIMARY KEY,
+    ap_id varchar(255) NOT NULL UNIQUE DEFAULT generate_unique_changeme (),
+    community_id int REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    display_name varchar(255) NOT NULL,
+    deleted boolean NOT NULL DEFAULT FALSE,
+    published timestamptz NOT NULL DEFAULT now(),
+    updated timestamptz
+);
+
+CREATE INDEX idx_community_post_tag_community ON community_post_tag (community_id);
+
+CREATE TABLE post_tag (
+    id serial PRIMARY KEY,
+    post_id int REFERENCES post ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    community_post_tag_id int REFERENCES community_post_tag ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    UNIQUE (post_id, community_post_tag_id)
+);
+
+CREATE INDEX idx_post_tag_tag ON post_tag (community_post_tag_id);
+
+CREATE TABLE mod_community_post_tag (
+    id serial PRIMARY KEY,
+    mod_person_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    community_post_tag_id int REFERENCES community_post_tag ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    display_name varchar(255) NOT NULL,
+    deleted boolean NOT NULL DEFAULT FALSE,
+    when_ timestamptz NOT NULL DEFAULT now()
+);
@@ -0,0 +1,2 @@
+ALTER TABLE post
+    DROP COLUMN scheduled_publish_time;
@@ -0,0 +1,7 @@
+DROP TABLE poll_vote;
+
+DROP FUNCTION poll_vote_count;
+
+DROP TABLE poll_option;
+
+DROP TABLE poll;
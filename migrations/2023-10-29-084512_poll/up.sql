@@ -0,0 +1,74 @@
+-- A post can have a single poll attached to it
+CREATE TABLE poll (
+    post_id int PRIMARY KEY REFERENCES post ON UPDATE CASCADE ON DELETE CASCADE,
+    multiple_choice boolean NOT NULL DEFAULT FALSE,
+    end_time timestamptz,
+    hide_results boolean NOT NULL DEFAULT FALSE,
+    voters_count int NOT NULL DEFAULT 0
+);
+
+CREATE TABLE poll_option (
+    id serial PRIMARY KEY,
+    post_id int REFERENCES poll ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    name varchar(255) NOT NULL,
+    position int NOT NULL,
+    vote_count int NOT NULL DEFAULT 0,
+    UNIQUE (post_id, name)
+);
+
+CREATE TABLE poll_vote (
+    poll_option_id int REFERENCES poll_option ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    person_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    post_id int REFERENCES poll ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    published timestamptz NOT NULL DEFAULT now(),
+    PRIMARY KEY (poll_option_id, person_id)
+);
+
+CREATE INDEX idx_poll_vote_post_person ON poll_vote (post_id, person_id);
+
+-- Vote counts of remote polls are taken from the federated Question object instead.
+CREATE FUNCTION poll_vote_count ()
+    RETURNS TRIGGER
+    LANGUAGE plpgsql
+    AS $$
+BEGIN
+    UPDATE
+        poll_option po
+    SET
+        vote_count = (
+            SELECT
+                count(*)
+            FROM
+                poll_vote pv
+            WHERE
+                pv.poll_option_id = po.id)
+    FROM
+        post p
+    WHERE
+        p.id = po.post_id
+        AND p.local
+        AND po.id IN (OLD.poll_option_id, NEW.poll_option_id);
+    UPDATE
+        poll
+    SET
+        voters_count = (
+            SELECT
+                count(DISTINCT pv.person_id)
+            FROM
+                poll_vote pv
+            WHERE
+                pv.post_id = poll.post_id)
+    FROM
+        post p
+    WHERE
+        p.id = poll.post_id
+        AND p.local
+        AND poll.post_id IN (OLD.post_id, NEW.post_id);
+    RETURN NULL;
+END
+$$;
+
+CREATE TRIGGER poll_vote_count
+    AFTER INSERT OR DELETE ON poll_vote
+    FOR EACH ROW
+    EXECUTE PROCEDURE poll_vote_count ();
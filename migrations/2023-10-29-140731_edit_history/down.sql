@@ -0,0 +1,3 @@
+DROP TABLE post_revision;
+
+DROP TABLE comment_revision;
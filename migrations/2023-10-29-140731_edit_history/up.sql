@@ -0,0 +1,23 @@
+-- Previous versions of edited posts and comments. Published is the time when the version was
+-- written, edited the time when it was replaced by a newer version.
+CREATE TABLE post_revision (
+    id serial PRIMARY KEY,
+    post_id int REFERENCES post ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    name varchar(200) NOT NULL,
+    url varchar(512),
+    body text,
+    published timestamptz NOT NULL,
+    edited timestamptz NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_post_revision_post ON post_revision (post_id, edited);
+
+CREATE TABLE comment_revision (
+    id serial PRIMARY KEY,
+    comment_id int REFERENCES comment ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    content text NOT NULL,
+    published timestamptz NOT NULL,
+    edited timestamptz NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_comment_revision_comment ON comment_revision (comment_id, edited);
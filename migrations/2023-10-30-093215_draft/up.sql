@@ -0,0 +1,20 @@
+-- Unpublished posts and comments which are saved for a local user. Drafts which have a post_id
+-- are comment drafts, all others are post drafts. Nothing is validated until the draft is
+-- published.
+CREATE TABLE draft (
+    id serial PRIMARY KEY,
+    local_user_id int REFERENCES local_user ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    community_id int REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE,
+    name text,
+    url text,
+    body text,
+    nsfw boolean,
+    language_id int REFERENCES
+    LANGUAGE ON UPDATE CASCADE ON DELETE CASCADE,
+    post_id int REFERENCES post ON UPDATE CASCADE ON DELETE CASCADE,
+    parent_id int REFERENCES comment ON UPDATE CASCADE ON DELETE CASCADE,
+    published timestamptz NOT NULL DEFAULT now(),
+    updated timestamptz
+);
+
+CREATE INDEX idx_draft_local_user ON draft (local_user_id, published);
@@ -0,0 +1,17 @@
+DROP TRIGGER conversation_latest_message ON private_message;
+
+DROP FUNCTION conversation_latest_message;
+
+-- Messages without a single recipient can't be kept
+DELETE FROM private_message
+WHERE recipient_id IS NULL;
+
+ALTER TABLE private_message
+    ALTER COLUMN recipient_id SET NOT NULL;
+
+ALTER TABLE private_message
+    DROP COLUMN conversation_id;
+
+DROP TABLE conversation_participant;
+
+DROP TABLE conversation;
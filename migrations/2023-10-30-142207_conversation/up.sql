@@ -0,0 +1,139 @@
+-- Conversations group private messages between two or more people. Direct messages are part of
+-- a conversation with exactly two participants.
+CREATE TABLE conversation (
+    id serial PRIMARY KEY,
+    ap_id varchar(255) NOT NULL UNIQUE DEFAULT generate_unique_changeme (),
+    local boolean NOT NULL DEFAULT TRUE,
+    published timestamptz NOT NULL DEFAULT now(),
+    updated timestamptz
+);
+
+-- Messages of a conversation which were published after last_read are unread for the
+-- participant.
+CREATE TABLE conversation_participant (
+    id serial PRIMARY KEY,
+    conversation_id int REFERENCES conversation ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    person_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    last_read timestamptz NOT NULL DEFAULT now(),
+    muted boolean NOT NULL DEFAULT FALSE,
+    published timestamptz NOT NULL DEFAULT now(),
+    UNIQUE (conversation_id, person_id)
+);
+
+CREATE INDEX idx_conversation_participant_person ON conversation_participant (person_id);
+
+ALTER TABLE private_message
+    ADD COLUMN conversation_id int REFERENCES conversation ON UPDATE CASCADE ON DELETE CASCADE;
+
+-- Messages to a group conversation have no single recipient
+ALTER TABLE private_message
+    ALTER COLUMN recipient_id DROP NOT NULL;
+
+-- Put the existing messages between each pair of people into a conversation
+CREATE TEMPORARY TABLE pm_pair AS
+SELECT
+    least (creator_id, recipient_id) AS person_a,
+    greatest (creator_id, recipient_id) AS person_b,
+    min(published) AS published,
+    nextval('conversation_id_seq') AS conversation_id
+FROM
+    private_message
+GROUP BY
+    least (creator_id, recipient_id),
+    greatest (creator_id, recipient_id);
+
+INSERT INTO conversation (id, published)
+SELECT
+    conversation_id,
+    published
+FROM
+    pm_pair;
+
+INSERT INTO conversation_participant (conversation_id, person_id)
+SELECT
+    conversation_id,
+    person_a
+FROM
+    pm_pair
+UNION
+SELECT
+    conversation_id,
+    person_b
+FROM
+    pm_pair;
+
+UPDATE
+    private_message pm
+SET
+    conversation_id = p.conversation_id
+FROM
+    pm_pair p
+WHERE
+    least (pm.creator_id, pm.recipient_id) = p.person_a
+    AND greatest (pm.creator_id, pm.recipient_id) = p.person_b;
+
+-- Keep unread messages unread
+UPDATE
+    conversation_participant cp
+SET
+    last_read = unread.published - interval '1 microsecond'
+FROM (
+    SELECT
+        conversation_id,
+        recipient_id,
+        min(published) AS published
+    FROM
+        private_message
+    WHERE
+        NOT read
+    GROUP BY
+        conversation_id,
+        recipient_id) unread
+WHERE
+    cp.conversation_id = unread.conversation_id
+    AND cp.person_id = unread.recipient_id;
+
+DROP TABLE pm_pair;
+
+ALTER TABLE private_message
+    ALTER COLUMN conversation_id SET NOT NULL;
+
+CREATE INDEX idx_private_message_conversation ON private_message (conversation_id, published);
+
+-- Conversations are sorted by their latest message
+UPDATE
+    conversation c
+SET
+    updated = latest.published
+FROM (
+    SELECT
+        conversation_id,
+        max(published) AS published
+    FROM
+        private_message
+    GROUP BY
+        conversation_id) latest
+WHERE
+    c.id = latest.conversation_id;
+
+CREATE FUNCTION conversation_latest_message ()
+    RETURNS TRIGGER
+    LANGUAGE plpgsql
+    AS $$
+BEGIN
+    UPDATE
+        conversation
+    SET
+        updated = NEW.published
+    WHERE
+        id = NEW.conversation_id
+        AND (updated IS NULL
+            OR updated < NEW.published);
+    RETURN NULL;
+END
+$$;
+
+CREATE TRIGGER conversation_latest_message
+    AFTER INSERT ON private_message
+    FOR EACH ROW
+    EXECUTE PROCEDURE conversation_latest_message ();
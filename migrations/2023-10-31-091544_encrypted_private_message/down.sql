@@ -0,0 +1,10 @@
+-- Encrypted messages can't be turned back into plaintext
+DELETE FROM private_message
+WHERE encrypted;
+
+ALTER TABLE private_message
+    DROP COLUMN encrypted,
+    DROP COLUMN encryption_metadata;
+
+ALTER TABLE person
+    DROP COLUMN encryption_public_key;
@@ -0,0 +1,10 @@
+-- Public key which other users encrypt private messages with. The matching private key never
+-- leaves the clients of the user.
+ALTER TABLE person
+    ADD COLUMN encryption_public_key text;
+
+-- The content of encrypted messages is ciphertext which only the participants can decrypt. The
+-- metadata is opaque to the server, it holds whatever the clients need for decryption.
+ALTER TABLE private_message
+    ADD COLUMN encrypted boolean NOT NULL DEFAULT FALSE,
+    ADD COLUMN encryption_metadata text;
@@ -0,0 +1,7 @@
+DROP INDEX idx_community_follower_pending;
+
+ALTER TABLE community
+    DROP COLUMN visibility;
+
+DROP TYPE community_visibility;
+
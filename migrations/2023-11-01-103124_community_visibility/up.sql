@@ -0,0 +1,17 @@
+-- Public communities can be read by everyone. Local-only communities are hidden from logged out
+-- users and don't federate. The content of private communities is only visible to followers which
+-- were approved by a moderator.
+CREATE TYPE community_visibility AS enum (
+    'Public',
+    'LocalOnly',
+    'Private'
+);
+
+ALTER TABLE community
+    ADD COLUMN visibility community_visibility DEFAULT 'Public' NOT NULL;
+
+-- Follow requests for private communities stay pending until a moderator approves them
+CREATE INDEX idx_community_follower_pending ON community_follower (community_id)
+WHERE
+    pending;
+
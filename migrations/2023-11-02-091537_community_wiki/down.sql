@@ -0,0 +1,3 @@
+DROP TABLE community_wiki_page_revision;
+
+DROP TABLE community_wiki_page;
@@ -0,0 +1,27 @@
+-- Wiki pages of communities. The slug identifies a page within its community.
+CREATE TABLE community_wiki_page (
+    id serial PRIMARY KEY,
+    ap_id varchar(255) NOT NULL UNIQUE DEFAULT generate_unique_changeme (),
+    community_id int REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    slug varchar(100) NOT NULL,
+    title varchar(200) NOT NULL,
+    body text NOT NULL,
+    members_can_edit boolean NOT NULL DEFAULT FALSE,
+    local boolean NOT NULL DEFAULT TRUE,
+    deleted boolean NOT NULL DEFAULT FALSE,
+    published timestamptz NOT NULL DEFAULT now(),
+    updated timestamptz,
+    UNIQUE (community_id, slug)
+);
+
+-- Every version of a wiki page, including the current one
+CREATE TABLE community_wiki_page_revision (
+    id serial PRIMARY KEY,
+    wiki_page_id int REFERENCES community_wiki_page ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    editor_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    title varchar(200) NOT NULL,
+    body text NOT NULL,
+    published timestamptz NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_community_wiki_page_revision_page ON community_wiki_page_revision (wiki_page_id, published);
@@ -0,0 +1,12 @@
+DELETE FROM custom_emoji
+WHERE local_site_id IS NULL;
+
+DROP INDEX idx_custom_emoji_site_shortcode, idx_custom_emoji_community_shortcode;
+
+ALTER TABLE custom_emoji
+    DROP COLUMN community_id,
+    DROP COLUMN ap_id,
+    DROP COLUMN local,
+    ALTER COLUMN local_site_id SET NOT NULL,
+    ADD CONSTRAINT custom_emoji_shortcode_key UNIQUE (shortcode),
+    ADD CONSTRAINT custom_emoji_image_url_key UNIQUE (image_url);
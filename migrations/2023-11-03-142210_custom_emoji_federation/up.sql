@@ -0,0 +1,19 @@
+-- Custom emojis can belong to the site, to a community, or be cached from a remote instance. In
+-- the last case neither local_site_id nor community_id is set.
+ALTER TABLE custom_emoji
+    ALTER COLUMN local_site_id DROP NOT NULL,
+    ADD COLUMN community_id int REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE,
+    ADD COLUMN ap_id varchar(255) NOT NULL UNIQUE DEFAULT generate_unique_changeme (),
+    ADD COLUMN local boolean NOT NULL DEFAULT TRUE,
+    ADD CONSTRAINT custom_emoji_site_or_community CHECK (local_site_id IS NULL OR community_id IS NULL),
+    DROP CONSTRAINT custom_emoji_shortcode_key,
+    DROP CONSTRAINT custom_emoji_image_url_key;
+
+-- Shortcodes only need to be unique within the emoji set they belong to
+CREATE UNIQUE INDEX idx_custom_emoji_site_shortcode ON custom_emoji (shortcode)
+WHERE
+    local_site_id IS NOT NULL;
+
+CREATE UNIQUE INDEX idx_custom_emoji_community_shortcode ON custom_emoji (community_id, shortcode)
+WHERE
+    community_id IS NOT NULL;
@@ -0,0 +1,11 @@
+DROP TRIGGER reaction_aggregates ON reaction;
+
+DROP FUNCTION reaction_aggregates, reaction_counts_add;
+
+DROP TABLE reaction;
+
+ALTER TABLE post_aggregates
+    DROP COLUMN reaction_counts;
+
+ALTER TABLE comment_aggregates
+    DROP COLUMN reaction_counts;
@@ -0,0 +1,88 @@
+-- Emoji reactions on posts and comments. Reactions on a comment also reference its post. The emoji
+-- is either a unicode emoji, or the shortcode of a custom emoji surrounded by colons.
+CREATE TABLE reaction (
+    id serial PRIMARY KEY,
+    person_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    post_id int REFERENCES post ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    comment_id int REFERENCES comment ON UPDATE CASCADE ON DELETE CASCADE,
+    emoji varchar(130) NOT NULL,
+    custom_emoji_id int REFERENCES custom_emoji ON UPDATE CASCADE ON DELETE SET NULL,
+    read boolean NOT NULL DEFAULT FALSE,
+    published timestamptz NOT NULL DEFAULT now()
+);
+
+-- A person can react with several different emojis, but only once with each
+CREATE UNIQUE INDEX idx_reaction_post ON reaction (post_id, person_id, emoji)
+WHERE
+    comment_id IS NULL;
+
+CREATE UNIQUE INDEX idx_reaction_comment ON reaction (comment_id, person_id, emoji)
+WHERE
+    comment_id IS NOT NULL;
+
+-- The number of reactions for each emoji
+ALTER TABLE post_aggregates
+    ADD COLUMN reaction_counts jsonb NOT NULL DEFAULT '{}';
+
+ALTER TABLE comment_aggregates
+    ADD COLUMN reaction_counts jsonb NOT NULL DEFAULT '{}';
+
+CREATE FUNCTION reaction_counts_add (counts jsonb, emoji text, delta int)
+    RETURNS jsonb
+    LANGUAGE sql
+    IMMUTABLE
+    AS $$
+    SELECT
+        CASE WHEN coalesce((counts ->> emoji)::int, 0) + delta <= 0 THEN
+            counts - emoji
+        ELSE
+            jsonb_set(counts, ARRAY[emoji], to_jsonb (coalesce((counts ->> emoji)::int, 0) + delta))
+        END
+$$;
+
+CREATE FUNCTION reaction_aggregates ()
+    RETURNS TRIGGER
+    LANGUAGE plpgsql
+    AS $$
+BEGIN
+    IF (TG_OP = 'INSERT') THEN
+        IF NEW.comment_id IS NULL THEN
+            UPDATE
+                post_aggregates
+            SET
+                reaction_counts = reaction_counts_add (reaction_counts, NEW.emoji, 1)
+            WHERE
+                post_id = NEW.post_id;
+        ELSE
+            UPDATE
+                comment_aggregates
+            SET
+                reaction_counts = reaction_counts_add (reaction_counts, NEW.emoji, 1)
+            WHERE
+                comment_id = NEW.comment_id;
+        END IF;
+    ELSIF (TG_OP = 'DELETE') THEN
+        IF OLD.comment_id IS NULL THEN
+            UPDATE
+                post_aggregates
+            SET
+                reaction_counts = reaction_counts_add (reaction_counts, OLD.emoji, -1)
+            WHERE
+                post_id = OLD.post_id;
+        ELSE
+            UPDATE
+                comment_aggregates
+            SET
+                reaction_counts = reaction_counts_add (reaction_counts, OLD.emoji, -1)
+            WHERE
+                comment_id = OLD.comment_id;
+        END IF;
+    END IF;
+    RETURN NULL;
+END
+$$;
+
+CREATE TRIGGER reaction_aggregates
+    AFTER INSERT OR DELETE ON reaction
+    FOR EACH ROW
+    EXECUTE PROCEDURE reaction_aggregates ();
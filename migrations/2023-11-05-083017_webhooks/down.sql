@@ -0,0 +1,9 @@
+DROP TABLE webhook_delivery;
+
+DROP TABLE webhook_queue_state;
+
+DROP TABLE webhook_event;
+
+DROP TABLE webhook;
+
+DROP TYPE webhook_event_type;
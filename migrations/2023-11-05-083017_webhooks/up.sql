@@ -0,0 +1,56 @@
+-- Webhooks which are registered by admins for the whole site, or by moderators for a local
+-- community. Payloads are signed with the secret, and only the selected events are delivered.
+CREATE TYPE webhook_event_type AS enum (
+    'PostCreated',
+    'CommentCreated',
+    'ReportCreated',
+    'PersonBanned',
+    'RegistrationApplicationCreated'
+);
+
+CREATE TABLE webhook (
+    id serial PRIMARY KEY,
+    creator_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    community_id int REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE,
+    url text NOT NULL,
+    secret text NOT NULL,
+    events webhook_event_type[] NOT NULL,
+    enabled boolean NOT NULL DEFAULT TRUE,
+    published timestamptz NOT NULL DEFAULT now(),
+    updated timestamptz
+);
+
+CREATE INDEX idx_webhook_community ON webhook (community_id);
+
+-- Persistent queue of events, which works like sent_activity for federation. Events are only
+-- stored if at least one webhook is subscribed to them.
+CREATE TABLE webhook_event (
+    id bigserial PRIMARY KEY,
+    event_type webhook_event_type NOT NULL,
+    community_id int REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE,
+    data jsonb NOT NULL,
+    published timestamptz NOT NULL DEFAULT now()
+);
+
+-- The position of each webhook in the event queue, like federation_queue_state
+CREATE TABLE webhook_queue_state (
+    id serial PRIMARY KEY,
+    webhook_id int REFERENCES webhook ON UPDATE CASCADE ON DELETE CASCADE NOT NULL UNIQUE,
+    last_successful_id bigint NOT NULL,
+    fail_count int NOT NULL,
+    last_retry timestamptz NOT NULL
+);
+
+-- Log of every delivery attempt. Not linked to webhook_event, as old events are cleared.
+CREATE TABLE webhook_delivery (
+    id bigserial PRIMARY KEY,
+    webhook_id int REFERENCES webhook ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    event_id bigint NOT NULL,
+    event_type webhook_event_type NOT NULL,
+    success boolean NOT NULL,
+    status_code int,
+    error text,
+    published timestamptz NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_webhook_delivery_webhook_published ON webhook_delivery (webhook_id, published DESC);
@@ -0,0 +1,7 @@
+DROP TABLE push_notification;
+
+DROP TABLE push_subscription;
+
+ALTER TABLE secret
+    DROP COLUMN vapid_private_key,
+    DROP COLUMN vapid_public_key;
@@ -0,0 +1,36 @@
+-- The keypair which identifies this server to push services (VAPID). It can't be generated in
+-- sql, so it is filled in on startup.
+ALTER TABLE secret
+    ADD COLUMN vapid_private_key text,
+    ADD COLUMN vapid_public_key text;
+
+-- Web Push subscriptions of browsers and apps, with the types of notifications which they
+-- receive. The endpoint is unique, so that a browser which is used with another account only
+-- notifies the latest one.
+CREATE TABLE push_subscription (
+    id serial PRIMARY KEY,
+    local_user_id int REFERENCES local_user ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    endpoint text NOT NULL UNIQUE,
+    p256dh text NOT NULL,
+    auth text NOT NULL,
+    notify_replies boolean NOT NULL DEFAULT TRUE,
+    notify_mentions boolean NOT NULL DEFAULT TRUE,
+    notify_private_messages boolean NOT NULL DEFAULT TRUE,
+    notify_reports boolean NOT NULL DEFAULT TRUE,
+    published timestamptz NOT NULL DEFAULT now(),
+    updated timestamptz
+);
+
+CREATE INDEX idx_push_subscription_local_user ON push_subscription (local_user_id);
+
+-- Queue of push messages which are waiting to be delivered, or retried after a failure
+CREATE TABLE push_notification (
+    id bigserial PRIMARY KEY,
+    subscription_id int REFERENCES push_subscription ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    payload text NOT NULL,
+    fail_count int NOT NULL DEFAULT 0,
+    next_attempt timestamptz NOT NULL DEFAULT now(),
+    published timestamptz NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_push_notification_next_attempt ON push_notification (next_attempt);
@@ -0,0 +1,5 @@
+ALTER TABLE local_user
+    DROP COLUMN email_digest,
+    DROP COLUMN last_email_digest;
+
+DROP TYPE email_digest_frequency;
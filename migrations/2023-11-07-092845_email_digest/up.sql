@@ -0,0 +1,10 @@
+-- Opt-in digest emails with top posts from the subscribed communities and unread replies
+CREATE TYPE email_digest_frequency AS enum (
+    'Never',
+    'Daily',
+    'Weekly'
+);
+
+ALTER TABLE local_user
+    ADD COLUMN email_digest email_digest_frequency NOT NULL DEFAULT 'Never',
+    ADD COLUMN last_email_digest timestamptz;
@@ -0,0 +1,35 @@
+DROP TRIGGER modlog_combined ON mod_remove_post;
+
+DROP TRIGGER modlog_combined ON mod_lock_post;
+
+DROP TRIGGER modlog_combined ON mod_feature_post;
+
+DROP TRIGGER modlog_combined ON mod_remove_comment;
+
+DROP TRIGGER modlog_combined ON mod_remove_community;
+
+DROP TRIGGER modlog_combined ON mod_ban_from_community;
+
+DROP TRIGGER modlog_combined ON mod_ban;
+
+DROP TRIGGER modlog_combined ON mod_add_community;
+
+DROP TRIGGER modlog_combined ON mod_transfer_community;
+
+DROP TRIGGER modlog_combined ON mod_add;
+
+DROP TRIGGER modlog_combined ON mod_hide_community;
+
+DROP TRIGGER modlog_combined ON mod_community_post_tag;
+
+DROP TRIGGER modlog_combined ON admin_purge_person;
+
+DROP TRIGGER modlog_combined ON admin_purge_community;
+
+DROP TRIGGER modlog_combined ON admin_purge_post;
+
+DROP TRIGGER modlog_combined ON admin_purge_comment;
+
+DROP FUNCTION modlog_combined_insert;
+
+DROP TABLE modlog_combined;
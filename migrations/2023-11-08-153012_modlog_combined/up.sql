@@ -0,0 +1,505 @@
+-- All modlog entries in a single table, so that they can be listed in chronological order with a
+-- single query. Every row points to exactly one entry of the mod_* and admin_purge_* tables,
+-- which hold the details. The persons, community and content which an entry is about are copied
+-- here, so that the modlog can be filtered without looking at the other tables.
+CREATE TABLE modlog_combined (
+    id serial PRIMARY KEY,
+    published timestamptz NOT NULL,
+    mod_person_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    other_person_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE,
+    community_id int REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE,
+    post_id int REFERENCES post ON UPDATE CASCADE ON DELETE CASCADE,
+    comment_id int REFERENCES comment ON UPDATE CASCADE ON DELETE CASCADE,
+    community_post_tag_id int REFERENCES community_post_tag ON UPDATE CASCADE ON DELETE CASCADE,
+    mod_remove_post_id int UNIQUE REFERENCES mod_remove_post ON UPDATE CASCADE ON DELETE CASCADE,
+    mod_lock_post_id int UNIQUE REFERENCES mod_lock_post ON UPDATE CASCADE ON DELETE CASCADE,
+    mod_feature_post_id int UNIQUE REFERENCES mod_feature_post ON UPDATE CASCADE ON DELETE CASCADE,
+    mod_remove_comment_id int UNIQUE REFERENCES mod_remove_comment ON UPDATE CASCADE ON DELETE CASCADE,
+    mod_remove_community_id int UNIQUE REFERENCES mod_remove_community ON UPDATE CASCADE ON DELETE CASCADE,
+    mod_ban_from_community_id int UNIQUE REFERENCES mod_ban_from_community ON UPDATE CASCADE ON DELETE CASCADE,
+    mod_ban_id int UNIQUE REFERENCES mod_ban ON UPDATE CASCADE ON DELETE CASCADE,
+    mod_add_community_id int UNIQUE REFERENCES mod_add_community ON UPDATE CASCADE ON DELETE CASCADE,
+    mod_transfer_community_id int UNIQUE REFERENCES mod_transfer_community ON UPDATE CASCADE ON DELETE CASCADE,
+    mod_add_id int UNIQUE REFERENCES mod_add ON UPDATE CASCADE ON DELETE CASCADE,
+    mod_hide_community_id int UNIQUE REFERENCES mod_hide_community ON UPDATE CASCADE ON DELETE CASCADE,
+    mod_community_post_tag_id int UNIQUE REFERENCES mod_community_post_tag ON UPDATE CASCADE ON DELETE CASCADE,
+    admin_purge_person_id int UNIQUE REFERENCES admin_purge_person ON UPDATE CASCADE ON DELETE CASCADE,
+    admin_purge_community_id int UNIQUE REFERENCES admin_purge_community ON UPDATE CASCADE ON DELETE CASCADE,
+    admin_purge_post_id int UNIQUE REFERENCES admin_purge_post ON UPDATE CASCADE ON DELETE CASCADE,
+    admin_purge_comment_id int UNIQUE REFERENCES admin_purge_comment ON UPDATE CASCADE ON DELETE CASCADE,
+    CHECK (num_nonnulls(mod_remove_post_id, mod_lock_post_id, mod_feature_post_id,
+        mod_remove_comment_id, mod_remove_community_id, mod_ban_from_community_id,
+        mod_ban_id, mod_add_community_id, mod_transfer_community_id,
+        mod_add_id, mod_hide_community_id, mod_community_post_tag_id,
+        admin_purge_person_id, admin_purge_community_id, admin_purge_post_id,
+        admin_purge_comment_id) = 1)
+);
+
+CREATE INDEX idx_modlog_combined_published ON modlog_combined (published DESC, id DESC);
+
+CREATE INDEX idx_modlog_combined_mod_person ON modlog_combined (mod_person_id);
+
+CREATE INDEX idx_modlog_combined_other_person ON modlog_combined (other_person_id);
+
+CREATE INDEX idx_modlog_combined_community ON modlog_combined (community_id);
+
+-- Existing entries
+INSERT INTO modlog_combined (mod_remove_post_id, published, mod_person_id, other_person_id, community_id, post_id, comment_id, community_post_tag_id)
+SELECT
+    mod_remove_post.id,
+    mod_remove_post.when_,
+    mod_remove_post.mod_person_id,
+    p.creator_id,
+    p.community_id,
+    p.id,
+    NULL,
+    NULL
+FROM
+    mod_remove_post
+    INNER JOIN post p ON p.id = mod_remove_post.post_id
+ORDER BY
+    mod_remove_post.when_;
+
+INSERT INTO modlog_combined (mod_lock_post_id, published, mod_person_id, other_person_id, community_id, post_id, comment_id, community_post_tag_id)
+SELECT
+    mod_lock_post.id,
+    mod_lock_post.when_,
+    mod_lock_post.mod_person_id,
+    p.creator_id,
+    p.community_id,
+    p.id,
+    NULL,
+    NULL
+FROM
+    mod_lock_post
+    INNER JOIN post p ON p.id = mod_lock_post.post_id
+ORDER BY
+    mod_lock_post.when_;
+
+INSERT INTO modlog_combined (mod_feature_post_id, published, mod_person_id, other_person_id, community_id, post_id, comment_id, community_post_tag_id)
+SELECT
+    mod_feature_post.id,
+    mod_feature_post.when_,
+    mod_feature_post.mod_person_id,
+    p.creator_id,
+    p.community_id,
+    p.id,
+    NULL,
+    NULL
+FROM
+    mod_feature_post
+    INNER JOIN post p ON p.id = mod_feature_post.post_id
+ORDER BY
+    mod_feature_post.when_;
+
+INSERT INTO modlog_combined (mod_remove_comment_id, published, mod_person_id, other_person_id, community_id, post_id, comment_id, community_post_tag_id)
+SELECT
+    mod_remove_comment.id,
+    mod_remove_comment.when_,
+    mod_remove_comment.mod_person_id,
+    c.creator_id,
+    p.community_id,
+    p.id,
+    c.id,
+    NULL
+FROM
+    mod_remove_comment
+    INNER JOIN comment c ON c.id = mod_remove_comment.comment_id
+    INNER JOIN post p ON p.id = c.post_id
+ORDER BY
+    mod_remove_comment.when_;
+
+INSERT INTO modlog_combined (mod_remove_community_id, published, mod_person_id, other_person_id, community_id, post_id, comment_id, community_post_tag_id)
+SELECT
+    mod_remove_community.id,
+    mod_remove_community.when_,
+    mod_remove_community.mod_person_id,
+    NULL,
+    mod_remove_community.community_id,
+    NULL,
+    NULL,
+    NULL
+FROM
+    mod_remove_community
+ORDER BY
+    mod_remove_community.when_;
+
+INSERT INTO modlog_combined (mod_ban_from_community_id, published, mod_person_id, other_person_id, community_id, post_id, comment_id, community_post_tag_id)
+SELECT
+    mod_ban_from_community.id,
+    mod_ban_from_community.when_,
+    mod_ban_from_community.mod_person_id,
+    mod_ban_from_community.other_person_id,
+    mod_ban_from_community.community_id,
+    NULL,
+    NULL,
+    NULL
+FROM
+    mod_ban_from_community
+ORDER BY
+    mod_ban_from_community.when_;
+
+INSERT INTO modlog_combined (mod_ban_id, published, mod_person_id, other_person_id, community_id, post_id, comment_id, community_post_tag_id)
+SELECT
+    mod_ban.id,
+    mod_ban.when_,
+    mod_ban.mod_person_id,
+    mod_ban.other_person_id,
+    NULL,
+    NULL,
+    NULL,
+    NULL
+FROM
+    mod_ban
+ORDER BY
+    mod_ban.when_;
+
+INSERT INTO modlog_combined (mod_add_community_id, published, mod_person_id, other_person_id, community_id, post_id, comment_id, community_post_tag_id)
+SELECT
+    mod_add_community.id,
+    mod_add_community.when_,
+    mod_add_community.mod_person_id,
+    mod_add_community.other_person_id,
+    mod_add_community.community_id,
+    NULL,
+    NULL,
+    NULL
+FROM
+    mod_add_community
+ORDER BY
+    mod_add_community.when_;
+
+INSERT INTO modlog_combined (mod_transfer_community_id, published, mod_person_id, other_person_id, community_id, post_id, comment_id, community_post_tag_id)
+SELECT
+    mod_transfer_community.id,
+    mod_transfer_community.when_,
+    mod_transfer_community.mod_person_id,
+    mod_transfer_community.other_person_id,
+    mod_transfer_community.community_id,
+    NULL,
+    NULL,
+    NULL
+FROM
+    mod_transfer_community
+ORDER BY
+    mod_transfer_community.when_;
+
+INSERT INTO modlog_combined (mod_add_id, published, mod_person_id, other_person_id, community_id, post_id, comment_id, community_post_tag_id)
+SELECT
+    mod_add.id,
+    mod_add.when_,
+    mod_add.mod_person_id,
+    mod_add.other_person_id,
+    NULL,
+    NULL,
+    NULL,
+    NULL
+FROM
+    mod_add
+ORDER BY
+    mod_add.when_;
+
+INSERT INTO modlog_combined (mod_hide_community_id, published, mod_person_id, other_person_id, community_id, post_id, comment_id, community_post_tag_id)
+SELECT
+    mod_hide_community.id,
+    mod_hide_community.when_,
+    mod_hide_community.mod_person_id,
+    NULL,
+    mod_hide_community.community_id,
+    NULL,
+    NULL,
+    NULL
+FROM
+    mod_hide_community
+ORDER BY
+    mod_hide_community.when_;
+
+INSERT INTO modlog_combined (mod_community_post_tag_id, published, mod_person_id, other_person_id, community_id, post_id, comment_id, community_post_tag_id)
+SELECT
+    mod_community_post_tag.id,
+    mod_community_post_tag.when_,
+    mod_community_post_tag.mod_person_id,
+    NULL,
+    tag.community_id,
+    NULL,
+    NULL,
+    tag.id
+FROM
+    mod_community_post_tag
+    INNER JOIN community_post_tag tag ON tag.id = mod_community_post_tag.community_post_tag_id
+ORDER BY
+    mod_community_post_tag.when_;
+
+INSERT INTO modlog_combined (admin_purge_person_id, published, mod_person_id, other_person_id, community_id, post_id, comment_id, community_post_tag_id)
+SELECT
+    admin_purge_person.id,
+    admin_purge_person.when_,
+    admin_purge_person.admin_person_id,
+    NULL,
+    NULL,
+    NULL,
+    NULL,
+    NULL
+FROM
+    admin_purge_person
+ORDER BY
+    admin_purge_person.when_;
+
+INSERT INTO modlog_combined (admin_purge_community_id, published, mod_person_id, other_person_id, community_id, post_id, comment_id, community_post_tag_id)
+SELECT
+    admin_purge_community.id,
+    admin_purge_community.when_,
+    admin_purge_community.admin_person_id,
+    NULL,
+    NULL,
+    NULL,
+    NULL,
+    NULL
+FROM
+    admin_purge_community
+ORDER BY
+    admin_purge_community.when_;
+
+INSERT INTO modlog_combined (admin_purge_post_id, published, mod_person_id, other_person_id, community_id, post_id, comment_id, community_post_tag_id)
+SELECT
+    admin_purge_post.id,
+    admin_purge_post.when_,
+    admin_purge_post.admin_person_id,
+    NULL,
+    admin_purge_post.community_id,
+    NULL,
+    NULL,
+    NULL
+FROM
+    admin_purge_post
+ORDER BY
+    admin_purge_post.when_;
+
+INSERT INTO modlog_combined (admin_purge_comment_id, published, mod_person_id, other_person_id, community_id, post_id, comment_id, community_post_tag_id)
+SELECT
+    admin_purge_comment.id,
+    admin_purge_comment.when_,
+    admin_purge_comment.admin_person_id,
+    NULL,
+    p.community_id,
+    p.id,
+    NULL,
+    NULL
+FROM
+    admin_purge_comment
+    INNER JOIN post p ON p.id = admin_purge_comment.post_id
+ORDER BY
+    admin_purge_comment.when_;
+
+-- Adds every new entry of the specific modlog tables to the combined table
+CREATE FUNCTION modlog_combined_insert ()
+    RETURNS TRIGGER
+    LANGUAGE plpgsql
+    AS $$
+BEGIN
+    CASE TG_TABLE_NAME
+    WHEN 'mod_remove_post' THEN
+        INSERT INTO modlog_combined (mod_remove_post_id, published, mod_person_id, other_person_id, community_id, post_id, comment_id, community_post_tag_id)
+        SELECT
+            NEW.id,
+            NEW.when_,
+            NEW.mod_person_id,
+            p.creator_id,
+            p.community_id,
+            p.id,
+            NULL,
+            NULL
+        FROM
+            post p
+        WHERE
+            p.id = NEW.post_id;
+    WHEN 'mod_lock_post' THEN
+        INSERT INTO modlog_combined (mod_lock_post_id, published, mod_person_id, other_person_id, community_id, post_id, comment_id, community_post_tag_id)
+        SELECT
+            NEW.id,
+            NEW.when_,
+            NEW.mod_person_id,
+            p.creator_id,
+            p.community_id,
+            p.id,
+            NULL,
+            NULL
+        FROM
+            post p
+        WHERE
+            p.id = NEW.post_id;
+    WHEN 'mod_feature_post' THEN
+        INSERT INTO modlog_combined (mod_feature_post_id, published, mod_person_id, other_person_id, community_id, post_id, comment_id, community_post_tag_id)
+        SELECT
+            NEW.id,
+            NEW.when_,
+            NEW.mod_person_id,
+            p.creator_id,
+            p.community_id,
+            p.id,
+            NULL,
+            NULL
+        FROM
+            post p
+        WHERE
+            p.id = NEW.post_id;
+    WHEN 'mod_remove_comment' THEN
+        INSERT INTO modlog_combined (mod_remove_comment_id, published, mod_person_id, other_person_id, community_id, post_id, comment_id, community_post_tag_id)
+        SELECT
+            NEW.id,
+            NEW.when_,
+            NEW.mod_person_id,
+            c.creator_id,
+            p.community_id,
+            p.id,
+            c.id,
+            NULL
+        FROM
+            comment c
+            INNER JOIN post p ON p.id = c.post_id
+        WHERE
+            c.id = NEW.comment_id;
+    WHEN 'mod_remove_community' THEN
+        INSERT INTO modlog_combined (mod_remove_community_id, published, mod_person_id, other_person_id, community_id, post_id, comment_id, community_post_tag_id)
+            VALUES (NEW.id, NEW.when_, NEW.mod_person_id, NULL, NEW.community_id, NULL, NULL, NULL);
+    WHEN 'mod_ban_from_community' THEN
+        INSERT INTO modlog_combined (mod_ban_from_community_id, published, mod_person_id, other_person_id, community_id, post_id, comment_id, community_post_tag_id)
+            VALUES (NEW.id, NEW.when_, NEW.mod_person_id, NEW.other_person_id, NEW.community_id, NULL, NULL, NULL);
+    WHEN 'mod_ban' THEN
+        INSERT INTO modlog_combined (mod_ban_id, published, mod_person_id, other_person_id, community_id, post_id, comment_id, community_post_tag_id)
+            VALUES (NEW.id, NEW.when_, NEW.mod_person_id, NEW.other_person_id, NULL, NULL, NULL, NULL);
+    WHEN 'mod_add_community' THEN
+        INSERT INTO modlog_combined (mod_add_community_id, published, mod_person_id, other_person_id, community_id, post_id, comment_id, community_post_tag_id)
+            VALUES (NEW.id, NEW.when_, NEW.mod_person_id, NEW.other_person_id, NEW.community_id, NULL, NULL, NULL);
+    WHEN 'mod_transfer_community' THEN
+        INSERT INTO modlog_combined (mod_transfer_community_id, published, mod_person_id, other_person_id, community_id, post_id, comment_id, community_post_tag_id)
+            VALUES (NEW.id, NEW.when_, NEW.mod_person_id, NEW.other_person_id, NEW.community_id, NULL, NULL, NULL);
+    WHEN 'mod_add' THEN
+        INSERT INTO modlog_combined (mod_add_id, published, mod_person_id, other_person_id, community_id, post_id, comment_id, community_post_tag_id)
+            VALUES (NEW.id, NEW.when_, NEW.mod_person_id, NEW.other_person_id, NULL, NULL, NULL, NULL);
+    WHEN 'mod_hide_community' THEN
+        INSERT INTO modlog_combined (mod_hide_community_id, published, mod_person_id, other_person_id, community_id, post_id, comment_id, community_post_tag_id)
+            VALUES (NEW.id, NEW.when_, NEW.mod_person_id, NULL, NEW.community_id, NULL, NULL, NULL);
+    WHEN 'mod_community_post_tag' THEN
+        INSERT INTO modlog_combined (mod_community_post_tag_id, published, mod_person_id, other_person_id, community_id, post_id, comment_id, community_post_tag_id)
+        SELECT
+            NEW.id,
+            NEW.when_,
+            NEW.mod_person_id,
+            NULL,
+            tag.community_id,
+            NULL,
+            NULL,
+            tag.id
+        FROM
+            community_post_tag tag
+        WHERE
+            tag.id = NEW.community_post_tag_id;
+    WHEN 'admin_purge_person' THEN
+        INSERT INTO modlog_combined (admin_purge_person_id, published, mod_person_id, other_person_id, community_id, post_id, comment_id, community_post_tag_id)
+            VALUES (NEW.id, NEW.when_, NEW.admin_person_id, NULL, NULL, NULL, NULL, NULL);
+    WHEN 'admin_purge_community' THEN
+        INSERT INTO modlog_combined (admin_purge_community_id, published, mod_person_id, other_person_id, community_id, post_id, comment_id, community_post_tag_id)
+            VALUES (NEW.id, NEW.when_, NEW.admin_person_id, NULL, NULL, NULL, NULL, NULL);
+    WHEN 'admin_purge_post' THEN
+        INSERT INTO modlog_combined (admin_purge_post_id, published, mod_person_id, other_person_id, community_id, post_id, comment_id, community_post_tag_id)
+            VALUES (NEW.id, NEW.when_, NEW.admin_person_id, NULL, NEW.community_id, NULL, NULL, NULL);
+    WHEN 'admin_purge_comment' THEN
+        INSERT INTO modlog_combined (admin_purge_comment_id, published, mod_person_id, other_person_id, community_id, post_id, comment_id, community_post_tag_id)
+        SELECT
+            NEW.id,
+            NEW.when_,
+            NEW.admin_person_id,
+            NULL,
+            p.community_id,
+            p.id,
+            NULL,
+            NULL
+        FROM
+            post p
+        WHERE
+            p.id = NEW.post_id;
+    END CASE;
+    RETURN NULL;
+END
+$$;
+
+CREATE TRIGGER modlog_combined
+    AFTER INSERT ON mod_remove_post
+    FOR EACH ROW
+    EXECUTE PROCEDURE modlog_combined_insert ();
+
+CREATE TRIGGER modlog_combined
+    AFTER INSERT ON mod_lock_post
+    FOR EACH ROW
+    EXECUTE PROCEDURE modlog_combined_insert ();
+
+CREATE TRIGGER modlog_combined
+    AFTER INSERT ON mod_feature_post
+    FOR EACH ROW
+    EXECUTE PROCEDURE modlog_combined_insert ();
+
+CREATE TRIGGER modlog_combined
+    AFTER INSERT ON mod_remove_comment
+    FOR EACH ROW
+    EXECUTE PROCEDURE modlog_combined_insert ();
+
+CREATE TRIGGER modlog_combined
+    AFTER INSERT ON mod_remove_community
+    FOR EACH ROW
+    EXECUTE PROCEDURE modlog_combined_insert ();
+
+CREATE TRIGGER modlog_combined
+    AFTER INSERT ON mod_ban_from_community
+    FOR EACH ROW
+    EXECUTE PROCEDURE modlog_combined_insert ();
+
+CREATE TRIGGER modlog_combined
+    AFTER INSERT ON mod_ban
+    FOR EACH ROW
+    EXECUTE PROCEDURE modlog_combined_insert ();
+
+CREATE TRIGGER modlog_combined
+    AFTER INSERT ON mod_add_community
+    FOR EACH ROW
+    EXECUTE PROCEDURE modlog_combined_insert ();
+
+CREATE TRIGGER modlog_combined
+    AFTER INSERT ON mod_transfer_community
+    FOR EACH ROW
+    EXECUTE PROCEDURE modlog_combined_insert ();
+
+CREATE TRIGGER modlog_combined
+    AFTER INSERT ON mod_add
+    FOR EACH ROW
+    EXECUTE PROCEDURE modlog_combined_insert ();
+
+CREATE TRIGGER modlog_combined
+    AFTER INSERT ON mod_hide_community
+    FOR EACH ROW
+    EXECUTE PROCEDURE modlog_combined_insert ();
+
+CREATE TRIGGER modlog_combined
+    AFTER INSERT ON mod_community_post_tag
+    FOR EACH ROW
+    EXECUTE PROCEDURE modlog_combined_insert ();
+
+CREATE TRIGGER modlog_combined
+    AFTER INSERT ON admin_purge_person
+    FOR EACH ROW
+    EXECUTE PROCEDURE modlog_combined_insert ();
+
+CREATE TRIGGER modlog_combined
+    AFTER INSERT ON admin_purge_community
+    FOR EACH ROW
+    EXECUTE PROCEDURE modlog_combined_insert ();
+
+CREATE TRIGGER modlog_combined
+    AFTER INSERT ON admin_purge_post
+    FOR EACH ROW
+    EXECUTE PROCEDURE modlog_combined_insert ();
+
+CREATE TRIGGER modlog_combined
+    AFTER INSERT ON admin_purge_comment
+    FOR EACH ROW
+    EXECUTE PROCEDURE modlog_combined_insert ();
@@ -0,0 +1,13 @@
+-- Private notes of moderators about a person, for example previous warnings. Notes with a
+-- community are visible to the mods of that community, those without one only to admins.
+CREATE TABLE person_note (
+    id serial PRIMARY KEY,
+    person_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    creator_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    community_id int REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE,
+    content text NOT NULL,
+    published timestamptz NOT NULL DEFAULT now(),
+    updated timestamptz
+);
+
+CREATE INDEX idx_person_note_person ON person_note (person_id, published);
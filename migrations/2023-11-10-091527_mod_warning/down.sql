@@ -0,0 +1,27 @@
+DROP TRIGGER modlog_combined ON mod_warning;
+
+DROP FUNCTION modlog_combined_insert_mod_warning;
+
+DELETE FROM modlog_combined
+WHERE mod_warning_id IS NOT NULL;
+
+ALTER TABLE modlog_combined
+    DROP CONSTRAINT modlog_combined_check;
+
+ALTER TABLE modlog_combined
+    DROP COLUMN mod_warning_id;
+
+ALTER TABLE modlog_combined
+    ADD CONSTRAINT modlog_combined_check CHECK (num_nonnulls(mod_remove_post_id, mod_lock_post_id,
+        mod_feature_post_id, mod_remove_comment_id, mod_remove_community_id,
+        mod_ban_from_community_id, mod_ban_id, mod_add_community_id, mod_transfer_community_id,
+        mod_add_id, mod_hide_community_id, mod_community_post_tag_id, admin_purge_person_id,
+        admin_purge_community_id, admin_purge_post_id, admin_purge_comment_id) = 1);
+
+ALTER TABLE community
+    DROP COLUMN warning_ban_threshold,
+    DROP COLUMN warning_ban_window_days,
+    DROP COLUMN warning_ban_days;
+
+DROP TABLE mod_warning;
+
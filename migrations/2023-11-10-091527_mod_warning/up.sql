@@ -0,0 +1,50 @@
+-- Warnings which mods give to the creator of a post or comment, as a step before banning them
+CREATE TABLE mod_warning (
+    id serial PRIMARY KEY,
+    mod_person_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    other_person_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    community_id int REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    post_id int REFERENCES post ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    comment_id int REFERENCES comment ON UPDATE CASCADE ON DELETE CASCADE,
+    reason text NOT NULL,
+    when_ timestamptz NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_mod_warning_person_community ON mod_warning (other_person_id, community_id, when_);
+
+-- Users are banned from the community for warning_ban_days once they got warning_ban_threshold
+-- warnings within warning_ban_window_days. No threshold means they are never banned automatically.
+ALTER TABLE community
+    ADD COLUMN warning_ban_threshold int,
+    ADD COLUMN warning_ban_window_days int NOT NULL DEFAULT 30,
+    ADD COLUMN warning_ban_days int NOT NULL DEFAULT 7;
+
+ALTER TABLE modlog_combined
+    ADD COLUMN mod_warning_id int UNIQUE REFERENCES mod_warning ON UPDATE CASCADE ON DELETE CASCADE;
+
+ALTER TABLE modlog_combined
+    DROP CONSTRAINT modlog_combined_check;
+
+ALTER TABLE modlog_combined
+    ADD CONSTRAINT modlog_combined_check CHECK (num_nonnulls(mod_remove_post_id, mod_lock_post_id,
+        mod_feature_post_id, mod_remove_comment_id, mod_remove_community_id,
+        mod_ban_from_community_id, mod_ban_id, mod_add_community_id, mod_transfer_community_id,
+        mod_add_id, mod_hide_community_id, mod_community_post_tag_id, admin_purge_person_id,
+        admin_purge_community_id, admin_purge_post_id, admin_purge_comment_id, mod_warning_id) = 1);
+
+CREATE FUNCTION modlog_combined_insert_mod_warning ()
+    RETURNS TRIGGER
+    LANGUAGE plpgsql
+    AS $$
+BEGIN
+    INSERT INTO modlog_combined (mod_warning_id, published, mod_person_id, other_person_id, community_id, post_id, comment_id, community_post_tag_id)
+        VALUES (NEW.id, NEW.when_, NEW.mod_person_id, NEW.other_person_id, NEW.community_id, NEW.post_id, NEW.comment_id, NULL);
+    RETURN NULL;
+END
+$$;
+
+CREATE TRIGGER modlog_combined
+    AFTER INSERT ON mod_warning
+    FOR EACH ROW
+    EXECUTE PROCEDURE modlog_combined_insert_mod_warning ();
+
@@ -0,0 +1,42 @@
+DROP TRIGGER modlog_combined ON mod_resolve_report;
+
+DROP FUNCTION modlog_combined_insert_mod_resolve_report;
+
+DELETE FROM modlog_combined
+WHERE mod_resolve_report_id IS NOT NULL;
+
+ALTER TABLE modlog_combined
+    DROP CONSTRAINT modlog_combined_check;
+
+ALTER TABLE modlog_combined
+    DROP COLUMN mod_resolve_report_id;
+
+ALTER TABLE modlog_combined
+    ADD CONSTRAINT modlog_combined_check CHECK (num_nonnulls(mod_remove_post_id, mod_lock_post_id,
+        mod_feature_post_id, mod_remove_comment_id, mod_remove_community_id,
+        mod_ban_from_community_id, mod_ban_id, mod_add_community_id, mod_transfer_community_id,
+        mod_add_id, mod_hide_community_id, mod_community_post_tag_id, admin_purge_person_id,
+        admin_purge_community_id, admin_purge_post_id, admin_purge_comment_id, mod_warning_id) = 1);
+
+DROP TABLE mod_resolve_report;
+
+DROP TABLE report_note;
+
+DROP TRIGGER report_combined ON post_report;
+
+DROP TRIGGER report_combined ON comment_report;
+
+DROP TRIGGER report_combined ON private_message_report;
+
+DROP FUNCTION report_combined_update;
+
+DROP TABLE report_combined;
+
+ALTER TABLE post_report
+    DROP COLUMN resolution_reason;
+
+ALTER TABLE comment_report
+    DROP COLUMN resolution_reason;
+
+ALTER TABLE private_message_report
+    DROP COLUMN resolution_reason;
@@ -0,0 +1,262 @@
+-- The reason given by the mod who resolved a report
+ALTER TABLE post_report
+    ADD COLUMN resolution_reason text;
+
+ALTER TABLE comment_report
+    ADD COLUMN resolution_reason text;
+
+ALTER TABLE private_message_report
+    ADD COLUMN resolution_reason text;
+
+-- The report queue. All reports about the same post, comment or private message are grouped into
+-- a single entry, which points to the newest report and counts the others. The entries are
+-- maintained by triggers on the report tables, mods only claim them.
+CREATE TABLE report_combined (
+    id serial PRIMARY KEY,
+    published timestamptz NOT NULL,
+    community_id int REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE,
+    post_id int UNIQUE REFERENCES post ON UPDATE CASCADE ON DELETE CASCADE,
+    comment_id int UNIQUE REFERENCES comment ON UPDATE CASCADE ON DELETE CASCADE,
+    private_message_id int UNIQUE REFERENCES private_message ON UPDATE CASCADE ON DELETE CASCADE,
+    -- No foreign keys, so that deleting the newest report doesn't take the entry and its notes
+    -- with it. The trigger points the entry to the next report instead.
+    post_report_id int,
+    comment_report_id int,
+    private_message_report_id int,
+    report_count int NOT NULL,
+    unresolved_report_count int NOT NULL,
+    assignee_id int REFERENCES person ON UPDATE CASCADE ON DELETE SET NULL,
+    assigned timestamptz,
+    CHECK (num_nonnulls(post_id, comment_id, private_message_id) = 1)
+);
+
+CREATE INDEX idx_report_combined_published ON report_combined (published DESC, id DESC);
+
+CREATE INDEX idx_report_combined_community ON report_combined (community_id);
+
+INSERT INTO report_combined (published, community_id, post_id, post_report_id, report_count, unresolved_report_count)
+SELECT
+    max(pr.published),
+    p.community_id,
+    pr.post_id,
+    (array_agg(pr.id ORDER BY pr.published DESC, pr.id DESC))[1],
+    count(*),
+    count(*) FILTER (WHERE NOT pr.resolved)
+FROM
+    post_report pr
+    INNER JOIN post p ON p.id = pr.post_id
+GROUP BY
+    pr.post_id,
+    p.community_id;
+
+INSERT INTO report_combined (published, community_id, comment_id, comment_report_id, report_count, unresolved_report_count)
+SELECT
+    max(cr.published),
+    p.community_id,
+    cr.comment_id,
+    (array_agg(cr.id ORDER BY cr.published DESC, cr.id DESC))[1],
+    count(*),
+    count(*) FILTER (WHERE NOT cr.resolved)
+FROM
+    comment_report cr
+    INNER JOIN comment c ON c.id = cr.comment_id
+    INNER JOIN post p ON p.id = c.post_id
+GROUP BY
+    cr.comment_id,
+    p.community_id;
+
+INSERT INTO report_combined (published, community_id, private_message_id, private_message_report_id, report_count, unresolved_report_count)
+SELECT
+    max(pmr.published),
+    NULL,
+    pmr.private_message_id,
+    (array_agg(pmr.id ORDER BY pmr.published DESC, pmr.id DESC))[1],
+    count(*),
+    count(*) FILTER (WHERE NOT pmr.resolved)
+FROM
+    private_message_report pmr
+GROUP BY
+    pmr.private_message_id;
+
+-- Recomputes the queue entry of the reported item, and removes it once no reports are left
+CREATE FUNCTION report_combined_update ()
+    RETURNS TRIGGER
+    LANGUAGE plpgsql
+    AS $$
+DECLARE
+    r record;
+BEGIN
+    IF (TG_OP = 'DELETE') THEN
+        r := OLD;
+    ELSE
+        r := NEW;
+    END IF;
+    CASE TG_TABLE_NAME
+    WHEN 'post_report' THEN
+        INSERT INTO report_combined (published, community_id, post_id, post_report_id, report_count, unresolved_report_count)
+        SELECT
+            max(pr.published),
+            p.community_id,
+            pr.post_id,
+            (array_agg(pr.id ORDER BY pr.published DESC, pr.id DESC))[1],
+            count(*),
+            count(*) FILTER (WHERE NOT pr.resolved)
+        FROM
+            post_report pr
+            INNER JOIN post p ON p.id = pr.post_id
+        WHERE
+            pr.post_id = r.post_id
+        GROUP BY
+            pr.post_id,
+            p.community_id
+        ON CONFLICT (post_id)
+            DO UPDATE SET
+                published = excluded.published,
+                post_report_id = excluded.post_report_id,
+                report_count = excluded.report_count,
+                unresolved_report_count = excluded.unresolved_report_count;
+        DELETE FROM report_combined
+        WHERE post_id = r.post_id
+            AND NOT EXISTS (
+                SELECT
+                FROM
+                    post_report
+                WHERE
+                    post_id = r.post_id);
+    WHEN 'comment_report' THEN
+        INSERT INTO report_combined (published, community_id, comment_id, comment_report_id, report_count, unresolved_report_count)
+        SELECT
+            max(cr.published),
+            p.community_id,
+            cr.comment_id,
+            (array_agg(cr.id ORDER BY cr.published DESC, cr.id DESC))[1],
+            count(*),
+            count(*) FILTER (WHERE NOT cr.resolved)
+        FROM
+            comment_report cr
+            INNER JOIN comment c ON c.id = cr.comment_id
+            INNER JOIN post p ON p.id = c.post_id
+        WHERE
+            cr.comment_id = r.comment_id
+        GROUP BY
+            cr.comment_id,
+            p.community_id
+        ON CONFLICT (comment_id)
+            DO UPDATE SET
+                published = excluded.published,
+                comment_report_id = excluded.comment_report_id,
+                report_count = excluded.report_count,
+                unresolved_report_count = excluded.unresolved_report_count;
+        DELETE FROM report_combined
+        WHERE comment_id = r.comment_id
+            AND NOT EXISTS (
+                SELECT
+                FROM
+                    comment_report
+                WHERE
+                    comment_id = r.comment_id);
+    WHEN 'private_message_report' THEN
+        INSERT INTO report_combined (published, community_id, private_message_id, private_message_report_id, report_count, unresolved_report_count)
+        SELECT
+            max(pmr.published),
+            NULL,
+            pmr.private_message_id,
+            (array_agg(pmr.id ORDER BY pmr.published DESC, pmr.id DESC))[1],
+            count(*),
+            count(*) FILTER (WHERE NOT pmr.resolved)
+        FROM
+            private_message_report pmr
+        WHERE
+            pmr.private_message_id = r.private_message_id
+        GROUP BY
+            pmr.private_message_id
+        ON CONFLICT (private_message_id)
+            DO UPDATE SET
+                published = excluded.published,
+                private_message_report_id = excluded.private_message_report_id,
+                report_count = excluded.report_count,
+                unresolved_report_count = excluded.unresolved_report_count;
+        DELETE FROM report_combined
+        WHERE private_message_id = r.private_message_id
+            AND NOT EXISTS (
+                SELECT
+                FROM
+                    private_message_report
+                WHERE
+                    private_message_id = r.private_message_id);
+    END CASE;
+    RETURN NULL;
+END
+$$;
+
+CREATE TRIGGER report_combined
+    AFTER INSERT OR DELETE OR UPDATE OF resolved ON post_report
+    FOR EACH ROW
+    EXECUTE PROCEDURE report_combined_update ();
+
+CREATE TRIGGER report_combined
+    AFTER INSERT OR DELETE OR UPDATE OF resolved ON comment_report
+    FOR EACH ROW
+    EXECUTE PROCEDURE report_combined_update ();
+
+CREATE TRIGGER report_combined
+    AFTER INSERT OR DELETE OR UPDATE OF resolved ON private_message_report
+    FOR EACH ROW
+    EXECUTE PROCEDURE report_combined_update ();
+
+-- Internal discussion of the mods about a report queue entry
+CREATE TABLE report_note (
+    id serial PRIMARY KEY,
+    report_combined_id int REFERENCES report_combined ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    creator_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    content text NOT NULL,
+    published timestamptz NOT NULL DEFAULT now(),
+    updated timestamptz
+);
+
+CREATE INDEX idx_report_note_report_combined ON report_note (report_combined_id, published);
+
+-- Resolving reports about posts and comments shows up in the modlog, together with the reason.
+-- Private message reports are left out, as the messages aren't public.
+CREATE TABLE mod_resolve_report (
+    id serial PRIMARY KEY,
+    mod_person_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    other_person_id int REFERENCES person ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    community_id int REFERENCES community ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    post_id int REFERENCES post ON UPDATE CASCADE ON DELETE CASCADE NOT NULL,
+    comment_id int REFERENCES comment ON UPDATE CASCADE ON DELETE CASCADE,
+    report_count int NOT NULL,
+    resolved boolean NOT NULL DEFAULT TRUE,
+    reason text,
+    when_ timestamptz NOT NULL DEFAULT now()
+);
+
+ALTER TABLE modlog_combined
+    ADD COLUMN mod_resolve_report_id int UNIQUE REFERENCES mod_resolve_report ON UPDATE CASCADE ON DELETE CASCADE;
+
+ALTER TABLE modlog_combined
+    DROP CONSTRAINT modlog_combined_check;
+
+ALTER TABLE modlog_combined
+    ADD CONSTRAINT modlog_combined_check CHECK (num_nonnulls(mod_remove_post_id, mod_lock_post_id,
+        mod_feature_post_id, mod_remove_comment_id, mod_remove_community_id,
+        mod_ban_from_community_id, mod_ban_id, mod_add_community_id, mod_transfer_community_id,
+        mod_add_id, mod_hide_community_id, mod_community_post_tag_id, admin_purge_person_id,
+        admin_purge_community_id, admin_purge_post_id, admin_purge_comment_id, mod_warning_id,
+        mod_resolve_report_id) = 1);
+
+CREATE FUNCTION modlog_combined_insert_mod_resolve_report ()
+    RETURNS TRIGGER
+    LANGUAGE plpgsql
+    AS $$
+BEGIN
+    INSERT INTO modlog_combined (mod_resolve_report_id, published, mod_person_id, other_person_id, community_id, post_id, comment_id, community_post_tag_id)
+        VALUES (NEW.id, NEW.when_, NEW.mod_person_id, NEW.other_person_id, NEW.community_id, NEW.post_id, NEW.comment_id, NULL);
+    RETURN NULL;
+END
+$$;
+
+CREATE TRIGGER modlog_combined
+    AFTER INSERT ON mod_resolve_report
+    FOR EACH ROW
+    EXECUTE PROCEDURE modlog_combined_insert_mod_resolve_report ();
@@ -0,0 +1,5 @@
+ALTER TABLE post_report
+    DROP COLUMN ap_id;
+
+ALTER TABLE comment_report
+    DROP COLUMN ap_id;
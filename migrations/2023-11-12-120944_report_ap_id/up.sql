@@ -0,0 +1,7 @@
+-- The id of the Flag activity which federated the report, so that resolving it can be federated
+-- too. Reports from before this change don't have one.
+ALTER TABLE post_report
+    ADD COLUMN ap_id text UNIQUE;
+
+ALTER TABLE comment_report
+    ADD COLUMN ap_id text UNIQUE;
@@ -0,0 +1,7 @@
+ALTER TABLE draft
+    DROP COLUMN tags,
+    DROP COLUMN scheduled_publish_time,
+    DROP COLUMN poll_options,
+    DROP COLUMN poll_multiple_choice,
+    DROP COLUMN poll_end_time,
+    DROP COLUMN poll_hide_results;
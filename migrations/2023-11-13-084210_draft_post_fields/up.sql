@@ -0,0 +1,9 @@
+-- Post drafts keep everything which can be given when creating a post, so that publishing them
+-- doesn't drop any of it.
+ALTER TABLE draft
+    ADD COLUMN tags int[],
+    ADD COLUMN scheduled_publish_time timestamptz,
+    ADD COLUMN poll_options text[],
+    ADD COLUMN poll_multiple_choice boolean,
+    ADD COLUMN poll_end_time timestamptz,
+    ADD COLUMN poll_hide_results boolean;
@@ -0,0 +1 @@
+DROP INDEX idx_multi_community_lower_name;
@@ -0,0 +1,2 @@
+-- Local multi-communities are looked up by their lowercase name
+CREATE UNIQUE INDEX idx_multi_community_lower_name ON multi_community (instance_id, lower(name));
@@ -1,6 +1,12 @@
 use actix_web::{guard, web};
 use lemmy_api::{
-  comment::{distinguish::distinguish_comment, like::like_comment, save::save_comment},
+  comment::{
+    distinguish::distinguish_comment,
+    like::like_comment,
+    list_revisions::list_comment_revisions,
+    react::react_to_comment,
+    save::save_comment,
+  },
   comment_report::{
     create::create_comment_report,
     list::list_comment_reports,
@@ -12,7 +18,31 @@ use lemmy_api::{
     block::block_community,
     follow::follow_community,
     hide::hide_community,
+    pending_follows::{
+      approve::approve_community_pending_follow,
+      list::list_community_pending_follows,
+    },
+    post_tag::{
+      create::create_community_post_tag,
+      delete::delete_community_post_tag,
+      update::update_community_post_tag,
+    },
     transfer::transfer_community,
+    warn::warn_person,
+    wiki::{
+      create::create_community_wiki_page,
+      delete::delete_community_wiki_page,
+      list::list_community_wiki_pages,
+      list_revisions::list_community_wiki_page_revisions,
+      read::get_community_wiki_page,
+      revert::revert_community_wiki_page,
+      update::update_community_wiki_page,
+    },
+  },
+  conversation::{
+    leave::leave_conversation,
+    mark_read::mark_conversation_as_read,
+    mute::mute_conversation,
   },
   local_user::{
     add_admin::add_admin,
@@ -20,34 +50,50 @@ use lemmy_api::{
     block::block_person,
     change_password::change_password,
     change_password_after_reset::change_password_after_reset,
+    follow::follow_person,
     generate_totp_secret::generate_totp_secret,
     get_captcha::get_captcha,
+    keyword_filter::{create::create_keyword_filter, delete::delete_keyword_filter},
     list_banned::list_banned_users,
     list_logins::list_logins,
+    list_warnings::list_warnings,
     login::login,
     logout::logout,
+    moderation_history::get_moderation_history,
     notifications::{
       list_mentions::list_mentions,
+      list_reactions::list_received_reactions,
       list_replies::list_replies,
       mark_all_read::mark_all_notifications_read,
       mark_mention_read::mark_person_mention_as_read,
+      mark_reaction_read::mark_reaction_as_read,
       mark_reply_read::mark_reply_as_read,
       unread_count::unread_count,
     },
     report_count::report_count,
     reset_password::reset_password,
     save_settings::save_user_settings,
+    unsubscribe_email_digest::unsubscribe_email_digest,
     update_totp::update_totp,
     validate_auth::validate_auth,
     verify_email::verify_email,
   },
+  person_note::{
+    create::create_person_note,
+    delete::delete_person_note,
+    update::update_person_note,
+  },
   post::{
     feature::feature_post,
     get_link_metadata::get_link_metadata,
     like::like_post,
+    list_reactions::list_reactions,
+    list_revisions::list_post_revisions,
     lock::lock_post,
     mark_read::mark_post_as_read,
+    react::react_to_post,
     save::save_post,
+    vote_poll::vote_poll,
   },
   post_report::{
     create::create_post_report,
@@ -60,6 +106,20 @@ use lemmy_api::{
     list::list_pm_reports,
     resolve::resolve_pm_report,
   },
+  push_subscription::{
+    create::create_push_subscription,
+    delete::delete_push_subscription,
+    list::list_push_subscriptions,
+    update::update_push_subscription,
+    vapid_public_key::get_vapid_public_key,
+  },
+  report::{
+    assign::assign_report,
+    create_note::create_report_note,
+    list::list_reports,
+    list_notes::list_report_notes,
+    resolve::resolve_reports,
+  },
   site::{
     block::block_instance,
     federated_instances::get_federated_instances,
@@ -78,6 +138,13 @@ use lemmy_api::{
     },
   },
   sitemap::get_sitemap,
+  webhook::{
+    create::create_webhook,
+    delete::delete_webhook,
+    list::list_webhooks,
+    list_deliveries::list_webhook_deliveries,
+    update::update_webhook,
+  },
 };
 use lemmy_api_crud::{
   comment::{
@@ -94,14 +161,35 @@ use lemmy_api_crud::{
     remove::remove_community,
     update::update_community,
   },
+  conversation::{
+    create::create_conversation,
+    create_message::create_conversation_message,
+    list::list_conversations,
+    read::get_conversation,
+  },
   custom_emoji::{
     create::create_custom_emoji,
     delete::delete_custom_emoji,
     update::update_custom_emoji,
   },
+  draft::{
+    create::create_draft,
+    delete::delete_draft,
+    list::list_drafts,
+    publish::publish_draft,
+    update::update_draft,
+  },
+  multi_community::{
+    create::create_multi_community,
+    delete::delete_multi_community,
+    list::list_multi_communities,
+    read::get_multi_community,
+    update::update_multi_community,
+  },
   post::{
     create::create_post,
     delete::delete_post,
+    list_scheduled::list_scheduled_posts,
     read::get_post,
     remove::remove_post,
     update::update_post,
@@ -122,8 +210,10 @@ use lemmy_apub::api::{
   read_person::read_person,
   resolve_object::resolve_object,
   search::search,
+  search_combined::search_combined,
   user_settings_backup::{export_settings, import_settings},
 };
+use lemmy_routes::live::get_live_events;
 use lemmy_utils::rate_limit::RateLimitCell;
 
 pub fn config(cfg: &mut web::ServiceConfig, rate_limit: &RateLimitCell) {
@@ -149,6 +239,11 @@ pub fn config(cfg: &mut web::ServiceConfig, rate_limit: &RateLimitCell) {
           .wrap(rate_limit.search())
           .route(web::get().to(search)),
       )
+      .service(
+        web::resource("/search/combined")
+          .wrap(rate_limit.search())
+          .route(web::get().to(search_combined)),
+      )
       .service(
         web::resource("/resolve_object")
           .wrap(rate_limit.message())
@@ -169,13 +264,64 @@ pub fn config(cfg: &mut web::ServiceConfig, rate_limit: &RateLimitCell) {
           .route("/hide", web::put().to(hide_community))
           .route("/list", web::get().to(list_communities))
           .route("/follow", web::post().to(follow_community))
+          .route(
+            "/pending_follows/list",
+            web::get().to(list_community_pending_follows),
+          )
+          .route(
+            "/pending_follows/approve",
+            web::post().to(approve_community_pending_follow),
+          )
+          .route("/wiki", web::get().to(get_community_wiki_page))
+          .route("/wiki", web::post().to(create_community_wiki_page))
+          .route("/wiki", web::put().to(update_community_wiki_page))
+          .route("/wiki/list", web::get().to(list_community_wiki_pages))
+          .route(
+            "/wiki/revisions",
+            web::get().to(list_community_wiki_page_revisions),
+          )
+          .route("/wiki/revert", web::post().to(revert_community_wiki_page))
+          .route("/wiki/delete", web::post().to(delete_community_wiki_page))
           .route("/block", web::post().to(block_community))
           .route("/delete", web::post().to(delete_community))
           // Mod Actions
           .route("/remove", web::post().to(remove_community))
           .route("/transfer", web::post().to(transfer_community))
           .route("/ban_user", web::post().to(ban_from_community))
-          .route("/mod", web::post().to(add_mod_to_community)),
+          .route("/warn", web::post().to(warn_person))
+          .route("/mod", web::post().to(add_mod_to_community))
+          .route("/post_tag", web::post().to(create_community_post_tag))
+          .route("/post_tag", web::put().to(update_community_post_tag))
+          .route(
+            "/post_tag/delete",
+            web::post().to(delete_community_post_tag),
+          ),
+      )
+      // Multi-community
+      .service(
+        web::scope("/multi_community")
+          .wrap(rate_limit.message())
+          .route("", web::get().to(get_multi_community))
+          .route("", web::post().to(create_multi_community))
+          .route("", web::put().to(update_multi_community))
+          .route("/list", web::get().to(list_multi_communities))
+          .route("/delete", web::post().to(delete_multi_community)),
+      )
+      // Draft
+      .service(
+        // Publishing creates a post or comment, so it uses the post() rate limitter
+        web::resource("/draft/publish")
+          .guard(guard::Post())
+          .wrap(rate_limit.post())
+          .route(web::post().to(publish_draft)),
+      )
+      .service(
+        web::scope("/draft")
+          .wrap(rate_limit.message())
+          .route("", web::post().to(create_draft))
+          .route("", web::put().to(update_draft))
+          .route("/list", web::get().to(list_drafts))
+          .route("/delete", web::post().to(delete_draft)),
       )
       .service(
         web::scope("/federated_instances")
@@ -201,7 +347,12 @@ pub fn config(cfg: &mut web::ServiceConfig, rate_limit: &RateLimitCell) {
           .route("/lock", web::post().to(lock_post))
           .route("/feature", web::post().to(feature_post))
           .route("/list", web::get().to(list_posts))
+          .route("/scheduled", web::get().to(list_scheduled_posts))
+          .route("/revisions", web::get().to(list_post_revisions))
           .route("/like", web::post().to(like_post))
+          .route("/react", web::post().to(react_to_post))
+          .route("/reactions", web::get().to(list_reactions))
+          .route("/poll/vote", web::post().to(vote_poll))
           .route("/save", web::put().to(save_post))
           .route("/report", web::post().to(create_post_report))
           .route("/report/resolve", web::put().to(resolve_post_report))
@@ -226,8 +377,11 @@ pub fn config(cfg: &mut web::ServiceConfig, rate_limit: &RateLimitCell) {
           .route("/mark_as_read", web::post().to(mark_reply_as_read))
           .route("/distinguish", web::post().to(distinguish_comment))
           .route("/like", web::post().to(like_comment))
+          .route("/react", web::post().to(react_to_comment))
+          .route("/reactions", web::get().to(list_reactions))
           .route("/save", web::put().to(save_comment))
           .route("/list", web::get().to(list_comments))
+          .route("/revisions", web::get().to(list_comment_revisions))
           .route("/report", web::post().to(create_comment_report))
           .route("/report/resolve", web::put().to(resolve_comment_report))
           .route("/report/list", web::get().to(list_comment_reports)),
@@ -245,6 +399,28 @@ pub fn config(cfg: &mut web::ServiceConfig, rate_limit: &RateLimitCell) {
           .route("/report/resolve", web::put().to(resolve_pm_report))
           .route("/report/list", web::get().to(list_pm_reports)),
       )
+      // Report queue
+      .service(
+        web::scope("/report")
+          .wrap(rate_limit.message())
+          .route("/list", web::get().to(list_reports))
+          .route("/resolve", web::put().to(resolve_reports))
+          .route("/assign", web::put().to(assign_report))
+          .route("/note", web::post().to(create_report_note))
+          .route("/note/list", web::get().to(list_report_notes)),
+      )
+      // Conversation
+      .service(
+        web::scope("/conversation")
+          .wrap(rate_limit.message())
+          .route("", web::get().to(get_conversation))
+          .route("", web::post().to(create_conversation))
+          .route("/list", web::get().to(list_conversations))
+          .route("/message", web::post().to(create_conversation_message))
+          .route("/mark_as_read", web::post().to(mark_conversation_as_read))
+          .route("/mute", web::post().to(mute_conversation))
+          .route("/leave", web::post().to(leave_conversation)),
+      )
       // User
       .service(
         // Account action, I don't like that it's in /user maybe /accounts
@@ -271,10 +447,27 @@ pub fn config(cfg: &mut web::ServiceConfig, rate_limit: &RateLimitCell) {
             web::post().to(mark_person_mention_as_read),
           )
           .route("/replies", web::get().to(list_replies))
+          .route("/reactions", web::get().to(list_received_reactions))
+          .route(
+            "/reactions/mark_as_read",
+            web::post().to(mark_reaction_as_read),
+          )
           // Admin action. I don't like that it's in /user
           .route("/ban", web::post().to(ban_from_site))
           .route("/banned", web::get().to(list_banned_users))
           .route("/block", web::post().to(block_person))
+          .route("/follow", web::post().to(follow_person))
+          .route("/keyword_filter", web::post().to(create_keyword_filter))
+          .route(
+            "/keyword_filter/delete",
+            web::post().to(delete_keyword_filter),
+          )
+          // Moderator actions
+          .route("/note", web::post().to(create_person_note))
+          .route("/note", web::put().to(update_person_note))
+          .route("/note/delete", web::post().to(delete_person_note))
+          .route("/moderation_history", web::get().to(get_moderation_history))
+          .route("/warnings", web::get().to(list_warnings))
           // Account actions. I don't like that they're in /user maybe /accounts
           .route("/login", web::post().to(login))
           .route("/logout", web::post().to(logout))
@@ -293,7 +486,12 @@ pub fn config(cfg: &mut web::ServiceConfig, rate_limit: &RateLimitCell) {
           .route("/change_password", web::put().to(change_password))
           .route("/report_count", web::get().to(report_count))
           .route("/unread_count", web::get().to(unread_count))
+          .route("/live", web::get().to(get_live_events))
           .route("/verify_email", web::post().to(verify_email))
+          .route(
+            "/email_digest/unsubscribe",
+            web::get().to(unsubscribe_email_digest),
+          )
           .route("/leave_admin", web::post().to(leave_admin))
           .route("/totp/generate", web::post().to(generate_totp_secret))
           .route("/totp/update", web::post().to(update_totp))
@@ -337,6 +535,24 @@ pub fn config(cfg: &mut web::ServiceConfig, rate_limit: &RateLimitCell) {
           .route("", web::post().to(create_custom_emoji))
           .route("", web::put().to(update_custom_emoji))
           .route("/delete", web::post().to(delete_custom_emoji)),
+      )
+      .service(
+        web::scope("/webhook")
+          .wrap(rate_limit.message())
+          .route("", web::post().to(create_webhook))
+          .route("", web::put().to(update_webhook))
+          .route("/delete", web::post().to(delete_webhook))
+          .route("/list", web::get().to(list_webhooks))
+          .route("/deliveries", web::get().to(list_webhook_deliveries)),
+      )
+      .service(
+        web::scope("/push_subscription")
+          .wrap(rate_limit.message())
+          .route("", web::post().to(create_push_subscription))
+          .route("", web::put().to(update_push_subscription))
+          .route("/delete", web::post().to(delete_push_subscription))
+          .route("/list", web::get().to(list_push_subscriptions))
+          .route("/vapid_public_key", web::get().to(get_vapid_public_key)),
       ),
   );
   cfg.service(
@@ -23,6 +23,8 @@ use lemmy_db_schema::{
   source::{
     comment::{Comment, CommentUpdateForm},
     community::{Community, CommunityUpdateForm},
+    conversation::{Conversation, ConversationUpdateForm},
+    custom_emoji::{CustomEmoji, CustomEmojiUpdateForm},
     instance::Instance,
     local_site::{LocalSite, LocalSiteInsertForm},
     local_site_rate_limit::{LocalSiteRateLimit, LocalSiteRateLimitInsertForm},
@@ -35,7 +37,11 @@ use lemmy_db_schema::{
   traits::Crud,
   utils::{get_conn, naive_now, DbPool},
 };
-use lemmy_utils::{error::LemmyError, settings::structs::Settings};
+use lemmy_utils::{
+  error::LemmyError,
+  settings::structs::Settings,
+  web_push::generate_vapid_keypair,
+};
 use tracing::info;
 use url::Url;
 
@@ -54,6 +60,9 @@ pub async fn run_advanced_migrations(
   instance_actor_2022_01_28(pool, protocol_and_hostname).await?;
   regenerate_public_keys_2022_07_05(pool).await?;
   initialize_local_site_2022_10_10(pool, settings).await?;
+  conversation_updates_2023_10_30(pool, protocol_and_hostname).await?;
+  custom_emoji_updates_2023_11_03(pool, protocol_and_hostname).await?;
+  vapid_keys_2023_11_06(pool).await?;
 
   Ok(())
 }
@@ -521,3 +530,107 @@ async fn initialize_local_site_2022_10_10(
 
   Ok(())
 }
+
+/// Conversations for the existing private messages are created by the database migration, which
+/// can't generate their ap_id.
+async fn conversation_updates_2023_10_30(
+  pool: &mut DbPool<'_>,
+  protocol_and_hostname: &str,
+) -> Result<(), LemmyError> {
+  use lemmy_db_schema::schema::conversation::dsl::{ap_id, conversation, local};
+  let conn = &mut get_conn(pool).await?;
+
+  info!("Running conversation_updates_2023_10_30");
+
+  let incorrect_conversations = conversation
+    .filter(ap_id.like("http://changeme%"))
+    .filter(local.eq(true))
+    .load::<Conversation>(conn)
+    .await?;
+
+  for c in &incorrect_conversations {
+    let apub_id = generate_local_apub_endpoint(
+      EndpointType::Conversation,
+      &c.id.to_string(),
+      protocol_and_hostname,
+    )?;
+    Conversation::update(
+      pool,
+      c.id,
+      &ConversationUpdateForm {
+        ap_id: Some(apub_id),
+        ..Default::default()
+      },
+    )
+    .await?;
+  }
+
+  info!(
+    "{} conversation rows updated.",
+    incorrect_conversations.len()
+  );
+
+  Ok(())
+}
+
+/// Custom emojis only got an ap_id when they started to federate.
+async fn custom_emoji_updates_2023_11_03(
+  pool: &mut DbPool<'_>,
+  protocol_and_hostname: &str,
+) -> Result<(), LemmyError> {
+  use lemmy_db_schema::schema::custom_emoji::dsl::{ap_id, custom_emoji, local};
+  let conn = &mut get_conn(pool).await?;
+
+  info!("Running custom_emoji_updates_2023_11_03");
+
+  let incorrect_emojis = custom_emoji
+    .filter(ap_id.like("http://changeme%"))
+    .filter(local.eq(true))
+    .load::<CustomEmoji>(conn)
+    .await?;
+
+  for e in &incorrect_emojis {
+    let apub_id = generate_local_apub_endpoint(
+      EndpointType::CustomEmoji,
+      &e.id.0.to_string(),
+      protocol_and_hostname,
+    )?;
+    let form = CustomEmojiUpdateForm::builder()
+      .image_url(e.image_url.clone())
+      .alt_text(e.alt_text.clone())
+      .category(e.category.clone())
+      .ap_id(Some(apub_id))
+      .build();
+    CustomEmoji::update(pool, e.id, &form).await?;
+  }
+
+  info!("{} custom_emoji rows updated.", incorrect_emojis.len());
+
+  Ok(())
+}
+
+/// The keypair for Web Push can't be generated in sql
+async fn vapid_keys_2023_11_06(pool: &mut DbPool<'_>) -> Result<(), LemmyError> {
+  use lemmy_db_schema::schema::secret::dsl::{secret, vapid_private_key, vapid_public_key};
+  let conn = &mut get_conn(pool).await?;
+  info!("Running vapid_keys_2023_11_06");
+
+  let missing: i64 = secret
+    .filter(vapid_private_key.is_null())
+    .count()
+    .get_result(conn)
+    .await?;
+  if missing > 0 {
+    let keypair = generate_vapid_keypair()?;
+    diesel::update(secret)
+      .set((
+        vapid_private_key.eq(keypair.private_key),
+        vapid_public_key.eq(keypair.public_key),
+      ))
+      .execute(conn)
+      .await?;
+    info!("Generated VAPID keypair");
+  }
+
+  Ok(())
+}
@@ -44,7 +44,12 @@ use lemmy_db_schema::{
   source::secret::Secret,
   utils::{build_db_pool, get_database_url, run_migrations},
 };
-use lemmy_federate::{start_stop_federation_workers_cancellable, Opts};
+use lemmy_federate::{
+  start_push_worker_cancellable,
+  start_stop_federation_workers_cancellable,
+  start_stop_webhook_workers_cancellable,
+  Opts,
+};
 use lemmy_routes::{feeds, images, nodeinfo, webfinger};
 use lemmy_utils::{
   error::LemmyError,
@@ -186,11 +191,6 @@ pub async fn start_lemmy_server(args: CmdArgs) -> Result<(), LemmyError> {
     rate_limit_cell.clone(),
   );
 
-  if !args.disable_scheduled_tasks {
-    // Schedules various cleanup tasks for the DB
-    let _scheduled_tasks = tokio::task::spawn(scheduled_tasks::setup(context.clone()));
-  }
-
   #[cfg(feature = "prometheus-metrics")]
   serve_prometheus(settings.prometheus.as_ref(), context.clone());
 
@@ -213,6 +213,11 @@ pub async fn start_lemmy_server(args: CmdArgs) -> Result<(), LemmyError> {
   let request_data = federation_config.to_request_data();
   let outgoing_activities_task = tokio::task::spawn(handle_outgoing_activities(request_data));
 
+  if !args.disable_scheduled_tasks {
+    // Schedules various cleanup tasks for the DB
+    let _scheduled_tasks = tokio::task::spawn(scheduled_tasks::setup(federation_config.clone()));
+  }
+
   let server = if args.http_server {
     if let Some(startup_server_handle) = startup_server_handle {
       startup_server_handle.stop(true).await;
@@ -236,6 +241,20 @@ pub async fn start_lemmy_server(args: CmdArgs) -> Result<(), LemmyError> {
       federation_config.clone(),
     )
   });
+  // Webhook deliveries are split between the same processes as federation
+  let webhooks = args.federate_activities.then(|| {
+    start_stop_webhook_workers_cancellable(
+      Opts {
+        process_index: args.federate_process_index,
+        process_count: args.federate_process_count,
+      },
+      pool.clone(),
+      federation_config.clone(),
+    )
+  });
+  // Push notifications are delivered by a single worker, in the first process
+  let push = (args.federate_activities && args.federate_process_index == 1)
+    .then(|| start_push_worker_cancellable(pool.clone(), federation_config.clone()));
   let mut interrupt = tokio::signal::unix::signal(SignalKind::interrupt())?;
   let mut terminate = tokio::signal::unix::signal(SignalKind::terminate())?;
 
@@ -256,6 +275,12 @@ pub async fn start_lemmy_server(args: CmdArgs) -> Result<(), LemmyError> {
   if let Some(federate) = federate {
     federate.cancel().await?;
   }
+  if let Some(webhooks) = webhooks {
+    webhooks.cancel().await?;
+  }
+  if let Some(push) = push {
+    push.cancel().await?;
+  }
 
   // Wait for outgoing apub sends to complete
   ActivityChannel::close(outgoing_activities_task).await?;
@@ -1,3 +1,4 @@
+use activitypub_federation::config::{Data, FederationConfig};
 use chrono::{DateTime, TimeZone, Utc};
 use clokwerk::{AsyncScheduler, TimeUnits as CTimeUnits};
 use diesel::{
@@ -10,7 +11,11 @@ use diesel::{
   QueryableByName,
 };
 use diesel_async::{AsyncPgConnection, RunQueryDsl};
-use lemmy_api_common::context::LemmyContext;
+use lemmy_api_common::{
+  context::LemmyContext,
+  lemmy_db_views::structs::LocalUserView,
+  utils::{announce_published_post, send_email_digest},
+};
 use lemmy_db_schema::{
   schema::{
     captcha_answer,
@@ -19,11 +24,21 @@ use lemmy_db_schema::{
     instance,
     person,
     post,
+    push_notification,
     received_activity,
     sent_activity,
+    webhook_delivery,
+    webhook_event,
+  },
+  source::{
+    instance::{Instance, InstanceForm},
+    local_site::LocalSite,
+    local_user::{LocalUser, LocalUserUpdateForm},
+    post::Post,
   },
-  source::instance::{Instance, InstanceForm},
+  traits::Crud,
   utils::{get_conn, naive_now, now, DbPool, DELETED_REPLACEMENT_TEXT},
+  EmailDigestFrequency,
 };
 use lemmy_routes::nodeinfo::NodeInfo;
 use lemmy_utils::error::{LemmyError, LemmyResult};
@@ -32,7 +47,7 @@ use std::time::Duration;
 use tracing::{error, info, warn};
 
 /// Schedules various cleanup tasks for lemmy in a background thread
-pub async fn setup(context: LemmyContext) -> Result<(), LemmyError> {
+pub async fn setup(context: FederationConfig<LemmyContext>) -> Result<(), LemmyError> {
   // Setup the connections
   let mut scheduler = AsyncScheduler::new();
   startup_jobs(&mut context.pool()).await;
@@ -68,6 +83,26 @@ pub async fn setup(context: LemmyContext) -> Result<(), LemmyError> {
     }
   });
 
+  let context_1 = context.clone();
+  // Publish scheduled posts every minute
+  scheduler.every(CTimeUnits::minute(1)).run(move || {
+    let context = context_1.to_request_data();
+
+    async move {
+      publish_scheduled_posts(&context).await;
+    }
+  });
+
+  let context_1 = context.clone();
+  // Send the email digests which are due every hour
+  scheduler.every(CTimeUnits::hour(1)).run(move || {
+    let context = context_1.to_request_data();
+
+    async move {
+      send_email_digests(&context).await;
+    }
+  });
+
   let context_1 = context.clone();
   // Clear old activities every week
   scheduler.every(CTimeUnits::weeks(1)).run(move || {
@@ -299,6 +334,55 @@ async fn delete_expired_captcha_answers(pool: &mut DbPool<'_>) {
   }
 }
 
+/// Sends the daily and weekly email digests of users who didn't get one for that long
+async fn send_email_digests(context: &LemmyContext) {
+  if context.settings().email.is_none() {
+    return;
+  }
+  let require_email_verified = match LocalSite::read(&mut context.pool()).await {
+    Ok(local_site) => local_site.require_email_verification,
+    Err(e) => {
+      error!("Failed to read local site: {e}");
+      return;
+    }
+  };
+  for frequency in [EmailDigestFrequency::Daily, EmailDigestFrequency::Weekly] {
+    let users = match LocalUserView::list_for_email_digest(
+      &mut context.pool(),
+      frequency,
+      require_email_verified,
+    )
+    .await
+    {
+      Ok(users) => users,
+      Err(e) => {
+        error!("Failed to list users for {frequency} email digest: {e}");
+        continue;
+      }
+    };
+    info!("Sending {frequency} email digest to {} users", users.len());
+    for local_user_view in users {
+      // Marked as sent first, so that a failing address isn't retried every hour
+      let form = LocalUserUpdateForm {
+        last_email_digest: Some(Some(naive_now())),
+        ..Default::default()
+      };
+      if let Err(e) =
+        LocalUser::update(&mut context.pool(), local_user_view.local_user.id, &form).await
+      {
+        error!("Failed to update last email digest: {e}");
+        continue;
+      }
+      if let Err(e) = send_email_digest(&local_user_view, context).await {
+        warn!(
+          "Failed to send email digest to {}: {e}",
+          local_user_view.person.name
+        );
+      }
+    }
+  }
+}
+
 /// Clear old activities (this table gets very large)
 async fn clear_old_activities(pool: &mut DbPool<'_>) {
   info!("Clearing old activities...");
@@ -312,6 +396,29 @@ async fn clear_old_activities(pool: &mut DbPool<'_>) {
         .map_err(|e| error!("Failed to clear old sent activities: {e}"))
         .ok();
 
+      diesel::delete(webhook_event::table.filter(webhook_event::published.lt(now() - 1.weeks())))
+        .execute(&mut conn)
+        .await
+        .map_err(|e| error!("Failed to clear old webhook events: {e}"))
+        .ok();
+
+      diesel::delete(
+        webhook_delivery::table.filter(webhook_delivery::published.lt(now() - 1.months())),
+      )
+      .execute(&mut conn)
+      .await
+      .map_err(|e| error!("Failed to clear old webhook deliveries: {e}"))
+      .ok();
+
+      // Push notifications are only left over if no process delivers them
+      diesel::delete(
+        push_notification::table.filter(push_notification::published.lt(now() - 1.days())),
+      )
+      .execute(&mut conn)
+      .await
+      .map_err(|e| error!("Failed to clear old push notifications: {e}"))
+      .ok();
+
       diesel::delete(
         received_activity::table.filter(received_activity::published.lt(now() - 3.months())),
       )
@@ -374,6 +481,24 @@ async fn overwrite_deleted_posts_and_comments(pool: &mut DbPool<'_>) {
   }
 }
 
+/// Publish the scheduled posts whose time was reached, and only then federate them
+async fn publish_scheduled_posts(context: &Data<LemmyContext>) {
+  let posts = match Post::publish_scheduled(&mut context.pool()).await {
+    Ok(posts) => posts,
+    Err(e) => {
+      error!("Failed to publish scheduled posts: {e}");
+      return;
+    }
+  };
+
+  for post in posts {
+    announce_published_post(post, context)
+      .await
+      .map_err(|e| error!("Failed to send scheduled post: {e}"))
+      .ok();
+  }
+}
+
 /// Re-calculate the site and community active counts every 12 hours
 async fn active_counts(pool: &mut DbPool<'_>) {
   info!("Updating active site and community aggregates ...");